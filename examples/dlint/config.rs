@@ -1,10 +1,19 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
+use anyhow::anyhow;
 use anyhow::bail;
 use anyhow::Error as AnyError;
+use deno_ast::ModuleSpecifier;
+use deno_lint::config::{
+  parse_config, parse_deno_lint_config, ConfigError, ConfigFormat,
+  ConfigOverride, DenoLintConfig, RuleSelection, DENO_CONFIG_FILE_NAMES,
+};
+use deno_lint::linter::EcmaVersion;
+use deno_lint::options_schema::{configure_rule, validate_options};
 use deno_lint::rules::get_all_rules;
-use deno_lint::rules::{filtered_rules, LintRule};
+use deno_lint::rules::{validate_rule_codes, LintRule, RuleConfigNotice};
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::path::Path;
 use std::path::PathBuf;
 
@@ -14,6 +23,8 @@ pub struct RulesConfig {
   pub tags: Vec<String>,
   pub include: Vec<String>,
   pub exclude: Vec<String>,
+  /// Options of individual rules, keyed by rule code.
+  pub options: HashMap<String, serde_json::Value>,
 }
 
 #[derive(Debug, Default, Deserialize)]
@@ -21,6 +32,10 @@ pub struct RulesConfig {
 pub struct FilesConfig {
   pub include: Vec<String>,
   pub exclude: Vec<String>,
+  /// The directory the globs are relative to, the current directory if it's
+  /// `None`.
+  #[serde(skip)]
+  pub base_dir: Option<PathBuf>,
 }
 
 #[derive(Debug, Default, Deserialize)]
@@ -28,15 +43,137 @@ pub struct FilesConfig {
 pub struct Config {
   pub rules: RulesConfig,
   pub files: FilesConfig,
+  /// The output format, used unless `--format` is passed.
+  pub report: Option<String>,
+  /// The oldest ECMAScript version the linted files have to run on, e.g.
+  /// `"es2018"`. The latest version if it's `None`.
+  pub target: Option<EcmaVersion>,
+  /// Changes of the rules for some of the files, applied in order.
+  pub overrides: Vec<ConfigOverride>,
+  /// The milliseconds a rule may spend on a file before it's aborted for
+  /// the file. Rules aren't limited if it's `None`.
+  #[serde(rename = "ruleTimeBudget")]
+  pub rule_time_budget: Option<u64>,
 }
 
+/// The extensions of the files that are linted when a `deno.json` file
+/// doesn't specify which files to include.
+const DENO_LINT_EXTENSIONS: &str = "{js,jsx,ts,tsx,mjs,mts,cjs,cts}";
+
 impl Config {
-  pub fn get_rules(&self) -> Vec<Box<dyn LintRule>> {
-    filtered_rules(
-      get_all_rules(),
-      Some(self.rules.tags.clone()),
-      Some(self.rules.exclude.clone()),
-      Some(self.rules.include.clone()),
+  /// Converts the `lint` section of a `deno.json` file in `base_dir`.
+  pub fn from_deno(lint: DenoLintConfig, base_dir: &Path) -> Self {
+    let to_glob = |path: String, suffix: &str| {
+      if base_dir.join(&path).is_dir() {
+        format!("{}/{}", path.trim_end_matches('/'), suffix)
+      } else {
+        path
+      }
+    };
+    let include = lint
+      .include
+      .unwrap_or_else(|| vec![".".to_string()])
+      .into_iter()
+      .map(|path| to_glob(path, &format!("**/*.{}", DENO_LINT_EXTENSIONS)))
+      .collect();
+    let exclude = lint
+      .exclude
+      .into_iter()
+      .map(|path| to_glob(path, "**"))
+      .collect();
+    Config {
+      rules: RulesConfig {
+        tags: lint
+          .rules
+          .tags
+          .unwrap_or_else(|| vec!["recommended".to_string()]),
+        include: lint.rules.include.unwrap_or_default(),
+        exclude: lint.rules.exclude.unwrap_or_default(),
+        options: HashMap::new(),
+      },
+      files: FilesConfig {
+        include,
+        exclude,
+        base_dir: Some(base_dir.to_path_buf()),
+      },
+      report: lint.report,
+      target: None,
+      overrides: Vec::new(),
+      rule_time_budget: None,
+    }
+  }
+
+  pub fn get_rules(&self) -> Result<Vec<Box<dyn LintRule>>, AnyError> {
+    self.configure_rules(self.rule_selection())
+  }
+
+  /// Checks if any override applies to the file, i.e. it needs other rules
+  /// than the ones of [`Config::get_rules`].
+  pub fn has_overrides_for(&self, specifier: &ModuleSpecifier) -> bool {
+    self
+      .overrides
+      .iter()
+      .any(|config_override| config_override.matches(specifier))
+  }
+
+  /// Returns the rules for the file after applying the matching overrides.
+  pub fn get_rules_for(
+    &self,
+    specifier: &ModuleSpecifier,
+  ) -> Result<Vec<Box<dyn LintRule>>, AnyError> {
+    self.configure_rules(
+      self
+        .rule_selection()
+        .with_overrides(&self.overrides, specifier),
+    )
+  }
+
+  fn rule_selection(&self) -> RuleSelection {
+    RuleSelection {
+      tags: self.rules.tags.clone(),
+      include: self.rules.include.clone(),
+      exclude: self.rules.exclude.clone(),
+    }
+  }
+
+  fn configure_rules(
+    &self,
+    selection: RuleSelection,
+  ) -> Result<Vec<Box<dyn LintRule>>, AnyError> {
+    selection
+      .rules(get_all_rules())
+      .into_iter()
+      .map(|rule| match self.rules.options.get(rule.code()) {
+        Some(options) => configure_rule(rule.as_ref(), options.clone())
+          .map_err(|errors| {
+            anyhow!(errors
+              .iter()
+              .map(ToString::to_string)
+              .collect::<Vec<_>>()
+              .join("\n"))
+          }),
+        None => Ok(rule),
+      })
+      .collect()
+  }
+
+  /// Notices about the configured rule codes, e.g. deprecated ones.
+  pub fn notices(&self) -> Vec<RuleConfigNotice> {
+    validate_rule_codes(
+      self
+        .rules
+        .include
+        .iter()
+        .chain(&self.rules.exclude)
+        .chain(self.rules.options.keys())
+        .chain(self.overrides.iter().flat_map(|config_override| {
+          config_override
+            .rules
+            .include
+            .iter()
+            .chain(&config_override.rules.exclude)
+        }))
+        .map(String::as_str),
     )
   }
 
@@ -45,9 +182,52 @@ impl Config {
   }
 }
 
-pub fn load_from_json(config_path: &Path) -> Result<Config, std::io::Error> {
-  let json_str = std::fs::read_to_string(config_path)?;
-  let config: Config = serde_json::from_str(&json_str)?;
+/// Loads a `.json` or `.jsonc` config file, or the `lint` section of a
+/// `deno.json` or `deno.jsonc` file. The options of the rules are
+/// validated against their schemas, so problems can be reported with their
+/// position in the file.
+pub fn load(config_path: &Path) -> Result<Config, AnyError> {
+  if is_deno_config(config_path) {
+    let text = std::fs::read_to_string(config_path)?;
+    let lint = parse_deno_lint_config(&text)
+      .map_err(|err| anyhow!("{}: {}", config_path.display(), err))?;
+    let base_dir = config_path.parent().unwrap_or(Path::new("."));
+    return Ok(Config::from_deno(lint, base_dir));
+  }
+  let Some(format) = ConfigFormat::from_path(config_path) else {
+    bail!(
+      "Unknown extension of config file \"{}\". Use .json or .jsonc instead.",
+      config_path.display()
+    );
+  };
+  let text = std::fs::read_to_string(config_path)?;
+  let config = parse(&text, format)
+    .map_err(|err| anyhow!("{}: {}", config_path.display(), err))?;
+  Ok(config)
+}
+
+fn is_deno_config(path: &Path) -> bool {
+  path
+    .file_name()
+    .and_then(|name| name.to_str())
+    .is_some_and(|name| DENO_CONFIG_FILE_NAMES.contains(&name))
+}
+
+fn parse(text: &str, format: ConfigFormat) -> Result<Config, ConfigError> {
+  let config: Config = parse_config(text, format)?;
+  let all_rules = get_all_rules();
+  for (code, options) in &config.rules.options {
+    let Some(rule) = all_rules.iter().find(|rule| rule.code() == code) else {
+      continue;
+    };
+    if let Some(err) = validate_options(rule.as_ref(), options).first() {
+      return Err(ConfigError::at_pointer(
+        text,
+        &format!("/rules/options/{}{}", code, err.path),
+        err.to_string(),
+      ));
+    }
+  }
   Ok(config)
 }
 
@@ -57,8 +237,11 @@ fn resolve_file_paths(config: &FilesConfig) -> Result<Vec<PathBuf>, AnyError> {
   let mut file_patterns = get_file_patterns(config);
   let absolute_paths = take_absolute_paths(&mut file_patterns);
 
-  let cwd = std::env::current_dir()?;
-  let mut file_paths = glob(&cwd, &file_patterns)?;
+  let base_dir = match &config.base_dir {
+    Some(base_dir) => base_dir.clone(),
+    None => std::env::current_dir()?,
+  };
+  let mut file_paths = glob(&base_dir, &file_patterns)?;
   file_paths.extend(absolute_paths);
   return Ok(file_paths);
 
@@ -171,22 +354,27 @@ mod tests {
         tags: svec![],
         include: svec![],
         exclude: svec![],
+        ..Default::default()
       },
       ..Default::default()
     };
-    assert!(config.get_rules().is_empty());
+    assert!(config.get_rules().unwrap().is_empty());
 
     let config = Config {
       rules: RulesConfig {
         tags: svec!["recommended"],
         include: svec![],
         exclude: svec![],
+        ..Default::default()
       },
       ..Default::default()
     };
     let recommended_rules_codes =
       into_codes(recommended_rules(get_all_rules()));
-    assert_eq!(into_codes(config.get_rules()), recommended_rules_codes);
+    assert_eq!(
+      into_codes(config.get_rules().unwrap()),
+      recommended_rules_codes
+    );
 
     // even if "recommended" is specified in `tags` and `include` contains a rule
     // code that is in the "recommended" set, we have to make sure that each
@@ -196,12 +384,16 @@ mod tests {
         tags: svec!["recommended"],
         include: svec!["no-empty"], // "no-empty" belongs to "recommended"
         exclude: svec![],
+        ..Default::default()
       },
       ..Default::default()
     };
     let recommended_rules_codes =
       into_codes(recommended_rules(get_all_rules()));
-    assert_eq!(into_codes(config.get_rules()), recommended_rules_codes);
+    assert_eq!(
+      into_codes(config.get_rules().unwrap()),
+      recommended_rules_codes
+    );
 
     // `exclude` has higher precedence over `include`
     let config = Config {
@@ -209,10 +401,11 @@ mod tests {
         tags: svec![],
         include: svec!["eqeqeq"],
         exclude: svec!["eqeqeq"],
+        ..Default::default()
       },
       ..Default::default()
     };
-    assert_eq!(into_codes(config.get_rules()), set![]);
+    assert_eq!(into_codes(config.get_rules().unwrap()), set![]);
 
     // if unknown rule is specified, just ignore it
     let config = Config {
@@ -220,9 +413,196 @@ mod tests {
         tags: svec![],
         include: svec!["this-is-a-totally-unknown-rule"],
         exclude: svec!["this-is-also-another-unknown-rule"],
+        ..Default::default()
       },
       ..Default::default()
     };
-    assert_eq!(into_codes(config.get_rules()), set![]);
+    assert_eq!(into_codes(config.get_rules().unwrap()), set![]);
+  }
+
+  #[test]
+  fn test_get_rules_with_options() {
+    let config: Config = serde_json::from_str(
+      r#"{
+        "rules": {
+          "include": ["no-node-globals"],
+          "options": { "no-node-globals": { "ignoreDirs": ["npm"] } }
+        }
+      }"#,
+    )
+    .unwrap();
+    assert_eq!(
+      into_codes(config.get_rules().unwrap()),
+      set!["no-node-globals"]
+    );
+
+    let config: Config = serde_json::from_str(
+      r#"{
+        "rules": {
+          "include": ["no-node-globals"],
+          "options": { "no-node-globals": { "unknown": true } }
+        }
+      }"#,
+    )
+    .unwrap();
+    assert_eq!(
+      config.get_rules().unwrap_err().to_string(),
+      "Invalid options for rule \"no-node-globals\" at \"/unknown\": Unknown property \"unknown\""
+    );
+
+    // rules without options reject any options
+    let config: Config = serde_json::from_str(
+      r#"{
+        "rules": {
+          "include": ["eqeqeq"],
+          "options": { "eqeqeq": { "foo": "bar" } }
+        }
+      }"#,
+    )
+    .unwrap();
+    assert!(config.get_rules().is_err());
+  }
+
+  #[test]
+  fn test_parse_jsonc() {
+    let config = parse(
+      r#"{
+        // Rules on top of the recommended ones.
+        "rules": {
+          "include": ["no-node-globals",],
+          "options": { "no-node-globals": { "ignoreDirs": ["npm"] } },
+        },
+      }"#,
+      ConfigFormat::Jsonc,
+    )
+    .unwrap();
+    assert_eq!(config.rules.include, svec!["no-node-globals"]);
+
+    let err = parse(
+      r#"{
+        "rules": {
+          "options": {
+            "no-node-globals": { "unknown": true }
+          }
+        }
+      }"#,
+      ConfigFormat::Json,
+    )
+    .unwrap_err();
+    assert_eq!((err.line, err.column), (4, 45));
+    assert_eq!(
+      err.message,
+      "Invalid options for rule \"no-node-globals\" at \"/unknown\": Unknown property \"unknown\""
+    );
+  }
+
+  #[test]
+  fn test_from_deno() {
+    let base_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let lint = parse_deno_lint_config(
+      r#"{
+        "lint": {
+          "include": ["examples/", "src/lib.rs"],
+          "exclude": ["examples/dlint/testdata"],
+          "rules": { "exclude": ["no-var"] },
+          "report": "compact"
+        }
+      }"#,
+    )
+    .unwrap();
+    let config = Config::from_deno(lint, base_dir);
+    assert_eq!(
+      config.files.include,
+      svec![
+        "examples/**/*.{js,jsx,ts,tsx,mjs,mts,cjs,cts}",
+        "src/lib.rs"
+      ]
+    );
+    assert_eq!(config.files.exclude, svec!["examples/dlint/testdata/**"]);
+    assert_eq!(config.report.as_deref(), Some("compact"));
+    let codes = into_codes(config.get_rules().unwrap());
+    assert!(codes.contains("no-empty"));
+    assert!(!codes.contains("no-var"));
+  }
+
+  #[test]
+  fn test_get_rules_with_overrides() {
+    let config: Config = serde_json::from_str(
+      r#"{
+        "rules": {
+          "tags": ["recommended"],
+          "include": ["no-node-globals"],
+          "options": { "no-node-globals": { "ignoreDirs": ["npm"] } }
+        },
+        "overrides": [
+          {
+            "files": ["tests/**"],
+            "rules": { "exclude": ["no-explicit-any"], "include": ["eqeqeq"] }
+          }
+        ]
+      }"#,
+    )
+    .unwrap();
+    let src = ModuleSpecifier::parse("file:///project/src/mod.ts").unwrap();
+    let tests = ModuleSpecifier::parse("file:///project/tests/mod.ts").unwrap();
+    assert!(!config.has_overrides_for(&src));
+    assert!(config.has_overrides_for(&tests));
+    assert_eq!(
+      into_codes(config.get_rules_for(&src).unwrap()),
+      into_codes(config.get_rules().unwrap())
+    );
+    assert!(into_codes(config.get_rules().unwrap()).contains("no-explicit-any"));
+    let codes = into_codes(config.get_rules_for(&tests).unwrap());
+    assert!(codes.contains("no-node-globals"));
+    assert!(codes.contains("eqeqeq"));
+    assert!(!codes.contains("no-explicit-any"));
+
+    assert!(serde_json::from_str::<Config>(
+      r#"{ "overrides": [{ "files": ["tests/**"], "unknown": true }] }"#
+    )
+    .is_err());
+  }
+
+  #[test]
+  fn test_target() {
+    let config: Config =
+      serde_json::from_str(r#"{ "target": "es2017" }"#).unwrap();
+    assert_eq!(config.target, Some(EcmaVersion::Es2017));
+    let config: Config = serde_json::from_str("{}").unwrap();
+    assert_eq!(config.target, None);
+    assert!(serde_json::from_str::<Config>(r#"{ "target": "es3" }"#).is_err());
+  }
+
+  #[test]
+  fn test_rule_time_budget() {
+    let config: Config =
+      serde_json::from_str(r#"{ "ruleTimeBudget": 500 }"#).unwrap();
+    assert_eq!(config.rule_time_budget, Some(500));
+    let config: Config = serde_json::from_str("{}").unwrap();
+    assert_eq!(config.rule_time_budget, None);
+  }
+
+  #[test]
+  fn test_get_rules_with_alias() {
+    let config: Config = serde_json::from_str(
+      r#"{
+        "rules": {
+          "tags": [],
+          "include": ["single-var-declarator"]
+        }
+      }"#,
+    )
+    .unwrap();
+    assert_eq!(into_codes(config.get_rules().unwrap()), set!["one-var"]);
+    let notices = config.notices();
+    assert_eq!(notices.len(), 1);
+    assert!(matches!(
+      notices[0],
+      RuleConfigNotice::Deprecated {
+        code: "single-var-declarator",
+        replacement: "one-var",
+        ..
+      }
+    ));
   }
 }
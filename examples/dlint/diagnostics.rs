@@ -10,6 +10,7 @@ pub fn display_diagnostics(
   match format {
     Some("compact") => print_compact(diagnostics),
     Some("pretty") => print_pretty(diagnostics),
+    Some("github") => print_github(diagnostics),
     _ => unreachable!("Invalid output format specified"),
   }
 }
@@ -27,7 +28,18 @@ fn print_compact(diagnostics: &[LintDiagnostic]) {
           display_index.column_number,
           diagnostic.details.message,
           diagnostic.details.code
-        )
+        );
+        for related in &diagnostic.details.related {
+          let display_index =
+            range.text_info.line_and_column_display(related.range.start);
+          eprintln!(
+            "  {}: line {}, col {}, Info - {}",
+            diagnostic.specifier,
+            display_index.line_number,
+            display_index.column_number,
+            related.message
+          )
+        }
       }
       None => {
         eprintln!(
@@ -46,3 +58,72 @@ fn print_pretty(diagnostics: &[LintDiagnostic]) {
     eprintln!("{}\n", diagnostic.display());
   }
 }
+
+/// Prints the diagnostics as GitHub Actions workflow commands to stdout, so
+/// they are shown as annotations on the changed lines of pull requests. The
+/// diagnostics of each file are grouped together in the log.
+///
+/// See https://docs.github.com/en/actions/using-workflows/workflow-commands-for-github-actions
+fn print_github(diagnostics: &[LintDiagnostic]) {
+  let Some(first) = diagnostics.first() else {
+    return;
+  };
+  // Annotations are matched to files by their path relative to the root of
+  // the repository, which is usually the working directory of the job.
+  let path = first
+    .specifier
+    .to_file_path()
+    .ok()
+    .map(|path| {
+      std::env::current_dir()
+        .ok()
+        .and_then(|cwd| path.strip_prefix(cwd).ok().map(|p| p.to_path_buf()))
+        .unwrap_or(path)
+        .display()
+        .to_string()
+    })
+    .unwrap_or_else(|| first.specifier.to_string());
+
+  println!("::group::{}", escape_github_data(&path));
+  for diagnostic in diagnostics {
+    let mut properties = format!("file={}", escape_github_property(&path));
+    if let Some(range) = &diagnostic.range {
+      let start = range.text_info.line_and_column_display(range.range.start);
+      let end = range.text_info.line_and_column_display(range.range.end);
+      properties.push_str(&format!(
+        ",line={},col={},endLine={},endColumn={}",
+        start.line_number,
+        start.column_number,
+        end.line_number,
+        end.column_number
+      ));
+    }
+    properties.push_str(&format!(
+      ",title={}",
+      escape_github_property(&diagnostic.details.code)
+    ));
+
+    let mut message = diagnostic.details.message.clone();
+    if let Some(hint) = &diagnostic.details.hint {
+      message.push_str("\n\n");
+      message.push_str(hint);
+    }
+    println!("::error {}::{}", properties, escape_github_data(&message));
+  }
+  println!("::endgroup::");
+}
+
+/// Escapes the message of a workflow command.
+fn escape_github_data(data: &str) -> String {
+  data
+    .replace('%', "%25")
+    .replace('\r', "%0D")
+    .replace('\n', "%0A")
+}
+
+/// Escapes the value of a property of a workflow command.
+fn escape_github_property(value: &str) -> String {
+  escape_github_data(value)
+    .replace(':', "%3A")
+    .replace(',', "%2C")
+}
@@ -8,6 +8,9 @@ use core::panic;
 use deno_ast::diagnostics::Diagnostic;
 use deno_ast::MediaType;
 use deno_ast::ModuleSpecifier;
+use deno_ast::TextChange;
+use deno_lint::config::find_deno_config;
+use deno_lint::extract::{Extractor, MarkdownExtractor, VirtualSource};
 use deno_lint::linter::LintConfig;
 use deno_lint::linter::LintFileOptions;
 use deno_lint::linter::Linter;
@@ -22,9 +25,12 @@ use std::collections::HashSet;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 mod config;
 mod diagnostics;
+#[cfg(feature = "scaffold")]
+mod new_rule;
 mod rules;
 
 fn create_cli_app<'a>() -> Command<'a> {
@@ -39,6 +45,27 @@ fn create_cli_app<'a>() -> Command<'a> {
         )
         .arg(Arg::new("json").long("json")),
     )
+    .subcommand(
+      Command::new("explain").arg(
+        Arg::new("RULE_CODE")
+          .help("Show the documentation, examples and options of a rule")
+          .required(true),
+      ),
+    )
+    .subcommand(
+      Command::new("new-rule")
+        .arg(
+          Arg::new("RULE_CODE")
+            .help("Generate the module, registration and tests of a new rule (requires the `scaffold` feature)")
+            .required(true),
+        )
+        .arg(
+          Arg::new("YEAR")
+            .long("year")
+            .help("The year of the copyright header, defaults to the one of src/rules.rs")
+            .takes_value(true),
+        ),
+    )
     .subcommand(
       Command::new("run")
         .arg(
@@ -52,10 +79,24 @@ fn create_cli_app<'a>() -> Command<'a> {
             .help("Run a certain rule")
             .takes_value(true),
         )
+        .arg(
+          Arg::new("EXT")
+            .long("ext")
+            .help("Lint all files as the given type, e.g. `md` for the code blocks of Markdown files without a `.md` extension")
+            .takes_value(true)
+            .possible_values(["md"]),
+        )
+        .arg(
+          Arg::new("ADD_IGNORES")
+            .long("add-ignores")
+            .help("Insert `// deno-lint-ignore` comments for the diagnostics of the given rule code instead of reporting them")
+            .takes_value(true)
+            .value_name("RULE_CODE"),
+        )
         .arg(
           Arg::new("CONFIG")
             .long("config")
-            .help("Load config from file")
+            .help("Load config from a .json or .jsonc file")
             .takes_value(true),
         ).arg(
           Arg::new("FORMAT")
@@ -66,17 +107,39 @@ fn create_cli_app<'a>() -> Command<'a> {
             .validator(|val: &str| match val {
               "compact" => Ok(()),
               "pretty" => Ok(()),
-              _ => Err("Output format must be compact or pretty")
+              "github" => Ok(()),
+              _ => Err("Output format must be compact, pretty or github")
             }),
         )
     )
 }
 
+/// Extracts the code blocks of all files as if they were Markdown, for
+/// `--ext md`.
+#[derive(Debug)]
+struct AllFilesAsMarkdown;
+
+impl Extractor for AllFilesAsMarkdown {
+  fn matches(&self, _specifier: &ModuleSpecifier) -> bool {
+    true
+  }
+
+  fn extract(
+    &self,
+    specifier: &ModuleSpecifier,
+    source_code: &str,
+  ) -> Vec<VirtualSource> {
+    MarkdownExtractor.extract(specifier, source_code)
+  }
+}
+
 fn run_linter(
   paths: Vec<String>,
   filter_rule_name: Option<&str>,
   maybe_config: Option<Arc<config::Config>>,
   format: Option<&str>,
+  ext: Option<&str>,
+  add_ignores: Option<&str>,
 ) -> Result<(), AnyError> {
   let cwd = std::env::current_dir()?;
   let mut paths: Vec<PathBuf> =
@@ -94,8 +157,15 @@ fn run_linter(
     .map(|rule| rule.code())
     .map(Cow::from)
     .collect::<HashSet<_>>();
-  let rules = if let Some(config) = maybe_config {
-    config.get_rules()
+  let ecma_version = maybe_config
+    .as_ref()
+    .and_then(|config| config.target)
+    .unwrap_or_default();
+  let rules = if let Some(config) = &maybe_config {
+    for notice in config.notices() {
+      eprintln!("Warning: {}", notice);
+    }
+    config.get_rules()?
   } else if let Some(rule_name) = filter_rule_name {
     let include = vec![rule_name.to_string()];
     filtered_rules(get_all_rules(), Some(vec![]), None, Some(include))
@@ -108,44 +178,107 @@ fn run_linter(
     debug!("Configured rules: {}", rules.len());
   }
   let file_diagnostics = Arc::new(Mutex::new(BTreeMap::new()));
-  let linter = Linter::new(LinterOptions {
-    rules,
-    all_rule_codes,
-    custom_ignore_file_directive: None,
-    custom_ignore_diagnostic_directive: None,
-  });
+  let timed_out_rules = Arc::new(Mutex::new(BTreeMap::new()));
+  let is_markdown = ext == Some("md");
+  let rule_time_budget = maybe_config
+    .as_ref()
+    .and_then(|config| config.rule_time_budget)
+    .map(Duration::from_millis);
+  let create_linter = |rules| {
+    let extractor: Box<dyn Extractor> = if is_markdown {
+      Box::new(AllFilesAsMarkdown)
+    } else {
+      Box::new(MarkdownExtractor)
+    };
+    Linter::new(LinterOptions {
+      rules,
+      all_rule_codes: all_rule_codes.clone(),
+      custom_ignore_file_directive: None,
+      custom_ignore_diagnostic_directive: None,
+      extractors: vec![extractor],
+      rule_time_budget,
+    })
+  };
+  let default_linter = create_linter(rules);
 
   paths
     .par_iter()
     .try_for_each(|file_path| -> Result<(), AnyError> {
       let source_code = std::fs::read_to_string(file_path)?;
 
-      let (parsed_source, diagnostics) = linter.lint_file(LintFileOptions {
-        specifier: ModuleSpecifier::from_file_path(file_path).unwrap_or_else(
-          |_| {
-            panic!(
-              "Failed to convert path to module specifier: {}",
-              file_path.display()
-            )
-          },
-        ),
+      let specifier = ModuleSpecifier::from_file_path(file_path)
+        .unwrap_or_else(|_| {
+          panic!(
+            "Failed to convert path to module specifier: {}",
+            file_path.display()
+          )
+        });
+      let is_extracted = is_markdown || MarkdownExtractor.matches(&specifier);
+      // Files matched by overrides get a linter with their own rules.
+      let override_linter = match &maybe_config {
+        Some(config) if config.has_overrides_for(&specifier) => {
+          Some(create_linter(config.get_rules_for(&specifier)?))
+        }
+        _ => None,
+      };
+      let linter = override_linter.as_ref().unwrap_or(&default_linter);
+      let options = LintFileOptions {
+        specifier,
         source_code,
         media_type: MediaType::from_path(file_path),
         config: LintConfig {
           default_jsx_factory: Some("React.createElement".to_string()),
           default_jsx_fragment_factory: Some("React.Fragment".to_string()),
+          ecma_version,
         },
         external_linter: None,
-      })?;
-
-      let mut number_of_errors = diagnostics.len();
-      if !parsed_source.diagnostics().is_empty() {
-        number_of_errors += parsed_source.diagnostics().to_vec().len();
-        parsed_source.diagnostics().to_vec().iter().for_each(
-          |parsing_diagnostic| {
-            eprintln!("{}", parsing_diagnostic.display());
-          },
+      };
+      if let Some(code) = add_ignores {
+        if is_extracted {
+          return Ok(());
+        }
+        let (parsed_source, diagnostics) = linter.lint_file(options)?;
+        let diagnostics = diagnostics
+          .into_iter()
+          .filter(|diagnostic| diagnostic.details.code == code)
+          .collect::<Vec<_>>();
+        let changes =
+          linter.ignore_directive_changes(&parsed_source, &diagnostics);
+        if changes.is_empty() {
+          return Ok(());
+        }
+        let text_info = parsed_source.text_info_lazy();
+        let new_text = deno_ast::apply_text_changes(
+          text_info.text_str(),
+          changes
+            .into_iter()
+            .map(|change| TextChange {
+              range: change.range.as_byte_range(text_info.range().start),
+              new_text: change.new_text.into_owned(),
+            })
+            .collect(),
         );
+        std::fs::write(file_path, new_text)?;
+        error_counts.fetch_add(diagnostics.len(), Ordering::Relaxed);
+        return Ok(());
+      }
+
+      let (diagnostics, parse_diagnostics) = if is_extracted {
+        (linter.lint_file_with_extraction(options), vec![])
+      } else {
+        let (parsed_source, result) = linter.lint_file_with_result(options)?;
+        if !result.timed_out_rules.is_empty() {
+          timed_out_rules
+            .lock()
+            .unwrap()
+            .insert(file_path, result.timed_out_rules);
+        }
+        (result.diagnostics, parsed_source.diagnostics().to_vec())
+      };
+
+      let number_of_errors = diagnostics.len() + parse_diagnostics.len();
+      for parsing_diagnostic in &parse_diagnostics {
+        eprintln!("{}", parsing_diagnostic.display());
       }
 
       error_counts.fetch_add(number_of_errors, Ordering::Relaxed);
@@ -157,11 +290,30 @@ fn run_linter(
       Ok(())
     })?;
 
+  let err_count = error_counts.load(Ordering::Relaxed);
+  if add_ignores.is_some() {
+    eprintln!(
+      "Ignored {} problem{}",
+      err_count,
+      if err_count == 1 { "" } else { "s" }
+    );
+    return Ok(());
+  }
+
   for d in file_diagnostics.lock().unwrap().values() {
     diagnostics::display_diagnostics(d, format);
   }
 
-  let err_count = error_counts.load(Ordering::Relaxed);
+  for (file_path, codes) in timed_out_rules.lock().unwrap().iter() {
+    for code in codes {
+      eprintln!(
+        "Warning: Rule \"{}\" exceeded its time budget on {} and was aborted",
+        code,
+        file_path.display()
+      );
+    }
+  }
+
   if err_count > 0 {
     eprintln!(
       "Found {} problem{}",
@@ -182,30 +334,44 @@ fn main() -> Result<(), AnyError> {
 
   match matches.subcommand() {
     Some(("run", run_matches)) => {
-      let maybe_config = if let Some(p) = run_matches.value_of("CONFIG") {
-        let path = PathBuf::from(p);
-
-        let c = match path.extension().and_then(|s| s.to_str()) {
-          Some("json") => config::load_from_json(&path)?,
-          ext => bail!("Unknown extension: \"{:#?}\". Use .json instead.", ext),
-        };
-        Some(Arc::new(c))
-      } else {
-        None
-      };
-
-      debug!("Config: {:#?}", maybe_config);
-
       let paths: Vec<String> = run_matches
         .values_of("FILES")
         .unwrap_or_default()
         .map(|p| p.to_string())
         .collect();
+      let maybe_config_path = match run_matches.value_of("CONFIG") {
+        Some(p) => Some(PathBuf::from(p)),
+        // Like `deno lint`, use the `deno.json` file of the project when no
+        // files are specified.
+        None if paths.is_empty() => find_deno_config(&std::env::current_dir()?),
+        None => None,
+      };
+      let maybe_config = match maybe_config_path {
+        Some(path) => Some(Arc::new(config::load(&path)?)),
+        None => None,
+      };
+
+      debug!("Config: {:#?}", maybe_config);
+
+      let format = match maybe_config
+        .as_ref()
+        .and_then(|config| config.report.as_deref())
+      {
+        Some(report) if run_matches.occurrences_of("FORMAT") == 0 => {
+          if !matches!(report, "compact" | "pretty" | "github") {
+            bail!("Unsupported report format: \"{}\"", report);
+          }
+          Some(report)
+        }
+        _ => run_matches.value_of("FORMAT"),
+      };
       run_linter(
         paths,
         run_matches.value_of("RULE_CODE"),
-        maybe_config,
-        run_matches.value_of("FORMAT"),
+        maybe_config.clone(),
+        format,
+        run_matches.value_of("EXT"),
+        run_matches.value_of("ADD_IGNORES"),
       )?;
     }
     Some(("rules", rules_matches)) => {
@@ -220,6 +386,25 @@ fn main() -> Result<(), AnyError> {
         rules::print_rules::<rules::PrettyFormatter>(rules);
       }
     }
+    Some(("explain", explain_matches)) => {
+      rules::explain_rule(explain_matches.value_of("RULE_CODE").unwrap())?;
+    }
+    Some(("new-rule", new_rule_matches)) => {
+      let code = new_rule_matches.value_of("RULE_CODE").unwrap();
+      #[cfg(feature = "scaffold")]
+      {
+        let year = new_rule_matches
+          .value_of("YEAR")
+          .map(|year| year.parse())
+          .transpose()?;
+        new_rule::new_rule(code, year)?;
+      }
+      #[cfg(not(feature = "scaffold"))]
+      bail!(
+        "Can't scaffold \"{}\": dlint was built without the `scaffold` feature",
+        code
+      );
+    }
     _ => unreachable!(),
   };
 
@@ -448,6 +633,18 @@ mod tests {
     exit_code: 1,
   });
 
+  itest!(explain_rule {
+    args: "explain jsx-alt-text",
+    output_str: Some("jsx-alt-text\nTags: a11y\nFixable: no\n\nRequires alternative text [WILDCARD]\nThis rule has no options.\nDocumentation: https://docs.deno.com/lint/rules/jsx-alt-text\n"),
+    exit_code: 0,
+  });
+
+  itest!(github_format {
+    args: "run --format github simple.ts",
+    output: "simple_github.out",
+    exit_code: 1,
+  });
+
   itest!(issue1145_no_trailing_newline {
     args: "run issue1145_no_trailing_newline.ts",
     output: "issue1145_no_trailing_newline.out",
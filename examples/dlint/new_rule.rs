@@ -0,0 +1,425 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Scaffolding of new lint rules, see `dlint new-rule --help`.
+
+use anyhow::bail;
+use anyhow::Error as AnyError;
+use std::path::Path;
+use std::path::PathBuf;
+
+struct RuleNames {
+  kebab: String,
+  snake: String,
+  pascal: String,
+}
+
+impl RuleNames {
+  fn new(code: &str) -> Result<Self, AnyError> {
+    let is_valid = !code.is_empty()
+      && code
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+      && !code.starts_with('-')
+      && !code.ends_with('-')
+      && !code.contains("--");
+    if !is_valid {
+      bail!(
+        "Invalid rule code \"{}\". Use lowercase kebab-case, e.g. \"no-foo-bar\".",
+        code
+      );
+    }
+
+    let pascal = code
+      .split('-')
+      .map(|word| {
+        let mut chars = word.chars();
+        match chars.next() {
+          Some(first) => {
+            first.to_ascii_uppercase().to_string() + chars.as_str()
+          }
+          None => String::new(),
+        }
+      })
+      .collect();
+    Ok(Self {
+      kebab: code.to_string(),
+      snake: code.replace('-', "_"),
+      pascal,
+    })
+  }
+}
+
+/// Generates the module of a new rule, registers it in `src/rules.rs` and
+/// adds its code to the rules JSON schema. The copyright header of the module
+/// uses `year`, or the year of the header of `src/rules.rs`.
+pub fn new_rule(code: &str, year: Option<u64>) -> Result<(), AnyError> {
+  let names = RuleNames::new(code)?;
+  let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+
+  let rule_path = root
+    .join("src")
+    .join("rules")
+    .join(format!("{}.rs", names.snake));
+  if rule_path.exists() {
+    bail!("Rule module already exists: {}", rule_path.display());
+  }
+
+  let rules_rs_path = root.join("src").join("rules.rs");
+  let rules_rs = std::fs::read_to_string(&rules_rs_path)?;
+  let Some(year) = year.or_else(|| copyright_year(&rules_rs)) else {
+    bail!("Failed to find the copyright year in src/rules.rs, pass --year");
+  };
+  let rules_rs = register_rule(&rules_rs, &names)?;
+
+  let schema_path = root.join("schemas").join("rules.v1.json");
+  let schema = add_to_schema(&std::fs::read_to_string(&schema_path)?, code)?;
+
+  std::fs::write(&rule_path, gen_rule_module(year, &names))?;
+  std::fs::write(&rules_rs_path, rules_rs)?;
+  std::fs::write(&schema_path, schema)?;
+
+  println!("Created {}", relative(&root, &rule_path));
+  println!("Registered \"{}\" in src/rules.rs", code);
+  println!("Added \"{}\" to schemas/rules.v1.json", code);
+  println!(
+    "Next, implement the rule and fill in its docs and tests in {}.",
+    relative(&root, &rule_path)
+  );
+  Ok(())
+}
+
+fn relative(root: &Path, path: &Path) -> String {
+  path
+    .strip_prefix(root)
+    .unwrap_or(path)
+    .display()
+    .to_string()
+}
+
+/// Adds the `pub mod` declaration and the `get_all_rules_raw` entry of the
+/// rule, keeping both lists sorted.
+fn register_rule(
+  rules_rs: &str,
+  names: &RuleNames,
+) -> Result<String, AnyError> {
+  let mut lines = rules_rs.lines().map(String::from).collect::<Vec<_>>();
+
+  let Some(first_mod) = lines.iter().position(|l| mod_name(l).is_some()) else {
+    bail!("Failed to find rule modules in src/rules.rs");
+  };
+  // Modules can be preceded by attributes like `#[deprecated(...)]`, which
+  // have to stay with them.
+  let mut item_start = None;
+  let mut mod_index = None;
+  for (i, line) in lines.iter().enumerate().skip(first_mod) {
+    if line.is_empty() {
+      mod_index = Some(i);
+      break;
+    }
+    if line.starts_with("#[") {
+      item_start.get_or_insert(i);
+    } else if let Some(name) = mod_name(line) {
+      if name > names.snake.as_str() {
+        mod_index = Some(item_start.unwrap_or(i));
+        break;
+      }
+      item_start = None;
+    }
+  }
+  lines.insert(
+    mod_index.unwrap_or(lines.len()),
+    format!("pub mod {};", names.snake),
+  );
+
+  let Some(start) = lines
+    .iter()
+    .position(|l| l.starts_with("fn get_all_rules_raw()"))
+  else {
+    bail!("Failed to find `get_all_rules_raw` in src/rules.rs");
+  };
+  let Some(end) = lines[start..].iter().position(|l| l == "  ]") else {
+    bail!("Failed to find the end of `get_all_rules_raw` in src/rules.rs");
+  };
+  let end = start + end;
+
+  let mut entry_index = end;
+  for (i, line) in lines.iter().enumerate().take(end).skip(start) {
+    let line = line.trim();
+    if !line.starts_with("Box::new(") {
+      continue;
+    }
+    // Long entries are split across lines by rustfmt.
+    let entry = match line.trim_start_matches("Box::new(") {
+      "" => lines[i + 1].trim(),
+      entry => entry,
+    };
+    let module = entry.split("::").next().unwrap_or_default();
+    if module.replace('_', "-") > names.kebab {
+      entry_index = i;
+      break;
+    }
+  }
+  let entry = format!("    Box::new({}::{}),", names.snake, names.pascal);
+  if entry.len() > 80 {
+    lines.insert(entry_index, "    ),".to_string());
+    lines.insert(
+      entry_index,
+      format!("      {}::{},", names.snake, names.pascal),
+    );
+    lines.insert(entry_index, "    Box::new(".to_string());
+  } else {
+    lines.insert(entry_index, entry);
+  }
+
+  let mut out = lines.join("\n");
+  out.push('\n');
+  Ok(out)
+}
+
+/// Returns the name of the module declared on the line, if any.
+fn mod_name(line: &str) -> Option<&str> {
+  let rest = line.strip_prefix("pub mod ")?;
+  let end = rest
+    .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+    .unwrap_or(rest.len());
+  Some(&rest[..end])
+}
+
+fn add_to_schema(schema: &str, code: &str) -> Result<String, AnyError> {
+  let mut schema: serde_json::Value = serde_json::from_str(schema)?;
+  let Some(codes) = schema.get_mut("enum").and_then(|e| e.as_array_mut())
+  else {
+    bail!("Failed to find the list of rule codes in the rules schema");
+  };
+  codes.push(serde_json::Value::String(code.to_string()));
+  codes.sort_by(|a, b| a.as_str().cmp(&b.as_str()));
+  codes.dedup();
+
+  let mut out = serde_json::to_string_pretty(&schema)?;
+  out.push('\n');
+  Ok(out)
+}
+
+fn gen_rule_module(year: u64, names: &RuleNames) -> String {
+  let RuleNames {
+    kebab,
+    snake,
+    pascal,
+  } = names;
+  format!(
+    r##"// Copyright 2018-{year} the Deno authors. All rights reserved. MIT license.
+
+use super::{{Context, LintRule, RuleDocs}};
+use crate::handler::{{Handler, Traverse}};
+use crate::Program;
+use deno_ast::view as ast_view;
+use deno_ast::SourceRanged;
+
+#[derive(Debug)]
+pub struct {pascal};
+
+const CODE: &str = "{kebab}";
+const MESSAGE: &str = "";
+const HINT: &str = "";
+
+impl LintRule for {pascal} {{
+  fn code(&self) -> &'static str {{
+    CODE
+  }}
+
+  fn docs(&self) -> RuleDocs {{
+    RuleDocs {{
+      description: "",
+      invalid_examples: &[],
+      valid_examples: &[],
+    }}
+  }}
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {{
+    {pascal}Handler.traverse(program, context);
+  }}
+}}
+
+struct {pascal}Handler;
+
+impl Handler for {pascal}Handler {{
+  // implement some methods to achieve the goal of this lint
+
+  // This is an example
+  fn with_stmt(&mut self, with_stmt: &ast_view::WithStmt, ctx: &mut Context) {{
+    ctx.add_diagnostic_with_hint(with_stmt.range(), CODE, MESSAGE, HINT);
+  }}
+}}
+
+#[cfg(test)]
+mod tests {{
+  use super::*;
+
+  #[test]
+  fn {snake}_valid() {{
+    assert_lint_ok! {{
+      {pascal},
+      r#"// put a valid case here"#,
+    }};
+  }}
+
+  #[test]
+  fn {snake}_invalid() {{
+    assert_lint_err! {{
+      {pascal},
+      MESSAGE,
+      HINT,
+      r#"
+// put a TypeScript/JavaScript snippet that is expected to trigger this lint
+      "#: [
+        {{
+          line: 1,
+          col: 0,
+        }},
+      ],
+    }};
+  }}
+}}
+"##
+  )
+}
+
+/// Returns the year of the copyright header of the file, e.g. 2024 for
+/// `// Copyright 2018-2024 the Deno authors.`
+fn copyright_year(source: &str) -> Option<u64> {
+  let header = source.lines().next()?.strip_prefix("// Copyright ")?;
+  let years = header.split(' ').next()?;
+  years.rsplit('-').next()?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const RULES_RS: &str = r##"// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+pub mod ban_types;
+pub mod no_var;
+#[deprecated(
+  note = "`single-var-declarator` was replaced by `one-var`"
+)]
+pub mod single_var_declarator {
+  pub use super::one_var::OneVar as SingleVarDeclarator;
+}
+pub mod sort_keys;
+
+fn get_all_rules_raw() -> Vec<Box<dyn LintRule>> {
+  vec![
+    Box::new(ban_types::BanTypes),
+    Box::new(
+      no_implicit_declare_namespace_export::NoImplicitDeclareNamespaceExport,
+    ),
+    Box::new(no_var::NoVar),
+  ]
+}
+"##;
+
+  #[test]
+  fn rule_names() {
+    let names = RuleNames::new("no-foo-bar").unwrap();
+    assert_eq!(names.kebab, "no-foo-bar");
+    assert_eq!(names.snake, "no_foo_bar");
+    assert_eq!(names.pascal, "NoFooBar");
+
+    assert!(RuleNames::new("").is_err());
+    assert!(RuleNames::new("no_foo").is_err());
+    assert!(RuleNames::new("NoFoo").is_err());
+    assert!(RuleNames::new("-no-foo").is_err());
+    assert!(RuleNames::new("no--foo").is_err());
+  }
+
+  #[test]
+  fn register() {
+    let names = RuleNames::new("no-this").unwrap();
+    assert_eq!(
+      register_rule(RULES_RS, &names).unwrap(),
+      r##"// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+pub mod ban_types;
+pub mod no_this;
+pub mod no_var;
+#[deprecated(
+  note = "`single-var-declarator` was replaced by `one-var`"
+)]
+pub mod single_var_declarator {
+  pub use super::one_var::OneVar as SingleVarDeclarator;
+}
+pub mod sort_keys;
+
+fn get_all_rules_raw() -> Vec<Box<dyn LintRule>> {
+  vec![
+    Box::new(ban_types::BanTypes),
+    Box::new(
+      no_implicit_declare_namespace_export::NoImplicitDeclareNamespaceExport,
+    ),
+    Box::new(no_this::NoThis),
+    Box::new(no_var::NoVar),
+  ]
+}
+"##
+    );
+
+    let names = RuleNames::new("no-a").unwrap();
+    let registered = register_rule(RULES_RS, &names).unwrap();
+    assert!(registered.contains(
+      "    Box::new(ban_types::BanTypes),\n    Box::new(no_a::NoA),\n    Box::new(\n"
+    ));
+
+    let names = RuleNames::new("prefer-foo").unwrap();
+    let registered = register_rule(RULES_RS, &names).unwrap();
+    assert!(registered
+      .contains("pub mod no_var;\npub mod prefer_foo;\n#[deprecated(\n"));
+
+    let names = RuleNames::new("single-var").unwrap();
+    let registered = register_rule(RULES_RS, &names).unwrap();
+    assert!(registered
+      .contains("pub mod no_var;\npub mod single_var;\n#[deprecated(\n"));
+
+    let names = RuleNames::new("zzz").unwrap();
+    let registered = register_rule(RULES_RS, &names).unwrap();
+    assert!(registered.contains("pub mod sort_keys;\npub mod zzz;\n\n"));
+    assert!(registered
+      .contains("    Box::new(no_var::NoVar),\n    Box::new(zzz::Zzz),\n  ]"));
+  }
+
+  #[test]
+  fn schema() {
+    let schema = r#"{
+  "enum": [
+    "ban-types",
+    "no-var"
+  ]
+}
+"#;
+    assert_eq!(
+      add_to_schema(schema, "no-this").unwrap(),
+      r#"{
+  "enum": [
+    "ban-types",
+    "no-this",
+    "no-var"
+  ]
+}
+"#
+    );
+  }
+
+  #[test]
+  fn year() {
+    assert_eq!(copyright_year(RULES_RS), Some(2024));
+    assert_eq!(
+      copyright_year("// Copyright 2024 the Deno authors.\n"),
+      Some(2024)
+    );
+    assert_eq!(copyright_year("use super::Context;\n"), None);
+  }
+}
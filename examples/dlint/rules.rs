@@ -1,7 +1,15 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
+use ansi_term::Colour;
+use ansi_term::Style;
+use anyhow::bail;
+use anyhow::Error as AnyError;
 use deno_lint::rules::get_all_rules;
+use deno_lint::rules::LintRule;
 use deno_lint::tags;
+use once_cell::sync::Lazy;
+use regex::Captures;
+use regex::Regex;
 use serde::Serialize;
 
 #[derive(Clone, Serialize)]
@@ -9,6 +17,12 @@ pub struct Rule {
   code: &'static str,
   docs: String,
   tags: Vec<&'static str>,
+  /// JSON schema of the options of the rule, e.g. for editor autocomplete.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  options_schema: Option<serde_json::Value>,
+  /// Codes of the rules whose diagnostics this rule reports as well.
+  #[serde(skip_serializing_if = "<[_]>::is_empty")]
+  supersedes: &'static [&'static str],
 }
 
 pub fn get_all_rules_metadata() -> Vec<Rule> {
@@ -18,6 +32,8 @@ pub fn get_all_rules_metadata() -> Vec<Rule> {
       code: rule.code(),
       docs: format!("https://docs.deno.com/lint/rules/{}", rule.code()),
       tags: rule.tags().iter().map(|tag| tag.display()).collect(),
+      options_schema: rule.options_schema(),
+      supersedes: rule.supersedes(),
     })
     .collect()
 }
@@ -87,3 +103,128 @@ impl RuleFormatter for PrettyFormatter {
     }
   }
 }
+
+/// Prints the documentation of a rule, as provided by its metadata.
+pub fn explain_rule(code: &str) -> Result<(), AnyError> {
+  let Some(rule) = get_all_rules().into_iter().find(|r| r.code() == code)
+  else {
+    bail!("Rule not found: \"{}\"", code);
+  };
+
+  #[cfg(windows)]
+  ansi_term::enable_ansi_support().expect("Failed to enable ANSI support");
+
+  let use_colors = atty::is(atty::Stream::Stdout);
+  println!("{}", format_explanation(rule.as_ref(), use_colors));
+  Ok(())
+}
+
+fn format_explanation(rule: &dyn LintRule, use_colors: bool) -> String {
+  let style = |style: Style| if use_colors { style } else { Style::new() };
+  let heading = style(Style::new().bold());
+  let docs = rule.docs();
+  let mut out = Vec::new();
+
+  out.push(heading.paint(rule.code()).to_string());
+  let tags = rule
+    .tags()
+    .iter()
+    .map(|tag| tag.display())
+    .collect::<Vec<_>>();
+  if !tags.is_empty() {
+    out.push(format!("Tags: {}", tags.join(", ")));
+  }
+  out.push(format!(
+    "Fixable: {}",
+    if rule.fixable() { "yes" } else { "no" }
+  ));
+  if !rule.supersedes().is_empty() {
+    out.push(format!("Supersedes: {}", rule.supersedes().join(", ")));
+  }
+
+  if !docs.description.is_empty() {
+    out.push(String::new());
+    out.push(wrap(docs.description, 80));
+  }
+
+  for (title, examples, colour) in [
+    ("Invalid:", docs.invalid_examples, Colour::Red),
+    ("Valid:", docs.valid_examples, Colour::Green),
+  ] {
+    if examples.is_empty() {
+      continue;
+    }
+    out.push(String::new());
+    out.push(style(colour.bold()).paint(title).to_string());
+    for example in examples {
+      out.push(String::new());
+      let example = if use_colors {
+        highlight(example)
+      } else {
+        example.to_string()
+      };
+      out.extend(example.lines().map(|line| format!("  {}", line)));
+    }
+  }
+
+  out.push(String::new());
+  match rule.options_schema() {
+    Some(schema) => {
+      out.push(heading.paint("Options:").to_string());
+      out.push(String::new());
+      let schema = serde_json::to_string_pretty(&schema).unwrap();
+      out.extend(schema.lines().map(|line| format!("  {}", line)));
+      out.push(String::new());
+    }
+    None => out.push("This rule has no options.".to_string()),
+  }
+
+  out.push(format!(
+    "Documentation: https://docs.deno.com/lint/rules/{}",
+    rule.code()
+  ));
+  out.join("\n")
+}
+
+fn wrap(text: &str, width: usize) -> String {
+  let mut lines = Vec::new();
+  let mut line = String::new();
+  for word in text.split_whitespace() {
+    if !line.is_empty() && line.len() + 1 + word.len() > width {
+      lines.push(std::mem::take(&mut line));
+    }
+    if !line.is_empty() {
+      line.push(' ');
+    }
+    line.push_str(word);
+  }
+  if !line.is_empty() {
+    lines.push(line);
+  }
+  lines.join("\n")
+}
+
+/// A tiny highlighter for JavaScript and TypeScript snippets.
+fn highlight(code: &str) -> String {
+  static TOKEN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+      r#"(?m)(?P<comment>//.*$)|(?P<string>"(?:[^"\\]|\\.)*"|'(?:[^'\\]|\\.)*'|`(?:[^`\\]|\\.)*`)|\b(?P<keyword>as|async|await|class|const|else|export|extends|for|from|function|if|import|interface|let|new|return|type|typeof|var|while)\b|\b(?P<number>\d+(?:\.\d+)?)\b"#,
+    )
+    .unwrap()
+  });
+
+  TOKEN
+    .replace_all(code, |caps: &Captures| {
+      let (colour, m) = if let Some(m) = caps.name("comment") {
+        (Colour::Fixed(8), m)
+      } else if let Some(m) = caps.name("string") {
+        (Colour::Green, m)
+      } else if let Some(m) = caps.name("keyword") {
+        (Colour::Cyan, m)
+      } else {
+        (Colour::Yellow, caps.name("number").unwrap())
+      };
+      colour.paint(m.as_str()).to_string()
+    })
+    .into_owned()
+}
@@ -0,0 +1,37 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Lints arbitrary sources with all the rules, to find the rules that panic.
+//!
+//! Run with `cargo +nightly fuzz run lint` from the root of the repository.
+
+#![no_main]
+
+use deno_ast::{MediaType, ModuleSpecifier};
+use deno_lint::linter::{LintConfig, LintFileOptions, Linter, LinterOptions};
+use deno_lint::rules::get_all_rules;
+use libfuzzer_sys::fuzz_target;
+use std::borrow::Cow;
+
+fuzz_target!(|source_code: &str| {
+  let rules = get_all_rules();
+  let all_rule_codes =
+    rules.iter().map(|rule| Cow::from(rule.code())).collect();
+  let linter = Linter::new(LinterOptions {
+    rules,
+    all_rule_codes,
+    ..Default::default()
+  });
+
+  // Sources that fail to parse are fine, only panics are reported.
+  let _ = linter.lint_file(LintFileOptions {
+    specifier: ModuleSpecifier::parse("file:///fuzz.tsx").unwrap(),
+    source_code: source_code.to_string(),
+    media_type: MediaType::Tsx,
+    config: LintConfig {
+      default_jsx_factory: Some("React.createElement".to_string()),
+      default_jsx_fragment_factory: Some("React.Fragment".to_string()),
+      ..Default::default()
+    },
+    external_linter: None,
+  });
+});
@@ -0,0 +1,465 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use crate::swc_util::StringRepr;
+use deno_ast::swc::ast::*;
+use deno_ast::swc::visit::{noop_visit_type, Visit, VisitWith};
+use deno_ast::view;
+use deno_ast::{SourceRange, SourceRangedForSpanned};
+use std::mem::{replace, take};
+
+/// Code metrics of a program, computed in a single traversal and shared by
+/// the `complexity`, `max-depth`, `max-nested-callbacks` and
+/// `max-lines-per-function` rules.
+#[derive(Debug, Default)]
+pub struct CodeMetrics {
+  functions: Vec<FunctionMetrics>,
+  blocks: Vec<Nesting>,
+  callbacks: Vec<Nesting>,
+}
+
+#[derive(Debug)]
+pub struct FunctionMetrics {
+  /// Description of the function for diagnostics, e.g. "function `foo`".
+  pub name: String,
+  /// Range of the whole function, including its body.
+  pub range: SourceRange,
+  /// Range of the function up to its body, where diagnostics are reported.
+  pub head_range: SourceRange,
+  /// Cyclomatic complexity, not including nested functions.
+  pub complexity: usize,
+  /// Whether the function is immediately invoked.
+  pub is_iife: bool,
+}
+
+#[derive(Debug)]
+pub struct Nesting {
+  pub range: SourceRange,
+  /// How deep the node is nested, starting at 1.
+  pub depth: usize,
+}
+
+impl CodeMetrics {
+  pub fn analyze(program: view::Program) -> Self {
+    let mut v = Analyzer::default();
+    match program {
+      view::Program::Module(module) => module.inner.visit_with(&mut v),
+      view::Program::Script(script) => script.inner.visit_with(&mut v),
+    }
+    v.metrics
+  }
+
+  /// Functions, methods, constructors and class static blocks, in source
+  /// order.
+  pub fn functions(&self) -> &[FunctionMetrics] {
+    &self.functions
+  }
+
+  /// Statements nesting a block, like `if` statements and loops, with their
+  /// depth within the enclosing function. `else if` doesn't add a level.
+  pub fn blocks(&self) -> &[Nesting] {
+    &self.blocks
+  }
+
+  /// Functions passed as arguments of calls, with how many callbacks they
+  /// are nested in, including themselves.
+  pub fn callbacks(&self) -> &[Nesting] {
+    &self.callbacks
+  }
+}
+
+/// Returns the JSON schema of the `max` option shared by the code metrics
+/// rules.
+pub fn max_option_schema(description: &str) -> serde_json::Value {
+  serde_json::json!({
+    "description": description,
+    "type": "integer",
+    "minimum": 0
+  })
+}
+
+struct Frame {
+  complexity: usize,
+  depth: usize,
+}
+
+impl Default for Frame {
+  fn default() -> Self {
+    Self {
+      complexity: 1,
+      depth: 0,
+    }
+  }
+}
+
+/// What is known about the next visited function from its parent.
+#[derive(Default)]
+struct Pending {
+  /// Name of the variable or property the function is assigned to.
+  name: Option<String>,
+  is_iife: bool,
+  is_callback: bool,
+}
+
+#[derive(Default)]
+struct Analyzer {
+  metrics: CodeMetrics,
+  /// The innermost function, or the top level of the program.
+  frame: Frame,
+  callback_depth: usize,
+  pending: Pending,
+}
+
+impl Analyzer {
+  fn function(
+    &mut self,
+    name: String,
+    range: SourceRange,
+    body: Option<SourceRange>,
+    visit: impl FnOnce(&mut Self),
+  ) {
+    let pending = take(&mut self.pending);
+    let head_range = match body {
+      Some(body) => SourceRange::new(range.start, body.start),
+      None => range,
+    };
+    let index = self.metrics.functions.len();
+    self.metrics.functions.push(FunctionMetrics {
+      name,
+      range,
+      head_range,
+      complexity: 1,
+      is_iife: pending.is_iife,
+    });
+    if pending.is_callback {
+      self.callback_depth += 1;
+      self.metrics.callbacks.push(Nesting {
+        range: head_range,
+        depth: self.callback_depth,
+      });
+    }
+
+    let outer = take(&mut self.frame);
+    visit(self);
+    let frame = replace(&mut self.frame, outer);
+
+    self.metrics.functions[index].complexity = frame.complexity;
+    if pending.is_callback {
+      self.callback_depth -= 1;
+    }
+  }
+
+  fn nested_block(
+    &mut self,
+    range: SourceRange,
+    visit: impl FnOnce(&mut Self),
+  ) {
+    self.frame.depth += 1;
+    self.metrics.blocks.push(Nesting {
+      range,
+      depth: self.frame.depth,
+    });
+    visit(self);
+    self.frame.depth -= 1;
+  }
+
+  fn if_stmt(&mut self, if_stmt: &IfStmt) {
+    self.frame.complexity += 1;
+    if_stmt.test.visit_with(self);
+    if_stmt.cons.visit_with(self);
+    match if_stmt.alt.as_deref() {
+      // `else if` is on the same level as the `if` it follows.
+      Some(Stmt::If(else_if)) => self.if_stmt(else_if),
+      Some(alt) => alt.visit_with(self),
+      None => {}
+    }
+  }
+
+  fn method(
+    &mut self,
+    kind: MethodKind,
+    key: Option<String>,
+    range: SourceRange,
+    function: &Function,
+  ) {
+    let kind = match kind {
+      MethodKind::Method => "method",
+      MethodKind::Getter => "getter",
+      MethodKind::Setter => "setter",
+    };
+    let name = match key {
+      Some(key) => format!("{} `{}`", kind, key),
+      None => kind.to_string(),
+    };
+    self.function(
+      name,
+      range,
+      function.body.as_ref().map(|body| body.range()),
+      |v| function.visit_children_with(v),
+    );
+  }
+}
+
+fn is_function(expr: &Expr) -> bool {
+  matches!(expr.unwrap_parens(), Expr::Fn(_) | Expr::Arrow(_))
+}
+
+impl Visit for Analyzer {
+  noop_visit_type!();
+
+  fn visit_fn_decl(&mut self, fn_decl: &FnDecl) {
+    self.function(
+      format!("function `{}`", fn_decl.ident.sym),
+      fn_decl.range(),
+      fn_decl.function.body.as_ref().map(|body| body.range()),
+      |v| fn_decl.function.visit_with(v),
+    );
+  }
+
+  fn visit_fn_expr(&mut self, fn_expr: &FnExpr) {
+    let name = fn_expr
+      .ident
+      .as_ref()
+      .map(|ident| ident.sym.to_string())
+      .or_else(|| self.pending.name.take());
+    let name = match name {
+      Some(name) => format!("function `{}`", name),
+      None => "function".to_string(),
+    };
+    self.function(
+      name,
+      fn_expr.function.range(),
+      fn_expr.function.body.as_ref().map(|body| body.range()),
+      |v| fn_expr.function.visit_with(v),
+    );
+  }
+
+  fn visit_arrow_expr(&mut self, arrow: &ArrowExpr) {
+    let name = match self.pending.name.take() {
+      Some(name) => format!("arrow function `{}`", name),
+      None => "arrow function".to_string(),
+    };
+    self.function(name, arrow.range(), Some(arrow.body.range()), |v| {
+      arrow.visit_children_with(v)
+    });
+  }
+
+  fn visit_class_method(&mut self, method: &ClassMethod) {
+    method.key.visit_with(self);
+    self.method(
+      method.kind,
+      method.key.string_repr(),
+      method.range(),
+      &method.function,
+    );
+  }
+
+  fn visit_private_method(&mut self, method: &PrivateMethod) {
+    self.method(
+      method.kind,
+      Some(format!("#{}", method.key.name)),
+      method.range(),
+      &method.function,
+    );
+  }
+
+  fn visit_method_prop(&mut self, method: &MethodProp) {
+    method.key.visit_with(self);
+    self.method(
+      MethodKind::Method,
+      method.key.string_repr(),
+      SourceRange::new(method.key.start(), method.function.end()),
+      &method.function,
+    );
+  }
+
+  fn visit_getter_prop(&mut self, getter: &GetterProp) {
+    getter.key.visit_with(self);
+    let name = match getter.key.string_repr() {
+      Some(key) => format!("getter `{}`", key),
+      None => "getter".to_string(),
+    };
+    self.function(
+      name,
+      getter.range(),
+      getter.body.as_ref().map(|body| body.range()),
+      |v| getter.body.visit_with(v),
+    );
+  }
+
+  fn visit_setter_prop(&mut self, setter: &SetterProp) {
+    setter.key.visit_with(self);
+    let name = match setter.key.string_repr() {
+      Some(key) => format!("setter `{}`", key),
+      None => "setter".to_string(),
+    };
+    self.function(
+      name,
+      setter.range(),
+      setter.body.as_ref().map(|body| body.range()),
+      |v| {
+        setter.param.visit_with(v);
+        setter.body.visit_with(v);
+      },
+    );
+  }
+
+  fn visit_constructor(&mut self, constructor: &Constructor) {
+    self.function(
+      "constructor".to_string(),
+      constructor.range(),
+      constructor.body.as_ref().map(|body| body.range()),
+      |v| {
+        constructor.params.visit_with(v);
+        constructor.body.visit_with(v);
+      },
+    );
+  }
+
+  fn visit_static_block(&mut self, static_block: &StaticBlock) {
+    self.function(
+      "class static block".to_string(),
+      static_block.range(),
+      Some(static_block.body.range()),
+      |v| static_block.body.visit_children_with(v),
+    );
+  }
+
+  fn visit_var_declarator(&mut self, declarator: &VarDeclarator) {
+    declarator.name.visit_with(self);
+    if let Some(init) = &declarator.init {
+      if let (Pat::Ident(ident), true) = (&declarator.name, is_function(init)) {
+        self.pending.name = Some(ident.id.sym.to_string());
+      }
+      init.visit_with(self);
+    }
+  }
+
+  fn visit_key_value_prop(&mut self, prop: &KeyValueProp) {
+    prop.key.visit_with(self);
+    if is_function(&prop.value) {
+      self.pending.name = prop.key.string_repr();
+    }
+    prop.value.visit_with(self);
+  }
+
+  fn visit_class_prop(&mut self, prop: &ClassProp) {
+    prop.key.visit_with(self);
+    if let Some(value) = &prop.value {
+      if is_function(value) {
+        self.pending.name = prop.key.string_repr();
+      }
+      value.visit_with(self);
+    }
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+    if let Callee::Expr(callee) = &call_expr.callee {
+      self.pending.is_iife = is_function(callee);
+    }
+    call_expr.callee.visit_with(self);
+    for arg in &call_expr.args {
+      self.pending.is_callback = arg.spread.is_none() && is_function(&arg.expr);
+      arg.visit_with(self);
+    }
+  }
+
+  fn visit_if_stmt(&mut self, if_stmt: &IfStmt) {
+    self.nested_block(if_stmt.range(), |v| v.if_stmt(if_stmt));
+  }
+
+  fn visit_switch_stmt(&mut self, switch_stmt: &SwitchStmt) {
+    self.nested_block(switch_stmt.range(), |v| {
+      switch_stmt.visit_children_with(v)
+    });
+  }
+
+  fn visit_switch_case(&mut self, case: &SwitchCase) {
+    if case.test.is_some() {
+      self.frame.complexity += 1;
+    }
+    case.visit_children_with(self);
+  }
+
+  fn visit_try_stmt(&mut self, try_stmt: &TryStmt) {
+    self.nested_block(try_stmt.range(), |v| try_stmt.visit_children_with(v));
+  }
+
+  fn visit_catch_clause(&mut self, catch_clause: &CatchClause) {
+    self.frame.complexity += 1;
+    catch_clause.visit_children_with(self);
+  }
+
+  fn visit_with_stmt(&mut self, with_stmt: &WithStmt) {
+    self.nested_block(with_stmt.range(), |v| with_stmt.visit_children_with(v));
+  }
+
+  fn visit_for_stmt(&mut self, for_stmt: &ForStmt) {
+    self.frame.complexity += 1;
+    self.nested_block(for_stmt.range(), |v| for_stmt.visit_children_with(v));
+  }
+
+  fn visit_for_in_stmt(&mut self, for_in: &ForInStmt) {
+    self.frame.complexity += 1;
+    self.nested_block(for_in.range(), |v| for_in.visit_children_with(v));
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of: &ForOfStmt) {
+    self.frame.complexity += 1;
+    self.nested_block(for_of.range(), |v| for_of.visit_children_with(v));
+  }
+
+  fn visit_while_stmt(&mut self, while_stmt: &WhileStmt) {
+    self.frame.complexity += 1;
+    self
+      .nested_block(while_stmt.range(), |v| while_stmt.visit_children_with(v));
+  }
+
+  fn visit_do_while_stmt(&mut self, do_while: &DoWhileStmt) {
+    self.frame.complexity += 1;
+    self.nested_block(do_while.range(), |v| do_while.visit_children_with(v));
+  }
+
+  fn visit_cond_expr(&mut self, cond_expr: &CondExpr) {
+    self.frame.complexity += 1;
+    cond_expr.visit_children_with(self);
+  }
+
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr) {
+    if matches!(
+      bin_expr.op,
+      BinaryOp::LogicalAnd | BinaryOp::LogicalOr | BinaryOp::NullishCoalescing
+    ) {
+      self.frame.complexity += 1;
+    }
+    bin_expr.visit_children_with(self);
+  }
+
+  fn visit_assign_expr(&mut self, assign_expr: &AssignExpr) {
+    if matches!(
+      assign_expr.op,
+      AssignOp::AndAssign | AssignOp::OrAssign | AssignOp::NullishAssign
+    ) {
+      self.frame.complexity += 1;
+    }
+    assign_expr.visit_children_with(self);
+  }
+
+  fn visit_opt_chain_expr(&mut self, opt_chain: &OptChainExpr) {
+    if opt_chain.optional {
+      self.frame.complexity += 1;
+    }
+    opt_chain.visit_children_with(self);
+  }
+
+  fn visit_assign_pat(&mut self, assign_pat: &AssignPat) {
+    self.frame.complexity += 1;
+    assign_pat.visit_children_with(self);
+  }
+
+  fn visit_assign_pat_prop(&mut self, assign_pat_prop: &AssignPatProp) {
+    if assign_pat_prop.value.is_some() {
+      self.frame.complexity += 1;
+    }
+    assign_pat_prop.visit_children_with(self);
+  }
+}
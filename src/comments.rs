@@ -0,0 +1,121 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use deno_ast::swc::common::comments::Comment;
+use deno_ast::view as ast_view;
+use deno_ast::{RootNode, SourceRange, SourceRangedForSpanned};
+
+/// Lookups of the comments of a program, shared by rules that check comments
+/// or need to know whether a fix would drop any.
+#[derive(Clone, Copy)]
+pub struct Comments<'a> {
+  program: ast_view::Program<'a>,
+}
+
+impl<'a> Comments<'a> {
+  pub(crate) fn new(program: ast_view::Program<'a>) -> Self {
+    Self { program }
+  }
+
+  /// All comments of the file, in source order.
+  pub fn all_comments(&self) -> impl Iterator<Item = &'a Comment> {
+    // The container keeps leading and trailing comments apart.
+    let mut comments = self
+      .program
+      .comment_container()
+      .all_comments()
+      .collect::<Vec<_>>();
+    comments.sort_by_key(|comment| comment.start());
+    comments.into_iter()
+  }
+
+  /// Returns the comments between the previous token and the start of
+  /// `range`.
+  pub fn leading_comments(
+    &self,
+    range: SourceRange,
+  ) -> impl Iterator<Item = &'a Comment> {
+    self
+      .program
+      .comment_container()
+      .leading_comments(range.start)
+  }
+
+  /// Returns the comments between the end of `range` and the next token.
+  pub fn trailing_comments(
+    &self,
+    range: SourceRange,
+  ) -> impl Iterator<Item = &'a Comment> {
+    self
+      .program
+      .comment_container()
+      .trailing_comments(range.end)
+  }
+
+  /// Returns the comments that are entirely inside of `range`.
+  pub fn comments_in_range(
+    &self,
+    range: SourceRange,
+  ) -> impl Iterator<Item = &'a Comment> {
+    self
+      .all_comments()
+      .filter(move |comment| range.contains(&comment.range()))
+  }
+
+  /// Checks if there are any comments inside of `range`.
+  pub fn has_comments_in_range(&self, range: SourceRange) -> bool {
+    self.comments_in_range(range).next().is_some()
+  }
+
+  /// Checks if any comment inside of `range` satisfies `predicate`, e.g. one
+  /// that isn't inside of a part of the range that a fix keeps.
+  pub fn has_comment_matching(
+    &self,
+    range: SourceRange,
+    predicate: impl FnMut(&Comment) -> bool,
+  ) -> bool {
+    self.comments_in_range(range).any(predicate)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util;
+  use deno_ast::SourceRanged;
+
+  #[test]
+  fn comments() {
+    test_util::parse_and_then(
+      "// a\nfoo(/* b */ 1); // c\n/* d */ bar();",
+      |program| {
+        let ast_view::Program::Script(script) = program else {
+          unreachable!();
+        };
+        let foo = script.body[0].range();
+        let bar = script.body[1].range();
+        let comments = Comments::new(program);
+        let texts = |comments: Vec<&Comment>| {
+          comments
+            .iter()
+            .map(|comment| comment.text.to_string())
+            .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+          texts(comments.all_comments().collect()),
+          [" a", " b ", " c", " d "]
+        );
+        assert_eq!(texts(comments.leading_comments(foo).collect()), [" a"]);
+        assert_eq!(
+          texts(comments.trailing_comments(foo).collect()),
+          [" c", " d "]
+        );
+        assert_eq!(texts(comments.comments_in_range(foo).collect()), [" b "]);
+        assert!(comments.has_comments_in_range(foo));
+        assert!(!comments.has_comments_in_range(bar));
+        assert!(comments.has_comment_matching(foo, |c| c.text.trim() == "b"));
+        assert!(!comments.has_comment_matching(foo, |c| c.text.trim() == "d"));
+      },
+    );
+  }
+}
@@ -0,0 +1,684 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Parsing of lint configuration files, shared by tools built on top of the
+//! library.
+//!
+//! Besides plain JSON, JSONC is accepted, i.e. JSON with comments and
+//! trailing commas like `deno.json`. Errors point to the line and column of
+//! the problem in the original text.
+//!
+//! The `lint` section of a `deno.json` file can be read with
+//! [`parse_deno_lint_config`], so tools behave like `deno lint` for a
+//! project.
+//!
+//! Rules can be changed for some of the files with [`ConfigOverride`]s, like
+//! the `overrides` of an ESLint config.
+
+use crate::glob;
+use crate::rules::{filtered_rules, LintRule};
+use deno_ast::ModuleSpecifier;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde::Deserializer;
+use std::path::{Path, PathBuf};
+
+/// Names of the Deno configuration files, in the order they are looked up.
+pub const DENO_CONFIG_FILE_NAMES: &[&str] = &["deno.json", "deno.jsonc"];
+
+/// The format of a configuration file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+  Json,
+  /// JSON with comments and trailing commas.
+  Jsonc,
+}
+
+impl ConfigFormat {
+  /// Returns the format of the file based on its extension.
+  pub fn from_path(path: &Path) -> Option<Self> {
+    match path.extension()?.to_str()? {
+      "json" => Some(ConfigFormat::Json),
+      "jsonc" => Some(ConfigFormat::Jsonc),
+      _ => None,
+    }
+  }
+}
+
+/// A problem with a configuration file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigError {
+  pub message: String,
+  /// 1-based line of the problem.
+  pub line: usize,
+  /// 1-based column of the problem.
+  pub column: usize,
+}
+
+impl ConfigError {
+  /// Creates an error for the value the JSON pointer points to, e.g.
+  /// `/rules/options/camelcase/properties`. If the value doesn't exist, the
+  /// error points to the closest parent that does.
+  pub fn at_pointer(text: &str, pointer: &str, message: String) -> Self {
+    let text = strip_jsonc(text);
+    let mut segments = pointer
+      .split('/')
+      .skip(1)
+      .map(|segment| segment.replace("~1", "/").replace("~0", "~"))
+      .collect::<Vec<_>>();
+    let offset = loop {
+      if let Some(offset) = JsonScanner::new(&text).find(&segments) {
+        break offset;
+      }
+      if segments.pop().is_none() {
+        break 0;
+      }
+    };
+    let (line, column) = line_and_column(&text, offset);
+    ConfigError {
+      message,
+      line,
+      column,
+    }
+  }
+
+  fn from_json_error(err: serde_json::Error) -> Self {
+    let suffix = format!(" at line {} column {}", err.line(), err.column());
+    let message = err.to_string();
+    ConfigError {
+      message: message
+        .strip_suffix(&suffix)
+        .unwrap_or(&message)
+        .to_string(),
+      line: err.line(),
+      column: err.column(),
+    }
+  }
+}
+
+impl std::fmt::Display for ConfigError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{} at line {} column {}",
+      self.message, self.line, self.column
+    )
+  }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Parses the text of a configuration file in the given format.
+pub fn parse_config<T: DeserializeOwned>(
+  text: &str,
+  format: ConfigFormat,
+) -> Result<T, ConfigError> {
+  let result = match format {
+    ConfigFormat::Json => serde_json::from_str(text),
+    ConfigFormat::Jsonc => serde_json::from_str(&strip_jsonc(text)),
+  };
+  result.map_err(ConfigError::from_json_error)
+}
+
+/// The `lint` section of a `deno.json` or `deno.jsonc` file.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct DenoLintConfig {
+  #[serde(deserialize_with = "deserialize_rules_config")]
+  pub rules: DenoLintRulesConfig,
+  /// Globs of the files to lint, relative to the directory of the config
+  /// file. All files are linted if it is `None`.
+  pub include: Option<Vec<String>>,
+  /// Globs of the files not to lint, including the ones of the top level
+  /// `exclude` of the config file.
+  pub exclude: Vec<String>,
+  /// The output format of the diagnostics, e.g. `pretty`, `compact` or
+  /// `json`.
+  pub report: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct DenoLintRulesConfig {
+  /// The tags of the rule sets to use, `["recommended"]` if it is `None`.
+  pub tags: Option<Vec<String>>,
+  pub include: Option<Vec<String>>,
+  pub exclude: Option<Vec<String>>,
+}
+
+/// Deserializes the `rules` of the `lint` section, which have to be an
+/// object. Structs deserialized by serde accept arrays too, so `[]` would be
+/// silently treated like the default.
+fn deserialize_rules_config<'de, D: Deserializer<'de>>(
+  deserializer: D,
+) -> Result<DenoLintRulesConfig, D::Error> {
+  let map = serde_json::Map::deserialize(deserializer)?;
+  DenoLintRulesConfig::deserialize(serde_json::Value::Object(map))
+    .map_err(serde::de::Error::custom)
+}
+
+impl DenoLintConfig {
+  /// Returns the rules that `deno lint` runs with this configuration.
+  pub fn rules(
+    &self,
+    all_rules: Vec<Box<dyn LintRule>>,
+  ) -> Vec<Box<dyn LintRule>> {
+    let tags = self
+      .rules
+      .tags
+      .clone()
+      .unwrap_or_else(|| vec!["recommended".to_string()]);
+    filtered_rules(
+      all_rules,
+      Some(tags),
+      self.rules.exclude.clone(),
+      self.rules.include.clone(),
+    )
+  }
+}
+
+/// The rules to run, selected by tags and rule codes.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RuleSelection {
+  pub tags: Vec<String>,
+  pub include: Vec<String>,
+  pub exclude: Vec<String>,
+}
+
+impl RuleSelection {
+  /// Returns the selection for the file after applying the overrides that
+  /// match it, in order. The tags of an override are added, and its
+  /// `include` and `exclude` take precedence over the ones before it, so an
+  /// override can turn a rule off or back on.
+  pub fn with_overrides(
+    &self,
+    overrides: &[ConfigOverride],
+    specifier: &ModuleSpecifier,
+  ) -> Self {
+    let mut selection = self.clone();
+    for config_override in overrides {
+      if !config_override.matches(specifier) {
+        continue;
+      }
+      let rules = &config_override.rules;
+      for tag in &rules.tags {
+        if !selection.tags.contains(tag) {
+          selection.tags.push(tag.clone());
+        }
+      }
+      for code in &rules.include {
+        selection.exclude.retain(|excluded| excluded != code);
+        selection.include.push(code.clone());
+      }
+      for code in &rules.exclude {
+        selection.include.retain(|included| included != code);
+        selection.exclude.push(code.clone());
+      }
+    }
+    selection
+  }
+
+  /// Returns the selected rules.
+  pub fn rules(
+    &self,
+    all_rules: Vec<Box<dyn LintRule>>,
+  ) -> Vec<Box<dyn LintRule>> {
+    filtered_rules(
+      all_rules,
+      Some(self.tags.clone()),
+      Some(self.exclude.clone()),
+      Some(self.include.clone()),
+    )
+  }
+}
+
+/// Changes of the rules for the files that match globs, e.g. turning off
+/// `no-explicit-any` under `tests/**`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct ConfigOverride {
+  /// Globs of the files the override applies to. Globs that end with `/`
+  /// match all files of the directory.
+  pub files: Vec<String>,
+  /// Globs of the files the override doesn't apply to, even if they match
+  /// `files`.
+  pub exclude_files: Vec<String>,
+  pub rules: RuleSelection,
+}
+
+impl ConfigOverride {
+  /// Checks if the override applies to the file.
+  pub fn matches(&self, specifier: &ModuleSpecifier) -> bool {
+    let matches_any = |patterns: &[String]| {
+      patterns.iter().any(|pattern| {
+        if pattern.ends_with('/') {
+          glob::matches_specifier(&format!("{}**", pattern), specifier)
+        } else {
+          glob::matches_specifier(pattern, specifier)
+        }
+      })
+    };
+    matches_any(&self.files) && !matches_any(&self.exclude_files)
+  }
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct DenoConfig {
+  lint: DenoLintConfig,
+  exclude: Vec<String>,
+}
+
+/// Parses the `lint` section of the text of a `deno.json` or `deno.jsonc`
+/// file. Like Deno, comments and trailing commas are accepted in both.
+pub fn parse_deno_lint_config(
+  text: &str,
+) -> Result<DenoLintConfig, ConfigError> {
+  let config: DenoConfig = parse_config(text, ConfigFormat::Jsonc)?;
+  let mut lint = config.lint;
+  lint.exclude.extend(config.exclude);
+  Ok(lint)
+}
+
+/// Looks for a Deno configuration file in the directory and its ancestors,
+/// like `deno lint` does.
+pub fn find_deno_config(dir: &Path) -> Option<PathBuf> {
+  dir.ancestors().find_map(|dir| {
+    DENO_CONFIG_FILE_NAMES
+      .iter()
+      .map(|name| dir.join(name))
+      .find(|path| path.is_file())
+  })
+}
+
+/// Turns JSONC into JSON by replacing comments and trailing commas with
+/// whitespace. The byte offsets and lines of the text are kept as they are,
+/// so errors can point into the original text.
+pub fn strip_jsonc(text: &str) -> String {
+  let bytes = text.as_bytes();
+  let mut out = bytes.to_vec();
+  let mut pending_comma = None;
+  let mut i = 0;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'"' => {
+        pending_comma = None;
+        i += 1;
+        while i < bytes.len() && bytes[i] != b'"' {
+          i += if bytes[i] == b'\\' { 2 } else { 1 };
+        }
+        i += 1;
+        continue;
+      }
+      b'/' if bytes.get(i + 1) == Some(&b'/') => {
+        while i < bytes.len() && bytes[i] != b'\n' {
+          out[i] = b' ';
+          i += 1;
+        }
+        continue;
+      }
+      b'/' if bytes.get(i + 1) == Some(&b'*') => {
+        let end = text[i + 2..]
+          .find("*/")
+          .map_or(bytes.len(), |end| i + 2 + end + 2);
+        for byte in &mut out[i..end] {
+          if !matches!(*byte, b'\n' | b'\r') {
+            *byte = b' ';
+          }
+        }
+        i = end;
+        continue;
+      }
+      b',' => pending_comma = Some(i),
+      b'}' | b']' => {
+        if let Some(comma) = pending_comma.take() {
+          out[comma] = b' ';
+        }
+      }
+      byte if byte.is_ascii_whitespace() => {}
+      _ => pending_comma = None,
+    }
+    i += 1;
+  }
+  // Only ASCII bytes outside of strings were replaced with spaces, and
+  // comments were replaced as a whole.
+  String::from_utf8(out).unwrap()
+}
+
+fn line_and_column(text: &str, offset: usize) -> (usize, usize) {
+  let before = &text[..offset];
+  let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+  (
+    before.matches('\n').count() + 1,
+    before[line_start..].chars().count() + 1,
+  )
+}
+
+/// Finds the offsets of values in JSON text without deserializing it.
+struct JsonScanner<'a> {
+  text: &'a str,
+  pos: usize,
+}
+
+impl<'a> JsonScanner<'a> {
+  fn new(text: &'a str) -> Self {
+    Self { text, pos: 0 }
+  }
+
+  fn peek(&self) -> Option<u8> {
+    self.text.as_bytes().get(self.pos).copied()
+  }
+
+  fn skip_whitespace(&mut self) {
+    while self.peek().is_some_and(|byte| byte.is_ascii_whitespace()) {
+      self.pos += 1;
+    }
+  }
+
+  fn eat(&mut self, byte: u8) -> Option<()> {
+    self.skip_whitespace();
+    (self.peek()? == byte).then(|| self.pos += 1)
+  }
+
+  /// Returns the offset of the value at the path, starting from the value at
+  /// the current position.
+  fn find(&mut self, path: &[String]) -> Option<usize> {
+    self.skip_whitespace();
+    let Some((segment, rest)) = path.split_first() else {
+      return Some(self.pos);
+    };
+    match self.peek()? {
+      b'{' => {
+        self.pos += 1;
+        loop {
+          self.skip_whitespace();
+          let key = self.string()?;
+          self.eat(b':')?;
+          if key == *segment {
+            return self.find(rest);
+          }
+          self.skip_value()?;
+          self.eat(b',')?;
+        }
+      }
+      b'[' => {
+        let index = segment.parse::<usize>().ok()?;
+        self.pos += 1;
+        for _ in 0..index {
+          self.skip_value()?;
+          self.eat(b',')?;
+        }
+        self.find(rest)
+      }
+      _ => None,
+    }
+  }
+
+  fn string(&mut self) -> Option<String> {
+    let start = self.pos;
+    self.skip_string()?;
+    serde_json::from_str(&self.text[start..self.pos]).ok()
+  }
+
+  fn skip_string(&mut self) -> Option<()> {
+    self.eat(b'"')?;
+    loop {
+      match self.peek()? {
+        b'"' => break,
+        b'\\' => self.pos += 2,
+        _ => self.pos += 1,
+      }
+    }
+    self.pos += 1;
+    Some(())
+  }
+
+  fn skip_value(&mut self) -> Option<()> {
+    self.skip_whitespace();
+    match self.peek()? {
+      b'"' => self.skip_string(),
+      b'{' | b'[' => {
+        let mut depth = 0;
+        loop {
+          match self.peek()? {
+            b'"' => {
+              self.skip_string()?;
+              continue;
+            }
+            b'{' | b'[' => depth += 1,
+            b'}' | b']' => depth -= 1,
+            _ => {}
+          }
+          self.pos += 1;
+          if depth == 0 {
+            return Some(());
+          }
+        }
+      }
+      _ => {
+        while self.peek().is_some_and(|byte| {
+          !byte.is_ascii_whitespace() && !matches!(byte, b',' | b'}' | b']')
+        }) {
+          self.pos += 1;
+        }
+        Some(())
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde::Deserialize;
+
+  #[derive(Debug, Deserialize, PartialEq)]
+  #[serde(deny_unknown_fields)]
+  struct TestConfig {
+    tags: Vec<String>,
+    #[serde(default)]
+    options: serde_json::Value,
+  }
+
+  #[test]
+  fn format_from_path() {
+    assert_eq!(
+      ConfigFormat::from_path(Path::new("dlint.json")),
+      Some(ConfigFormat::Json)
+    );
+    assert_eq!(
+      ConfigFormat::from_path(Path::new("config/dlint.jsonc")),
+      Some(ConfigFormat::Jsonc)
+    );
+    assert_eq!(ConfigFormat::from_path(Path::new("dlint.toml")), None);
+    assert_eq!(ConfigFormat::from_path(Path::new("dlint")), None);
+  }
+
+  #[test]
+  fn strips_jsonc() {
+    assert_eq!(
+      strip_jsonc("{ \"a\": 1, // comment\n}"),
+      "{ \"a\": 1            \n}"
+    );
+    assert_eq!(strip_jsonc("[1, /* a\nb */ 2,]"), "[1,     \n     2 ]");
+    // Comment markers and commas in strings are kept.
+    assert_eq!(
+      strip_jsonc(r#"{ "a": "// not a comment,]", "b\"/*": 1 }"#),
+      r#"{ "a": "// not a comment,]", "b\"/*": 1 }"#
+    );
+  }
+
+  #[test]
+  fn parses_jsonc() {
+    let text = r#"{
+      // The rule sets to use.
+      "tags": ["recommended",],
+      /* Options of individual rules. */
+      "options": { "camelcase": { "properties": "never" }, },
+    }"#;
+    let config = parse_config::<TestConfig>(text, ConfigFormat::Jsonc).unwrap();
+    assert_eq!(config.tags, vec!["recommended"]);
+    assert_eq!(
+      config.options,
+      serde_json::json!({ "camelcase": { "properties": "never" } })
+    );
+
+    // Comments are only allowed in JSONC.
+    assert!(parse_config::<TestConfig>(text, ConfigFormat::Json).is_err());
+  }
+
+  #[test]
+  fn deno_lint_config() {
+    let config = parse_deno_lint_config(
+      r#"{
+        "imports": { "@std/path": "jsr:@std/path@1" },
+        // Shared by all subcommands.
+        "exclude": ["dist/"],
+        "lint": {
+          "include": ["src/"],
+          "exclude": ["src/testdata/"],
+          "rules": {
+            "tags": ["recommended"],
+            "include": ["eqeqeq"],
+            "exclude": ["no-unused-vars"],
+          },
+          "report": "compact",
+        },
+      }"#,
+    )
+    .unwrap();
+    assert_eq!(
+      config,
+      DenoLintConfig {
+        rules: DenoLintRulesConfig {
+          tags: Some(vec!["recommended".to_string()]),
+          include: Some(vec!["eqeqeq".to_string()]),
+          exclude: Some(vec!["no-unused-vars".to_string()]),
+        },
+        include: Some(vec!["src/".to_string()]),
+        exclude: vec!["src/testdata/".to_string(), "dist/".to_string()],
+        report: Some("compact".to_string()),
+      }
+    );
+    let codes = config
+      .rules(crate::rules::get_all_rules())
+      .iter()
+      .map(|rule| rule.code())
+      .collect::<Vec<_>>();
+    assert!(codes.contains(&"eqeqeq"));
+    assert!(codes.contains(&"no-var"));
+    assert!(!codes.contains(&"no-unused-vars"));
+
+    // The recommended rules are used by default.
+    let config = parse_deno_lint_config(r#"{ "tasks": {} }"#).unwrap();
+    assert_eq!(config, DenoLintConfig::default());
+    assert_eq!(
+      config.rules(crate::rules::get_all_rules()).len(),
+      crate::rules::recommended_rules(crate::rules::get_all_rules()).len()
+    );
+
+    let err =
+      parse_deno_lint_config(r#"{ "lint": { "rules": [] } }"#).unwrap_err();
+    assert_eq!(err.line, 1);
+  }
+
+  #[test]
+  fn overrides() {
+    let overrides = parse_config::<Vec<ConfigOverride>>(
+      r#"[
+        {
+          "files": ["tests/", "**/*_test.ts"],
+          "excludeFiles": ["tests/fixtures/**"],
+          "rules": { "exclude": ["no-explicit-any"] },
+        },
+        {
+          "files": ["scripts/**"],
+          "rules": { "tags": ["jsdoc"], "include": ["no-console"] },
+        },
+        {
+          "files": ["scripts/legacy.ts"],
+          "rules": { "exclude": ["no-console"] },
+        },
+        {
+          "files": ["tests/types_test.ts"],
+          "rules": { "include": ["no-explicit-any"] },
+        },
+      ]"#,
+      ConfigFormat::Jsonc,
+    )
+    .unwrap();
+    let selection = RuleSelection {
+      tags: vec!["recommended".to_string()],
+      include: vec![],
+      exclude: vec![],
+    };
+    let codes = |path: &str| {
+      let specifier =
+        ModuleSpecifier::parse(&format!("file:///project/{}", path)).unwrap();
+      selection
+        .with_overrides(&overrides, &specifier)
+        .rules(crate::rules::get_all_rules())
+        .iter()
+        .map(|rule| rule.code())
+        .collect::<Vec<_>>()
+    };
+
+    let src = codes("src/mod.ts");
+    assert!(src.contains(&"no-var"));
+    assert!(src.contains(&"no-explicit-any"));
+    assert!(!src.contains(&"no-console"));
+    assert_eq!(codes("tests/fixtures/mod.ts"), src);
+
+    let tests = codes("tests/mod.ts");
+    assert!(tests.contains(&"no-var"));
+    assert!(!tests.contains(&"no-explicit-any"));
+    assert_eq!(codes("src/mod_test.ts"), tests);
+
+    let scripts = codes("scripts/build.ts");
+    assert!(scripts.contains(&"no-console"));
+    assert!(scripts.contains(&"jsdoc-require-returns"));
+    assert!(scripts.contains(&"no-explicit-any"));
+
+    // Later overrides take precedence.
+    let legacy = codes("scripts/legacy.ts");
+    assert!(!legacy.contains(&"no-console"));
+    assert!(legacy.contains(&"jsdoc-require-returns"));
+    assert_eq!(codes("tests/types_test.ts"), src);
+
+    let unknown = parse_config::<ConfigOverride>(
+      r#"{ "files": ["tests/"], "severity": "off" }"#,
+      ConfigFormat::Json,
+    );
+    assert!(unknown.is_err());
+  }
+
+  #[test]
+  fn errors_have_positions() {
+    let text = "{\n  // comment\n  \"tags\": [],\n  \"unknown\": true\n}";
+    let err =
+      parse_config::<TestConfig>(text, ConfigFormat::Jsonc).unwrap_err();
+    assert_eq!(err.line, 4);
+    assert!(err.message.starts_with("unknown field `unknown`"));
+    assert!(err
+      .to_string()
+      .ends_with(&format!(" at line 4 column {}", err.column)));
+
+    let text = "{\n  \"tags\": [\"a\"],\n  \"options\": {\n    \"camelcase\": { \"properties\": 1 }\n  }\n}";
+    let err = ConfigError::at_pointer(
+      text,
+      "/options/camelcase/properties",
+      "Expected a string".to_string(),
+    );
+    assert_eq!((err.line, err.column), (4, 34));
+
+    let err = ConfigError::at_pointer(
+      text,
+      "/options/camelcase/ignoreImports",
+      "Expected a boolean".to_string(),
+    );
+    assert_eq!((err.line, err.column), (4, 18));
+
+    let err = ConfigError::at_pointer(text, "/tags/0", "Unknown tag".into());
+    assert_eq!((err.line, err.column), (2, 12));
+  }
+}
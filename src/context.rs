@@ -1,28 +1,39 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
+use crate::code_metrics::CodeMetrics;
+use crate::comments::Comments;
 use crate::control_flow::ControlFlow;
 use crate::diagnostic::{
-  LintDiagnostic, LintDiagnosticDetails, LintDiagnosticRange, LintFix,
+  LintDiagnostic, LintDiagnosticDetails, LintDiagnosticRange,
+  LintDiagnosticRelated, LintFix, SuppressedDiagnostic,
 };
 use crate::ignore_directives::{
-  parse_line_ignore_directives, CodeStatus, FileIgnoreDirective,
-  LineIgnoreDirective,
+  parse_line_ignore_directives, resolve_rule_alias, CodeStatus,
+  FileIgnoreDirective, LineIgnoreDirective,
 };
-use crate::linter::LinterContext;
+use crate::js_regex::EcmaVersion;
+use crate::jsdoc::JsDoc;
+use crate::linter::{LintConfig, LinterContext};
+use crate::module_graph::ModuleGraph;
 use crate::rules;
 use deno_ast::swc::ast::Expr;
 use deno_ast::swc::common::comments::Comment;
 use deno_ast::swc::common::util::take::Take;
 use deno_ast::swc::common::{SourceMap, SyntaxContext};
+use deno_ast::swc::parser::token::TokenAndSpan;
+use deno_ast::Scope;
+use deno_ast::SourceRangedForSpanned;
 use deno_ast::SourceTextInfo;
 use deno_ast::{
-  view as ast_view, ParsedSource, RootNode, SourcePos, SourceRange,
+  view as ast_view, MultiThreadedComments, ParsedSource, RootNode, SourcePos,
+  SourceRange,
 };
 use deno_ast::{MediaType, ModuleSpecifier};
-use deno_ast::{MultiThreadedComments, Scope};
 use std::borrow::Cow;
+use std::cell::OnceCell;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 /// `Context` stores all data needed to perform linting of a particular file.
 pub struct Context<'a> {
@@ -32,13 +43,18 @@ pub struct Context<'a> {
   file_ignore_directive: Option<FileIgnoreDirective>,
   line_ignore_directives: HashMap<usize, LineIgnoreDirective>,
   scope: Scope,
+  module_graph: Option<Arc<ModuleGraph>>,
   control_flow: ControlFlow,
+  code_metrics: OnceCell<CodeMetrics>,
   traverse_flow: TraverseFlow,
+  rule_budget: RuleBudget,
+  timed_out_rules: Vec<&'static str>,
   check_unknown_rules: bool,
   #[allow(clippy::redundant_allocation)] // This type comes from SWC.
   jsx_factory: Option<Arc<Box<Expr>>>,
   #[allow(clippy::redundant_allocation)] // This type comes from SWC.
   jsx_fragment_factory: Option<Arc<Box<Expr>>>,
+  ecma_version: EcmaVersion,
 }
 
 impl<'a> Context<'a> {
@@ -47,14 +63,15 @@ impl<'a> Context<'a> {
     parsed_source: ParsedSource,
     program: ast_view::Program<'a>,
     file_ignore_directive: Option<FileIgnoreDirective>,
-    default_jsx_factory: Option<String>,
-    default_jsx_fragment_factory: Option<String>,
+    config: LintConfig,
+    maybe_scope: Option<Scope>,
+    module_graph: Option<Arc<ModuleGraph>>,
   ) -> Self {
     let line_ignore_directives = parse_line_ignore_directives(
       linter_ctx.ignore_diagnostic_directive,
       program,
     );
-    let scope = Scope::analyze(program);
+    let scope = maybe_scope.unwrap_or_else(|| Scope::analyze(program));
     let control_flow =
       ControlFlow::analyze(program, parsed_source.unresolved_context());
 
@@ -79,7 +96,7 @@ impl<'a> Context<'a> {
       }
 
       if jsx_factory.is_none() {
-        if let Some(factory) = default_jsx_factory {
+        if let Some(factory) = config.default_jsx_factory {
           jsx_factory =
             Some(deno_ast::swc::transforms::react::parse_expr_for_jsx(
               &SourceMap::default(),
@@ -90,7 +107,7 @@ impl<'a> Context<'a> {
         }
       }
       if jsx_fragment_factory.is_none() {
-        if let Some(factory) = default_jsx_fragment_factory {
+        if let Some(factory) = config.default_jsx_fragment_factory {
           jsx_fragment_factory =
             Some(deno_ast::swc::transforms::react::parse_expr_for_jsx(
               &SourceMap::default(),
@@ -106,14 +123,19 @@ impl<'a> Context<'a> {
       file_ignore_directive,
       line_ignore_directives,
       scope,
+      module_graph,
       control_flow,
+      code_metrics: OnceCell::new(),
       program,
       parsed_source,
       diagnostics: Vec::new(),
       traverse_flow: TraverseFlow::default(),
+      rule_budget: RuleBudget::default(),
+      timed_out_rules: Vec::new(),
       check_unknown_rules: linter_ctx.check_unknown_rules,
       jsx_factory,
       jsx_fragment_factory,
+      ecma_version: config.ecma_version,
     }
   }
 
@@ -133,6 +155,11 @@ impl<'a> Context<'a> {
     self.parsed_source.comments()
   }
 
+  /// Lookups of the comments of the program.
+  pub fn comment_lookups(&self) -> Comments<'a> {
+    Comments::new(self.program)
+  }
+
   /// Stores diagnostics that are generated while linting
   pub fn diagnostics(&self) -> &[LintDiagnostic] {
     &self.diagnostics
@@ -170,11 +197,25 @@ impl<'a> Context<'a> {
     &self.scope
   }
 
+  /// The files of the project and the imports between them, if the file is
+  /// linted as part of a project, see `Linter::lint_project`.
+  pub fn module_graph(&self) -> Option<&ModuleGraph> {
+    self.module_graph.as_deref()
+  }
+
   /// Control-flow analysis result
   pub fn control_flow(&self) -> &ControlFlow {
     &self.control_flow
   }
 
+  /// Code metrics of the program, computed when first requested since only
+  /// opt-in rules use them.
+  pub(crate) fn code_metrics(&self) -> &CodeMetrics {
+    self
+      .code_metrics
+      .get_or_init(|| CodeMetrics::analyze(self.program))
+  }
+
   /// Get the JSX factory expression for this file, if one is specified (via
   /// pragma or using a default). If this file is not JSX, uses the automatic
   /// transform, or the default factory is not specified, this will return
@@ -191,6 +232,12 @@ impl<'a> Context<'a> {
     self.jsx_fragment_factory.clone()
   }
 
+  /// The oldest ECMAScript version the linted code has to run on, as
+  /// configured with `LintConfig::ecma_version`.
+  pub fn ecma_version(&self) -> EcmaVersion {
+    self.ecma_version
+  }
+
   /// The `SyntaxContext` of any unresolved identifiers
   pub(crate) fn unresolved_ctxt(&self) -> SyntaxContext {
     self.parsed_source.unresolved_context()
@@ -208,34 +255,130 @@ impl<'a> Context<'a> {
     self.traverse_flow.set_stop_traverse();
   }
 
+  /// Starts the clock for the next rule, see
+  /// `LinterOptions::rule_time_budget`.
+  pub(crate) fn start_rule_budget(&mut self, budget: Option<Duration>) {
+    self.rule_budget = RuleBudget::start(budget);
+  }
+
+  /// Checks if the current rule ran out of its time budget for the file, see
+  /// `LinterOptions::rule_time_budget`. Once it did, its diagnostics are
+  /// dropped anyway, so it can stop early. Traversals with `Handler` check it
+  /// for every node, and rules that walk the file on their own can call it
+  /// in their loops. It's cheap enough to call often, since the clock is only
+  /// read now and then.
+  pub fn is_over_rule_budget(&mut self) -> bool {
+    self.rule_budget.tick()
+  }
+
+  /// Checks if the current rule exceeded its time budget, once it's done.
+  pub(crate) fn is_rule_budget_exceeded(&self) -> bool {
+    self.rule_budget.is_exceeded()
+  }
+
+  pub(crate) fn timed_out_rules(&self) -> &[&'static str] {
+    &self.timed_out_rules
+  }
+
+  /// See `Comments::all_comments`.
   pub fn all_comments(&self) -> impl Iterator<Item = &'a Comment> {
-    self.program.comment_container().all_comments()
+    self.comment_lookups().all_comments()
   }
 
+  /// See `Comments::leading_comments`.
   pub fn leading_comments_at(
     &self,
     start: SourcePos,
   ) -> impl Iterator<Item = &'a Comment> {
-    self.program.comment_container().leading_comments(start)
+    self
+      .comment_lookups()
+      .leading_comments(SourceRange::new(start, start))
   }
 
+  /// See `Comments::trailing_comments`.
   pub fn trailing_comments_at(
     &self,
     end: SourcePos,
   ) -> impl Iterator<Item = &'a Comment> {
-    self.program.comment_container().trailing_comments(end)
+    self
+      .comment_lookups()
+      .trailing_comments(SourceRange::new(end, end))
+  }
+
+  /// Returns the JSDoc comment right before `start`, like the one of a
+  /// declaration. If there are several, the last one is returned, since it's
+  /// the one that belongs to the declaration.
+  pub fn leading_jsdoc_at(&self, start: SourcePos) -> Option<JsDoc> {
+    self
+      .leading_comments_at(start)
+      .filter_map(JsDoc::parse)
+      .last()
+  }
+
+  /// Checks if there are any comments inside of `range`. Fixes that rewrite
+  /// the range would drop these comments.
+  pub fn has_comments_in_range(&self, range: SourceRange) -> bool {
+    self.comment_lookups().has_comments_in_range(range)
+  }
+
+  /// All tokens of the file, in source order.
+  pub fn tokens(&self) -> &'a [TokenAndSpan] {
+    self.program.token_container().tokens
+  }
+
+  /// Returns the tokens that are entirely inside of `range`.
+  pub fn tokens_in_range(&self, range: SourceRange) -> &'a [TokenAndSpan] {
+    let tokens = self.tokens();
+    let start = tokens.partition_point(|token| token.start() < range.start);
+    let end = tokens.partition_point(|token| token.end() <= range.end);
+    &tokens[start..end.max(start)]
+  }
+
+  /// Returns the token that starts at `start`, if any.
+  pub fn token_at(&self, start: SourcePos) -> Option<&'a TokenAndSpan> {
+    let tokens = self.tokens();
+    let index = tokens.partition_point(|token| token.start() < start);
+    tokens.get(index).filter(|token| token.start() == start)
+  }
+
+  /// Returns the last token that ends at or before `pos`, e.g. the token
+  /// before a node when given the node's start.
+  pub fn previous_token(&self, pos: SourcePos) -> Option<&'a TokenAndSpan> {
+    let tokens = self.tokens();
+    let index = tokens.partition_point(|token| token.end() <= pos);
+    index.checked_sub(1).map(|index| &tokens[index])
+  }
+
+  /// Returns the first token that starts at or after `pos`, e.g. the token
+  /// after a node when given the node's end.
+  pub fn next_token(&self, pos: SourcePos) -> Option<&'a TokenAndSpan> {
+    let tokens = self.tokens();
+    let index = tokens.partition_point(|token| token.start() < pos);
+    tokens.get(index)
   }
 
   /// Mark ignore directives as used if that directive actually suppresses some
   /// diagnostic, and return a list of diagnostics that are not ignored.
   /// Make sure that this method is called after all lint rules have been
   /// executed.
-  pub(crate) fn check_ignore_directive_usage(&mut self) -> Vec<LintDiagnostic> {
+  ///
+  /// Returns the diagnostics that are not ignored, and the ones that are
+  /// silenced by an ignore directive.
+  pub(crate) fn check_ignore_directive_usage(
+    &mut self,
+  ) -> (Vec<LintDiagnostic>, Vec<SuppressedDiagnostic>) {
     let mut filtered = Vec::new();
+    let mut suppressed = Vec::new();
 
     for diagnostic in self.diagnostics.iter().cloned() {
       if let Some(f) = self.file_ignore_directive.as_mut() {
-        if f.check_used(&diagnostic.details.code) {
+        // Files ignored as a whole are only linted to report suppressed
+        // diagnostics.
+        if f.ignore_all() || f.check_used(&diagnostic.details.code) {
+          suppressed.push(SuppressedDiagnostic {
+            diagnostic,
+            directive_range: f.range(),
+          });
           continue;
         }
       }
@@ -249,6 +392,10 @@ impl<'a> Context<'a> {
           self.line_ignore_directives.get_mut(&(diagnostic_line - 1))
         {
           if l.check_used(&diagnostic.details.code) {
+            suppressed.push(SuppressedDiagnostic {
+              diagnostic,
+              directive_range: l.range(),
+            });
             continue;
           }
         }
@@ -257,7 +404,7 @@ impl<'a> Context<'a> {
       filtered.push(diagnostic);
     }
 
-    filtered
+    (filtered, suppressed)
   }
 
   /// Lint rule implementation for `ban-unused-ignore`.
@@ -280,7 +427,7 @@ impl<'a> Context<'a> {
     }
 
     let is_unused_code = |&(code, status): &(&String, &CodeStatus)| {
-      let is_unknown = !known_rules_codes.contains(code.as_str());
+      let is_unknown = !known_rules_codes.contains(resolve_rule_alias(code));
       !status.used && !is_unknown
     };
 
@@ -442,6 +589,44 @@ impl<'a> Context<'a> {
     );
   }
 
+  /// Add a diagnostic that refers to other locations in the file, e.g. a
+  /// previous declaration of the same name.
+  pub fn add_diagnostic_with_related(
+    &mut self,
+    range: SourceRange,
+    code: impl ToString,
+    message: impl ToString,
+    hint: Option<String>,
+    related: Vec<LintDiagnosticRelated>,
+  ) {
+    let mut details =
+      self.create_diagnostic_details(code, message, hint, Vec::new());
+    details.related = related;
+    self.add_diagnostic_details(
+      Some(self.create_diagnostic_range(range)),
+      details,
+    );
+  }
+
+  /// Add a diagnostic with fixes that are offered in the editor, but not
+  /// applied automatically.
+  pub fn add_diagnostic_with_suggestions(
+    &mut self,
+    range: SourceRange,
+    code: impl ToString,
+    message: impl ToString,
+    hint: Option<String>,
+    suggestions: Vec<LintFix>,
+  ) {
+    let mut details =
+      self.create_diagnostic_details(code, message, hint, Vec::new());
+    details.suggestions = suggestions;
+    self.add_diagnostic_details(
+      Some(self.create_diagnostic_range(range)),
+      details,
+    );
+  }
+
   pub fn add_diagnostic_details(
     &mut self,
     maybe_range: Option<LintDiagnosticRange>,
@@ -460,6 +645,48 @@ impl<'a> Context<'a> {
     self.diagnostics.extend_from_slice(diagnostics);
   }
 
+  /// Replaces the diagnostics that a rule reported before it panicked with a
+  /// diagnostic about the panic, and resets the state the rule may have left
+  /// behind, so the next rules can run.
+  pub(crate) fn recover_from_rule_panic(
+    &mut self,
+    code: &str,
+    diagnostics_len: usize,
+    panic_message: &str,
+  ) {
+    self.diagnostics.truncate(diagnostics_len);
+    self.traverse_flow.reset();
+    let start = self.text_info().range().start;
+    let details = self.create_diagnostic_details(
+      code,
+      format!("The rule panicked while linting this file: {}", panic_message),
+      Some(
+        "This is a bug in the rule. Ignore the rule for this file until it's fixed"
+          .to_string(),
+      ),
+      vec![],
+    );
+    self.add_diagnostic_details(
+      Some(self.create_diagnostic_range(SourceRange::new(
+        start.as_source_pos(),
+        start.as_source_pos(),
+      ))),
+      details,
+    );
+  }
+
+  /// Drops the diagnostics of a rule that exceeded its time budget, and
+  /// resets the state it may have left behind, so the next rules can run.
+  pub(crate) fn recover_from_rule_timeout(
+    &mut self,
+    code: &'static str,
+    diagnostics_len: usize,
+  ) {
+    self.diagnostics.truncate(diagnostics_len);
+    self.traverse_flow.reset();
+    self.timed_out_rules.push(code);
+  }
+
   pub(crate) fn create_diagnostic(
     &self,
     maybe_range: Option<LintDiagnosticRange>,
@@ -484,8 +711,10 @@ impl<'a> Context<'a> {
       code: code.to_string(),
       hint: maybe_hint,
       fixes,
+      suggestions: vec![],
       custom_docs_url: None,
       info: vec![],
+      related: vec![],
     }
   }
 
@@ -530,3 +759,45 @@ impl TraverseFlow {
     stop
   }
 }
+
+/// The time the current rule may still spend on the file.
+#[derive(Debug, Default)]
+struct RuleBudget {
+  deadline: Option<Instant>,
+  /// Nodes traversed since the clock was read last.
+  nodes: u32,
+  exceeded: bool,
+}
+
+impl RuleBudget {
+  /// Number of nodes between reads of the clock.
+  const CHECK_INTERVAL: u32 = 256;
+
+  fn start(budget: Option<Duration>) -> Self {
+    RuleBudget {
+      deadline: budget.map(|budget| Instant::now() + budget),
+      ..Default::default()
+    }
+  }
+
+  fn tick(&mut self) -> bool {
+    let Some(deadline) = self.deadline else {
+      return false;
+    };
+    if !self.exceeded {
+      self.nodes += 1;
+      if self.nodes >= Self::CHECK_INTERVAL {
+        self.nodes = 0;
+        self.exceeded = Instant::now() >= deadline;
+      }
+    }
+    self.exceeded
+  }
+
+  fn is_exceeded(&self) -> bool {
+    self.exceeded
+      || self
+        .deadline
+        .is_some_and(|deadline| Instant::now() >= deadline)
+  }
+}
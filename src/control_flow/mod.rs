@@ -1,5 +1,18 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
+//! Control-flow analysis, which tells for statements and switch cases
+//! whether they can be reached and whether execution continues after them.
+//!
+//! The analysis runs once per file, and its result is available to rules
+//! through [`Context::control_flow`](crate::context::Context::control_flow):
+//!
+//! ```ignore
+//! let unreachable = ctx
+//!   .control_flow()
+//!   .meta(stmt.start())
+//!   .map_or(false, |meta| meta.unreachable);
+//! ```
+
 #[cfg(test)]
 mod analyze_test;
 
@@ -18,12 +31,16 @@ use std::{
   mem::take,
 };
 
+/// The result of the control-flow analysis of a program.
 #[derive(Debug, Clone)]
 pub struct ControlFlow {
   meta: BTreeMap<SourcePos, Metadata>,
 }
 
 impl ControlFlow {
+  /// Analyzes the program. `unresolved_ctxt` is the syntax context of
+  /// unresolved references, which is used to tell if e.g. `undefined` is the
+  /// global one.
   pub fn analyze(
     program: view::Program,
     unresolved_ctxt: SyntaxContext,
@@ -43,10 +60,13 @@ impl ControlFlow {
     ControlFlow { meta: v.info }
   }
 
-  /// start_pos can be extracted from range of
+  /// Returns the metadata of the node starting at `start_pos`, which can be
+  /// the start of
   ///
   /// - All statements (including stmt.range())
   /// - [SwitchCase]
+  ///
+  /// Other nodes don't have metadata.
   pub fn meta(&self, start_pos: SourcePos) -> Option<&Metadata> {
     self.meta.get(&start_pos)
   }
@@ -54,7 +74,7 @@ impl ControlFlow {
 
 /// Kind of a basic block.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub enum BlockKind {
+pub(crate) enum BlockKind {
   /// Program (module or script)
   Program,
   /// Function's body
@@ -72,8 +92,12 @@ pub enum BlockKind {
   Finally,
 }
 
+/// Reachability of a statement or a switch case, and how execution goes on
+/// after it.
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct Metadata {
+  /// Whether the node can never be executed, e.g. because it comes after a
+  /// `return` statement.
   pub unreachable: bool,
   end: Option<End>,
 }
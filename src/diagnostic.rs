@@ -11,6 +11,7 @@ use deno_ast::diagnostics::DiagnosticSnippetHighlightStyle;
 use deno_ast::diagnostics::DiagnosticSourcePos;
 use deno_ast::diagnostics::DiagnosticSourceRange;
 use deno_ast::ModuleSpecifier;
+use deno_ast::ParseDiagnostic;
 use deno_ast::SourceRange;
 use deno_ast::SourceTextInfo;
 
@@ -26,7 +27,7 @@ pub struct LintFix {
   pub changes: Vec<LintFixChange>,
 }
 
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub struct LintDiagnosticRange {
   pub text_info: SourceTextInfo,
   pub range: SourceRange,
@@ -34,7 +35,15 @@ pub struct LintDiagnosticRange {
   pub description: Option<String>,
 }
 
-#[derive(Clone)]
+/// Another location in the same file that gives context to a diagnostic,
+/// similar to the related information of LSP diagnostics.
+#[derive(Debug, Clone)]
+pub struct LintDiagnosticRelated {
+  pub range: SourceRange,
+  pub message: String,
+}
+
+#[derive(Debug, Clone)]
 pub struct LintDiagnosticDetails {
   pub message: String,
   pub code: String,
@@ -46,14 +55,20 @@ pub struct LintDiagnosticDetails {
   /// only the first fix will be used for the `--fix` flag, but
   /// multiple will be shown in the LSP.
   pub fixes: Vec<LintFix>,
+  /// Fixes that change the code in ways the user should opt into, e.g.
+  /// because they are a matter of taste. They are only shown in the Deno LSP
+  /// and never applied by the `deno lint --fix` flag.
+  pub suggestions: Vec<LintFix>,
   /// URL to the lint rule documentation. By default, the url uses the
   /// code to link to lint.deno.land
   pub custom_docs_url: Option<String>,
   /// Displays additional information at the end of a diagnostic.
   pub info: Vec<Cow<'static, str>>,
+  /// Other locations in the file that are relevant to the diagnostic.
+  pub related: Vec<LintDiagnosticRelated>,
 }
 
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub struct LintDiagnostic {
   pub specifier: ModuleSpecifier,
   /// Optional range within the file.
@@ -64,6 +79,47 @@ pub struct LintDiagnostic {
   pub details: LintDiagnosticDetails,
 }
 
+/// Code of the diagnostics for the errors of the parser, see
+/// `Linter::lint_file_tolerant`.
+pub const PARSE_ERROR_CODE: &str = "parse-error";
+
+impl LintDiagnostic {
+  /// Converts an error of the parser into a diagnostic with the
+  /// [`PARSE_ERROR_CODE`] code. `text_info` is the text of the parsed file.
+  pub fn from_parse_diagnostic(
+    diagnostic: &ParseDiagnostic,
+    text_info: &SourceTextInfo,
+  ) -> Self {
+    LintDiagnostic {
+      specifier: diagnostic.specifier.clone(),
+      range: Some(LintDiagnosticRange {
+        text_info: text_info.clone(),
+        range: diagnostic.range,
+        description: None,
+      }),
+      details: LintDiagnosticDetails {
+        message: diagnostic.message().to_string(),
+        code: PARSE_ERROR_CODE.to_string(),
+        hint: None,
+        fixes: vec![],
+        suggestions: vec![],
+        custom_docs_url: None,
+        info: vec![],
+        related: vec![],
+      },
+    }
+  }
+}
+
+/// A diagnostic that was silenced by an ignore directive.
+#[derive(Clone)]
+pub struct SuppressedDiagnostic {
+  pub diagnostic: LintDiagnostic,
+  /// Range of the `deno-lint-ignore` or `deno-lint-ignore-file` comment that
+  /// silenced the diagnostic.
+  pub directive_range: SourceRange,
+}
+
 impl Diagnostic for LintDiagnostic {
   fn level(&self) -> DiagnosticLevel {
     DiagnosticLevel::Error
@@ -92,16 +148,27 @@ impl Diagnostic for LintDiagnostic {
 
   fn snippet(&self) -> Option<DiagnosticSnippet<'_>> {
     let range = self.range.as_ref()?;
-    Some(DiagnosticSnippet {
-      source: Cow::Borrowed(&range.text_info),
-      highlights: vec![DiagnosticSnippetHighlight {
+    let mut highlights = vec![DiagnosticSnippetHighlight {
+      range: DiagnosticSourceRange {
+        start: DiagnosticSourcePos::SourcePos(range.range.start),
+        end: DiagnosticSourcePos::SourcePos(range.range.end),
+      },
+      style: DiagnosticSnippetHighlightStyle::Error,
+      description: range.description.as_deref().map(Cow::Borrowed),
+    }];
+    highlights.extend(self.details.related.iter().map(|related| {
+      DiagnosticSnippetHighlight {
         range: DiagnosticSourceRange {
-          start: DiagnosticSourcePos::SourcePos(range.range.start),
-          end: DiagnosticSourcePos::SourcePos(range.range.end),
+          start: DiagnosticSourcePos::SourcePos(related.range.start),
+          end: DiagnosticSourcePos::SourcePos(related.range.end),
         },
-        style: DiagnosticSnippetHighlightStyle::Error,
-        description: range.description.as_deref().map(Cow::Borrowed),
-      }],
+        style: DiagnosticSnippetHighlightStyle::Hint,
+        description: Some(Cow::Borrowed(related.message.as_str())),
+      }
+    }));
+    Some(DiagnosticSnippet {
+      source: Cow::Borrowed(&range.text_info),
+      highlights,
     })
   }
 
@@ -124,6 +191,9 @@ impl Diagnostic for LintDiagnostic {
   fn docs_url(&self) -> Option<Cow<'_, str>> {
     if let Some(custom_docs_url) = &self.details.custom_docs_url {
       Some(Cow::Borrowed(custom_docs_url))
+    } else if self.details.code == PARSE_ERROR_CODE {
+      // Errors of the parser don't belong to a rule.
+      None
     } else {
       Some(Cow::Owned(format!(
         "https://docs.deno.com/lint/rules/{}",
@@ -0,0 +1,158 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Analysis of whether a thrown or rejected value may be an `Error`. Without
+//! type information, a value is only known not to be an error if it is e.g. a
+//! literal, a template, an object or array literal, the result of an operator
+//! that returns a primitive, or an instance of a class declared in the same
+//! file that doesn't extend `Error`. Anything else, like identifiers and calls,
+//! may be an error.
+
+use crate::ProgramRef;
+use deno_ast::swc::ast::{
+  AssignOp, BinaryOp, Class, ClassDecl, Expr, Id, Pat, VarDeclarator,
+};
+use deno_ast::swc::visit::{noop_visit_type, Visit, VisitWith};
+use std::collections::HashMap;
+
+/// How deep classes extending other classes are followed.
+const MAX_CLASS_DEPTH: usize = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum NonErrorValue {
+  /// `undefined`, which is also what an empty `throw` or `reject()` results
+  /// in.
+  Undefined,
+  /// A literal, e.g. `"failed"` or `404`.
+  Literal,
+  /// Any other value that isn't an error.
+  Other,
+}
+
+/// What a class declared in the file extends.
+#[derive(Debug)]
+enum SuperClass {
+  None,
+  Ident(Id),
+  /// An expression like `mixin(Base)`, which may result in any class.
+  Other,
+}
+
+/// The classes declared in a file, along with the classes they extend.
+#[derive(Debug, Default)]
+pub(crate) struct LocalClasses {
+  super_classes: HashMap<Id, SuperClass>,
+}
+
+impl LocalClasses {
+  pub(crate) fn collect(program: ProgramRef) -> Self {
+    let mut classes = Self::default();
+    match program {
+      ProgramRef::Module(m) => m.visit_with(&mut classes),
+      ProgramRef::Script(s) => s.visit_with(&mut classes),
+    }
+    classes
+  }
+
+  /// Returns the kind of the value the expression results in, or `None` if
+  /// it may be an error.
+  pub(crate) fn non_error_value(&self, expr: &Expr) -> Option<NonErrorValue> {
+    match expr {
+      Expr::Lit(_) => Some(NonErrorValue::Literal),
+      Expr::Ident(ident) if ident.sym == *"undefined" => {
+        Some(NonErrorValue::Undefined)
+      }
+      Expr::Tpl(_)
+      | Expr::Object(_)
+      | Expr::Array(_)
+      | Expr::Arrow(_)
+      | Expr::Fn(_)
+      | Expr::Class(_)
+      | Expr::Unary(_)
+      | Expr::Update(_) => Some(NonErrorValue::Other),
+      Expr::Bin(bin) => match bin.op {
+        // An error is truthy, so `a && b` is either a falsy `a` or `b`.
+        BinaryOp::LogicalAnd => self
+          .non_error_value(&bin.right)
+          .map(|_| NonErrorValue::Other),
+        BinaryOp::LogicalOr | BinaryOp::NullishCoalescing => {
+          self.non_error_value(&bin.left)?;
+          self.non_error_value(&bin.right)?;
+          Some(NonErrorValue::Other)
+        }
+        _ => Some(NonErrorValue::Other),
+      },
+      Expr::Assign(assign) => match assign.op {
+        AssignOp::Assign => self.non_error_value(&assign.right),
+        AssignOp::AndAssign | AssignOp::OrAssign | AssignOp::NullishAssign => {
+          None
+        }
+        _ => Some(NonErrorValue::Other),
+      },
+      Expr::Cond(cond) => {
+        self.non_error_value(&cond.cons)?;
+        self.non_error_value(&cond.alt)?;
+        Some(NonErrorValue::Other)
+      }
+      Expr::Seq(seq) => self.non_error_value(seq.exprs.last()?),
+      Expr::New(new_expr) => match &*new_expr.callee {
+        Expr::Ident(ident) if !self.may_extend_error(&ident.to_id(), 0) => {
+          Some(NonErrorValue::Other)
+        }
+        _ => None,
+      },
+      Expr::Paren(paren) => self.non_error_value(&paren.expr),
+      Expr::TsAs(ts_as) => self.non_error_value(&ts_as.expr),
+      Expr::TsTypeAssertion(assertion) => self.non_error_value(&assertion.expr),
+      Expr::TsNonNull(non_null) => self.non_error_value(&non_null.expr),
+      Expr::TsConstAssertion(assertion) => {
+        self.non_error_value(&assertion.expr)
+      }
+      Expr::TsSatisfies(satisfies) => self.non_error_value(&satisfies.expr),
+      _ => None,
+    }
+  }
+
+  /// Checks if the class may be `Error` or a subclass of it. Only classes
+  /// declared in the file, which don't extend anything or extend other such
+  /// classes, are known not to be errors.
+  fn may_extend_error(&self, id: &Id, depth: usize) -> bool {
+    let Some(super_class) = self.super_classes.get(id) else {
+      return true;
+    };
+    match super_class {
+      SuperClass::None => false,
+      SuperClass::Ident(id) if depth < MAX_CLASS_DEPTH => {
+        self.may_extend_error(id, depth + 1)
+      }
+      SuperClass::Ident(_) | SuperClass::Other => true,
+    }
+  }
+
+  fn insert(&mut self, id: Id, class: &Class) {
+    let super_class =
+      match class.super_class.as_deref().map(Expr::unwrap_parens) {
+        None => SuperClass::None,
+        Some(Expr::Ident(ident)) => SuperClass::Ident(ident.to_id()),
+        Some(_) => SuperClass::Other,
+      };
+    self.super_classes.insert(id, super_class);
+  }
+}
+
+impl Visit for LocalClasses {
+  noop_visit_type!();
+
+  fn visit_class_decl(&mut self, class_decl: &ClassDecl) {
+    self.insert(class_decl.ident.to_id(), &class_decl.class);
+    class_decl.visit_children_with(self);
+  }
+
+  fn visit_var_declarator(&mut self, declarator: &VarDeclarator) {
+    if let (Pat::Ident(binding), Some(Expr::Class(class_expr))) =
+      (&declarator.name, declarator.init.as_deref())
+    {
+      self.insert(binding.id.to_id(), &class_expr.class);
+    }
+    declarator.visit_children_with(self);
+  }
+}
@@ -0,0 +1,487 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Extraction of JavaScript and TypeScript sources that are embedded in
+//! other files, e.g. the `<script>` block of a `.vue` or `.svelte` component
+//! or the fenced code blocks of a Markdown file.
+
+use crate::diagnostic::LintDiagnostic;
+use deno_ast::MediaType;
+use deno_ast::ModuleSpecifier;
+use deno_ast::SourcePos;
+use deno_ast::SourceRange;
+use deno_ast::SourceTextInfo;
+use deno_ast::StartSourcePos;
+
+/// A pre-processor that maps a file to the sources that should be linted.
+///
+/// Extractors are registered with `LinterOptions::extractors` and used by
+/// `Linter::lint_file_with_extraction`.
+pub trait Extractor: std::fmt::Debug + Send + Sync {
+  /// Returns `true` if the file should be extracted, usually based on its
+  /// extension.
+  fn matches(&self, specifier: &ModuleSpecifier) -> bool;
+
+  /// Returns the sources embedded in the file, which may be none.
+  fn extract(
+    &self,
+    specifier: &ModuleSpecifier,
+    source_code: &str,
+  ) -> Vec<VirtualSource>;
+}
+
+/// A JavaScript or TypeScript source extracted from another file.
+#[derive(Debug, Clone)]
+pub struct VirtualSource {
+  pub media_type: MediaType,
+  pub source_code: String,
+  /// Maps byte offsets of the source to byte offsets of the original file,
+  /// sorted by `generated`.
+  pub mappings: Vec<SourceMapping>,
+}
+
+/// The start of a chunk of a virtual source that is copied from the original
+/// file. The chunk ends where the next mapping starts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceMapping {
+  /// Byte offset in the virtual source.
+  pub generated: usize,
+  /// Byte offset in the original file.
+  pub original: usize,
+}
+
+impl VirtualSource {
+  /// Creates a source that is copied as a whole from the original file,
+  /// starting at `original_offset`.
+  pub fn new(
+    media_type: MediaType,
+    source_code: String,
+    original_offset: usize,
+  ) -> Self {
+    VirtualSource {
+      media_type,
+      source_code,
+      mappings: vec![SourceMapping {
+        generated: 0,
+        original: original_offset,
+      }],
+    }
+  }
+
+  /// Returns the byte offset in the original file of a byte offset of the
+  /// source. Offsets before the first mapping map to its start.
+  pub fn original_offset(&self, offset: usize) -> usize {
+    let index = self
+      .mappings
+      .partition_point(|mapping| mapping.generated <= offset);
+    match index.checked_sub(1) {
+      Some(index) => {
+        let mapping = &self.mappings[index];
+        mapping.original + (offset - mapping.generated)
+      }
+      None => self.mappings.first().map_or(offset, |m| m.original),
+    }
+  }
+
+  fn original_pos(
+    &self,
+    pos: SourcePos,
+    original: &SourceTextInfo,
+  ) -> SourcePos {
+    let offset = self
+      .original_offset(pos.as_byte_index(StartSourcePos::START_SOURCE_POS))
+      .min(original.text_str().len());
+    StartSourcePos::START_SOURCE_POS + offset
+  }
+
+  fn original_range(
+    &self,
+    range: SourceRange,
+    original: &SourceTextInfo,
+  ) -> SourceRange {
+    SourceRange::new(
+      self.original_pos(range.start, original),
+      self.original_pos(range.end, original),
+    )
+  }
+
+  /// Moves the ranges of a diagnostic of this source to the original file.
+  pub(crate) fn remap_diagnostic(
+    &self,
+    mut diagnostic: LintDiagnostic,
+    original: &SourceTextInfo,
+  ) -> LintDiagnostic {
+    if let Some(range) = &mut diagnostic.range {
+      range.range = self.original_range(range.range, original);
+      range.text_info = original.clone();
+    }
+    let details = &mut diagnostic.details;
+    for fix in details
+      .fixes
+      .iter_mut()
+      .chain(details.suggestions.iter_mut())
+    {
+      for change in &mut fix.changes {
+        change.range = self.original_range(change.range, original);
+      }
+    }
+    for related in &mut details.related {
+      related.range = self.original_range(related.range, original);
+    }
+    diagnostic
+  }
+}
+
+/// Extracts the fenced code blocks of Markdown files that are written in
+/// JavaScript or TypeScript, e.g. ` ```ts `. Like with `deno test --doc`,
+/// blocks with the `ignore` attribute (` ```ts ignore `) are skipped.
+///
+/// Each block is linted as a separate source, so ignore directives like
+/// `// deno-lint-ignore-file` only apply to the block they are written in.
+#[derive(Debug, Default)]
+pub struct MarkdownExtractor;
+
+impl Extractor for MarkdownExtractor {
+  fn matches(&self, specifier: &ModuleSpecifier) -> bool {
+    let path = specifier.path();
+    path.ends_with(".md") || path.ends_with(".markdown")
+  }
+
+  fn extract(
+    &self,
+    _specifier: &ModuleSpecifier,
+    source_code: &str,
+  ) -> Vec<VirtualSource> {
+    let mut sources = vec![];
+    let mut lines = lines_with_offsets(source_code);
+    while let Some((_, line)) = lines.next() {
+      let Some(fence) = Fence::parse(line) else {
+        continue;
+      };
+      let mut source = fence.media_type().map(|media_type| VirtualSource {
+        media_type,
+        source_code: String::new(),
+        mappings: vec![],
+      });
+      for (offset, line) in lines.by_ref() {
+        if fence.is_closed_by(line) {
+          break;
+        }
+        if let Some(source) = &mut source {
+          // The indentation of the fence is removed from the content.
+          let indent = line
+            .bytes()
+            .take(fence.indent)
+            .take_while(|c| *c == b' ')
+            .count();
+          source.mappings.push(SourceMapping {
+            generated: source.source_code.len(),
+            original: offset + indent,
+          });
+          source.source_code.push_str(&line[indent..]);
+        }
+      }
+      sources.extend(source);
+    }
+    sources
+  }
+}
+
+/// Returns the lines of the text, including their line breaks, along with
+/// their byte offsets.
+fn lines_with_offsets(text: &str) -> impl Iterator<Item = (usize, &str)> {
+  text.split_inclusive('\n').scan(0, |offset, line| {
+    let start = *offset;
+    *offset += line.len();
+    Some((start, line))
+  })
+}
+
+/// The opening fence of a Markdown code block.
+struct Fence<'a> {
+  indent: usize,
+  char: u8,
+  len: usize,
+  info: &'a str,
+}
+
+impl<'a> Fence<'a> {
+  fn parse(line: &'a str) -> Option<Self> {
+    let trimmed = line.trim_start_matches(' ');
+    let indent = line.len() - trimmed.len();
+    let char = *trimmed.as_bytes().first()?;
+    if indent > 3 || (char != b'`' && char != b'~') {
+      return None;
+    }
+    let len = trimmed.bytes().take_while(|c| *c == char).count();
+    let info = trimmed[len..].trim();
+    if len < 3 || (char == b'`' && info.contains('`')) {
+      return None;
+    }
+    Some(Fence {
+      indent,
+      char,
+      len,
+      info,
+    })
+  }
+
+  fn is_closed_by(&self, line: &str) -> bool {
+    let trimmed = line.trim_start_matches(' ');
+    let len = trimmed.bytes().take_while(|c| *c == self.char).count();
+    line.len() - trimmed.len() <= 3
+      && len >= self.len
+      && trimmed[len..].trim().is_empty()
+  }
+
+  /// Returns the media type of the language of the block, unless it's not
+  /// JavaScript or TypeScript or the block is ignored.
+  fn media_type(&self) -> Option<MediaType> {
+    let mut words = self
+      .info
+      .split(|c: char| c.is_whitespace() || c == ',')
+      .filter(|word| !word.is_empty());
+    let media_type = match words.next()? {
+      "ts" | "typescript" => MediaType::TypeScript,
+      "tsx" => MediaType::Tsx,
+      "mts" => MediaType::Mts,
+      "cts" => MediaType::Cts,
+      "js" | "javascript" => MediaType::JavaScript,
+      "jsx" => MediaType::Jsx,
+      "mjs" => MediaType::Mjs,
+      "cjs" => MediaType::Cjs,
+      _ => return None,
+    };
+    if words.any(|word| word == "ignore") {
+      return None;
+    }
+    Some(media_type)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::diagnostic::PARSE_ERROR_CODE;
+  use crate::linter::{
+    EcmaVersion, LintConfig, LintFileOptions, Linter, LinterOptions,
+  };
+  use crate::rules::{eqeqeq::Eqeqeq, no_var::NoVar, LintRule};
+
+  /// Extracts the `<script>` blocks of `.vue` files.
+  #[derive(Debug)]
+  struct ScriptBlockExtractor;
+
+  impl Extractor for ScriptBlockExtractor {
+    fn matches(&self, specifier: &ModuleSpecifier) -> bool {
+      specifier.path().ends_with(".vue")
+    }
+
+    fn extract(
+      &self,
+      _specifier: &ModuleSpecifier,
+      source_code: &str,
+    ) -> Vec<VirtualSource> {
+      let mut sources = vec![];
+      let mut rest = 0;
+      while let Some(start) = source_code[rest..].find("<script>") {
+        let start = rest + start + "<script>".len();
+        let Some(end) = source_code[start..].find("</script>") else {
+          break;
+        };
+        let end = start + end;
+        sources.push(VirtualSource::new(
+          MediaType::TypeScript,
+          source_code[start..end].to_string(),
+          start,
+        ));
+        rest = end;
+      }
+      sources
+    }
+  }
+
+  fn lint(specifier: &str, source_code: &str) -> Vec<LintDiagnostic> {
+    let rules: Vec<Box<dyn LintRule>> =
+      vec![Box::new(NoVar), Box::new(Eqeqeq::default())];
+    let linter = Linter::new(LinterOptions {
+      all_rule_codes: rules.iter().map(|rule| rule.code().into()).collect(),
+      rules,
+      custom_ignore_diagnostic_directive: None,
+      custom_ignore_file_directive: None,
+      extractors: vec![Box::new(ScriptBlockExtractor)],
+      rule_time_budget: None,
+    });
+    linter.lint_file_with_extraction(LintFileOptions {
+      specifier: ModuleSpecifier::parse(specifier).unwrap(),
+      source_code: source_code.to_string(),
+      media_type: MediaType::Unknown,
+      config: LintConfig {
+        default_jsx_factory: None,
+        default_jsx_fragment_factory: None,
+        ecma_version: EcmaVersion::default(),
+      },
+      external_linter: None,
+    })
+  }
+
+  fn line_and_column(diagnostic: &LintDiagnostic) -> (usize, usize) {
+    let range = diagnostic.range.as_ref().unwrap();
+    let display = range.text_info.line_and_column_display(range.range.start);
+    (display.line_number, display.column_number)
+  }
+
+  #[test]
+  fn original_offset() {
+    let source = VirtualSource {
+      media_type: MediaType::JavaScript,
+      source_code: "a;\nb;".to_string(),
+      mappings: vec![
+        SourceMapping {
+          generated: 0,
+          original: 10,
+        },
+        SourceMapping {
+          generated: 3,
+          original: 30,
+        },
+      ],
+    };
+    assert_eq!(source.original_offset(0), 10);
+    assert_eq!(source.original_offset(2), 12);
+    assert_eq!(source.original_offset(3), 30);
+    assert_eq!(source.original_offset(4), 31);
+  }
+
+  #[test]
+  fn remaps_diagnostics_to_original_file() {
+    let source = "<template>\n  <div></div>\n</template>\n\n<script>\nvar a = 1;\n</script>\n<script>\nif (typeof a == \"number\") {}\n</script>\n";
+    let diagnostics = lint("file:///App.vue", source);
+    assert_eq!(diagnostics.len(), 2);
+
+    assert_eq!(diagnostics[0].details.code, "no-var");
+    assert_eq!(line_and_column(&diagnostics[0]), (6, 1));
+    let range = &diagnostics[0].range.as_ref().unwrap().range;
+    assert_eq!(
+      &source[range.as_byte_range(StartSourcePos::START_SOURCE_POS)],
+      "var"
+    );
+
+    assert_eq!(diagnostics[1].details.code, "eqeqeq");
+    assert_eq!(line_and_column(&diagnostics[1]), (9, 5));
+    let change = &diagnostics[1].details.fixes[0].changes[0];
+    assert_eq!(
+      &source[change.range.as_byte_range(StartSourcePos::START_SOURCE_POS)],
+      "=="
+    );
+  }
+
+  #[test]
+  fn ignore_directives_apply_to_virtual_sources() {
+    let source =
+      "<script>\n// deno-lint-ignore no-var\nvar a = 1;\nvar b = 2;\n</script>";
+    let diagnostics = lint("file:///App.vue", source);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(line_and_column(&diagnostics[0]), (4, 1));
+  }
+
+  #[test]
+  fn remaps_parse_errors_to_original_file() {
+    let source = "<p>Hello</p>\n<script>\nconst = 1;\n</script>";
+    let diagnostics = lint("file:///App.vue", source);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].details.code, PARSE_ERROR_CODE);
+    assert_eq!(line_and_column(&diagnostics[0]).0, 3);
+  }
+
+  #[test]
+  fn lints_other_sources_after_parse_error() {
+    let source = "```ts
+const = 1;
+```
+
+```ts
+var a = 1;
+```
+";
+    let rules: Vec<Box<dyn LintRule>> = vec![Box::new(NoVar)];
+    let linter = Linter::new(LinterOptions {
+      all_rule_codes: rules.iter().map(|rule| rule.code().into()).collect(),
+      rules,
+      custom_ignore_diagnostic_directive: None,
+      custom_ignore_file_directive: None,
+      extractors: vec![Box::new(MarkdownExtractor)],
+      rule_time_budget: None,
+    });
+    let diagnostics = linter.lint_file_with_extraction(LintFileOptions {
+      specifier: ModuleSpecifier::parse("file:///README.md").unwrap(),
+      source_code: source.to_string(),
+      media_type: MediaType::Unknown,
+      config: LintConfig {
+        default_jsx_factory: None,
+        default_jsx_fragment_factory: None,
+        ecma_version: EcmaVersion::default(),
+      },
+      external_linter: None,
+    });
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(diagnostics[0].details.code, PARSE_ERROR_CODE);
+    assert_eq!(line_and_column(&diagnostics[0]).0, 2);
+    assert_eq!(diagnostics[1].details.code, "no-var");
+    assert_eq!(line_and_column(&diagnostics[1]), (6, 1));
+  }
+
+  #[test]
+  fn extracts_markdown_code_blocks() {
+    let source = "# Title\n\n```ts\nconst a = 1;\n```\n\n```sh\necho\n```\n\n- item\n\n  ~~~~js title=\"mod.js\"\n  let b;\n    c();\n  ~~~~\n\n```ts ignore\nfoo(\n```\n\n```jsx\n<div />";
+    let specifier = ModuleSpecifier::parse("file:///README.md").unwrap();
+    assert!(MarkdownExtractor.matches(&specifier));
+    let sources = MarkdownExtractor.extract(&specifier, source);
+    assert_eq!(sources.len(), 3);
+
+    assert_eq!(sources[0].media_type, MediaType::TypeScript);
+    assert_eq!(sources[0].source_code, "const a = 1;\n");
+    assert_eq!(sources[0].original_offset(0), source.find("const").unwrap());
+
+    assert_eq!(sources[1].media_type, MediaType::JavaScript);
+    assert_eq!(sources[1].source_code, "let b;\n  c();\n");
+    assert_eq!(sources[1].original_offset(0), source.find("let").unwrap());
+    assert_eq!(sources[1].original_offset(7), source.find("  c()").unwrap());
+
+    assert_eq!(sources[2].media_type, MediaType::Jsx);
+    assert_eq!(sources[2].source_code, "<div />");
+  }
+
+  #[test]
+  fn lints_markdown_code_blocks() {
+    let rules: Vec<Box<dyn LintRule>> = vec![Box::new(NoVar)];
+    let linter = Linter::new(LinterOptions {
+      all_rule_codes: rules.iter().map(|rule| rule.code().into()).collect(),
+      rules,
+      custom_ignore_diagnostic_directive: None,
+      custom_ignore_file_directive: None,
+      extractors: vec![Box::new(MarkdownExtractor)],
+      rule_time_budget: None,
+    });
+    let source = "Usage:\n\n```ts\n// deno-lint-ignore-file\nvar a = 1;\n```\n\n1. Step\n   ```js\n   var b = 2;\n   ```\n";
+    let diagnostics = linter.lint_file_with_extraction(LintFileOptions {
+      specifier: ModuleSpecifier::parse("file:///README.md").unwrap(),
+      source_code: source.to_string(),
+      media_type: MediaType::Unknown,
+      config: LintConfig {
+        default_jsx_factory: None,
+        default_jsx_fragment_factory: None,
+        ecma_version: EcmaVersion::default(),
+      },
+      external_linter: None,
+    });
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(line_and_column(&diagnostics[0]), (10, 4));
+  }
+
+  #[test]
+  fn lints_other_files_directly() {
+    let diagnostics = lint("file:///mod.ts", "var a = 1;");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(line_and_column(&diagnostics[0]), (1, 1));
+  }
+}
@@ -0,0 +1,96 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Minimal glob matching for rule options that select files, e.g.
+//! `src/**` or `**/mod.ts`.
+
+use deno_ast::ModuleSpecifier;
+
+/// Returns `true` if the path of the specifier matches the glob pattern.
+///
+/// Supported syntax is `**` (any number of path segments), `*` (any number
+/// of characters within a segment) and `?` (a single character). Patterns
+/// that don't start with `/` are relative, so they can match starting at any
+/// directory of the path, e.g. `src/*.ts` matches `/project/src/mod.ts`.
+pub(crate) fn matches_specifier(
+  pattern: &str,
+  specifier: &ModuleSpecifier,
+) -> bool {
+  matches_path(pattern, specifier.path())
+}
+
+pub(crate) fn matches_path(pattern: &str, path: &str) -> bool {
+  let path = path
+    .split('/')
+    .filter(|s| !s.is_empty())
+    .collect::<Vec<_>>();
+  let mut segments = pattern
+    .split('/')
+    .filter(|s| !s.is_empty())
+    .collect::<Vec<_>>();
+  if !pattern.starts_with('/') && segments.first() != Some(&"**") {
+    segments.insert(0, "**");
+  }
+  matches_segments(&segments, &path)
+}
+
+fn matches_segments(pattern: &[&str], path: &[&str]) -> bool {
+  match pattern.split_first() {
+    None => path.is_empty(),
+    Some((&"**", rest)) => {
+      (0..=path.len()).any(|skip| matches_segments(rest, &path[skip..]))
+    }
+    Some((segment, rest)) => match path.split_first() {
+      Some((first, path_rest)) => {
+        matches_segment(segment.as_bytes(), first.as_bytes())
+          && matches_segments(rest, path_rest)
+      }
+      None => false,
+    },
+  }
+}
+
+fn matches_segment(pattern: &[u8], text: &[u8]) -> bool {
+  match pattern.split_first() {
+    None => text.is_empty(),
+    Some((b'*', rest)) => {
+      (0..=text.len()).any(|skip| matches_segment(rest, &text[skip..]))
+    }
+    Some((b'?', rest)) => !text.is_empty() && matches_segment(rest, &text[1..]),
+    Some((c, rest)) => {
+      text.first() == Some(c) && matches_segment(rest, &text[1..])
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn glob_matches_path() {
+    let cases = [
+      ("src/**", "/project/src/mod.ts", true),
+      ("src/**", "/project/src/a/b/mod.ts", true),
+      ("src/**", "/project/tests/mod.ts", false),
+      ("**/mod.ts", "/project/src/mod.ts", true),
+      ("mod.ts", "/project/mod.ts", true),
+      ("mod.ts", "/project/src/mod.tsx", false),
+      ("src/*.ts", "/project/src/mod.ts", true),
+      ("src/*.ts", "/project/src/a/mod.ts", false),
+      ("src/**/*.ts", "/project/src/a/mod.ts", true),
+      ("src/**/*.ts", "/project/src/mod.ts", true),
+      ("/src/**", "/project/src/mod.ts", false),
+      ("/project/src/**", "/project/src/mod.ts", true),
+      ("main?.ts", "/project/main2.ts", true),
+      ("main?.ts", "/project/main.ts", false),
+      ("*_test.ts", "/project/src/foo_test.ts", true),
+    ];
+    for (pattern, path, expected) in cases {
+      assert_eq!(
+        matches_path(pattern, path),
+        expected,
+        "pattern: {pattern}, path: {path}"
+      );
+    }
+  }
+}
@@ -218,3 +218,49 @@ pub static GLOBALS: &[(&str, bool)] = &[
   ("WritableStreamDefaultController", false),
   ("WritableStreamDefaultWriter", false),
 ];
+
+/// Globals that are only available in browsers, in addition to the web APIs
+/// of [`GLOBALS`].
+pub static BROWSER_GLOBALS: &[&str] = &[
+  "cancelAnimationFrame",
+  "customElements",
+  "document",
+  "Document",
+  "DocumentFragment",
+  "DOMParser",
+  "Element",
+  "getComputedStyle",
+  "history",
+  "HTMLCanvasElement",
+  "HTMLElement",
+  "HTMLFormElement",
+  "HTMLInputElement",
+  "Image",
+  "IntersectionObserver",
+  "KeyboardEvent",
+  "localStorage",
+  "matchMedia",
+  "MouseEvent",
+  "MutationObserver",
+  "Node",
+  "NodeList",
+  "requestAnimationFrame",
+  "ResizeObserver",
+  "screen",
+  "sessionStorage",
+  "XMLHttpRequest",
+];
+
+/// Globals of Node.js, including the ones of CommonJS modules.
+pub static NODE_GLOBALS: &[&str] = &[
+  "__dirname",
+  "__filename",
+  "Buffer",
+  "clearImmediate",
+  "exports",
+  "global",
+  "module",
+  "process",
+  "require",
+  "setImmediate",
+];
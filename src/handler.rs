@@ -536,6 +536,12 @@ pub trait Traverse: Handler {
     // Make sure that `traverse_flow` is in initialized state
     ctx.assert_traverse_init();
 
+    // Abort the rule once it ran out of its time budget. It's reported by
+    // the linter after it returns.
+    if ctx.is_over_rule_budget() {
+      return;
+    }
+
     // First, invoke a handler that does anything we want when _entering_ a node.
     self.on_enter_node(node, ctx);
 
@@ -4,13 +4,18 @@ use deno_ast::SourceRangedForSpanned;
 use deno_ast::SourceTextInfoProvider;
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
+use crate::diagnostic::{LintDiagnostic, LintFixChange};
+use crate::rules::{find_rule_alias, program_ref};
+use crate::ProgramRef;
+use deno_ast::swc::ast::{JSXElement, JSXExprContainer, JSXFragment, Str, Tpl};
 use deno_ast::swc::common::comments::Comment;
 use deno_ast::swc::common::comments::CommentKind;
+use deno_ast::swc::visit::{Visit, VisitWith};
 use deno_ast::view as ast_view;
-use deno_ast::RootNode;
+use deno_ast::{RootNode, SourcePos};
 use once_cell::sync::Lazy;
 use regex::Regex;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 pub type LineIgnoreDirective = IgnoreDirective<Line>;
 pub type FileIgnoreDirective = IgnoreDirective<File>;
@@ -47,16 +52,26 @@ impl<T: DirectiveKind> IgnoreDirective<T> {
     self.codes.contains_key(code)
   }
 
+  /// Marks the codes that ignore `diagnostic_code` as used, including the
+  /// aliases of removed rules that were replaced by it.
   pub fn check_used(&mut self, diagnostic_code: &str) -> bool {
-    if let Some(status) = self.codes.get_mut(diagnostic_code) {
-      status.mark_as_used();
-      true
-    } else {
-      false
+    let mut used = false;
+    for (code, status) in self.codes.iter_mut() {
+      if resolve_rule_alias(code) == diagnostic_code {
+        status.mark_as_used();
+        used = true;
+      }
     }
+    used
   }
 }
 
+/// Returns the code of the rule that replaced `code` if it's an alias, or
+/// `code` itself.
+pub(crate) fn resolve_rule_alias(code: &str) -> &str {
+  find_rule_alias(code).map_or(code, |alias| alias.replacement)
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct CodeStatus {
   pub used: bool,
@@ -173,6 +188,184 @@ fn parse_ignore_comment<T: DirectiveKind>(
   None
 }
 
+/// Creates the changes that insert line ignore directives above the lines of
+/// the diagnostics. The codes are appended to a directive that already exists
+/// on the line above, and lines that start inside of JSX children get the
+/// directive in an expression container, e.g. `{// deno-lint-ignore foo\n}`.
+///
+/// Lines that start inside of a string, template or block comment can't be
+/// preceded by a directive, so their diagnostics are skipped.
+pub(crate) fn ignore_directive_changes(
+  ignore_diagnostic_directive: &str,
+  program: ast_view::Program,
+  diagnostics: &[LintDiagnostic],
+) -> Vec<LintFixChange> {
+  let text_info = program.text_info();
+  let mut codes_by_line = BTreeMap::<usize, Vec<&str>>::new();
+  for diagnostic in diagnostics {
+    let Some(range) = &diagnostic.range else {
+      continue;
+    };
+    let codes = codes_by_line
+      .entry(text_info.line_index(range.range.start))
+      .or_default();
+    if !codes.contains(&diagnostic.details.code.as_str()) {
+      codes.push(&diagnostic.details.code);
+    }
+  }
+  if codes_by_line.is_empty() {
+    return vec![];
+  }
+
+  let line_directives =
+    parse_line_ignore_directives(ignore_diagnostic_directive, program);
+  let regions = Regions::collect(program);
+  let mut changes = vec![];
+  for (line, codes) in codes_by_line {
+    let existing = line
+      .checked_sub(1)
+      .and_then(|line| line_directives.get(&line));
+    if let Some(directive) = existing {
+      if directive.ignore_all() {
+        continue;
+      }
+      let codes = codes
+        .into_iter()
+        .filter(|code| !directive.has_code(code))
+        .collect::<Vec<_>>();
+      if codes.is_empty() {
+        continue;
+      }
+      // Append the codes before the reason, if there's one.
+      let text = directive.range().text_fast(text_info);
+      let end = match text.find("--") {
+        Some(index) => text[..index].trim_end().len(),
+        None => text.trim_end().len(),
+      };
+      let pos = directive.range().start + end;
+      changes.push(LintFixChange {
+        new_text: format!(" {}", codes.join(" ")).into(),
+        range: SourceRange::new(pos, pos),
+      });
+      continue;
+    }
+
+    let line_start = text_info.line_start(line);
+    if regions.is_inside_text(line_start) {
+      continue;
+    }
+    let line_text = text_info.line_text(line);
+    let indent = &line_text[..line_text.len() - line_text.trim_start().len()];
+    let comment =
+      format!("// {} {}", ignore_diagnostic_directive, codes.join(" "));
+    let first_token = line_start + indent.len();
+    changes.push(if regions.is_jsx_child(first_token) {
+      LintFixChange {
+        new_text: format!("{{\n{indent}  {comment}\n{indent}}}").into(),
+        range: SourceRange::new(first_token, first_token),
+      }
+    } else {
+      LintFixChange {
+        new_text: format!("{indent}{comment}\n").into(),
+        range: SourceRange::new(line_start, line_start),
+      }
+    });
+  }
+  changes
+}
+
+/// Ranges of the program where a line comment has a different meaning.
+#[derive(Default)]
+struct Regions {
+  /// Children of JSX elements and fragments.
+  jsx_children: Vec<SourceRange>,
+  /// JSX expression containers, which are JavaScript inside of JSX.
+  jsx_exprs: Vec<SourceRange>,
+  /// Strings, templates and block comments.
+  texts: Vec<SourceRange>,
+}
+
+impl Regions {
+  fn collect(program: ast_view::Program) -> Self {
+    let mut regions = Regions::default();
+    match program_ref(program) {
+      ProgramRef::Module(m) => m.visit_with(&mut regions),
+      ProgramRef::Script(s) => s.visit_with(&mut regions),
+    }
+    regions.texts.extend(
+      program
+        .comment_container()
+        .all_comments()
+        .filter(|comment| comment.kind == CommentKind::Block)
+        .map(|comment| comment.range()),
+    );
+    regions
+  }
+
+  fn is_inside_text(&self, pos: SourcePos) -> bool {
+    self
+      .texts
+      .iter()
+      .any(|range| range.start < pos && pos <= range.end)
+  }
+
+  /// Checks if the innermost JSX region that contains the position is the
+  /// children of an element rather than an expression container.
+  fn is_jsx_child(&self, pos: SourcePos) -> bool {
+    let child = self
+      .jsx_children
+      .iter()
+      .filter(|range| range.start <= pos && pos <= range.end)
+      .map(|range| range.start)
+      .max();
+    let expr = self
+      .jsx_exprs
+      .iter()
+      .filter(|range| range.start < pos && pos < range.end)
+      .map(|range| range.start)
+      .max();
+    match (child, expr) {
+      (Some(child), Some(expr)) => child > expr,
+      (child, _) => child.is_some(),
+    }
+  }
+}
+
+impl Visit for Regions {
+  fn visit_jsx_element(&mut self, element: &JSXElement) {
+    if let Some(closing) = &element.closing {
+      self
+        .jsx_children
+        .push(SourceRange::new(element.opening.end(), closing.start()));
+    }
+    element.visit_children_with(self);
+  }
+
+  fn visit_jsx_fragment(&mut self, fragment: &JSXFragment) {
+    self.jsx_children.push(SourceRange::new(
+      fragment.opening.end(),
+      fragment.closing.start(),
+    ));
+    fragment.visit_children_with(self);
+  }
+
+  fn visit_jsx_expr_container(&mut self, container: &JSXExprContainer) {
+    self.jsx_exprs.push(container.range());
+    container.visit_children_with(self);
+  }
+
+  fn visit_str(&mut self, str: &Str) {
+    self.texts.push(str.range());
+  }
+
+  fn visit_tpl(&mut self, tpl: &Tpl) {
+    self
+      .texts
+      .extend(tpl.quasis.iter().map(|quasi| quasi.range()));
+    tpl.visit_children_with(self);
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -6,7 +6,25 @@ mod validator;
 
 use std::fmt;
 
-pub use validator::{EcmaRegexValidator, EcmaVersion};
+pub use unicode::is_combining_mark;
+pub use validator::{
+  EcmaRegexValidator, EcmaVersion, RegexBackreference, RegexCharacter,
+  RegexGroup,
+};
+
+/// Converts a position in a pattern, as used by
+/// [`RegexPattern`](validator::RegexPattern), to a byte offset in the source
+/// of the pattern.
+pub fn byte_offset(source: &str, position: usize, u_flag: bool) -> usize {
+  let mut units = 0;
+  for (offset, c) in source.char_indices() {
+    if units >= position {
+      return offset;
+    }
+    units += if u_flag { 1 } else { c.len_utf16() };
+  }
+  source.len()
+}
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Copy)]
 pub struct UnicodeChar {
@@ -1335,4 +1353,84 @@ mod tests {
       Ok(())
     );
   }
+
+  #[test]
+  fn parse_characters() {
+    let mut validator = EcmaRegexValidator::new(EcmaVersion::Es2022);
+    let characters = validator
+      .parse_characters("a\\x62[c\\d-e]\\n", false)
+      .unwrap();
+    let summary = characters
+      .iter()
+      .map(|c| (c.value, c.raw.as_str(), c.start, c.class))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      summary,
+      vec![
+        (0x61, "a", 0, None),
+        (0x62, "\\x62", 1, None),
+        (0x63, "c", 6, Some(0)),
+        (0x65, "e", 10, Some(0)),
+        (0x0a, "\\n", 12, None),
+      ]
+    );
+
+    // Without the `u` flag, characters outside the BMP are split.
+    let characters = validator.parse_characters("[👍]", false).unwrap();
+    let values = characters.iter().map(|c| c.value).collect::<Vec<_>>();
+    assert_eq!(values, vec![0xd83d, 0xdc4d]);
+    let characters = validator.parse_characters("[👍]", true).unwrap();
+    let values = characters.iter().map(|c| c.value).collect::<Vec<_>>();
+    assert_eq!(values, vec![0x1f44d]);
+
+    assert!(validator.parse_characters("[a", false).is_err());
+  }
+
+  #[test]
+  fn parse() {
+    let mut validator = EcmaRegexValidator::new(EcmaVersion::Es2022);
+    let pattern = validator
+      .parse("(a)(?:b|c)+\\1(?<n>d)\\k<n>", false)
+      .unwrap();
+    assert_eq!(pattern.alternatives.len(), 1);
+    assert_eq!(pattern.alternatives[0].len(), 5);
+    let summary = pattern
+      .groups
+      .iter()
+      .map(|g| {
+        (
+          g.start,
+          g.end,
+          g.content_start,
+          g.capturing.clone(),
+          g.alternatives.len(),
+          g.quantified,
+        )
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(
+      summary,
+      vec![
+        (0, 3, 1, Some((1, None)), 1, false),
+        (3, 10, 6, None, 2, true),
+        (13, 20, 18, Some((2, Some("n".to_string()))), 1, false),
+      ]
+    );
+    assert_eq!(
+      pattern.backreferences,
+      vec![
+        RegexBackreference::Index(1),
+        RegexBackreference::Name("n".to_string())
+      ]
+    );
+
+    assert!(validator.parse("(a", false).is_err());
+  }
+
+  #[test]
+  fn byte_offset_test() {
+    assert_eq!(byte_offset("a👍b", 3, false), 5);
+    assert_eq!(byte_offset("a👍b", 2, true), 5);
+    assert_eq!(byte_offset("éa", 1, false), 2);
+  }
 }
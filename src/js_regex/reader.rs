@@ -121,6 +121,16 @@ impl Reader {
     }
   }
 
+  /// Returns the source between the two positions.
+  pub fn slice(&self, start: usize, end: usize) -> String {
+    if self.unicode {
+      self.src.chars().skip(start).take(end - start).collect()
+    } else {
+      let units = self.src.encode_utf16().skip(start).take(end - start);
+      String::from_utf16_lossy(&units.collect::<Vec<_>>())
+    }
+  }
+
   fn at(&self, i: usize) -> Option<UnicodeChar> {
     if i >= self.end {
       None
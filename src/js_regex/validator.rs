@@ -3,6 +3,8 @@
 use std::collections::HashSet;
 use std::ops::{Deref, DerefMut};
 
+use serde::Deserialize;
+
 use super::reader::Reader;
 use super::{unicode::*, UnicodeChar};
 
@@ -91,8 +93,12 @@ fn combine_surrogate_pair(lead: i64, trail: i64) -> i64 {
   (lead - 0xd800) * 0x400 + (trail - 0xdc00) + 0x10000
 }
 
-#[allow(dead_code)]
-#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+/// The version of ECMAScript that patterns are validated against, e.g. the
+/// oldest version supported by the runtimes a project targets.
+#[derive(
+  Clone, Copy, Debug, Default, Ord, PartialOrd, Eq, PartialEq, Hash, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
 pub enum EcmaVersion {
   Es5,
   Es2015,
@@ -103,6 +109,73 @@ pub enum EcmaVersion {
   Es2020,
   Es2021,
   Es2022,
+  Es2023,
+  #[default]
+  Es2024,
+}
+
+/// A character matched by a pattern, as returned by
+/// [`EcmaRegexValidator::parse_characters`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegexCharacter {
+  /// The code point of the character. Without the `u` flag, characters are
+  /// UTF-16 code units, so characters outside the BMP are split into
+  /// surrogates.
+  pub value: u32,
+  /// The source of the character, e.g. `a` or `\x61`.
+  pub raw: String,
+  /// The position of the character in the pattern, in code points with the
+  /// `u` flag and in UTF-16 code units without it.
+  pub start: usize,
+  pub end: usize,
+  /// The index of the character class containing the character, counted
+  /// from the start of the pattern.
+  pub class: Option<usize>,
+}
+
+/// The terms, groups and characters of a pattern, as returned by
+/// [`EcmaRegexValidator::parse`]. Positions are in code points with the `u`
+/// flag and in UTF-16 code units without it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RegexPattern {
+  /// The alternatives of the pattern, separated by `|`.
+  pub alternatives: Vec<Vec<RegexTerm>>,
+  /// The groups in the pattern, in the order they end.
+  pub groups: Vec<RegexGroup>,
+  pub backreferences: Vec<RegexBackreference>,
+  pub characters: Vec<RegexCharacter>,
+}
+
+/// An assertion or an atom, with its quantifier.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegexTerm {
+  pub start: usize,
+  /// The end of the term including its quantifier.
+  pub end: usize,
+  pub assertion: bool,
+  pub quantified: bool,
+}
+
+/// A capturing group or a non-capturing group. Lookarounds are assertions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegexGroup {
+  /// The position of the opening parenthesis.
+  pub start: usize,
+  /// The position after the closing parenthesis.
+  pub end: usize,
+  /// The position of the content of the group, after `(`, `(?:` or
+  /// `(?<name>`.
+  pub content_start: usize,
+  /// For capturing groups, the group number and name.
+  pub capturing: Option<(u32, Option<String>)>,
+  pub alternatives: Vec<Vec<RegexTerm>>,
+  pub quantified: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RegexBackreference {
+  Index(u32),
+  Name(String),
 }
 
 #[derive(Debug)]
@@ -122,6 +195,15 @@ pub struct EcmaRegexValidator {
   num_capturing_parens: u32,
   group_names: HashSet<String>,
   backreference_names: HashSet<String>,
+  characters: Vec<RegexCharacter>,
+  current_class: Option<usize>,
+  num_classes: usize,
+  groups: Vec<RegexGroup>,
+  backreferences: Vec<RegexBackreference>,
+  /// The alternatives of the disjunctions being consumed.
+  disjunctions: Vec<Vec<Vec<RegexTerm>>>,
+  last_alternatives: Vec<Vec<RegexTerm>>,
+  last_group_index: u32,
 }
 
 impl Deref for EcmaRegexValidator {
@@ -156,6 +238,14 @@ impl EcmaRegexValidator {
       num_capturing_parens: 0,
       group_names: HashSet::new(),
       backreference_names: HashSet::new(),
+      characters: Vec::new(),
+      current_class: None,
+      num_classes: 0,
+      groups: Vec::new(),
+      backreferences: Vec::new(),
+      disjunctions: Vec::new(),
+      last_alternatives: Vec::new(),
+      last_group_index: 0,
     }
   }
 
@@ -176,7 +266,7 @@ impl EcmaRegexValidator {
         || (flag == 'y' && self.ecma_version >= EcmaVersion::Es2015)
         || (flag == 's' && self.ecma_version >= EcmaVersion::Es2018)
         || (flag == 'd' && self.ecma_version >= EcmaVersion::Es2022)
-        || (flag == 'v' && self.ecma_version >= EcmaVersion::Es2022)
+        || (flag == 'v' && self.ecma_version >= EcmaVersion::Es2024)
       {
         // do nothing
       } else {
@@ -195,8 +285,13 @@ impl EcmaRegexValidator {
     self.strict = u_flag; // TODO: allow toggling strict independently of u flag
     self.u_flag = u_flag && self.ecma_version >= EcmaVersion::Es2015;
     self.n_flag = u_flag && self.ecma_version >= EcmaVersion::Es2018;
-    //self.reset(source, 0, source.len(), u_flag);
-    self.reset(source, 0, source.chars().count(), u_flag);
+    // Without the `u` flag, the pattern is read in UTF-16 code units.
+    let end = if u_flag {
+      source.chars().count()
+    } else {
+      source.encode_utf16().count()
+    };
+    self.reset(source, 0, end, u_flag);
     self.consume_pattern()?;
 
     if !self.n_flag
@@ -211,6 +306,98 @@ impl EcmaRegexValidator {
     Ok(())
   }
 
+  /// Validates the pattern of a EcmaScript regular expression like
+  /// [`EcmaRegexValidator::validate_pattern`], and returns the characters it
+  /// matches, in the order they appear. Escapes like `\x61` are resolved,
+  /// while character class escapes like `\d` and assertions aren't
+  /// included.
+  pub fn parse_characters(
+    &mut self,
+    source: &str,
+    u_flag: bool,
+  ) -> Result<Vec<RegexCharacter>, String> {
+    self.validate_pattern(source, u_flag)?;
+    Ok(std::mem::take(&mut self.characters))
+  }
+
+  /// Validates the pattern of a EcmaScript regular expression like
+  /// [`EcmaRegexValidator::validate_pattern`], and returns its structure.
+  pub fn parse(
+    &mut self,
+    source: &str,
+    u_flag: bool,
+  ) -> Result<RegexPattern, String> {
+    self.validate_pattern(source, u_flag)?;
+    Ok(RegexPattern {
+      alternatives: std::mem::take(&mut self.last_alternatives),
+      groups: std::mem::take(&mut self.groups),
+      backreferences: std::mem::take(&mut self.backreferences),
+      characters: std::mem::take(&mut self.characters),
+    })
+  }
+
+  /// Records the term between `start` and the current position. `atom_end`
+  /// is the end of the term without its quantifier.
+  fn on_term(&mut self, start: usize, atom_end: usize, assertion: bool) {
+    let end = self.index();
+    let quantified = atom_end != end;
+    if quantified {
+      if let Some(group) = self
+        .groups
+        .iter_mut()
+        .rev()
+        .find(|group| group.start == start && group.end == atom_end)
+      {
+        group.quantified = true;
+      }
+    }
+    if let Some(alternative) = self
+      .disjunctions
+      .last_mut()
+      .and_then(|alternatives| alternatives.last_mut())
+    {
+      alternative.push(RegexTerm {
+        start,
+        end,
+        assertion,
+        quantified,
+      });
+    }
+  }
+
+  /// Records the group between `start` and the current position, whose
+  /// content are the last consumed alternatives.
+  fn on_group(
+    &mut self,
+    start: usize,
+    content_start: usize,
+    capturing: Option<(u32, Option<String>)>,
+  ) {
+    let alternatives = std::mem::take(&mut self.last_alternatives);
+    self.groups.push(RegexGroup {
+      start,
+      end: self.index(),
+      content_start,
+      capturing,
+      alternatives,
+      quantified: false,
+    });
+  }
+
+  /// Records the character ending at the current position, whose value is
+  /// `self.last_int_value`.
+  fn on_character(&mut self, start: usize) {
+    let end = self.index();
+    let raw = self.slice(start, end);
+    self.characters.push(RegexCharacter {
+      value: self.last_int_value as u32,
+      raw,
+      start,
+      end,
+      class: self.current_class,
+    });
+  }
+
   /// Validate the next characters as a RegExp `Pattern` production.
   /// ```grammar
   /// Pattern[U, N]::
@@ -220,6 +407,12 @@ impl EcmaRegexValidator {
     self.num_capturing_parens = self.count_capturing_parens();
     self.group_names.clear();
     self.backreference_names.clear();
+    self.characters.clear();
+    self.num_classes = 0;
+    self.groups.clear();
+    self.backreferences.clear();
+    self.disjunctions.clear();
+    self.last_group_index = 0;
 
     self.consume_disjunction()?;
 
@@ -251,10 +444,15 @@ impl EcmaRegexValidator {
   ///      Alternative[?U, ?N] `|` Disjunction[?U, ?N]
   /// ```
   fn consume_disjunction(&mut self) -> Result<(), String> {
+    self.disjunctions.push(vec![vec![]]);
     self.consume_alternative()?;
     while self.eat('|') {
+      if let Some(alternatives) = self.disjunctions.last_mut() {
+        alternatives.push(vec![]);
+      }
       self.consume_alternative()?;
     }
+    self.last_alternatives = self.disjunctions.pop().unwrap_or_default();
 
     if self.consume_quantifier(true)? {
       Err("Nothing to repeat".to_string())
@@ -294,25 +492,27 @@ impl EcmaRegexValidator {
   /// ```
   /// Returns `true` if it consumed the next characters successfully.
   fn consume_term(&mut self) -> Result<bool, String> {
-    if self.u_flag || self.strict {
-      Ok(
-        self.consume_assertion()?
-          || (self.consume_atom()? && self.consume_optional_quantifier()?),
-      )
-    } else {
-      Ok(
-        (self.consume_assertion()?
-          && (!self.last_assertion_is_quantifiable
-            || self.consume_optional_quantifier()?))
-          || (self.consume_extended_atom()?
-            && self.consume_optional_quantifier()?),
-      )
+    let start = self.index();
+    if self.consume_assertion()? {
+      let assertion_end = self.index();
+      if !self.u_flag && !self.strict && self.last_assertion_is_quantifiable {
+        self.consume_quantifier(false)?;
+      }
+      self.on_term(start, assertion_end, true);
+      return Ok(true);
     }
-  }
 
-  fn consume_optional_quantifier(&mut self) -> Result<bool, String> {
-    self.consume_quantifier(false)?;
-    Ok(true)
+    let consumed = if self.u_flag || self.strict {
+      self.consume_atom()?
+    } else {
+      self.consume_extended_atom()?
+    };
+    if consumed {
+      let atom_end = self.index();
+      self.consume_quantifier(false)?;
+      self.on_term(start, atom_end, false);
+    }
+    Ok(consumed)
   }
 
   /// Validate the next characters as a RegExp `Term` production if possible.
@@ -480,11 +680,14 @@ impl EcmaRegexValidator {
   /// ```
   /// Returns `true` if it consumed the next characters successfully.
   fn consume_uncapturing_group(&mut self) -> Result<bool, String> {
+    let start = self.index();
     if self.eat3('(', '?', ':') {
+      let content_start = self.index();
       self.consume_disjunction()?;
       if !self.eat(')') {
         Err("Unterminated group".to_string())
       } else {
+        self.on_group(start, content_start, None);
         Ok(true)
       }
     } else {
@@ -498,20 +701,28 @@ impl EcmaRegexValidator {
   /// ```
   /// Returns `true` if it consumed the next characters successfully.
   fn consume_capturing_group(&mut self) -> Result<bool, String> {
+    let start = self.index();
     if !self.eat('(') {
       return Ok(false);
     }
+    self.last_group_index += 1;
+    let index = self.last_group_index;
 
+    let mut name = None;
     if self.ecma_version >= EcmaVersion::Es2018 {
-      self.consume_group_specifier()?;
+      if self.consume_group_specifier()? {
+        name = Some(self.last_str_value.clone());
+      }
     } else if self.code_point_value_with_offset(0) == Some('?' as u32) {
       return Err("Invalid group".to_string());
     }
 
+    let content_start = self.index();
     self.consume_disjunction()?;
     if !self.eat(')') {
       return Err("Unterminated group".to_string());
     }
+    self.on_group(start, content_start, Some((index, name)));
     Ok(true)
   }
 
@@ -550,8 +761,10 @@ impl EcmaRegexValidator {
     if self.code_point_value_with_offset(0) == Some('\\' as u32)
       && self.code_point_value_with_offset(1) == Some('c' as u32)
     {
+      let start = self.index();
       self.last_int_value = '\\' as i64;
       self.advance();
+      self.on_character(start);
       true
     } else {
       false
@@ -585,7 +798,10 @@ impl EcmaRegexValidator {
   fn consume_pattern_character(&mut self) -> bool {
     if let Some(cp) = self.code_point_with_offset(0) {
       if !is_syntax_character(cp) {
+        let start = self.index();
+        self.last_int_value = cp.to_i64();
         self.advance();
+        self.on_character(start);
         return true;
       }
     }
@@ -613,7 +829,10 @@ impl EcmaRegexValidator {
         && cp != '['
         && cp != '|'
       {
+        let start = self.index();
+        self.last_int_value = cp.to_i64();
         self.advance();
+        self.on_character(start);
         return true;
       }
     }
@@ -658,11 +877,14 @@ impl EcmaRegexValidator {
   /// ```
   /// Returns `Ok(true)` if it consumed the next characters successfully.
   fn consume_atom_escape(&mut self) -> Result<bool, String> {
-    if self.consume_backreference()?
-      || self.consume_character_class_escape()?
-      || self.consume_character_escape()?
-      || (self.n_flag && self.consume_k_group_name()?)
-    {
+    // The escape follows a `\`.
+    let start = self.index() - 1;
+    if self.consume_backreference()? || self.consume_character_class_escape()? {
+      Ok(true)
+    } else if self.consume_character_escape()? {
+      self.on_character(start);
+      Ok(true)
+    } else if self.n_flag && self.consume_k_group_name()? {
       Ok(true)
     } else if self.strict || self.u_flag {
       Err("Invalid escape".to_string())
@@ -682,6 +904,9 @@ impl EcmaRegexValidator {
     let start = self.index();
     if self.eat_decimal_escape() {
       if self.last_int_value <= self.num_capturing_parens as i64 {
+        self
+          .backreferences
+          .push(RegexBackreference::Index(self.last_int_value as u32));
         return Ok(true);
       } else if self.strict || self.u_flag {
         return Err("Invalid escape");
@@ -769,7 +994,10 @@ impl EcmaRegexValidator {
     if self.eat('k') {
       if self.eat_group_name()? {
         let group_name = self.last_str_value.clone();
-        self.backreference_names.insert(group_name);
+        self.backreference_names.insert(group_name.clone());
+        self
+          .backreferences
+          .push(RegexBackreference::Name(group_name));
         return Ok(true);
       }
       return Err("Invalid named reference".to_string());
@@ -788,7 +1016,11 @@ impl EcmaRegexValidator {
     if !self.eat('[') {
       return Ok(false);
     }
-    self.consume_class_ranges()?;
+    self.current_class = Some(self.num_classes);
+    self.num_classes += 1;
+    let result = self.consume_class_ranges();
+    self.current_class = None;
+    result?;
     if !self.eat(']') {
       return Err("Unterminated character class".to_string());
     }
@@ -862,18 +1094,24 @@ impl EcmaRegexValidator {
       if cp != '\\' && cp != ']' {
         self.advance();
         self.last_int_value = cp.to_i64();
+        self.on_character(start);
         return Ok(true);
       }
     }
 
     if self.eat('\\') {
       if self.consume_class_escape()? {
+        // Character class escapes like `\d` aren't characters.
+        if self.last_int_value >= 0 {
+          self.on_character(start);
+        }
         return Ok(true);
       }
       if !self.strict
         && self.code_point_value_with_offset(0) == Some('c' as u32)
       {
         self.last_int_value = '\\' as i64;
+        self.on_character(start);
         return Ok(true);
       }
       if self.strict || self.u_flag {
@@ -0,0 +1,413 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! A lightweight parser of JSDoc comments, which splits them into the
+//! description and the block tags like `@param {string} name Description`,
+//! keeping the source ranges of the parts that rules report on.
+
+use deno_ast::swc::ast::{TsKeywordTypeKind, TsType};
+use deno_ast::swc::common::comments::{Comment, CommentKind};
+use deno_ast::view::{Node, NodeTrait};
+use deno_ast::{SourcePos, SourceRange, SourceRanged, SourceRangedForSpanned};
+
+/// Tags that are followed by a name, after the optional type.
+const NAMED_TAGS: &[&str] = &[
+  "param", "arg", "argument", "property", "prop", "template", "typedef",
+  "callback",
+];
+
+/// A parsed JSDoc comment, see `Context::leading_jsdoc_at`.
+#[derive(Debug)]
+pub struct JsDoc {
+  /// Range of the whole comment.
+  pub range: SourceRange,
+  /// The text before the first tag.
+  pub description: String,
+  pub tags: Vec<JsDocTag>,
+}
+
+/// A block tag of a JSDoc comment, e.g. `@param {string} name Description`.
+#[derive(Debug)]
+pub struct JsDocTag {
+  /// Name of the tag without the `@`, e.g. `param`.
+  pub name: String,
+  /// Range of the whole tag, from the `@` to the end of its description.
+  pub range: SourceRange,
+  /// Range of the tag name, including the `@`.
+  pub name_range: SourceRange,
+  /// The type in braces, e.g. `string` in `@param {string} name`.
+  pub type_expr: Option<JsDocText>,
+  /// The documented name of tags like `@param`, without the brackets of
+  /// optional names like `[name=value]`.
+  pub value_name: Option<JsDocText>,
+  /// The text after the type and name, which may span multiple lines.
+  pub description: String,
+}
+
+/// A part of a tag, along with where it is in the source.
+#[derive(Debug)]
+pub struct JsDocText {
+  pub text: String,
+  pub range: SourceRange,
+}
+
+impl JsDoc {
+  /// Parses a comment if it's a JSDoc comment, i.e. a block comment starting
+  /// with `/**`.
+  pub fn parse(comment: &Comment) -> Option<JsDoc> {
+    if comment.kind != CommentKind::Block
+      || !comment.text.starts_with('*')
+      || comment.text.starts_with("**")
+    {
+      return None;
+    }
+    // Skips the `/**`.
+    let lines = content_lines(&comment.text[1..], comment.start() + 3);
+
+    let mut description = vec![];
+    let mut tags: Vec<Vec<Line>> = vec![];
+    let mut in_code_block = false;
+    for line in lines {
+      let trimmed = line.text.trim_start();
+      if trimmed.starts_with("```") {
+        in_code_block = !in_code_block;
+      } else if !in_code_block && tag_name_len(trimmed) > 0 {
+        let offset = line.text.len() - trimmed.len();
+        tags.push(vec![Line {
+          text: trimmed,
+          start: line.start + offset,
+        }]);
+        continue;
+      }
+      match tags.last_mut() {
+        Some(tag) => tag.push(line),
+        None => description.push(line.text),
+      }
+    }
+
+    Some(JsDoc {
+      range: comment.range(),
+      description: description.join("\n").trim().to_string(),
+      tags: tags.iter().map(|lines| parse_tag(lines)).collect(),
+    })
+  }
+
+  /// Checks if the comment has a tag with one of the names, e.g. `returns`
+  /// and its synonym `return`.
+  pub fn has_tag(&self, names: &[&str]) -> bool {
+    self
+      .tags
+      .iter()
+      .any(|tag| names.contains(&tag.name.as_str()))
+  }
+}
+
+/// A line of a comment without the leading `*`, and where it starts.
+#[derive(Clone, Copy)]
+struct Line<'a> {
+  text: &'a str,
+  start: SourcePos,
+}
+
+fn content_lines(text: &str, start: SourcePos) -> Vec<Line> {
+  let mut lines = vec![];
+  let mut line_start = 0;
+  for (index, line) in text.split('\n').enumerate() {
+    let mut offset = line_start;
+    line_start += line.len() + 1;
+    let mut content = line.trim_end_matches('\r');
+    if index > 0 {
+      let trimmed = content.trim_start();
+      if let Some(rest) = trimmed.strip_prefix('*') {
+        offset += content.len() - rest.len();
+        content = rest;
+        // One space after the `*` separates it from the content, any other
+        // indentation is kept, e.g. for code examples.
+        if let Some(rest) = content.strip_prefix(' ') {
+          offset += 1;
+          content = rest;
+        }
+      }
+    }
+    lines.push(Line {
+      text: content,
+      start: start + offset,
+    });
+  }
+  lines
+}
+
+fn tag_name_len(text: &str) -> usize {
+  let Some(rest) = text.strip_prefix('@') else {
+    return 0;
+  };
+  rest
+    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '-'))
+    .unwrap_or(rest.len())
+}
+
+/// The text of a tag spanning multiple lines, which maps offsets in the text
+/// back to source positions.
+struct TagText {
+  text: String,
+  /// Offsets of the lines in `text`, and where they start in the source.
+  line_starts: Vec<(usize, SourcePos)>,
+}
+
+impl TagText {
+  fn new(lines: &[Line]) -> Self {
+    let mut text = String::new();
+    let mut line_starts = vec![];
+    for line in lines {
+      if !text.is_empty() {
+        text.push('\n');
+      }
+      line_starts.push((text.len(), line.start));
+      text.push_str(line.text);
+    }
+    Self { text, line_starts }
+  }
+
+  fn pos(&self, offset: usize) -> SourcePos {
+    let (line_offset, line_start) = self
+      .line_starts
+      .iter()
+      .rev()
+      .find(|(line_offset, _)| *line_offset <= offset)
+      .copied()
+      .unwrap_or(self.line_starts[0]);
+    line_start + (offset - line_offset)
+  }
+
+  fn range(&self, start: usize, end: usize) -> SourceRange {
+    SourceRange::new(self.pos(start), self.pos(end))
+  }
+}
+
+fn parse_tag(lines: &[Line]) -> JsDocTag {
+  let tag = TagText::new(lines);
+  let text = tag.text.as_str();
+  let name_len = tag_name_len(text);
+  let name = text[1..=name_len].to_string();
+  let name_range = tag.range(0, name_len + 1);
+
+  let skip_whitespace = |offset: usize| {
+    offset + (text[offset..].len() - text[offset..].trim_start().len())
+  };
+  let mut offset = skip_whitespace(name_len + 1);
+
+  let mut type_expr = None;
+  if text[offset..].starts_with('{') {
+    if let Some(len) = type_len(&text[offset..]) {
+      type_expr = Some(JsDocText {
+        text: text[offset + 1..offset + len - 1].trim().to_string(),
+        range: tag.range(offset, offset + len),
+      });
+      offset = skip_whitespace(offset + len);
+    }
+  }
+
+  let mut value_name = None;
+  if NAMED_TAGS.contains(&name.as_str()) {
+    let rest = &text[offset..];
+    let parsed = match rest.strip_prefix('[') {
+      Some(optional) => optional.find(']').map(|end| {
+        let name_end = optional[..end].find('=').unwrap_or(end);
+        (
+          offset + 1,
+          optional[..name_end].trim_end(),
+          offset + end + 2,
+        )
+      }),
+      None => {
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        Some((offset, &rest[..end], offset + end))
+      }
+    };
+    if let Some((start, value, end)) = parsed {
+      if !value.is_empty() {
+        value_name = Some(JsDocText {
+          text: value.to_string(),
+          range: tag.range(start, start + value.len()),
+        });
+        offset = skip_whitespace(end);
+        if let Some(rest) = text[offset..].strip_prefix('-') {
+          if rest.is_empty() || rest.starts_with(char::is_whitespace) {
+            offset = skip_whitespace(offset + 1);
+          }
+        }
+      }
+    }
+  }
+
+  JsDocTag {
+    range: tag.range(0, text.trim_end().len()),
+    name,
+    name_range,
+    type_expr,
+    value_name,
+    description: text[offset..].trim_end().to_string(),
+  }
+}
+
+/// Returns the length of a type expression in braces like `{string}` at the
+/// start of `text`.
+fn type_len(text: &str) -> Option<usize> {
+  let mut depth = 0;
+  for (index, c) in text.char_indices() {
+    match c {
+      '{' => depth += 1,
+      '}' => {
+        depth -= 1;
+        if depth == 0 {
+          return Some(index + 1);
+        }
+      }
+      _ => {}
+    }
+  }
+  None
+}
+
+/// Returns the start of the declaration a function belongs to, which its
+/// JSDoc comment is attached to, e.g. `export const` in
+/// `export const foo = () => {}`.
+pub(crate) fn owner_start(node: Node) -> SourcePos {
+  let mut node = node;
+  while let Some(
+    parent @ (Node::ParenExpr(_)
+    | Node::VarDeclarator(_)
+    | Node::VarDecl(_)
+    | Node::ExportDecl(_)
+    | Node::ExportDefaultDecl(_)
+    | Node::ExportDefaultExpr(_)
+    | Node::KeyValueProp(_)
+    | Node::ClassProp(_)),
+  ) = node.parent()
+  {
+    node = parent;
+  }
+  node.start()
+}
+
+/// Checks if a return type is `void`, or `Promise<void>` for async
+/// functions.
+pub(crate) fn is_void(ts_type: &TsType, is_async: bool) -> bool {
+  match ts_type {
+    TsType::TsKeywordType(keyword) => {
+      keyword.kind == TsKeywordTypeKind::TsVoidKeyword
+    }
+    // `Promise<void>` of async functions.
+    TsType::TsTypeRef(type_ref) if is_async => {
+      type_ref
+        .type_name
+        .as_ident()
+        .map_or(false, |ident| ident.sym == *"Promise")
+        && type_ref.type_params.as_ref().map_or(false, |params| {
+          params.params.len() == 1 && is_void(&params.params[0], false)
+        })
+    }
+    _ => false,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util;
+  use deno_ast::StartSourcePos;
+
+  fn parse(src: &str) -> (String, JsDoc) {
+    let parsed_source = test_util::parse(src);
+    let comment = parsed_source.comments().get_vec().remove(0);
+    let jsdoc = JsDoc::parse(&comment).expect("expected a JSDoc comment");
+    (src.to_string(), jsdoc)
+  }
+
+  fn text_at(src: &str, range: SourceRange) -> &str {
+    &src[range.as_byte_range(StartSourcePos::START_SOURCE_POS)]
+  }
+
+  #[test]
+  fn ignores_non_jsdoc_comments() {
+    let parsed_source = test_util::parse("/* a */ // b\n/*** c */");
+    for comment in parsed_source.comments().get_vec() {
+      assert!(JsDoc::parse(&comment).is_none());
+    }
+  }
+
+  #[test]
+  fn description_and_tags() {
+    let (src, jsdoc) = parse(
+      r#"/**
+ * Adds two numbers.
+ *
+ * @param {number} a - The first number.
+ * @param {number} [b=1] The second
+ *   number.
+ * @returns {number}
+ */
+function add(a, b) {}"#,
+    );
+    assert_eq!(jsdoc.description, "Adds two numbers.");
+    assert_eq!(jsdoc.tags.len(), 3);
+
+    let a = &jsdoc.tags[0];
+    assert_eq!(a.name, "param");
+    assert_eq!(text_at(&src, a.name_range), "@param");
+    assert_eq!(a.type_expr.as_ref().unwrap().text, "number");
+    let a_name = a.value_name.as_ref().unwrap();
+    assert_eq!(a_name.text, "a");
+    assert_eq!(text_at(&src, a_name.range), "a");
+    assert_eq!(a.description, "The first number.");
+    assert_eq!(
+      text_at(&src, a.range),
+      "@param {number} a - The first number."
+    );
+
+    let b = &jsdoc.tags[1];
+    let b_name = b.value_name.as_ref().unwrap();
+    assert_eq!(b_name.text, "b");
+    assert_eq!(text_at(&src, b_name.range), "b");
+    assert_eq!(b.description, "The second\n  number.");
+
+    let returns = &jsdoc.tags[2];
+    assert_eq!(returns.name, "returns");
+    assert!(returns.value_name.is_none());
+    assert_eq!(
+      text_at(&src, returns.type_expr.as_ref().unwrap().range),
+      "{number}"
+    );
+    assert_eq!(returns.description, "");
+  }
+
+  #[test]
+  fn multi_line_type() {
+    let (src, jsdoc) = parse(
+      "/**\n * @param {{\n *   a: string,\n * }} options\n */\nfunction f(options) {}",
+    );
+    let tag = &jsdoc.tags[0];
+    assert_eq!(tag.type_expr.as_ref().unwrap().text, "{\n  a: string,\n}");
+    let name = tag.value_name.as_ref().unwrap();
+    assert_eq!(name.text, "options");
+    assert_eq!(text_at(&src, name.range), "options");
+  }
+
+  #[test]
+  fn skips_code_blocks() {
+    let (_, jsdoc) = parse(
+      "/**\n * Example:\n * ```ts\n * @decorator class A {}\n * ```\n * @deprecated Use `b`.\n */\nfunction a() {}",
+    );
+    assert_eq!(jsdoc.tags.len(), 1);
+    assert_eq!(jsdoc.tags[0].name, "deprecated");
+    assert_eq!(jsdoc.tags[0].description, "Use `b`.");
+    assert!(jsdoc.description.contains("@decorator"));
+  }
+
+  #[test]
+  fn single_line() {
+    let (src, jsdoc) = parse("/** @deprecated */\nfunction a() {}");
+    assert_eq!(jsdoc.description, "");
+    assert_eq!(jsdoc.tags[0].name, "deprecated");
+    assert_eq!(text_at(&src, jsdoc.tags[0].range), "@deprecated");
+  }
+}
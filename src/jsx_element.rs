@@ -0,0 +1,213 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! A uniform view over elements, regardless of whether they are written with
+//! JSX syntax (`<img alt="" />`) or created with a factory call
+//! (`h("img", { alt: "" })`).
+
+use crate::context::Context;
+use deno_ast::swc::ast as swc_ast;
+use deno_ast::view::{
+  CallExpr, Expr, JSXAttrName, JSXAttrOrSpread, JSXAttrValue, JSXElementName,
+  JSXExpr, JSXOpeningElement, Lit, Prop, PropName, PropOrSpread,
+};
+use deno_ast::{SourceRange, SourceRanged};
+
+/// Functions that are commonly used to create elements without JSX syntax,
+/// e.g. `h` of Preact or the ones of the automatic JSX runtime.
+const FACTORY_FNS: &[&str] = &[
+  "h",
+  "createElement",
+  "jsx",
+  "jsxs",
+  "jsxDEV",
+  "_jsx",
+  "_jsxs",
+];
+
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum AttrValue {
+  /// The attribute is written without a value, e.g. `<input disabled />`.
+  Empty,
+  /// `undefined` is passed explicitly.
+  Undefined,
+  /// A statically known string, e.g. `alt="foo"` or `alt={"foo"}`.
+  Str(String),
+  /// Any other value that can't be determined statically.
+  Dynamic,
+}
+
+impl AttrValue {
+  fn from_expr(expr: Expr) -> Self {
+    match expr {
+      Expr::Lit(Lit::Str(s)) => AttrValue::Str(s.value().to_string()),
+      Expr::Tpl(tpl) if tpl.exprs.is_empty() => match tpl.quasis.first() {
+        Some(quasi) => AttrValue::Str(quasi.inner.raw.to_string()),
+        None => AttrValue::Dynamic,
+      },
+      Expr::Ident(ident) if ident.sym() == "undefined" => AttrValue::Undefined,
+      _ => AttrValue::Dynamic,
+    }
+  }
+}
+
+pub(crate) struct Attr {
+  pub name: String,
+  pub value: AttrValue,
+  pub range: SourceRange,
+}
+
+pub(crate) struct Element {
+  /// Tag name, e.g. `img`.
+  pub name: String,
+  pub name_range: SourceRange,
+  pub attrs: Vec<Attr>,
+  /// Whether props are spread into the element, in which case the absence of
+  /// an attribute can't be known for sure.
+  pub has_spread: bool,
+}
+
+impl Element {
+  /// Creates an element from JSX syntax. Returns `None` for member
+  /// (`<Foo.Bar />`) and namespaced (`<svg:rect />`) element names.
+  pub fn from_jsx(node: &JSXOpeningElement) -> Option<Self> {
+    let JSXElementName::Ident(ident) = node.name else {
+      return None;
+    };
+
+    let mut attrs = Vec::new();
+    let mut has_spread = false;
+    for attr in node.attrs {
+      let JSXAttrOrSpread::JSXAttr(attr) = attr else {
+        has_spread = true;
+        continue;
+      };
+      let JSXAttrName::Ident(name) = attr.name else {
+        continue;
+      };
+
+      let value = match attr.value {
+        None => AttrValue::Empty,
+        Some(JSXAttrValue::Lit(lit)) => AttrValue::from_expr(Expr::Lit(lit)),
+        Some(JSXAttrValue::JSXExprContainer(container)) => {
+          match container.expr {
+            JSXExpr::Expr(expr) => AttrValue::from_expr(expr),
+            _ => AttrValue::Dynamic,
+          }
+        }
+        Some(_) => AttrValue::Dynamic,
+      };
+
+      attrs.push(Attr {
+        name: name.sym().to_string(),
+        value,
+        range: attr.range(),
+      });
+    }
+
+    Some(Element {
+      name: ident.sym().to_string(),
+      name_range: ident.range(),
+      attrs,
+      has_spread,
+    })
+  }
+
+  /// Creates an element from a factory call like `h("img", { alt: "" })` or
+  /// `React.createElement("img", { alt: "" })`. Only calls of the functions
+  /// in `FACTORY_FNS`, `React.createElement` and the JSX factory of the file
+  /// are considered, and only if their first argument is a string literal
+  /// and they have a props argument, so that calls like
+  /// `document.createElement("img")` are not mistaken for elements.
+  pub fn from_factory_call(node: &CallExpr, ctx: &Context) -> Option<Self> {
+    let swc_ast::Callee::Expr(callee) = &node.inner.callee else {
+      return None;
+    };
+    let callee = dotted_name(callee)?;
+    let is_factory = FACTORY_FNS.contains(&callee.as_str())
+      || callee == "React.createElement"
+      || ctx
+        .jsx_factory()
+        .and_then(|factory| dotted_name(&factory))
+        .is_some_and(|factory| factory == callee);
+    if !is_factory || node.args.len() < 2 {
+      return None;
+    }
+
+    let name_arg = node.args.first()?;
+    let Expr::Lit(Lit::Str(name)) = name_arg.expr else {
+      return None;
+    };
+
+    let mut attrs = Vec::new();
+    let mut has_spread = false;
+    if let Some(props_arg) = node.args.get(1) {
+      match props_arg.expr {
+        Expr::Object(obj) => {
+          for prop in obj.props {
+            let prop = match prop {
+              PropOrSpread::Prop(prop) => prop,
+              PropOrSpread::Spread(_) => {
+                has_spread = true;
+                continue;
+              }
+            };
+
+            match prop {
+              Prop::KeyValue(kv) => {
+                if let Some(name) = prop_name(&kv.key) {
+                  attrs.push(Attr {
+                    name,
+                    value: AttrValue::from_expr(kv.value),
+                    range: kv.range(),
+                  });
+                }
+              }
+              Prop::Shorthand(ident) => attrs.push(Attr {
+                name: ident.sym().to_string(),
+                value: AttrValue::Dynamic,
+                range: ident.range(),
+              }),
+              _ => {}
+            }
+          }
+        }
+        Expr::Lit(Lit::Null(_)) => {}
+        _ => has_spread = true,
+      }
+    }
+
+    Some(Element {
+      name: name.value().to_string(),
+      name_range: name.range(),
+      attrs,
+      has_spread,
+    })
+  }
+
+  pub fn attr(&self, name: &str) -> Option<&Attr> {
+    self.attrs.iter().find(|attr| attr.name == name)
+  }
+}
+
+/// Returns the name of an identifier or a chain of property accesses, e.g.
+/// `React.createElement`.
+fn dotted_name(expr: &swc_ast::Expr) -> Option<String> {
+  match expr {
+    swc_ast::Expr::Ident(ident) => Some(ident.sym.to_string()),
+    swc_ast::Expr::Member(member) => {
+      let swc_ast::MemberProp::Ident(prop) = &member.prop else {
+        return None;
+      };
+      Some(format!("{}.{}", dotted_name(&member.obj)?, prop.sym))
+    }
+    _ => None,
+  }
+}
+
+fn prop_name(key: &PropName) -> Option<String> {
+  match key {
+    PropName::Ident(ident) => Some(ident.sym().to_string()),
+    PropName::Str(s) => Some(s.value().to_string()),
+    _ => None,
+  }
+}
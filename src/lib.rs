@@ -6,23 +6,37 @@
 #[macro_use]
 extern crate log;
 
+#[macro_use]
+pub mod testing;
 #[cfg(test)]
 #[macro_use]
 mod test_util;
 
 pub mod ast_parser;
+mod code_metrics;
+pub mod comments;
+pub mod config;
 pub mod context;
-mod control_flow;
+pub mod control_flow;
 pub mod diagnostic;
+mod error_value;
+pub mod extract;
+mod glob;
 mod globals;
 mod handler;
 mod ignore_directives;
 mod js_regex;
+pub mod jsdoc;
+mod jsx_element;
 pub mod linter;
+pub mod module_graph;
+pub mod options_schema;
 mod performance_mark;
+mod reorder;
 pub mod rules;
 pub mod swc_util;
 pub mod tags;
+mod this_context;
 
 pub use deno_ast::view::Program;
 pub use deno_ast::view::ProgramRef;
@@ -49,6 +63,8 @@ mod lint_tests {
       all_rule_codes,
       custom_ignore_diagnostic_directive: None,
       custom_ignore_file_directive: None,
+      extractors: vec![],
+      rule_time_budget: None,
     });
 
     let (_, diagnostics) = linter
@@ -59,6 +75,7 @@ mod lint_tests {
         config: LintConfig {
           default_jsx_factory: None,
           default_jsx_fragment_factory: None,
+          ecma_version: EcmaVersion::default(),
         },
         external_linter: None,
       })
@@ -76,12 +93,15 @@ mod lint_tests {
       all_rule_codes,
       custom_ignore_diagnostic_directive: None,
       custom_ignore_file_directive: None,
+      extractors: vec![],
+      rule_time_budget: None,
     });
     linter.lint_with_ast(
       parsed_source,
       LintConfig {
         default_jsx_factory: None,
         default_jsx_fragment_factory: None,
+        ecma_version: EcmaVersion::default(),
       },
       None,
     )
@@ -172,7 +192,7 @@ export function foo() {
   fn unknown_rules_always_know_available_rules() {
     use crate::rules::camelcase::Camelcase;
     let diagnostics = lint_specified_rule(
-      Box::new(Camelcase),
+      Box::new(Camelcase::default()),
       r#"
 // deno-lint-ignore no-explicit-any
 const fooBar: any = 42;
@@ -203,7 +223,7 @@ const fooBar: any = 42;
   fn ban_unused_ignore_not_report_unexecuted_rule() {
     use crate::rules::camelcase::Camelcase;
     let diagnostics = lint_specified_rule(
-      Box::new(Camelcase),
+      Box::new(Camelcase::default()),
       r#"
 // deno-lint-ignore no-explicit-any
 const _fooBar = 42;
@@ -297,4 +317,507 @@ const _foo = 42;
     let diagnostics = lint_recommended_rules_with_ast(&parsed_source);
     assert!(diagnostics.is_empty());
   }
+
+  #[test]
+  fn lint_with_artifacts() {
+    let src = "let a = 1;\nnew Symbol();\nfunction f(b) {}";
+    let parsed_source = parse(src);
+    let expected = lint_recommended_rules_with_ast(&parsed_source);
+
+    let scope = parsed_source.with_view(deno_ast::Scope::analyze);
+    let linter = Linter::new(LinterOptions {
+      rules: recommended_rules(get_all_rules()),
+      all_rule_codes: get_all_rules_codes(),
+      custom_ignore_diagnostic_directive: None,
+      custom_ignore_file_directive: None,
+      extractors: vec![],
+      rule_time_budget: None,
+    });
+    let diagnostics = linter.lint_with_artifacts(
+      &parsed_source,
+      LintConfig {
+        default_jsx_factory: None,
+        default_jsx_fragment_factory: None,
+        ecma_version: EcmaVersion::default(),
+      },
+      None,
+      LintArtifacts::new().with_scope(scope),
+    );
+
+    assert!(!diagnostics.is_empty());
+    assert_eq!(
+      diagnostics
+        .iter()
+        .map(|d| (d.details.code.clone(), d.range.as_ref().map(|r| r.range)))
+        .collect::<Vec<_>>(),
+      expected
+        .iter()
+        .map(|d| (d.details.code.clone(), d.range.as_ref().map(|r| r.range)))
+        .collect::<Vec<_>>(),
+    );
+  }
+
+  #[derive(Debug)]
+  struct PanickingRule;
+
+  impl LintRule for PanickingRule {
+    fn lint_program_with_ast_view(
+      &self,
+      context: &mut crate::context::Context,
+      program: crate::Program,
+    ) {
+      use deno_ast::SourceRanged;
+      context.add_diagnostic(program.range(), "panicking-rule", "Dropped");
+      panic!("oops");
+    }
+
+    fn code(&self) -> &'static str {
+      "panicking-rule"
+    }
+
+    fn tags(&self) -> crate::tags::Tags {
+      &[]
+    }
+  }
+
+  /// A rule like out-of-tree ones, which only uses the public API.
+  #[derive(Debug)]
+  struct UnreachableTopLevelRule;
+
+  impl LintRule for UnreachableTopLevelRule {
+    fn lint_program_with_ast_view(
+      &self,
+      context: &mut crate::context::Context,
+      program: crate::Program,
+    ) {
+      use deno_ast::view::ModuleItem;
+      use deno_ast::SourceRanged;
+      let stmts = match program {
+        crate::Program::Module(module) => module
+          .body
+          .iter()
+          .filter_map(|item| match item {
+            ModuleItem::Stmt(stmt) => Some(*stmt),
+            ModuleItem::ModuleDecl(_) => None,
+          })
+          .collect::<Vec<_>>(),
+        crate::Program::Script(script) => script.body.to_vec(),
+      };
+      for stmt in stmts {
+        let meta: Option<&crate::control_flow::Metadata> =
+          context.control_flow().meta(stmt.start());
+        if meta.map_or(false, |meta| meta.unreachable) {
+          context.add_diagnostic(stmt.range(), "unreachable", "Unreachable");
+        }
+      }
+    }
+
+    fn code(&self) -> &'static str {
+      "unreachable"
+    }
+
+    fn tags(&self) -> crate::tags::Tags {
+      &[]
+    }
+  }
+
+  #[test]
+  fn control_flow_from_rule() {
+    let src = "throw new Error();
+foo();";
+    let linter = Linter::new(LinterOptions {
+      rules: vec![Box::new(UnreachableTopLevelRule)],
+      all_rule_codes: get_all_rules_codes(),
+      custom_ignore_diagnostic_directive: None,
+      custom_ignore_file_directive: None,
+      extractors: vec![],
+      rule_time_budget: None,
+    });
+    let (_, diagnostics) = linter
+      .lint_file(LintFileOptions {
+        specifier: ModuleSpecifier::parse("file:///lint_test.js").unwrap(),
+        source_code: src.to_string(),
+        media_type: MediaType::JavaScript,
+        config: LintConfig {
+          default_jsx_factory: None,
+          default_jsx_fragment_factory: None,
+          ecma_version: EcmaVersion::default(),
+        },
+        external_linter: None,
+      })
+      .expect("Failed to lint");
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_diagnostic(&diagnostics[0], "unreachable", 2, 0, src);
+  }
+
+  #[test]
+  fn lint_file_catch_unwind() {
+    let linter = Linter::new(LinterOptions {
+      rules: vec![
+        Box::new(PanickingRule),
+        Box::new(crate::rules::no_var::NoVar),
+      ],
+      all_rule_codes: get_all_rules_codes(),
+      custom_ignore_diagnostic_directive: None,
+      custom_ignore_file_directive: None,
+      extractors: vec![],
+      rule_time_budget: None,
+    });
+    let src = "\nvar a = 1;";
+    let (_, diagnostics) = linter
+      .lint_file_catch_unwind(LintFileOptions {
+        specifier: ModuleSpecifier::parse("file:///lint_test.ts").unwrap(),
+        source_code: src.to_string(),
+        media_type: MediaType::TypeScript,
+        config: LintConfig {
+          default_jsx_factory: None,
+          default_jsx_fragment_factory: None,
+          ecma_version: EcmaVersion::default(),
+        },
+        external_linter: None,
+      })
+      .expect("Failed to lint");
+
+    assert_eq!(diagnostics.len(), 2);
+    assert_diagnostic(&diagnostics[0], "panicking-rule", 1, 0, src);
+    assert_eq!(
+      diagnostics[0].details.message,
+      "The rule panicked while linting this file: oops"
+    );
+    assert_diagnostic(&diagnostics[1], "no-var", 2, 0, src);
+  }
+
+  /// A rule that reports every node of a file and counts the nodes it visits.
+  #[derive(Debug)]
+  struct CountingRule {
+    nodes: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+  }
+
+  impl LintRule for CountingRule {
+    fn lint_program_with_ast_view(
+      &self,
+      context: &mut crate::context::Context,
+      program: crate::Program,
+    ) {
+      use crate::handler::Traverse;
+      CountingRuleHandler { nodes: &self.nodes }.traverse(program, context);
+    }
+
+    fn code(&self) -> &'static str {
+      "counting-rule"
+    }
+
+    fn tags(&self) -> crate::tags::Tags {
+      &[]
+    }
+  }
+
+  struct CountingRuleHandler<'a> {
+    nodes: &'a std::sync::atomic::AtomicUsize,
+  }
+
+  impl<'a> crate::handler::Handler for CountingRuleHandler<'a> {
+    fn on_enter_node(
+      &mut self,
+      node: deno_ast::view::Node,
+      ctx: &mut crate::context::Context,
+    ) {
+      use deno_ast::SourceRanged;
+      self
+        .nodes
+        .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+      ctx.add_diagnostic(node.range(), "counting-rule", "Counted");
+    }
+  }
+
+  fn lint_with_budget(
+    rules: Vec<Box<dyn LintRule>>,
+    budget: std::time::Duration,
+    src: &str,
+  ) -> LintResult {
+    let linter = Linter::new(LinterOptions {
+      rules,
+      rule_time_budget: Some(budget),
+      ..Default::default()
+    });
+    let (_, result) = linter
+      .lint_file_with_result(LintFileOptions {
+        specifier: ModuleSpecifier::parse("file:///lint_test.ts").unwrap(),
+        source_code: src.to_string(),
+        media_type: MediaType::TypeScript,
+        config: LintConfig::default(),
+        external_linter: None,
+      })
+      .expect("Failed to lint");
+    result
+  }
+
+  #[test]
+  fn rule_time_budget() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    let src = format!("var a = 1;\n{}", "a;\n".repeat(500));
+    let nodes = Arc::new(AtomicUsize::new(0));
+    let rules = || -> Vec<Box<dyn LintRule>> {
+      vec![
+        Box::new(CountingRule {
+          nodes: nodes.clone(),
+        }),
+        Box::new(crate::rules::no_var::NoVar),
+      ]
+    };
+
+    let result = lint_with_budget(rules(), Duration::from_secs(3600), &src);
+    assert!(result.timed_out_rules.is_empty());
+    assert_eq!(
+      result
+        .diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.details.code == "no-var")
+        .count(),
+      1
+    );
+    let all_nodes = nodes.swap(0, Ordering::Relaxed);
+
+    // Every rule runs out of a budget of zero, so all their diagnostics are
+    // dropped.
+    let result = lint_with_budget(rules(), Duration::ZERO, &src);
+    assert_eq!(result.timed_out_rules, vec!["counting-rule", "no-var"]);
+    assert!(result.diagnostics.is_empty());
+    // The traversal was aborted long before it reached the end of the file.
+    assert!(nodes.load(Ordering::Relaxed) < all_nodes / 2);
+  }
+
+  /// A rule that doesn't use `Handler`, and keeps working on a file until it
+  /// runs out of its time budget.
+  #[derive(Debug)]
+  struct LoopingRule;
+
+  impl LintRule for LoopingRule {
+    fn lint_program_with_ast_view(
+      &self,
+      context: &mut crate::context::Context,
+      program: crate::Program,
+    ) {
+      use deno_ast::SourceRanged;
+      context.add_diagnostic(program.range(), "looping-rule", "Dropped");
+      while !context.is_over_rule_budget() {}
+    }
+
+    fn code(&self) -> &'static str {
+      "looping-rule"
+    }
+
+    fn tags(&self) -> crate::tags::Tags {
+      &[]
+    }
+  }
+
+  #[test]
+  fn rule_time_budget_without_handler() {
+    let result = lint_with_budget(
+      vec![Box::new(LoopingRule)],
+      std::time::Duration::ZERO,
+      "var a = 1;",
+    );
+    assert_eq!(result.timed_out_rules, vec!["looping-rule"]);
+    assert!(result.diagnostics.is_empty());
+  }
+
+  fn lint_tolerant(source: &str) -> (bool, Vec<LintDiagnostic>) {
+    let linter = Linter::new(LinterOptions {
+      rules: vec![Box::new(crate::rules::no_var::NoVar)],
+      all_rule_codes: get_all_rules_codes(),
+      custom_ignore_diagnostic_directive: None,
+      custom_ignore_file_directive: None,
+      extractors: vec![],
+      rule_time_budget: None,
+    });
+    let (parsed_source, diagnostics) =
+      linter.lint_file_tolerant(LintFileOptions {
+        specifier: ModuleSpecifier::parse("file:///lint_test.ts").unwrap(),
+        source_code: source.to_string(),
+        media_type: MediaType::TypeScript,
+        config: LintConfig {
+          default_jsx_factory: None,
+          default_jsx_fragment_factory: None,
+          ecma_version: EcmaVersion::default(),
+        },
+        external_linter: None,
+      });
+    (parsed_source.is_some(), diagnostics)
+  }
+
+  #[test]
+  fn lint_file_tolerant() {
+    use crate::diagnostic::PARSE_ERROR_CODE;
+    use deno_ast::diagnostics::Diagnostic;
+
+    let src = "var a = 1;";
+    let (parsed, diagnostics) = lint_tolerant(src);
+    assert!(parsed);
+    assert_eq!(diagnostics.len(), 1);
+    assert_diagnostic(&diagnostics[0], "no-var", 1, 0, src);
+
+    // The rules run on the AST the parser recovered.
+    let src = "break;\nvar b = 1;";
+    let (parsed, diagnostics) = lint_tolerant(src);
+    assert!(parsed);
+    assert_eq!(diagnostics.len(), 2);
+    assert_diagnostic(&diagnostics[0], PARSE_ERROR_CODE, 1, 0, src);
+    assert_diagnostic(&diagnostics[1], "no-var", 2, 0, src);
+
+    let src = "var a = ;";
+    let (parsed, diagnostics) = lint_tolerant(src);
+    assert!(!parsed);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].details.code, PARSE_ERROR_CODE);
+    assert!(!diagnostics[0].details.message.is_empty());
+    assert!(diagnostics[0].docs_url().is_none());
+  }
+
+  #[test]
+  fn superseded_diagnostics() {
+    use crate::rules::no_window::NoWindow;
+    use crate::rules::no_window_prefix::NoWindowPrefix;
+
+    let src = "window.fetch('https://deno.land');";
+    let diagnostics = lint(
+      src,
+      vec![Box::new(NoWindow), Box::new(NoWindowPrefix)],
+      get_all_rules_codes(),
+    );
+    assert_eq!(diagnostics.len(), 1);
+    assert_diagnostic(&diagnostics[0], "no-window", 1, 0, src);
+
+    let diagnostics = lint_specified_rule(Box::new(NoWindowPrefix), src);
+    assert_eq!(diagnostics.len(), 1);
+    assert_diagnostic(&diagnostics[0], "no-window-prefix", 1, 0, src);
+  }
+
+  fn add_ignore_directives(source: &str) -> String {
+    let linter = Linter::new(LinterOptions {
+      rules: vec![
+        Box::new(crate::rules::no_var::NoVar),
+        Box::new(crate::rules::no_explicit_any::NoExplicitAny),
+        Box::new(crate::rules::jsx_alt_text::JSXAltText),
+      ],
+      all_rule_codes: get_all_rules_codes(),
+      custom_ignore_diagnostic_directive: None,
+      custom_ignore_file_directive: None,
+      extractors: vec![],
+      rule_time_budget: None,
+    });
+    let (parsed_source, diagnostics) = linter
+      .lint_file(LintFileOptions {
+        specifier: ModuleSpecifier::parse("file:///lint_test.tsx").unwrap(),
+        source_code: source.to_string(),
+        media_type: MediaType::Tsx,
+        config: LintConfig {
+          default_jsx_factory: None,
+          default_jsx_fragment_factory: None,
+          ecma_version: EcmaVersion::default(),
+        },
+        external_linter: None,
+      })
+      .expect("Failed to lint");
+    let changes = linter.ignore_directive_changes(&parsed_source, &diagnostics);
+    let text_info = parsed_source.text_info_lazy();
+    deno_ast::apply_text_changes(
+      text_info.text_str(),
+      changes
+        .into_iter()
+        .map(|change| deno_ast::TextChange {
+          range: change.range.as_byte_range(text_info.range().start),
+          new_text: change.new_text.into_owned(),
+        })
+        .collect(),
+    )
+  }
+
+  #[test]
+  fn ignore_directive_changes() {
+    assert_eq!(
+      add_ignore_directives(
+        "function f(a: any) {\n  var b: any = a;\n  return b;\n}\n"
+      ),
+      "// deno-lint-ignore no-explicit-any\nfunction f(a: any) {\n  // deno-lint-ignore no-var no-explicit-any\n  var b: any = a;\n  return b;\n}\n"
+    );
+    assert_eq!(
+      add_ignore_directives(
+        "// deno-lint-ignore no-var -- legacy code\nvar a: any;\n// deno-lint-ignore\nvar b;\n"
+      ),
+      "// deno-lint-ignore no-var no-explicit-any -- legacy code\nvar a: any;\n// deno-lint-ignore\nvar b;\n"
+    );
+    assert_eq!(
+      add_ignore_directives(
+        "const a = (\n  <div>\n    <img src=\"a.png\" />\n    {cond && (\n      <img src=\"b.png\" />\n    )}\n  </div>\n);\n"
+      ),
+      "const a = (\n  <div>\n    {\n      // deno-lint-ignore jsx-alt-text\n    }<img src=\"a.png\" />\n    {cond && (\n      // deno-lint-ignore jsx-alt-text\n      <img src=\"b.png\" />\n    )}\n  </div>\n);\n"
+    );
+    assert_eq!(
+      add_ignore_directives("const a = `\n${(() => { var b; })()}`;\n"),
+      "const a = `\n${(() => { var b; })()}`;\n"
+    );
+  }
+
+  fn lint_with_suppressed(source: &str) -> LintResult {
+    let linter = Linter::new(LinterOptions {
+      rules: vec![Box::new(crate::rules::no_var::NoVar)],
+      all_rule_codes: get_all_rules_codes(),
+      custom_ignore_diagnostic_directive: None,
+      custom_ignore_file_directive: None,
+      extractors: vec![],
+      rule_time_budget: None,
+    });
+    let (_, result) = linter
+      .lint_file_with_suppressed(LintFileOptions {
+        specifier: ModuleSpecifier::parse("file:///lint_test.ts").unwrap(),
+        source_code: source.to_string(),
+        media_type: MediaType::TypeScript,
+        config: LintConfig {
+          default_jsx_factory: None,
+          default_jsx_fragment_factory: None,
+          ecma_version: EcmaVersion::default(),
+        },
+        external_linter: None,
+      })
+      .expect("Failed to lint");
+    result
+  }
+
+  #[test]
+  fn suppressed_diagnostics() {
+    let src = "var a = 1;\n// deno-lint-ignore no-var -- legacy\nvar b = 2;\n";
+    let result = lint_with_suppressed(src);
+    assert_eq!(result.diagnostics.len(), 1);
+    assert_diagnostic(&result.diagnostics[0], "no-var", 1, 0, src);
+    assert_eq!(result.suppressed.len(), 1);
+    assert_diagnostic(&result.suppressed[0].diagnostic, "no-var", 3, 0, src);
+    let text_info = result.suppressed[0]
+      .diagnostic
+      .range
+      .as_ref()
+      .unwrap()
+      .text_info
+      .clone();
+    assert_eq!(
+      text_info.line_index(result.suppressed[0].directive_range.start),
+      1
+    );
+
+    let src = "// deno-lint-ignore-file no-var\nvar a = 1;\n";
+    let result = lint_with_suppressed(src);
+    assert!(result.diagnostics.is_empty());
+    assert_eq!(result.suppressed.len(), 1);
+    assert_diagnostic(&result.suppressed[0].diagnostic, "no-var", 2, 0, src);
+
+    let src = "// deno-lint-ignore-file\nvar a = 1;\nvar b = 2;\n";
+    let result = lint_with_suppressed(src);
+    assert!(result.diagnostics.is_empty());
+    assert_eq!(result.suppressed.len(), 2);
+  }
 }
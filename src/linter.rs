@@ -2,18 +2,30 @@
 
 use crate::ast_parser::parse_program;
 use crate::context::Context;
-use crate::diagnostic::LintDiagnostic;
-use crate::ignore_directives::parse_file_ignore_directives;
+use crate::diagnostic::{LintDiagnostic, LintFixChange, SuppressedDiagnostic};
+use crate::extract::Extractor;
+use crate::ignore_directives::{
+  ignore_directive_changes, parse_file_ignore_directives,
+};
+use crate::module_graph::ModuleGraph;
 use crate::performance_mark::PerformanceMark;
 use crate::rules::{ban_unknown_rule_code::BanUnknownRuleCode, LintRule};
 use deno_ast::diagnostics::Diagnostic;
 use deno_ast::MediaType;
 use deno_ast::ParsedSource;
+use deno_ast::Scope;
+use deno_ast::SourceTextInfo;
 use deno_ast::{ModuleSpecifier, ParseDiagnostic};
+use std::any::Any;
 use std::borrow::Cow;
 use std::collections::HashSet;
+use std::panic::{self, AssertUnwindSafe};
 use std::sync::Arc;
+use std::time::Duration;
 
+pub use crate::js_regex::EcmaVersion;
+
+#[derive(Default)]
 pub struct LinterOptions {
   /// Rules to lint with.
   pub rules: Vec<Box<dyn LintRule>>,
@@ -23,6 +35,15 @@ pub struct LinterOptions {
   pub custom_ignore_file_directive: Option<&'static str>,
   /// Defaults to "deno-lint-ignore"
   pub custom_ignore_diagnostic_directive: Option<&'static str>,
+  /// Pre-processors for files that embed JavaScript or TypeScript, like
+  /// `.vue` components. Used by `Linter::lint_file_with_extraction`.
+  pub extractors: Vec<Box<dyn Extractor>>,
+  /// The time a rule may spend on a file. It's checked after each rule: a
+  /// rule that exceeded it has its diagnostics for the file dropped and is
+  /// listed in `LintResult::timed_out_rules`. Rules stop early once
+  /// `Context::is_over_rule_budget` returns `true`, which traversals with
+  /// `Handler` check for every node.
+  pub rule_time_budget: Option<Duration>,
 }
 
 /// A linter instance.
@@ -43,6 +64,8 @@ pub(crate) struct LinterContext {
   /// Rules are sorted by priority
   pub rules: Vec<Box<dyn LintRule>>,
   pub all_rule_codes: HashSet<Cow<'static, str>>,
+  pub extractors: Vec<Box<dyn Extractor>>,
+  pub rule_time_budget: Option<Duration>,
 }
 
 impl LinterContext {
@@ -63,6 +86,8 @@ impl LinterContext {
       check_unknown_rules,
       rules,
       all_rule_codes: options.all_rule_codes,
+      extractors: options.extractors,
+      rule_time_budget: options.rule_time_budget,
     }
   }
 }
@@ -90,10 +115,68 @@ pub struct LintFileOptions {
   pub external_linter: Option<ExternalLinterCb>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct LintConfig {
   pub default_jsx_factory: Option<String>,
   pub default_jsx_fragment_factory: Option<String>,
+  /// The oldest ECMAScript version the linted code has to run on. Regexes
+  /// using newer features, like lookbehinds before ES2018 or the `v` flag
+  /// before ES2024, are reported, and rules don't suggest newer syntax, like
+  /// template literals before ES2015 or `??` before ES2020.
+  pub ecma_version: EcmaVersion,
+}
+
+/// The diagnostics of a file, along with the ones that were silenced by
+/// ignore directives.
+pub struct LintResult {
+  pub diagnostics: Vec<LintDiagnostic>,
+  pub suppressed: Vec<SuppressedDiagnostic>,
+  /// Codes of the rules that were aborted for the file because they exceeded
+  /// `LinterOptions::rule_time_budget`.
+  pub timed_out_rules: Vec<&'static str>,
+}
+
+/// Analysis results of an already parsed file that were computed by other
+/// consumers of `deno_ast`, so the linter doesn't have to compute them again.
+/// See `Linter::lint_with_artifacts`.
+///
+/// The comments of the file are shared through the `ParsedSource` itself.
+#[derive(Default)]
+pub struct LintArtifacts {
+  scope: Option<Scope>,
+  module_graph: Option<Arc<ModuleGraph>>,
+}
+
+impl LintArtifacts {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// The scope analysis of the program, i.e. the result of `Scope::analyze`.
+  /// It has to be computed for the same `ParsedSource` the file is linted
+  /// with.
+  pub fn with_scope(mut self, scope: Scope) -> Self {
+    self.scope = Some(scope);
+    self
+  }
+
+  /// The files of the project the file belongs to and the imports between
+  /// them, which enables the checks of rules that span multiple files. See
+  /// `Linter::lint_project`.
+  pub fn with_module_graph(mut self, module_graph: Arc<ModuleGraph>) -> Self {
+    self.module_graph = Some(module_graph);
+    self
+  }
+}
+
+/// How `Linter::lint_inner` runs the rules.
+#[derive(Clone, Copy, Default)]
+struct LintMode {
+  /// Catch the panics of rules, see `Linter::lint_file_catch_unwind`.
+  catch_unwind: bool,
+  /// Also lint the files that are ignored as a whole, so their suppressed
+  /// diagnostics can be reported.
+  report_suppressed: bool,
 }
 
 impl Linter {
@@ -114,27 +197,251 @@ impl Linter {
     options: LintFileOptions,
   ) -> Result<(ParsedSource, Vec<LintDiagnostic>), ParseDiagnostic> {
     let _mark = PerformanceMark::new("Linter::lint");
+    let (parsed_source, result) =
+      self.lint_file_inner(options, LintMode::default())?;
+    Ok((parsed_source, result.diagnostics))
+  }
+
+  /// Lint a single file like `Linter::lint_file`, and return the whole
+  /// `LintResult`, e.g. to report the rules that exceeded
+  /// `LinterOptions::rule_time_budget` in the summary of a run.
+  ///
+  /// The suppressed diagnostics are empty for files that are ignored as a
+  /// whole, see `Linter::lint_file_with_suppressed`.
+  pub fn lint_file_with_result(
+    &self,
+    options: LintFileOptions,
+  ) -> Result<(ParsedSource, LintResult), ParseDiagnostic> {
+    let _mark = PerformanceMark::new("Linter::lint_file_with_result");
+    self.lint_file_inner(options, LintMode::default())
+  }
+
+  /// Lint a single file like `Linter::lint_file`, but report the errors of
+  /// the parser as diagnostics with the `parse-error` code instead of
+  /// failing, e.g. to give feedback in an editor while typing.
+  ///
+  /// If the parser recovered from its errors, the rules still run on the
+  /// recovered AST, and the `ParsedSource` is returned. Otherwise the error
+  /// of the parser is the only diagnostic.
+  pub fn lint_file_tolerant(
+    &self,
+    options: LintFileOptions,
+  ) -> (Option<ParsedSource>, Vec<LintDiagnostic>) {
+    let _mark = PerformanceMark::new("Linter::lint_file_tolerant");
+    let source_code = options.source_code.clone();
+    match self.lint_file_inner(options, LintMode::default()) {
+      Ok((parsed_source, result)) => {
+        let text_info = parsed_source.text_info_lazy();
+        let mut diagnostics = parsed_source
+          .diagnostics()
+          .iter()
+          .map(|diagnostic| {
+            LintDiagnostic::from_parse_diagnostic(diagnostic, text_info)
+          })
+          .collect::<Vec<_>>();
+        diagnostics.extend(result.diagnostics);
+        diagnostics.sort_by_key(|diagnostic| {
+          diagnostic.range.as_ref().map(|range| range.range.start)
+        });
+        (Some(parsed_source), diagnostics)
+      }
+      Err(diagnostic) => {
+        let text_info = SourceTextInfo::from_string(source_code);
+        (
+          None,
+          vec![LintDiagnostic::from_parse_diagnostic(
+            &diagnostic,
+            &text_info,
+          )],
+        )
+      }
+    }
+  }
 
+  /// Lint a single file like `Linter::lint_file`, but catch the panics of
+  /// rules instead of unwinding into the caller.
+  ///
+  /// A rule that panics has the diagnostics it reported for the file
+  /// replaced with a single diagnostic at the start of the file, and the
+  /// other rules still run. The panic hook is still called, so the panic is
+  /// printed to stderr by default.
+  pub fn lint_file_catch_unwind(
+    &self,
+    options: LintFileOptions,
+  ) -> Result<(ParsedSource, Vec<LintDiagnostic>), ParseDiagnostic> {
+    let _mark = PerformanceMark::new("Linter::lint_file_catch_unwind");
+    let mode = LintMode {
+      catch_unwind: true,
+      ..Default::default()
+    };
+    let (parsed_source, result) = self.lint_file_inner(options, mode)?;
+    Ok((parsed_source, result.diagnostics))
+  }
+
+  /// Lint a single file like `Linter::lint_file`, and also return the
+  /// diagnostics that were silenced by ignore directives, e.g. to audit or
+  /// count the suppressions.
+  ///
+  /// Files ignored as a whole with `// deno-lint-ignore-file` are linted as
+  /// well, to report their suppressed diagnostics.
+  pub fn lint_file_with_suppressed(
+    &self,
+    options: LintFileOptions,
+  ) -> Result<(ParsedSource, LintResult), ParseDiagnostic> {
+    let _mark = PerformanceMark::new("Linter::lint_file_with_suppressed");
+    let mode = LintMode {
+      report_suppressed: true,
+      ..Default::default()
+    };
+    self.lint_file_inner(options, mode)
+  }
+
+  fn lint_file_inner(
+    &self,
+    options: LintFileOptions,
+    mode: LintMode,
+  ) -> Result<(ParsedSource, LintResult), ParseDiagnostic> {
     let parse_result = {
       let _mark = PerformanceMark::new("ast_parser.parse_program");
       parse_program(options.specifier, options.media_type, options.source_code)
     };
 
     let parsed_source = parse_result?;
-    let diagnostics = self.lint_inner(
+    let result = self.lint_inner(
       &parsed_source,
-      options.config.default_jsx_factory,
-      options.config.default_jsx_fragment_factory,
+      options.config,
       options.external_linter,
+      mode,
     );
 
-    Ok((parsed_source, diagnostics))
+    Ok((parsed_source, result))
+  }
+
+  /// Lint a single file, extracting the sources embedded in it with the
+  /// first extractor of `LinterOptions::extractors` that matches the file.
+  ///
+  /// The diagnostics point into the original file. Like with
+  /// `Linter::lint_file_tolerant`, the errors of the parser are reported as
+  /// diagnostics with the `parse-error` code, so a source that fails to parse
+  /// doesn't hide the diagnostics of the others. Files that aren't matched by
+  /// any extractor are linted like with `Linter::lint_file_tolerant`.
+  pub fn lint_file_with_extraction(
+    &self,
+    options: LintFileOptions,
+  ) -> Vec<LintDiagnostic> {
+    let Some(extractor) = self
+      .ctx
+      .extractors
+      .iter()
+      .find(|extractor| extractor.matches(&options.specifier))
+    else {
+      return self.lint_file_tolerant(options).1;
+    };
+    let _mark = PerformanceMark::new("Linter::lint_file_with_extraction");
+
+    let original = SourceTextInfo::from_string(options.source_code);
+    let sources = extractor.extract(&options.specifier, original.text_str());
+    let mut diagnostics = vec![];
+    for source in sources {
+      let parse_result = {
+        let _mark = PerformanceMark::new("ast_parser.parse_program");
+        parse_program(
+          options.specifier.clone(),
+          source.media_type,
+          source.source_code.clone(),
+        )
+      };
+      let parsed_source = match parse_result {
+        Ok(parsed_source) => parsed_source,
+        Err(diagnostic) => {
+          let diagnostic =
+            LintDiagnostic::from_parse_diagnostic(&diagnostic, &original);
+          diagnostics.push(source.remap_diagnostic(diagnostic, &original));
+          continue;
+        }
+      };
+      let result = self.lint_inner(
+        &parsed_source,
+        options.config.clone(),
+        options.external_linter.clone(),
+        LintMode::default(),
+      );
+      diagnostics.extend(
+        result
+          .diagnostics
+          .into_iter()
+          .map(|diagnostic| source.remap_diagnostic(diagnostic, &original)),
+      );
+    }
+    diagnostics.sort_by_key(|diagnostic| {
+      diagnostic.range.as_ref().map(|range| range.range.start)
+    });
+
+    diagnostics
+  }
+
+  /// Lint the files of a project together, so rules can also check how the
+  /// files relate to each other, e.g. that imported JSON modules exist.
+  /// `other_files` are the files of the project that aren't linted but can be
+  /// imported, like JSON modules.
+  ///
+  /// Returns the result of each file in the order of `files`, like
+  /// `Linter::lint_file` would.
+  pub fn lint_project(
+    &self,
+    files: Vec<LintFileOptions>,
+    other_files: Vec<ModuleSpecifier>,
+  ) -> Vec<Result<(ParsedSource, Vec<LintDiagnostic>), ParseDiagnostic>> {
+    let _mark = PerformanceMark::new("Linter::lint_project");
+
+    let mut module_graph = ModuleGraph::new();
+    for specifier in other_files {
+      module_graph.add_file(specifier);
+    }
+    let parsed_files = files
+      .into_iter()
+      .map(|options| {
+        let parse_result = {
+          let _mark = PerformanceMark::new("ast_parser.parse_program");
+          parse_program(
+            options.specifier.clone(),
+            options.media_type,
+            options.source_code,
+          )
+        };
+        match &parse_result {
+          Ok(parsed_source) => module_graph.add_module(parsed_source),
+          // The file exists even though it can't be linted.
+          Err(_) => module_graph.add_file(options.specifier),
+        }
+        (parse_result, options.config, options.external_linter)
+      })
+      .collect::<Vec<_>>();
+
+    let module_graph = Arc::new(module_graph);
+    parsed_files
+      .into_iter()
+      .map(|(parse_result, config, external_linter)| {
+        let parsed_source = parse_result?;
+        let result = self.lint_inner_with_artifacts(
+          &parsed_source,
+          config,
+          external_linter,
+          LintMode::default(),
+          LintArtifacts::new().with_module_graph(module_graph.clone()),
+        );
+        Ok((parsed_source, result.diagnostics))
+      })
+      .collect()
   }
 
   /// Lint an already parsed file.
   ///
-  /// This method is useful in context where the file is already parsed for other
-  /// purposes like transpilation or LSP analysis.
+  /// This is the primary way to embed the linter, e.g. in contexts where the
+  /// file is already parsed for other purposes like transpilation or LSP
+  /// analysis. The file is never parsed again, and the diagnostics are the
+  /// same as those of `Linter::lint_file`. If other analysis results of the
+  /// file are available as well, use `Linter::lint_with_artifacts`.
   pub fn lint_with_ast(
     &self,
     parsed_source: &ParsedSource,
@@ -142,12 +449,77 @@ impl Linter {
     maybe_external_linter: Option<ExternalLinterCb>,
   ) -> Vec<LintDiagnostic> {
     let _mark = PerformanceMark::new("Linter::lint_with_ast");
-    self.lint_inner(
-      parsed_source,
-      config.default_jsx_factory,
-      config.default_jsx_fragment_factory,
-      maybe_external_linter,
-    )
+    self
+      .lint_inner(
+        parsed_source,
+        config,
+        maybe_external_linter,
+        LintMode::default(),
+      )
+      .diagnostics
+  }
+
+  /// Lint an already parsed file like `Linter::lint_with_ast`, reusing the
+  /// analysis results that were already computed for it.
+  pub fn lint_with_artifacts(
+    &self,
+    parsed_source: &ParsedSource,
+    config: LintConfig,
+    maybe_external_linter: Option<ExternalLinterCb>,
+    artifacts: LintArtifacts,
+  ) -> Vec<LintDiagnostic> {
+    let _mark = PerformanceMark::new("Linter::lint_with_artifacts");
+    self
+      .lint_inner_with_artifacts(
+        parsed_source,
+        config,
+        maybe_external_linter,
+        LintMode::default(),
+        artifacts,
+      )
+      .diagnostics
+  }
+
+  /// Lint an already parsed file like `Linter::lint_with_ast`, and also
+  /// return the diagnostics that were silenced by ignore directives, e.g. to
+  /// show them on demand in an editor.
+  pub fn lint_with_ast_with_suppressed(
+    &self,
+    parsed_source: &ParsedSource,
+    config: LintConfig,
+    maybe_external_linter: Option<ExternalLinterCb>,
+  ) -> LintResult {
+    let _mark = PerformanceMark::new("Linter::lint_with_ast_with_suppressed");
+    let mode = LintMode {
+      report_suppressed: true,
+      ..Default::default()
+    };
+    self.lint_inner(parsed_source, config, maybe_external_linter, mode)
+  }
+
+  /// Create the changes that insert `// deno-lint-ignore <code>` comments
+  /// above the lines of the diagnostics, to silence them.
+  ///
+  /// The codes are appended to an ignore directive that already exists above
+  /// a line, and the comments are wrapped in `{}` inside of JSX children.
+  /// The changes can be applied all at once to the source of the file.
+  pub fn ignore_directive_changes(
+    &self,
+    parsed_source: &ParsedSource,
+    diagnostics: &[LintDiagnostic],
+  ) -> Vec<LintFixChange> {
+    let diagnostics = diagnostics
+      .iter()
+      .filter(|diagnostic| diagnostic.specifier == *parsed_source.specifier())
+      .cloned()
+      .collect::<Vec<_>>();
+    parsed_source.with_view(|program| {
+      ignore_directive_changes(
+        self.ctx.ignore_diagnostic_directive,
+        program,
+        &diagnostics,
+      )
+    })
   }
 
   // TODO(bartlomieju): this struct does too much - not only it checks for ignored
@@ -157,10 +529,20 @@ impl Linter {
     &self,
     mut context: Context,
     external_rule_codes: Vec<Cow<'static, str>>,
-  ) -> Vec<LintDiagnostic> {
+  ) -> LintResult {
     let _mark = PerformanceMark::new("Linter::collect_diagnostics");
 
-    let mut diagnostics = context.check_ignore_directive_usage();
+    let (mut diagnostics, suppressed) = context.check_ignore_directive_usage();
+    if context
+      .file_ignore_directive()
+      .is_some_and(|directive| directive.ignore_all())
+    {
+      return LintResult {
+        diagnostics: vec![],
+        suppressed,
+        timed_out_rules: context.timed_out_rules().to_vec(),
+      };
+    }
 
     let mut all_rules = self.ctx.all_rule_codes.clone();
     all_rules.extend(external_rule_codes.iter().cloned());
@@ -174,6 +556,8 @@ impl Linter {
     // Run `ban-unused-ignore`
     diagnostics.extend(context.ban_unused_ignore(&enabled_rules));
 
+    remove_superseded_diagnostics(&mut diagnostics, &self.ctx.rules);
+
     // Finally sort by position the diagnostics originates on then by code
     diagnostics.sort_by(|a, b| {
       let a_range = a.range.as_ref().map(|r| r.range.start);
@@ -184,19 +568,40 @@ impl Linter {
       }
     });
 
-    diagnostics
+    LintResult {
+      diagnostics,
+      suppressed,
+      timed_out_rules: context.timed_out_rules().to_vec(),
+    }
   }
 
   fn lint_inner(
     &self,
     parsed_source: &ParsedSource,
-    default_jsx_factory: Option<String>,
-    default_jsx_fragment_factory: Option<String>,
+    config: LintConfig,
     maybe_external_linter: Option<ExternalLinterCb>,
-  ) -> Vec<LintDiagnostic> {
+    mode: LintMode,
+  ) -> LintResult {
+    self.lint_inner_with_artifacts(
+      parsed_source,
+      config,
+      maybe_external_linter,
+      mode,
+      LintArtifacts::default(),
+    )
+  }
+
+  fn lint_inner_with_artifacts(
+    &self,
+    parsed_source: &ParsedSource,
+    config: LintConfig,
+    maybe_external_linter: Option<ExternalLinterCb>,
+    mode: LintMode,
+    artifacts: LintArtifacts,
+  ) -> LintResult {
     let _mark = PerformanceMark::new("Linter::lint_inner");
 
-    let diagnostics = parsed_source.with_view(|pg| {
+    parsed_source.with_view(|pg| {
       // If a top-level ignore directive exists, eg:
       // ```
       //   // deno-lint-ignore-file
@@ -209,12 +614,17 @@ impl Linter {
       //
       // That means we want to return no diagnostics for a particular file, so
       // we're gonna check if the file should be ignored, before performing
-      // other expensive work like scope or control-flow analysis.
+      // other expensive work like scope or control-flow analysis, unless the
+      // suppressed diagnostics are reported.
       let file_ignore_directive =
         parse_file_ignore_directives(self.ctx.ignore_file_directive, pg);
       if let Some(ignore_directive) = file_ignore_directive.as_ref() {
-        if ignore_directive.ignore_all() {
-          return vec![];
+        if ignore_directive.ignore_all() && !mode.report_suppressed {
+          return LintResult {
+            diagnostics: vec![],
+            suppressed: vec![],
+            timed_out_rules: vec![],
+          };
         }
       }
 
@@ -225,14 +635,35 @@ impl Linter {
         parsed_source.clone(),
         pg,
         file_ignore_directive,
-        default_jsx_factory,
-        default_jsx_fragment_factory,
+        config,
+        artifacts.scope,
+        artifacts.module_graph,
       );
 
       // Run configured lint rules.
       for rule in self.ctx.rules.iter() {
-        rule.lint_program_with_ast_view(&mut context, pg);
+        let diagnostics_len = context.diagnostics().len();
+        context.start_rule_budget(self.ctx.rule_time_budget);
+        if mode.catch_unwind {
+          let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            rule.lint_program_with_ast_view(&mut context, pg);
+          }));
+          if let Err(payload) = result {
+            context.recover_from_rule_panic(
+              rule.code(),
+              diagnostics_len,
+              panic_message(&*payload),
+            );
+            continue;
+          }
+        } else {
+          rule.lint_program_with_ast_view(&mut context, pg);
+        }
+        if context.is_rule_budget_exceeded() {
+          context.recover_from_rule_timeout(rule.code(), diagnostics_len);
+        }
       }
+      context.start_rule_budget(None);
 
       let mut external_rule_codes = vec![];
       if let Some(cb) = maybe_external_linter {
@@ -243,8 +674,68 @@ impl Linter {
       }
 
       self.collect_diagnostics(context, external_rule_codes)
-    });
+    })
+  }
+}
 
+/// Drops the diagnostics of rules that are superseded by another enabled rule,
+/// see `LintRule::supersedes`, if that rule reported a diagnostic at the same
+/// span, or at a span containing it or contained by it.
+fn remove_superseded_diagnostics(
+  diagnostics: &mut Vec<LintDiagnostic>,
+  rules: &[Box<dyn LintRule>],
+) {
+  let superseded_by = |code: &str| {
+    rules
+      .iter()
+      .filter(|rule| rule.supersedes().contains(&code))
+      .map(|rule| rule.code())
+      .collect::<Vec<_>>()
+  };
+  let superseding_ranges = |codes: &[&str]| {
     diagnostics
+      .iter()
+      .filter(|diagnostic| codes.contains(&diagnostic.details.code.as_str()))
+      .filter_map(|diagnostic| diagnostic.range.as_ref())
+      .map(|range| range.range)
+      .collect::<Vec<_>>()
+  };
+
+  let mut removed = HashSet::new();
+  for (i, diagnostic) in diagnostics.iter().enumerate() {
+    let Some(range) = diagnostic.range.as_ref().map(|range| range.range) else {
+      continue;
+    };
+    let codes = superseded_by(&diagnostic.details.code);
+    if codes.is_empty() {
+      continue;
+    }
+    let is_superseded = superseding_ranges(&codes).iter().any(|other| {
+      (other.start <= range.start && range.end <= other.end)
+        || (range.start <= other.start && other.end <= range.end)
+    });
+    if is_superseded {
+      removed.insert(i);
+    }
+  }
+
+  if !removed.is_empty() {
+    let mut i = 0;
+    diagnostics.retain(|_| {
+      let keep = !removed.contains(&i);
+      i += 1;
+      keep
+    });
+  }
+}
+
+/// Returns the message a panic was raised with, if it has one.
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+  if let Some(message) = payload.downcast_ref::<&str>() {
+    message
+  } else if let Some(message) = payload.downcast_ref::<String>() {
+    message
+  } else {
+    "Box<dyn Any>"
   }
 }
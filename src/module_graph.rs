@@ -0,0 +1,294 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! The files of a project and the imports between them, so rules can check
+//! how the files relate to each other when a whole project is linted, see
+//! `Linter::lint_project`. Only imports with string literal specifiers are
+//! recorded, and bare specifiers like `"npm:preact"` or `"@std/path"` aren't
+//! resolved, since that needs an import map.
+
+use crate::swc_util::StringRepr;
+use deno_ast::swc::ast::{
+  CallExpr, Callee, ExportAll, Expr, ImportDecl, Lit, NamedExport, ObjectLit,
+  Prop, PropOrSpread, Str,
+};
+use deno_ast::swc::visit::{noop_visit_type, Visit, VisitWith};
+use deno_ast::{ModuleSpecifier, ParsedSource, ProgramRef};
+use std::collections::HashSet;
+
+/// The files of a project and the imports between them.
+#[derive(Debug, Default)]
+pub struct ModuleGraph {
+  specifiers: HashSet<ModuleSpecifier>,
+  imports: Vec<ModuleImport>,
+}
+
+/// An import of a module, either with an import or export declaration or
+/// with a dynamic `import()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleImport {
+  /// The module the import is in.
+  pub referrer: ModuleSpecifier,
+  /// The resolved specifier of the imported module.
+  pub specifier: ModuleSpecifier,
+  /// The import attributes, e.g. `type: "json"`, sorted by key. Attributes
+  /// whose values aren't string literals are left out.
+  pub attributes: Vec<(String, String)>,
+}
+
+impl ModuleGraph {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Adds a file that isn't a JavaScript or TypeScript module, like a JSON
+  /// module, so imports of it can be checked.
+  pub fn add_file(&mut self, specifier: ModuleSpecifier) {
+    self.specifiers.insert(specifier);
+  }
+
+  /// Adds a module along with its imports.
+  pub fn add_module(&mut self, parsed_source: &ParsedSource) {
+    let referrer = parsed_source.specifier().clone();
+    let mut collector = ImportCollector {
+      referrer: &referrer,
+      imports: &mut self.imports,
+    };
+    match parsed_source.program_ref() {
+      ProgramRef::Module(m) => m.visit_with(&mut collector),
+      ProgramRef::Script(s) => s.visit_with(&mut collector),
+    }
+    self.specifiers.insert(referrer);
+  }
+
+  /// Checks if the file is part of the project.
+  pub fn contains(&self, specifier: &ModuleSpecifier) -> bool {
+    self.specifiers.contains(specifier)
+  }
+
+  /// Returns the imports of the module, in the order the modules importing
+  /// it were added.
+  pub fn imports_of<'a>(
+    &'a self,
+    specifier: &'a ModuleSpecifier,
+  ) -> impl Iterator<Item = &'a ModuleImport> + 'a {
+    self
+      .imports
+      .iter()
+      .filter(move |import| import.specifier == *specifier)
+  }
+}
+
+/// Resolves a specifier imported by `referrer`, or returns `None` if it's a
+/// bare specifier. The query and fragment of `file:` specifiers are removed,
+/// so the result can be looked up in the `ModuleGraph`.
+pub fn resolve_import(
+  specifier: &str,
+  referrer: &ModuleSpecifier,
+) -> Option<ModuleSpecifier> {
+  let is_relative = specifier.starts_with("./")
+    || specifier.starts_with("../")
+    || specifier.starts_with('/');
+  let mut resolved = if is_relative {
+    referrer.join(specifier).ok()?
+  } else {
+    let resolved = ModuleSpecifier::parse(specifier).ok()?;
+    // Specifiers like `npm:preact` and `jsr:@std/path` are URLs as well, but
+    // they don't point to files.
+    if !matches!(resolved.scheme(), "file" | "http" | "https") {
+      return None;
+    }
+    resolved
+  };
+  if resolved.scheme() == "file" {
+    resolved.set_query(None);
+    resolved.set_fragment(None);
+  }
+  Some(resolved)
+}
+
+/// Returns the `key: "value"` attributes of an import, sorted by key.
+pub(crate) fn import_attributes(with: &ObjectLit) -> Vec<(String, String)> {
+  let mut attributes = with
+    .props
+    .iter()
+    .filter_map(|prop| {
+      let PropOrSpread::Prop(prop) = prop else {
+        return None;
+      };
+      let Prop::KeyValue(key_value) = &**prop else {
+        return None;
+      };
+      let Expr::Lit(Lit::Str(value)) = &*key_value.value else {
+        return None;
+      };
+      Some((key_value.key.string_repr()?, value.value.to_string()))
+    })
+    .collect::<Vec<_>>();
+  attributes.sort();
+  attributes
+}
+
+/// Returns the attributes object of the options of a dynamic `import()`, i.e.
+/// the value of its `with` or `assert` property.
+pub(crate) fn dynamic_import_attributes(
+  options: &ObjectLit,
+) -> Option<&ObjectLit> {
+  options.props.iter().find_map(|prop| {
+    let PropOrSpread::Prop(prop) = prop else {
+      return None;
+    };
+    let Prop::KeyValue(key_value) = &**prop else {
+      return None;
+    };
+    let key = key_value.key.string_repr()?;
+    match &*key_value.value {
+      Expr::Object(attributes) if key == "with" || key == "assert" => {
+        Some(attributes)
+      }
+      _ => None,
+    }
+  })
+}
+
+struct ImportCollector<'a> {
+  referrer: &'a ModuleSpecifier,
+  imports: &'a mut Vec<ModuleImport>,
+}
+
+impl ImportCollector<'_> {
+  fn add(&mut self, src: &Str, with: Option<&ObjectLit>) {
+    let Some(specifier) = resolve_import(&src.value, self.referrer) else {
+      return;
+    };
+    self.imports.push(ModuleImport {
+      referrer: self.referrer.clone(),
+      specifier,
+      attributes: with.map(import_attributes).unwrap_or_default(),
+    });
+  }
+}
+
+impl Visit for ImportCollector<'_> {
+  noop_visit_type!();
+
+  fn visit_import_decl(&mut self, import_decl: &ImportDecl) {
+    // Type-only imports are erased, so the module isn't loaded.
+    if !import_decl.type_only {
+      self.add(&import_decl.src, import_decl.with.as_deref());
+    }
+  }
+
+  fn visit_named_export(&mut self, named_export: &NamedExport) {
+    if let Some(src) = &named_export.src {
+      if !named_export.type_only {
+        self.add(src, named_export.with.as_deref());
+      }
+    }
+  }
+
+  fn visit_export_all(&mut self, export_all: &ExportAll) {
+    if !export_all.type_only {
+      self.add(&export_all.src, export_all.with.as_deref());
+    }
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+    if matches!(call_expr.callee, Callee::Import(_)) {
+      if let Some(Expr::Lit(Lit::Str(src))) =
+        call_expr.args.first().map(|arg| &*arg.expr)
+      {
+        match call_expr.args.get(1).map(|arg| &*arg.expr) {
+          None => self.add(src, None),
+          Some(Expr::Object(options)) => {
+            self.add(src, dynamic_import_attributes(options))
+          }
+          // The attributes aren't known.
+          Some(_) => {}
+        }
+      }
+    }
+    call_expr.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::parse;
+
+  fn specifier(specifier: &str) -> ModuleSpecifier {
+    ModuleSpecifier::parse(specifier).unwrap()
+  }
+
+  #[test]
+  fn resolves_imports() {
+    let referrer = specifier("file:///project/src/mod.ts");
+    let cases = [
+      ("./a.ts", Some("file:///project/src/a.ts")),
+      ("../a.json?v=1#b", Some("file:///project/a.json")),
+      ("/a.ts", Some("file:///a.ts")),
+      ("https://deno.land/x/a.ts", Some("https://deno.land/x/a.ts")),
+      ("npm:preact", None),
+      ("@std/path", None),
+    ];
+    for (import, expected) in cases {
+      assert_eq!(
+        resolve_import(import, &referrer),
+        expected.map(specifier),
+        "{}",
+        import
+      );
+    }
+  }
+
+  #[test]
+  fn collects_imports() {
+    let parsed_source = parse(
+      r#"
+import a from "./a.json" with { type: "json" };
+import type { B } from "./b.ts";
+export * from "./c.ts";
+export { d } from "npm:d";
+const e = await import("./e.json", { with: { type: "json", foo: "bar" } });
+const f = await import("./f.ts", options);
+"#,
+    );
+    let mut graph = ModuleGraph::new();
+    graph.add_module(&parsed_source);
+    graph.add_file(specifier("file:///a.json"));
+
+    let referrer = parsed_source.specifier().clone();
+    assert!(graph.contains(&referrer));
+    assert!(graph.contains(&specifier("file:///a.json")));
+    assert!(!graph.contains(&specifier("file:///c.ts")));
+
+    let json = |key: &str, value: &str| (key.to_string(), value.to_string());
+    assert_eq!(
+      graph.imports,
+      vec![
+        ModuleImport {
+          referrer: referrer.clone(),
+          specifier: specifier("file:///a.json"),
+          attributes: vec![json("type", "json")],
+        },
+        ModuleImport {
+          referrer: referrer.clone(),
+          specifier: specifier("file:///c.ts"),
+          attributes: vec![],
+        },
+        ModuleImport {
+          referrer: referrer.clone(),
+          specifier: specifier("file:///e.json"),
+          attributes: vec![json("foo", "bar"), json("type", "json")],
+        },
+      ]
+    );
+    assert_eq!(
+      graph
+        .imports_of(&specifier("file:///a.json"))
+        .map(|import| &import.referrer)
+        .collect::<Vec<_>>(),
+      vec![&referrer]
+    );
+  }
+}
@@ -0,0 +1,493 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Validation of rule options against the JSON schema returned by
+//! [`LintRule::options_schema`].
+//!
+//! Only the keywords that are used by the schemas of the rules are
+//! supported, see [`SUPPORTED_KEYWORDS`].
+
+use crate::rules::LintRule;
+use serde_json::Value;
+
+/// The JSON schema keywords understood by [`validate_options`]. Other
+/// keywords are ignored.
+pub const SUPPORTED_KEYWORDS: &[&str] = &[
+  "type",
+  "description",
+  "properties",
+  "additionalProperties",
+  "required",
+  "items",
+  "uniqueItems",
+  "enum",
+  "anyOf",
+  "oneOf",
+  "minimum",
+  "maximum",
+  "minLength",
+  "maxLength",
+  "pattern",
+  "default",
+];
+
+/// A problem with the options of a rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleOptionsError {
+  /// Code of the rule.
+  pub rule: String,
+  /// JSON pointer to the invalid value within the options, e.g.
+  /// `/conventions/0/format`. Empty for the options as a whole.
+  pub path: String,
+  pub message: String,
+}
+
+impl std::fmt::Display for RuleOptionsError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    if self.path.is_empty() {
+      write!(
+        f,
+        "Invalid options for rule \"{}\": {}",
+        self.rule, self.message
+      )
+    } else {
+      write!(
+        f,
+        "Invalid options for rule \"{}\" at \"{}\": {}",
+        self.rule, self.path, self.message
+      )
+    }
+  }
+}
+
+impl std::error::Error for RuleOptionsError {}
+
+/// Returns a copy of the rule configured with the options, after validating
+/// them against the schema of the rule. Unlike [`LintRule::with_options`],
+/// all problems are returned, along with the path of the invalid value.
+pub fn configure_rule(
+  rule: &dyn LintRule,
+  options: Value,
+) -> Result<Box<dyn LintRule>, Vec<RuleOptionsError>> {
+  let errors = validate_options(rule, &options);
+  if !errors.is_empty() {
+    return Err(errors);
+  }
+  rule.with_options(options).map_err(|err| {
+    vec![RuleOptionsError {
+      rule: rule.code().to_string(),
+      path: String::new(),
+      message: err.to_string(),
+    }]
+  })
+}
+
+/// Validates the options against the schema of the rule.
+pub fn validate_options(
+  rule: &dyn LintRule,
+  options: &Value,
+) -> Vec<RuleOptionsError> {
+  let Some(schema) = rule.options_schema() else {
+    return vec![RuleOptionsError {
+      rule: rule.code().to_string(),
+      path: String::new(),
+      message: "The rule doesn't accept any options".to_string(),
+    }];
+  };
+  let mut errors = vec![];
+  validate(&schema, options, "", &mut errors);
+  errors
+    .into_iter()
+    .map(|(path, message)| RuleOptionsError {
+      rule: rule.code().to_string(),
+      path,
+      message,
+    })
+    .collect()
+}
+
+/// Returns the JSON schema of an object with the options of every rule that
+/// accepts options, keyed by rule code, e.g. for the autocompletion of lint
+/// options in editors.
+pub fn rules_options_schema(rules: &[Box<dyn LintRule>]) -> Value {
+  let properties = rules
+    .iter()
+    .filter_map(|rule| Some((rule.code().to_string(), rule.options_schema()?)))
+    .collect::<serde_json::Map<_, _>>();
+  serde_json::json!({
+    "$schema": "http://json-schema.org/draft-07/schema#",
+    "type": "object",
+    "properties": properties,
+    "additionalProperties": false
+  })
+}
+
+fn validate(
+  schema: &Value,
+  value: &Value,
+  path: &str,
+  errors: &mut Vec<(String, String)>,
+) {
+  let Some(schema) = schema.as_object() else {
+    return;
+  };
+
+  if let Some(expected) = schema.get("type") {
+    let types = match expected {
+      Value::String(ty) => vec![ty.as_str()],
+      Value::Array(types) => types.iter().filter_map(Value::as_str).collect(),
+      _ => vec![],
+    };
+    if !types.is_empty() && !types.iter().any(|ty| has_type(value, ty)) {
+      errors.push((
+        path.to_string(),
+        format!(
+          "Expected {}, found {}",
+          types.join(" or "),
+          type_name(value)
+        ),
+      ));
+      return;
+    }
+  }
+
+  if let Some(Value::Array(allowed)) = schema.get("enum") {
+    if !allowed.contains(value) {
+      let allowed = allowed
+        .iter()
+        .map(Value::to_string)
+        .collect::<Vec<_>>()
+        .join(", ");
+      errors.push((path.to_string(), format!("Expected one of {}", allowed)));
+    }
+  }
+
+  for (keyword, needs_one) in [("anyOf", false), ("oneOf", true)] {
+    let Some(Value::Array(schemas)) = schema.get(keyword) else {
+      continue;
+    };
+    let matches = schemas
+      .iter()
+      .filter(|schema| {
+        let mut errors = vec![];
+        validate(schema, value, path, &mut errors);
+        errors.is_empty()
+      })
+      .count();
+    if matches == 0 || (needs_one && matches > 1) {
+      let message = if needs_one {
+        "Expected a value matching exactly one of the allowed schemas"
+      } else {
+        "Expected a value matching one of the allowed schemas"
+      };
+      errors.push((path.to_string(), message.to_string()));
+    }
+  }
+
+  match value {
+    Value::Object(object) => {
+      validate_object(schema, object, path, errors);
+    }
+    Value::Array(items) => {
+      if let Some(items_schema) = schema.get("items") {
+        for (index, item) in items.iter().enumerate() {
+          validate(items_schema, item, &format!("{}/{}", path, index), errors);
+        }
+      }
+      if schema.get("uniqueItems") == Some(&Value::Bool(true)) {
+        for (index, item) in items.iter().enumerate() {
+          if items[..index].contains(item) {
+            errors.push((
+              format!("{}/{}", path, index),
+              format!("Duplicate item {}", item),
+            ));
+          }
+        }
+      }
+    }
+    Value::Number(number) => {
+      let number = number.as_f64().unwrap_or_default();
+      if let Some(minimum) = schema.get("minimum").and_then(Value::as_f64) {
+        if number < minimum {
+          errors.push((
+            path.to_string(),
+            format!("Expected a number greater than or equal to {}", minimum),
+          ));
+        }
+      }
+      if let Some(maximum) = schema.get("maximum").and_then(Value::as_f64) {
+        if number > maximum {
+          errors.push((
+            path.to_string(),
+            format!("Expected a number less than or equal to {}", maximum),
+          ));
+        }
+      }
+    }
+    Value::String(string) => {
+      let len = string.chars().count() as u64;
+      if let Some(min) = schema.get("minLength").and_then(Value::as_u64) {
+        if len < min {
+          errors.push((
+            path.to_string(),
+            format!("Expected at least {} characters", min),
+          ));
+        }
+      }
+      if let Some(max) = schema.get("maxLength").and_then(Value::as_u64) {
+        if len > max {
+          errors.push((
+            path.to_string(),
+            format!("Expected at most {} characters", max),
+          ));
+        }
+      }
+      if let Some(pattern) = schema.get("pattern").and_then(Value::as_str) {
+        let is_match = regex::Regex::new(pattern)
+          .map_or(true, |regex| regex.is_match(string));
+        if !is_match {
+          errors.push((
+            path.to_string(),
+            format!("Expected a string matching `{}`", pattern),
+          ));
+        }
+      }
+    }
+    Value::Bool(_) | Value::Null => {}
+  }
+}
+
+fn validate_object(
+  schema: &serde_json::Map<String, Value>,
+  object: &serde_json::Map<String, Value>,
+  path: &str,
+  errors: &mut Vec<(String, String)>,
+) {
+  let properties = schema.get("properties").and_then(Value::as_object);
+  if let Some(Value::Array(required)) = schema.get("required") {
+    for name in required.iter().filter_map(Value::as_str) {
+      if !object.contains_key(name) {
+        errors.push((
+          path.to_string(),
+          format!("Missing required property \"{}\"", name),
+        ));
+      }
+    }
+  }
+  for (key, value) in object {
+    let property_path = format!("{}/{}", path, escape_pointer(key));
+    match properties.and_then(|properties| properties.get(key)) {
+      Some(property_schema) => {
+        validate(property_schema, value, &property_path, errors)
+      }
+      None => match schema.get("additionalProperties") {
+        Some(Value::Bool(false)) => {
+          errors.push((property_path, format!("Unknown property \"{}\"", key)))
+        }
+        Some(additional @ Value::Object(_)) => {
+          validate(additional, value, &property_path, errors)
+        }
+        _ => {}
+      },
+    }
+  }
+}
+
+fn has_type(value: &Value, ty: &str) -> bool {
+  match ty {
+    "object" => value.is_object(),
+    "array" => value.is_array(),
+    "string" => value.is_string(),
+    "boolean" => value.is_boolean(),
+    "null" => value.is_null(),
+    "number" => value.is_number(),
+    "integer" => {
+      value.is_i64()
+        || value.is_u64()
+        || value.as_f64().map_or(false, |n| n.fract() == 0.0)
+    }
+    _ => true,
+  }
+}
+
+fn type_name(value: &Value) -> &'static str {
+  match value {
+    Value::Null => "null",
+    Value::Bool(_) => "boolean",
+    Value::Number(_) => "number",
+    Value::String(_) => "string",
+    Value::Array(_) => "array",
+    Value::Object(_) => "object",
+  }
+}
+
+/// Escapes a property name for a JSON pointer, see RFC 6901.
+fn escape_pointer(key: &str) -> String {
+  key.replace('~', "~0").replace('/', "~1")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::rules::get_all_rules;
+  use crate::rules::naming_convention::NamingConvention;
+  use crate::rules::no_console::NoConsole;
+  use crate::rules::no_magic_numbers::NoMagicNumbers;
+  use crate::rules::no_restricted_paths::NoRestrictedPaths;
+  use crate::rules::RuleRegistry;
+  use serde_json::json;
+
+  fn errors(rule: &dyn LintRule, options: Value) -> Vec<(String, String)> {
+    validate_options(rule, &options)
+      .into_iter()
+      .map(|error| (error.path, error.message))
+      .collect()
+  }
+
+  #[test]
+  fn validates_options() {
+    let rule = NamingConvention::default();
+    assert!(errors(&rule, json!({})).is_empty());
+    assert!(
+      errors(&rule, json!({ "minLength": 2, "maxLength": 10 })).is_empty()
+    );
+    assert_eq!(
+      errors(&rule, json!({ "minLength": "2", "unknown": true })),
+      vec![
+        (
+          "/minLength".to_string(),
+          "Expected integer, found string".to_string()
+        ),
+        (
+          "/unknown".to_string(),
+          "Unknown property \"unknown\"".to_string()
+        ),
+      ]
+    );
+    assert_eq!(
+      errors(&rule, json!({ "minLength": -1 })),
+      vec![(
+        "/minLength".to_string(),
+        "Expected a number greater than or equal to 0".to_string()
+      )]
+    );
+    assert_eq!(
+      errors(&rule, json!([])),
+      vec![(String::new(), "Expected object, found array".to_string())]
+    );
+
+    let rule = NoMagicNumbers::default();
+    let ignore_errors = errors(&rule, json!({ "ignore": [1, "2n", 1, "x"] }));
+    assert_eq!(
+      ignore_errors,
+      vec![
+        (
+          "/ignore/3".to_string(),
+          "Expected a value matching one of the allowed schemas".to_string()
+        ),
+        ("/ignore/2".to_string(), "Duplicate item 1".to_string()),
+      ]
+    );
+  }
+
+  #[test]
+  fn configure_rule_returns_structured_errors() {
+    let rule = configure_rule(&NoConsole, json!({ "allow": ["log"] }));
+    assert_eq!(
+      rule.unwrap_err(),
+      vec![RuleOptionsError {
+        rule: "no-console".to_string(),
+        path: String::new(),
+        message: "The rule doesn't accept any options".to_string(),
+      }]
+    );
+
+    let rule = configure_rule(&NamingConvention::default(), json!({}));
+    assert!(rule.is_ok());
+
+    let error = configure_rule(
+      &NoRestrictedPaths::default(),
+      json!({ "zones": [{ "from": "src/cli" }] }),
+    )
+    .unwrap_err();
+    assert_eq!(
+      error[0].to_string(),
+      "Invalid options for rule \"no-restricted-paths\" at \"/zones/0\": Missing required property \"target\""
+    );
+  }
+
+  #[test]
+  fn rule_schemas_are_supported() {
+    fn check_keywords(code: &str, schema: &Value) {
+      let Some(object) = schema.as_object() else {
+        return;
+      };
+      for (keyword, value) in object {
+        assert!(
+          SUPPORTED_KEYWORDS.contains(&keyword.as_str()),
+          "Unsupported keyword \"{}\" in the options schema of \"{}\"",
+          keyword,
+          code
+        );
+        match keyword.as_str() {
+          "properties" => {
+            for property in value.as_object().unwrap().values() {
+              check_keywords(code, property);
+            }
+          }
+          "items" | "additionalProperties" => check_keywords(code, value),
+          "anyOf" | "oneOf" => {
+            for schema in value.as_array().unwrap() {
+              check_keywords(code, schema);
+            }
+          }
+          _ => {}
+        }
+      }
+    }
+
+    for rule in get_all_rules() {
+      let accepts_options = rule.with_options(json!({})).is_ok();
+      match rule.options_schema() {
+        Some(schema) => {
+          assert!(
+            accepts_options,
+            "\"{}\" has an options schema, but doesn't accept options",
+            rule.code()
+          );
+          check_keywords(rule.code(), &schema);
+          assert!(
+            validate_options(rule.as_ref(), &json!({})).is_empty(),
+            "Empty options of \"{}\" don't match its schema",
+            rule.code()
+          );
+        }
+        None => assert!(
+          !accepts_options,
+          "\"{}\" accepts options, but has no options schema",
+          rule.code()
+        ),
+      }
+    }
+
+    let schema = rules_options_schema(&get_all_rules());
+    assert!(schema["properties"]["eqeqeq"].is_object());
+    assert!(schema["properties"].get("no-console").is_none());
+  }
+
+  #[test]
+  fn rules_reject_unknown_options() {
+    let options = json!({ "unknownOption": true });
+    for rule in RuleRegistry::new().rules() {
+      assert!(
+        rule.with_options(options.clone()).is_err(),
+        "\"{}\" accepts unknown options",
+        rule.code()
+      );
+      assert!(
+        !validate_options(rule.as_ref(), &options).is_empty(),
+        "The options schema of \"{}\" allows unknown options",
+        rule.code()
+      );
+    }
+  }
+}
@@ -0,0 +1,162 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Helpers for fixes that reorder sibling nodes, e.g. the members of a union
+//! type or the properties of an object literal.
+
+use crate::context::Context;
+use crate::diagnostic::LintFixChange;
+use deno_ast::SourcePos;
+use deno_ast::SourceRange;
+use deno_ast::SourceRanged;
+use deno_ast::SourceRangedForSpanned;
+
+/// Builds a change that reorders `items`, the ranges of sibling nodes in
+/// source order, so that the item at `order[i]` ends up at position `i`.
+///
+/// Comments on the lines above an item, below the token before it, are moved
+/// together with it, and so are comments that start on the line an item ends
+/// on, after its separator, e.g. `a: 1, // about a`. Comments above the first
+/// item at the start of the file, like a copyright header, stay in place. The
+/// text between items (separators and whitespace) is left in place, so the
+/// layout of the list is preserved.
+pub(crate) fn reorder_change(
+  ctx: &Context,
+  items: &[SourceRange],
+  order: &[usize],
+) -> LintFixChange {
+  debug_assert_eq!(items.len(), order.len());
+  debug_assert!(!items.is_empty());
+
+  let text_info = ctx.text_info();
+  let regions = items
+    .iter()
+    .map(|item| {
+      let start = ctx
+        .previous_token(item.start)
+        .and_then(|token| {
+          let token_line = text_info.line_index(token.end());
+          ctx
+            .comment_lookups()
+            .leading_comments(*item)
+            .map(|comment| comment.start())
+            .find(|start| text_info.line_index(*start) > token_line)
+        })
+        .unwrap_or(item.start);
+      SourceRange::new(start, item.end)
+    })
+    .collect::<Vec<_>>();
+  let trailing = items
+    .iter()
+    .enumerate()
+    .map(|(i, item)| {
+      trailing_comments(ctx, *item, items.get(i + 1).map(|next| next.start))
+    })
+    .collect::<Vec<_>>();
+
+  // The text after each item up to the next region. After the last item,
+  // it's the trailing comments, or the separators if any of the items moved
+  // there may bring a trailing comment along.
+  let last = items.len() - 1;
+  let end = match trailing[last] {
+    Some(comments) => comments.end,
+    None if trailing.iter().any(Option::is_some) => {
+      let rest = &text_info.text_str()
+        [items[last].end.as_byte_index(text_info.range().start)..];
+      let separators = rest.len() - rest.trim_start_matches(is_separator).len();
+      let separators = rest[..separators].trim_end().len();
+      items[last].end + separators
+    }
+    None => items[last].end,
+  };
+  let tails = (0..items.len()).map(|i| {
+    let tail_end = regions.get(i + 1).map_or(end, |next| next.start);
+    let (separator, rest) = match trailing[i] {
+      Some(comments) => (
+        SourceRange::new(items[i].end, comments.start),
+        SourceRange::new(comments.end, tail_end),
+      ),
+      None => {
+        let text =
+          SourceRange::new(items[i].end, tail_end).text_fast(text_info);
+        let line_end = text.find('\n').unwrap_or(text.len());
+        let separator = items[i].end + text[..line_end].trim_end().len();
+        (
+          SourceRange::new(items[i].end, separator),
+          SourceRange::new(separator, tail_end),
+        )
+      }
+    };
+    (separator, rest)
+  });
+
+  let mut new_text = String::new();
+  for ((separator, rest), &index) in tails.zip(order) {
+    new_text.push_str(regions[index].text_fast(text_info));
+    new_text.push_str(separator.text_fast(text_info).trim_end());
+    if let Some(comments) = trailing[index] {
+      let spacing =
+        SourceRange::new(items[index].end, comments.start).text_fast(text_info);
+      let spacing = &spacing[spacing.trim_end().len()..];
+      new_text.push_str(if spacing.is_empty() { " " } else { spacing });
+      new_text.push_str(comments.text_fast(text_info));
+    }
+    new_text.push_str(rest.text_fast(text_info));
+  }
+
+  LintFixChange {
+    new_text: new_text.into(),
+    range: SourceRange::new(regions[0].start, end),
+  }
+}
+
+/// Returns the range of the comments that start on the line `item` ends on,
+/// with only separators between them and the item, e.g. `// b` in
+/// `b: 1, // b`.
+fn trailing_comments(
+  ctx: &Context,
+  item: SourceRange,
+  next: Option<SourcePos>,
+) -> Option<SourceRange> {
+  let text_info = ctx.text_info();
+  let line = text_info.line_index(item.end);
+  let mut comments = ctx
+    .comment_lookups()
+    .all_comments()
+    .map(|comment| comment.range())
+    .filter(|comment| comment.start >= item.end)
+    .collect::<Vec<_>>();
+  comments.sort_by_key(|comment| comment.start);
+  let mut range: Option<SourceRange> = None;
+  for comment in comments {
+    if text_info.line_index(comment.start) != line
+      || next.is_some_and(|next| comment.end > next)
+    {
+      break;
+    }
+    let before = range.map_or(item.end, |range| range.end);
+    let between = SourceRange::new(before, comment.start).text_fast(text_info);
+    if !between.chars().all(is_separator) {
+      break;
+    }
+    range = Some(SourceRange::new(
+      range.map_or(comment.start, |range| range.start),
+      comment.end,
+    ));
+  }
+  range
+}
+
+fn is_separator(c: char) -> bool {
+  matches!(c, ',' | ';' | '|' | '&') || (c.is_whitespace() && c != '\n')
+}
+
+/// Returns the order that sorts `items` with `compare`, as expected by
+/// [`reorder_change`]. The sort is stable, so equal items keep their order.
+pub(crate) fn sorted_order<T>(
+  items: &[T],
+  mut compare: impl FnMut(&T, &T) -> std::cmp::Ordering,
+) -> Vec<usize> {
+  let mut order = (0..items.len()).collect::<Vec<_>>();
+  order.sort_by(|a, b| compare(&items[*a], &items[*b]));
+  order
+}
@@ -5,10 +5,13 @@ use crate::tags;
 use crate::tags::Tags;
 use crate::Program;
 use crate::ProgramRef;
+use std::borrow::Cow;
 use std::cmp::Ordering;
 use std::collections::HashSet;
 
+pub mod accessor_pairs;
 pub mod adjacent_overload_signatures;
+pub mod arrow_body_style;
 pub mod ban_ts_comment;
 pub mod ban_types;
 pub mod ban_unknown_rule_code;
@@ -16,16 +19,27 @@ pub mod ban_untagged_ignore;
 pub mod ban_untagged_todo;
 pub mod ban_unused_ignore;
 pub mod camelcase;
+pub mod check_identifier_spelling;
+pub mod complexity;
+pub mod consistent_return;
 pub mod constructor_super;
 pub mod default_param_last;
+pub mod dot_notation;
 pub mod eqeqeq;
 pub mod explicit_function_return_type;
 pub mod explicit_module_boundary_types;
 pub mod for_direction;
 pub mod fresh_handler_export;
 pub mod fresh_server_event_handlers;
+pub mod func_style;
 pub mod getter_return;
 pub mod guard_for_in;
+pub mod jsdoc_check_tag_names;
+pub mod jsdoc_param_match;
+pub mod jsdoc_require_returns;
+pub mod json_import_attributes;
+pub mod jsx_alt_text;
+pub mod jsx_anchor_is_valid;
 pub mod jsx_boolean_value;
 pub mod jsx_button_has_type;
 pub mod jsx_curly_braces;
@@ -37,7 +51,12 @@ pub mod jsx_no_unescaped_entities;
 pub mod jsx_no_useless_fragment;
 pub mod jsx_props_no_spread_multi;
 pub mod jsx_void_dom_elements_no_children;
+pub mod max_depth;
+pub mod max_lines_per_function;
+pub mod max_nested_callbacks;
+pub mod naming_convention;
 pub mod no_array_constructor;
+pub mod no_async_iife_without_catch;
 pub mod no_async_promise_executor;
 pub mod no_await_in_loop;
 pub mod no_await_in_sync_fn;
@@ -46,6 +65,7 @@ pub mod no_case_declarations;
 pub mod no_class_assign;
 pub mod no_compare_neg_zero;
 pub mod no_cond_assign;
+pub mod no_confusing_arrow;
 pub mod no_console;
 pub mod no_const_assign;
 pub mod no_constant_condition;
@@ -53,22 +73,28 @@ pub mod no_control_regex;
 pub mod no_debugger;
 pub mod no_delete_var;
 pub mod no_deprecated_deno_api;
+pub mod no_deprecated_usage;
 pub mod no_dupe_args;
 pub mod no_dupe_class_members;
 pub mod no_dupe_else_if;
 pub mod no_dupe_keys;
 pub mod no_duplicate_case;
+pub mod no_duplicate_imports;
+pub mod no_duplicate_type_union_members;
 pub mod no_empty;
 pub mod no_empty_character_class;
 pub mod no_empty_enum;
 pub mod no_empty_interface;
 pub mod no_empty_pattern;
+pub mod no_empty_static_block;
 pub mod no_eval;
 pub mod no_ex_assign;
 pub mod no_explicit_any;
 pub mod no_external_imports;
 pub mod no_extra_boolean_cast;
+pub mod no_extra_label;
 pub mod no_extra_non_null_assertion;
+pub mod no_extra_semi;
 pub mod no_fallthrough;
 pub mod no_func_assign;
 pub mod no_global_assign;
@@ -80,23 +106,33 @@ pub mod no_inner_declarations;
 pub mod no_invalid_regexp;
 pub mod no_invalid_triple_slash_reference;
 pub mod no_irregular_whitespace;
+pub mod no_lonely_if;
+pub mod no_magic_numbers;
+pub mod no_misleading_character_class;
 pub mod no_misused_new;
+pub mod no_misused_promises;
+pub mod no_multi_assign;
 pub mod no_namespace;
+pub mod no_namespace_import;
+pub mod no_negated_condition;
 pub mod no_new_symbol;
 pub mod no_node_globals;
 pub mod no_non_null_asserted_optional_chain;
 pub mod no_non_null_assertion;
 pub mod no_obj_calls;
 pub mod no_octal;
+pub mod no_process_exit;
 pub mod no_process_global;
 pub mod no_prototype_builtins;
 pub mod no_redeclare;
 pub mod no_regex_spaces;
+pub mod no_restricted_paths;
 pub mod no_self_assign;
 pub mod no_self_compare;
 pub mod no_setter_return;
 pub mod no_shadow_restricted_names;
 pub mod no_sparse_arrays;
+pub mod no_static_only_class;
 pub mod no_sync_fn_in_async_fn;
 pub mod no_this_alias;
 pub mod no_this_before_super;
@@ -105,25 +141,56 @@ pub mod no_top_level_await;
 pub mod no_undef;
 pub mod no_unreachable;
 pub mod no_unsafe_finally;
+pub mod no_unsafe_member_access;
 pub mod no_unsafe_negation;
+pub mod no_unused_capturing_group;
 pub mod no_unused_labels;
 pub mod no_unused_vars;
+pub mod no_useless_catch;
+pub mod no_useless_constructor;
+pub mod no_useless_non_capturing_group;
 pub mod no_useless_rename;
+pub mod no_useless_return;
+pub mod no_useless_template_literals;
+pub mod no_useless_yield_star;
 pub mod no_var;
 pub mod no_window;
 pub mod no_window_prefix;
 pub mod no_with;
+pub mod one_var;
+pub mod operator_assignment;
+pub mod prefer_arrow_callback;
 pub mod prefer_as_const;
 pub mod prefer_ascii;
+pub mod prefer_caught_error_name;
 pub mod prefer_const;
+pub mod prefer_enum_initializers;
+pub mod prefer_literal_enum_member;
 pub mod prefer_namespace_keyword;
+pub mod prefer_nullish_coalescing;
 pub mod prefer_primordials;
+pub mod prefer_promise_reject_errors;
+pub mod prefer_readonly;
+pub mod prefer_string_raw;
+pub mod prefer_template;
 pub mod react_no_danger;
 pub mod react_no_danger_with_children;
 pub mod react_rules_of_hooks;
 pub mod require_await;
+pub mod require_jsdoc;
 pub mod require_yield;
-pub mod single_var_declarator;
+pub mod return_await;
+#[deprecated(
+  note = "`single-var-declarator` was replaced by `one-var`, use `one_var::OneVar` instead"
+)]
+pub mod single_var_declarator {
+  pub use super::one_var::OneVar as SingleVarDeclarator;
+}
+pub mod sort_imports;
+pub mod sort_keys;
+pub mod sort_type_constituents;
+pub mod switch_exhaustiveness_check;
+pub mod todo_format;
 pub mod triple_slash_reference;
 pub mod use_isnan;
 pub mod valid_typeof;
@@ -153,6 +220,128 @@ pub trait LintRule: std::fmt::Debug + Send + Sync {
   fn priority(&self) -> u32 {
     0
   }
+
+  /// Structured documentation of the rule, used by tooling like
+  /// `dlint explain` to describe the rule without going out of sync with its
+  /// implementation.
+  fn docs(&self) -> RuleDocs {
+    RuleDocs::default()
+  }
+
+  /// JSON schema of the options this rule accepts, if any.
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    None
+  }
+
+  /// Whether diagnostics reported by this rule may come with automatic fixes.
+  fn fixable(&self) -> bool {
+    false
+  }
+
+  /// Codes of rules whose diagnostics this rule reports as well, e.g. because
+  /// it is a stricter version of them. When both rules are enabled, the
+  /// diagnostics of the superseded rules that overlap the diagnostics of this
+  /// rule are dropped, so the same code isn't reported twice.
+  fn supersedes(&self) -> &'static [&'static str] {
+    &[]
+  }
+
+  /// Returns a copy of this rule configured with the given options, as
+  /// described by [`LintRule::options_schema`]. Rules that don't accept any
+  /// options return an error.
+  fn with_options(
+    &self,
+    _options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Err(serde::de::Error::custom(format!(
+      "Rule \"{}\" doesn't accept any options",
+      self.code()
+    )))
+  }
+}
+
+/// Documentation of a lint rule, see [`LintRule::docs`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RuleDocs {
+  /// Summary of what the rule detects and why it is considered a problem.
+  pub description: &'static str,
+  /// Code snippets reported by the rule.
+  pub invalid_examples: &'static [&'static str],
+  /// Code snippets accepted by the rule.
+  pub valid_examples: &'static [&'static str],
+}
+
+/// A rule code that keeps working after its rule was replaced by another
+/// rule, see [`get_rule_aliases`].
+#[derive(Debug, Clone, Copy)]
+pub struct RuleAlias {
+  /// The code of the replaced rule.
+  pub code: &'static str,
+  /// The code of the rule that replaces it.
+  pub replacement: &'static str,
+  /// The options the replacement is configured with to behave like the
+  /// replaced rule, as accepted by [`LintRule::with_options`].
+  pub options: fn() -> serde_json::Value,
+}
+
+static RULE_ALIASES: &[RuleAlias] = &[RuleAlias {
+  code: "single-var-declarator",
+  replacement: "one-var",
+  options: || serde_json::json!({ "var": "never", "let": "never", "const": "never" }),
+}];
+
+/// Returns the codes of removed rules that map to a configuration of another
+/// rule. They are resolved by [`filtered_rules`].
+pub fn get_rule_aliases() -> &'static [RuleAlias] {
+  RULE_ALIASES
+}
+
+pub(crate) fn find_rule_alias(code: &str) -> Option<&'static RuleAlias> {
+  RULE_ALIASES.iter().find(|alias| alias.code == code)
+}
+
+/// A problem with the rule codes of a lint configuration that doesn't prevent
+/// linting, but should be shown to the user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuleConfigNotice {
+  /// The code is an alias of a rule that was replaced.
+  Deprecated {
+    code: &'static str,
+    replacement: &'static str,
+    options: String,
+  },
+}
+
+impl std::fmt::Display for RuleConfigNotice {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      RuleConfigNotice::Deprecated {
+        code,
+        replacement,
+        options,
+      } => write!(
+        f,
+        "Rule \"{}\" is deprecated, use \"{}\" with the options {} instead",
+        code, replacement, options
+      ),
+    }
+  }
+}
+
+/// Checks the rule codes of a lint configuration, e.g. the ones that are
+/// passed to [`filtered_rules`], and returns notices about them.
+pub fn validate_rule_codes<'a>(
+  codes: impl IntoIterator<Item = &'a str>,
+) -> Vec<RuleConfigNotice> {
+  codes
+    .into_iter()
+    .filter_map(find_rule_alias)
+    .map(|alias| RuleConfigNotice::Deprecated {
+      code: alias.code,
+      replacement: alias.replacement,
+      options: (alias.options)().to_string(),
+    })
+    .collect()
 }
 
 /// TODO(@magurotuna): remove this after all rules get to use ast_view
@@ -191,6 +380,10 @@ pub fn recommended_rules(
 /// - if `maybe_include` is `Some`, rules with matching codes will be added
 ///   to the return list
 ///
+/// Codes of [rule aliases](get_rule_aliases) in `maybe_exclude` and
+/// `maybe_include` refer to the rules that replace them. A replacement that is
+/// only included through an alias is configured with the options of the alias.
+///
 /// Before returning the list will sorted alphabetically.
 pub fn filtered_rules(
   all_rules: Vec<Box<dyn LintRule>>,
@@ -200,6 +393,30 @@ pub fn filtered_rules(
 ) -> Vec<Box<dyn LintRule>> {
   let tags_set =
     maybe_tags.map(|tags| tags.into_iter().collect::<HashSet<_>>());
+  let included_aliases = maybe_include
+    .iter()
+    .flatten()
+    .filter_map(|code| find_rule_alias(code))
+    .filter(|alias| {
+      !maybe_include
+        .iter()
+        .flatten()
+        .any(|code| code == alias.replacement)
+    })
+    .collect::<Vec<_>>();
+  let resolve_aliases = |codes: Option<Vec<String>>| {
+    codes.map(|codes| {
+      codes
+        .into_iter()
+        .map(|code| match find_rule_alias(&code) {
+          Some(alias) => alias.replacement.to_string(),
+          None => code,
+        })
+        .collect::<Vec<_>>()
+    })
+  };
+  let maybe_exclude = resolve_aliases(maybe_exclude);
+  let maybe_include = resolve_aliases(maybe_include);
 
   let mut rules = all_rules
     .into_iter()
@@ -227,6 +444,17 @@ pub fn filtered_rules(
 
       passes
     })
+    .map(|rule| {
+      match included_aliases
+        .iter()
+        .find(|alias| alias.replacement == rule.code())
+      {
+        Some(alias) => rule
+          .with_options((alias.options)())
+          .expect("options of rule aliases are valid"),
+        None => rule,
+      }
+    })
     .collect::<Vec<_>>();
 
   rules.sort_by_key(|r| r.code());
@@ -234,6 +462,103 @@ pub fn filtered_rules(
   rules
 }
 
+/// The lint rules available to a linter, i.e. the built-in rules and the ones
+/// registered by embedders. Registered rules take part in filtering by tags
+/// and codes like the built-in ones, and their codes are known to
+/// `ban-unknown-rule-code`.
+pub struct RuleRegistry {
+  rules: Vec<Box<dyn LintRule>>,
+}
+
+/// An error returned by [`RuleRegistry::register`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuleRegistryError {
+  /// A rule or a rule alias with the same code is already registered.
+  DuplicateCode(&'static str),
+}
+
+impl std::fmt::Display for RuleRegistryError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      RuleRegistryError::DuplicateCode(code) => {
+        write!(f, "Rule \"{}\" is already registered", code)
+      }
+    }
+  }
+}
+
+impl std::error::Error for RuleRegistryError {}
+
+impl Default for RuleRegistry {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl RuleRegistry {
+  /// Creates a registry with the built-in rules.
+  pub fn new() -> Self {
+    Self {
+      rules: get_all_rules(),
+    }
+  }
+
+  /// Adds a rule, which must have a code that no other rule or
+  /// [rule alias](get_rule_aliases) uses.
+  pub fn register(
+    &mut self,
+    rule: Box<dyn LintRule>,
+  ) -> Result<(), RuleRegistryError> {
+    let code = rule.code();
+    if self.get(code).is_some() || find_rule_alias(code).is_some() {
+      return Err(RuleRegistryError::DuplicateCode(code));
+    }
+    self.rules.push(rule);
+    Ok(())
+  }
+
+  /// Returns the rule with the given code, e.g. to show its documentation.
+  pub fn get(&self, code: &str) -> Option<&dyn LintRule> {
+    self
+      .rules
+      .iter()
+      .find(|rule| rule.code() == code)
+      .map(|rule| rule.as_ref())
+  }
+
+  /// Returns all rules, with the registered ones after the built-in ones.
+  pub fn rules(&self) -> &[Box<dyn LintRule>] {
+    &self.rules
+  }
+
+  /// Returns the codes of all rules, as expected by
+  /// [`LinterOptions::all_rule_codes`](crate::linter::LinterOptions).
+  pub fn all_rule_codes(&self) -> HashSet<Cow<'static, str>> {
+    self
+      .rules
+      .iter()
+      .map(|rule| rule.code())
+      .chain(RULE_ALIASES.iter().map(|alias| alias.code))
+      .map(Cow::from)
+      .collect()
+  }
+
+  /// Returns the recommended rules, see [`recommended_rules`].
+  pub fn recommended_rules(self) -> Vec<Box<dyn LintRule>> {
+    recommended_rules(self.rules)
+  }
+
+  /// Returns the rules matching the configuration, see [`filtered_rules`].
+  pub fn filtered_rules(
+    self,
+    maybe_tags: Option<Vec<String>>,
+    maybe_exclude: Option<Vec<String>>,
+    maybe_include: Option<Vec<String>>,
+  ) -> Vec<Box<dyn LintRule>> {
+    filtered_rules(self.rules, maybe_tags, maybe_exclude, maybe_include)
+  }
+}
+
 /// Sort lint rules by priority and alphabetically.
 pub(crate) fn sort_rules_by_priority(rules: &mut [Box<dyn LintRule>]) {
   rules.sort_by(|rule1, rule2| {
@@ -249,24 +574,37 @@ pub(crate) fn sort_rules_by_priority(rules: &mut [Box<dyn LintRule>]) {
 
 fn get_all_rules_raw() -> Vec<Box<dyn LintRule>> {
   vec![
+    Box::new(accessor_pairs::AccessorPairs::default()),
     Box::new(adjacent_overload_signatures::AdjacentOverloadSignatures),
-    Box::new(ban_ts_comment::BanTsComment),
-    Box::new(ban_types::BanTypes),
+    Box::new(arrow_body_style::ArrowBodyStyle::default()),
+    Box::new(ban_ts_comment::BanTsComment::default()),
+    Box::new(ban_types::BanTypes::default()),
     Box::new(ban_unknown_rule_code::BanUnknownRuleCode),
     Box::new(ban_untagged_ignore::BanUntaggedIgnore),
     Box::new(ban_untagged_todo::BanUntaggedTodo),
     Box::new(ban_unused_ignore::BanUnusedIgnore),
-    Box::new(camelcase::Camelcase),
+    Box::new(camelcase::Camelcase::default()),
+    Box::new(check_identifier_spelling::CheckIdentifierSpelling::default()),
+    Box::new(complexity::Complexity::default()),
+    Box::new(consistent_return::ConsistentReturn::default()),
     Box::new(constructor_super::ConstructorSuper),
     Box::new(default_param_last::DefaultParamLast),
-    Box::new(eqeqeq::Eqeqeq),
+    Box::new(dot_notation::DotNotation::default()),
+    Box::new(eqeqeq::Eqeqeq::default()),
     Box::new(explicit_function_return_type::ExplicitFunctionReturnType),
     Box::new(explicit_module_boundary_types::ExplicitModuleBoundaryTypes),
     Box::new(for_direction::ForDirection),
-    Box::new(fresh_handler_export::FreshHandlerExport),
-    Box::new(fresh_server_event_handlers::FreshServerEventHandlers),
+    Box::new(fresh_handler_export::FreshHandlerExport::default()),
+    Box::new(fresh_server_event_handlers::FreshServerEventHandlers::default()),
+    Box::new(func_style::FuncStyle::default()),
     Box::new(getter_return::GetterReturn),
     Box::new(guard_for_in::GuardForIn),
+    Box::new(jsdoc_check_tag_names::JsdocCheckTagNames::default()),
+    Box::new(jsdoc_param_match::JsdocParamMatch),
+    Box::new(jsdoc_require_returns::JsdocRequireReturns),
+    Box::new(json_import_attributes::JsonImportAttributes),
+    Box::new(jsx_alt_text::JSXAltText),
+    Box::new(jsx_anchor_is_valid::JSXAnchorIsValid),
     Box::new(jsx_boolean_value::JSXBooleanValue),
     Box::new(jsx_button_has_type::JSXButtonHasType),
     Box::new(jsx_curly_braces::JSXCurlyBraces),
@@ -278,39 +616,53 @@ fn get_all_rules_raw() -> Vec<Box<dyn LintRule>> {
     Box::new(jsx_no_useless_fragment::JSXNoUselessFragment),
     Box::new(jsx_props_no_spread_multi::JSXPropsNoSpreadMulti),
     Box::new(jsx_void_dom_elements_no_children::JSXVoidDomElementsNoChildren),
+    Box::new(max_depth::MaxDepth::default()),
+    Box::new(max_lines_per_function::MaxLinesPerFunction::default()),
+    Box::new(max_nested_callbacks::MaxNestedCallbacks::default()),
+    Box::new(naming_convention::NamingConvention::default()),
     Box::new(no_array_constructor::NoArrayConstructor),
+    Box::new(no_async_iife_without_catch::NoAsyncIifeWithoutCatch),
     Box::new(no_async_promise_executor::NoAsyncPromiseExecutor),
     Box::new(no_await_in_loop::NoAwaitInLoop),
     Box::new(no_await_in_sync_fn::NoAwaitInSyncFn),
-    Box::new(no_boolean_literal_for_arguments::NoBooleanLiteralForArguments),
+    Box::new(
+      no_boolean_literal_for_arguments::NoBooleanLiteralForArguments::default(),
+    ),
     Box::new(no_case_declarations::NoCaseDeclarations),
     Box::new(no_class_assign::NoClassAssign),
     Box::new(no_compare_neg_zero::NoCompareNegZero),
     Box::new(no_cond_assign::NoCondAssign),
+    Box::new(no_confusing_arrow::NoConfusingArrow::default()),
     Box::new(no_console::NoConsole),
     Box::new(no_const_assign::NoConstAssign),
-    Box::new(no_constant_condition::NoConstantCondition),
+    Box::new(no_constant_condition::NoConstantCondition::default()),
     Box::new(no_control_regex::NoControlRegex),
     Box::new(no_debugger::NoDebugger),
     Box::new(no_delete_var::NoDeleteVar),
     Box::new(no_deprecated_deno_api::NoDeprecatedDenoApi),
+    Box::new(no_deprecated_usage::NoDeprecatedUsage),
     Box::new(no_dupe_args::NoDupeArgs),
     Box::new(no_dupe_class_members::NoDupeClassMembers),
     Box::new(no_dupe_else_if::NoDupeElseIf),
     Box::new(no_dupe_keys::NoDupeKeys),
     Box::new(no_duplicate_case::NoDuplicateCase),
+    Box::new(no_duplicate_imports::NoDuplicateImports::default()),
+    Box::new(no_duplicate_type_union_members::NoDuplicateTypeUnionMembers),
     Box::new(no_empty::NoEmpty),
     Box::new(no_empty_character_class::NoEmptyCharacterClass),
     Box::new(no_empty_enum::NoEmptyEnum),
     Box::new(no_empty_interface::NoEmptyInterface),
     Box::new(no_empty_pattern::NoEmptyPattern),
+    Box::new(no_empty_static_block::NoEmptyStaticBlock),
     Box::new(no_eval::NoEval),
     Box::new(no_ex_assign::NoExAssign),
     Box::new(no_explicit_any::NoExplicitAny),
     Box::new(no_external_imports::NoExternalImport),
     Box::new(no_extra_boolean_cast::NoExtraBooleanCast),
+    Box::new(no_extra_label::NoExtraLabel),
     Box::new(no_extra_non_null_assertion::NoExtraNonNullAssertion),
-    Box::new(no_fallthrough::NoFallthrough),
+    Box::new(no_extra_semi::NoExtraSemi),
+    Box::new(no_fallthrough::NoFallthrough::default()),
     Box::new(no_func_assign::NoFuncAssign),
     Box::new(no_global_assign::NoGlobalAssign),
     Box::new(
@@ -319,57 +671,94 @@ fn get_all_rules_raw() -> Vec<Box<dyn LintRule>> {
     Box::new(no_import_assertions::NoImportAssertions),
     Box::new(no_import_assign::NoImportAssign),
     Box::new(no_inferrable_types::NoInferrableTypes),
-    Box::new(no_inner_declarations::NoInnerDeclarations),
+    Box::new(no_inner_declarations::NoInnerDeclarations::default()),
     Box::new(no_invalid_regexp::NoInvalidRegexp),
     Box::new(no_invalid_triple_slash_reference::NoInvalidTripleSlashReference),
-    Box::new(no_irregular_whitespace::NoIrregularWhitespace),
+    Box::new(no_irregular_whitespace::NoIrregularWhitespace::default()),
+    Box::new(no_lonely_if::NoLonelyIf),
+    Box::new(no_magic_numbers::NoMagicNumbers::default()),
+    Box::new(no_misleading_character_class::NoMisleadingCharacterClass),
     Box::new(no_misused_new::NoMisusedNew),
+    Box::new(no_misused_promises::NoMisusedPromises),
+    Box::new(no_multi_assign::NoMultiAssign::default()),
     Box::new(no_namespace::NoNamespace),
+    Box::new(no_namespace_import::NoNamespaceImport::default()),
+    Box::new(no_negated_condition::NoNegatedCondition),
     Box::new(no_new_symbol::NoNewSymbol),
-    Box::new(no_node_globals::NoNodeGlobals),
+    Box::new(no_node_globals::NoNodeGlobals::default()),
     Box::new(
       no_non_null_asserted_optional_chain::NoNonNullAssertedOptionalChain,
     ),
     Box::new(no_non_null_assertion::NoNonNullAssertion),
     Box::new(no_obj_calls::NoObjCalls),
     Box::new(no_octal::NoOctal),
+    Box::new(no_process_exit::NoProcessExit::default()),
     Box::new(no_process_global::NoProcessGlobal),
     Box::new(no_prototype_builtins::NoPrototypeBuiltins),
     Box::new(no_redeclare::NoRedeclare),
     Box::new(no_regex_spaces::NoRegexSpaces),
+    Box::new(no_restricted_paths::NoRestrictedPaths::default()),
     Box::new(no_self_assign::NoSelfAssign),
     Box::new(no_self_compare::NoSelfCompare),
     Box::new(no_setter_return::NoSetterReturn),
     Box::new(no_shadow_restricted_names::NoShadowRestrictedNames),
     Box::new(no_sparse_arrays::NoSparseArrays),
-    Box::new(no_sync_fn_in_async_fn::NoSyncFnInAsyncFn),
-    Box::new(no_this_alias::NoThisAlias),
+    Box::new(no_static_only_class::NoStaticOnlyClass),
+    Box::new(no_sync_fn_in_async_fn::NoSyncFnInAsyncFn::default()),
+    Box::new(no_this_alias::NoThisAlias::default()),
     Box::new(no_this_before_super::NoThisBeforeSuper),
     Box::new(no_throw_literal::NoThrowLiteral),
-    Box::new(no_top_level_await::NoTopLevelAwait),
-    Box::new(no_undef::NoUndef),
+    Box::new(no_top_level_await::NoTopLevelAwait::default()),
+    Box::new(no_undef::NoUndef::default()),
     Box::new(no_unreachable::NoUnreachable),
     Box::new(no_unsafe_finally::NoUnsafeFinally),
+    Box::new(no_unsafe_member_access::NoUnsafeMemberAccess),
     Box::new(no_unsafe_negation::NoUnsafeNegation),
+    Box::new(no_unused_capturing_group::NoUnusedCapturingGroup),
     Box::new(no_unused_labels::NoUnusedLabels),
     Box::new(no_unused_vars::NoUnusedVars),
+    Box::new(no_useless_catch::NoUselessCatch),
+    Box::new(no_useless_constructor::NoUselessConstructor),
+    Box::new(no_useless_non_capturing_group::NoUselessNonCapturingGroup),
     Box::new(no_useless_rename::NoUselessRename),
+    Box::new(no_useless_return::NoUselessReturn),
+    Box::new(no_useless_template_literals::NoUselessTemplateLiterals),
+    Box::new(no_useless_yield_star::NoUselessYieldStar),
     Box::new(no_var::NoVar),
     Box::new(no_window::NoWindow),
     Box::new(no_window_prefix::NoWindowPrefix),
     Box::new(no_with::NoWith),
+    Box::new(one_var::OneVar::default()),
+    Box::new(operator_assignment::OperatorAssignment::default()),
+    Box::new(prefer_arrow_callback::PreferArrowCallback::default()),
     Box::new(prefer_as_const::PreferAsConst),
-    Box::new(prefer_ascii::PreferAscii),
-    Box::new(prefer_const::PreferConst),
+    Box::new(prefer_ascii::PreferAscii::default()),
+    Box::new(prefer_caught_error_name::PreferCaughtErrorName::default()),
+    Box::new(prefer_const::PreferConst::default()),
+    Box::new(prefer_enum_initializers::PreferEnumInitializers::default()),
+    Box::new(prefer_literal_enum_member::PreferLiteralEnumMember::default()),
     Box::new(prefer_namespace_keyword::PreferNamespaceKeyword),
+    Box::new(prefer_nullish_coalescing::PreferNullishCoalescing),
     Box::new(prefer_primordials::PreferPrimordials),
+    Box::new(
+      prefer_promise_reject_errors::PreferPromiseRejectErrors::default(),
+    ),
+    Box::new(prefer_readonly::PreferReadonly),
+    Box::new(prefer_string_raw::PreferStringRaw::default()),
+    Box::new(prefer_template::PreferTemplate),
     Box::new(react_no_danger::ReactNoDanger),
     Box::new(react_no_danger_with_children::ReactNoDangerWithChildren),
     Box::new(react_rules_of_hooks::ReactRulesOfHooks),
     Box::new(require_await::RequireAwait),
+    Box::new(require_jsdoc::RequireJsdoc::default()),
     Box::new(require_yield::RequireYield),
-    Box::new(single_var_declarator::SingleVarDeclarator),
-    Box::new(triple_slash_reference::TripleSlashReference),
+    Box::new(return_await::ReturnAwait::default()),
+    Box::new(sort_imports::SortImports::default()),
+    Box::new(sort_keys::SortKeys),
+    Box::new(sort_type_constituents::SortTypeConstituents),
+    Box::new(switch_exhaustiveness_check::SwitchExhaustivenessCheck),
+    Box::new(todo_format::TodoFormat::default()),
+    Box::new(triple_slash_reference::TripleSlashReference::default()),
     Box::new(use_isnan::UseIsNaN),
     Box::new(valid_typeof::ValidTypeof),
     Box::new(verbatim_module_syntax::VerbatimModuleSyntax),
@@ -380,7 +769,12 @@ fn get_all_rules_raw() -> Vec<Box<dyn LintRule>> {
 mod tests {
   use std::sync::Arc;
 
+  use crate::diagnostic::LintDiagnostic;
+  use crate::linter::{
+    EcmaVersion, LintConfig, LintFileOptions, Linter, LinterOptions,
+  };
   use crate::tags;
+  use deno_ast::{MediaType, ModuleSpecifier};
 
   use super::*;
 
@@ -479,6 +873,191 @@ mod tests {
     assert_eq!(rules[1].code(), "no-throw-literal");
   }
 
+  #[test]
+  fn filtered_rules_resolve_aliases() {
+    let rules = filtered_rules(
+      get_all_rules(),
+      Some(vec![]),
+      None,
+      Some(vec!["single-var-declarator".to_string()]),
+    );
+    assert_eq!(rules.len(), 1);
+    assert_eq!(rules[0].code(), "one-var");
+    let diagnostics = lint_with_rules(rules, "let a;\nlet b, c;");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+      diagnostics[0].details.message,
+      "Split `let` declarations into multiple statements"
+    );
+
+    // Ignore directives for the alias apply to its replacement.
+    let rules = filtered_rules(
+      get_all_rules(),
+      Some(vec![]),
+      None,
+      Some(vec!["single-var-declarator".to_string()]),
+    );
+    let diagnostics = lint_with_rules(
+      rules,
+      "// deno-lint-ignore single-var-declarator\nlet b, c;",
+    );
+    assert!(diagnostics.is_empty());
+
+    // Including the replacement directly keeps its own options.
+    let rules = filtered_rules(
+      get_all_rules(),
+      Some(vec![]),
+      None,
+      Some(vec![
+        "single-var-declarator".to_string(),
+        "one-var".to_string(),
+      ]),
+    );
+    assert_eq!(rules.len(), 1);
+    let diagnostics = lint_with_rules(rules, "let a;\nlet b, c;");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+      diagnostics[0].details.message,
+      "Combine this with the previous `let` declaration"
+    );
+
+    // Excluding an alias excludes its replacement.
+    let rules = filtered_rules(
+      get_all_rules(),
+      Some(vec![]),
+      Some(vec!["single-var-declarator".to_string()]),
+      Some(vec!["one-var".to_string()]),
+    );
+    assert!(rules.is_empty());
+  }
+
+  #[test]
+  fn validate_rule_codes_reports_aliases() {
+    for alias in get_rule_aliases() {
+      assert!(!get_all_rules().iter().any(|rule| rule.code() == alias.code));
+      let replacement = get_all_rules()
+        .into_iter()
+        .find(|rule| rule.code() == alias.replacement)
+        .unwrap();
+      assert!(replacement.with_options((alias.options)()).is_ok());
+    }
+
+    let notices = validate_rule_codes(["eqeqeq", "single-var-declarator"]);
+    let [RuleConfigNotice::Deprecated {
+      code,
+      replacement,
+      options,
+    }] = notices.as_slice()
+    else {
+      panic!("expected exactly one notice, got {:?}", notices);
+    };
+    assert_eq!(*code, "single-var-declarator");
+    assert_eq!(*replacement, "one-var");
+    assert_eq!(
+      serde_json::from_str::<serde_json::Value>(options).unwrap(),
+      serde_json::json!({ "var": "never", "let": "never", "const": "never" })
+    );
+    assert!(notices[0].to_string().starts_with(
+      r#"Rule "single-var-declarator" is deprecated, use "one-var" with the options {"#
+    ));
+    assert!(validate_rule_codes(["eqeqeq", "unknown-rule"]).is_empty());
+  }
+
+  #[derive(Debug)]
+  struct CustomRule;
+
+  impl LintRule for CustomRule {
+    fn lint_program_with_ast_view(
+      &self,
+      context: &mut Context,
+      program: Program,
+    ) {
+      use deno_ast::SourceRanged;
+      context.add_diagnostic(program.range(), "custom-rule", "Custom");
+    }
+
+    fn code(&self) -> &'static str {
+      "custom-rule"
+    }
+
+    fn tags(&self) -> Tags {
+      &[tags::RECOMMENDED]
+    }
+
+    fn docs(&self) -> RuleDocs {
+      RuleDocs {
+        description: "A rule of an embedder.",
+        ..Default::default()
+      }
+    }
+  }
+
+  #[test]
+  fn rule_registry_registers_custom_rules() {
+    let mut registry = RuleRegistry::new();
+    assert_eq!(registry.rules().len(), get_all_rules().len());
+    registry.register(Box::new(CustomRule)).unwrap();
+    assert_eq!(
+      registry.register(Box::new(CustomRule)),
+      Err(RuleRegistryError::DuplicateCode("custom-rule"))
+    );
+    assert_eq!(
+      registry.register(Box::new(eqeqeq::Eqeqeq::default())),
+      Err(RuleRegistryError::DuplicateCode("eqeqeq"))
+    );
+    assert_eq!(
+      registry.get("custom-rule").unwrap().docs().description,
+      "A rule of an embedder."
+    );
+    assert!(registry.all_rule_codes().contains("custom-rule"));
+    assert!(registry.all_rule_codes().contains("eqeqeq"));
+    assert!(registry.all_rule_codes().contains("single-var-declarator"));
+
+    let all_rule_codes = registry.all_rule_codes();
+    let rules = registry.recommended_rules();
+    assert!(rules.iter().any(|rule| rule.code() == "custom-rule"));
+    assert!(rules.iter().any(|rule| rule.code() == "no-var"));
+
+    // The ignore directive is neither unknown nor unused.
+    let linter = Linter::new(LinterOptions {
+      rules,
+      all_rule_codes,
+      custom_ignore_file_directive: None,
+      custom_ignore_diagnostic_directive: None,
+      extractors: vec![],
+      rule_time_budget: None,
+    });
+    let (_, diagnostics) = linter
+      .lint_file(LintFileOptions {
+        specifier: ModuleSpecifier::parse("file:///custom.ts").unwrap(),
+        source_code: "// deno-lint-ignore custom-rule\nexport const a = 1;\n"
+          .to_string(),
+        media_type: MediaType::TypeScript,
+        config: LintConfig {
+          default_jsx_factory: None,
+          default_jsx_fragment_factory: None,
+          ecma_version: EcmaVersion::default(),
+        },
+        external_linter: None,
+      })
+      .unwrap();
+    assert!(
+      diagnostics.is_empty(),
+      "{:?}",
+      diagnostics
+        .iter()
+        .map(|diagnostic| &diagnostic.details.code)
+        .collect::<Vec<_>>()
+    );
+
+    let rules = RuleRegistry::new().filtered_rules(
+      Some(vec![]),
+      None,
+      Some(vec!["custom-rule".to_string()]),
+    );
+    assert!(rules.is_empty());
+  }
+
   #[test]
   fn ensure_lint_rules_are_sharable_across_threads() {
     use std::thread::spawn;
@@ -500,13 +1079,78 @@ mod tests {
     }
   }
 
+  #[test]
+  fn docs_examples_match_rule_behavior() {
+    for rule in get_all_rules() {
+      let code = rule.code();
+      let docs = rule.docs();
+      for example in docs.invalid_examples {
+        assert!(
+          !lint_docs_example(code, example).is_empty(),
+          "Invalid example of \"{}\" is not reported:\n{}",
+          code,
+          example
+        );
+      }
+      for example in docs.valid_examples {
+        assert!(
+          lint_docs_example(code, example).is_empty(),
+          "Valid example of \"{}\" is reported:\n{}",
+          code,
+          example
+        );
+      }
+    }
+  }
+
+  fn lint_docs_example(code: &str, source: &str) -> Vec<LintDiagnostic> {
+    lint_with_rules(
+      filtered_rules(
+        get_all_rules(),
+        Some(vec![]),
+        None,
+        Some(vec![code.to_string()]),
+      ),
+      source,
+    )
+  }
+
+  fn lint_with_rules(
+    rules: Vec<Box<dyn LintRule>>,
+    source: &str,
+  ) -> Vec<LintDiagnostic> {
+    let linter = Linter::new(LinterOptions {
+      rules,
+      all_rule_codes: RuleRegistry::new().all_rule_codes(),
+      custom_ignore_file_directive: None,
+      custom_ignore_diagnostic_directive: None,
+      extractors: vec![],
+      rule_time_budget: None,
+    });
+
+    let (_, diagnostics) = linter
+      .lint_file(LintFileOptions {
+        specifier: ModuleSpecifier::parse("file:///docs_example.tsx").unwrap(),
+        source_code: source.to_string(),
+        media_type: MediaType::Tsx,
+        config: LintConfig {
+          default_jsx_factory: Some("React.createElement".to_owned()),
+          default_jsx_fragment_factory: Some("React.Fragment".to_owned()),
+          ecma_version: EcmaVersion::default(),
+        },
+        external_linter: None,
+      })
+      .expect("Failed to lint");
+    diagnostics
+  }
+
   #[test]
   fn sort_by_priority() {
     let mut rules: Vec<Box<dyn LintRule>> = vec![
       Box::new(ban_unknown_rule_code::BanUnknownRuleCode),
       Box::new(ban_unused_ignore::BanUnusedIgnore),
       Box::new(no_redeclare::NoRedeclare),
-      Box::new(eqeqeq::Eqeqeq),
+      Box::new(eqeqeq::Eqeqeq::default()),
     ];
 
     sort_rules_by_priority(&mut rules);
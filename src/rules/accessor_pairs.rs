@@ -0,0 +1,384 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::StringRepr;
+use crate::tags::Tags;
+use crate::Program;
+use deno_ast::swc::ast::{
+  ClassMember, MethodKind, Prop, PropName, PropOrSpread,
+};
+use deno_ast::view as ast_view;
+use deno_ast::{SourceRange, SourceRanged, SourceRangedForSpanned};
+use derive_more::Display;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct AccessorPairs {
+  /// Reports setters without a getter.
+  pub set_without_get: bool,
+  /// Reports getters without a setter.
+  pub get_without_set: bool,
+  /// Checks the accessors of classes too, not only of object literals.
+  pub enforce_for_class_members: bool,
+}
+
+impl Default for AccessorPairs {
+  fn default() -> Self {
+    Self {
+      set_without_get: true,
+      get_without_set: false,
+      enforce_for_class_members: true,
+    }
+  }
+}
+
+const CODE: &str = "accessor-pairs";
+
+#[derive(Display)]
+enum AccessorPairsMessage {
+  #[display(fmt = "Setter for `{}` is defined without a getter", _0)]
+  MissingGetter(String),
+  #[display(fmt = "Getter for `{}` is defined without a setter", _0)]
+  MissingSetter(String),
+}
+
+#[derive(Display)]
+enum AccessorPairsHint {
+  #[display(fmt = "Add a getter, so that the value can be read")]
+  MissingGetter,
+  #[display(
+    fmt = "Add a setter, or replace the getter with a read-only value"
+  )]
+  MissingSetter,
+}
+
+impl LintRule for AccessorPairs {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Requires a getter for each setter of object literals and \
+        classes, since a property that can only be written is usually a \
+        mistake. With the `getWithoutSet` option, getters without a setter \
+        are reported too. Static and instance accessors of classes are \
+        paired separately, and classes can be left out with the \
+        `enforceForClassMembers` option.",
+      invalid_examples: &[
+        "const user = {\n  set name(value) {\n    this._name = value;\n  },\n};",
+        "class User {\n  set name(value) {\n    this._name = value;\n  }\n}",
+      ],
+      valid_examples: &[
+        "const user = {\n  get name() {\n    return this._name;\n  },\n  set name(value) {\n    this._name = value;\n  },\n};",
+        "class User {\n  get name() {\n    return this._name;\n  }\n}",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "setWithoutGet": {
+          "description": "Reports setters without a getter",
+          "type": "boolean"
+        },
+        "getWithoutSet": {
+          "description": "Reports getters without a setter",
+          "type": "boolean"
+        },
+        "enforceForClassMembers": {
+          "description": "Checks the accessors of classes too, not only of object literals",
+          "type": "boolean"
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<AccessorPairs>(options)?))
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    AccessorPairsHandler { options: self }.traverse(program, context);
+  }
+}
+
+/// A getter or a setter of an object literal or a class.
+struct Accessor {
+  name: String,
+  is_static: bool,
+  kind: MethodKind,
+  range: SourceRange,
+}
+
+struct AccessorPairsHandler<'a> {
+  options: &'a AccessorPairs,
+}
+
+impl AccessorPairsHandler<'_> {
+  fn check(&self, accessors: Vec<Accessor>, ctx: &mut Context) {
+    let has_pair = |accessor: &Accessor, kind: MethodKind| {
+      accessors.iter().any(|other| {
+        other.kind == kind
+          && other.is_static == accessor.is_static
+          && other.name == accessor.name
+      })
+    };
+    for accessor in &accessors {
+      match accessor.kind {
+        MethodKind::Setter
+          if self.options.set_without_get
+            && !has_pair(accessor, MethodKind::Getter) =>
+        {
+          ctx.add_diagnostic_with_hint(
+            accessor.range,
+            CODE,
+            AccessorPairsMessage::MissingGetter(accessor.name.clone()),
+            AccessorPairsHint::MissingGetter,
+          );
+        }
+        MethodKind::Getter
+          if self.options.get_without_set
+            && !has_pair(accessor, MethodKind::Setter) =>
+        {
+          ctx.add_diagnostic_with_hint(
+            accessor.range,
+            CODE,
+            AccessorPairsMessage::MissingSetter(accessor.name.clone()),
+            AccessorPairsHint::MissingSetter,
+          );
+        }
+        _ => {}
+      }
+    }
+  }
+}
+
+/// Returns the name of a key, or the source text of a computed key whose
+/// value isn't known, e.g. `[key]`, so that it only pairs with the same
+/// expression.
+fn key_name(key: &PropName, ctx: &Context) -> String {
+  key
+    .string_repr()
+    .unwrap_or_else(|| key.range().text_fast(ctx.text_info()).to_string())
+}
+
+impl Handler for AccessorPairsHandler<'_> {
+  fn object_lit(
+    &mut self,
+    object_lit: &ast_view::ObjectLit,
+    ctx: &mut Context,
+  ) {
+    let accessors = object_lit
+      .inner
+      .props
+      .iter()
+      .filter_map(|prop| match prop {
+        PropOrSpread::Prop(prop) => match &**prop {
+          Prop::Getter(getter) => Some(Accessor {
+            name: key_name(&getter.key, ctx),
+            is_static: false,
+            kind: MethodKind::Getter,
+            range: getter.range(),
+          }),
+          Prop::Setter(setter) => Some(Accessor {
+            name: key_name(&setter.key, ctx),
+            is_static: false,
+            kind: MethodKind::Setter,
+            range: setter.range(),
+          }),
+          _ => None,
+        },
+        PropOrSpread::Spread(_) => None,
+      })
+      .collect();
+    self.check(accessors, ctx);
+  }
+
+  fn class(&mut self, class: &ast_view::Class, ctx: &mut Context) {
+    if !self.options.enforce_for_class_members {
+      return;
+    }
+    let accessors = class
+      .inner
+      .body
+      .iter()
+      .filter_map(|member| match member {
+        ClassMember::Method(method) if method.kind != MethodKind::Method => {
+          Some(Accessor {
+            name: key_name(&method.key, ctx),
+            is_static: method.is_static,
+            kind: method.kind,
+            range: method.range(),
+          })
+        }
+        ClassMember::PrivateMethod(method)
+          if method.kind != MethodKind::Method =>
+        {
+          Some(Accessor {
+            name: format!("#{}", method.key.name),
+            is_static: method.is_static,
+            kind: method.kind,
+            range: method.range(),
+          })
+        }
+        _ => None,
+      })
+      .collect();
+    self.check(accessors, ctx);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v9.0.0/tests/lib/rules/accessor-pairs.js
+  // MIT Licensed.
+
+  #[test]
+  fn accessor_pairs_valid() {
+    assert_lint_ok! {
+      AccessorPairs::default(),
+      "var o = { a: 1 };",
+      "var o = { get a() {} };",
+      "var o = { get a() {}, set a(v) {} };",
+      "var o = { set a(v) {}, get a() {} };",
+      "var o = { get 'a'() {}, set a(v) {} };",
+      "var o = { get [a]() {}, set [a](v) {} };",
+      "var o = { get ['a']() {}, set a(v) {} };",
+      "var o = { get 1() {}, set [1](v) {} };",
+      "var o = { ...set, a: 1 };",
+      "class A { get a() {} }",
+      "class A { get a() {} set a(v) {} }",
+      "class A { static get a() {} static set a(v) {} }",
+      "class A { get #a() {} set #a(v) {} }",
+      "class A { a() {} set(v) {} }",
+      "var A = class { set a(v) {} get a() {} };",
+    };
+
+    assert_lint_ok! {
+      AccessorPairs {
+        enforce_for_class_members: false,
+        ..Default::default()
+      },
+      "class A { set a(v) {} }",
+      "class A { static set a(v) {} }",
+    };
+
+    assert_lint_ok! {
+      AccessorPairs {
+        set_without_get: false,
+        ..Default::default()
+      },
+      "var o = { set a(v) {} };",
+      "class A { set a(v) {} }",
+    };
+  }
+
+  #[test]
+  fn accessor_pairs_invalid() {
+    assert_lint_err! {
+      AccessorPairs::default(),
+      "var o = { set a(v) {} };": [
+        {
+          col: 10,
+          message: variant!(AccessorPairsMessage, MissingGetter, "a"),
+          hint: AccessorPairsHint::MissingGetter,
+        }
+      ],
+      "var o = { get b() {}, set a(v) {} };": [
+        {
+          col: 22,
+          message: variant!(AccessorPairsMessage, MissingGetter, "a"),
+          hint: AccessorPairsHint::MissingGetter,
+        }
+      ],
+      "var o = { get [a]() {}, set [b](v) {} };": [
+        {
+          col: 24,
+          message: variant!(AccessorPairsMessage, MissingGetter, "[b]"),
+          hint: AccessorPairsHint::MissingGetter,
+        }
+      ],
+      "var o = { a: 1, set a(v) {} };": [
+        {
+          col: 16,
+          message: variant!(AccessorPairsMessage, MissingGetter, "a"),
+          hint: AccessorPairsHint::MissingGetter,
+        }
+      ],
+      "class A { set a(v) {} }": [
+        {
+          col: 10,
+          message: variant!(AccessorPairsMessage, MissingGetter, "a"),
+          hint: AccessorPairsHint::MissingGetter,
+        }
+      ],
+      "class A { get a() {} static set a(v) {} }": [
+        {
+          col: 21,
+          message: variant!(AccessorPairsMessage, MissingGetter, "a"),
+          hint: AccessorPairsHint::MissingGetter,
+        }
+      ],
+      "class A { set #a(v) {} }": [
+        {
+          col: 10,
+          message: variant!(AccessorPairsMessage, MissingGetter, "#a"),
+          hint: AccessorPairsHint::MissingGetter,
+        }
+      ],
+      "var A = class { set a(v) {} };": [
+        {
+          col: 16,
+          message: variant!(AccessorPairsMessage, MissingGetter, "a"),
+          hint: AccessorPairsHint::MissingGetter,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      AccessorPairs {
+        get_without_set: true,
+        ..Default::default()
+      },
+      "var o = { get a() {} };": [
+        {
+          col: 10,
+          message: variant!(AccessorPairsMessage, MissingSetter, "a"),
+          hint: AccessorPairsHint::MissingSetter,
+        }
+      ],
+      "class A { get a() {} set b(v) {} }": [
+        {
+          col: 10,
+          message: variant!(AccessorPairsMessage, MissingSetter, "a"),
+          hint: AccessorPairsHint::MissingSetter,
+        },
+        {
+          col: 21,
+          message: variant!(AccessorPairsMessage, MissingGetter, "b"),
+          hint: AccessorPairsHint::MissingGetter,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,387 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::{Handler, Traverse};
+use crate::linter::EcmaVersion;
+use crate::tags::{self, Tags};
+use crate::Program;
+use deno_ast::swc::ast::{
+  AssignTarget, AssignTargetPat, BlockStmtOrExpr, Expr, Stmt,
+};
+use deno_ast::view as ast_view;
+use deno_ast::{SourceRange, SourceRanged, SourceRangedForSpanned};
+use serde::Deserialize;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct ArrowBodyStyle {
+  /// Whether block bodies are only used when needed, or always.
+  pub style: Style,
+  /// With `as-needed`, still requires a block body with a `return` statement
+  /// for arrow functions returning object literals.
+  pub require_return_for_object_literal: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Style {
+  #[default]
+  AsNeeded,
+  Always,
+}
+
+const CODE: &str = "arrow-body-style";
+
+impl LintRule for ArrowBodyStyle {
+  fn tags(&self) -> Tags {
+    &[tags::STYLISTIC]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Enforces a consistent style for the bodies of arrow \
+        functions. With the default `as-needed` style, block bodies that only \
+        return a value must be written as expression bodies. With the \
+        `always` style, block bodies are required.",
+      invalid_examples: &["const double = (n) => {\n  return n * 2;\n};"],
+      valid_examples: &[
+        "const double = (n) => n * 2;",
+        "const log = (n) => {\n  console.log(n);\n  return n;\n};",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "style": {
+          "description": "Use block bodies only when needed, or always. Defaults to `as-needed`",
+          "enum": ["as-needed", "always"]
+        },
+        "requireReturnForObjectLiteral": {
+          "description": "With `as-needed`, require a block body for arrow functions returning object literals",
+          "type": "boolean"
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<ArrowBodyStyle>(options)?))
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    ArrowBodyStyleHandler { rule: self }.traverse(program, context);
+  }
+}
+
+enum DiagnosticKind {
+  UnexpectedBlock,
+  ExpectedBlock,
+}
+
+impl DiagnosticKind {
+  #[cfg(test)]
+  fn message_and_hint(&self) -> (&'static str, &'static str) {
+    (self.message(), self.hint())
+  }
+
+  fn message(&self) -> &'static str {
+    match *self {
+      DiagnosticKind::UnexpectedBlock => {
+        "Unexpected block statement surrounding arrow body"
+      }
+      DiagnosticKind::ExpectedBlock => {
+        "Expected block statement surrounding arrow body"
+      }
+    }
+  }
+
+  fn hint(&self) -> &'static str {
+    match *self {
+      DiagnosticKind::UnexpectedBlock => {
+        "Move the returned value immediately after the `=>`"
+      }
+      DiagnosticKind::ExpectedBlock => {
+        "Wrap the body in a block with a `return` statement"
+      }
+    }
+  }
+
+  fn fix_description(&self) -> &'static str {
+    match *self {
+      DiagnosticKind::UnexpectedBlock => "Use an expression body",
+      DiagnosticKind::ExpectedBlock => "Use a block body",
+    }
+  }
+}
+
+struct ArrowBodyStyleHandler<'a> {
+  rule: &'a ArrowBodyStyle,
+}
+
+impl<'a> Handler for ArrowBodyStyleHandler<'a> {
+  fn arrow_expr(&mut self, arrow: &ast_view::ArrowExpr, ctx: &mut Context) {
+    match (&*arrow.inner.body, self.rule.style) {
+      (BlockStmtOrExpr::BlockStmt(block), Style::AsNeeded) => {
+        let [Stmt::Return(return_stmt)] = block.stmts.as_slice() else {
+          return;
+        };
+        let Some(arg) = &return_stmt.arg else {
+          return;
+        };
+        if self.rule.require_return_for_object_literal && arg.is_object() {
+          return;
+        }
+
+        let range = block.range();
+        // Comments inside the block other than around the returned value
+        // would be lost, so only a hint is given for them.
+        let has_comments = ctx
+          .comment_lookups()
+          .has_comment_matching(range, |comment| {
+            !arg.range().contains(&comment.range())
+          });
+        let fix = (!has_comments).then(|| {
+          let text = arg.range().text_fast(ctx.text_info());
+          let new_text = if starts_with_object_or_is_seq(arg) {
+            format!("({})", text)
+          } else {
+            text.to_string()
+          };
+          (range, new_text)
+        });
+        report(DiagnosticKind::UnexpectedBlock, range, fix, ctx);
+      }
+      (BlockStmtOrExpr::Expr(expr), Style::Always) => {
+        let range = expr.range();
+        let text = range.text_fast(ctx.text_info());
+        let fix = Some((range, format!("{{ return {}; }}", text)));
+        report(DiagnosticKind::ExpectedBlock, range, fix, ctx);
+      }
+      _ => {}
+    }
+  }
+}
+
+fn report(
+  kind: DiagnosticKind,
+  range: SourceRange,
+  fix: Option<(SourceRange, String)>,
+  ctx: &mut Context,
+) {
+  // The fixes rewrite arrow functions, which are only available from ES2015
+  // on.
+  let fixes = fix
+    .filter(|_| ctx.ecma_version() >= EcmaVersion::Es2015)
+    .map(|(range, new_text)| LintFix {
+      description: kind.fix_description().into(),
+      changes: vec![LintFixChange {
+        new_text: new_text.into(),
+        range,
+      }],
+    })
+    .into_iter()
+    .collect();
+  ctx.add_diagnostic_with_fixes(
+    range,
+    CODE,
+    kind.message(),
+    Some(kind.hint().to_string()),
+    fixes,
+  );
+}
+
+/// Checks if the expression needs parentheses to be used as the expression
+/// body of an arrow function, i.e. it's a sequence expression or its text
+/// starts with an object literal which would be parsed as a block instead.
+fn starts_with_object_or_is_seq(expr: &Expr) -> bool {
+  match expr {
+    Expr::Object(_) | Expr::Seq(_) => true,
+    Expr::Assign(assign) => {
+      matches!(assign.left, AssignTarget::Pat(AssignTargetPat::Object(_)))
+    }
+    Expr::Member(member) => starts_with_object_or_is_seq(&member.obj),
+    Expr::Call(call) => call
+      .callee
+      .as_expr()
+      .is_some_and(|callee| starts_with_object_or_is_seq(callee)),
+    Expr::Bin(bin) => starts_with_object_or_is_seq(&bin.left),
+    Expr::Cond(cond) => starts_with_object_or_is_seq(&cond.test),
+    Expr::TaggedTpl(tpl) => starts_with_object_or_is_seq(&tpl.tag),
+    Expr::Update(update) if !update.prefix => {
+      starts_with_object_or_is_seq(&update.arg)
+    }
+    Expr::TsAs(as_expr) => starts_with_object_or_is_seq(&as_expr.expr),
+    Expr::TsSatisfies(satisfies) => {
+      starts_with_object_or_is_seq(&satisfies.expr)
+    }
+    Expr::TsNonNull(non_null) => starts_with_object_or_is_seq(&non_null.expr),
+    _ => false,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn arrow_body_style_valid() {
+    assert_lint_ok! {
+      ArrowBodyStyle::default(),
+      "var foo = () => 0;",
+      "var foo = () => ({ foo: 0 });",
+      "var foo = () => {};",
+      "var foo = () => { return; };",
+      "var foo = () => { bar(); };",
+      "var foo = () => { bar(); return 0; };",
+      "var foo = () => { if (a) { return 0; } };",
+    };
+
+    assert_lint_ok! {
+      ArrowBodyStyle {
+        require_return_for_object_literal: true,
+        ..Default::default()
+      },
+      "var foo = () => { return { foo: 0 }; };",
+    };
+
+    assert_lint_ok! {
+      ArrowBodyStyle {
+        style: Style::Always,
+        ..Default::default()
+      },
+      "var foo = () => { return 0; };",
+      "var foo = () => {};",
+    };
+  }
+
+  #[test]
+  fn arrow_body_style_invalid() {
+    let (unexpected_message, unexpected_hint) =
+      DiagnosticKind::UnexpectedBlock.message_and_hint();
+    let (expected_message, expected_hint) =
+      DiagnosticKind::ExpectedBlock.message_and_hint();
+
+    assert_lint_err! {
+      ArrowBodyStyle::default(),
+      "var foo = () => { return 0; };": [
+        {
+          col: 16,
+          message: unexpected_message,
+          hint: unexpected_hint,
+          fix: ("Use an expression body", "var foo = () => 0;"),
+        }
+      ],
+      "var foo = (a) => {\n  return a * 2;\n};": [
+        {
+          col: 17,
+          message: unexpected_message,
+          hint: unexpected_hint,
+          fix: ("Use an expression body", "var foo = (a) => a * 2;"),
+        }
+      ],
+      "var foo = () => { return { a: 1 }; };": [
+        {
+          col: 16,
+          message: unexpected_message,
+          hint: unexpected_hint,
+          fix: ("Use an expression body", "var foo = () => ({ a: 1 });"),
+        }
+      ],
+      "var foo = () => { return {}.toString(); };": [
+        {
+          col: 16,
+          message: unexpected_message,
+          hint: unexpected_hint,
+          fix: ("Use an expression body", "var foo = () => ({}.toString());"),
+        }
+      ],
+      "var foo = () => { return { a } = b; };": [
+        {
+          col: 16,
+          message: unexpected_message,
+          hint: unexpected_hint,
+          fix: ("Use an expression body", "var foo = () => ({ a } = b);"),
+        }
+      ],
+      "var foo = () => { return a, b; };": [
+        {
+          col: 16,
+          message: unexpected_message,
+          hint: unexpected_hint,
+          fix: ("Use an expression body", "var foo = () => (a, b);"),
+        }
+      ],
+      "var foo = () => { return /* zero */ 0; };": [
+        {
+          col: 16,
+          message: unexpected_message,
+          hint: unexpected_hint,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      ArrowBodyStyle {
+        style: Style::Always,
+        ..Default::default()
+      },
+      "var foo = () => 0;": [
+        {
+          col: 16,
+          message: expected_message,
+          hint: expected_hint,
+          fix: ("Use a block body", "var foo = () => { return 0; };"),
+        }
+      ],
+      "var foo = () => ({ a: 1 });": [
+        {
+          col: 16,
+          message: expected_message,
+          hint: expected_hint,
+          fix: ("Use a block body", "var foo = () => { return ({ a: 1 }); };"),
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn arrow_body_style_ecma_version() {
+    let lint = |source: &str, ecma_version: EcmaVersion| {
+      let (_, diagnostics) = crate::testing::lint_with_ecma_version(
+        Box::new(ArrowBodyStyle::default()),
+        source,
+        "file:///arrow_body_style_test.ts",
+        ecma_version,
+      );
+      diagnostics
+    };
+    // The fixes rewrite arrow functions, so they are only offered from ES2015
+    // on.
+    let diagnostics = lint("const a = () => { return 1; };", EcmaVersion::Es5);
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0].details.fixes.is_empty());
+    let diagnostics =
+      lint("const a = () => { return 1; };", EcmaVersion::Es2015);
+    assert_eq!(diagnostics[0].details.fixes.len(), 1);
+  }
+}
@@ -1,6 +1,6 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
-use super::{Context, LintRule};
+use super::{Context, LintRule, RuleDocs};
 use crate::tags;
 use crate::tags::Tags;
 use crate::Program;
@@ -10,19 +10,96 @@ use deno_ast::SourceRange;
 use deno_ast::SourceRangedForSpanned;
 use once_cell::sync::Lazy;
 use regex::Regex;
+use serde::{Deserialize, Deserializer};
 
 /// This rule differs from typescript-eslint. In typescript-eslint the following
 /// defaults apply:
 /// - ts-expect-error: allowed with comment
 /// - ts-ignore: not allowed
 /// - ts-nocheck: not allowed
+/// - minimumDescriptionLength: 3
 ///
 /// This rules defaults:
 /// - ts-expect-error: allowed with comment
 /// - ts-ignore: allowed with comment
 /// - ts-nocheck: allowed with comment
-#[derive(Debug)]
-pub struct BanTsComment;
+/// - minimumDescriptionLength: 1
+#[derive(Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct BanTsComment {
+  #[serde(rename = "ts-expect-error")]
+  pub ts_expect_error: DirectiveOption,
+  #[serde(rename = "ts-ignore")]
+  pub ts_ignore: DirectiveOption,
+  #[serde(rename = "ts-nocheck")]
+  pub ts_nocheck: DirectiveOption,
+  #[serde(rename = "ts-check")]
+  pub ts_check: DirectiveOption,
+  /// Minimum number of characters of the description of directives that are
+  /// allowed with a description.
+  pub minimum_description_length: usize,
+}
+
+impl Default for BanTsComment {
+  fn default() -> Self {
+    Self {
+      ts_expect_error: DirectiveOption::AllowWithDescription,
+      ts_ignore: DirectiveOption::AllowWithDescription,
+      ts_nocheck: DirectiveOption::AllowWithDescription,
+      ts_check: DirectiveOption::Allow,
+      minimum_description_length: 1,
+    }
+  }
+}
+
+/// How a directive may be used, configured as `false`, `true`,
+/// `"allow-with-description"` or `{ "descriptionFormat": "<regex>" }`.
+#[derive(Debug, Clone)]
+pub enum DirectiveOption {
+  Allow,
+  Ban,
+  AllowWithDescription,
+  /// Allowed with a description matching the regular expression.
+  DescriptionFormat(Regex),
+}
+
+impl<'de> Deserialize<'de> for DirectiveOption {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase", deny_unknown_fields)]
+    struct DescriptionFormat {
+      description_format: String,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum RawOption {
+      Bool(bool),
+      String(String),
+      DescriptionFormat(DescriptionFormat),
+    }
+
+    match RawOption::deserialize(deserializer)? {
+      RawOption::Bool(false) => Ok(DirectiveOption::Allow),
+      RawOption::Bool(true) => Ok(DirectiveOption::Ban),
+      RawOption::String(value) if value == "allow-with-description" => {
+        Ok(DirectiveOption::AllowWithDescription)
+      }
+      RawOption::String(value) => Err(serde::de::Error::custom(format!(
+        "Unknown value \"{}\", expected \"allow-with-description\"",
+        value
+      ))),
+      RawOption::DescriptionFormat(format) => {
+        Regex::new(&format.description_format)
+          .map(DirectiveOption::DescriptionFormat)
+          .map_err(serde::de::Error::custom)
+      }
+    }
+  }
+}
 
 const CODE: &str = "ban-ts-comment";
 
@@ -31,15 +108,27 @@ enum DirectiveKind {
   ExpectError,
   Ignore,
   Nocheck,
+  Check,
 }
 
 impl DirectiveKind {
+  fn as_str(&self) -> &'static str {
+    use DirectiveKind::*;
+    match *self {
+      ExpectError => "@ts-expect-error",
+      Ignore => "@ts-ignore",
+      Nocheck => "@ts-nocheck",
+      Check => "@ts-check",
+    }
+  }
+
   fn as_message(&self) -> &'static str {
     use DirectiveKind::*;
     match *self {
       ExpectError => "`@ts-expect-error` is not allowed without comment",
       Ignore => "`@ts-ignore` is not allowed without comment",
       Nocheck => "`@ts-nocheck` is not allowed without comment",
+      Check => "`@ts-check` is not allowed without comment",
     }
   }
 
@@ -49,23 +138,79 @@ impl DirectiveKind {
       ExpectError => "Add an in-line comment explaining the reason for using `@ts-expect-error`, like `// @ts-expect-error: <reason>`",
       Ignore => "Add an in-line comment explaining the reason for using `@ts-ignore`, like `// @ts-ignore: <reason>`",
       Nocheck => "Add an in-line comment explaining the reason for using `@ts-nocheck`, like `// @ts-nocheck: <reason>`",
+      Check => "Add an in-line comment explaining the reason for using `@ts-check`, like `// @ts-check: <reason>`",
+    }
+  }
+
+  fn as_banned_hint(&self) -> &'static str {
+    use DirectiveKind::*;
+    match *self {
+      Ignore => "Use `@ts-expect-error` instead, which reports an error once there is nothing to suppress",
+      ExpectError | Nocheck | Check => "Remove the directive",
     }
   }
 }
 
 impl BanTsComment {
-  fn report(
+  fn option(&self, kind: DirectiveKind) -> &DirectiveOption {
+    match kind {
+      DirectiveKind::ExpectError => &self.ts_expect_error,
+      DirectiveKind::Ignore => &self.ts_ignore,
+      DirectiveKind::Nocheck => &self.ts_nocheck,
+      DirectiveKind::Check => &self.ts_check,
+    }
+  }
+
+  fn check(
     &self,
     context: &mut Context,
     range: SourceRange,
     kind: DirectiveKind,
+    description: &str,
   ) {
-    context.add_diagnostic_with_hint(
-      range,
-      CODE,
-      kind.as_message(),
-      kind.as_hint(),
-    );
+    let is_too_short =
+      description.trim().chars().count() < self.minimum_description_length;
+    match self.option(kind) {
+      DirectiveOption::Allow => {}
+      DirectiveOption::Ban => {
+        context.add_diagnostic_with_hint(
+          range,
+          CODE,
+          format!("`{}` is not allowed", kind.as_str()),
+          kind.as_banned_hint(),
+        );
+      }
+      DirectiveOption::AllowWithDescription
+      | DirectiveOption::DescriptionFormat(_)
+        if is_too_short =>
+      {
+        let hint = if self.minimum_description_length > 1 {
+          format!(
+            "{} with at least {} characters",
+            kind.as_hint(),
+            self.minimum_description_length
+          )
+        } else {
+          kind.as_hint().to_string()
+        };
+        context.add_diagnostic_with_hint(range, CODE, kind.as_message(), hint);
+      }
+      DirectiveOption::AllowWithDescription => {}
+      DirectiveOption::DescriptionFormat(format) => {
+        if !format.is_match(description) {
+          context.add_diagnostic_with_hint(
+            range,
+            CODE,
+            format!(
+              "The description of `{}` must match `{}`",
+              kind.as_str(),
+              format
+            ),
+            "Rewrite the description to match the configured format",
+          );
+        }
+      }
+    }
   }
 }
 
@@ -78,48 +223,106 @@ impl LintRule for BanTsComment {
     CODE
   }
 
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows TypeScript directive comments that suppress \
+        type errors, like `// @ts-ignore`, without a description of the \
+        reason. Each of `ts-expect-error`, `ts-ignore`, `ts-nocheck` and \
+        `ts-check` can be configured as `false` to allow it, `true` to ban \
+        it, `\"allow-with-description\"` to require a description, or \
+        `{ \"descriptionFormat\": \"<regex>\" }` to require a description \
+        matching a regular expression. All but `ts-check` require a \
+        description by default, and `minimumDescriptionLength` sets how long \
+        it has to be.",
+      invalid_examples: &[
+        "// @ts-ignore\nconst a: string = 1;",
+        "// @ts-expect-error\nconst a: string = 1;",
+      ],
+      valid_examples: &[
+        "// @ts-expect-error: the fixture is deliberately of the wrong type\nconst a: string = 1;",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    let directive = serde_json::json!({
+      "anyOf": [
+        { "type": "boolean" },
+        { "enum": ["allow-with-description"] },
+        {
+          "type": "object",
+          "properties": {
+            "descriptionFormat": {
+              "description": "Regular expression the description has to match",
+              "type": "string"
+            }
+          },
+          "required": ["descriptionFormat"],
+          "additionalProperties": false
+        }
+      ]
+    });
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "ts-expect-error": directive,
+        "ts-ignore": directive,
+        "ts-nocheck": directive,
+        "ts-check": directive,
+        "minimumDescriptionLength": {
+          "description": "Minimum number of characters of the description of directives that are allowed with a description",
+          "type": "integer",
+          "minimum": 1
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<BanTsComment>(options)?))
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
     _program: Program,
   ) {
-    let mut violated_comment_ranges = Vec::new();
+    let mut directive_comments = Vec::new();
 
-    violated_comment_ranges.extend(context.all_comments().filter_map(|c| {
-      let kind = check_comment(c)?;
-      Some((c.range(), kind))
+    directive_comments.extend(context.all_comments().filter_map(|c| {
+      let (kind, description) = parse_directive(c)?;
+      Some((c.range(), kind, description))
     }));
 
-    for (range, kind) in violated_comment_ranges {
-      self.report(context, range, kind);
+    for (range, kind, description) in directive_comments {
+      self.check(context, range, kind, description);
     }
   }
 }
 
-/// Returns `None` if the comment includes no directives.
-fn check_comment(comment: &Comment) -> Option<DirectiveKind> {
+/// Returns the directive of the comment and the description that follows it,
+/// or `None` if the comment includes no directives.
+fn parse_directive(comment: &Comment) -> Option<(DirectiveKind, &str)> {
   if comment.kind != CommentKind::Line {
     return None;
   }
 
-  static EXPECT_ERROR_REGEX: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r"^/*\s*@ts-expect-error\s*$").unwrap());
-  static IGNORE_REGEX: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r"^/*\s*@ts-ignore\s*$").unwrap());
-  static NOCHECK_REGEX: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r"^/*\s*@ts-nocheck\s*$").unwrap());
-
-  if EXPECT_ERROR_REGEX.is_match(&comment.text) {
-    return Some(DirectiveKind::ExpectError);
-  }
-  if IGNORE_REGEX.is_match(&comment.text) {
-    return Some(DirectiveKind::Ignore);
-  }
-  if NOCHECK_REGEX.is_match(&comment.text) {
-    return Some(DirectiveKind::Nocheck);
-  }
+  static DIRECTIVE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^/*\s*@ts-(expect-error|ignore|nocheck|check)\b(.*)$").unwrap()
+  });
 
-  None
+  let captures = DIRECTIVE_REGEX.captures(&comment.text)?;
+  let kind = match &captures[1] {
+    "expect-error" => DirectiveKind::ExpectError,
+    "ignore" => DirectiveKind::Ignore,
+    "nocheck" => DirectiveKind::Nocheck,
+    _ => DirectiveKind::Check,
+  };
+  Some((kind, captures.get(2).unwrap().as_str()))
 }
 
 #[cfg(test)]
@@ -129,7 +332,7 @@ mod tests {
   #[test]
   fn ban_ts_comment_valid() {
     assert_lint_ok! {
-      BanTsComment,
+      BanTsComment::default(),
       r#"// just a comment containing @ts-expect-error somewhere"#,
       r#"// just a random @ts-expect-error     comment with too many spaces"#,
       r#"/* @ts-expect-error */"#,
@@ -180,7 +383,7 @@ console.log('hello');
   fn ban_ts_comment_invalid() {
     //@ts-expect-error
     assert_lint_err! {
-      BanTsComment,
+      BanTsComment::default(),
       DirectiveKind::ExpectError.as_message(),
       DirectiveKind::ExpectError.as_hint(),
       r#"// @ts-expect-error"# : [
@@ -207,7 +410,7 @@ console.log('hello');
 
     //@ts-ignore
     assert_lint_err! {
-      BanTsComment,
+      BanTsComment::default(),
       DirectiveKind::Ignore.as_message(),
       DirectiveKind::Ignore.as_hint(),
       r#"// @ts-ignore"# : [
@@ -234,7 +437,7 @@ console.log('hello');
 
     //@ts-nocheck
     assert_lint_err! {
-      BanTsComment,
+      BanTsComment::default(),
       DirectiveKind::Nocheck.as_message(),
       DirectiveKind::Nocheck.as_hint(),
       r#"// @ts-nocheck"# : [
@@ -259,4 +462,81 @@ console.log('hello');
       ]
     }
   }
+
+  #[test]
+  fn ban_ts_comment_with_options() {
+    let rule = || BanTsComment {
+      ts_ignore: DirectiveOption::Ban,
+      ts_check: DirectiveOption::AllowWithDescription,
+      ts_expect_error: DirectiveOption::DescriptionFormat(
+        Regex::new(r"^: TS\d+ because .+$").unwrap(),
+      ),
+      minimum_description_length: 10,
+      ..Default::default()
+    };
+
+    assert_lint_ok! {
+      rule(),
+      r#"// @ts-expect-error: TS2322 because the fixture is wrong"#,
+      r#"// @ts-nocheck: generated by a tool"#,
+      r#"// @ts-check: enabled for this file"#,
+      r#"// @ts-ignored"#,
+    };
+
+    assert_lint_err! {
+      rule(),
+      r#"// @ts-ignore: the types of the library are wrong"#: [
+        {
+          col: 0,
+          message: "`@ts-ignore` is not allowed",
+          hint: DirectiveKind::Ignore.as_banned_hint(),
+        }
+      ],
+      r#"// @ts-expect-error: the fixture is deliberately wrong"#: [
+        {
+          col: 0,
+          message: r"The description of `@ts-expect-error` must match `^: TS\d+ because .+$`",
+          hint: "Rewrite the description to match the configured format",
+        }
+      ],
+      r#"// @ts-expect-error: TS2322"#: [
+        {
+          col: 0,
+          message: DirectiveKind::ExpectError.as_message(),
+          hint: format!(
+            "{} with at least 10 characters",
+            DirectiveKind::ExpectError.as_hint()
+          ),
+        }
+      ],
+      r#"// @ts-nocheck: todo"#: [
+        {
+          col: 0,
+          message: DirectiveKind::Nocheck.as_message(),
+          hint: format!(
+            "{} with at least 10 characters",
+            DirectiveKind::Nocheck.as_hint()
+          ),
+        }
+      ],
+      r#"// @ts-check"#: [
+        {
+          col: 0,
+          message: DirectiveKind::Check.as_message(),
+          hint: format!(
+            "{} with at least 10 characters",
+            DirectiveKind::Check.as_hint()
+          ),
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn description_format_must_be_a_regex() {
+    let rule = BanTsComment::default().with_options(
+      serde_json::json!({ "ts-ignore": { "descriptionFormat": "(" } }),
+    );
+    assert!(rule.is_err());
+  }
 }
@@ -1,16 +1,54 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
-use super::{Context, LintRule};
+use super::{Context, LintRule, RuleDocs};
+use crate::diagnostic::{LintFix, LintFixChange};
 use crate::handler::{Handler, Traverse};
 use crate::tags::{self, Tags};
 use crate::Program;
 use deno_ast::view::TsEntityName;
-use deno_ast::{view as ast_view, SourceRanged};
-use if_chain::if_chain;
+use deno_ast::{view as ast_view, SourceRange, SourceRanged};
+use serde::Deserialize;
+use std::borrow::Cow;
+use std::collections::HashMap;
 use std::convert::TryFrom;
 
-#[derive(Debug)]
-pub struct BanTypes;
+#[derive(Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct BanTypes {
+  /// Types to ban in addition to the defaults, keyed by their name, e.g.
+  /// `Foo` or `React.FC`. `false` allows a type banned by default.
+  pub types: HashMap<String, BanTypeConfig>,
+  /// Whether the default banned types are checked.
+  pub extend_defaults: bool,
+}
+
+impl Default for BanTypes {
+  fn default() -> Self {
+    Self {
+      types: HashMap::new(),
+      extend_defaults: true,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum BanTypeConfig {
+  /// `true` bans the type with the default message, `false` allows it.
+  Enabled(bool),
+  /// Bans the type with a custom message.
+  Message(String),
+  Details(BanTypeDetails),
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct BanTypeDetails {
+  /// Message of the diagnostic.
+  pub message: Option<String>,
+  /// Type to replace the banned type with in the automatic fix.
+  pub fix_with: Option<String>,
+}
 
 const CODE: &str = "ban-types";
 
@@ -54,6 +92,19 @@ impl BannedType {
       }
     }
   }
+
+  /// The type that safely replaces the banned type, if there is one.
+  fn fix_with(&self) -> Option<&'static str> {
+    use BannedType::*;
+    match *self {
+      String => Some("string"),
+      Boolean => Some("boolean"),
+      Number => Some("number"),
+      Symbol => Some("symbol"),
+      BigInt => Some("bigint"),
+      Function | CapitalObject | EmptyObjectLiteral => None,
+    }
+  }
 }
 
 impl TryFrom<&str> for BannedType {
@@ -74,6 +125,51 @@ impl TryFrom<&str> for BannedType {
   }
 }
 
+/// A banned type found in the program.
+struct Ban<'a> {
+  message: Cow<'a, str>,
+  hint: Option<Cow<'a, str>>,
+  fix_with: Option<&'a str>,
+}
+
+impl BanTypes {
+  /// Looks up the ban of the type named `name`. `is_builtin` tells whether
+  /// the name refers to the global type, which is required for the defaults.
+  fn find(&self, name: &str, is_builtin: bool) -> Option<Ban<'_>> {
+    let default = BannedType::try_from(name)
+      .ok()
+      .filter(|_| self.extend_defaults && is_builtin);
+    let (message, fix_with) = match self.types.get(name) {
+      None => (None, None),
+      Some(BanTypeConfig::Enabled(false)) => return None,
+      Some(BanTypeConfig::Enabled(true)) => (None, None),
+      Some(BanTypeConfig::Message(message)) => (Some(message.as_str()), None),
+      Some(BanTypeConfig::Details(details)) => {
+        (details.message.as_deref(), details.fix_with.as_deref())
+      }
+    };
+    if default.is_none() && !self.types.contains_key(name) {
+      return None;
+    }
+    let fix_with =
+      fix_with.or_else(|| default.and_then(|banned| banned.fix_with()));
+    let hint = match (message, default) {
+      (None, Some(banned)) => Some(Cow::Borrowed(banned.as_hint())),
+      _ => fix_with.map(|ty| Cow::Owned(format!("Use `{}` instead", ty))),
+    };
+    let message = match (message, default) {
+      (Some(message), _) => Cow::Borrowed(message),
+      (None, Some(banned)) => Cow::Borrowed(banned.as_message()),
+      (None, None) => Cow::Owned(format!("Don't use `{}` as a type", name)),
+    };
+    Some(Ban {
+      message,
+      hint,
+      fix_with,
+    })
+  }
+}
+
 impl LintRule for BanTypes {
   fn tags(&self) -> Tags {
     &[tags::RECOMMENDED]
@@ -83,37 +179,146 @@ impl LintRule for BanTypes {
     CODE
   }
 
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Bans the use of primitive wrapper objects (e.g. `String` \
+        the object is a wrapper of `string` the primitive) in addition to the \
+        non-explicit `Function` type and the misunderstood `Object` and `{}` \
+        types. The wrappers are fixed to their primitive types. More types \
+        can be banned with the `types` option, keyed by their name and mapped \
+        to `true`, a message, or an object with a `message` and a `fixWith` \
+        type to replace them with, e.g. `{ \"types\": { \"React.FC\": \
+        \"Type the props of the component instead\" } }`. Mapping a default \
+        type to `false` allows it, and `extendDefaults: false` allows all of \
+        them.",
+      invalid_examples: &[
+        "let a: Boolean;",
+        "let b: String;",
+        "let c: Number;",
+        "let d: Symbol;",
+        "let e: Function;",
+        "let f: Object;",
+        "let g: {};",
+      ],
+      valid_examples: &[
+        "let a: boolean;",
+        "let b: string;",
+        "let c: number;",
+        "let d: symbol;",
+        "let e: () => number;",
+        "let f: Record<string, unknown>;",
+        "let g: Record<string, never>;",
+      ],
+    }
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "types": {
+          "description": "Types to ban in addition to the defaults, keyed by their name, e.g. `Foo` or `React.FC`. `false` allows a type banned by default",
+          "type": "object",
+          "additionalProperties": {
+            "anyOf": [
+              { "type": "boolean" },
+              { "type": "string" },
+              {
+                "type": "object",
+                "properties": {
+                  "message": {
+                    "description": "Message of the diagnostic",
+                    "type": "string"
+                  },
+                  "fixWith": {
+                    "description": "Type to replace the banned type with in the automatic fix",
+                    "type": "string"
+                  }
+                },
+                "additionalProperties": false
+              }
+            ]
+          }
+        },
+        "extendDefaults": {
+          "description": "Whether the default banned types are checked",
+          "type": "boolean"
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<BanTypes>(options)?))
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
     program: Program,
   ) {
-    BanTypesHandler.traverse(program, context);
+    BanTypesHandler { rule: self }.traverse(program, context);
   }
 }
 
-struct BanTypesHandler;
+struct BanTypesHandler<'a> {
+  rule: &'a BanTypes,
+}
+
+impl<'a> BanTypesHandler<'a> {
+  fn report(&self, range: SourceRange, ban: Ban, ctx: &mut Context) {
+    let fixes = ban
+      .fix_with
+      .map(|ty| LintFix {
+        description: format!("Replace with `{}`", ty).into(),
+        changes: vec![LintFixChange {
+          new_text: ty.to_string().into(),
+          range,
+        }],
+      })
+      .into_iter()
+      .collect();
+    ctx.add_diagnostic_with_fixes(
+      range,
+      CODE,
+      ban.message,
+      ban.hint.map(Cow::into_owned),
+      fixes,
+    );
+  }
+}
 
-impl Handler for BanTypesHandler {
+impl<'a> Handler for BanTypesHandler<'a> {
   fn ts_type_ref(
     &mut self,
     ts_type_ref: &ast_view::TsTypeRef,
     ctx: &mut Context,
   ) {
-    if_chain! {
-      if let TsEntityName::Ident(ident) = &ts_type_ref.type_name;
-      if ident.ctxt() == ctx.unresolved_ctxt();
-      if ctx.scope().is_global(&ident.to_id());
-      if let Ok(banned_type) = BannedType::try_from(ident.sym().as_ref());
-      then {
-        ctx.add_diagnostic_with_hint(
-          ts_type_ref.range(),
-          CODE,
-          banned_type.as_message(),
-          banned_type.as_hint(),
-        );
+    let type_name = &ts_type_ref.type_name;
+    let is_builtin = match type_name {
+      TsEntityName::Ident(ident) => {
+        ident.ctxt() == ctx.unresolved_ctxt()
+          && ctx.scope().is_global(&ident.to_id())
       }
+      TsEntityName::TsQualifiedName(_) => false,
+    };
+    let name = type_name.range().text_fast(ctx.text_info());
+    let Some(mut ban) = self.rule.find(name, is_builtin) else {
+      return;
+    };
+    // The fix replaces the whole reference, which would drop type arguments.
+    if ts_type_ref.type_params.is_some() {
+      ban.fix_with = None;
     }
+    self.report(ts_type_ref.range(), ban, ctx);
   }
 
   fn ts_type_lit(
@@ -121,13 +326,11 @@ impl Handler for BanTypesHandler {
     ts_type_lit: &ast_view::TsTypeLit,
     ctx: &mut Context,
   ) {
-    if ts_type_lit.members.is_empty() {
-      ctx.add_diagnostic_with_hint(
-        ts_type_lit.range(),
-        CODE,
-        BannedType::EmptyObjectLiteral.as_message(),
-        BannedType::EmptyObjectLiteral.as_hint(),
-      );
+    if !ts_type_lit.members.is_empty() {
+      return;
+    }
+    if let Some(ban) = self.rule.find("{}", true) {
+      self.report(ts_type_lit.range(), ban, ctx);
     }
   }
 }
@@ -136,10 +339,18 @@ impl Handler for BanTypesHandler {
 mod tests {
   use super::*;
 
+  fn message(ty: &str) -> &str {
+    BannedType::try_from(ty).unwrap().as_message()
+  }
+
+  fn hint(ty: &str) -> &str {
+    BannedType::try_from(ty).unwrap().as_hint()
+  }
+
   #[test]
   fn ban_types_valid() {
     assert_lint_ok! {
-      BanTypes,
+      BanTypes::default(),
       "let f = Object();",
       "let f: { x: number; y: number } = { x: 1, y: 1 };",
       "let f = Object();",
@@ -153,21 +364,14 @@ mod tests {
 
   #[test]
   fn ban_types_invalid() {
-    fn message(ty: &str) -> &str {
-      BannedType::try_from(ty).unwrap().as_message()
-    }
-
-    fn hint(ty: &str) -> &str {
-      BannedType::try_from(ty).unwrap().as_hint()
-    }
-
     assert_lint_err! {
-      BanTypes,
+      BanTypes::default(),
       "let a: String;": [
         {
           col: 7,
           message: message("String"),
           hint: hint("String"),
+          fix: ("Replace with `string`", "let a: string;"),
         }
       ],
       "let a: Object;": [
@@ -182,6 +386,7 @@ mod tests {
           col: 7,
           message: message("Number"),
           hint: hint("Number"),
+          fix: ("Replace with `number`", "let a: number;"),
         }
       ],
       "let a: Symbol;": [
@@ -189,6 +394,7 @@ mod tests {
           col: 7,
           message: message("Symbol"),
           hint: hint("Symbol"),
+          fix: ("Replace with `symbol`", "let a: symbol;"),
         }
       ],
       "let a: BigInt;": [
@@ -196,6 +402,7 @@ mod tests {
           col: 7,
           message: message("BigInt"),
           hint: hint("BigInt"),
+          fix: ("Replace with `bigint`", "let a: bigint;"),
         }
       ],
       "let a: Function;": [
@@ -217,6 +424,7 @@ mod tests {
           col: 12,
           message: message("String"),
           hint: hint("String"),
+          fix: ("Replace with `string`", "let a: { b: string };"),
         }
       ],
       "let a: { b: Number };": [
@@ -224,6 +432,7 @@ mod tests {
           col: 12,
           message: message("Number"),
           hint: hint("Number"),
+          fix: ("Replace with `number`", "let a: { b: number };"),
         }
       ],
       "let a: { b: object, c: Object };": [
@@ -245,6 +454,7 @@ mod tests {
           col: 13,
           message: message("String"),
           hint: hint("String"),
+          fix: ("Replace with `string`", "let a: Array<string>"),
         }
       ],
       "let a: Number<Function>": [
@@ -264,6 +474,7 @@ mod tests {
           col: 16,
           message: message("String"),
           hint: hint("String"),
+          fix: ("Replace with `string`", "function foo(a: string) {}"),
         }
       ],
       "function foo(): Number {}": [
@@ -271,6 +482,7 @@ mod tests {
           col: 16,
           message: message("Number"),
           hint: hint("Number"),
+          fix: ("Replace with `number`", "function foo(): number {}"),
         }
       ],
       "let a: () => Number;": [
@@ -278,6 +490,7 @@ mod tests {
           col: 13,
           message: message("Number"),
           hint: hint("Number"),
+          fix: ("Replace with `number`", "let a: () => number;"),
         }
       ],
       "'a' as String;": [
@@ -285,6 +498,7 @@ mod tests {
           col: 7,
           message: message("String"),
           hint: hint("String"),
+          fix: ("Replace with `string`", "'a' as string;"),
         }
       ],
       "1 as Number;": [
@@ -292,6 +506,7 @@ mod tests {
           col: 5,
           message: message("Number"),
           hint: hint("Number"),
+          fix: ("Replace with `number`", "1 as number;"),
         }
       ],
       "
@@ -307,12 +522,28 @@ class Foo<F = String> extends Bar<String> implements Baz<Object> {
           col: 14,
           message: message("String"),
           hint: hint("String"),
+          fix: ("Replace with `string`", "
+class Foo<F = string> extends Bar<String> implements Baz<Object> {
+  constructor(foo: String | Object) {}
+
+  exit(): Array<String> {
+    const foo: String = 1 as String;
+  }
+}"),
         },
         {
           line: 2,
           col: 34,
           message: message("String"),
           hint: hint("String"),
+          fix: ("Replace with `string`", "
+class Foo<F = String> extends Bar<string> implements Baz<Object> {
+  constructor(foo: String | Object) {}
+
+  exit(): Array<String> {
+    const foo: String = 1 as String;
+  }
+}"),
         },
         {
           line: 2,
@@ -325,6 +556,14 @@ class Foo<F = String> extends Bar<String> implements Baz<Object> {
           col: 19,
           message: message("String"),
           hint: hint("String"),
+          fix: ("Replace with `string`", "
+class Foo<F = String> extends Bar<String> implements Baz<Object> {
+  constructor(foo: string | Object) {}
+
+  exit(): Array<String> {
+    const foo: String = 1 as String;
+  }
+}"),
         },
         {
           line: 3,
@@ -337,20 +576,133 @@ class Foo<F = String> extends Bar<String> implements Baz<Object> {
           col: 16,
           message: message("String"),
           hint: hint("String"),
+          fix: ("Replace with `string`", "
+class Foo<F = String> extends Bar<String> implements Baz<Object> {
+  constructor(foo: String | Object) {}
+
+  exit(): Array<string> {
+    const foo: String = 1 as String;
+  }
+}"),
         },
         {
           line: 6,
           col: 15,
           message: message("String"),
           hint: hint("String"),
+          fix: ("Replace with `string`", "
+class Foo<F = String> extends Bar<String> implements Baz<Object> {
+  constructor(foo: String | Object) {}
+
+  exit(): Array<String> {
+    const foo: string = 1 as String;
+  }
+}"),
         },
         {
           line: 6,
           col: 29,
           message: message("String"),
           hint: hint("String"),
+          fix: ("Replace with `string`", "
+class Foo<F = String> extends Bar<String> implements Baz<Object> {
+  constructor(foo: String | Object) {}
+
+  exit(): Array<String> {
+    const foo: String = 1 as string;
+  }
+}"),
         }
       ]
     };
   }
+
+  #[test]
+  fn ban_types_with_options() {
+    let rule = || BanTypes {
+      types: HashMap::from([
+        (
+          "Foo".to_string(),
+          BanTypeConfig::Message("Use `Bar` instead".to_string()),
+        ),
+        ("React.FC".to_string(), BanTypeConfig::Enabled(true)),
+        (
+          "Baz".to_string(),
+          BanTypeConfig::Details(BanTypeDetails {
+            message: None,
+            fix_with: Some("Qux".to_string()),
+          }),
+        ),
+        ("Object".to_string(), BanTypeConfig::Enabled(false)),
+        (
+          "String".to_string(),
+          BanTypeConfig::Message("Strings are not allowed".to_string()),
+        ),
+      ]),
+      extend_defaults: true,
+    };
+
+    assert_lint_ok! {
+      rule(),
+      "let a: Object;",
+      "let a: Bar;",
+      "let a: React.Component;",
+      "let a = Foo;",
+    };
+
+    assert_lint_err! {
+      rule(),
+      "let a: Foo;": [
+        {
+          col: 7,
+          message: "Use `Bar` instead",
+        }
+      ],
+      "interface Foo {} let a: Foo<number>;": [
+        {
+          col: 24,
+          message: "Use `Bar` instead",
+        }
+      ],
+      "let a: React.FC<Props>;": [
+        {
+          col: 7,
+          message: "Don't use `React.FC` as a type",
+        }
+      ],
+      "let a: Baz;": [
+        {
+          col: 7,
+          message: "Don't use `Baz` as a type",
+          hint: "Use `Qux` instead",
+          fix: ("Replace with `Qux`", "let a: Qux;"),
+        }
+      ],
+      "let a: String;": [
+        {
+          col: 7,
+          message: "Strings are not allowed",
+          hint: "Use `string` instead",
+          fix: ("Replace with `string`", "let a: string;"),
+        }
+      ],
+      "let a: {};": [
+        {
+          col: 7,
+          message: message("{}"),
+          hint: hint("{}"),
+        }
+      ],
+    };
+
+    assert_lint_ok! {
+      BanTypes {
+        types: HashMap::new(),
+        extend_defaults: false,
+      },
+      "let a: String;",
+      "let a: Object;",
+      "let a: {};",
+    };
+  }
 }
@@ -1,6 +1,6 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
-use super::{Context, LintRule};
+use super::{Context, LintRule, RuleDocs};
 use crate::handler::{Handler, Traverse};
 use crate::swc_util::StringRepr;
 use crate::tags::Tags;
@@ -9,10 +9,33 @@ use deno_ast::view::{Node, NodeKind, NodeTrait};
 use deno_ast::{view as ast_view, SourceRange, SourceRanged};
 use once_cell::sync::Lazy;
 use regex::{Captures, Regex};
+use serde::Deserialize;
 use std::collections::{BTreeMap, BTreeSet};
 
-#[derive(Debug)]
-pub struct Camelcase;
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct Camelcase {
+  /// Whether object keys and property names are checked.
+  pub properties: Properties,
+  /// Skips identifiers bound by destructuring that keep the name of the
+  /// destructured property, e.g. `{ foo_bar }` in `function f({ foo_bar }) {}`.
+  pub ignore_destructuring: bool,
+  /// Skips identifiers bound by `import` declarations.
+  pub ignore_imports: bool,
+  /// Names that are allowed even if they aren't in camel case, either
+  /// verbatim or as regular expressions, e.g. `^UNSAFE_`.
+  pub allow: Vec<String>,
+  #[serde(skip)]
+  allow_regexes: Vec<Regex>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Properties {
+  #[default]
+  Always,
+  Never,
+}
 
 const CODE: &str = "camelcase";
 
@@ -25,17 +48,104 @@ impl LintRule for Camelcase {
     CODE
   }
 
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Enforces the use of camel case for variable, function \
+        and property names, and pascal case for classes, types, interfaces, \
+        enums and namespaces. Leading and trailing underscores as well as \
+        all-uppercase names like `MAX_SIZE` are allowed.",
+      invalid_examples: &[
+        "let first_name = \"Ichigo\";",
+        "function do_something() {}",
+        "const obj = { last_name: \"Hoshimiya\" };",
+      ],
+      valid_examples: &[
+        "let firstName = \"Ichigo\";",
+        "const MAX_SIZE = 100;",
+        "const obj = { \"last_name\": \"Hoshimiya\" };",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "properties": {
+          "description": "Whether object keys and property names are checked",
+          "enum": ["always", "never"]
+        },
+        "ignoreDestructuring": {
+          "description": "Don't check identifiers bound by destructuring that keep the name of the property",
+          "type": "boolean"
+        },
+        "ignoreImports": {
+          "description": "Don't check identifiers bound by import declarations",
+          "type": "boolean"
+        },
+        "allow": {
+          "description": "Names or regular expressions of names that are allowed",
+          "type": "array",
+          "items": { "type": "string" }
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(Camelcase::from_options(options)?))
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
     program: ast_view::Program,
   ) {
-    let mut handler = CamelcaseHandler::default();
+    let mut handler = CamelcaseHandler::new(self);
     handler.traverse(program, context);
     handler.report_errors(context);
   }
 }
 
+impl Camelcase {
+  fn from_options(
+    options: serde_json::Value,
+  ) -> Result<Self, serde_json::Error> {
+    let mut rule = serde_json::from_value::<Camelcase>(options)?;
+    rule.allow_regexes = rule
+      .allow
+      .iter()
+      .map(|pattern| Regex::new(pattern))
+      .collect::<Result<_, _>>()
+      .map_err(serde::de::Error::custom)?;
+    Ok(rule)
+  }
+
+  fn is_allowed(&self, name: &str) -> bool {
+    self.allow.iter().any(|allowed| allowed == name)
+      || self.allow_regexes.iter().any(|re| re.is_match(name))
+  }
+
+  fn is_ignored(&self, ident: &IdentToCheck) -> bool {
+    match ident {
+      IdentToCheck::ObjectKey { .. } => self.properties == Properties::Never,
+      IdentToCheck::ObjectPat {
+        key_name,
+        value_name,
+        ..
+      } => {
+        self.ignore_destructuring
+          && value_name.as_ref().map_or(true, |value| value == key_name)
+      }
+      _ => false,
+    }
+  }
+}
+
 /// Check if it contains underscores, except for leading and trailing ones
 fn is_underscored(ident_name: &str) -> bool {
   let trimmed_ident = ident_name.trim_matches('_');
@@ -333,15 +443,23 @@ impl IdentToCheck {
   }
 }
 
-#[derive(Default)]
-struct CamelcaseHandler {
+struct CamelcaseHandler<'a> {
+  rule: &'a Camelcase,
   /// Accumulated errors to report
   errors: BTreeMap<SourceRange, IdentToCheck>,
   /// Already visited identifiers
   visited: BTreeSet<SourceRange>,
 }
 
-impl CamelcaseHandler {
+impl<'a> CamelcaseHandler<'a> {
+  fn new(rule: &'a Camelcase) -> Self {
+    Self {
+      rule,
+      errors: BTreeMap::new(),
+      visited: BTreeSet::new(),
+    }
+  }
+
   /// Report accumulated errors, consuming `self`.
   fn report_errors(self, ctx: &mut Context) {
     for (range, error_ident) in self.errors {
@@ -357,7 +475,12 @@ impl CamelcaseHandler {
   /// Check if this ident is underscored only when it's not yet visited.
   fn check_ident<S: SourceRanged>(&mut self, range: &S, ident: IdentToCheck) {
     let range = range.range();
-    if self.visited.insert(range) && is_underscored(ident.get_ident_name()) {
+    let name = ident.get_ident_name();
+    if self.visited.insert(range)
+      && is_underscored(name)
+      && !self.rule.is_allowed(name)
+      && !self.rule.is_ignored(&ident)
+    {
       self.errors.insert(range, ident);
     }
   }
@@ -499,7 +622,7 @@ impl CamelcaseHandler {
   }
 }
 
-impl Handler for CamelcaseHandler {
+impl<'a> Handler for CamelcaseHandler<'a> {
   fn fn_decl(&mut self, fn_decl: &ast_view::FnDecl, ctx: &mut Context) {
     if fn_decl.declare() {
       ctx.stop_traverse();
@@ -599,6 +722,10 @@ impl Handler for CamelcaseHandler {
     import_named_specifier: &ast_view::ImportNamedSpecifier,
     _ctx: &mut Context,
   ) {
+    if self.rule.ignore_imports {
+      return;
+    }
+
     let ast_view::ImportNamedSpecifier {
       local, imported, ..
     } = import_named_specifier;
@@ -621,6 +748,10 @@ impl Handler for CamelcaseHandler {
     import_default_specifier: &ast_view::ImportDefaultSpecifier,
     _ctx: &mut Context,
   ) {
+    if self.rule.ignore_imports {
+      return;
+    }
+
     let ast_view::ImportDefaultSpecifier { local, .. } =
       import_default_specifier;
     self.check_ident(local, IdentToCheck::variable(local.inner));
@@ -631,6 +762,10 @@ impl Handler for CamelcaseHandler {
     import_star_as_specifier: &ast_view::ImportStarAsSpecifier,
     _ctx: &mut Context,
   ) {
+    if self.rule.ignore_imports {
+      return;
+    }
+
     let ast_view::ImportStarAsSpecifier { local, .. } =
       import_star_as_specifier;
     self.check_ident(local, IdentToCheck::variable(local.inner));
@@ -894,7 +1029,7 @@ mod tests {
   #[test]
   fn camelcase_valid() {
     assert_lint_ok! {
-      Camelcase,
+      Camelcase::default(),
       r#"firstName = "Ichigo""#,
       r#"FIRST_NAME = "Ichigo""#,
       r#"__myPrivateVariable = "Hoshimiya""#,
@@ -1011,7 +1146,7 @@ mod tests {
   #[test]
   fn camelcase_invalid() {
     assert_lint_err! {
-      Camelcase,
+      Camelcase::default(),
       r#"function foo_bar(){}"#: [
             {
               col: 9,
@@ -1243,4 +1378,72 @@ mod tests {
           ],
     };
   }
+
+  #[test]
+  fn camelcase_options() {
+    let options = |options| Camelcase::from_options(options).unwrap();
+
+    assert_lint_ok! {
+      options(serde_json::json!({ "properties": "never" })),
+      "const obj = { foo_bar: 1, baz_qux() {} };",
+      "interface Foo { foo_bar: string }",
+    };
+    assert_lint_err! {
+      options(serde_json::json!({ "properties": "never" })),
+      "const foo_bar = {};": [
+        {
+          col: 6,
+          message: "Identifier 'foo_bar' is not in camel case.",
+          hint: "Consider renaming `foo_bar` to `fooBar`",
+        }
+      ],
+    };
+
+    assert_lint_ok! {
+      options(serde_json::json!({ "ignoreDestructuring": true })),
+      "function f({ foo_bar, baz_qux = 1 }) {}",
+      "const { foo_bar: foo_bar } = obj;",
+    };
+    assert_lint_err! {
+      options(serde_json::json!({ "ignoreDestructuring": true })),
+      "const { foo: bar_baz } = obj;": [
+        {
+          col: 13,
+          message: "Identifier 'bar_baz' is not in camel case.",
+          hint: "Consider renaming `bar_baz` to `barBaz`",
+        }
+      ],
+    };
+
+    assert_lint_ok! {
+      options(serde_json::json!({ "ignoreImports": true })),
+      "import foo_bar from 'a';",
+      "import * as foo_bar from 'a';",
+      "import { foo as bar_baz } from 'a';",
+    };
+
+    assert_lint_ok! {
+      options(serde_json::json!({ "allow": ["^foo_bar$", "^UNSAFE_"] })),
+      "const foo_bar = 1;",
+      "class Foo { UNSAFE_componentWillMount() {} }",
+      "function UNSAFE_componentWillMount() {}",
+    };
+    assert_lint_err! {
+      options(serde_json::json!({ "allow": ["^foo_bar$"] })),
+      "const foo_bar_baz = 1;": [
+        {
+          col: 6,
+          message: "Identifier 'foo_bar_baz' is not in camel case.",
+          hint: "Consider renaming `foo_bar_baz` to `fooBarBaz`",
+        }
+      ],
+    };
+
+    assert!(Camelcase::default()
+      .with_options(serde_json::json!({ "allow": ["("] }))
+      .is_err());
+    assert!(Camelcase::default()
+      .with_options(serde_json::json!({ "properties": "sometimes" }))
+      .is_err());
+  }
 }
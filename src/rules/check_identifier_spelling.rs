@@ -0,0 +1,601 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{program_ref, Context, LintRule, RuleDocs};
+use crate::tags::Tags;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{
+  BindingIdent, ClassDecl, ClassExpr, ClassMember, FnDecl, FnExpr, Ident,
+  IdentName, PropName, TsEnumDecl, TsEnumMemberId, TsInterfaceDecl,
+  TsTypeAliasDecl, TsTypeElement, TsTypeParam,
+};
+use deno_ast::swc::common::comments::Comment;
+use deno_ast::swc::visit::{Visit, VisitWith};
+use deno_ast::{SourceRange, SourceRangedForSpanned};
+use derive_more::Display;
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use std::collections::HashSet;
+
+#[derive(Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct CheckIdentifierSpelling {
+  /// Additional words that are spelled correctly, e.g. product names or
+  /// domain-specific terms. Matched case-insensitively.
+  pub words: Vec<String>,
+  /// Checks the words in comments in addition to identifiers.
+  pub check_comments: bool,
+  /// Words shorter than this aren't checked.
+  pub min_word_length: usize,
+}
+
+impl Default for CheckIdentifierSpelling {
+  fn default() -> Self {
+    Self {
+      words: Vec::new(),
+      check_comments: true,
+      min_word_length: 4,
+    }
+  }
+}
+
+const CODE: &str = "check-identifier-spelling";
+const HINT: &str =
+  "Fix the spelling, or add the word to the `words` option if it is correct";
+
+#[derive(Display)]
+enum CheckIdentifierSpellingMessage {
+  #[display(fmt = "Unknown word `{}` in `{}`", _0, _1)]
+  Identifier(String, String),
+  #[display(fmt = "Unknown word `{}` in comment", _0)]
+  Comment(String),
+}
+
+impl LintRule for CheckIdentifierSpelling {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Checks the spelling of the words in declared names and \
+        comments against a built-in dictionary of English and programming \
+        words. Names are split into words at camelCase and snake_case \
+        boundaries, and acronyms like `URL` in `parseURL` are skipped. Only \
+        names where they are declared are checked, since names from \
+        dependencies can't be changed. Words that aren't in the dictionary, \
+        like product names, can be allowed with the `words` option.",
+      invalid_examples: &[
+        "const maxLenght = 10;",
+        "// Recieve the message from the worker.\nworker.onmessage = handle;",
+      ],
+      valid_examples: &[
+        "const maxLength = 10;",
+        "function parseURL(input: string) {}",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "words": {
+          "description": "Additional words that are spelled correctly, e.g. product names",
+          "type": "array",
+          "items": { "type": "string" },
+          "uniqueItems": true
+        },
+        "checkComments": {
+          "description": "Check the words in comments as well. Defaults to `true`",
+          "type": "boolean"
+        },
+        "minWordLength": {
+          "description": "Words shorter than this aren't checked. Defaults to `4`",
+          "type": "integer",
+          "minimum": 1
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<CheckIdentifierSpelling>(
+      options,
+    )?))
+  }
+
+  fn lint_program_with_ast_view<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: Program<'view>,
+  ) {
+    let checker = SpellChecker {
+      words: self.words.iter().map(|word| word.to_lowercase()).collect(),
+      min_word_length: self.min_word_length,
+    };
+
+    let mut visitor = IdentifierVisitor {
+      checker: &checker,
+      context,
+    };
+    match program_ref(program) {
+      ProgramRef::Module(m) => m.visit_with(&mut visitor),
+      ProgramRef::Script(s) => s.visit_with(&mut visitor),
+    }
+
+    if self.check_comments {
+      let comments = context.all_comments().collect::<Vec<_>>();
+      for comment in comments {
+        check_comment(&checker, comment, context);
+      }
+    }
+  }
+}
+
+struct SpellChecker {
+  words: HashSet<String>,
+  min_word_length: usize,
+}
+
+impl SpellChecker {
+  fn is_known(&self, word: &str) -> bool {
+    if word.len() < self.min_word_length {
+      return true;
+    }
+    let word = word.to_ascii_lowercase();
+    self.words.contains(&word) || is_in_dictionary(&word)
+  }
+}
+
+struct IdentifierVisitor<'a, 'c, 'view> {
+  checker: &'a SpellChecker,
+  context: &'c mut Context<'view>,
+}
+
+impl IdentifierVisitor<'_, '_, '_> {
+  fn check_name(&mut self, name: &str, range: SourceRange) {
+    for (_, word) in split_identifier(name) {
+      if !self.checker.is_known(word) {
+        self.context.add_diagnostic_with_hint(
+          range,
+          CODE,
+          CheckIdentifierSpellingMessage::Identifier(
+            word.to_string(),
+            name.to_string(),
+          ),
+          HINT,
+        );
+        return;
+      }
+    }
+  }
+
+  fn check_ident(&mut self, ident: &Ident) {
+    self.check_name(&ident.sym, ident.range());
+  }
+
+  fn check_ident_name(&mut self, ident: &IdentName) {
+    self.check_name(&ident.sym, ident.range());
+  }
+
+  fn check_prop_name(&mut self, key: &PropName) {
+    if let PropName::Ident(ident) = key {
+      self.check_ident_name(ident);
+    }
+  }
+}
+
+impl Visit for IdentifierVisitor<'_, '_, '_> {
+  fn visit_binding_ident(&mut self, binding: &BindingIdent) {
+    self.check_ident(&binding.id);
+  }
+
+  fn visit_fn_decl(&mut self, fn_decl: &FnDecl) {
+    if !fn_decl.declare {
+      self.check_ident(&fn_decl.ident);
+    }
+    fn_decl.visit_children_with(self);
+  }
+
+  fn visit_fn_expr(&mut self, fn_expr: &FnExpr) {
+    if let Some(ident) = &fn_expr.ident {
+      self.check_ident(ident);
+    }
+    fn_expr.visit_children_with(self);
+  }
+
+  fn visit_class_decl(&mut self, class_decl: &ClassDecl) {
+    if !class_decl.declare {
+      self.check_ident(&class_decl.ident);
+    }
+    class_decl.visit_children_with(self);
+  }
+
+  fn visit_class_expr(&mut self, class_expr: &ClassExpr) {
+    if let Some(ident) = &class_expr.ident {
+      self.check_ident(ident);
+    }
+    class_expr.visit_children_with(self);
+  }
+
+  fn visit_class_member(&mut self, member: &ClassMember) {
+    match member {
+      ClassMember::Method(method) => self.check_prop_name(&method.key),
+      ClassMember::ClassProp(prop) => self.check_prop_name(&prop.key),
+      _ => {}
+    }
+    member.visit_children_with(self);
+  }
+
+  fn visit_ts_interface_decl(&mut self, interface_decl: &TsInterfaceDecl) {
+    self.check_ident(&interface_decl.id);
+    for element in &interface_decl.body.body {
+      let key = match element {
+        TsTypeElement::TsPropertySignature(prop) => &prop.key,
+        TsTypeElement::TsMethodSignature(method) => &method.key,
+        _ => continue,
+      };
+      if let Some(ident) = key.as_ident() {
+        self.check_ident(ident);
+      }
+    }
+    interface_decl.visit_children_with(self);
+  }
+
+  fn visit_ts_type_alias_decl(&mut self, type_alias: &TsTypeAliasDecl) {
+    self.check_ident(&type_alias.id);
+    type_alias.visit_children_with(self);
+  }
+
+  fn visit_ts_enum_decl(&mut self, enum_decl: &TsEnumDecl) {
+    self.check_ident(&enum_decl.id);
+    for member in &enum_decl.members {
+      if let TsEnumMemberId::Ident(ident) = &member.id {
+        self.check_ident(ident);
+      }
+    }
+    enum_decl.visit_children_with(self);
+  }
+
+  fn visit_ts_type_param(&mut self, type_param: &TsTypeParam) {
+    self.check_ident(&type_param.name);
+    type_param.visit_children_with(self);
+  }
+}
+
+fn check_comment(checker: &SpellChecker, comment: &Comment, ctx: &mut Context) {
+  let text = comment.text.as_ref();
+  let trimmed = text.trim_start_matches(['/', '*', '!', ' ']);
+  if ["deno-", "@ts-", "eslint", "prettier-", "<reference", "#"]
+    .iter()
+    .any(|prefix| trimmed.starts_with(prefix))
+  {
+    return;
+  }
+  // The text of a comment starts after `//` or `/*`.
+  let text_start = comment.range().start + 2;
+  for (offset, word) in comment_words(text) {
+    if !checker.is_known(word) {
+      let start = text_start + offset;
+      ctx.add_diagnostic_with_hint(
+        SourceRange::new(start, start + word.len()),
+        CODE,
+        CheckIdentifierSpellingMessage::Comment(word.to_string()),
+        HINT,
+      );
+    }
+  }
+}
+
+/// Splits an identifier into words at underscores, digits and camelCase
+/// boundaries, e.g. `parseHTTPResponse2` into `parse`, `HTTP` and
+/// `Response`. Acronyms in identifiers that contain lowercase letters are
+/// skipped, as are parts with non-ASCII letters.
+fn split_identifier(name: &str) -> Vec<(usize, &str)> {
+  let has_lowercase = name.chars().any(|c| c.is_ascii_lowercase());
+  let mut words = Vec::new();
+  for (offset, part) in split_runs(name) {
+    for (word_offset, word) in split_camel_case(part) {
+      let is_acronym = word.chars().all(|c| c.is_ascii_uppercase());
+      if !(is_acronym && has_lowercase && word.len() > 1) {
+        words.push((offset + word_offset, word));
+      }
+    }
+  }
+  words
+}
+
+/// Returns the words of a comment, skipping code in backticks, URLs, paths
+/// and anything else that looks like code rather than prose.
+fn comment_words(text: &str) -> Vec<(usize, &str)> {
+  let mut words = Vec::new();
+  let mut in_code = false;
+  let mut offset = 0;
+  for chunk in text.split_inclusive(char::is_whitespace) {
+    let chunk_offset = offset;
+    offset += chunk.len();
+    let backticks = chunk.matches('`').count();
+    let was_in_code = in_code;
+    in_code ^= backticks % 2 == 1;
+    if was_in_code || backticks > 0 {
+      continue;
+    }
+
+    let trimmed_start = chunk
+      .trim_start_matches(|c: char| "\"'([{*".contains(c))
+      .len();
+    let chunk_offset = chunk_offset + chunk.len() - trimmed_start;
+    let chunk = chunk
+      .trim_start_matches(|c: char| "\"'([{*".contains(c))
+      .trim_end_matches(|c: char| {
+        c.is_whitespace() || "\"'()[]{},.;:!?*".contains(c)
+      });
+    // Words are only letters, optionally joined by hyphens or apostrophes.
+    // Anything else, or uppercase letters inside of a word, means code.
+    let is_prose = chunk
+      .chars()
+      .all(|c| c.is_ascii_alphabetic() || c == '-' || c == '\'')
+      && !chunk.chars().skip(1).any(|c| c.is_ascii_uppercase());
+    if !is_prose {
+      continue;
+    }
+    for (word_offset, word) in split_runs(chunk) {
+      words.push((chunk_offset + word_offset, word));
+    }
+  }
+  words
+}
+
+/// Splits the text into runs of ASCII letters. Runs that are directly
+/// adjacent to non-ASCII characters are dropped, since they are only part of
+/// a word.
+fn split_runs(text: &str) -> Vec<(usize, &str)> {
+  let mut runs = Vec::new();
+  let mut start = None;
+  let mut touches_non_ascii = false;
+  for (index, c) in text.char_indices().chain([(text.len(), ' ')]) {
+    if c.is_ascii_alphabetic() {
+      start.get_or_insert(index);
+      continue;
+    }
+    if let Some(run_start) = start.take() {
+      if !touches_non_ascii && (c.is_ascii() || index == text.len()) {
+        runs.push((run_start, &text[run_start..index]));
+      }
+    }
+    touches_non_ascii = !c.is_ascii();
+  }
+  runs
+}
+
+fn split_camel_case(run: &str) -> Vec<(usize, &str)> {
+  let bytes = run.as_bytes();
+  let mut words = Vec::new();
+  let mut start = 0;
+  for i in 1..bytes.len() {
+    let prev = bytes[i - 1];
+    let current = bytes[i];
+    let next = bytes.get(i + 1);
+    // `fooBar` and the `R` in `HTTPResponse`.
+    let is_boundary = (prev.is_ascii_lowercase()
+      && current.is_ascii_uppercase())
+      || (prev.is_ascii_uppercase()
+        && current.is_ascii_uppercase()
+        && next.map_or(false, |next| next.is_ascii_lowercase()));
+    if is_boundary {
+      words.push((start, &run[start..i]));
+      start = i;
+    }
+  }
+  if start < run.len() {
+    words.push((start, &run[start..]));
+  }
+  words
+}
+
+/// Checks the word against the dictionary, including common inflections of
+/// the words in it, e.g. `parsers` or `parsing` for `parser` and `parse`.
+fn is_in_dictionary(word: &str) -> bool {
+  if DICTIONARY.contains(word) {
+    return true;
+  }
+  const SUFFIXES: &[(&str, &[&str])] = &[
+    ("s", &[""]),
+    ("es", &[""]),
+    ("ies", &["y"]),
+    ("ed", &["", "e"]),
+    ("ing", &["", "e"]),
+    ("er", &["", "e"]),
+    ("ers", &["", "e"]),
+    ("ly", &[""]),
+    ("able", &["", "e"]),
+  ];
+  SUFFIXES.iter().any(|(suffix, replacements)| {
+    word.strip_suffix(suffix).map_or(false, |stem| {
+      stem.len() >= 3
+        && replacements.iter().any(|replacement| {
+          DICTIONARY.contains(&format!("{}{}", stem, replacement))
+        })
+    })
+  })
+}
+
+static DICTIONARY: Lazy<BloomFilter> = Lazy::new(|| {
+  BloomFilter::new(include_str!("check_identifier_spelling/words.txt").lines())
+});
+
+/// A Bloom filter of the dictionary, which takes a fraction of the memory of
+/// a hash set of the words. False positives only make misspelled words go
+/// unreported, with a probability below 0.5%.
+struct BloomFilter {
+  bits: Vec<u64>,
+}
+
+impl BloomFilter {
+  const BITS_PER_WORD: usize = 12;
+  const HASHES: u64 = 8;
+
+  fn new<'a>(words: impl Iterator<Item = &'a str> + Clone) -> Self {
+    let len = (words.clone().count() * Self::BITS_PER_WORD / 64).max(1);
+    let mut filter = Self { bits: vec![0; len] };
+    for word in words {
+      for bit in filter.bit_indices(word) {
+        filter.bits[bit / 64] |= 1 << (bit % 64);
+      }
+    }
+    filter
+  }
+
+  fn contains(&self, word: &str) -> bool {
+    self
+      .bit_indices(word)
+      .all(|bit| self.bits[bit / 64] & (1 << (bit % 64)) != 0)
+  }
+
+  /// Derives the bits of a word from two hashes, as described in "Less
+  /// Hashing, Same Performance" by Kirsch and Mitzenmacher.
+  fn bit_indices(&self, word: &str) -> impl Iterator<Item = usize> {
+    let h1 = fnv1a(word.as_bytes(), 0xcbf29ce484222325);
+    let h2 = fnv1a(word.as_bytes(), 0x84222325cbf29ce4) | 1;
+    let len = (self.bits.len() * 64) as u64;
+    (0..Self::HASHES)
+      .map(move |i| (h1.wrapping_add(i.wrapping_mul(h2)) % len) as usize)
+  }
+}
+
+fn fnv1a(bytes: &[u8], seed: u64) -> u64 {
+  bytes.iter().fold(seed, |hash, byte| {
+    (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn identifier(word: &str, name: &str) -> String {
+    CheckIdentifierSpellingMessage::Identifier(
+      word.to_string(),
+      name.to_string(),
+    )
+    .to_string()
+  }
+
+  fn comment(word: &str) -> String {
+    CheckIdentifierSpellingMessage::Comment(word.to_string()).to_string()
+  }
+
+  #[test]
+  fn split_identifier_words() {
+    let words = |name| {
+      split_identifier(name)
+        .into_iter()
+        .map(|(_, word)| word)
+        .collect::<Vec<_>>()
+    };
+    assert_eq!(words("maxLength"), ["max", "Length"]);
+    assert_eq!(words("parseHTTPResponse2"), ["parse", "Response"]);
+    assert_eq!(words("MAX_RETRY_COUNT"), ["MAX", "RETRY", "COUNT"]);
+    assert_eq!(words("_private$value"), ["private", "value"]);
+    assert!(words("naïveValue").is_empty());
+  }
+
+  #[test]
+  fn dictionary() {
+    for word in ["length", "receive", "parsers", "parsing", "libraries"] {
+      assert!(is_in_dictionary(word), "{}", word);
+    }
+    for word in ["lenght", "recieve", "seperate", "paramter"] {
+      assert!(!is_in_dictionary(word), "{}", word);
+    }
+  }
+
+  #[test]
+  fn check_identifier_spelling_valid() {
+    assert_lint_ok! {
+      CheckIdentifierSpelling::default(),
+      "const maxLength = 10;",
+      "function parseURL(input: string) {}",
+      "class HttpServer { handleRequest() {} }",
+      "interface Options { timeout: number }",
+      "enum Color { Red, Green }",
+      "const MAX_RETRY_COUNT = 3;",
+      "import { lenght } from './mod.ts'; lenght();",
+      "foo.recieve();",
+      "// Receive the message, see `recieveMessage` and https://example.com/recieve.",
+      "// deno-lint-ignore no-explicit-any",
+      "/** @param {string} input The text. */",
+    };
+
+    assert_lint_ok! {
+      CheckIdentifierSpelling {
+        words: vec!["Lenght".to_string()],
+        ..Default::default()
+      },
+      "const maxLenght = 10;",
+    };
+
+    assert_lint_ok! {
+      CheckIdentifierSpelling {
+        check_comments: false,
+        ..Default::default()
+      },
+      "// recieve",
+    };
+  }
+
+  #[test]
+  fn check_identifier_spelling_invalid() {
+    assert_lint_err! {
+      CheckIdentifierSpelling::default(),
+      "const maxLenght = 10;": [
+        {
+          col: 6,
+          message: identifier("Lenght", "maxLenght"),
+          hint: HINT,
+        }
+      ],
+      "function recieveMessage(paramter) {}": [
+        {
+          col: 9,
+          message: identifier("recieve", "recieveMessage"),
+          hint: HINT,
+        },
+        {
+          col: 24,
+          message: identifier("paramter", "paramter"),
+          hint: HINT,
+        }
+      ],
+      "class Parser { seperate = 1; }": [
+        {
+          col: 15,
+          message: identifier("seperate", "seperate"),
+          hint: HINT,
+        }
+      ],
+      "// Recieve the message.\nconst a = 1;": [
+        {
+          col: 3,
+          message: comment("Recieve"),
+          hint: HINT,
+        }
+      ],
+      "/* A well-knwon value */": [
+        {
+          col: 10,
+          message: comment("knwon"),
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,216 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::code_metrics::max_option_schema;
+use crate::tags::Tags;
+use crate::Program;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct Complexity {
+  /// The maximum cyclomatic complexity of a function.
+  pub max: usize,
+}
+
+impl Default for Complexity {
+  fn default() -> Self {
+    Self { max: 20 }
+  }
+}
+
+const CODE: &str = "complexity";
+const HINT: &str =
+  "Split the function into smaller functions, or simplify its conditions";
+
+impl LintRule for Complexity {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Limits the cyclomatic complexity of functions, which is \
+        the number of paths through them. Every function starts at 1, and \
+        each `if`, loop, `case`, `catch`, conditional expression, `&&`, \
+        `||`, `??`, logical assignment, optional chaining `?.` and default \
+        value adds one path. Nested functions are counted separately. \
+        Functions with more complexity than the `max` option (20 by default) \
+        are reported.",
+      invalid_examples: &[
+        "function isAllowed(user) {\n  if (!user || !user.name || !user.email || !user.age) return false;\n  if (user.age < 0 || user.age > 150 || !Number.isInteger(user.age)) return false;\n  if (user.email && !user.email.includes(\"@\") || user.email?.length > 254) return false;\n  const role = user.role ?? (user.isAdmin ? \"admin\" : \"user\");\n  for (const group of user.groups ?? []) {\n    if (group.disabled && group.owner !== user.id) return false;\n  }\n  return role === \"admin\" || role === \"user\" && user.verified || user.invited;\n}",
+      ],
+      valid_examples: &[
+        "function isAllowed(user) {\n  if (!isValidUser(user)) return false;\n  const role = user.role ?? (user.isAdmin ? \"admin\" : \"user\");\n  return role === \"admin\" || user.verified;\n}",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "max": max_option_schema("The maximum cyclomatic complexity of a function. Defaults to 20")
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<Complexity>(options)?))
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    _program: Program,
+  ) {
+    let reports: Vec<_> = context
+      .code_metrics()
+      .functions()
+      .iter()
+      .filter(|function| function.complexity > self.max)
+      .map(|function| {
+        (
+          function.head_range,
+          format!(
+            "Complexity of {} is {}, more than the maximum of {}",
+            function.name, function.complexity, self.max
+          ),
+        )
+      })
+      .collect();
+    for (range, message) in reports {
+      context.add_diagnostic_with_hint(range, CODE, message, HINT);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v9.9.0/tests/lib/rules/complexity.js
+  // MIT Licensed.
+
+  #[test]
+  fn complexity_valid() {
+    assert_lint_ok! {
+      Complexity { max: 1 },
+      "function a(x) {}",
+      "const a = (x) => x;",
+      "if (a) {} else if (b) {} else if (c) {}",
+      "class A { static { b(); } }",
+    };
+
+    assert_lint_ok! {
+      Complexity { max: 2 },
+      "function a(x) { if (x) {} }",
+      "function a(x) { if (x) {} else {} }",
+      "function a(x) { return x ? 1 : 2; }",
+      "function a(x) { return x?.y; }",
+      "function a(x = 1) {}",
+      "function a(x) { x ??= 1; }",
+      "function a(x) { switch (x) { case 1: break; default: break; } }",
+    };
+
+    assert_lint_ok! {
+      Complexity::default(),
+      "function a(x) { if (x && y || z) {} }",
+    };
+  }
+
+  #[test]
+  fn complexity_invalid() {
+    assert_lint_err! {
+      Complexity { max: 1 },
+      "function a(x) { if (x) {} }": [
+        {
+          col: 0,
+          message: "Complexity of function `a` is 2, more than the maximum of 1",
+          hint: HINT,
+        }
+      ],
+      "const a = function(x) { return x && y; };": [
+        {
+          col: 10,
+          message: "Complexity of function `a` is 2, more than the maximum of 1",
+          hint: HINT,
+        }
+      ],
+      "const a = (x) => x ?? y;": [
+        {
+          col: 10,
+          message: "Complexity of arrow function `a` is 2, more than the maximum of 1",
+          hint: HINT,
+        }
+      ],
+      "class A { b(x) { for (;;) {} } }": [
+        {
+          col: 10,
+          message: "Complexity of method `b` is 2, more than the maximum of 1",
+          hint: HINT,
+        }
+      ],
+      "const a = { b({ c = 1 }) {} };": [
+        {
+          col: 12,
+          message: "Complexity of method `b` is 2, more than the maximum of 1",
+          hint: HINT,
+        }
+      ],
+      "class A { static { if (a) {} } }": [
+        {
+          col: 10,
+          message: "Complexity of class static block is 2, more than the maximum of 1",
+          hint: HINT,
+        }
+      ],
+      "function a(x) { return function b() { if (x) {} }; }": [
+        {
+          col: 23,
+          message: "Complexity of function `b` is 2, more than the maximum of 1",
+          hint: HINT,
+        }
+      ],
+      "function a(x) { try {} catch { b(() => c?.d); } }": [
+        {
+          col: 0,
+          message: "Complexity of function `a` is 2, more than the maximum of 1",
+          hint: HINT,
+        },
+        {
+          col: 33,
+          message: "Complexity of arrow function is 2, more than the maximum of 1",
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      Complexity { max: 3 },
+      "function a(x) { switch (x) { case 1: break; case 2: break; case 3: break; default: break; } }": [
+        {
+          col: 0,
+          message: "Complexity of function `a` is 4, more than the maximum of 3",
+          hint: HINT,
+        }
+      ],
+      "function a(x) { if (x) {} else if (y) {} else if (z) {} }": [
+        {
+          col: 0,
+          message: "Complexity of function `a` is 4, more than the maximum of 3",
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,496 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::StringRepr;
+use crate::tags::Tags;
+use crate::Program;
+use deno_ast::swc::ast::{
+  ArrowExpr, BlockStmt, BlockStmtOrExpr, Constructor, Expr, Function,
+  GetterProp, MethodKind, Pat, ReturnStmt, SetterProp, UnaryOp,
+};
+use deno_ast::swc::visit::{noop_visit_type, Visit, VisitWith};
+use deno_ast::view::{self as ast_view, Node, NodeTrait};
+use deno_ast::{SourceRange, SourceRanged, SourceRangedForSpanned};
+use serde::Deserialize;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct ConsistentReturn {
+  /// Treats `return undefined` and `return void 0` like `return` without a
+  /// value.
+  pub treat_undefined_as_unspecified: bool,
+}
+
+const CODE: &str = "consistent-return";
+
+impl LintRule for ConsistentReturn {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Requires functions to either always or never return a \
+        value. The first `return` statement of a function decides which one \
+        is expected. If it returns a value, the end of the function must not \
+        be reachable either, since that implicitly returns `undefined`. \
+        Generators are exempt from the latter, because their final value is \
+        rarely used.",
+      invalid_examples: &[
+        "function find(items, id) {\n  for (const item of items) {\n    if (item.id === id) {\n      return item;\n    }\n  }\n}",
+        "function check(value) {\n  if (!value) {\n    return;\n  }\n  return value.trim();\n}",
+      ],
+      valid_examples: &[
+        "function find(items, id) {\n  for (const item of items) {\n    if (item.id === id) {\n      return item;\n    }\n  }\n  return null;\n}",
+        "function log(value) {\n  if (!value) {\n    return;\n  }\n  console.log(value);\n}",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "treatUndefinedAsUnspecified": {
+          "description": "Treat `return undefined` like `return` without a value",
+          "type": "boolean"
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<ConsistentReturn>(
+      options,
+    )?))
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    ConsistentReturnHandler { rule: self }.traverse(program, context);
+  }
+}
+
+enum DiagnosticKind {
+  ExpectedValue,
+  UnexpectedValue,
+  MissingReturn,
+}
+
+impl DiagnosticKind {
+  fn message(&self, name: &str) -> String {
+    match *self {
+      DiagnosticKind::ExpectedValue => {
+        format!("{} expected a return value", capitalize(name))
+      }
+      DiagnosticKind::UnexpectedValue => {
+        format!("{} expected no return value", capitalize(name))
+      }
+      DiagnosticKind::MissingReturn => {
+        format!("Expected to return a value at the end of {}", name)
+      }
+    }
+  }
+
+  fn hint(&self) -> &'static str {
+    match *self {
+      DiagnosticKind::ExpectedValue => {
+        "Return a value like the first `return` statement of the function does"
+      }
+      DiagnosticKind::UnexpectedValue => {
+        "Remove the value like in the first `return` statement of the function"
+      }
+      DiagnosticKind::MissingReturn => {
+        "Add a `return` statement with a value at the end of the function"
+      }
+    }
+  }
+}
+
+fn capitalize(name: &str) -> String {
+  let mut chars = name.chars();
+  match chars.next() {
+    Some(first) => first.to_uppercase().chain(chars).collect(),
+    None => String::new(),
+  }
+}
+
+struct ConsistentReturnHandler<'a> {
+  rule: &'a ConsistentReturn,
+}
+
+impl<'a> ConsistentReturnHandler<'a> {
+  /// Checks the `return` statements of a function body. `range` is where a
+  /// missing return value at the end of the function is reported.
+  fn check(
+    &self,
+    body: &BlockStmt,
+    name: &str,
+    range: SourceRange,
+    is_generator: bool,
+    ctx: &mut Context,
+  ) {
+    let mut collector = ReturnCollector {
+      treat_undefined_as_unspecified: self.rule.treat_undefined_as_unspecified,
+      returns: vec![],
+    };
+    body.visit_children_with(&mut collector);
+    let Some((_, expects_value)) = collector.returns.first() else {
+      return;
+    };
+    let expects_value = *expects_value;
+
+    for (return_range, has_value) in &collector.returns[1..] {
+      if *has_value != expects_value {
+        let kind = if expects_value {
+          DiagnosticKind::ExpectedValue
+        } else {
+          DiagnosticKind::UnexpectedValue
+        };
+        ctx.add_diagnostic_with_hint(
+          *return_range,
+          CODE,
+          kind.message(name),
+          kind.hint(),
+        );
+      }
+    }
+
+    if expects_value
+      && !is_generator
+      && ctx
+        .control_flow()
+        .meta(body.start())
+        .map_or(false, |meta| meta.continues_execution())
+    {
+      let kind = DiagnosticKind::MissingReturn;
+      ctx.add_diagnostic_with_hint(
+        range,
+        CODE,
+        kind.message(name),
+        kind.hint(),
+      );
+    }
+  }
+
+  fn check_function(
+    &self,
+    function: &Function,
+    name: &str,
+    range: SourceRange,
+    ctx: &mut Context,
+  ) {
+    if let Some(body) = &function.body {
+      self.check(body, name, range, function.is_generator, ctx);
+    }
+  }
+}
+
+impl<'a> Handler for ConsistentReturnHandler<'a> {
+  fn fn_decl(&mut self, fn_decl: &ast_view::FnDecl, ctx: &mut Context) {
+    let ident = &fn_decl.inner.ident;
+    let name = format!("function `{}`", ident.sym);
+    self.check_function(&fn_decl.inner.function, &name, ident.range(), ctx);
+  }
+
+  fn fn_expr(&mut self, fn_expr: &ast_view::FnExpr, ctx: &mut Context) {
+    let (name, range) = match &fn_expr.inner.ident {
+      Some(ident) => (format!("function `{}`", ident.sym), ident.range()),
+      None => match declarator_name(fn_expr.as_node()) {
+        Some(name) => (format!("function `{}`", name), fn_expr.range()),
+        None => ("function".to_string(), fn_expr.range()),
+      },
+    };
+    self.check_function(&fn_expr.inner.function, &name, range, ctx);
+  }
+
+  fn arrow_expr(&mut self, arrow: &ast_view::ArrowExpr, ctx: &mut Context) {
+    let ArrowExpr { body, .. } = arrow.inner;
+    // Expression bodies always return a value.
+    let BlockStmtOrExpr::BlockStmt(body) = &**body else {
+      return;
+    };
+    let name = match declarator_name(arrow.as_node()) {
+      Some(name) => format!("arrow function `{}`", name),
+      None => "arrow function".to_string(),
+    };
+    self.check(body, &name, arrow.range(), false, ctx);
+  }
+
+  fn class_method(
+    &mut self,
+    class_method: &ast_view::ClassMethod,
+    ctx: &mut Context,
+  ) {
+    let name = method_name(
+      class_method.inner.kind,
+      class_method.inner.key.string_repr(),
+    );
+    self.check_function(
+      &class_method.inner.function,
+      &name,
+      class_method.inner.key.range(),
+      ctx,
+    );
+  }
+
+  fn private_method(
+    &mut self,
+    private_method: &ast_view::PrivateMethod,
+    ctx: &mut Context,
+  ) {
+    let key = &private_method.inner.key;
+    let name =
+      method_name(private_method.inner.kind, Some(format!("#{}", key.name)));
+    self.check_function(
+      &private_method.inner.function,
+      &name,
+      key.range(),
+      ctx,
+    );
+  }
+
+  fn method_prop(
+    &mut self,
+    method_prop: &ast_view::MethodProp,
+    ctx: &mut Context,
+  ) {
+    let name =
+      method_name(MethodKind::Method, method_prop.inner.key.string_repr());
+    self.check_function(
+      &method_prop.inner.function,
+      &name,
+      method_prop.inner.key.range(),
+      ctx,
+    );
+  }
+
+  fn getter_prop(
+    &mut self,
+    getter_prop: &ast_view::GetterProp,
+    ctx: &mut Context,
+  ) {
+    let GetterProp { key, body, .. } = getter_prop.inner;
+    if let Some(body) = body {
+      let name = method_name(MethodKind::Getter, key.string_repr());
+      self.check(body, &name, key.range(), false, ctx);
+    }
+  }
+
+  fn setter_prop(
+    &mut self,
+    setter_prop: &ast_view::SetterProp,
+    ctx: &mut Context,
+  ) {
+    let SetterProp { key, body, .. } = setter_prop.inner;
+    if let Some(body) = body {
+      let name = method_name(MethodKind::Setter, key.string_repr());
+      self.check(body, &name, key.range(), false, ctx);
+    }
+  }
+
+  fn constructor(
+    &mut self,
+    constructor: &ast_view::Constructor,
+    ctx: &mut Context,
+  ) {
+    let Constructor { key, body, .. } = constructor.inner;
+    if let Some(body) = body {
+      self.check(body, "constructor", key.range(), false, ctx);
+    }
+  }
+}
+
+fn method_name(kind: MethodKind, key: Option<String>) -> String {
+  let kind = match kind {
+    MethodKind::Method => "method",
+    MethodKind::Getter => "getter",
+    MethodKind::Setter => "setter",
+  };
+  match key {
+    Some(name) => format!("{} `{}`", kind, name),
+    None => kind.to_string(),
+  }
+}
+
+/// Returns the name of the variable a function is assigned to in its
+/// declaration, e.g. `foo` in `const foo = () => {}`.
+fn declarator_name(node: Node) -> Option<String> {
+  let Some(Node::VarDeclarator(declarator)) = node.parent() else {
+    return None;
+  };
+  match &declarator.inner.name {
+    Pat::Ident(ident) => Some(ident.id.sym.to_string()),
+    _ => None,
+  }
+}
+
+/// Collects the `return` statements of a function, together with whether
+/// they return a value, skipping the ones of nested functions.
+struct ReturnCollector {
+  treat_undefined_as_unspecified: bool,
+  returns: Vec<(SourceRange, bool)>,
+}
+
+impl Visit for ReturnCollector {
+  noop_visit_type!();
+
+  fn visit_return_stmt(&mut self, return_stmt: &ReturnStmt) {
+    let has_value = match return_stmt.arg.as_deref() {
+      None => false,
+      Some(Expr::Ident(ident)) if self.treat_undefined_as_unspecified => {
+        ident.sym != *"undefined"
+      }
+      Some(Expr::Unary(unary)) if self.treat_undefined_as_unspecified => {
+        unary.op != UnaryOp::Void
+      }
+      Some(_) => true,
+    };
+    self.returns.push((return_stmt.range(), has_value));
+  }
+
+  fn visit_function(&mut self, _: &Function) {}
+
+  fn visit_arrow_expr(&mut self, _: &ArrowExpr) {}
+
+  fn visit_constructor(&mut self, _: &Constructor) {}
+
+  fn visit_getter_prop(&mut self, _: &GetterProp) {}
+
+  fn visit_setter_prop(&mut self, _: &SetterProp) {}
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v9.9.0/tests/lib/rules/consistent-return.js
+  // MIT Licensed.
+
+  #[test]
+  fn consistent_return_valid() {
+    assert_lint_ok! {
+      ConsistentReturn::default(),
+      "function foo() { return; }",
+      "function foo() { if (true) return; }",
+      "function foo() { if (true) return; else return; }",
+      "function foo() { if (true) return true; else return false; }",
+      "function foo() { if (a) return 1; throw new Error(); }",
+      "function foo() { while (true) { if (a) return 1; } }",
+      "function foo() { function bar() { return true; } return; }",
+      "function foo() { return; function bar() { return true; } }",
+      "function foo() { const bar = () => 1; if (a) return; }",
+      "async function foo() { if (a) return await b; return null; }",
+      "const foo = () => a;",
+      "const foo = () => { if (a) return; bar(); };",
+      "function* foo() { if (a) return 1; yield 2; }",
+      "class A { constructor() { if (a) return; this.b = 1; } }",
+      "const a = { get b() { return 1; } };",
+      "function foo(): number;",
+    };
+
+    assert_lint_ok! {
+      ConsistentReturn {
+        treat_undefined_as_unspecified: true,
+      },
+      "function foo() { if (a) return; return undefined; }",
+      "function foo() { if (a) return void 0; return; }",
+    };
+  }
+
+  #[test]
+  fn consistent_return_invalid() {
+    assert_lint_err! {
+      ConsistentReturn::default(),
+      "function foo() { if (a) return true; else return; }": [
+        {
+          col: 42,
+          message: "Function `foo` expected a return value",
+          hint: "Return a value like the first `return` statement of the function does",
+        }
+      ],
+      "function foo() { if (a) return; else return true; }": [
+        {
+          col: 37,
+          message: "Function `foo` expected no return value",
+          hint: "Remove the value like in the first `return` statement of the function",
+        }
+      ],
+      "function foo() { if (a) return true; }": [
+        {
+          col: 9,
+          message: "Expected to return a value at the end of function `foo`",
+          hint: "Add a `return` statement with a value at the end of the function",
+        }
+      ],
+      "const foo = function () { if (a) return true; };": [
+        {
+          col: 12,
+          message: "Expected to return a value at the end of function `foo`",
+          hint: "Add a `return` statement with a value at the end of the function",
+        }
+      ],
+      "const foo = async () => { if (a) return 1; return; };": [
+        {
+          col: 43,
+          message: "Arrow function `foo` expected a return value",
+          hint: "Return a value like the first `return` statement of the function does",
+        }
+      ],
+      "foo(() => { if (a) return 1; });": [
+        {
+          col: 4,
+          message: "Expected to return a value at the end of arrow function",
+          hint: "Add a `return` statement with a value at the end of the function",
+        }
+      ],
+      "class A { b() { if (a) return 1; } }": [
+        {
+          col: 10,
+          message: "Expected to return a value at the end of method `b`",
+          hint: "Add a `return` statement with a value at the end of the function",
+        }
+      ],
+      "function* foo() { if (a) return 1; return; }": [
+        {
+          col: 35,
+          message: "Function `foo` expected a return value",
+          hint: "Return a value like the first `return` statement of the function does",
+        }
+      ],
+      "function foo() { if (a) return undefined; return; }": [
+        {
+          col: 42,
+          message: "Function `foo` expected a return value",
+          hint: "Return a value like the first `return` statement of the function does",
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      ConsistentReturn {
+        treat_undefined_as_unspecified: true,
+      },
+      "function foo() { if (a) return undefined; return 1; }": [
+        {
+          col: 42,
+          message: "Function `foo` expected no return value",
+          hint: "Remove the value like in the first `return` statement of the function",
+        }
+      ],
+    };
+  }
+}
@@ -1,6 +1,7 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
+use crate::diagnostic::LintDiagnosticRelated;
 use crate::handler::{Handler, Traverse};
 use crate::tags::{self, Tags};
 use crate::Program;
@@ -136,11 +137,12 @@ fn check_constructor(
 
   if inherits_from_non_constructor(class) {
     let kind = DiagnosticKind::UnnecessaryConstructor;
-    ctx.add_diagnostic_with_hint(
+    ctx.add_diagnostic_with_related(
       cons.range(),
       CODE,
       kind.message(),
-      kind.hint(),
+      Some(kind.hint().to_string()),
+      super_class_related(class, "The class inherits from this value"),
     );
     return;
   }
@@ -150,22 +152,27 @@ fn check_constructor(
   // in case where there are more than one `super()` calls.
   for exceeded_super_range in super_calls.iter().skip(1) {
     let kind = DiagnosticKind::TooManySuper;
-    ctx.add_diagnostic_with_hint(
+    ctx.add_diagnostic_with_related(
       *exceeded_super_range,
       CODE,
       kind.message(),
-      kind.hint(),
+      Some(kind.hint().to_string()),
+      vec![LintDiagnosticRelated {
+        range: super_calls[0],
+        message: "super() is first called here".to_string(),
+      }],
     );
   }
 
   match (super_calls.is_empty(), class.super_class.is_some()) {
     (true, true) => {
       let kind = DiagnosticKind::NoSuper;
-      ctx.add_diagnostic_with_hint(
+      ctx.add_diagnostic_with_related(
         cons.range(),
         CODE,
         kind.message(),
-        kind.hint(),
+        Some(kind.hint().to_string()),
+        super_class_related(class, "The class extends this class"),
       );
     }
     (false, false) => {
@@ -181,6 +188,20 @@ fn check_constructor(
   }
 }
 
+fn super_class_related(
+  class: &ast_view::Class,
+  message: &str,
+) -> Vec<LintDiagnosticRelated> {
+  class
+    .super_class
+    .map(|super_class| LintDiagnosticRelated {
+      range: super_class.range(),
+      message: message.to_string(),
+    })
+    .into_iter()
+    .collect()
+}
+
 struct ConstructorSuperHandler;
 
 impl Handler for ConstructorSuperHandler {
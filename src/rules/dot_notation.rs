@@ -0,0 +1,643 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{program_ref, Context, LintRule, RuleDocs};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::{Handler, Traverse};
+use crate::tags::{self, Tags};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{
+  BindingIdent, Expr, Id, Ident, IdentName, Lit, MemberProp, TsEntityName,
+  TsInterfaceDecl, TsType, TsTypeAliasDecl, TsTypeElement,
+};
+use deno_ast::swc::atoms::Atom;
+use deno_ast::swc::visit::{noop_visit_type, Visit, VisitWith};
+use deno_ast::view as ast_view;
+use deno_ast::{SourceRange, SourceRanged, SourceRangedForSpanned};
+use regex::Regex;
+use serde::{Deserialize, Deserializer};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct DotNotation {
+  /// Allows reserved words like `class` as property names in dot notation.
+  /// Disable it to support environments that predate ES5, in which case
+  /// `obj.class` is reported and `obj["class"]` is not.
+  pub allow_keywords: bool,
+  /// Property names matching this regular expression may be accessed with
+  /// bracket notation.
+  #[serde(deserialize_with = "deserialize_pattern")]
+  pub allow_pattern: Option<Regex>,
+  /// Allows bracket notation for properties that are only covered by an index
+  /// signature of the object's declared type, as TypeScript's
+  /// `noPropertyAccessFromIndexSignature` option requires. Only types
+  /// declared in the same file are taken into account.
+  pub allow_index_signature_property_access: bool,
+}
+
+impl Default for DotNotation {
+  fn default() -> Self {
+    Self {
+      allow_keywords: true,
+      allow_pattern: None,
+      allow_index_signature_property_access: false,
+    }
+  }
+}
+
+fn deserialize_pattern<'de, D>(
+  deserializer: D,
+) -> Result<Option<Regex>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  Option::<String>::deserialize(deserializer)?
+    .map(|pattern| Regex::new(&pattern).map_err(serde::de::Error::custom))
+    .transpose()
+}
+
+const CODE: &str = "dot-notation";
+
+impl LintRule for DotNotation {
+  fn tags(&self) -> Tags {
+    &[tags::STYLISTIC]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Requires dot notation (`obj.prop`) for property accesses \
+        whose key is a valid identifier, instead of bracket notation \
+        (`obj[\"prop\"]`). With `allowKeywords` disabled, reserved words are \
+        required to use bracket notation instead, as in ES3. In TypeScript, \
+        `allowIndexSignaturePropertyAccess` keeps bracket notation for \
+        properties that only exist through an index signature of a type \
+        declared in the same file.",
+      invalid_examples: &[
+        "const name = user[\"name\"];",
+        "config[`verbose`] = true;",
+      ],
+      valid_examples: &[
+        "const name = user.name;",
+        "const value = headers[\"content-type\"];",
+        "const key = \"name\";\nconst name = user[key];",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "allowKeywords": {
+          "description": "Allow reserved words in dot notation. Defaults to `true`",
+          "type": "boolean"
+        },
+        "allowPattern": {
+          "description": "A regular expression for property names that may use bracket notation",
+          "type": "string"
+        },
+        "allowIndexSignaturePropertyAccess": {
+          "description": "Allow bracket notation for properties covered by an index signature",
+          "type": "boolean"
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<DotNotation>(options)?))
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    let index_signatures = if self.allow_index_signature_property_access {
+      IndexSignatures::collect(program_ref(program))
+    } else {
+      IndexSignatures::default()
+    };
+    DotNotationHandler {
+      rule: self,
+      index_signatures,
+    }
+    .traverse(program, context);
+  }
+}
+
+/// Reserved words of ES3, which can't be used as property names in dot
+/// notation in environments that predate ES5.
+const KEYWORDS: &[&str] = &[
+  "abstract",
+  "boolean",
+  "break",
+  "byte",
+  "case",
+  "catch",
+  "char",
+  "class",
+  "const",
+  "continue",
+  "debugger",
+  "default",
+  "delete",
+  "do",
+  "double",
+  "else",
+  "enum",
+  "export",
+  "extends",
+  "false",
+  "final",
+  "finally",
+  "float",
+  "for",
+  "function",
+  "goto",
+  "if",
+  "implements",
+  "import",
+  "in",
+  "instanceof",
+  "int",
+  "interface",
+  "long",
+  "native",
+  "new",
+  "null",
+  "package",
+  "private",
+  "protected",
+  "public",
+  "return",
+  "short",
+  "static",
+  "super",
+  "switch",
+  "synchronized",
+  "this",
+  "throw",
+  "throws",
+  "transient",
+  "true",
+  "try",
+  "typeof",
+  "var",
+  "void",
+  "volatile",
+  "while",
+  "with",
+];
+
+fn is_identifier_name(name: &str) -> bool {
+  let mut chars = name.chars();
+  chars.next().map_or(false, Ident::is_valid_start)
+    && chars.all(Ident::is_valid_continue)
+}
+
+struct DotNotationHandler<'a> {
+  rule: &'a DotNotation,
+  index_signatures: IndexSignatures,
+}
+
+impl<'a> DotNotationHandler<'a> {
+  fn check_computed(
+    &self,
+    member_expr: &ast_view::MemberExpr,
+    key: &Expr,
+    ctx: &mut Context,
+  ) {
+    let name = match key {
+      Expr::Lit(Lit::Str(str)) => str.value.to_string(),
+      Expr::Tpl(tpl) if tpl.exprs.is_empty() => match &tpl.quasis[..] {
+        [quasi] => match &quasi.cooked {
+          Some(cooked) => cooked.to_string(),
+          None => return,
+        },
+        _ => return,
+      },
+      _ => return,
+    };
+    if !is_identifier_name(&name)
+      || (!self.rule.allow_keywords && KEYWORDS.contains(&name.as_str()))
+      || self
+        .rule
+        .allow_pattern
+        .as_ref()
+        .map_or(false, |pattern| pattern.is_match(&name))
+    {
+      return;
+    }
+    if self.rule.allow_index_signature_property_access {
+      if let Expr::Ident(obj) = &*member_expr.inner.obj {
+        if self.index_signatures.allows(&obj.to_id(), &name) {
+          return;
+        }
+      }
+    }
+
+    let obj = &member_expr.inner.obj;
+    // The `[` and `]` of the computed property, plus a `?.` that precedes
+    // them in optional chains.
+    let range = SourceRange::new(obj.end(), member_expr.end());
+    let key_text = key.range().text_fast(ctx.text_info());
+    let new_text = format!(
+      "{}{}.{}",
+      // `1["toString"]` would become `1.toString`, which doesn't parse.
+      if is_integer_literal(obj, ctx) {
+        " "
+      } else {
+        ""
+      },
+      if range.text_fast(ctx.text_info()).starts_with("?.") {
+        "?"
+      } else {
+        ""
+      },
+      name
+    );
+    let fixes = if ctx.has_comments_in_range(range) {
+      vec![]
+    } else {
+      vec![LintFix {
+        description: "Use dot notation".into(),
+        changes: vec![LintFixChange {
+          new_text: new_text.into(),
+          range,
+        }],
+      }]
+    };
+    ctx.add_diagnostic_with_fixes(
+      key.range(),
+      CODE,
+      format!("[{}] is better written in dot notation", key_text),
+      Some(format!("Use `.{}` instead", name)),
+      fixes,
+    );
+  }
+
+  fn check_keyword(
+    &self,
+    member_expr: &ast_view::MemberExpr,
+    prop: &IdentName,
+    ctx: &mut Context,
+  ) {
+    if self.rule.allow_keywords || !KEYWORDS.contains(&&*prop.sym) {
+      return;
+    }
+    let range =
+      SourceRange::new(member_expr.inner.obj.end(), member_expr.end());
+    let new_text = format!(
+      "{}[\"{}\"]",
+      if range.text_fast(ctx.text_info()).starts_with("?.") {
+        "?."
+      } else {
+        ""
+      },
+      prop.sym
+    );
+    let fixes = if ctx.has_comments_in_range(range) {
+      vec![]
+    } else {
+      vec![LintFix {
+        description: "Use bracket notation".into(),
+        changes: vec![LintFixChange {
+          new_text: new_text.into(),
+          range,
+        }],
+      }]
+    };
+    ctx.add_diagnostic_with_fixes(
+      prop.range(),
+      CODE,
+      format!(".{} is a syntax error in ES3 environments", prop.sym),
+      Some(format!("Use `[\"{}\"]` instead", prop.sym)),
+      fixes,
+    );
+  }
+}
+
+impl<'a> Handler for DotNotationHandler<'a> {
+  fn member_expr(
+    &mut self,
+    member_expr: &ast_view::MemberExpr,
+    ctx: &mut Context,
+  ) {
+    match &member_expr.inner.prop {
+      MemberProp::Computed(computed) => {
+        self.check_computed(member_expr, &computed.expr, ctx)
+      }
+      MemberProp::Ident(prop) => self.check_keyword(member_expr, prop, ctx),
+      MemberProp::PrivateName(_) => {}
+    }
+  }
+}
+
+fn is_integer_literal(expr: &Expr, ctx: &Context) -> bool {
+  matches!(expr, Expr::Lit(Lit::Num(_)))
+    && expr
+      .range()
+      .text_fast(ctx.text_info())
+      .chars()
+      .all(|c| c.is_ascii_digit())
+}
+
+/// Bindings declared with a type that has an index signature, along with the
+/// properties the type declares explicitly.
+#[derive(Default)]
+struct IndexSignatures {
+  bindings: HashMap<Id, HashSet<Atom>>,
+}
+
+impl IndexSignatures {
+  fn collect(program: ProgramRef) -> Self {
+    let mut types = TypeCollector::default();
+    let mut bindings = BindingCollector {
+      types: HashMap::new(),
+      bindings: HashMap::new(),
+    };
+    match program {
+      ProgramRef::Module(m) => {
+        m.visit_with(&mut types);
+        bindings.types = types.types;
+        m.visit_with(&mut bindings);
+      }
+      ProgramRef::Script(s) => {
+        s.visit_with(&mut types);
+        bindings.types = types.types;
+        s.visit_with(&mut bindings);
+      }
+    }
+    Self {
+      bindings: bindings.bindings,
+    }
+  }
+
+  /// Checks if `name` is only accessible through an index signature.
+  fn allows(&self, id: &Id, name: &str) -> bool {
+    self
+      .bindings
+      .get(id)
+      .map_or(false, |props| !props.iter().any(|prop| *prop == *name))
+  }
+}
+
+/// Returns the explicitly declared properties of a type literal or interface
+/// body, or `None` if it has no index signature.
+fn index_signature_props(members: &[TsTypeElement]) -> Option<HashSet<Atom>> {
+  let mut has_index_signature = false;
+  let mut props = HashSet::new();
+  for member in members {
+    match member {
+      TsTypeElement::TsIndexSignature(_) => has_index_signature = true,
+      TsTypeElement::TsPropertySignature(prop) => {
+        if let Expr::Ident(ident) = &*prop.key {
+          props.insert(ident.sym.clone());
+        }
+      }
+      TsTypeElement::TsMethodSignature(method) => {
+        if let Expr::Ident(ident) = &*method.key {
+          props.insert(ident.sym.clone());
+        }
+      }
+      _ => {}
+    }
+  }
+  has_index_signature.then_some(props)
+}
+
+/// Collects the interfaces and type aliases of the file that have an index
+/// signature.
+#[derive(Default)]
+struct TypeCollector {
+  types: HashMap<Atom, HashSet<Atom>>,
+}
+
+impl Visit for TypeCollector {
+  fn visit_ts_interface_decl(&mut self, decl: &TsInterfaceDecl) {
+    if let Some(props) = index_signature_props(&decl.body.body) {
+      self.types.insert(decl.id.sym.clone(), props);
+    }
+  }
+
+  fn visit_ts_type_alias_decl(&mut self, decl: &TsTypeAliasDecl) {
+    if let TsType::TsTypeLit(lit) = &*decl.type_ann {
+      if let Some(props) = index_signature_props(&lit.members) {
+        self.types.insert(decl.id.sym.clone(), props);
+      }
+    }
+  }
+}
+
+struct BindingCollector {
+  types: HashMap<Atom, HashSet<Atom>>,
+  bindings: HashMap<Id, HashSet<Atom>>,
+}
+
+impl BindingCollector {
+  fn props_of(&self, ty: &TsType) -> Option<HashSet<Atom>> {
+    match ty {
+      TsType::TsTypeLit(lit) => index_signature_props(&lit.members),
+      TsType::TsTypeRef(type_ref) => match &type_ref.type_name {
+        TsEntityName::Ident(ident) => self.types.get(&ident.sym).cloned(),
+        TsEntityName::TsQualifiedName(_) => None,
+      },
+      _ => None,
+    }
+  }
+}
+
+impl Visit for BindingCollector {
+  noop_visit_type!();
+
+  fn visit_binding_ident(&mut self, binding: &BindingIdent) {
+    let Some(type_ann) = &binding.type_ann else {
+      return;
+    };
+    if let Some(props) = self.props_of(&type_ann.type_ann) {
+      self.bindings.insert(binding.id.to_id(), props);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v9.9.0/tests/lib/rules/dot-notation.js
+  // MIT Licensed.
+
+  #[test]
+  fn dot_notation_valid() {
+    assert_lint_ok! {
+      DotNotation::default(),
+      "a.b;",
+      "a.b.c;",
+      "a['12'];",
+      "a[b];",
+      "a[0];",
+      "a.b.c['d-e'];",
+      "a['foo bar'];",
+      "a[`${b}`];",
+      "a[`c-d`];",
+      "a.null;",
+      "a.true;",
+      "a.class;",
+      "a?.b;",
+      "class A { #b = 1; c() { return this.#b; } }",
+      "const obj: Record<string, number> = {};\nobj.foo;",
+    };
+
+    assert_lint_ok! {
+      DotNotation {
+        allow_keywords: false,
+        ..Default::default()
+      },
+      "a['true'];",
+      "a['class'];",
+      "a.b;",
+    };
+
+    assert_lint_ok! {
+      DotNotation {
+        allow_pattern: Some(Regex::new("^[a-z]+(_[a-z]+)+$").unwrap()),
+        ..Default::default()
+      },
+      "a['snake_case'];",
+    };
+
+    assert_lint_ok! {
+      DotNotation {
+        allow_index_signature_property_access: true,
+        ..Default::default()
+      },
+      "const headers: { [key: string]: string } = {};\nheaders['foo'];",
+      "interface Headers { [key: string]: string }\nfunction f(headers: Headers) { return headers['foo']; }",
+      "function f(headers: Headers) { return headers['foo']; }\ntype Headers = { [key: string]: string };",
+    };
+  }
+
+  #[test]
+  fn dot_notation_invalid() {
+    assert_lint_err! {
+      DotNotation::default(),
+      "a['b'];": [
+        {
+          col: 2,
+          message: "['b'] is better written in dot notation",
+          hint: "Use `.b` instead",
+          fix: ("Use dot notation", "a.b;"),
+        }
+      ],
+      "a.b['c'];": [
+        {
+          col: 4,
+          message: "['c'] is better written in dot notation",
+          hint: "Use `.c` instead",
+          fix: ("Use dot notation", "a.b.c;"),
+        }
+      ],
+      "a[`b`];": [
+        {
+          col: 2,
+          message: "[`b`] is better written in dot notation",
+          hint: "Use `.b` instead",
+          fix: ("Use dot notation", "a.b;"),
+        }
+      ],
+      "a?.['b'];": [
+        {
+          col: 4,
+          message: "['b'] is better written in dot notation",
+          hint: "Use `.b` instead",
+          fix: ("Use dot notation", "a?.b;"),
+        }
+      ],
+      "a['class'];": [
+        {
+          col: 2,
+          message: "['class'] is better written in dot notation",
+          hint: "Use `.class` instead",
+          fix: ("Use dot notation", "a.class;"),
+        }
+      ],
+      "1['toString']();": [
+        {
+          col: 2,
+          message: "['toString'] is better written in dot notation",
+          hint: "Use `.toString` instead",
+          fix: ("Use dot notation", "1 .toString();"),
+        }
+      ],
+      "a[/* comment */ 'b'];": [
+        {
+          col: 16,
+          message: "['b'] is better written in dot notation",
+          hint: "Use `.b` instead",
+        }
+      ],
+      "const headers: { [key: string]: string } = {};\nheaders['foo'];": [
+        {
+          line: 2,
+          col: 8,
+          message: "['foo'] is better written in dot notation",
+          hint: "Use `.foo` instead",
+          fix: ("Use dot notation", "const headers: { [key: string]: string } = {};\nheaders.foo;"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      DotNotation {
+        allow_keywords: false,
+        ..Default::default()
+      },
+      "a.class;": [
+        {
+          col: 2,
+          message: ".class is a syntax error in ES3 environments",
+          hint: "Use `[\"class\"]` instead",
+          fix: ("Use bracket notation", "a[\"class\"];"),
+        }
+      ],
+      "a?.true;": [
+        {
+          col: 3,
+          message: ".true is a syntax error in ES3 environments",
+          hint: "Use `[\"true\"]` instead",
+          fix: ("Use bracket notation", "a?.[\"true\"];"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      DotNotation {
+        allow_index_signature_property_access: true,
+        ..Default::default()
+      },
+      "interface Headers { [key: string]: string; accept: string }\nfunction f(headers: Headers) { return headers['accept']; }": [
+        {
+          line: 2,
+          col: 46,
+          message: "['accept'] is better written in dot notation",
+          hint: "Use `.accept` instead",
+          fix: ("Use dot notation", "interface Headers { [key: string]: string; accept: string }\nfunction f(headers: Headers) { return headers.accept; }"),
+        }
+      ],
+    };
+  }
+}
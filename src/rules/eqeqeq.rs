@@ -1,14 +1,37 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
-use super::{Context, LintRule};
+use super::{Context, LintRule, RuleDocs};
+use crate::diagnostic::{LintFix, LintFixChange};
 use crate::handler::{Handler, Traverse};
 use crate::Program;
-use deno_ast::swc::ast::BinaryOp;
-use deno_ast::{view as ast_view, SourceRanged};
+use deno_ast::swc::ast::{BinExpr, BinaryOp, Expr, Lit, UnaryOp};
+use deno_ast::{
+  view as ast_view, SourceRange, SourceRanged, SourceRangedForSpanned,
+};
 use derive_more::Display;
+use serde::Deserialize;
 
-#[derive(Debug)]
-pub struct Eqeqeq;
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct Eqeqeq {
+  /// Which comparisons with `==` and `!=` are allowed.
+  pub mode: Mode,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Mode {
+  /// Requires `===` and `!==` everywhere.
+  #[default]
+  Always,
+  /// Allows `==` and `!=` where they can't behave differently from the
+  /// strict operators, i.e. in `typeof` checks and comparisons of literals
+  /// of the same type, as well as in comparisons with `null`, which also
+  /// match `undefined`.
+  Smart,
+  /// Allows `==` and `!=` in comparisons with `null` only.
+  AllowNull,
+}
 
 const CODE: &str = "eqeqeq";
 
@@ -33,30 +56,156 @@ impl LintRule for Eqeqeq {
     CODE
   }
 
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Requires the strict equality operators `===` and `!==` \
+        instead of `==` and `!=`, whose type coercions are easy to get \
+        wrong. The `smart` mode allows `typeof` checks, comparisons of two \
+        literals of the same type and comparisons with `null`, and the \
+        `allow-null` mode allows comparisons with `null` only, since `x == \
+        null` is a common way to check for both `null` and `undefined`. \
+        Operators in `typeof` checks and comparisons of literals of the same \
+        type are fixed automatically, since the result doesn't change.",
+      invalid_examples: &["if (count == \"0\") {}", "if (value != null) {}"],
+      valid_examples: &[
+        "if (count === 0) {}",
+        "if (value !== null && value !== undefined) {}",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "mode": {
+          "description": "Which comparisons may use `==` and `!=`. Defaults to `always`, which allows none",
+          "enum": ["always", "smart", "allow-null"]
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<Eqeqeq>(options)?))
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
     program: Program,
   ) {
-    EqeqeqHandler.traverse(program, context);
+    EqeqeqHandler { rule: self }.traverse(program, context);
   }
 }
 
-struct EqeqeqHandler;
+struct EqeqeqHandler<'a> {
+  rule: &'a Eqeqeq,
+}
 
-impl Handler for EqeqeqHandler {
+impl<'a> Handler for EqeqeqHandler<'a> {
   fn bin_expr(&mut self, bin_expr: &ast_view::BinExpr, context: &mut Context) {
-    if matches!(bin_expr.op(), BinaryOp::EqEq | BinaryOp::NotEq) {
-      let (message, hint) = if bin_expr.op() == BinaryOp::EqEq {
-        (EqeqeqMessage::ExpectedEqual, EqeqeqHint::UseEqeqeq)
-      } else {
-        (EqeqeqMessage::ExpectedNotEqual, EqeqeqHint::UseNoteqeq)
-      };
-      context.add_diagnostic_with_hint(bin_expr.range(), CODE, message, hint)
+    if !matches!(bin_expr.op(), BinaryOp::EqEq | BinaryOp::NotEq) {
+      return;
+    }
+    let bin = bin_expr.inner;
+    let is_allowed = match self.rule.mode {
+      Mode::Always => false,
+      Mode::Smart => {
+        is_typeof_check(bin)
+          || are_same_type_literals(bin)
+          || is_null_check(bin)
+      }
+      Mode::AllowNull => is_null_check(bin),
+    };
+    if is_allowed {
+      return;
     }
+
+    let (message, hint, strict_op) = if bin.op == BinaryOp::EqEq {
+      (EqeqeqMessage::ExpectedEqual, EqeqeqHint::UseEqeqeq, "===")
+    } else {
+      (
+        EqeqeqMessage::ExpectedNotEqual,
+        EqeqeqHint::UseNoteqeq,
+        "!==",
+      )
+    };
+    let fixes = if is_typeof_check(bin) || are_same_type_literals(bin) {
+      operator_range(bin, context)
+        .map(|range| LintFix {
+          description: format!("Replace with '{}'", strict_op).into(),
+          changes: vec![LintFixChange {
+            new_text: strict_op.into(),
+            range,
+          }],
+        })
+        .into_iter()
+        .collect()
+    } else {
+      vec![]
+    };
+    context.add_diagnostic_with_fixes(
+      bin.range(),
+      CODE,
+      message,
+      Some(hint.to_string()),
+      fixes,
+    );
   }
 }
 
+fn is_typeof(expr: &Expr) -> bool {
+  matches!(expr.unwrap_parens(), Expr::Unary(unary) if unary.op == UnaryOp::TypeOf)
+}
+
+/// Checks if `typeof x` is compared with a string, which can't be coerced.
+fn is_typeof_check(bin: &BinExpr) -> bool {
+  let is_string = |expr: &Expr| {
+    is_typeof(expr) || matches!(expr.unwrap_parens(), Expr::Lit(Lit::Str(_)))
+  };
+  (is_typeof(&bin.left) || is_typeof(&bin.right))
+    && is_string(&bin.left)
+    && is_string(&bin.right)
+}
+
+fn are_same_type_literals(bin: &BinExpr) -> bool {
+  matches!(
+    (bin.left.unwrap_parens(), bin.right.unwrap_parens()),
+    (Expr::Lit(Lit::Str(_)), Expr::Lit(Lit::Str(_)))
+      | (Expr::Lit(Lit::Num(_)), Expr::Lit(Lit::Num(_)))
+      | (Expr::Lit(Lit::Bool(_)), Expr::Lit(Lit::Bool(_)))
+      | (Expr::Lit(Lit::BigInt(_)), Expr::Lit(Lit::BigInt(_)))
+      | (Expr::Lit(Lit::Null(_)), Expr::Lit(Lit::Null(_)))
+  )
+}
+
+fn is_null_check(bin: &BinExpr) -> bool {
+  matches!(bin.left.unwrap_parens(), Expr::Lit(Lit::Null(_)))
+    || matches!(bin.right.unwrap_parens(), Expr::Lit(Lit::Null(_)))
+}
+
+/// Returns the range of the `==` or `!=` operator, unless there are comments
+/// next to it.
+fn operator_range(bin: &BinExpr, context: &Context) -> Option<SourceRange> {
+  let between = SourceRange::new(bin.left.end(), bin.right.start());
+  if context.has_comments_in_range(between) {
+    return None;
+  }
+  let text = between.text_fast(context.text_info());
+  let offset = text.find(['=', '!'])?;
+  let start = between.start + offset;
+  Some(SourceRange::new(start, start + 2))
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -64,7 +213,7 @@ mod tests {
   #[test]
   fn eqeqeq_valid() {
     assert_lint_ok! {
-      Eqeqeq,
+      Eqeqeq::default(),
       "midori === sapphire",
       "midori !== hazuki",
       "kumiko === null",
@@ -77,7 +226,7 @@ mod tests {
   #[test]
   fn eqeqeq_invalid() {
     assert_lint_err! {
-      Eqeqeq,
+      Eqeqeq::default(),
 
       "a == b": [
       {
@@ -96,12 +245,14 @@ mod tests {
         col: 0,
         message: EqeqeqMessage::ExpectedEqual,
         hint: EqeqeqHint::UseEqeqeq,
+        fix: ("Replace with '==='", "typeof a === 'number'"),
       }],
       "'string' != typeof a": [
       {
         col: 0,
         message: EqeqeqMessage::ExpectedNotEqual,
         hint: EqeqeqHint::UseNoteqeq,
+        fix: ("Replace with '!=='", "'string' !== typeof a"),
       }],
       "true == true": [
       {
@@ -109,18 +260,21 @@ mod tests {
 
         message: EqeqeqMessage::ExpectedEqual,
         hint: EqeqeqHint::UseEqeqeq,
+        fix: ("Replace with '==='", "true === true"),
       }],
       "2 == 3": [
       {
         col: 0,
         message: EqeqeqMessage::ExpectedEqual,
         hint: EqeqeqHint::UseEqeqeq,
+        fix: ("Replace with '==='", "2 === 3"),
       }],
       "'hello' != 'world'": [
       {
         col: 0,
         message: EqeqeqMessage::ExpectedNotEqual,
         hint: EqeqeqHint::UseNoteqeq,
+        fix: ("Replace with '!=='", "'hello' !== 'world'"),
       }],
       "a == null": [
       {
@@ -151,12 +305,14 @@ mod tests {
         col: 0,
         message: EqeqeqMessage::ExpectedEqual,
         hint: EqeqeqHint::UseEqeqeq,
+        fix: ("Replace with '==='", "null === null"),
       }],
       "null != null": [
       {
         col: 0,
         message: EqeqeqMessage::ExpectedNotEqual,
         hint: EqeqeqHint::UseNoteqeq,
+        fix: ("Replace with '!=='", "null !== null"),
       }],
       r#"
 a
@@ -284,7 +440,100 @@ b     "#: [
         col: 0,
         message: EqeqeqMessage::ExpectedEqual,
         hint: EqeqeqHint::UseEqeqeq,
+        fix: ("Replace with '==='", "((1) )  ===  (2);"),
       }],
     }
   }
+
+  #[test]
+  fn eqeqeq_smart_valid() {
+    assert_lint_ok! {
+      Eqeqeq { mode: Mode::Smart },
+      "typeof foo == 'undefined'",
+      "'hello' != 'world'",
+      "0 == 0",
+      "true == true",
+      "foo == null",
+      "null != foo",
+    };
+  }
+
+  #[test]
+  fn eqeqeq_smart_invalid() {
+    assert_lint_err! {
+      Eqeqeq { mode: Mode::Smart },
+      "a == b": [
+      {
+        col: 0,
+        message: EqeqeqMessage::ExpectedEqual,
+        hint: EqeqeqHint::UseEqeqeq,
+      }],
+      "foo == true": [
+      {
+        col: 0,
+        message: EqeqeqMessage::ExpectedEqual,
+        hint: EqeqeqHint::UseEqeqeq,
+      }],
+      "bananas != 1": [
+      {
+        col: 0,
+        message: EqeqeqMessage::ExpectedNotEqual,
+        hint: EqeqeqHint::UseNoteqeq,
+      }],
+      "value == undefined": [
+      {
+        col: 0,
+        message: EqeqeqMessage::ExpectedEqual,
+        hint: EqeqeqHint::UseEqeqeq,
+      }],
+      "typeof foo == bar": [
+      {
+        col: 0,
+        message: EqeqeqMessage::ExpectedEqual,
+        hint: EqeqeqHint::UseEqeqeq,
+      }],
+      "'1' == 1": [
+      {
+        col: 0,
+        message: EqeqeqMessage::ExpectedEqual,
+        hint: EqeqeqHint::UseEqeqeq,
+      }],
+    };
+  }
+
+  #[test]
+  fn eqeqeq_allow_null_valid() {
+    assert_lint_ok! {
+      Eqeqeq { mode: Mode::AllowNull },
+      "foo == null",
+      "null != foo",
+      "foo === undefined",
+    };
+  }
+
+  #[test]
+  fn eqeqeq_allow_null_invalid() {
+    assert_lint_err! {
+      Eqeqeq { mode: Mode::AllowNull },
+      "foo == undefined": [
+      {
+        col: 0,
+        message: EqeqeqMessage::ExpectedEqual,
+        hint: EqeqeqHint::UseEqeqeq,
+      }],
+      "typeof foo == 'undefined'": [
+      {
+        col: 0,
+        message: EqeqeqMessage::ExpectedEqual,
+        hint: EqeqeqHint::UseEqeqeq,
+        fix: ("Replace with '==='", "typeof foo === 'undefined'"),
+      }],
+      "typeof foo /* type */ != 'string'": [
+      {
+        col: 0,
+        message: EqeqeqMessage::ExpectedNotEqual,
+        hint: EqeqeqHint::UseNoteqeq,
+      }],
+    };
+  }
 }
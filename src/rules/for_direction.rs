@@ -4,13 +4,12 @@ use super::{Context, LintRule};
 use crate::handler::{Handler, Traverse};
 use crate::tags::{self, Tags};
 use crate::Program;
-use deno_ast::swc::ast::AssignOp;
-use deno_ast::swc::ast::BinaryOp;
-use deno_ast::swc::ast::UpdateOp;
-use deno_ast::view::{
-  AssignExpr, AssignTarget, Expr, SimpleAssignTarget, UnaryOp, UpdateExpr,
+use deno_ast::swc::ast::{
+  AssignExpr, AssignOp, AssignTarget, BinaryOp, Expr, Lit, Pat,
+  SimpleAssignTarget, UnaryOp, UpdateOp, VarDeclOrExpr,
 };
-use deno_ast::{view as ast_view, SourceRanged};
+use deno_ast::{view as ast_view, SourceRanged, SourceRangedForSpanned};
+use derive_more::Display;
 
 #[derive(Debug)]
 pub struct ForDirection;
@@ -34,63 +33,114 @@ impl LintRule for ForDirection {
 }
 
 const MESSAGE: &str = "Update clause moves variable in the wrong direction";
-const HINT: &str =
-  "Flip the update clause logic or change the continuation step condition";
+const MESSAGE_NEVER_EQUAL: &str =
+  "Update clause never makes the variable equal to the bound";
+
+#[derive(Display)]
+enum ForDirectionHint {
+  #[display(
+    fmt = "`{}` {} in every iteration, but `{}` only becomes false when it {}. Flip the update clause logic or change the continuation step condition",
+    counter,
+    changes,
+    condition,
+    needed
+  )]
+  WrongDirection {
+    counter: String,
+    changes: &'static str,
+    condition: String,
+    needed: &'static str,
+  },
+  #[display(
+    fmt = "`{}` starts at {} and changes by {} in every iteration, so it never equals {}. Use `<`, `<=`, `>` or `>=` in the condition instead",
+    counter,
+    start,
+    step,
+    bound
+  )]
+  NeverEqual {
+    counter: String,
+    start: f64,
+    step: f64,
+    bound: f64,
+  },
+}
+
+fn changes_text(direction: i32) -> &'static str {
+  if direction > 0 {
+    "increases"
+  } else {
+    "decreases"
+  }
+}
 
 struct ForDirectionHandler;
 
-fn check_update_direction(
-  update_expr: &UpdateExpr,
-  counter_name: impl AsRef<str>,
-) -> i32 {
-  let mut update_direction = 0;
+/// How the update clause of a loop changes its counter.
+struct Update<'a> {
+  counter: &'a str,
+  /// `1` if the counter increases, `-1` if it decreases and `0` if unknown.
+  direction: i32,
+  /// The amount the counter changes by, if it's a number literal.
+  step: Option<f64>,
+}
 
-  if let Expr::Ident(ident) = update_expr.arg {
-    if ident.inner.as_ref() == counter_name.as_ref() {
-      match update_expr.op() {
-        UpdateOp::PlusPlus => {
-          update_direction = 1;
-        }
-        UpdateOp::MinusMinus => {
-          update_direction = -1;
-        }
-      }
+fn update_of(expr: &Expr) -> Option<Update> {
+  match expr {
+    Expr::Update(update_expr) => {
+      let Expr::Ident(ident) = &*update_expr.arg else {
+        return None;
+      };
+      let direction = match update_expr.op {
+        UpdateOp::PlusPlus => 1,
+        UpdateOp::MinusMinus => -1,
+      };
+      Some(Update {
+        counter: ident.sym.as_ref(),
+        direction,
+        step: Some(direction as f64),
+      })
     }
+    Expr::Assign(assign_expr) => assign_update_of(assign_expr),
+    Expr::Paren(paren) => update_of(&paren.expr),
+    _ => None,
   }
-
-  update_direction
 }
 
-fn check_assign_direction(
-  assign_expr: &AssignExpr,
-  counter_name: impl AsRef<str>,
-) -> i32 {
-  let update_direction = 0;
-
-  let name = match &assign_expr.left {
-    AssignTarget::Simple(SimpleAssignTarget::Ident(ident)) => {
-      ident.inner.as_ref()
-    }
-    _ => return update_direction,
+fn assign_update_of(assign_expr: &AssignExpr) -> Option<Update> {
+  let AssignTarget::Simple(SimpleAssignTarget::Ident(ident)) =
+    &assign_expr.left
+  else {
+    return None;
   };
-
-  if name == counter_name.as_ref() {
-    return match assign_expr.op() {
-      AssignOp::AddAssign => check_assign_right_direction(assign_expr, 1),
-      AssignOp::SubAssign => check_assign_right_direction(assign_expr, -1),
-      _ => update_direction,
-    };
-  }
-  update_direction
+  let counter = ident.id.sym.as_ref();
+  let (sign, right) = match assign_expr.op {
+    AssignOp::AddAssign => (1, &*assign_expr.right),
+    AssignOp::SubAssign => (-1, &*assign_expr.right),
+    // `i = i + 1` and `i = i - 1`
+    AssignOp::Assign => match &*assign_expr.right {
+      Expr::Bin(bin_expr) if matches!(&*bin_expr.left, Expr::Ident(left) if left.sym == *counter) => {
+        match bin_expr.op {
+          BinaryOp::Add => (1, &*bin_expr.right),
+          BinaryOp::Sub => (-1, &*bin_expr.right),
+          _ => return None,
+        }
+      }
+      _ => return None,
+    },
+    _ => return None,
+  };
+  Some(Update {
+    counter,
+    direction: assign_right_direction(right, sign),
+    step: number_value(right).map(|value| sign as f64 * value),
+  })
 }
 
-fn check_assign_right_direction(
-  assign_expr: &AssignExpr,
-  direction: i32,
-) -> i32 {
-  match &assign_expr.right {
+fn assign_right_direction(right: &Expr, direction: i32) -> i32 {
+  match right {
     Expr::Unary(unary_expr) => {
-      if unary_expr.op() == UnaryOp::Minus {
+      if unary_expr.op == UnaryOp::Minus {
         -direction
       } else {
         direction
@@ -101,43 +151,148 @@ fn check_assign_right_direction(
   }
 }
 
-impl Handler for ForDirectionHandler {
-  fn for_stmt(&mut self, for_stmt: &ast_view::ForStmt, context: &mut Context) {
-    if for_stmt.update.is_none() {
-      return;
+/// Returns the value of a number literal, optionally negated.
+fn number_value(expr: &Expr) -> Option<f64> {
+  match expr {
+    Expr::Lit(Lit::Num(num)) => Some(num.value),
+    Expr::Unary(unary) if unary.op == UnaryOp::Minus => {
+      number_value(&unary.arg).map(|value| -value)
     }
+    Expr::Paren(paren) => number_value(&paren.expr),
+    _ => None,
+  }
+}
 
-    if let Some(Expr::Bin(bin_expr)) = &for_stmt.test {
-      let counter_name = match &bin_expr.left {
-        Expr::Ident(ident) => ident.inner.as_ref(),
-        _ => return,
-      };
-
-      let wrong_direction = match &bin_expr.op() {
-        BinaryOp::Lt | BinaryOp::LtEq => -1,
-        BinaryOp::Gt | BinaryOp::GtEq => 1,
-        _ => return,
-      };
+/// Returns the comparison of the test with the counter on the left side, and
+/// the other side, e.g. `(Lt, 10)` for both `i < 10` and `10 > i`.
+fn comparison_of<'a>(
+  test: &'a Expr,
+  counter: &str,
+) -> Option<(BinaryOp, &'a Expr)> {
+  let Expr::Bin(bin_expr) = test else {
+    return None;
+  };
+  let is_counter =
+    |expr: &Expr| matches!(expr, Expr::Ident(ident) if ident.sym == *counter);
+  if is_counter(&bin_expr.left) {
+    Some((bin_expr.op, &*bin_expr.right))
+  } else if is_counter(&bin_expr.right) {
+    let op = match bin_expr.op {
+      BinaryOp::Lt => BinaryOp::Gt,
+      BinaryOp::LtEq => BinaryOp::GtEq,
+      BinaryOp::Gt => BinaryOp::Lt,
+      BinaryOp::GtEq => BinaryOp::LtEq,
+      op => op,
+    };
+    Some((op, &*bin_expr.left))
+  } else {
+    None
+  }
+}
 
-      let update = for_stmt.update.as_ref().unwrap();
-      let update_direction = match &update {
-        Expr::Update(update_expr) => {
-          check_update_direction(update_expr, counter_name)
+/// Returns the initial value of the counter if the init clause sets it to a
+/// number literal.
+fn start_value(init: Option<&VarDeclOrExpr>, counter: &str) -> Option<f64> {
+  match init? {
+    VarDeclOrExpr::VarDecl(var_decl) => {
+      var_decl.decls.iter().find_map(|decl| match &decl.name {
+        Pat::Ident(ident) if ident.id.sym == *counter => {
+          number_value(decl.init.as_ref()?)
         }
-        Expr::Assign(assign_expr) => {
-          check_assign_direction(assign_expr, counter_name)
+        _ => None,
+      })
+    }
+    VarDeclOrExpr::Expr(expr) => match &**expr {
+      Expr::Assign(assign_expr) if assign_expr.op == AssignOp::Assign => {
+        match &assign_expr.left {
+          AssignTarget::Simple(SimpleAssignTarget::Ident(ident))
+            if ident.id.sym == *counter =>
+          {
+            number_value(&assign_expr.right)
+          }
+          _ => None,
         }
-        _ => return,
-      };
+      }
+      _ => None,
+    },
+  }
+}
+
+/// Checks if stepping from `start` by `step` never hits `bound` exactly. Only
+/// integers are checked, since fractional steps accumulate rounding errors.
+fn never_equals(start: f64, step: f64, bound: f64) -> bool {
+  if [start, step, bound]
+    .iter()
+    .any(|value| value.fract() != 0.0)
+    || step == 0.0
+  {
+    return false;
+  }
+  let steps = (bound - start) / step;
+  steps < 0.0 || steps.fract() != 0.0
+}
 
-      if update_direction == wrong_direction {
-        context.add_diagnostic_with_hint(
-          for_stmt.range(),
-          "for-direction",
-          MESSAGE,
-          HINT,
-        );
+impl Handler for ForDirectionHandler {
+  fn for_stmt(&mut self, for_stmt: &ast_view::ForStmt, context: &mut Context) {
+    let for_stmt_inner = for_stmt.inner;
+    let (Some(test), Some(update)) =
+      (&for_stmt_inner.test, &for_stmt_inner.update)
+    else {
+      return;
+    };
+    let Some(update) = update_of(update) else {
+      return;
+    };
+    let Some((op, bound)) = comparison_of(test, update.counter) else {
+      return;
+    };
+    let condition = test.range().text_fast(context.text_info()).to_string();
+
+    match op {
+      BinaryOp::Lt | BinaryOp::LtEq | BinaryOp::Gt | BinaryOp::GtEq => {
+        let wrong_direction = match op {
+          BinaryOp::Lt | BinaryOp::LtEq => -1,
+          _ => 1,
+        };
+        if update.direction == wrong_direction {
+          let hint = ForDirectionHint::WrongDirection {
+            counter: update.counter.to_string(),
+            changes: changes_text(update.direction),
+            condition,
+            needed: changes_text(-update.direction),
+          };
+          context.add_diagnostic_with_hint(
+            for_stmt.range(),
+            "for-direction",
+            MESSAGE,
+            hint,
+          );
+        }
+      }
+      BinaryOp::NotEq | BinaryOp::NotEqEq => {
+        let (Some(start), Some(step), Some(bound)) = (
+          start_value(for_stmt_inner.init.as_ref(), update.counter),
+          update.step,
+          number_value(bound),
+        ) else {
+          return;
+        };
+        if never_equals(start, step, bound) {
+          let hint = ForDirectionHint::NeverEqual {
+            counter: update.counter.to_string(),
+            start,
+            step,
+            bound,
+          };
+          context.add_diagnostic_with_hint(
+            for_stmt.range(),
+            "for-direction",
+            MESSAGE_NEVER_EQUAL,
+            hint,
+          );
+        }
       }
+      _ => {}
     }
   }
 }
@@ -146,6 +301,31 @@ impl Handler for ForDirectionHandler {
 mod tests {
   use super::*;
 
+  fn wrong_direction(
+    counter: &str,
+    changes: &'static str,
+    condition: &str,
+    needed: &'static str,
+  ) -> String {
+    ForDirectionHint::WrongDirection {
+      counter: counter.to_string(),
+      changes,
+      condition: condition.to_string(),
+      needed,
+    }
+    .to_string()
+  }
+
+  fn never_equal(counter: &str, start: f64, step: f64, bound: f64) -> String {
+    ForDirectionHint::NeverEqual {
+      counter: counter.to_string(),
+      start,
+      step,
+      bound,
+    }
+    .to_string()
+  }
+
   #[test]
   fn for_direction_valid() {
     assert_lint_ok! {
@@ -188,120 +368,137 @@ mod tests {
   fn for_direction_invalid() {
     assert_lint_err! {
       ForDirection,
-
-      // ++, --
       "for(let i = 0; i < 2; i--) {}": [
         {
           col: 0,
           message: MESSAGE,
-          hint: HINT,
+          hint: wrong_direction("i", "decreases", "i < 2", "increases"),
         }
       ],
       "for(let i = 0; i < 2; --i) {}": [
         {
           col: 0,
           message: MESSAGE,
-          hint: HINT,
+          hint: wrong_direction("i", "decreases", "i < 2", "increases"),
         }
       ],
       "for(let i = 0; i <= 2; i--) {}": [
         {
           col: 0,
           message: MESSAGE,
-          hint: HINT,
+          hint: wrong_direction("i", "decreases", "i <= 2", "increases"),
         }
       ],
       "for(let i = 0; i <= 2; --i) {}": [
         {
           col: 0,
           message: MESSAGE,
-          hint: HINT,
+          hint: wrong_direction("i", "decreases", "i <= 2", "increases"),
         }
       ],
       "for(let i = 2; i > 2; i++) {}": [
         {
           col: 0,
           message: MESSAGE,
-          hint: HINT,
+          hint: wrong_direction("i", "increases", "i > 2", "decreases"),
         }
       ],
       "for(let i = 2; i > 2; ++i) {}": [
         {
           col: 0,
           message: MESSAGE,
-          hint: HINT,
+          hint: wrong_direction("i", "increases", "i > 2", "decreases"),
         }
       ],
       "for(let i = 2; i >= 0; i++) {}": [
         {
           col: 0,
           message: MESSAGE,
-          hint: HINT,
+          hint: wrong_direction("i", "increases", "i >= 0", "decreases"),
         }
       ],
       "for(let i = 2; i >= 0; ++i) {}": [
         {
           col: 0,
           message: MESSAGE,
-          hint: HINT,
+          hint: wrong_direction("i", "increases", "i >= 0", "decreases"),
         }
       ],
-
-      // +=, -=
       "for(let i = 0; i < 2; i -= 1) {}": [
         {
           col: 0,
           message: MESSAGE,
-          hint: HINT,
+          hint: wrong_direction("i", "decreases", "i < 2", "increases"),
         }
       ],
       "for(let i = 0; i <= 2; i -= 1) {}": [
         {
           col: 0,
           message: MESSAGE,
-          hint: HINT,
+          hint: wrong_direction("i", "decreases", "i <= 2", "increases"),
         }
       ],
       "for(let i = 2; i > 2; i -= -1) {}": [
         {
           col: 0,
           message: MESSAGE,
-          hint: HINT,
+          hint: wrong_direction("i", "increases", "i > 2", "decreases"),
         }
       ],
       "for(let i = 2; i >= 0; i -= -1) {}": [
         {
           col: 0,
           message: MESSAGE,
-          hint: HINT,
+          hint: wrong_direction("i", "increases", "i >= 0", "decreases"),
         }
       ],
       "for(let i = 2; i > 2; i += 1) {}": [
         {
           col: 0,
           message: MESSAGE,
-          hint: HINT,
+          hint: wrong_direction("i", "increases", "i > 2", "decreases"),
         }
       ],
       "for(let i = 2; i >= 0; i += 1) {}": [
         {
           col: 0,
           message: MESSAGE,
-          hint: HINT,
+          hint: wrong_direction("i", "increases", "i >= 0", "decreases"),
         }
       ],
       "for(let i = 0; i < 2; i += -1) {}": [
         {
           col: 0,
           message: MESSAGE,
-          hint: HINT,
+          hint: wrong_direction("i", "decreases", "i < 2", "increases"),
         }
       ],
       "for(let i = 0; i <= 2; i += -1) {}": [
         {
           col: 0,
           message: MESSAGE,
-          hint: HINT,
+          hint: wrong_direction("i", "decreases", "i <= 2", "increases"),
+        }
+      ],
+      "for(let i = 0; 2 > i; i--) {}": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: wrong_direction("i", "decreases", "2 > i", "increases"),
+        }
+      ],
+      "for(let i = 10; 0 <= i; i++) {}": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: wrong_direction("i", "increases", "0 <= i", "decreases"),
+        }
+      ],
+      "for(let i = 0; i < 10; i = i - 1) {}": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: wrong_direction("i", "decreases", "i < 10", "increases"),
         }
       ],
 
@@ -315,9 +512,46 @@ for (let i = 0; i < 2; i++) {
           line: 3,
           col: 2,
           message: MESSAGE,
-          hint: HINT,
+          hint: wrong_direction("j", "decreases", "j < 2", "increases"),
         }
       ]
     };
   }
+
+  #[test]
+  fn for_direction_never_equal() {
+    assert_lint_ok! {
+      ForDirection,
+      "for(let i = 0; i != 10; i += 2) {}",
+      "for(let i = 10; i !== 0; i -= 5) {}",
+      "for(i = 1; i != 7; i = i + 3) {}",
+      "for(let i = 0; i != n; i += 2) {}",
+      "for(let i = 0; i != 1; i += 0.5) {}",
+    };
+
+    assert_lint_err! {
+      ForDirection,
+      "for(let i = 0; i != 5; i += 2) {}": [
+        {
+          col: 0,
+          message: MESSAGE_NEVER_EQUAL,
+          hint: never_equal("i", 0.0, 2.0, 5.0),
+        }
+      ],
+      "for(let i = 0; i !== 10; i--) {}": [
+        {
+          col: 0,
+          message: MESSAGE_NEVER_EQUAL,
+          hint: never_equal("i", 0.0, -1.0, 10.0),
+        }
+      ],
+      "for(i = 10; 0 != i; i = i - 3) {}": [
+        {
+          col: 0,
+          message: MESSAGE_NEVER_EQUAL,
+          hint: never_equal("i", 10.0, -3.0, 0.0),
+        }
+      ],
+    };
+  }
 }
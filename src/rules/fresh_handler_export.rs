@@ -1,20 +1,68 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
+use crate::glob;
 use crate::handler::{Handler, Traverse};
+use crate::swc_util::StringRepr;
 use crate::tags::{self, Tags};
 
+use deno_ast::swc::ast::{Expr, Prop, PropOrSpread};
 use deno_ast::view::{Decl, Pat, Program};
-use deno_ast::SourceRanged;
+use deno_ast::{ModuleSpecifier, SourceRanged, SourceRangedForSpanned};
+use derive_more::Display;
+use serde::Deserialize;
 
-#[derive(Debug)]
-pub struct FreshHandlerExport;
+#[derive(Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct FreshHandlerExport {
+  /// Glob patterns of the files that are routes.
+  pub routes: Vec<String>,
+}
+
+impl Default for FreshHandlerExport {
+  fn default() -> Self {
+    Self {
+      routes: vec!["routes/**".to_string()],
+    }
+  }
+}
 
 const CODE: &str = "fresh-handler-export";
 const MESSAGE: &str =
   "Fresh middlewares must be exported as \"handler\" but got \"handlers\" instead.";
 const HINT: &str = "Did you mean \"handler\"?";
 
+/// The methods Fresh calls the handlers of an object handler for.
+const HTTP_METHODS: &[&str] =
+  &["GET", "HEAD", "POST", "PUT", "DELETE", "OPTIONS", "PATCH"];
+
+#[derive(Display)]
+enum FreshHandlerExportMessage {
+  #[display(
+    fmt = "\"{}\" is not an HTTP method, so Fresh never calls this handler.",
+    _0
+  )]
+  UnknownMethod(String),
+  #[display(
+    fmt = "Fresh handlers must be a function or an object of HTTP method handlers."
+  )]
+  NotAHandler,
+}
+
+#[derive(Display)]
+enum FreshHandlerExportHint {
+  #[display(fmt = "Did you mean \"{}\"?", _0)]
+  DidYouMean(String),
+  #[display(
+    fmt = "Use one of GET, HEAD, POST, PUT, DELETE, OPTIONS and PATCH"
+  )]
+  KnownMethods,
+  #[display(
+    fmt = "Export a function that handles all requests, or an object like `{{ GET(req, ctx) {{}} }}`"
+  )]
+  HandlerShape,
+}
+
 impl LintRule for FreshHandlerExport {
   fn tags(&self) -> Tags {
     &[tags::FRESH]
@@ -24,15 +72,51 @@ impl LintRule for FreshHandlerExport {
     CODE
   }
 
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "routes": {
+          "description": "Glob patterns of the files that are routes, `routes/**` by default",
+          "type": "array",
+          "items": { "type": "string" }
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<FreshHandlerExport>(
+      options,
+    )?))
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
     program: Program,
   ) {
+    // Fresh only considers the files in the routes/ folder to be routes.
+    if !self.is_route(context.specifier()) {
+      return;
+    }
     Visitor.traverse(program, context);
   }
 }
 
+impl FreshHandlerExport {
+  fn is_route(&self, specifier: &ModuleSpecifier) -> bool {
+    self
+      .routes
+      .iter()
+      .any(|pattern| glob::matches_specifier(pattern, specifier))
+  }
+}
+
 struct Visitor;
 
 impl Handler for Visitor {
@@ -41,27 +125,18 @@ impl Handler for Visitor {
     export_decl: &deno_ast::view::ExportDecl,
     ctx: &mut Context,
   ) {
-    // Fresh only considers components in the routes/ folder to be
-    // server components.
-    let Some(mut path_segments) = ctx.specifier().path_segments() else {
-      return;
-    };
-    if !path_segments.any(|part| part == "routes") {
-      return;
-    }
-
-    let id = match export_decl.decl {
+    let (id, init) = match export_decl.decl {
       Decl::Var(var_decl) => {
         if let Some(first) = var_decl.decls.first() {
           let Pat::Ident(name_ident) = first.name else {
             return;
           };
-          name_ident.id
+          (name_ident.id, first.inner.init.as_deref())
         } else {
           return;
         }
       }
-      Decl::Fn(fn_decl) => fn_decl.ident,
+      Decl::Fn(fn_decl) => (fn_decl.ident, None),
       _ => return,
     };
 
@@ -69,6 +144,67 @@ impl Handler for Visitor {
     if id.sym().eq("handlers") {
       ctx.add_diagnostic_with_hint(id.range(), CODE, MESSAGE, HINT);
     }
+
+    if id.sym().eq("handler") {
+      if let Some(init) = init {
+        check_handler_value(init, ctx);
+      }
+    }
+  }
+}
+
+/// Checks that the value of a `handler` export is a function or an object
+/// with a handler for each HTTP method, like `{ GET(req, ctx) {} }`.
+fn check_handler_value(value: &Expr, ctx: &mut Context) {
+  match value {
+    Expr::Paren(paren) => check_handler_value(&paren.expr, ctx),
+    Expr::TsAs(ts_as) => check_handler_value(&ts_as.expr, ctx),
+    Expr::TsSatisfies(satisfies) => check_handler_value(&satisfies.expr, ctx),
+    Expr::Object(object) => {
+      for prop in &object.props {
+        let PropOrSpread::Prop(prop) = prop else {
+          continue;
+        };
+        let (key, key_range) = match &**prop {
+          Prop::KeyValue(kv) => (kv.key.string_repr(), kv.key.range()),
+          Prop::Method(method) => {
+            (method.key.string_repr(), method.key.range())
+          }
+          Prop::Shorthand(ident) => {
+            (Some(ident.sym.to_string()), ident.range())
+          }
+          _ => continue,
+        };
+        // Computed keys like `[method]` can't be checked.
+        let Some(key) = key else {
+          continue;
+        };
+        if HTTP_METHODS.contains(&key.as_str()) {
+          continue;
+        }
+        let upper = key.to_uppercase();
+        let hint = if HTTP_METHODS.contains(&upper.as_str()) {
+          FreshHandlerExportHint::DidYouMean(upper)
+        } else {
+          FreshHandlerExportHint::KnownMethods
+        };
+        ctx.add_diagnostic_with_hint(
+          key_range,
+          CODE,
+          FreshHandlerExportMessage::UnknownMethod(key),
+          hint,
+        );
+      }
+    }
+    Expr::Lit(_) | Expr::Tpl(_) | Expr::Array(_) => {
+      ctx.add_diagnostic_with_hint(
+        value.range(),
+        CODE,
+        FreshHandlerExportMessage::NotAHandler,
+        FreshHandlerExportHint::HandlerShape,
+      );
+    }
+    _ => {}
   }
 }
 
@@ -79,64 +215,133 @@ mod tests {
   #[test]
   fn fresh_handler_export_name() {
     assert_lint_ok!(
-      FreshHandlerExport,
+      FreshHandlerExport::default(),
       filename: "file:///foo.jsx",
       "const handler = {}",
     );
     assert_lint_ok!(
-      FreshHandlerExport,
+      FreshHandlerExport::default(),
       filename: "file:///foo.jsx",
       "function handler() {}",
     );
     assert_lint_ok!(
-      FreshHandlerExport,
+      FreshHandlerExport::default(),
       filename: "file:///foo.jsx",
       "export const handler = {}",
     );
     assert_lint_ok!(
-      FreshHandlerExport,
+      FreshHandlerExport::default(),
       filename: "file:///foo.jsx",
       "export const handlers = {}",
     );
     assert_lint_ok!(
-      FreshHandlerExport,
+      FreshHandlerExport::default(),
       filename: "file:///foo.jsx",
       "export function handlers() {}",
     );
 
     assert_lint_ok!(
-      FreshHandlerExport,
+      FreshHandlerExport::default(),
       filename: "file:///routes/foo.jsx",
       "export const handler = {}",
     );
     assert_lint_ok!(
-      FreshHandlerExport,
+      FreshHandlerExport::default(),
       filename: "file:///routes/foo.jsx",
       "export function handler() {}",
     );
     assert_lint_ok!(
-      FreshHandlerExport,
+      FreshHandlerExport::default(),
       filename: "file:///routes/foo.jsx",
       "export async function handler() {}",
     );
 
-    assert_lint_err!(FreshHandlerExport, filename: "file:///routes/index.tsx",  r#"export const handlers = {}"#: [
+    assert_lint_err!(FreshHandlerExport::default(), filename: "file:///routes/index.tsx",  r#"export const handlers = {}"#: [
     {
       col: 13,
       message: MESSAGE,
       hint: HINT,
     }]);
-    assert_lint_err!(FreshHandlerExport, filename: "file:///routes/index.tsx",  r#"export function handlers() {}"#: [
+    assert_lint_err!(FreshHandlerExport::default(), filename: "file:///routes/index.tsx",  r#"export function handlers() {}"#: [
     {
       col: 16,
       message: MESSAGE,
       hint: HINT,
     }]);
-    assert_lint_err!(FreshHandlerExport, filename: "file:///routes/index.tsx",  r#"export async function handlers() {}"#: [
+    assert_lint_err!(FreshHandlerExport::default(), filename: "file:///routes/index.tsx",  r#"export async function handlers() {}"#: [
     {
       col: 22,
       message: MESSAGE,
       hint: HINT,
     }]);
   }
+
+  #[test]
+  fn fresh_handler_export_shape() {
+    assert_lint_ok!(
+      FreshHandlerExport::default(),
+      filename: "file:///routes/index.tsx",
+      "export const handler = (req, ctx) => ctx.render();",
+      "export const handler = { GET(req, ctx) {}, POST: (req, ctx) => {} };",
+      "export const handler: Handlers = { async GET(req, ctx) {} };",
+      "export const handler = { GET, HEAD } satisfies Handlers;",
+      "export const handler = { [method](req, ctx) {} };",
+      "export const handler = { ...handlers, GET(req, ctx) {} };",
+      "export const handler = createHandler();",
+    );
+    assert_lint_ok!(
+      FreshHandlerExport::default(),
+      filename: "file:///islands/index.tsx",
+      "export const handler = { get(req, ctx) {} };",
+    );
+
+    assert_lint_err!(FreshHandlerExport::default(), filename: "file:///routes/index.tsx",  r#"export const handler = { get(req, ctx) {} };"#: [
+    {
+      col: 25,
+      message: variant!(FreshHandlerExportMessage, UnknownMethod, "get"),
+      hint: variant!(FreshHandlerExportHint, DidYouMean, "GET"),
+    }]);
+    assert_lint_err!(FreshHandlerExport::default(), filename: "file:///routes/index.tsx",  r#"export const handler = { GET(req, ctx) {}, Post: (req, ctx) => {} };"#: [
+    {
+      col: 43,
+      message: variant!(FreshHandlerExportMessage, UnknownMethod, "Post"),
+      hint: variant!(FreshHandlerExportHint, DidYouMean, "POST"),
+    }]);
+    assert_lint_err!(FreshHandlerExport::default(), filename: "file:///routes/index.tsx",  r#"export const handler = { FETCH(req, ctx) {} };"#: [
+    {
+      col: 25,
+      message: variant!(FreshHandlerExportMessage, UnknownMethod, "FETCH"),
+      hint: FreshHandlerExportHint::KnownMethods,
+    }]);
+    assert_lint_err!(FreshHandlerExport::default(), filename: "file:///routes/index.tsx",  r#"export const handler = { get } satisfies Handlers;"#: [
+    {
+      col: 25,
+      message: variant!(FreshHandlerExportMessage, UnknownMethod, "get"),
+      hint: variant!(FreshHandlerExportHint, DidYouMean, "GET"),
+    }]);
+    assert_lint_err!(FreshHandlerExport::default(), filename: "file:///routes/index.tsx",  r#"export const handler = "index";"#: [
+    {
+      col: 23,
+      message: FreshHandlerExportMessage::NotAHandler,
+      hint: FreshHandlerExportHint::HandlerShape,
+    }]);
+  }
+
+  #[test]
+  fn custom_route_patterns() {
+    let rule = || FreshHandlerExport {
+      routes: vec!["src/pages/**".to_string()],
+    };
+    assert_lint_ok!(
+      rule(),
+      filename: "file:///routes/index.tsx",
+      "export const handlers = {}",
+    );
+    assert_lint_err!(rule(), filename: "file:///src/pages/index.tsx",  r#"export const handlers = {}"#: [
+    {
+      col: 13,
+      message: MESSAGE,
+      hint: HINT,
+    }]);
+  }
 }
@@ -1,16 +1,35 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
+use crate::glob;
 use crate::handler::{Handler, Traverse};
 use crate::tags::{self, Tags};
 
 use deno_ast::view::{
   Expr, JSXAttrName, JSXAttrValue, JSXElementName, JSXExpr, Program,
 };
-use deno_ast::SourceRanged;
+use deno_ast::{ModuleSpecifier, SourceRanged};
+use serde::Deserialize;
 
-#[derive(Debug)]
-pub struct FreshServerEventHandlers;
+#[derive(Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct FreshServerEventHandlers {
+  /// Glob patterns of the files that are routes, which Fresh renders on the
+  /// server.
+  pub routes: Vec<String>,
+  /// Glob patterns of the files that are islands, which are rendered in the
+  /// browser too, even if they match `routes`.
+  pub islands: Vec<String>,
+}
+
+impl Default for FreshServerEventHandlers {
+  fn default() -> Self {
+    Self {
+      routes: vec!["routes/**".to_string()],
+      islands: vec!["(_islands)/**".to_string()],
+    }
+  }
+}
 
 const CODE: &str = "fresh-server-event-handlers";
 const MESSAGE: &str =
@@ -27,15 +46,60 @@ impl LintRule for FreshServerEventHandlers {
     CODE
   }
 
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "routes": {
+          "description": "Glob patterns of the files that are server rendered routes, `routes/**` by default",
+          "type": "array",
+          "items": { "type": "string" }
+        },
+        "islands": {
+          "description": "Glob patterns of the files that are islands, `(_islands)/**` by default",
+          "type": "array",
+          "items": { "type": "string" }
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(
+      serde_json::from_value::<FreshServerEventHandlers>(options)?,
+    ))
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
     program: Program,
   ) {
+    // Fresh only considers components in the routes/ folder to be
+    // server components. Files inside an `(_islands)` folder are considered
+    // islands though, even if they are inside the `routes` folder.
+    if !self.is_server_component(context.specifier()) {
+      return;
+    }
     Visitor.traverse(program, context);
   }
 }
 
+impl FreshServerEventHandlers {
+  fn is_server_component(&self, specifier: &ModuleSpecifier) -> bool {
+    let matches = |patterns: &[String]| {
+      patterns
+        .iter()
+        .any(|pattern| glob::matches_specifier(pattern, specifier))
+    };
+    matches(&self.routes) && !matches(&self.islands)
+  }
+}
+
 struct Visitor;
 
 impl Handler for Visitor {
@@ -44,19 +108,6 @@ impl Handler for Visitor {
     jsx_attr: &deno_ast::view::JSXAttr,
     ctx: &mut Context,
   ) {
-    // Fresh only considers components in the routes/ folder to be
-    // server components. Files inside an `(_islands)` folder are considered
-    // islands though, even if they are inside the `routes` folder.
-    let Some(path_segments) = ctx.specifier().path_segments() else {
-      return;
-    };
-    let segments = path_segments.collect::<Vec<_>>();
-    if !segments.iter().any(|comp| *comp == "routes")
-      || segments.iter().any(|comp| *comp == "(_islands)")
-    {
-      return;
-    }
-
     // We only care about native HTML elements not namespaced XML
     // like `<foo:bar />` or member expressions like `<foo.bar />`
     let parent_name = match jsx_attr.parent().name {
@@ -130,76 +181,100 @@ mod tests {
   #[test]
   fn no_server_event_handler() {
     assert_lint_ok!(
-      FreshServerEventHandlers,
+      FreshServerEventHandlers::default(),
       filename: "file:///foo.jsx",
       "<Foo onClick={() => {}} />",
     );
     assert_lint_ok!(
-      FreshServerEventHandlers,
+      FreshServerEventHandlers::default(),
       filename: "file:///foo.jsx",
       "<button onClick={() => {}} />",
     );
     assert_lint_ok!(
-      FreshServerEventHandlers,
+      FreshServerEventHandlers::default(),
       filename: "file:///foo.jsx",
       "<button onClick={function () {}} />",
     );
     assert_lint_ok!(
-      FreshServerEventHandlers,
+      FreshServerEventHandlers::default(),
       filename: "file:///foo.jsx",
       "<button onclick={function () {}} />",
     );
     assert_lint_ok!(
-      FreshServerEventHandlers,
+      FreshServerEventHandlers::default(),
       filename: "file:///foo.jsx",
       "<button onClick=\"console.log('hey')\" />",
     );
     assert_lint_ok!(
-      FreshServerEventHandlers,
+      FreshServerEventHandlers::default(),
       filename: "file:///foo.jsx",
       "<button online=\"foo\" />",
     );
     assert_lint_ok!(
-      FreshServerEventHandlers,
+      FreshServerEventHandlers::default(),
       filename: "file:///foo.jsx",
       "<x-foo onClick=\"console.log('hey')\" />",
     );
     assert_lint_ok!(
-      FreshServerEventHandlers,
+      FreshServerEventHandlers::default(),
       filename: "file:///routes/foo/(_islands)/foo.jsx",
       "<button onClick={function () {}} />",
     );
 
-    assert_lint_err!(FreshServerEventHandlers, filename: "file:///routes/index.tsx",  r#"<button onClick={() => {}} />"#: [
+    assert_lint_err!(FreshServerEventHandlers::default(), filename: "file:///routes/index.tsx",  r#"<button onClick={() => {}} />"#: [
     {
       col: 8,
       message: MESSAGE,
       hint: HINT,
     }]);
-    assert_lint_err!(FreshServerEventHandlers, filename: "file:///routes/index.tsx",  r#"<button onTouchMove={() => {}} />"#: [
+    assert_lint_err!(FreshServerEventHandlers::default(), filename: "file:///routes/index.tsx",  r#"<button onTouchMove={() => {}} />"#: [
     {
       col: 8,
       message: MESSAGE,
       hint: HINT,
     }]);
-    assert_lint_err!(FreshServerEventHandlers, filename: "file:///routes/index.tsx",  r#"<button onTouchMove={"console.log('hey')"} />"#: [
+    assert_lint_err!(FreshServerEventHandlers::default(), filename: "file:///routes/index.tsx",  r#"<button onTouchMove={"console.log('hey')"} />"#: [
     {
       col: 8,
       message: MESSAGE,
       hint: HINT,
     }]);
 
-    assert_lint_err!(FreshServerEventHandlers, filename: "file:///routes/index.tsx",  r#"<foo-button foo={() => {}} />"#: [
+    assert_lint_err!(FreshServerEventHandlers::default(), filename: "file:///routes/index.tsx",  r#"<foo-button foo={() => {}} />"#: [
     {
       col: 12,
       message: MESSAGE,
       hint: HINT,
     }]);
-    assert_lint_err!(FreshServerEventHandlers, filename: "file:///routes/index.tsx",  r#"<foo-button foo={function () {}} />"#: [
+    assert_lint_err!(FreshServerEventHandlers::default(), filename: "file:///routes/index.tsx",  r#"<foo-button foo={function () {}} />"#: [
     {
       col: 12,
       message: MESSAGE,
       hint: HINT,
     }]);
   }
+
+  #[test]
+  fn custom_route_patterns() {
+    let rule = || FreshServerEventHandlers {
+      routes: vec!["src/pages/**".to_string()],
+      islands: vec!["src/pages/**/*.island.tsx".to_string()],
+    };
+    assert_lint_ok!(
+      rule(),
+      filename: "file:///routes/index.tsx",
+      "<button onClick={() => {}} />",
+    );
+    assert_lint_ok!(
+      rule(),
+      filename: "file:///src/pages/counter.island.tsx",
+      "<button onClick={() => {}} />",
+    );
+    assert_lint_err!(rule(), filename: "file:///src/pages/index.tsx",  r#"<button onClick={() => {}} />"#: [
+    {
+      col: 8,
+      message: MESSAGE,
+      hint: HINT,
+    }]);
+  }
 }
@@ -0,0 +1,413 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::diagnostic::LintDiagnosticRelated;
+use crate::handler::{Handler, Traverse};
+use crate::tags::{self, Tags};
+use crate::this_context::ThisContext;
+use crate::Program;
+use deno_ast::swc::ast::{Expr, Pat};
+use deno_ast::view as ast_view;
+use deno_ast::{SourceRange, SourceRanged};
+use serde::Deserialize;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct FuncStyle {
+  /// Whether named functions are written as function expressions assigned to
+  /// variables, or as function declarations.
+  pub style: Style,
+  /// With the `declaration` style, still allows arrow functions assigned to
+  /// variables.
+  pub allow_arrow_functions: bool,
+  /// With the `declaration` style, still allows functions assigned to
+  /// variables with a type annotation, e.g. `const foo: Handler = () => {}`,
+  /// since the type can't be written on a function declaration.
+  pub allow_type_annotation: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Style {
+  #[default]
+  Expression,
+  Declaration,
+}
+
+const CODE: &str = "func-style";
+
+impl LintRule for FuncStyle {
+  fn tags(&self) -> Tags {
+    &[tags::STYLISTIC]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Enforces a consistent style for named functions: either \
+        function expressions assigned to variables (the default \
+        `expression` style), or function declarations (the `declaration` \
+        style). Diagnostics point to the first function in the file that is \
+        written in the expected style. Arrow functions that use `this`, \
+        `arguments`, `super` or `new.target` are never reported, because \
+        they can't be written as declarations. With the \
+        `allowTypeAnnotation` option, functions assigned to variables with a \
+        type annotation are allowed in the `declaration` style, because the \
+        type of a function declaration can't be given as a whole.",
+      invalid_examples: &["function add(a, b) {\n  return a + b;\n}"],
+      valid_examples: &[
+        "const add = function (a, b) {\n  return a + b;\n};",
+        "const add = (a, b) => a + b;",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "style": {
+          "description": "Use function expressions or declarations. Defaults to `expression`",
+          "enum": ["expression", "declaration"]
+        },
+        "allowArrowFunctions": {
+          "description": "With `declaration`, allow arrow functions assigned to variables",
+          "type": "boolean"
+        },
+        "allowTypeAnnotation": {
+          "description": "With `declaration`, allow functions assigned to variables with a type annotation",
+          "type": "boolean"
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<FuncStyle>(options)?))
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    let mut handler = FuncStyleHandler::default();
+    handler.traverse(program, context);
+    handler.report_errors(self, context);
+  }
+}
+
+#[derive(Clone, Copy)]
+enum DiagnosticKind {
+  ExpectedExpression,
+  ExpectedDeclaration,
+}
+
+impl DiagnosticKind {
+  #[cfg(test)]
+  fn message_and_hint(&self) -> (&'static str, &'static str) {
+    (self.message(), self.hint())
+  }
+
+  fn message(&self) -> &'static str {
+    match *self {
+      DiagnosticKind::ExpectedExpression => "Expected a function expression",
+      DiagnosticKind::ExpectedDeclaration => "Expected a function declaration",
+    }
+  }
+
+  fn hint(&self) -> &'static str {
+    match *self {
+      DiagnosticKind::ExpectedExpression => {
+        "Assign a function expression to a variable instead, e.g. `const foo = function () {}`"
+      }
+      DiagnosticKind::ExpectedDeclaration => {
+        "Use a function declaration instead, e.g. `function foo() {}`"
+      }
+    }
+  }
+
+  fn related_message(&self) -> &'static str {
+    match *self {
+      DiagnosticKind::ExpectedExpression => {
+        "The first function expression in this file"
+      }
+      DiagnosticKind::ExpectedDeclaration => {
+        "The first function declaration in this file"
+      }
+    }
+  }
+}
+
+struct FunctionExpression {
+  range: SourceRange,
+  is_arrow: bool,
+  /// Whether the arrow function inherits `this`, `arguments`, `super` or
+  /// `new.target` from the enclosing function.
+  uses_this_context: bool,
+  /// Whether the variable has a type annotation, e.g. `const foo: Handler`.
+  has_type_annotation: bool,
+}
+
+#[derive(Default)]
+struct FuncStyleHandler {
+  declarations: Vec<SourceRange>,
+  expressions: Vec<FunctionExpression>,
+}
+
+impl FuncStyleHandler {
+  fn report_errors(self, rule: &FuncStyle, ctx: &mut Context) {
+    match rule.style {
+      Style::Expression => report(
+        DiagnosticKind::ExpectedExpression,
+        &self.declarations,
+        self.expressions.first().map(|expression| expression.range),
+        ctx,
+      ),
+      Style::Declaration => {
+        let ranges = self
+          .expressions
+          .iter()
+          .filter(|expression| {
+            !(expression.is_arrow
+              && (rule.allow_arrow_functions || expression.uses_this_context))
+              && !(rule.allow_type_annotation && expression.has_type_annotation)
+          })
+          .map(|expression| expression.range)
+          .collect::<Vec<_>>();
+        report(
+          DiagnosticKind::ExpectedDeclaration,
+          &ranges,
+          self.declarations.first().copied(),
+          ctx,
+        );
+      }
+    }
+  }
+}
+
+fn report(
+  kind: DiagnosticKind,
+  ranges: &[SourceRange],
+  first_expected: Option<SourceRange>,
+  ctx: &mut Context,
+) {
+  let related = first_expected
+    .map(|range| LintDiagnosticRelated {
+      range,
+      message: kind.related_message().to_string(),
+    })
+    .into_iter()
+    .collect::<Vec<_>>();
+  for range in ranges {
+    ctx.add_diagnostic_with_related(
+      *range,
+      CODE,
+      kind.message(),
+      Some(kind.hint().to_string()),
+      related.clone(),
+    );
+  }
+}
+
+impl Handler for FuncStyleHandler {
+  fn fn_decl(&mut self, fn_decl: &ast_view::FnDecl, _ctx: &mut Context) {
+    // Overload signatures and ambient declarations have no counterpart as
+    // expressions.
+    if fn_decl.inner.declare || fn_decl.inner.function.body.is_none() {
+      return;
+    }
+    self.declarations.push(fn_decl.range());
+  }
+
+  fn var_declarator(
+    &mut self,
+    declarator: &ast_view::VarDeclarator,
+    _ctx: &mut Context,
+  ) {
+    let (Pat::Ident(binding), Some(init)) =
+      (&declarator.inner.name, &declarator.inner.init)
+    else {
+      return;
+    };
+    let has_type_annotation = binding.type_ann.is_some();
+    let expression = match &**init {
+      Expr::Fn(_) => FunctionExpression {
+        range: declarator.range(),
+        is_arrow: false,
+        uses_this_context: false,
+        has_type_annotation,
+      },
+      Expr::Arrow(arrow) => {
+        let this_context = ThisContext::of_arrow(arrow);
+        FunctionExpression {
+          range: declarator.range(),
+          is_arrow: true,
+          uses_this_context: this_context.uses_this
+            || this_context.uses_non_this_bindings(),
+          has_type_annotation,
+        }
+      }
+      _ => return,
+    };
+    self.expressions.push(expression);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn func_style_valid() {
+    assert_lint_ok! {
+      FuncStyle::default(),
+      "const foo = function () {};",
+      "const foo = () => {};",
+      "export default function () {}",
+      "declare function foo(): void;",
+      "function foo(a: string): void;\nconst bar = 1;",
+      "class A { foo() {} }",
+    };
+
+    assert_lint_ok! {
+      FuncStyle {
+        style: Style::Declaration,
+        ..Default::default()
+      },
+      "function foo() {}",
+      "const foo = bar(function () {});",
+      "const foo = () => this.bar;",
+      "function outer() { const foo = () => arguments[0]; }",
+      "const { foo } = function () {};",
+    };
+
+    assert_lint_ok! {
+      FuncStyle {
+        style: Style::Declaration,
+        allow_arrow_functions: true,
+        ..Default::default()
+      },
+      "const foo = () => {};",
+    };
+
+    assert_lint_ok! {
+      FuncStyle {
+        style: Style::Declaration,
+        allow_type_annotation: true,
+        ..Default::default()
+      },
+      "const foo: Handler = () => {};",
+      "const foo: (a: number) => number = function (a) { return a; };",
+    };
+  }
+
+  #[test]
+  fn func_style_invalid() {
+    let (expression_message, expression_hint) =
+      DiagnosticKind::ExpectedExpression.message_and_hint();
+    let (declaration_message, declaration_hint) =
+      DiagnosticKind::ExpectedDeclaration.message_and_hint();
+
+    assert_lint_err! {
+      FuncStyle::default(),
+      "function foo() {}": [
+        {
+          col: 0,
+          message: expression_message,
+          hint: expression_hint,
+        }
+      ],
+      "export function foo() {}": [
+        {
+          col: 7,
+          message: expression_message,
+          hint: expression_hint,
+        }
+      ],
+      "const foo = () => { function bar() {} };": [
+        {
+          col: 20,
+          message: expression_message,
+          hint: expression_hint,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      FuncStyle {
+        style: Style::Declaration,
+        ..Default::default()
+      },
+      "const foo = function () {};": [
+        {
+          col: 6,
+          message: declaration_message,
+          hint: declaration_hint,
+        }
+      ],
+      "const foo = () => {};": [
+        {
+          col: 6,
+          message: declaration_message,
+          hint: declaration_hint,
+        }
+      ],
+      "const foo: Handler = () => {};": [
+        {
+          col: 6,
+          message: declaration_message,
+          hint: declaration_hint,
+        }
+      ],
+      "let foo = function () {}, bar = () => 1;": [
+        {
+          col: 4,
+          message: declaration_message,
+          hint: declaration_hint,
+        },
+        {
+          col: 26,
+          message: declaration_message,
+          hint: declaration_hint,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn func_style_related() {
+    let (parsed_source, diagnostics) = crate::testing::lint(
+      Box::new(FuncStyle::default()),
+      "function foo() {}\nconst bar = () => {};\nfunction baz() {}",
+      "file:///func_style_test.ts",
+    );
+    let text_info = parsed_source.text_info_lazy();
+    assert_eq!(diagnostics.len(), 2);
+    for diagnostic in &diagnostics {
+      let [related] = diagnostic.details.related.as_slice() else {
+        panic!("expected exactly one related location");
+      };
+      assert_eq!(related.range.text_fast(text_info), "bar = () => {}");
+      assert_eq!(
+        related.message,
+        "The first function expression in this file"
+      );
+    }
+
+    let (_, diagnostics) = crate::testing::lint(
+      Box::new(FuncStyle::default()),
+      "function foo() {}",
+      "file:///func_style_test.ts",
+    );
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0].details.related.is_empty());
+  }
+}
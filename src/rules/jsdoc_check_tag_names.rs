@@ -0,0 +1,257 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::jsdoc::JsDoc;
+use crate::tags::{self, Tags};
+use crate::Program;
+use serde::Deserialize;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct JsdocCheckTagNames {
+  /// Additional tags that are allowed, without the `@`.
+  pub defined_tags: Vec<String>,
+}
+
+const CODE: &str = "jsdoc-check-tag-names";
+const HINT: &str =
+  "Use a known tag, or add it to the `definedTags` option of the rule";
+
+/// Block tags of JSDoc, TypeScript and TSDoc, and the JSX pragmas that are
+/// written in JSDoc comments.
+const KNOWN_TAGS: &[&str] = &[
+  "abstract",
+  "access",
+  "alias",
+  "alpha",
+  "arg",
+  "argument",
+  "async",
+  "augments",
+  "author",
+  "beta",
+  "borrows",
+  "callback",
+  "category",
+  "class",
+  "classdesc",
+  "const",
+  "constant",
+  "constructor",
+  "constructs",
+  "copyright",
+  "default",
+  "defaultValue",
+  "defaultvalue",
+  "deprecated",
+  "desc",
+  "description",
+  "emits",
+  "enum",
+  "event",
+  "example",
+  "exception",
+  "experimental",
+  "exports",
+  "extends",
+  "external",
+  "file",
+  "fileoverview",
+  "fires",
+  "func",
+  "function",
+  "generator",
+  "global",
+  "hideconstructor",
+  "host",
+  "ignore",
+  "implements",
+  "import",
+  "inheritDoc",
+  "inheritdoc",
+  "inner",
+  "instance",
+  "interface",
+  "internal",
+  "jsx",
+  "jsxFrag",
+  "jsxImportSource",
+  "jsxRuntime",
+  "kind",
+  "lends",
+  "license",
+  "listens",
+  "member",
+  "memberof",
+  "method",
+  "mixes",
+  "mixin",
+  "module",
+  "name",
+  "namespace",
+  "overload",
+  "override",
+  "overview",
+  "package",
+  "packageDocumentation",
+  "param",
+  "private",
+  "privateRemarks",
+  "prop",
+  "property",
+  "protected",
+  "public",
+  "readonly",
+  "remarks",
+  "requires",
+  "return",
+  "returns",
+  "satisfies",
+  "see",
+  "since",
+  "static",
+  "summary",
+  "tags",
+  "template",
+  "this",
+  "throws",
+  "todo",
+  "tutorial",
+  "type",
+  "typeParam",
+  "typedef",
+  "var",
+  "variation",
+  "version",
+  "virtual",
+  "yield",
+  "yields",
+];
+
+impl LintRule for JsdocCheckTagNames {
+  fn tags(&self) -> Tags {
+    &[tags::JSDOC]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows unknown block tags in JSDoc comments, which \
+        are usually typos like `@retuns` and are ignored by documentation \
+        generators and type checkers. The tags of JSDoc, TypeScript and TSDoc \
+        are known. Custom tags can be allowed with the `definedTags` \
+        option.",
+      invalid_examples: &[
+        "/**\n * Adds two numbers.\n * @retuns The sum.\n */\nfunction add(a, b) {\n  return a + b;\n}",
+      ],
+      valid_examples: &[
+        "/**\n * Adds two numbers.\n * @returns The sum.\n */\nfunction add(a, b) {\n  return a + b;\n}",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "definedTags": {
+          "description": "Additional tags that are allowed, without the `@`",
+          "type": "array",
+          "items": { "type": "string" }
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<JsdocCheckTagNames>(
+      options,
+    )?))
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    _program: Program,
+  ) {
+    let unknown_tags = context
+      .all_comments()
+      .filter_map(JsDoc::parse)
+      .flat_map(|jsdoc| jsdoc.tags)
+      .filter(|tag| {
+        !KNOWN_TAGS.contains(&tag.name.as_str())
+          && !self.defined_tags.contains(&tag.name)
+      })
+      .collect::<Vec<_>>();
+    for tag in unknown_tags {
+      context.add_diagnostic_with_hint(
+        tag.name_range,
+        CODE,
+        format!("Unknown JSDoc tag `@{}`", tag.name),
+        HINT,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn jsdoc_check_tag_names_valid() {
+    assert_lint_ok! {
+      JsdocCheckTagNames::default(),
+      "/** @param a The number. @returns The sum. */\nfunction add(a) {}",
+      "/**\n * @param {number} a\n * @returns {number}\n * @throws {Error}\n * @example\n * add(1);\n */\nfunction add(a) {}",
+      "/** @jsx h */",
+      "/** @deprecated Use `bar` instead. */\nfunction foo() {}",
+      "/** @module */",
+      "/** @typeParam T The type. */\nfunction id<T>(t: T) {}",
+      "/* @unknown Not a JSDoc comment. */",
+      "// @unknown Not a JSDoc comment.",
+      "/** Contact foo@example.com for details. */",
+      "/** Renders {@link Foo}. */",
+      "/**\n * ```ts\n * @decorator\n * class A {}\n * ```\n */",
+    };
+  }
+
+  #[test]
+  fn jsdoc_check_tag_names_invalid() {
+    assert_lint_err! {
+      JsdocCheckTagNames::default(),
+      "/** @retuns The sum. */": [
+        {
+          col: 4,
+          message: "Unknown JSDoc tag `@retuns`",
+          hint: HINT,
+        }
+      ],
+      "/**\n * @param a\n * @paramm b\n */\nfunction add(a, b) {}": [
+        {
+          line: 3,
+          col: 3,
+          message: "Unknown JSDoc tag `@paramm`",
+          hint: HINT,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn jsdoc_check_tag_names_defined_tags() {
+    assert_lint_ok! {
+      JsdocCheckTagNames {
+        defined_tags: vec!["route".to_string()],
+      },
+      "/** @route /users */\nfunction users() {}",
+    };
+  }
+}
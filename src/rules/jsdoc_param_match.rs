@@ -0,0 +1,372 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::handler::{Handler, Traverse};
+use crate::jsdoc::{is_void, owner_start};
+use crate::tags::{self, Tags};
+use crate::Program;
+use deno_ast::swc::ast::{
+  Function, ParamOrTsParamProp, Pat, TsParamPropParam, TsTypeAnn,
+};
+use deno_ast::view::{self as ast_view, Node, NodeTrait};
+use deno_ast::{SourcePos, SourceRanged};
+
+#[derive(Debug)]
+pub struct JsdocParamMatch;
+
+const CODE: &str = "jsdoc-param-match";
+
+const PARAM_TAGS: &[&str] = &["param", "arg", "argument"];
+const RETURNS_TAGS: &[&str] = &["returns", "return"];
+
+enum DiagnosticKind {
+  UnknownParam,
+  DuplicateParam,
+  ReturnsOnVoid,
+}
+
+impl DiagnosticKind {
+  fn message(&self, name: &str) -> String {
+    match *self {
+      DiagnosticKind::UnknownParam => format!(
+        "Documented parameter `{}` isn't a parameter of the function",
+        name
+      ),
+      DiagnosticKind::DuplicateParam => {
+        format!("Parameter `{}` is documented more than once", name)
+      }
+      DiagnosticKind::ReturnsOnVoid => format!(
+        "`@{}` is documented for a function that returns `void`",
+        name
+      ),
+    }
+  }
+
+  fn hint(&self) -> &'static str {
+    match *self {
+      DiagnosticKind::UnknownParam => {
+        "Use the name of the parameter it documents, or remove the tag"
+      }
+      DiagnosticKind::DuplicateParam => "Remove the duplicate tag",
+      DiagnosticKind::ReturnsOnVoid => {
+        "Remove the tag, or change the return type of the function"
+      }
+    }
+  }
+}
+
+impl LintRule for JsdocParamMatch {
+  fn tags(&self) -> Tags {
+    &[tags::JSDOC]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Checks that the JSDoc comments of functions match their \
+        implementation. The names in `@param` tags must be parameters of the \
+        function, and each parameter is documented at most once. Properties \
+        of parameters like `options.verbose` and destructured parameters, \
+        which can be documented with any name, are allowed. `@returns` must \
+        not be used for functions with a `void` return type annotation.",
+      invalid_examples: &[
+        "/**\n * @param name The name to greet.\n */\nfunction greet(user: string) {}",
+        "/** @returns Nothing. */\nfunction log(message: string): void {}",
+      ],
+      valid_examples: &[
+        "/**\n * @param user The name to greet.\n */\nfunction greet(user: string) {}",
+        "/**\n * @param options The options.\n * @param options.verbose Whether to log more.\n */\nfunction run(options: { verbose: boolean }) {}",
+        "/** @param point The point. */\nfunction draw({ x, y }: Point) {}",
+      ],
+    }
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    JsdocParamMatchHandler.traverse(program, context);
+  }
+}
+
+struct JsdocParamMatchHandler;
+
+impl Handler for JsdocParamMatchHandler {
+  fn fn_decl(&mut self, fn_decl: &ast_view::FnDecl, ctx: &mut Context) {
+    check_function(fn_decl.as_node(), fn_decl.inner.function.as_ref(), ctx);
+  }
+
+  fn fn_expr(&mut self, fn_expr: &ast_view::FnExpr, ctx: &mut Context) {
+    check_function(fn_expr.as_node(), fn_expr.inner.function.as_ref(), ctx);
+  }
+
+  fn arrow_expr(&mut self, arrow: &ast_view::ArrowExpr, ctx: &mut Context) {
+    let params = arrow.inner.params.iter().map(param_name).collect();
+    check(
+      owner_start(arrow.as_node()),
+      params,
+      arrow.inner.return_type.as_deref(),
+      arrow.inner.is_async,
+      ctx,
+    );
+  }
+
+  fn class_method(
+    &mut self,
+    class_method: &ast_view::ClassMethod,
+    ctx: &mut Context,
+  ) {
+    check_function(
+      class_method.as_node(),
+      class_method.inner.function.as_ref(),
+      ctx,
+    );
+  }
+
+  fn private_method(
+    &mut self,
+    private_method: &ast_view::PrivateMethod,
+    ctx: &mut Context,
+  ) {
+    check_function(
+      private_method.as_node(),
+      private_method.inner.function.as_ref(),
+      ctx,
+    );
+  }
+
+  fn method_prop(
+    &mut self,
+    method_prop: &ast_view::MethodProp,
+    ctx: &mut Context,
+  ) {
+    check_function(
+      method_prop.as_node(),
+      method_prop.inner.function.as_ref(),
+      ctx,
+    );
+  }
+
+  fn constructor(
+    &mut self,
+    constructor: &ast_view::Constructor,
+    ctx: &mut Context,
+  ) {
+    let params = constructor
+      .inner
+      .params
+      .iter()
+      .map(|param| match param {
+        ParamOrTsParamProp::Param(param) => param_name(&param.pat),
+        ParamOrTsParamProp::TsParamProp(prop) => match &prop.param {
+          TsParamPropParam::Ident(ident) => Some(ident.id.sym.to_string()),
+          TsParamPropParam::Assign(assign) => param_name(&assign.left),
+        },
+      })
+      .collect();
+    check(constructor.start(), params, None, false, ctx);
+  }
+}
+
+fn check_function(node: Node, function: &Function, ctx: &mut Context) {
+  let params = function
+    .params
+    .iter()
+    .map(|param| &param.pat)
+    // TypeScript's `this` parameter isn't passed as an argument.
+    .filter(|pat| !matches!(pat, Pat::Ident(ident) if ident.id.sym == *"this"))
+    .map(param_name)
+    .collect();
+  check(
+    owner_start(node),
+    params,
+    function.return_type.as_deref(),
+    function.is_async,
+    ctx,
+  );
+}
+
+/// Checks the JSDoc comment before `start` against the parameters of a
+/// function. Destructured parameters have no name.
+fn check(
+  start: SourcePos,
+  params: Vec<Option<String>>,
+  return_type: Option<&TsTypeAnn>,
+  is_async: bool,
+  ctx: &mut Context,
+) {
+  let Some(jsdoc) = ctx.leading_jsdoc_at(start) else {
+    return;
+  };
+
+  let mut documented: Vec<&str> = vec![];
+  for tag in &jsdoc.tags {
+    if RETURNS_TAGS.contains(&tag.name.as_str()) {
+      if return_type.map_or(false, |ann| is_void(&ann.type_ann, is_async)) {
+        let kind = DiagnosticKind::ReturnsOnVoid;
+        ctx.add_diagnostic_with_hint(
+          tag.name_range,
+          CODE,
+          kind.message(&tag.name),
+          kind.hint(),
+        );
+      }
+      continue;
+    }
+    if !PARAM_TAGS.contains(&tag.name.as_str()) {
+      continue;
+    }
+    let Some(value_name) = &tag.value_name else {
+      continue;
+    };
+    let range = value_name.range;
+    let name = value_name.text.as_str();
+    let name = name.strip_prefix("...").unwrap_or(name);
+    let is_param = |name: &str| params.iter().flatten().any(|p| p == name);
+
+    let kind = match name.split_once('.') {
+      // A property of a parameter, e.g. `options.verbose`.
+      Some((root, _)) => {
+        if documented.contains(&root) || is_param(root) {
+          continue;
+        }
+        DiagnosticKind::UnknownParam
+      }
+      None if documented.contains(&name) => DiagnosticKind::DuplicateParam,
+      None => {
+        let position = documented.len();
+        documented.push(name);
+        // Destructured parameters can be documented with any name.
+        if is_param(name) || matches!(params.get(position), Some(None)) {
+          continue;
+        }
+        DiagnosticKind::UnknownParam
+      }
+    };
+    ctx.add_diagnostic_with_hint(range, CODE, kind.message(name), kind.hint());
+  }
+}
+
+fn param_name(pat: &Pat) -> Option<String> {
+  match pat {
+    Pat::Ident(ident) => Some(ident.id.sym.to_string()),
+    Pat::Assign(assign) => param_name(&assign.left),
+    Pat::Rest(rest) => param_name(&rest.arg),
+    _ => None,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn jsdoc_param_match_valid() {
+    assert_lint_ok! {
+      JsdocParamMatch,
+      "/** @param a A. */\nfunction foo(a) {}",
+      "/** @param {string} a A. */\nfunction foo(a: string) {}",
+      "/** @param {{ b: string }} a A. */\nfunction foo(a) {}",
+      "/** @param [a] A. */\nfunction foo(a?: string) {}",
+      "/** @param [a=1] A. */\nfunction foo(a = 1) {}",
+      "/** @param ...a A. */\nfunction foo(...a) {}",
+      "/**\n * @param a A.\n * @param a.b B.\n * @param a.c C.\n */\nfunction foo(a) {}",
+      "/** @param a.b B. */\nfunction foo(a) {}",
+      "/**\n * @param b B.\n * @param a A.\n */\nfunction foo(a, b) {}",
+      "/** @param options Options. */\nfunction foo({ a, b }) {}",
+      "/** @param options Options. */\nfunction foo(this: Foo, { a }) {}",
+      "/** @arg a A. */\nconst foo = (a) => {};",
+      "/** @param a A. */\nexport const foo = function (a) {};",
+      "/** @param a A. */\nexport function foo(a) {}",
+      "/** @param a A. */\nexport default function (a) {}",
+      "class A {\n  /** @param a A. */\n  constructor(private a: string) {}\n  /** @param b B. */\n  foo(b) {}\n  /** @param c C. */\n  #bar(c) {}\n}",
+      "const obj = {\n  /** @param a A. */\n  foo(a) {},\n  /** @param b B. */\n  bar: (b) => {},\n};",
+      "/** @returns The value. */\nfunction foo(): string {}",
+      "/** @returns The value. */\nfunction foo() {}",
+      "/** @returns A promise. */\nfunction foo(): Promise<void> {}",
+      "/**\n * Example:\n * ```ts\n * @param b\n * ```\n * @param a A.\n */\nfunction foo(a) {}",
+      "/* @param b B. */\nfunction foo(a) {}",
+      "/** @param b B. */\nconst a = 1;\nfunction foo(a) {}",
+    };
+  }
+
+  #[test]
+  fn jsdoc_param_match_invalid() {
+    let unknown_hint = DiagnosticKind::UnknownParam.hint();
+
+    assert_lint_err! {
+      JsdocParamMatch,
+      "/** @param b B. */\nfunction foo(a) {}": [
+        {
+          col: 11,
+          message: "Documented parameter `b` isn't a parameter of the function",
+          hint: unknown_hint,
+        }
+      ],
+      "/** @param {string} [b=\"x\"] B. */\nfunction foo(a) {}": [
+        {
+          col: 21,
+          message: "Documented parameter `b` isn't a parameter of the function",
+          hint: unknown_hint,
+        }
+      ],
+      "/**\n * @param a A.\n * @param b.c C.\n */\nfunction foo(a) {}": [
+        {
+          line: 3,
+          col: 10,
+          message: "Documented parameter `b.c` isn't a parameter of the function",
+          hint: unknown_hint,
+        }
+      ],
+      "/**\n * @param a A.\n * @param a Again.\n */\nfunction foo(a) {}": [
+        {
+          line: 3,
+          col: 10,
+          message: "Parameter `a` is documented more than once",
+          hint: DiagnosticKind::DuplicateParam.hint(),
+        }
+      ],
+      "/**\n * @param a A.\n * @param options Options.\n */\nfunction foo(a, b) {}": [
+        {
+          line: 3,
+          col: 10,
+          message: "Documented parameter `options` isn't a parameter of the function",
+          hint: unknown_hint,
+        }
+      ],
+      "/** @param b B. */\nexport const foo = (a) => {};": [
+        {
+          col: 11,
+          message: "Documented parameter `b` isn't a parameter of the function",
+          hint: unknown_hint,
+        }
+      ],
+      "class A {\n  /** @param b B. */\n  foo(a) {}\n}": [
+        {
+          line: 2,
+          col: 13,
+          message: "Documented parameter `b` isn't a parameter of the function",
+          hint: unknown_hint,
+        }
+      ],
+      "/** @returns Nothing. */\nfunction foo(): void {}": [
+        {
+          col: 4,
+          message: "`@returns` is documented for a function that returns `void`",
+          hint: DiagnosticKind::ReturnsOnVoid.hint(),
+        }
+      ],
+      "/** @return Nothing. */\nconst foo = async (): Promise<void> => {};": [
+        {
+          col: 4,
+          message: "`@return` is documented for a function that returns `void`",
+          hint: DiagnosticKind::ReturnsOnVoid.hint(),
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,246 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::handler::{Handler, Traverse};
+use crate::jsdoc::{is_void, owner_start};
+use crate::tags::{self, Tags};
+use crate::Program;
+use deno_ast::swc::ast::{
+  ArrowExpr, BlockStmt, BlockStmtOrExpr, Class, Function, MethodKind,
+  ReturnStmt, TsKeywordTypeKind, TsType, TsTypeAnn,
+};
+use deno_ast::swc::visit::{noop_visit_type, Visit, VisitWith};
+use deno_ast::view::{self as ast_view, Node, NodeTrait};
+
+#[derive(Debug)]
+pub struct JsdocRequireReturns;
+
+const CODE: &str = "jsdoc-require-returns";
+const MESSAGE: &str =
+  "JSDoc comment of a function that returns a value is missing `@returns`";
+const HINT: &str = "Add a `@returns` tag that describes the return value";
+
+/// Tags that document the return value, or make documenting it unnecessary.
+const SKIP_TAGS: &[&str] =
+  &["returns", "return", "type", "inheritdoc", "inheritDoc"];
+
+impl LintRule for JsdocRequireReturns {
+  fn tags(&self) -> Tags {
+    &[tags::JSDOC]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Requires a `@returns` tag in the JSDoc comments of \
+        functions that return a value. A function returns a value if its \
+        return type annotation isn't `void`, `undefined` or `never` (or \
+        `Promise<void>` for async functions), or, without an annotation, if \
+        it has a `return` statement with a value or an expression body. \
+        Generators and comments with `@type` or `@inheritdoc` are skipped.",
+      invalid_examples: &[
+        "/**\n * Adds two numbers.\n * @param {number} a\n * @param {number} b\n */\nfunction add(a, b) {\n  return a + b;\n}",
+      ],
+      valid_examples: &[
+        "/**\n * Adds two numbers.\n * @param {number} a\n * @param {number} b\n * @returns {number} The sum.\n */\nfunction add(a, b) {\n  return a + b;\n}",
+        "/** Logs a message. */\nfunction log(message) {\n  console.log(message);\n}",
+      ],
+    }
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    JsdocRequireReturnsHandler.traverse(program, context);
+  }
+}
+
+struct JsdocRequireReturnsHandler;
+
+impl Handler for JsdocRequireReturnsHandler {
+  fn fn_decl(&mut self, fn_decl: &ast_view::FnDecl, ctx: &mut Context) {
+    check_function(fn_decl.as_node(), fn_decl.inner.function.as_ref(), ctx);
+  }
+
+  fn fn_expr(&mut self, fn_expr: &ast_view::FnExpr, ctx: &mut Context) {
+    check_function(fn_expr.as_node(), fn_expr.inner.function.as_ref(), ctx);
+  }
+
+  fn arrow_expr(&mut self, arrow: &ast_view::ArrowExpr, ctx: &mut Context) {
+    let ArrowExpr {
+      body,
+      return_type,
+      is_async,
+      ..
+    } = arrow.inner;
+    let returns_value = match return_type {
+      Some(return_type) => is_value_type(return_type, *is_async),
+      None => match &**body {
+        BlockStmtOrExpr::BlockStmt(block) => has_return_value(block),
+        BlockStmtOrExpr::Expr(_) => true,
+      },
+    };
+    if returns_value {
+      check(arrow.as_node(), ctx);
+    }
+  }
+
+  fn class_method(
+    &mut self,
+    class_method: &ast_view::ClassMethod,
+    ctx: &mut Context,
+  ) {
+    // Setters can't return a value.
+    if class_method.inner.kind == MethodKind::Setter {
+      return;
+    }
+    check_function(
+      class_method.as_node(),
+      class_method.inner.function.as_ref(),
+      ctx,
+    );
+  }
+
+  fn private_method(
+    &mut self,
+    private_method: &ast_view::PrivateMethod,
+    ctx: &mut Context,
+  ) {
+    if private_method.inner.kind == MethodKind::Setter {
+      return;
+    }
+    check_function(
+      private_method.as_node(),
+      private_method.inner.function.as_ref(),
+      ctx,
+    );
+  }
+
+  fn method_prop(
+    &mut self,
+    method_prop: &ast_view::MethodProp,
+    ctx: &mut Context,
+  ) {
+    check_function(
+      method_prop.as_node(),
+      method_prop.inner.function.as_ref(),
+      ctx,
+    );
+  }
+}
+
+fn check_function(node: Node, function: &Function, ctx: &mut Context) {
+  if function.is_generator {
+    return;
+  }
+  let returns_value = match &function.return_type {
+    Some(return_type) => is_value_type(return_type, function.is_async),
+    None => function.body.as_ref().map_or(false, has_return_value),
+  };
+  if returns_value {
+    check(node, ctx);
+  }
+}
+
+/// Reports the JSDoc comment of a function that returns a value if it
+/// doesn't document the return value.
+fn check(node: Node, ctx: &mut Context) {
+  let Some(jsdoc) = ctx.leading_jsdoc_at(owner_start(node)) else {
+    return;
+  };
+  if !jsdoc.has_tag(SKIP_TAGS) {
+    ctx.add_diagnostic_with_hint(jsdoc.range, CODE, MESSAGE, HINT);
+  }
+}
+
+fn is_value_type(return_type: &TsTypeAnn, is_async: bool) -> bool {
+  let ts_type = &*return_type.type_ann;
+  !is_void(ts_type, is_async)
+    && !matches!(
+      ts_type,
+      TsType::TsKeywordType(keyword)
+        if matches!(
+          keyword.kind,
+          TsKeywordTypeKind::TsUndefinedKeyword
+            | TsKeywordTypeKind::TsNeverKeyword
+        )
+    )
+}
+
+/// Checks if a function body has a `return` statement with a value, not
+/// counting the ones of nested functions.
+fn has_return_value(body: &BlockStmt) -> bool {
+  let mut finder = ReturnValueFinder { found: false };
+  body.visit_with(&mut finder);
+  finder.found
+}
+
+struct ReturnValueFinder {
+  found: bool,
+}
+
+impl Visit for ReturnValueFinder {
+  noop_visit_type!();
+
+  fn visit_return_stmt(&mut self, return_stmt: &ReturnStmt) {
+    self.found |= return_stmt.arg.is_some();
+  }
+
+  fn visit_function(&mut self, _: &Function) {}
+
+  fn visit_arrow_expr(&mut self, _: &ArrowExpr) {}
+
+  fn visit_class(&mut self, _: &Class) {}
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn jsdoc_require_returns_valid() {
+    assert_lint_ok! {
+      JsdocRequireReturns,
+      "function add(a, b) { return a + b; }",
+      "// Adds two numbers.\nfunction add(a, b) { return a + b; }",
+      "/** @returns The sum. */\nfunction add(a, b) { return a + b; }",
+      "/** @return The sum. */\nfunction add(a, b) { return a + b; }",
+      "/** Logs. */\nfunction log(a) { console.log(a); }",
+      "/** Logs. */\nfunction log(a) { if (!a) { return; } console.log(a); }",
+      "/** Logs. */\nfunction log(a): void { return console.log(a); }",
+      "/** Logs. */\nasync function log(a): Promise<void> { await write(a); }",
+      "/** Fails. */\nfunction fail(): never { throw new Error(); }",
+      "/** Runs. */\nfunction run() { return; function inner() { return 1; } }",
+      "/** Runs. */\nfunction run() { items.map((item) => { return item.id; }); }",
+      "/** Counts. */\nfunction* count() { yield 1; return 2; }",
+      "/** @type {Add} */\nconst add = (a, b) => a + b;",
+      "class A {\n  /** @inheritdoc */\n  add(a, b) { return a + b; }\n}",
+      "class A {\n  /** Sets. */\n  set value(v) { this.v = v; }\n}",
+      "/** Declared. */\ndeclare function run(): void;",
+    };
+  }
+
+  #[test]
+  fn jsdoc_require_returns_invalid() {
+    assert_lint_err! {
+      JsdocRequireReturns,
+      MESSAGE,
+      HINT,
+      "/** Adds. */\nfunction add(a, b) { return a + b; }": [{ line: 1, col: 0 }],
+      "/** Adds. */\nfunction add(a: number, b: number): number;": [{ col: 0 }],
+      "/** Adds. */\nconst add = (a, b) => a + b;": [{ col: 0 }],
+      "/** Adds. */\nexport const add = function (a, b) { return a + b; };": [{ col: 0 }],
+      "/** Loads. */\nasync function load(): Promise<string> { return await read(); }": [{ col: 0 }],
+      "/** Loads. */\nasync function load() { return read(); }": [{ col: 0 }],
+      "/**\n * @param a The first number.\n */\nfunction add(a, b) { if (a) { return a + b; } }": [{ line: 1, col: 0 }],
+      "class A {\n  /** Gets. */\n  get value() { return this.v; }\n}": [{ line: 2, col: 2 }],
+      "class A {\n  /** Adds. */\n  #add(a, b) { return a + b; }\n}": [{ line: 2, col: 2 }],
+      "const a = {\n  /** Adds. */\n  add(a, b) { return a + b; },\n};": [{ line: 2, col: 2 }],
+    };
+  }
+}
@@ -0,0 +1,481 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::{Handler, Traverse};
+use crate::module_graph::{
+  dynamic_import_attributes, import_attributes, resolve_import,
+};
+use crate::tags::Tags;
+use crate::Program;
+use deno_ast::swc::ast::{Callee, Expr, Lit, ObjectLit, Str};
+use deno_ast::view as ast_view;
+use deno_ast::{
+  ModuleSpecifier, SourceRange, SourceRanged, SourceRangedForSpanned,
+};
+
+#[derive(Debug)]
+pub struct JsonImportAttributes;
+
+const CODE: &str = "json-import-attributes";
+
+impl LintRule for JsonImportAttributes {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Requires imports of JSON modules (`.json` and `.jsonc` \
+        files, and `data:application/json` URLs) to use the \
+        `type: \"json\"` import attribute, and disallows the attribute on \
+        imports of JavaScript and TypeScript modules. Without it, a JSON \
+        module fails to load at runtime. When a whole project is linted, \
+        imports of JSON modules are also checked against the other files: \
+        the imported file has to be part of the project, and all imports of \
+        a JSON module have to use the same import attributes.",
+      invalid_examples: &[
+        "import config from \"./config.json\";",
+        "import mod from \"./mod.ts\" with { type: \"json\" };",
+      ],
+      valid_examples: &[
+        "import config from \"./config.json\" with { type: \"json\" };",
+        "const config = await import(\"./config.json\", {\n  with: { type: \"json\" },\n});",
+      ],
+    }
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    JsonImportAttributesHandler.traverse(program, context);
+  }
+}
+
+enum DiagnosticKind {
+  MissingJsonType,
+  UnexpectedJsonType,
+  NotInProject,
+  InconsistentAttributes { first_referrer: ModuleSpecifier },
+}
+
+impl DiagnosticKind {
+  fn message(&self, specifier: &str) -> String {
+    match self {
+      DiagnosticKind::MissingJsonType => format!(
+        "JSON module `{}` is imported without the `type: \"json\"` import attribute",
+        specifier
+      ),
+      DiagnosticKind::UnexpectedJsonType => format!(
+        "Module `{}` is imported with the `type: \"json\"` import attribute, but it isn't a JSON module",
+        specifier
+      ),
+      DiagnosticKind::NotInProject => format!(
+        "JSON module `{}` doesn't exist in the project",
+        specifier
+      ),
+      DiagnosticKind::InconsistentAttributes { first_referrer } => format!(
+        "JSON module `{}` is imported with other import attributes than in `{}`",
+        specifier, first_referrer
+      ),
+    }
+  }
+
+  fn hint(&self) -> &'static str {
+    match self {
+      DiagnosticKind::MissingJsonType => {
+        "Add `with { type: \"json\" }` to the import"
+      }
+      DiagnosticKind::UnexpectedJsonType => {
+        "Remove the import attribute, or check that the right module is imported"
+      }
+      DiagnosticKind::NotInProject => {
+        "Check the path of the import, or add the file to the project"
+      }
+      DiagnosticKind::InconsistentAttributes { .. } => {
+        "Use the same import attributes in all imports of the module"
+      }
+    }
+  }
+}
+
+const FIX_DESC: &str = "Add the `type: \"json\"` import attribute";
+
+struct JsonImportAttributesHandler;
+
+impl Handler for JsonImportAttributesHandler {
+  fn import_decl(
+    &mut self,
+    import_decl: &ast_view::ImportDecl,
+    ctx: &mut Context,
+  ) {
+    // Type-only imports are erased, so the module isn't loaded.
+    if import_decl.inner.type_only {
+      return;
+    }
+    check(
+      import_decl.range(),
+      &import_decl.inner.src,
+      import_decl.inner.with.as_deref(),
+      ctx,
+    );
+  }
+
+  fn named_export(
+    &mut self,
+    named_export: &ast_view::NamedExport,
+    ctx: &mut Context,
+  ) {
+    if named_export.inner.type_only {
+      return;
+    }
+    if let Some(src) = &named_export.inner.src {
+      check(
+        named_export.range(),
+        src,
+        named_export.inner.with.as_deref(),
+        ctx,
+      );
+    }
+  }
+
+  fn export_all(
+    &mut self,
+    export_all: &ast_view::ExportAll,
+    ctx: &mut Context,
+  ) {
+    if export_all.inner.type_only {
+      return;
+    }
+    check(
+      export_all.range(),
+      &export_all.inner.src,
+      export_all.inner.with.as_deref(),
+      ctx,
+    );
+  }
+
+  fn call_expr(&mut self, call_expr: &ast_view::CallExpr, ctx: &mut Context) {
+    let call_expr = call_expr.inner;
+    if !matches!(call_expr.callee, Callee::Import(_)) {
+      return;
+    }
+    let Some(Expr::Lit(Lit::Str(src))) =
+      call_expr.args.first().map(|arg| &*arg.expr)
+    else {
+      return;
+    };
+    // The attributes are only known if the options are an object literal.
+    let attributes = match call_expr.args.get(1).map(|arg| &*arg.expr) {
+      None => Some(vec![]),
+      Some(Expr::Object(options)) => Some(
+        dynamic_import_attributes(options)
+          .map(import_attributes)
+          .unwrap_or_default(),
+      ),
+      Some(_) => None,
+    };
+    report(call_expr.range(), src, attributes.as_deref(), None, ctx);
+  }
+}
+
+fn check(
+  range: SourceRange,
+  src: &Str,
+  with: Option<&ObjectLit>,
+  ctx: &mut Context,
+) {
+  let attributes = with.map(import_attributes).unwrap_or_default();
+  // Only offer to add the attribute if there are no other attributes.
+  let fix_after = with.is_none().then(|| src.range());
+  report(range, src, Some(&attributes), fix_after, ctx);
+}
+
+fn report(
+  range: SourceRange,
+  src: &Str,
+  attributes: Option<&[(String, String)]>,
+  fix_after: Option<SourceRange>,
+  ctx: &mut Context,
+) {
+  let has_json_type = attributes.map_or(false, has_json_type);
+  let module_kind = module_kind(&src.value);
+  let mut kinds = match (module_kind, has_json_type) {
+    (ModuleKind::Json, false) => vec![DiagnosticKind::MissingJsonType],
+    (ModuleKind::NonJson, true) => vec![DiagnosticKind::UnexpectedJsonType],
+    _ => vec![],
+  };
+  if module_kind == ModuleKind::Json {
+    kinds.extend(check_project(src, attributes, ctx));
+  }
+
+  for kind in kinds {
+    let fixes = match (&kind, fix_after) {
+      (DiagnosticKind::MissingJsonType, Some(src_range)) => vec![LintFix {
+        description: FIX_DESC.into(),
+        changes: vec![LintFixChange {
+          new_text: " with { type: \"json\" }".into(),
+          range: SourceRange::new(src_range.end, src_range.end),
+        }],
+      }],
+      _ => vec![],
+    };
+    ctx.add_diagnostic_with_fixes(
+      range,
+      CODE,
+      kind.message(&src.value),
+      Some(kind.hint().to_string()),
+      fixes,
+    );
+  }
+}
+
+/// Checks an import of a JSON module against the other files of the project,
+/// if the file is linted as part of one.
+fn check_project(
+  src: &Str,
+  attributes: Option<&[(String, String)]>,
+  ctx: &Context,
+) -> Option<DiagnosticKind> {
+  let module_graph = ctx.module_graph()?;
+  let specifier = resolve_import(&src.value, ctx.specifier())?;
+  // Remote modules can't be part of the project.
+  if specifier.scheme() == "file" && !module_graph.contains(&specifier) {
+    return Some(DiagnosticKind::NotInProject);
+  }
+  // Imports without the `type: "json"` attribute are already reported on
+  // their own.
+  let attributes = attributes.filter(|attributes| has_json_type(attributes))?;
+  let first = module_graph
+    .imports_of(&specifier)
+    .find(|import| has_json_type(&import.attributes))?;
+  (first.attributes != attributes).then(|| {
+    DiagnosticKind::InconsistentAttributes {
+      first_referrer: first.referrer.clone(),
+    }
+  })
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ModuleKind {
+  Json,
+  NonJson,
+  /// The kind of the module can't be told from its specifier, e.g. for
+  /// `https://api.example.com/data` or `npm:pkg`.
+  Unknown,
+}
+
+fn module_kind(specifier: &str) -> ModuleKind {
+  if let Some(data) = specifier.strip_prefix("data:") {
+    let mime_type = data
+      .split([',', ';'])
+      .next()
+      .unwrap_or_default()
+      .trim()
+      .to_ascii_lowercase();
+    return match mime_type.as_str() {
+      "application/json" | "text/json" => ModuleKind::Json,
+      mime_type if mime_type.ends_with("+json") => ModuleKind::Json,
+      "application/javascript"
+      | "text/javascript"
+      | "application/typescript"
+      | "text/typescript" => ModuleKind::NonJson,
+      _ => ModuleKind::Unknown,
+    };
+  }
+
+  let path = specifier
+    .split(['?', '#'])
+    .next()
+    .unwrap_or_default()
+    .to_ascii_lowercase();
+  let Some((_, extension)) = path.rsplit_once('.') else {
+    return ModuleKind::Unknown;
+  };
+  match extension {
+    "json" | "jsonc" => ModuleKind::Json,
+    "ts" | "tsx" | "mts" | "cts" | "js" | "jsx" | "mjs" | "cjs" => {
+      ModuleKind::NonJson
+    }
+    _ => ModuleKind::Unknown,
+  }
+}
+
+fn has_json_type(attributes: &[(String, String)]) -> bool {
+  attributes
+    .iter()
+    .any(|(key, value)| key == "type" && value == "json")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn json_import_attributes_valid() {
+    assert_lint_ok! {
+      JsonImportAttributes,
+      r#"import a from "./a.ts";"#,
+      r#"import a from "./a.json" with { type: "json" };"#,
+      r#"import a from "./a.jsonc" with { type: "json" };"#,
+      r#"import a from "./a.JSON?v=1" with { "type": "json" };"#,
+      r#"export { default } from "./a.json" with { type: "json" };"#,
+      r#"export * from "./a.ts";"#,
+      r#"import("./a.json", { with: { type: "json" } });"#,
+      r#"import("./a.ts");"#,
+      r#"import type { A } from "./a.json";"#,
+      r#"import(specifier);"#,
+      r#"import a from "https://example.com/a.ts" with { foo: "bar" };"#,
+      r#"import a from "https://api.example.com/data" with { type: "json" };"#,
+      r#"import a from "https://example.com/v1.2/data" with { type: "json" };"#,
+      r#"import d from "data:application/json,{}" with { type: "json" };"#,
+      r#"import d from "data:application/json;base64,e30=" with { type: "json" };"#,
+      r#"import a from "npm:pkg" with { type: "json" };"#,
+      r#"import a from "https://api.example.com/data";"#,
+    };
+  }
+
+  #[test]
+  fn json_import_attributes_invalid() {
+    let missing_hint = DiagnosticKind::MissingJsonType.hint();
+    let unexpected_hint = DiagnosticKind::UnexpectedJsonType.hint();
+
+    assert_lint_err! {
+      JsonImportAttributes,
+      r#"import a from "./a.json";"#: [
+        {
+          col: 0,
+          message: "JSON module `./a.json` is imported without the `type: \"json\"` import attribute",
+          hint: missing_hint,
+          fix: (FIX_DESC, r#"import a from "./a.json" with { type: "json" };"#),
+        }
+      ],
+      r#"export { default as a } from "./a.jsonc";"#: [
+        {
+          col: 0,
+          message: "JSON module `./a.jsonc` is imported without the `type: \"json\"` import attribute",
+          hint: missing_hint,
+          fix: (FIX_DESC, r#"export { default as a } from "./a.jsonc" with { type: "json" };"#),
+        }
+      ],
+      r#"import a from "./a.json" with { type: "text" };"#: [
+        {
+          col: 0,
+          message: "JSON module `./a.json` is imported without the `type: \"json\"` import attribute",
+          hint: missing_hint,
+        }
+      ],
+      r#"import d from "data:application/json,{}";"#: [
+        {
+          col: 0,
+          message: "JSON module `data:application/json,{}` is imported without the `type: \"json\"` import attribute",
+          hint: missing_hint,
+          fix: (FIX_DESC, r#"import d from "data:application/json,{}" with { type: "json" };"#),
+        }
+      ],
+      r#"import d from "data:text/javascript,export default 1" with { type: "json" };"#: [
+        {
+          col: 0,
+          message: "Module `data:text/javascript,export default 1` is imported with the `type: \"json\"` import attribute, but it isn't a JSON module",
+          hint: unexpected_hint,
+        }
+      ],
+      r#"const a = await import("./a.json");"#: [
+        {
+          col: 16,
+          message: "JSON module `./a.json` is imported without the `type: \"json\"` import attribute",
+          hint: missing_hint,
+        }
+      ],
+      r#"import a from "./a.ts" with { type: "json" };"#: [
+        {
+          col: 0,
+          message: "Module `./a.ts` is imported with the `type: \"json\"` import attribute, but it isn't a JSON module",
+          hint: unexpected_hint,
+        }
+      ],
+      r#"export * from "./a.ts" with { type: "json" };"#: [
+        {
+          col: 0,
+          message: "Module `./a.ts` is imported with the `type: \"json\"` import attribute, but it isn't a JSON module",
+          hint: unexpected_hint,
+        }
+      ],
+      r#"import("./a.ts", { with: { type: "json" } });"#: [
+        {
+          col: 0,
+          message: "Module `./a.ts` is imported with the `type: \"json\"` import attribute, but it isn't a JSON module",
+          hint: unexpected_hint,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn json_import_attributes_project() {
+    let diagnostics = crate::testing::lint_project(
+      Box::new(JsonImportAttributes),
+      &[
+        (
+          "file:///src/a.ts",
+          r#"import config from "../config.json" with { type: "json" };"#,
+        ),
+        (
+          "file:///src/b.ts",
+          r#"import config from "/config.json?v=1" with { foo: "bar", type: "json" };"#,
+        ),
+        (
+          "file:///src/c.ts",
+          r#"const config = await import("../config.json", { with: { type: "json" } });
+import missing from "./missing.json" with { type: "json" };
+import remote from "https://example.com/a.json" with { type: "json" };
+import data from "./data.json" with { type: "json" };"#,
+        ),
+        (
+          "file:///src/data.json.ts",
+          r#"export * from "./data.json" with { type: "json" };"#,
+        ),
+      ],
+      &["file:///config.json", "file:///src/data.json"],
+    );
+    let messages = diagnostics
+      .iter()
+      .map(|diagnostics| {
+        diagnostics
+          .iter()
+          .map(|diagnostic| {
+            let range = diagnostic.range.as_ref().unwrap();
+            let line = range.text_info.line_index(range.range.start) + 1;
+            (line, diagnostic.details.message.clone())
+          })
+          .collect::<Vec<_>>()
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(
+      messages,
+      vec![
+        vec![],
+        vec![(
+          1,
+          "JSON module `/config.json?v=1` is imported with other import attributes than in `file:///src/a.ts`".to_string(),
+        )],
+        vec![(
+          2,
+          "JSON module `./missing.json` doesn't exist in the project"
+            .to_string(),
+        )],
+        vec![],
+      ]
+    );
+  }
+}
@@ -0,0 +1,316 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::handler::{Handler, Traverse};
+use crate::jsx_element::{AttrValue, Element};
+use crate::tags::{self, Tags};
+use crate::Program;
+use deno_ast::view::{CallExpr, JSXOpeningElement};
+
+#[derive(Debug)]
+pub struct JSXAltText;
+
+const CODE: &str = "jsx-alt-text";
+
+impl LintRule for JSXAltText {
+  fn tags(&self) -> Tags {
+    &[tags::A11Y]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description:
+        "Requires alternative text for elements that display images \
+        or act as image buttons (`img`, `area` and `input` with \
+        `type=\"image\"`), so that assistive technologies can describe them. \
+        Purely decorative images should use an empty `alt` attribute.",
+      invalid_examples: &[
+        r#"<img src="cat.png" />"#,
+        r#"<img src="cat.png" alt={undefined} />"#,
+        r#"<input type="image" src="submit.png" />"#,
+        r#"h("img", { src: "cat.png" });"#,
+      ],
+      valid_examples: &[
+        r#"<img src="cat.png" alt="A cat sleeping on a sofa" />"#,
+        r#"<img src="divider.png" alt="" />"#,
+        r#"<input type="image" src="submit.png" alt="Submit" />"#,
+        r#"h("img", { src: "cat.png", alt: "A cat" });"#,
+      ],
+    }
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    JSXAltTextHandler.traverse(program, context);
+  }
+}
+
+enum DiagnosticKind {
+  MissingImgAlt,
+  MissingAreaAlt,
+  MissingInputImageAlt,
+  InvalidAltValue,
+}
+
+impl DiagnosticKind {
+  #[cfg(test)]
+  fn message_and_hint(&self) -> (&'static str, &'static str) {
+    (self.message(), self.hint())
+  }
+
+  fn message(&self) -> &'static str {
+    match *self {
+      DiagnosticKind::MissingImgAlt => {
+        "`img` elements must have an `alt` attribute"
+      }
+      DiagnosticKind::MissingAreaAlt => {
+        "`area` elements must have an `alt`, `aria-label` or `aria-labelledby` attribute"
+      }
+      DiagnosticKind::MissingInputImageAlt => {
+        "`input` elements with `type=\"image\"` must have an `alt`, `aria-label` or `aria-labelledby` attribute"
+      }
+      DiagnosticKind::InvalidAltValue => {
+        "Invalid value for the `alt` attribute"
+      }
+    }
+  }
+
+  fn hint(&self) -> &'static str {
+    match *self {
+      DiagnosticKind::MissingImgAlt | DiagnosticKind::InvalidAltValue => {
+        "Describe the image in the `alt` attribute, or use `alt=\"\"` if the image is purely decorative"
+      }
+      DiagnosticKind::MissingAreaAlt => {
+        "Add an `alt` attribute describing the destination of the link"
+      }
+      DiagnosticKind::MissingInputImageAlt => {
+        "Add an `alt` attribute describing the action of the button"
+      }
+    }
+  }
+}
+
+struct JSXAltTextHandler;
+
+impl Handler for JSXAltTextHandler {
+  fn jsx_opening_element(
+    &mut self,
+    node: &JSXOpeningElement,
+    ctx: &mut Context,
+  ) {
+    if let Some(element) = Element::from_jsx(node) {
+      check_element(&element, ctx);
+    }
+  }
+
+  fn call_expr(&mut self, node: &CallExpr, ctx: &mut Context) {
+    if let Some(element) = Element::from_factory_call(node, ctx) {
+      check_element(&element, ctx);
+    }
+  }
+}
+
+fn check_element(element: &Element, ctx: &mut Context) {
+  let kind = match element.name.as_str() {
+    "img" => DiagnosticKind::MissingImgAlt,
+    "area" => DiagnosticKind::MissingAreaAlt,
+    "input" if is_image_input(element) => DiagnosticKind::MissingInputImageAlt,
+    _ => return,
+  };
+
+  if let Some(alt) = element.attr("alt") {
+    if matches!(alt.value, AttrValue::Empty | AttrValue::Undefined) {
+      let kind = DiagnosticKind::InvalidAltValue;
+      ctx.add_diagnostic_with_hint(
+        alt.range,
+        CODE,
+        kind.message(),
+        kind.hint(),
+      );
+    }
+    return;
+  }
+
+  let has_aria_label = ["aria-label", "aria-labelledby"]
+    .iter()
+    .any(|name| element.attr(name).is_some());
+  if has_aria_label || element.has_spread {
+    return;
+  }
+
+  ctx.add_diagnostic_with_hint(
+    element.name_range,
+    CODE,
+    kind.message(),
+    kind.hint(),
+  );
+}
+
+fn is_image_input(element: &Element) -> bool {
+  matches!(
+    element.attr("type").map(|attr| &attr.value),
+    Some(AttrValue::Str(value)) if value == "image"
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn jsx_alt_text_valid() {
+    assert_lint_ok! {
+      JSXAltText,
+      filename: "file:///foo.jsx",
+      r#"<img alt="A cat" />"#,
+      r#"<img alt="" />"#,
+      r#"<img alt={description} />"#,
+      r#"<img alt={`A cat`} />"#,
+      r#"<img aria-label="A cat" />"#,
+      r#"<img aria-labelledby="caption" />"#,
+      r#"<img {...props} />"#,
+      r#"<Img />"#,
+      r#"<Foo.img />"#,
+      r#"<area alt="Home" />"#,
+      r#"<area aria-label="Home" />"#,
+      r#"<input />"#,
+      r#"<input type="text" />"#,
+      r#"<input type="image" alt="Submit" />"#,
+      r#"<input type="image" aria-labelledby="label" />"#,
+    };
+
+    assert_lint_ok! {
+      JSXAltText,
+      r#"h("img", { alt: "A cat" });"#,
+      r#"h("img", { "alt": "" });"#,
+      r#"h("img", { alt });"#,
+      r#"h("img", { ...props });"#,
+      r#"h("img", props);"#,
+      r#"React.createElement("img", { alt: "A cat" });"#,
+      r#"_jsx("img", { alt: "A cat" });"#,
+      r#"h(Image, {});"#,
+      r#"foo("img", {});"#,
+      r#"h("input", { type: "text" });"#,
+      r#"h("img");"#,
+      r#"document.createElement("img");"#,
+      r#"document.createElement("img", { is: "fancy-img" });"#,
+      r#"foo.createElement("img", {});"#,
+    };
+
+    assert_lint_ok! {
+      JSXAltText,
+      filename: "file:///foo.tsx",
+      r#"/** @jsx el */ el("img", { alt: "A cat" });"#,
+    };
+  }
+
+  #[test]
+  fn jsx_alt_text_invalid() {
+    let (img_message, img_hint) =
+      DiagnosticKind::MissingImgAlt.message_and_hint();
+    let (area_message, area_hint) =
+      DiagnosticKind::MissingAreaAlt.message_and_hint();
+    let (input_message, input_hint) =
+      DiagnosticKind::MissingInputImageAlt.message_and_hint();
+    let (invalid_message, invalid_hint) =
+      DiagnosticKind::InvalidAltValue.message_and_hint();
+
+    assert_lint_err! {
+      JSXAltText,
+      filename: "file:///foo.jsx",
+      r#"<img />"#: [
+        {
+          col: 1,
+          message: img_message,
+          hint: img_hint,
+        }
+      ],
+      r#"<img src="cat.png"></img>"#: [
+        {
+          col: 1,
+          message: img_message,
+          hint: img_hint,
+        }
+      ],
+      r#"<img alt />"#: [
+        {
+          col: 5,
+          message: invalid_message,
+          hint: invalid_hint,
+        }
+      ],
+      r#"<img alt={undefined} />"#: [
+        {
+          col: 5,
+          message: invalid_message,
+          hint: invalid_hint,
+        }
+      ],
+      r#"<area />"#: [
+        {
+          col: 1,
+          message: area_message,
+          hint: area_hint,
+        }
+      ],
+      r#"<input type="image" />"#: [
+        {
+          col: 1,
+          message: input_message,
+          hint: input_hint,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      JSXAltText,
+      filename: "file:///foo.tsx",
+      r#"/** @jsx el */ el("img", {});"#: [
+        {
+          col: 18,
+          message: img_message,
+          hint: img_hint,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      JSXAltText,
+      r#"h("img", { src: "cat.png" });"#: [
+        {
+          col: 2,
+          message: img_message,
+          hint: img_hint,
+        }
+      ],
+      r#"React.createElement("img", null);"#: [
+        {
+          col: 20,
+          message: img_message,
+          hint: img_hint,
+        }
+      ],
+      r#"h("img", { alt: undefined });"#: [
+        {
+          col: 11,
+          message: invalid_message,
+          hint: invalid_hint,
+        }
+      ],
+      r#"h("input", { type: "image" });"#: [
+        {
+          col: 2,
+          message: input_message,
+          hint: input_hint,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,208 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::handler::{Handler, Traverse};
+use crate::jsx_element::{AttrValue, Element};
+use crate::tags::{self, Tags};
+use crate::Program;
+use deno_ast::view::{CallExpr, JSXOpeningElement};
+
+#[derive(Debug)]
+pub struct JSXAnchorIsValid;
+
+const CODE: &str = "jsx-anchor-is-valid";
+
+impl LintRule for JSXAnchorIsValid {
+  fn tags(&self) -> Tags {
+    &[tags::A11Y]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows anchors whose `href` is `#` or a `javascript:` \
+        URL. Such anchors don't navigate anywhere and are usually used as \
+        buttons, which breaks keyboard and screen reader navigation.",
+      invalid_examples: &[
+        r##"<a href="#" onClick={open}>Open</a>"##,
+        r#"<a href="javascript:void(0)">Open</a>"#,
+        r##"h("a", { href: "#" }, "Open");"##,
+      ],
+      valid_examples: &[
+        r#"<a href="/docs">Docs</a>"#,
+        r##"<a href="#installation">Installation</a>"##,
+        r#"<button type="button" onClick={open}>Open</button>"#,
+      ],
+    }
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    JSXAnchorIsValidHandler.traverse(program, context);
+  }
+}
+
+enum DiagnosticKind {
+  HashHref,
+  ScriptHref,
+}
+
+impl DiagnosticKind {
+  #[cfg(test)]
+  fn message_and_hint(&self) -> (&'static str, &'static str) {
+    (self.message(), self.hint())
+  }
+
+  fn message(&self) -> &'static str {
+    match *self {
+      DiagnosticKind::HashHref => "`#` is not a valid `href` for anchors",
+      DiagnosticKind::ScriptHref => {
+        "`javascript:` URLs are not valid `href`s for anchors"
+      }
+    }
+  }
+
+  fn hint(&self) -> &'static str {
+    match *self {
+      DiagnosticKind::HashHref | DiagnosticKind::ScriptHref => {
+        "Link to a valid URL, or use a `button` element if the anchor is used to perform an action"
+      }
+    }
+  }
+}
+
+struct JSXAnchorIsValidHandler;
+
+impl Handler for JSXAnchorIsValidHandler {
+  fn jsx_opening_element(
+    &mut self,
+    node: &JSXOpeningElement,
+    ctx: &mut Context,
+  ) {
+    if let Some(element) = Element::from_jsx(node) {
+      check_element(&element, ctx);
+    }
+  }
+
+  fn call_expr(&mut self, node: &CallExpr, ctx: &mut Context) {
+    if let Some(element) = Element::from_factory_call(node, ctx) {
+      check_element(&element, ctx);
+    }
+  }
+}
+
+fn check_element(element: &Element, ctx: &mut Context) {
+  if element.name != "a" {
+    return;
+  }
+
+  let Some(href) = element.attr("href") else {
+    return;
+  };
+  let AttrValue::Str(value) = &href.value else {
+    return;
+  };
+
+  let value = value.trim();
+  let kind = if value == "#" {
+    DiagnosticKind::HashHref
+  } else if value.to_ascii_lowercase().starts_with("javascript:") {
+    DiagnosticKind::ScriptHref
+  } else {
+    return;
+  };
+
+  ctx.add_diagnostic_with_hint(href.range, CODE, kind.message(), kind.hint());
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn jsx_anchor_is_valid_valid() {
+    assert_lint_ok! {
+      JSXAnchorIsValid,
+      filename: "file:///foo.jsx",
+      r#"<a href="https://deno.com">Deno</a>"#,
+      r#"<a href="/about">About</a>"#,
+      r##"<a href="#section">Section</a>"##,
+      r#"<a href={url}>Link</a>"#,
+      r#"<a {...props}>Link</a>"#,
+      r##"<A href="#">Link</A>"##,
+      r#"<button onClick={onClick}>Action</button>"#,
+    };
+
+    assert_lint_ok! {
+      JSXAnchorIsValid,
+      r#"h("a", { href: "/about" });"#,
+      r#"h("a", { href });"#,
+      r##"h("div", { href: "#" });"##,
+      r##"document.createElement("a", { href: "#" });"##,
+    };
+  }
+
+  #[test]
+  fn jsx_anchor_is_valid_invalid() {
+    let (hash_message, hash_hint) = DiagnosticKind::HashHref.message_and_hint();
+    let (script_message, script_hint) =
+      DiagnosticKind::ScriptHref.message_and_hint();
+
+    assert_lint_err! {
+      JSXAnchorIsValid,
+      filename: "file:///foo.jsx",
+      r##"<a href="#">Link</a>"##: [
+        {
+          col: 3,
+          message: hash_message,
+          hint: hash_hint,
+        }
+      ],
+      r##"<a href={"#"}>Link</a>"##: [
+        {
+          col: 3,
+          message: hash_message,
+          hint: hash_hint,
+        }
+      ],
+      r#"<a href="javascript:void(0)">Link</a>"#: [
+        {
+          col: 3,
+          message: script_message,
+          hint: script_hint,
+        }
+      ],
+      r#"<a href=" JavaScript:alert(1)">Link</a>"#: [
+        {
+          col: 3,
+          message: script_message,
+          hint: script_hint,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      JSXAnchorIsValid,
+      r##"h("a", { href: "#" });"##: [
+        {
+          col: 9,
+          message: hash_message,
+          hint: hash_hint,
+        }
+      ],
+      r#"React.createElement("a", { href: "javascript:;" });"#: [
+        {
+          col: 27,
+          message: script_message,
+          hint: script_hint,
+        }
+      ],
+    };
+  }
+}
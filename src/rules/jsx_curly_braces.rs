@@ -25,6 +25,10 @@ impl LintRule for JSXCurlyBraces {
     CODE
   }
 
+  fn fixable(&self) -> bool {
+    true
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
@@ -22,6 +22,10 @@ impl LintRule for JSXNoUnescapedEntities {
     CODE
   }
 
+  fn fixable(&self) -> bool {
+    true
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
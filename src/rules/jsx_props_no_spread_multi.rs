@@ -24,6 +24,10 @@ impl LintRule for JSXPropsNoSpreadMulti {
     CODE
   }
 
+  fn fixable(&self) -> bool {
+    true
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
@@ -0,0 +1,184 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::code_metrics::max_option_schema;
+use crate::tags::Tags;
+use crate::Program;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct MaxDepth {
+  /// The maximum depth blocks can be nested at within a function.
+  pub max: usize,
+}
+
+impl Default for MaxDepth {
+  fn default() -> Self {
+    Self { max: 4 }
+  }
+}
+
+const CODE: &str = "max-depth";
+const HINT: &str =
+  "Return early, or move the nested blocks into a separate function";
+
+impl LintRule for MaxDepth {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Limits how deeply blocks can be nested within a function. \
+        Each `if`, `switch`, `try`, `with` statement and loop adds a level, \
+        while `else if` stays on the level of its `if`. Nested functions \
+        start over at the first level. Blocks nested deeper than the `max` \
+        option (4 by default) are reported.",
+      invalid_examples: &[
+        "function process(items) {\n  for (const item of items) {\n    if (item.enabled) {\n      try {\n        if (item.value > 0) {\n          if (item.value < 100) {\n            handle(item);\n          }\n        }\n      } catch (err) {\n        console.error(err);\n      }\n    }\n  }\n}",
+      ],
+      valid_examples: &[
+        "function process(items) {\n  for (const item of items) {\n    if (!item.enabled) {\n      continue;\n    }\n    try {\n      if (item.value > 0 && item.value < 100) {\n        handle(item);\n      }\n    } catch (err) {\n      console.error(err);\n    }\n  }\n}",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "max": max_option_schema("The maximum depth blocks can be nested at within a function. Defaults to 4")
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<MaxDepth>(options)?))
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    _program: Program,
+  ) {
+    let reports: Vec<_> = context
+      .code_metrics()
+      .blocks()
+      .iter()
+      .filter(|block| block.depth > self.max)
+      .map(|block| {
+        (
+          block.range,
+          format!(
+            "Blocks are nested too deeply ({}), more than the maximum of {}",
+            block.depth, self.max
+          ),
+        )
+      })
+      .collect();
+    for (range, message) in reports {
+      context.add_diagnostic_with_hint(range, CODE, message, HINT);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v9.9.0/tests/lib/rules/max-depth.js
+  // MIT Licensed.
+
+  #[test]
+  fn max_depth_valid() {
+    assert_lint_ok! {
+      MaxDepth { max: 3 },
+      "function foo() { if (true) { if (false) { if (true) { } } } }",
+      "function foo() { if (true) { } else if (false) { } else if (true) { } else if (false) {} }",
+      "function foo() { if (true) { if (false) { if (true) { function bar() { if (true) {} } } } } }",
+      "var foo = () => { if (true) { if (false) { if (true) { } } } }",
+      "function foo() { if (true) { if (false) { if (true) { } } } if (true) {} }",
+    };
+
+    assert_lint_ok! {
+      MaxDepth { max: 2 },
+      "function foo() { if (true) {} else { for(;;) {} } }",
+    };
+
+    assert_lint_ok! {
+      MaxDepth { max: 0 },
+      "function foo() { }",
+      "function foo() { { { } } }",
+    };
+  }
+
+  #[test]
+  fn max_depth_invalid() {
+    assert_lint_err! {
+      MaxDepth { max: 2 },
+      "function foo() { if (true) { if (false) { if (true) { } } } }": [
+        {
+          col: 42,
+          message: "Blocks are nested too deeply (3), more than the maximum of 2",
+          hint: HINT,
+        }
+      ],
+      "var foo = () => { if (true) { if (false) { if (true) { } } } }": [
+        {
+          col: 43,
+          message: "Blocks are nested too deeply (3), more than the maximum of 2",
+          hint: HINT,
+        }
+      ],
+      "function foo() { while (true) { if (true) { switch (a) {} } } }": [
+        {
+          col: 44,
+          message: "Blocks are nested too deeply (3), more than the maximum of 2",
+          hint: HINT,
+        }
+      ],
+      "function foo() { for (a of b) { try { switch (c) {} } catch {} } }": [
+        {
+          col: 38,
+          message: "Blocks are nested too deeply (3), more than the maximum of 2",
+          hint: HINT,
+        }
+      ],
+      "function foo() { do { for (a in b) { if (c) {} } } while (d); }": [
+        {
+          col: 37,
+          message: "Blocks are nested too deeply (3), more than the maximum of 2",
+          hint: HINT,
+        }
+      ],
+      "if (a) { if (b) { if (c) {} } }": [
+        {
+          col: 18,
+          message: "Blocks are nested too deeply (3), more than the maximum of 2",
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      MaxDepth { max: 1 },
+      "function foo() { if (a) { if (b) {} else if (c) {} } }": [
+        {
+          col: 26,
+          message: "Blocks are nested too deeply (2), more than the maximum of 1",
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,294 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::code_metrics::{max_option_schema, FunctionMetrics};
+use crate::tags::Tags;
+use crate::Program;
+use deno_ast::{SourcePos, SourceRange, SourceRanged, SourceRangedForSpanned};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct MaxLinesPerFunction {
+  /// The maximum number of lines of a function.
+  pub max: usize,
+  /// Doesn't count lines that only contain whitespace.
+  pub skip_blank_lines: bool,
+  /// Doesn't count lines that only contain comments.
+  pub skip_comments: bool,
+  /// Also checks immediately invoked function expressions.
+  pub iifes: bool,
+}
+
+impl Default for MaxLinesPerFunction {
+  fn default() -> Self {
+    Self {
+      max: 50,
+      skip_blank_lines: false,
+      skip_comments: false,
+      iifes: false,
+    }
+  }
+}
+
+const CODE: &str = "max-lines-per-function";
+const HINT: &str = "Split the function into smaller functions";
+
+impl LintRule for MaxLinesPerFunction {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Limits the number of lines of functions, counted from \
+        the line the function starts on to the line it ends on. Functions \
+        with more lines than the `max` option (50 by default) are reported. \
+        Blank lines and lines with only comments are counted unless the \
+        `skipBlankLines` and `skipComments` options are set, and immediately \
+        invoked function expressions are only checked with the `iifes` \
+        option.",
+      invalid_examples: &[
+        "function statusText(status) {\n  switch (status) {\n    case 100: return \"Continue\";\n    case 101: return \"Switching Protocols\";\n    case 102: return \"Processing\";\n    case 103: return \"Early Hints\";\n    case 200: return \"OK\";\n    case 201: return \"Created\";\n    case 202: return \"Accepted\";\n    case 203: return \"Non-Authoritative Information\";\n    case 204: return \"No Content\";\n    case 205: return \"Reset Content\";\n    case 206: return \"Partial Content\";\n    case 207: return \"Multi-Status\";\n    case 208: return \"Already Reported\";\n    case 226: return \"IM Used\";\n    case 300: return \"Multiple Choices\";\n    case 301: return \"Moved Permanently\";\n    case 302: return \"Found\";\n    case 303: return \"See Other\";\n    case 304: return \"Not Modified\";\n    case 305: return \"Use Proxy\";\n    case 307: return \"Temporary Redirect\";\n    case 308: return \"Permanent Redirect\";\n    case 400: return \"Bad Request\";\n    case 401: return \"Unauthorized\";\n    case 402: return \"Payment Required\";\n    case 403: return \"Forbidden\";\n    case 404: return \"Not Found\";\n    case 405: return \"Method Not Allowed\";\n    case 406: return \"Not Acceptable\";\n    case 407: return \"Proxy Authentication Required\";\n    case 408: return \"Request Timeout\";\n    case 409: return \"Conflict\";\n    case 410: return \"Gone\";\n    case 411: return \"Length Required\";\n    case 412: return \"Precondition Failed\";\n    case 413: return \"Content Too Large\";\n    case 414: return \"URI Too Long\";\n    case 415: return \"Unsupported Media Type\";\n    case 416: return \"Range Not Satisfiable\";\n    case 417: return \"Expectation Failed\";\n    case 418: return \"I'm a teapot\";\n    case 421: return \"Misdirected Request\";\n    case 422: return \"Unprocessable Content\";\n    case 423: return \"Locked\";\n    case 424: return \"Failed Dependency\";\n    case 425: return \"Too Early\";\n    default: return \"Unknown\";\n  }\n}",
+      ],
+      valid_examples: &[
+        "const STATUS_TEXT = new Map([\n  [200, \"OK\"],\n  [404, \"Not Found\"],\n  [500, \"Internal Server Error\"],\n]);\n\nfunction statusText(status) {\n  return STATUS_TEXT.get(status) ?? \"Unknown\";\n}",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "max": max_option_schema("The maximum number of lines of a function. Defaults to 50"),
+        "skipBlankLines": {
+          "description": "Don't count lines that only contain whitespace",
+          "type": "boolean"
+        },
+        "skipComments": {
+          "description": "Don't count lines that only contain comments",
+          "type": "boolean"
+        },
+        "iifes": {
+          "description": "Check immediately invoked function expressions too",
+          "type": "boolean"
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<MaxLinesPerFunction>(
+      options,
+    )?))
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    _program: Program,
+  ) {
+    let reports: Vec<_> = context
+      .code_metrics()
+      .functions()
+      .iter()
+      .filter(|function| self.iifes || !function.is_iife)
+      .filter_map(|function| {
+        let lines = self.count_lines(function, context);
+        (lines > self.max).then(|| {
+          (
+            function.head_range,
+            format!(
+              "Too many lines in {} ({}), more than the maximum of {}",
+              function.name, lines, self.max
+            ),
+          )
+        })
+      })
+      .collect();
+    for (range, message) in reports {
+      context.add_diagnostic_with_hint(range, CODE, message, HINT);
+    }
+  }
+}
+
+impl MaxLinesPerFunction {
+  fn count_lines(&self, function: &FunctionMetrics, ctx: &Context) -> usize {
+    let text_info = ctx.text_info();
+    let comments: Vec<SourceRange> = if self.skip_comments {
+      ctx
+        .comment_lookups()
+        .comments_in_range(function.range)
+        .map(|comment| comment.range())
+        .collect()
+    } else {
+      vec![]
+    };
+    let in_comment = |pos: SourcePos| {
+      comments
+        .iter()
+        .any(|comment| comment.start <= pos && pos < comment.end)
+    };
+
+    let first_line = text_info.line_index(function.range.start);
+    let last_line = text_info.line_index(function.range.end);
+    (first_line..=last_line)
+      .filter(|&line_index| {
+        let line = SourceRange::new(
+          text_info.line_start(line_index),
+          text_info.line_end(line_index),
+        );
+        let text = line.text_fast(text_info);
+        if text.trim().is_empty() {
+          return !self.skip_blank_lines && !in_comment(line.start);
+        }
+        !self.skip_comments
+          || !text.char_indices().all(|(offset, c)| {
+            c.is_whitespace() || in_comment(line.start + offset)
+          })
+      })
+      .count()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v9.9.0/tests/lib/rules/max-lines-per-function.js
+  // MIT Licensed.
+
+  #[test]
+  fn max_lines_per_function_valid() {
+    assert_lint_ok! {
+      MaxLinesPerFunction { max: 1, ..Default::default() },
+      "var x = 5;\nvar x = 2;\n",
+      "function name() {}",
+      "const a = () => 1;",
+      "(function() {\n  a();\n})();",
+      "(() => {\n  a();\n})();",
+    };
+
+    assert_lint_ok! {
+      MaxLinesPerFunction { max: 3, ..Default::default() },
+      "function name() {\n  var x = 5;\n}",
+      "class A {\n  b() {\n    c();\n  }\n}",
+    };
+
+    assert_lint_ok! {
+      MaxLinesPerFunction {
+        max: 3,
+        skip_blank_lines: true,
+        ..Default::default()
+      },
+      "function name() {\n  var x = 5;\n\n  \n}",
+    };
+
+    assert_lint_ok! {
+      MaxLinesPerFunction {
+        max: 3,
+        skip_comments: true,
+        ..Default::default()
+      },
+      "function name() {\n  // a comment\n  var x = 5; // another\n  /* a\n\n  block */\n}",
+    };
+  }
+
+  #[test]
+  fn max_lines_per_function_invalid() {
+    assert_lint_err! {
+      MaxLinesPerFunction { max: 2, ..Default::default() },
+      "function name() {\n  var x = 5;\n}": [
+        {
+          col: 0,
+          message: "Too many lines in function `name` (3), more than the maximum of 2",
+          hint: HINT,
+        }
+      ],
+      "function name() {\n  var x = 5;\n\n}": [
+        {
+          col: 0,
+          message: "Too many lines in function `name` (4), more than the maximum of 2",
+          hint: HINT,
+        }
+      ],
+      "const a = () => {\n  b();\n};": [
+        {
+          col: 10,
+          message: "Too many lines in arrow function `a` (3), more than the maximum of 2",
+          hint: HINT,
+        }
+      ],
+      "class A {\n  b() {\n    c();\n  }\n}": [
+        {
+          line: 2,
+          col: 2,
+          message: "Too many lines in method `b` (3), more than the maximum of 2",
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      MaxLinesPerFunction {
+        max: 2,
+        skip_blank_lines: true,
+        ..Default::default()
+      },
+      "function name() {\n\n  // a\n}": [
+        {
+          col: 0,
+          message: "Too many lines in function `name` (3), more than the maximum of 2",
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      MaxLinesPerFunction {
+        max: 2,
+        skip_comments: true,
+        ..Default::default()
+      },
+      "function name() {\n  a(); // a\n}": [
+        {
+          col: 0,
+          message: "Too many lines in function `name` (3), more than the maximum of 2",
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      MaxLinesPerFunction {
+        max: 2,
+        iifes: true,
+        ..Default::default()
+      },
+      "(function() {\n  a();\n})();": [
+        {
+          col: 1,
+          message: "Too many lines in function (3), more than the maximum of 2",
+          hint: HINT,
+        }
+      ],
+      "(() => {\n  a();\n})();": [
+        {
+          col: 1,
+          message: "Too many lines in arrow function (3), more than the maximum of 2",
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,166 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::code_metrics::max_option_schema;
+use crate::tags::Tags;
+use crate::Program;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct MaxNestedCallbacks {
+  /// The maximum depth callbacks can be nested at.
+  pub max: usize,
+}
+
+impl Default for MaxNestedCallbacks {
+  fn default() -> Self {
+    Self { max: 10 }
+  }
+}
+
+const CODE: &str = "max-nested-callbacks";
+const HINT: &str =
+  "Move the callbacks into named functions, or use promises with `async` and `await`";
+
+impl LintRule for MaxNestedCallbacks {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Limits how deeply callbacks can be nested. A callback is \
+        a function or arrow function passed directly as an argument of a \
+        call. Callbacks nested deeper than the `max` option (10 by default) \
+        are reported.",
+      invalid_examples: &[
+        "a(() => {\n  b(() => {\n    c(() => {\n      d(() => {\n        e(() => {\n          f(() => {\n            g(() => {\n              h(() => {\n                i(() => {\n                  j(() => {\n                    k(() => {});\n                  });\n                });\n              });\n            });\n          });\n        });\n      });\n    });\n  });\n});",
+      ],
+      valid_examples: &[
+        "const data = await readConfig();\nconst user = await fetchUser(data.userId);\nawait Promise.all(user.posts.map((post) => render(post)));",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "max": max_option_schema("The maximum depth callbacks can be nested at. Defaults to 10")
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<MaxNestedCallbacks>(
+      options,
+    )?))
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    _program: Program,
+  ) {
+    let reports: Vec<_> = context
+      .code_metrics()
+      .callbacks()
+      .iter()
+      .filter(|callback| callback.depth > self.max)
+      .map(|callback| {
+        (
+          callback.range,
+          format!(
+            "Callbacks are nested too deeply ({}), more than the maximum of {}",
+            callback.depth, self.max
+          ),
+        )
+      })
+      .collect();
+    for (range, message) in reports {
+      context.add_diagnostic_with_hint(range, CODE, message, HINT);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v9.9.0/tests/lib/rules/max-nested-callbacks.js
+  // MIT Licensed.
+
+  #[test]
+  fn max_nested_callbacks_valid() {
+    assert_lint_ok! {
+      MaxNestedCallbacks { max: 3 },
+      "foo(function() { bar(thing, function(data) {}); });",
+      "var foo = function() {}; bar(function(){ baz(function() { qux(foo); }) });",
+      "fn(function(){}, function(){}, function(){});",
+      "fn(() => {}, function(){}, function(){});",
+      "foo(function() { bar(function() { baz(function() {}); }); });",
+      "foo(() => { const a = function() { bar(() => { baz(() => {}); }); }; });",
+    };
+
+    assert_lint_ok! {
+      MaxNestedCallbacks { max: 1 },
+      "foo(function() { const a = () => { (() => {})(); }; });",
+      "foo(function() { return [1].map; });",
+      "foo(...[() => { bar(() => {}); }]);",
+    };
+  }
+
+  #[test]
+  fn max_nested_callbacks_invalid() {
+    assert_lint_err! {
+      MaxNestedCallbacks { max: 2 },
+      "foo(function() { bar(thing, function(data) { baz(function() {}); }); });": [
+        {
+          col: 49,
+          message: "Callbacks are nested too deeply (3), more than the maximum of 2",
+          hint: HINT,
+        }
+      ],
+      "foo(() => { bar(thing, (data) => { baz(() => {}); }); });": [
+        {
+          col: 39,
+          message: "Callbacks are nested too deeply (3), more than the maximum of 2",
+          hint: HINT,
+        }
+      ],
+      "foo(() => { function a() { bar(() => { baz((() => {})); }); } });": [
+        {
+          col: 44,
+          message: "Callbacks are nested too deeply (3), more than the maximum of 2",
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      MaxNestedCallbacks { max: 0 },
+      "foo(function() {}, () => {});": [
+        {
+          col: 4,
+          message: "Callbacks are nested too deeply (1), more than the maximum of 0",
+          hint: HINT,
+        },
+        {
+          col: 19,
+          message: "Callbacks are nested too deeply (1), more than the maximum of 0",
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
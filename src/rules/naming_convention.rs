@@ -0,0 +1,833 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::handler::{Handler, Traverse};
+use crate::tags::Tags;
+use crate::Program;
+use deno_ast::swc::ast::{Expr, PropName, TsEnumMemberId};
+use deno_ast::view::{self as ast_view, NodeKind, NodeTrait};
+use deno_ast::{
+  BindingKind, SourceRange, SourceRanged, SourceRangedForSpanned,
+};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct NamingConvention {
+  /// Minimum length of all checked identifiers, unless overridden by the
+  /// matching convention.
+  pub min_length: Option<usize>,
+  /// Maximum length of all checked identifiers, unless overridden by the
+  /// matching convention.
+  pub max_length: Option<usize>,
+  /// Names that are never reported, e.g. `i` or `_`.
+  pub exceptions: Vec<String>,
+  /// The conventions to enforce. For each identifier the most specific
+  /// matching selector is used; if several conventions have the same
+  /// selector, the first one wins.
+  pub conventions: Vec<Convention>,
+}
+
+impl Default for NamingConvention {
+  fn default() -> Self {
+    Self {
+      min_length: None,
+      max_length: None,
+      exceptions: vec![],
+      conventions: vec![
+        Convention {
+          selector: Selector::TypeLike,
+          format: vec![Format::PascalCase],
+          ..Default::default()
+        },
+        Convention {
+          selector: Selector::VariableLike,
+          format: vec![
+            Format::CamelCase,
+            Format::PascalCase,
+            Format::UpperCase,
+          ],
+          ..Default::default()
+        },
+      ],
+    }
+  }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct Convention {
+  /// The kind of identifiers this convention applies to.
+  pub selector: Selector,
+  /// Allowed formats of the name. Leading and trailing underscores as well
+  /// as the prefix and suffix are removed before checking the format.
+  pub format: Vec<Format>,
+  /// If not empty, the name must start with one of these prefixes.
+  pub prefix: Vec<String>,
+  /// If not empty, the name must end with one of these suffixes.
+  pub suffix: Vec<String>,
+  /// Overrides the global minimum length.
+  pub min_length: Option<usize>,
+  /// Overrides the global maximum length.
+  pub max_length: Option<usize>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Selector {
+  #[default]
+  Default,
+  VariableLike,
+  Variable,
+  Function,
+  Parameter,
+  MemberLike,
+  Property,
+  Method,
+  EnumMember,
+  TypeLike,
+  Class,
+  Interface,
+  TypeAlias,
+  Enum,
+  TypeParameter,
+}
+
+impl Selector {
+  /// Checks if this selector applies to identifiers of the given kind, which
+  /// is always one of the individual selectors.
+  fn matches(self, kind: Selector) -> bool {
+    use Selector::*;
+    match self {
+      Default => true,
+      VariableLike => matches!(kind, Variable | Function | Parameter),
+      MemberLike => matches!(kind, Property | Method | EnumMember),
+      TypeLike => {
+        matches!(kind, Class | Interface | TypeAlias | Enum | TypeParameter)
+      }
+      _ => self == kind,
+    }
+  }
+
+  fn specificity(self) -> u8 {
+    match self {
+      Selector::Default => 0,
+      Selector::VariableLike | Selector::MemberLike | Selector::TypeLike => 1,
+      _ => 2,
+    }
+  }
+
+  fn name(self) -> &'static str {
+    match self {
+      Selector::Default => "Identifier",
+      Selector::VariableLike | Selector::Variable => "Variable",
+      Selector::Function => "Function",
+      Selector::Parameter => "Parameter",
+      Selector::MemberLike | Selector::Property => "Property",
+      Selector::Method => "Method",
+      Selector::EnumMember => "Enum member",
+      Selector::TypeLike => "Type",
+      Selector::Class => "Class",
+      Selector::Interface => "Interface",
+      Selector::TypeAlias => "Type alias",
+      Selector::Enum => "Enum",
+      Selector::TypeParameter => "Type parameter",
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum Format {
+  #[serde(rename = "camelCase")]
+  CamelCase,
+  #[serde(rename = "PascalCase")]
+  PascalCase,
+  #[serde(rename = "UPPER_CASE")]
+  UpperCase,
+  #[serde(rename = "snake_case")]
+  SnakeCase,
+}
+
+impl Format {
+  fn name(self) -> &'static str {
+    match self {
+      Format::CamelCase => "camelCase",
+      Format::PascalCase => "PascalCase",
+      Format::UpperCase => "UPPER_CASE",
+      Format::SnakeCase => "snake_case",
+    }
+  }
+
+  fn matches(self, name: &str) -> bool {
+    match self {
+      Format::CamelCase => {
+        name.starts_with(char::is_lowercase) && !name.contains('_')
+      }
+      Format::PascalCase => {
+        name.starts_with(char::is_uppercase) && !name.contains('_')
+      }
+      Format::UpperCase => !name.chars().any(char::is_lowercase),
+      Format::SnakeCase => !name.chars().any(char::is_uppercase),
+    }
+  }
+}
+
+const CODE: &str = "naming-convention";
+
+impl LintRule for NamingConvention {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Enforces naming conventions for identifiers: their \
+        length, format (`camelCase`, `PascalCase`, `UPPER_CASE` or \
+        `snake_case`), and required prefixes or suffixes. Conventions are \
+        selected by the kind of the identifier, e.g. `variable`, \
+        `parameter`, `property` or `interface`, or by groups of kinds like \
+        `variableLike`, `memberLike` and `typeLike`. By default types must \
+        be in `PascalCase`, and variables, functions and parameters in \
+        `camelCase`, `PascalCase` or `UPPER_CASE`.",
+      invalid_examples: &[
+        "interface user_info {\n  name: string;\n}",
+        "const user_name = \"Ichigo\";",
+      ],
+      valid_examples: &[
+        "interface UserInfo {\n  name: string;\n}",
+        "const userName = \"Ichigo\";",
+        "const MAX_USERS = 100;",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    let selectors = serde_json::json!([
+      "default",
+      "variableLike",
+      "variable",
+      "function",
+      "parameter",
+      "memberLike",
+      "property",
+      "method",
+      "enumMember",
+      "typeLike",
+      "class",
+      "interface",
+      "typeAlias",
+      "enum",
+      "typeParameter"
+    ]);
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "minLength": {
+          "description": "Minimum length of identifiers",
+          "type": "integer",
+          "minimum": 0
+        },
+        "maxLength": {
+          "description": "Maximum length of identifiers",
+          "type": "integer",
+          "minimum": 0
+        },
+        "exceptions": {
+          "description": "Names that are never reported",
+          "type": "array",
+          "items": { "type": "string" }
+        },
+        "conventions": {
+          "description": "The conventions to enforce. The most specific matching selector is used for each identifier",
+          "type": "array",
+          "items": {
+            "type": "object",
+            "properties": {
+              "selector": { "enum": selectors },
+              "format": {
+                "type": "array",
+                "items": {
+                  "enum": ["camelCase", "PascalCase", "UPPER_CASE", "snake_case"]
+                }
+              },
+              "prefix": { "type": "array", "items": { "type": "string" } },
+              "suffix": { "type": "array", "items": { "type": "string" } },
+              "minLength": { "type": "integer", "minimum": 0 },
+              "maxLength": { "type": "integer", "minimum": 0 }
+            },
+            "additionalProperties": false
+          }
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<NamingConvention>(
+      options,
+    )?))
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    NamingConventionHandler { rule: self }.traverse(program, context);
+  }
+}
+
+impl NamingConvention {
+  fn convention_for(&self, kind: Selector) -> Option<&Convention> {
+    self
+      .conventions
+      .iter()
+      .filter(|convention| convention.selector.matches(kind))
+      .fold(None, |best: Option<&Convention>, convention| match best {
+        Some(best)
+          if best.selector.specificity()
+            >= convention.selector.specificity() =>
+        {
+          Some(best)
+        }
+        _ => Some(convention),
+      })
+  }
+
+  fn check(&self, kind: Selector, name: &str) -> Option<DiagnosticKind> {
+    if self.exceptions.iter().any(|exception| exception == name) {
+      return None;
+    }
+    let convention = self.convention_for(kind);
+
+    let length = name.chars().count();
+    let min_length = convention
+      .and_then(|convention| convention.min_length)
+      .or(self.min_length);
+    if let Some(min) = min_length.filter(|min| length < *min) {
+      return Some(DiagnosticKind::TooShort(min));
+    }
+    let max_length = convention
+      .and_then(|convention| convention.max_length)
+      .or(self.max_length);
+    if let Some(max) = max_length.filter(|max| length > *max) {
+      return Some(DiagnosticKind::TooLong(max));
+    }
+
+    let convention = convention?;
+    let mut rest = name.trim_matches('_');
+    if !convention.prefix.is_empty() {
+      match convention
+        .prefix
+        .iter()
+        .find_map(|prefix| rest.strip_prefix(prefix.as_str()))
+      {
+        Some(stripped) => rest = stripped,
+        None => return Some(DiagnosticKind::MissingPrefix(&convention.prefix)),
+      }
+    }
+    if !convention.suffix.is_empty() {
+      match convention
+        .suffix
+        .iter()
+        .find_map(|suffix| rest.strip_suffix(suffix.as_str()))
+      {
+        Some(stripped) => rest = stripped,
+        None => return Some(DiagnosticKind::MissingSuffix(&convention.suffix)),
+      }
+    }
+    if !convention.format.is_empty()
+      && !convention.format.iter().any(|format| format.matches(rest))
+    {
+      return Some(DiagnosticKind::WrongFormat(&convention.format));
+    }
+    None
+  }
+}
+
+enum DiagnosticKind<'a> {
+  TooShort(usize),
+  TooLong(usize),
+  MissingPrefix(&'a [String]),
+  MissingSuffix(&'a [String]),
+  WrongFormat(&'a [Format]),
+}
+
+impl DiagnosticKind<'_> {
+  fn message(&self, kind: Selector, name: &str) -> String {
+    match self {
+      DiagnosticKind::TooShort(min) => {
+        format!("{} name `{}` is too short (< {})", kind.name(), name, min)
+      }
+      DiagnosticKind::TooLong(max) => {
+        format!("{} name `{}` is too long (> {})", kind.name(), name, max)
+      }
+      DiagnosticKind::MissingPrefix(prefixes) => format!(
+        "{} name `{}` must start with one of: {}",
+        kind.name(),
+        name,
+        quote_list(prefixes.iter().map(String::as_str))
+      ),
+      DiagnosticKind::MissingSuffix(suffixes) => format!(
+        "{} name `{}` must end with one of: {}",
+        kind.name(),
+        name,
+        quote_list(suffixes.iter().map(String::as_str))
+      ),
+      DiagnosticKind::WrongFormat(formats) => format!(
+        "{} name `{}` must be in {}",
+        kind.name(),
+        name,
+        formats
+          .iter()
+          .map(|format| format.name())
+          .collect::<Vec<_>>()
+          .join(" or ")
+      ),
+    }
+  }
+
+  fn hint(&self, name: &str) -> String {
+    match self {
+      DiagnosticKind::TooShort(min) => format!(
+        "Use a more descriptive name with at least {} characters",
+        min
+      ),
+      DiagnosticKind::TooLong(max) => {
+        format!("Use a shorter name with at most {} characters", max)
+      }
+      DiagnosticKind::MissingPrefix(prefixes) => {
+        format!("Rename it to `{}{}`", prefixes[0], name)
+      }
+      DiagnosticKind::MissingSuffix(suffixes) => {
+        format!("Rename it to `{}{}`", name, suffixes[0])
+      }
+      DiagnosticKind::WrongFormat(formats) => {
+        format!("Rename it to use {}", formats[0].name())
+      }
+    }
+  }
+}
+
+fn quote_list<'a>(items: impl Iterator<Item = &'a str>) -> String {
+  items
+    .map(|item| format!("`{}`", item))
+    .collect::<Vec<_>>()
+    .join(", ")
+}
+
+struct NamingConventionHandler<'a> {
+  rule: &'a NamingConvention,
+}
+
+impl<'a> NamingConventionHandler<'a> {
+  fn check(
+    &self,
+    kind: Selector,
+    name: &str,
+    range: SourceRange,
+    ctx: &mut Context,
+  ) {
+    if let Some(diagnostic) = self.rule.check(kind, name) {
+      ctx.add_diagnostic_with_hint(
+        range,
+        CODE,
+        diagnostic.message(kind, name),
+        diagnostic.hint(name),
+      );
+    }
+  }
+
+  fn check_prop_name(&self, kind: Selector, key: &PropName, ctx: &mut Context) {
+    if let PropName::Ident(ident) = key {
+      self.check(kind, &ident.sym, ident.range(), ctx);
+    }
+  }
+
+  fn check_key_expr(
+    &self,
+    kind: Selector,
+    key: &Expr,
+    computed: bool,
+    ctx: &mut Context,
+  ) {
+    if let (Expr::Ident(ident), false) = (key, computed) {
+      self.check(kind, &ident.sym, ident.range(), ctx);
+    }
+  }
+}
+
+impl<'a> Handler for NamingConventionHandler<'a> {
+  fn binding_ident(
+    &mut self,
+    binding_ident: &ast_view::BindingIdent,
+    ctx: &mut Context,
+  ) {
+    let ident = binding_ident.id;
+    if &**ident.sym() == "this" || !is_declaration(binding_ident.as_node()) {
+      return;
+    }
+    // The scope analysis tells apart variables and parameters, including the
+    // ones bound by destructuring patterns.
+    let kind = match ctx.scope().var(&ident.inner.to_id()).map(|v| v.kind()) {
+      Some(
+        BindingKind::Var
+        | BindingKind::Let
+        | BindingKind::Const
+        | BindingKind::CatchClause,
+      ) => Selector::Variable,
+      Some(BindingKind::Param) => Selector::Parameter,
+      _ => return,
+    };
+    self.check(kind, ident.sym(), ident.range(), ctx);
+  }
+
+  fn fn_decl(&mut self, fn_decl: &ast_view::FnDecl, ctx: &mut Context) {
+    let ident = fn_decl.ident;
+    self.check(Selector::Function, ident.sym(), ident.range(), ctx);
+  }
+
+  fn class_decl(
+    &mut self,
+    class_decl: &ast_view::ClassDecl,
+    ctx: &mut Context,
+  ) {
+    let ident = class_decl.ident;
+    self.check(Selector::Class, ident.sym(), ident.range(), ctx);
+  }
+
+  fn ts_interface_decl(
+    &mut self,
+    interface_decl: &ast_view::TsInterfaceDecl,
+    ctx: &mut Context,
+  ) {
+    let ident = interface_decl.id;
+    self.check(Selector::Interface, ident.sym(), ident.range(), ctx);
+  }
+
+  fn ts_type_alias_decl(
+    &mut self,
+    type_alias_decl: &ast_view::TsTypeAliasDecl,
+    ctx: &mut Context,
+  ) {
+    let ident = type_alias_decl.id;
+    self.check(Selector::TypeAlias, ident.sym(), ident.range(), ctx);
+  }
+
+  fn ts_enum_decl(
+    &mut self,
+    enum_decl: &ast_view::TsEnumDecl,
+    ctx: &mut Context,
+  ) {
+    let ident = enum_decl.id;
+    self.check(Selector::Enum, ident.sym(), ident.range(), ctx);
+  }
+
+  fn ts_enum_member(
+    &mut self,
+    enum_member: &ast_view::TsEnumMember,
+    ctx: &mut Context,
+  ) {
+    if let TsEnumMemberId::Ident(ident) = &enum_member.inner.id {
+      self.check(Selector::EnumMember, &ident.sym, ident.range(), ctx);
+    }
+  }
+
+  fn ts_type_param(
+    &mut self,
+    type_param: &ast_view::TsTypeParam,
+    ctx: &mut Context,
+  ) {
+    let ident = type_param.name;
+    self.check(Selector::TypeParameter, ident.sym(), ident.range(), ctx);
+  }
+
+  fn class_prop(
+    &mut self,
+    class_prop: &ast_view::ClassProp,
+    ctx: &mut Context,
+  ) {
+    self.check_prop_name(Selector::Property, &class_prop.inner.key, ctx);
+  }
+
+  fn class_method(
+    &mut self,
+    class_method: &ast_view::ClassMethod,
+    ctx: &mut Context,
+  ) {
+    self.check_prop_name(Selector::Method, &class_method.inner.key, ctx);
+  }
+
+  fn key_value_prop(
+    &mut self,
+    key_value_prop: &ast_view::KeyValueProp,
+    ctx: &mut Context,
+  ) {
+    self.check_prop_name(Selector::Property, &key_value_prop.inner.key, ctx);
+  }
+
+  fn method_prop(
+    &mut self,
+    method_prop: &ast_view::MethodProp,
+    ctx: &mut Context,
+  ) {
+    self.check_prop_name(Selector::Method, &method_prop.inner.key, ctx);
+  }
+
+  fn ts_property_signature(
+    &mut self,
+    property_signature: &ast_view::TsPropertySignature,
+    ctx: &mut Context,
+  ) {
+    let inner = property_signature.inner;
+    self.check_key_expr(Selector::Property, &inner.key, inner.computed, ctx);
+  }
+
+  fn ts_method_signature(
+    &mut self,
+    method_signature: &ast_view::TsMethodSignature,
+    ctx: &mut Context,
+  ) {
+    let inner = method_signature.inner;
+    self.check_key_expr(Selector::Method, &inner.key, inner.computed, ctx);
+  }
+}
+
+/// Checks if the binding identifier declares a name, as opposed to being
+/// the target of an assignment like `a = 1` or `[a, b] = [b, a]`.
+fn is_declaration(node: ast_view::Node) -> bool {
+  for ancestor in node.ancestors() {
+    match ancestor.kind() {
+      NodeKind::ArrayPat
+      | NodeKind::ObjectPat
+      | NodeKind::AssignPat
+      | NodeKind::AssignPatProp
+      | NodeKind::RestPat
+      | NodeKind::KeyValuePatProp => {
+        // keep going
+      }
+      NodeKind::AssignExpr | NodeKind::ForInStmt | NodeKind::ForOfStmt => {
+        return false;
+      }
+      _ => {
+        return true;
+      }
+    }
+  }
+  true
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn with_options(options: serde_json::Value) -> NamingConvention {
+    serde_json::from_value(options).unwrap()
+  }
+
+  #[test]
+  fn naming_convention_valid() {
+    assert_lint_ok! {
+      NamingConvention::default(),
+      "const fooBar = 1;",
+      "const FooBar = 1;",
+      "const FOO_BAR = 1;",
+      "let _private = 1;",
+      "function doSomething(firstArg, { secondArg }, ...rest) {}",
+      "class Foo { bar_baz = 1; }",
+      "interface Foo { bar_baz: string; }",
+      "type Foo<T> = T[];",
+      "enum Color { red_ish }",
+      "try {} catch (err) {}",
+      "let fooBar; foo_bar = 1;",
+      "let fooBar; [foo_bar] = [1];",
+      "for (foo_bar of list) {}",
+      "import { foo_bar } from './mod.ts';",
+      "function foo(this: Window) {}",
+    };
+
+    assert_lint_ok! {
+      with_options(serde_json::json!({
+        "minLength": 2,
+        "exceptions": ["i", "_"],
+      })),
+      "for (let i = 0; i < 10; i++) {}",
+      "const [_, second] = pair;",
+      "const ok = 1;",
+    };
+
+    assert_lint_ok! {
+      with_options(serde_json::json!({
+        "conventions": [
+          { "selector": "interface", "format": ["PascalCase"], "prefix": ["I"] },
+          { "selector": "enumMember", "format": ["UPPER_CASE"] },
+          { "selector": "typeParameter", "format": ["PascalCase"], "prefix": ["T"] },
+        ],
+      })),
+      "interface IFoo {}",
+      "enum Color { RED, DARK_BLUE }",
+      "type Box<TValue> = { value: TValue };",
+      "const foo_bar = 1;",
+    };
+  }
+
+  #[test]
+  fn naming_convention_invalid() {
+    assert_lint_err! {
+      NamingConvention::default(),
+      "const foo_bar = 1;": [
+        {
+          col: 6,
+          message: "Variable name `foo_bar` must be in camelCase or PascalCase or UPPER_CASE",
+          hint: "Rename it to use camelCase",
+        }
+      ],
+      "function do_something(first_arg) {}": [
+        {
+          col: 9,
+          message: "Function name `do_something` must be in camelCase or PascalCase or UPPER_CASE",
+          hint: "Rename it to use camelCase",
+        },
+        {
+          col: 22,
+          message: "Parameter name `first_arg` must be in camelCase or PascalCase or UPPER_CASE",
+          hint: "Rename it to use camelCase",
+        }
+      ],
+      "const { a: foo_bar } = obj;": [
+        {
+          col: 11,
+          message: "Variable name `foo_bar` must be in camelCase or PascalCase or UPPER_CASE",
+          hint: "Rename it to use camelCase",
+        }
+      ],
+      "try {} catch (some_err) {}": [
+        {
+          col: 14,
+          message: "Variable name `some_err` must be in camelCase or PascalCase or UPPER_CASE",
+          hint: "Rename it to use camelCase",
+        }
+      ],
+      "class foo {}": [
+        {
+          col: 6,
+          message: "Class name `foo` must be in PascalCase",
+          hint: "Rename it to use PascalCase",
+        }
+      ],
+      "interface user_info {}": [
+        {
+          col: 10,
+          message: "Interface name `user_info` must be in PascalCase",
+          hint: "Rename it to use PascalCase",
+        }
+      ],
+      "type Foo<t> = t[];": [
+        {
+          col: 9,
+          message: "Type parameter name `t` must be in PascalCase",
+          hint: "Rename it to use PascalCase",
+        }
+      ],
+      "enum color {}": [
+        {
+          col: 5,
+          message: "Enum name `color` must be in PascalCase",
+          hint: "Rename it to use PascalCase",
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      with_options(serde_json::json!({ "minLength": 2, "maxLength": 10 })),
+      "const x = 1;": [
+        {
+          col: 6,
+          message: "Variable name `x` is too short (< 2)",
+          hint: "Use a more descriptive name with at least 2 characters",
+        }
+      ],
+      "function f(veryLongParameter) {}": [
+        {
+          col: 9,
+          message: "Function name `f` is too short (< 2)",
+          hint: "Use a more descriptive name with at least 2 characters",
+        },
+        {
+          col: 11,
+          message: "Parameter name `veryLongParameter` is too long (> 10)",
+          hint: "Use a shorter name with at most 10 characters",
+        }
+      ],
+      "const obj = { a: 1 };": [
+        {
+          col: 14,
+          message: "Property name `a` is too short (< 2)",
+          hint: "Use a more descriptive name with at least 2 characters",
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      with_options(serde_json::json!({
+        "conventions": [
+          { "selector": "interface", "format": ["PascalCase"], "prefix": ["I"] },
+          { "selector": "typeLike", "format": ["PascalCase"] },
+          { "selector": "enumMember", "format": ["UPPER_CASE"] },
+          { "selector": "memberLike", "format": ["camelCase"] },
+          { "selector": "typeAlias", "suffix": ["Type"] },
+        ],
+      })),
+      "interface Foo {}": [
+        {
+          col: 10,
+          message: "Interface name `Foo` must start with one of: `I`",
+          hint: "Rename it to `IFoo`",
+        }
+      ],
+      "interface Ifoo {}": [
+        {
+          col: 10,
+          message: "Interface name `Ifoo` must be in PascalCase",
+          hint: "Rename it to use PascalCase",
+        }
+      ],
+      "enum Color { Red }": [
+        {
+          col: 13,
+          message: "Enum member name `Red` must be in UPPER_CASE",
+          hint: "Rename it to use UPPER_CASE",
+        }
+      ],
+      "class Foo { bar_baz() {} }": [
+        {
+          col: 12,
+          message: "Method name `bar_baz` must be in camelCase",
+          hint: "Rename it to use camelCase",
+        }
+      ],
+      "interface IFoo { bar_baz: string; }": [
+        {
+          col: 17,
+          message: "Property name `bar_baz` must be in camelCase",
+          hint: "Rename it to use camelCase",
+        }
+      ],
+      "type Foo = string;": [
+        {
+          col: 5,
+          message: "Type alias name `Foo` must end with one of: `Type`",
+          hint: "Rename it to `FooType`",
+        }
+      ],
+    };
+  }
+}
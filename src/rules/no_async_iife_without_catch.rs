@@ -0,0 +1,161 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::handler::{Handler, Traverse};
+use crate::tags::Tags;
+use crate::Program;
+use deno_ast::swc::ast::{
+  BlockStmtOrExpr, CallExpr, Callee, Expr, MemberProp, Stmt, UnaryOp,
+};
+use deno_ast::view::{self as ast_view, Node};
+use deno_ast::SourceRangedForSpanned;
+
+#[derive(Debug)]
+pub struct NoAsyncIifeWithoutCatch;
+
+const CODE: &str = "no-async-iife-without-catch";
+const MESSAGE: &str = "Top level async IIFE without error handling";
+const HINT: &str = "Add a `.catch()` handler to the call, or wrap the body of the function in a `try`/`catch` statement, so that errors aren't silently turned into unhandled rejections";
+
+impl LintRule for NoAsyncIifeWithoutCatch {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows async functions that are invoked immediately \
+        at the top level, like `(async () => { ... })()`, without handling \
+        their errors. The returned promise is ignored, so an error thrown by \
+        the function becomes an unhandled rejection. Handle errors with \
+        `.catch()` (or `.then()` with a rejection handler) on the call, or \
+        make the body of the function a `try`/`catch` statement. Awaited \
+        calls are allowed, since their errors are thrown by the module.",
+      invalid_examples: &[
+        "(async () => {\n  await main();\n})();",
+        "(async function () {\n  await main();\n})().then(() => console.log(\"done\"));",
+      ],
+      valid_examples: &[
+        "(async () => {\n  await main();\n})().catch((error) => console.error(error));",
+        "(async () => {\n  try {\n    await main();\n  } catch (error) {\n    console.error(error);\n  }\n})();",
+        "await (async () => {\n  await main();\n})();",
+      ],
+    }
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoAsyncIifeWithoutCatchHandler.traverse(program, context);
+  }
+}
+
+struct NoAsyncIifeWithoutCatchHandler;
+
+impl Handler for NoAsyncIifeWithoutCatchHandler {
+  fn expr_stmt(&mut self, expr_stmt: &ast_view::ExprStmt, ctx: &mut Context) {
+    if !matches!(expr_stmt.parent(), Node::Module(_) | Node::Script(_)) {
+      return;
+    }
+    if let Some(call) = unhandled_async_iife(&expr_stmt.inner.expr) {
+      ctx.add_diagnostic_with_hint(call.range(), CODE, MESSAGE, HINT);
+    }
+  }
+}
+
+/// Returns the async IIFE whose rejection isn't handled by the expression,
+/// looking through `.then()` and `.finally()` calls without a rejection
+/// handler.
+fn unhandled_async_iife(expr: &Expr) -> Option<&CallExpr> {
+  match expr.unwrap_parens() {
+    Expr::Unary(unary) if unary.op == UnaryOp::Void => {
+      unhandled_async_iife(&unary.arg)
+    }
+    Expr::Call(call) => {
+      let Callee::Expr(callee) = &call.callee else {
+        return None;
+      };
+      match callee.unwrap_parens() {
+        Expr::Member(member) => {
+          let MemberProp::Ident(prop) = &member.prop else {
+            return None;
+          };
+          match &*prop.sym {
+            "then" if call.args.len() < 2 => unhandled_async_iife(&member.obj),
+            "finally" => unhandled_async_iife(&member.obj),
+            _ => None,
+          }
+        }
+        Expr::Arrow(arrow) if arrow.is_async => match &*arrow.body {
+          BlockStmtOrExpr::BlockStmt(block) if !is_try_catch(&block.stmts) => {
+            Some(call)
+          }
+          BlockStmtOrExpr::Expr(_) => Some(call),
+          _ => None,
+        },
+        Expr::Fn(fn_expr) if fn_expr.function.is_async => {
+          match &fn_expr.function.body {
+            Some(block) if !is_try_catch(&block.stmts) => Some(call),
+            _ => None,
+          }
+        }
+        _ => None,
+      }
+    }
+    _ => None,
+  }
+}
+
+/// Checks if the statements of a function body are a single `try` statement
+/// with a `catch` clause.
+fn is_try_catch(stmts: &[Stmt]) -> bool {
+  matches!(stmts, [Stmt::Try(try_stmt)] if try_stmt.handler.is_some())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_async_iife_without_catch_valid() {
+    assert_lint_ok! {
+      NoAsyncIifeWithoutCatch,
+      "(async () => { await main(); })().catch(console.error);",
+      "(async () => { await main(); })().then(done, console.error);",
+      "(async () => { await main(); })().then(done).catch(console.error);",
+      "(async () => { await main(); })().finally(cleanup).catch(console.error);",
+      "(async () => { try { await main(); } catch (e) { console.error(e); } })();",
+      "(async function () { try { await main(); } catch { Deno.exit(1); } })();",
+      "await (async () => { await main(); })();",
+      "const result = (async () => { await main(); })();",
+      "(() => { main(); })();",
+      "(function () { main(); })();",
+      "function run() { (async () => { await main(); })(); }",
+      "async function main() {} main();",
+    };
+  }
+
+  #[test]
+  fn no_async_iife_without_catch_invalid() {
+    assert_lint_err! {
+      NoAsyncIifeWithoutCatch,
+      MESSAGE,
+      HINT,
+      "(async () => { await main(); })();": [{ col: 0 }],
+      "(async () => main())();": [{ col: 0 }],
+      "(async function main() { await run(); })();": [{ col: 0 }],
+      "(async () => { await main(); })().then(done);": [{ col: 0 }],
+      "(async () => { await main(); })().finally(cleanup);": [{ col: 0 }],
+      "void (async () => { await main(); })();": [{ col: 5 }],
+      "((async () => { await main(); }))();": [{ col: 0 }],
+      "(async () => {\n  const x = await load();\n  try { await run(x); } catch (e) {}\n})();": [{ col: 0 }],
+      "(async () => { try { await main(); } finally { cleanup(); } })();": [{ col: 0 }],
+    };
+  }
+}
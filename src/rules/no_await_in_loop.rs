@@ -1,10 +1,19 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
 use crate::handler::{Handler, Traverse};
 use crate::Program;
+use deno_ast::swc::ast::{
+  AssignExpr, AwaitExpr, Callee, Expr, ForHead, ForOfStmt, Id, Ident,
+  MemberExpr, MemberProp, Stmt, UnaryExpr, UnaryOp, UpdateExpr, VarDeclKind,
+  YieldExpr,
+};
+use deno_ast::swc::visit::{Visit, VisitWith};
 use deno_ast::view::NodeTrait;
-use deno_ast::{view as ast_view, SourceRanged};
+use deno_ast::{
+  view as ast_view, SourceRanged, SourceRangedForSpanned, SourceTextInfo,
+};
 
 #[derive(Debug)]
 pub struct NoAwaitInLoop;
@@ -12,6 +21,7 @@ pub struct NoAwaitInLoop;
 const CODE: &str = "no-await-in-loop";
 const MESSAGE: &str = "Unexpected `await` inside a loop.";
 const HINT: &str = "Remove `await` in loop body, store all promises generated and then `await Promise.all(storedPromises)` after the loop";
+const FIX_DESC: &str = "Await all values at once with `Promise.all`";
 
 impl LintRule for NoAwaitInLoop {
   fn code(&self) -> &'static str {
@@ -35,16 +45,16 @@ impl Handler for NoAwaitInLoopHandler {
     await_expr: &ast_view::AwaitExpr,
     ctx: &mut Context,
   ) {
-    fn inside_loop(
+    fn enclosing_loop<'a>(
       await_expr: &ast_view::AwaitExpr,
-      node: ast_view::Node,
-    ) -> bool {
+      node: ast_view::Node<'a>,
+    ) -> Option<ast_view::Node<'a>> {
       use deno_ast::view::Node::*;
       match node {
-        FnDecl(_) | FnExpr(_) | ArrowExpr(_) => false,
+        FnDecl(_) | FnExpr(_) | ArrowExpr(_) => None,
         ForOfStmt(stmt) if stmt.is_await() => {
           // `await` is allowed to use within the body of `for await (const x of y) { ... }`
-          false
+          None
         }
         ForInStmt(ast_view::ForInStmt { right, .. })
         | ForOfStmt(ast_view::ForOfStmt { right, .. }) => {
@@ -52,7 +62,7 @@ impl Handler for NoAwaitInLoopHandler {
           // except for the case where the given `await_expr` is contained in the `right` part.
           // e.g. for (const x of await xs) { ... }
           //                      ^^^^^^^^ <-------- `right` part
-          !right.range().contains(&await_expr.range())
+          (!right.range().contains(&await_expr.range())).then_some(node)
         }
         ForStmt(stmt) => {
           // When it encounters `ForStmt`, we should treat it as `inside_loop = true`
@@ -63,20 +73,179 @@ impl Handler for NoAwaitInLoopHandler {
             .init
             .as_ref()
             .map_or(true, |init| !init.range().contains(&await_expr.range()))
+            .then_some(node)
         }
-        WhileStmt(_) | DoWhileStmt(_) => true,
-        _ => {
-          let parent = match node.parent() {
-            Some(p) => p,
-            None => return false,
-          };
-          inside_loop(await_expr, parent)
-        }
+        WhileStmt(_) | DoWhileStmt(_) => Some(node),
+        _ => enclosing_loop(await_expr, node.parent()?),
+      }
+    }
+
+    let Some(loop_node) = enclosing_loop(await_expr, await_expr.as_node())
+    else {
+      return;
+    };
+    let suggestion = match loop_node {
+      ast_view::Node::ForOfStmt(for_of) => {
+        promise_all_suggestion(for_of.inner, await_expr.inner, ctx)
+      }
+      _ => None,
+    };
+    match suggestion {
+      Some(suggestion) => ctx.add_diagnostic_with_suggestions(
+        await_expr.range(),
+        CODE,
+        MESSAGE,
+        Some(HINT.to_string()),
+        vec![suggestion],
+      ),
+      None => {
+        ctx.add_diagnostic_with_hint(await_expr.range(), CODE, MESSAGE, HINT)
+      }
+    }
+  }
+}
+
+/// Suggests to rewrite a `for...of` loop that only awaits a value, or pushes
+/// it to an array, into a single `Promise.all`, e.g.
+/// `for (const x of xs) results.push(await f(x));` becomes
+/// `results.push(...await Promise.all(Array.from(xs, (x) => f(x))));`.
+///
+/// This is only a suggestion, since the promises are then created at once
+/// instead of one after the other. It's not offered if the awaited
+/// expression assigns variables or refers to the array, since the order of
+/// evaluation would matter.
+fn promise_all_suggestion(
+  for_of: &ForOfStmt,
+  await_expr: &AwaitExpr,
+  ctx: &Context,
+) -> Option<LintFix> {
+  let ForHead::VarDecl(var_decl) = &for_of.left else {
+    return None;
+  };
+  // `var` bindings are also visible after the loop.
+  if var_decl.kind == VarDeclKind::Var || var_decl.decls.len() != 1 {
+    return None;
+  }
+
+  let stmt = match &*for_of.body {
+    Stmt::Block(block) => match block.stmts.as_slice() {
+      [stmt] => stmt,
+      _ => return None,
+    },
+    stmt => stmt,
+  };
+  let Stmt::Expr(expr_stmt) = stmt else {
+    return None;
+  };
+  let (accumulator, awaited) = match &*expr_stmt.expr {
+    Expr::Await(awaited) => (None, awaited),
+    Expr::Call(call) => {
+      let Callee::Expr(callee) = &call.callee else {
+        return None;
+      };
+      let Expr::Member(MemberExpr {
+        obj,
+        prop: MemberProp::Ident(prop),
+        ..
+      }) = &**callee
+      else {
+        return None;
+      };
+      let (Expr::Ident(accumulator), [arg]) = (&**obj, call.args.as_slice())
+      else {
+        return None;
+      };
+      let Expr::Await(awaited) = &*arg.expr else {
+        return None;
+      };
+      if prop.sym != *"push" || arg.spread.is_some() {
+        return None;
       }
+      (Some(accumulator), awaited)
+    }
+    _ => return None,
+  };
+  if awaited.range() != await_expr.range() {
+    return None;
+  }
+
+  let mut finder = OrderDependenceFinder {
+    accumulator: accumulator.map(|ident| ident.to_id()),
+    found: false,
+  };
+  awaited.arg.visit_with(&mut finder);
+  let range = for_of.range();
+  if finder.found || ctx.has_comments_in_range(range) {
+    return None;
+  }
+
+  let text_info = ctx.text_info();
+  let promises = format!(
+    "Promise.all(Array.from({}, ({}) => {}))",
+    parenthesized_text(&for_of.right, text_info),
+    var_decl.decls[0].name.range().text_fast(text_info),
+    parenthesized_text(&awaited.arg, text_info),
+  );
+  let new_text = match accumulator {
+    Some(accumulator) => {
+      format!("{}.push(...await {});", accumulator.sym, promises)
+    }
+    None => format!("await {};", promises),
+  };
+  Some(LintFix {
+    description: FIX_DESC.into(),
+    changes: vec![LintFixChange {
+      new_text: new_text.into(),
+      range,
+    }],
+  })
+}
+
+/// Returns the text of an expression, in parentheses if it can't be used as
+/// an argument or the body of an arrow function as is.
+fn parenthesized_text(expr: &Expr, text_info: &SourceTextInfo) -> String {
+  let text = expr.range().text_fast(text_info);
+  if matches!(expr, Expr::Object(_) | Expr::Seq(_)) {
+    format!("({})", text)
+  } else {
+    text.to_string()
+  }
+}
+
+/// Finds expressions whose result depends on the order in which the
+/// iterations run.
+struct OrderDependenceFinder {
+  accumulator: Option<Id>,
+  found: bool,
+}
+
+impl Visit for OrderDependenceFinder {
+  fn visit_assign_expr(&mut self, _: &AssignExpr) {
+    self.found = true;
+  }
+
+  fn visit_update_expr(&mut self, _: &UpdateExpr) {
+    self.found = true;
+  }
+
+  fn visit_await_expr(&mut self, _: &AwaitExpr) {
+    self.found = true;
+  }
+
+  fn visit_yield_expr(&mut self, _: &YieldExpr) {
+    self.found = true;
+  }
+
+  fn visit_unary_expr(&mut self, unary_expr: &UnaryExpr) {
+    if unary_expr.op == UnaryOp::Delete {
+      self.found = true;
     }
+    unary_expr.visit_children_with(self);
+  }
 
-    if inside_loop(await_expr, await_expr.as_node()) {
-      ctx.add_diagnostic_with_hint(await_expr.range(), CODE, MESSAGE, HINT);
+  fn visit_ident(&mut self, ident: &Ident) {
+    if self.accumulator.as_ref() == Some(&ident.to_id()) {
+      self.found = true;
     }
   }
 }
@@ -228,12 +397,28 @@ async function foo(things) {
   }
   return baz(results);
 }
-      "#: [{ line: 5, col: 17 }],
+      "#: [{
+        line: 5,
+        col: 17,
+        suggestion: (FIX_DESC, r#"
+async function foo(things) {
+  const results = [];
+  results.push(...await Promise.all(Array.from(things, (thing) => bar(thing))));
+  return baz(results);
+}
+      "#),
+      }],
       r#"
 for (const thing of things) {
   results.push(await foo(thing));
 }
-      "#: [{ line: 3, col: 15 }],
+      "#: [{
+        line: 3,
+        col: 15,
+        suggestion: (FIX_DESC, r#"
+results.push(...await Promise.all(Array.from(things, (thing) => foo(thing))));
+      "#),
+      }],
       r#"
 for (let i = 0; i < await foo(); i++) {
   bar();
@@ -288,7 +473,18 @@ for await (const thing of things) {
   }
   await baz();
 }
-      "#: [{ line: 5, col: 6 }],
+      "#: [{
+        line: 5,
+        col: 6,
+        suggestion: (FIX_DESC, r#"
+for await (const thing of things) {
+  async function foo() {
+    await Promise.all(Array.from(them, (one) => bar(one)));
+  }
+  await baz();
+}
+      "#),
+      }],
 
       r#"
 function foo() {
@@ -298,7 +494,17 @@ function foo() {
     }
   }
 }
-      "#: [{ line: 5, col: 6 }],
+      "#: [{
+        line: 5,
+        col: 6,
+        suggestion: (FIX_DESC, r#"
+function foo() {
+  async function bar() {
+    await Promise.all(Array.from(things, (thing) => baz(thing)));
+  }
+}
+      "#),
+      }],
       r#"
 async function foo() {
   for (const thing of things) {
@@ -311,4 +517,44 @@ async function foo() {
       "#: [{ line: 6, col: 6 }]
     }
   }
+  #[test]
+  fn no_await_in_loop_suggestions() {
+    assert_lint_err! {
+      NoAwaitInLoop,
+      MESSAGE,
+      HINT,
+      "for (const [key, value] of Object.entries(obj)) await save(key, value);": [{
+        col: 48,
+        suggestion: (
+          FIX_DESC,
+          "await Promise.all(Array.from(Object.entries(obj), ([key, value]) => save(key, value)));"
+        ),
+      }],
+      "for (let url of urls) { pages.push(await fetch(url).then((res) => res.text())); }": [{
+        col: 35,
+        suggestion: (
+          FIX_DESC,
+          "pages.push(...await Promise.all(Array.from(urls, (url) => fetch(url).then((res) => res.text()))));"
+        ),
+      }],
+      "for (const x of (a, b)) await (f(x), g(x));": [{
+        col: 24,
+        suggestion: (
+          FIX_DESC,
+          "await Promise.all(Array.from((a, b), (x) => (f(x), g(x))));"
+        ),
+      }],
+      "for (var x of xs) await f(x);": [{ col: 18 }],
+      "for (x of xs) await f(x);": [{ col: 14 }],
+      "for (const x of xs) { await f(x); g(); }": [{ col: 22 }],
+      "for (const x of xs) await f(i++);": [{ col: 20 }],
+      "for (const x of xs) results.push(await f(x, results));": [{ col: 33 }],
+      "for (const x of xs) results.push(await f(await g(x)));": [
+        { col: 33 },
+        { col: 41 },
+      ],
+      "for (const x of xs) results.unshift(await f(x));": [{ col: 36 }],
+      "for (const x of xs) {\n  // Sequentially\n  await f(x);\n}": [{ line: 3, col: 2 }],
+    };
+  }
 }
@@ -1,12 +1,20 @@
-use super::{Context, LintRule};
+use super::{Context, LintRule, RuleDocs};
 use crate::handler::{Handler, Traverse};
 use crate::tags::Tags;
 use crate::Program;
-use deno_ast::view::{CallExpr, NodeTrait};
-use deno_ast::SourceRanged;
+use deno_ast::view::{
+  CallExpr, Callee, Expr, ExprOrSpread, NodeTrait, OptCall,
+};
+use deno_ast::{SourceRange, SourceRanged};
+use serde::Deserialize;
 
-#[derive(Debug)]
-pub struct NoBooleanLiteralForArguments;
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoBooleanLiteralForArguments {
+  /// Functions that may be called with boolean literals, e.g. `assert` or
+  /// `element.toggleAttribute`.
+  pub allow: Vec<String>,
+}
 
 const CODE: &str = "no-boolean-literal-for-arguments";
 const MESSAGE: &str = "Please create a self-documenting constant instead of \
@@ -20,7 +28,8 @@ impl LintRule for NoBooleanLiteralForArguments {
     context: &mut Context<'view>,
     program: Program<'view>,
   ) {
-    NoBooleanLiteralForArgumentsVisitor.traverse(program, context);
+    NoBooleanLiteralForArgumentsVisitor { allow: &self.allow }
+      .traverse(program, context);
   }
 
   fn code(&self) -> &'static str {
@@ -30,29 +39,104 @@ impl LintRule for NoBooleanLiteralForArguments {
   fn tags(&self) -> Tags {
     &[]
   }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows passing `true` or `false` literals to functions \
+        that are called with more than one argument, since the meaning of the \
+        literal is not clear at the call site. Use a constant or an options \
+        object instead. Calls with a single argument, like \
+        `setVisible(true)`, are allowed. Functions listed in the `allow` \
+        option, e.g. `assertEquals` or `element.toggleAttribute`, can always \
+        be called with boolean literals.",
+      invalid_examples: &["render(node, true, false);"],
+      valid_examples: &[
+        "render(node, { recursive: true, force: false });",
+        "setVisible(true);",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "allow": {
+          "description": "Functions that may be called with boolean literals, e.g. `assert` or `element.toggleAttribute`",
+          "type": "array",
+          "items": { "type": "string" },
+          "uniqueItems": true
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<
+      NoBooleanLiteralForArguments,
+    >(options)?))
+  }
 }
 
-struct NoBooleanLiteralForArgumentsVisitor;
+struct NoBooleanLiteralForArgumentsVisitor<'a> {
+  allow: &'a [String],
+}
 
-impl Handler for NoBooleanLiteralForArgumentsVisitor {
-  fn call_expr(&mut self, call_expression: &CallExpr, ctx: &mut Context) {
-    let args = call_expression.args;
+impl NoBooleanLiteralForArgumentsVisitor<'_> {
+  fn check_call(
+    &self,
+    range: SourceRange,
+    callee: Option<Expr>,
+    args: &[&ExprOrSpread],
+    ctx: &mut Context,
+  ) {
+    // With a single argument, the name of the function usually tells what
+    // the boolean means, e.g. `setVisible(true)`.
+    if args.len() < 2 {
+      return;
+    }
+    if let Some(callee) = callee {
+      let name = callee
+        .text()
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .replace("?.", ".");
+      if self.allow.contains(&name) {
+        return;
+      }
+    }
     let is_boolean_literal =
       |text: &str| -> bool { matches!(text, "true" | "false") };
-    for arg in args {
-      if is_boolean_literal(arg.text()) {
-        ctx.add_diagnostic_with_hint(
-          call_expression.range(),
-          CODE,
-          MESSAGE,
-          HINT,
-        );
-        break;
-      }
+    if args.iter().any(|arg| is_boolean_literal(arg.text())) {
+      ctx.add_diagnostic_with_hint(range, CODE, MESSAGE, HINT);
     }
   }
 }
 
+impl Handler for NoBooleanLiteralForArgumentsVisitor<'_> {
+  fn call_expr(&mut self, call_expression: &CallExpr, ctx: &mut Context) {
+    let callee = match call_expression.callee {
+      Callee::Expr(callee) => Some(callee),
+      _ => None,
+    };
+    self.check_call(call_expression.range(), callee, call_expression.args, ctx);
+  }
+
+  fn opt_call(&mut self, opt_call: &OptCall, ctx: &mut Context) {
+    self.check_call(
+      opt_call.range(),
+      Some(opt_call.callee),
+      opt_call.args,
+      ctx,
+    );
+  }
+}
+
 #[cfg(test)]
 mod test {
   use super::*;
@@ -60,7 +144,7 @@ mod test {
   #[test]
   fn no_boolean_literal_for_arguments_valid() {
     assert_lint_ok! {
-      NoBooleanLiteralForArguments,
+      NoBooleanLiteralForArguments::default(),
       r#"runCMDCommand(command, executionMode)"#,
       r#"
       function formatLog(logData: { level: string, text: string }) {
@@ -76,14 +160,45 @@ mod test {
         // TODO!
       }
       displayInformation({ renderer: "terminal", recursive: true });
-      "#
+      "#,
+      r#"
+      function displayInformation(display: boolean) {
+        if (display) {
+          renderInformation();
+        }
+        // TODO!
+      }
+      displayInformation(true);
+      "#,
+      r#"setVisible(false)"#,
     }
   }
 
+  #[test]
+  fn no_boolean_literal_for_arguments_allow() {
+    let rule = || NoBooleanLiteralForArguments {
+      allow: vec![
+        "assertEquals".to_string(),
+        "element.toggleAttribute".to_string(),
+      ],
+    };
+    assert_lint_ok! {
+      rule(),
+      r#"assertEquals(isValid(input), true)"#,
+      r#"element.toggleAttribute("hidden", false)"#,
+      r#"element?.toggleAttribute("hidden", false)"#,
+    };
+    assert_lint_err! {
+      rule(),
+      r#"assertNotEquals(isValid(input), true)"#:[{line: 1, col: 0, message: MESSAGE, hint: HINT}],
+      r#"other.toggleAttribute("hidden", false)"#:[{line: 1, col: 0, message: MESSAGE, hint: HINT}],
+    };
+  }
+
   #[test]
   fn no_boolean_literal_for_arguments_invalid() {
     assert_lint_err! {
-      NoBooleanLiteralForArguments,
+      NoBooleanLiteralForArguments::default(),
       r#"test(true,true)"#:[{line: 1, col: 0, message: MESSAGE, hint: HINT}],
       r#"test(false,true)"#:[{line: 1, col: 0, message: MESSAGE, hint: HINT}],
       r#"test(false,false)"#:[{line: 1, col: 0, message: MESSAGE, hint: HINT}],
@@ -99,15 +214,7 @@ mod test {
       r#"
       runCMD(true, CMD.MODE_ONE)
       "#:[{line: 2, col: 6, message: MESSAGE, hint: HINT}],
-      r#"
-      function displayInformation(display: boolean) {
-        if (display) {
-          renderInformation();
-        }
-        // TODO!
-      }
-      displayInformation(true);
-      "#:[{line: 8, col: 6, message: MESSAGE, hint: HINT}],
+      r#"element?.toggleAttribute("hidden", true)"#:[{line: 1, col: 0, message: MESSAGE, hint: HINT}],
     }
   }
 }
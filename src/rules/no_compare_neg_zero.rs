@@ -1,6 +1,7 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
-use super::{Context, LintRule};
+use super::{Context, LintRule, RuleDocs};
+use crate::diagnostic::{LintFix, LintFixChange};
 use crate::handler::{Handler, Traverse};
 use crate::tags::{self, Tags};
 use crate::Program;
@@ -9,7 +10,9 @@ use deno_ast::swc::ast::Expr::Lit;
 use deno_ast::swc::ast::Lit::Num;
 use deno_ast::swc::ast::UnaryExpr;
 use deno_ast::swc::ast::UnaryOp::Minus;
-use deno_ast::view::{BinExpr, BinaryOp, Expr};
+use deno_ast::view::{
+  BinExpr, BinaryOp, CallExpr, Callee, Expr, MemberProp, SwitchCase,
+};
 use deno_ast::SourceRanged;
 use derive_more::Display;
 
@@ -28,8 +31,19 @@ enum NoCompareNegZeroMessage {
 enum NoCompareNegZeroHint {
   #[display(fmt = "Use Object.is(x, -0) instead")]
   ObjectIs,
+  #[display(
+    fmt = "`case -0` also matches 0, use `Object.is(x, -0)` in an `if` statement to only match -0"
+  )]
+  SwitchCase,
+  #[display(
+    fmt = "`{}(-0)` also finds 0, use `.some((x) => Object.is(x, -0))` to only find -0",
+    _0
+  )]
+  Search(String),
 }
 
+const FIX_DESC: &str = "Compare with `Object.is`";
+
 impl LintRule for NoCompareNegZero {
   fn tags(&self) -> Tags {
     &[tags::RECOMMENDED]
@@ -39,6 +53,24 @@ impl LintRule for NoCompareNegZero {
     CODE
   }
 
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows comparing against `-0`, since comparisons \
+        don't tell `-0` and `0` apart: `x === -0` is also true if `x` is \
+        `0`. Use `Object.is(x, -0)` to check for `-0`. Besides comparison \
+        operators, `case -0` in a `switch` statement and searching for `-0` \
+        with `includes`, `indexOf` or `lastIndexOf` are reported. Replacing \
+        an equality check with `Object.is` is offered as a suggestion, since \
+        it changes the result for `0`.",
+      invalid_examples: &[
+        "if (x === -0) {}",
+        "switch (x) {\n  case -0:\n    break;\n}",
+        "values.includes(-0);",
+      ],
+      valid_examples: &["if (Object.is(x, -0)) {}", "if (x === 0) {}"],
+    }
+  }
+
   fn lint_program_with_ast_view<'view>(
     &self,
     context: &mut Context,
@@ -56,12 +88,81 @@ impl Handler for NoCompareNegZeroHandler {
       return;
     }
 
-    if bin_expr.left.is_neg_zero() || bin_expr.right.is_neg_zero() {
+    let other = if bin_expr.right.is_neg_zero() {
+      bin_expr.left
+    } else if bin_expr.left.is_neg_zero() {
+      bin_expr.right
+    } else {
+      return;
+    };
+
+    // Relational comparisons have no equivalent with `Object.is`.
+    let negate = match bin_expr.op() {
+      EqEq | EqEqEq => "",
+      NotEq | NotEqEq => "!",
+      _ => {
+        context.add_diagnostic_with_hint(
+          bin_expr.range(),
+          CODE,
+          NoCompareNegZeroMessage::Unexpected,
+          NoCompareNegZeroHint::ObjectIs,
+        );
+        return;
+      }
+    };
+    context.add_diagnostic_with_suggestions(
+      bin_expr.range(),
+      CODE,
+      NoCompareNegZeroMessage::Unexpected,
+      Some(NoCompareNegZeroHint::ObjectIs.to_string()),
+      vec![LintFix {
+        description: FIX_DESC.into(),
+        changes: vec![LintFixChange {
+          new_text: format!(
+            "{}Object.is({}, -0)",
+            negate,
+            other.range().text_fast(context.text_info())
+          )
+          .into(),
+          range: bin_expr.range(),
+        }],
+      }],
+    );
+  }
+
+  fn switch_case(&mut self, switch_case: &SwitchCase, context: &mut Context) {
+    if let Some(test) = switch_case.test {
+      if test.is_neg_zero() {
+        context.add_diagnostic_with_hint(
+          test.range(),
+          CODE,
+          NoCompareNegZeroMessage::Unexpected,
+          NoCompareNegZeroHint::SwitchCase,
+        );
+      }
+    }
+  }
+
+  fn call_expr(&mut self, call_expr: &CallExpr, context: &mut Context) {
+    let Callee::Expr(Expr::Member(member)) = call_expr.callee else {
+      return;
+    };
+    let MemberProp::Ident(prop) = member.prop else {
+      return;
+    };
+    let method = prop.sym().as_ref();
+    if !matches!(method, "includes" | "indexOf" | "lastIndexOf") {
+      return;
+    }
+    let Some(arg) = call_expr.args.first() else {
+      return;
+    };
+    if arg.spread().is_none() && arg.expr.is_neg_zero() {
       context.add_diagnostic_with_hint(
-        bin_expr.range(),
+        arg.range(),
         CODE,
         NoCompareNegZeroMessage::Unexpected,
-        NoCompareNegZeroHint::ObjectIs,
+        NoCompareNegZeroHint::Search(method.to_string()),
       );
     }
   }
@@ -143,6 +244,11 @@ mod tests {
       r#"x !== 0"#,
       r#"0 !== x"#,
       r#"{} == { foo: x === 0 }"#,
+      r#"switch (x) { case 0: break; case -1: break; }"#,
+      r#"values.includes(0)"#,
+      r#"values.indexOf(-1)"#,
+      r#"values.includes(x, -0)"#,
+      r#"values.push(-0)"#,
     };
   }
 
@@ -155,6 +261,7 @@ mod tests {
           col: 4,
           message: NoCompareNegZeroMessage::Unexpected,
           hint: NoCompareNegZeroHint::ObjectIs,
+          suggestion: (FIX_DESC, "if (Object.is(x, -0)) { }"),
         }
       ],
       "if (-0 == x) { }": [
@@ -162,6 +269,7 @@ mod tests {
           col: 4,
           message: NoCompareNegZeroMessage::Unexpected,
           hint: NoCompareNegZeroHint::ObjectIs,
+          suggestion: (FIX_DESC, "if (Object.is(x, -0)) { }"),
         }
       ],
       "if (x != -0) { }": [
@@ -169,6 +277,7 @@ mod tests {
           col: 4,
           message: NoCompareNegZeroMessage::Unexpected,
           hint: NoCompareNegZeroHint::ObjectIs,
+          suggestion: (FIX_DESC, "if (!Object.is(x, -0)) { }"),
         }
       ],
       "if (-0 != x) { }": [
@@ -176,6 +285,7 @@ mod tests {
           col: 4,
           message: NoCompareNegZeroMessage::Unexpected,
           hint: NoCompareNegZeroHint::ObjectIs,
+          suggestion: (FIX_DESC, "if (!Object.is(x, -0)) { }"),
         }
       ],
       "if (x === -0) { }": [
@@ -183,6 +293,7 @@ mod tests {
           col: 4,
           message: NoCompareNegZeroMessage::Unexpected,
           hint: NoCompareNegZeroHint::ObjectIs,
+          suggestion: (FIX_DESC, "if (Object.is(x, -0)) { }"),
         }
       ],
       "if (-0 === x) { }": [
@@ -190,6 +301,7 @@ mod tests {
           col: 4,
           message: NoCompareNegZeroMessage::Unexpected,
           hint: NoCompareNegZeroHint::ObjectIs,
+          suggestion: (FIX_DESC, "if (Object.is(x, -0)) { }"),
         }
       ],
       "if (x !== -0) { }": [
@@ -197,6 +309,7 @@ mod tests {
           col: 4,
           message: NoCompareNegZeroMessage::Unexpected,
           hint: NoCompareNegZeroHint::ObjectIs,
+          suggestion: (FIX_DESC, "if (!Object.is(x, -0)) { }"),
         }
       ],
       "if (-0 !== x) { }": [
@@ -204,6 +317,7 @@ mod tests {
           col: 4,
           message: NoCompareNegZeroMessage::Unexpected,
           hint: NoCompareNegZeroHint::ObjectIs,
+          suggestion: (FIX_DESC, "if (!Object.is(x, -0)) { }"),
         }
       ],
       "if (x < -0) { }": [
@@ -267,6 +381,7 @@ mod tests {
           col: 4,
           message: NoCompareNegZeroMessage::Unexpected,
           hint: NoCompareNegZeroHint::ObjectIs,
+          suggestion: (FIX_DESC, "if (Object.is(x, -0)) { }"),
         }
       ],
       "if (-0.0 == x) { }": [
@@ -274,6 +389,7 @@ mod tests {
           col: 4,
           message: NoCompareNegZeroMessage::Unexpected,
           hint: NoCompareNegZeroHint::ObjectIs,
+          suggestion: (FIX_DESC, "if (Object.is(x, -0)) { }"),
         }
       ],
       "if (x === -0.0) { }": [
@@ -281,6 +397,7 @@ mod tests {
           col: 4,
           message: NoCompareNegZeroMessage::Unexpected,
           hint: NoCompareNegZeroHint::ObjectIs,
+          suggestion: (FIX_DESC, "if (Object.is(x, -0)) { }"),
         }
       ],
       "if (-0.0 === x) { }": [
@@ -288,6 +405,7 @@ mod tests {
           col: 4,
           message: NoCompareNegZeroMessage::Unexpected,
           hint: NoCompareNegZeroHint::ObjectIs,
+          suggestion: (FIX_DESC, "if (Object.is(x, -0)) { }"),
         }
       ],
 
@@ -297,8 +415,44 @@ mod tests {
           col: 13,
           message: NoCompareNegZeroMessage::Unexpected,
           hint: NoCompareNegZeroHint::ObjectIs,
+          suggestion: (FIX_DESC, "{} == { foo: Object.is(x, -0) }"),
         }
       ]
     };
   }
+
+  #[test]
+  fn no_compare_neg_zero_switch_case_and_search() {
+    assert_lint_err! {
+      NoCompareNegZero,
+      "switch (x) { case -0: break; }": [
+        {
+          col: 18,
+          message: NoCompareNegZeroMessage::Unexpected,
+          hint: NoCompareNegZeroHint::SwitchCase,
+        }
+      ],
+      "values.includes(-0)": [
+        {
+          col: 16,
+          message: NoCompareNegZeroMessage::Unexpected,
+          hint: NoCompareNegZeroHint::Search("includes".to_string()),
+        }
+      ],
+      "values.indexOf(-0.0)": [
+        {
+          col: 15,
+          message: NoCompareNegZeroMessage::Unexpected,
+          hint: NoCompareNegZeroHint::Search("indexOf".to_string()),
+        }
+      ],
+      "values.lastIndexOf(-0, 2)": [
+        {
+          col: 19,
+          message: NoCompareNegZeroMessage::Unexpected,
+          hint: NoCompareNegZeroHint::Search("lastIndexOf".to_string()),
+        }
+      ],
+    };
+  }
 }
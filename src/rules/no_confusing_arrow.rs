@@ -0,0 +1,222 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::{Handler, Traverse};
+use crate::tags::{self, Tags};
+use crate::Program;
+use deno_ast::swc::ast::{BlockStmtOrExpr, Expr, Pat};
+use deno_ast::view as ast_view;
+use deno_ast::{SourceRanged, SourceRangedForSpanned};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoConfusingArrow {
+  /// Allows conditional expressions wrapped in parentheses, e.g.
+  /// `(a) => (a ? b : c)`.
+  pub allow_parens: bool,
+  /// Only reports arrow functions with exactly one parameter that is a plain
+  /// identifier, e.g. `a => a ? b : c` but not `(a, b) => a ? b : c`.
+  pub only_one_simple_param: bool,
+}
+
+impl Default for NoConfusingArrow {
+  fn default() -> Self {
+    Self {
+      allow_parens: true,
+      only_one_simple_param: false,
+    }
+  }
+}
+
+const CODE: &str = "no-confusing-arrow";
+const MESSAGE: &str =
+  "Arrow function used ambiguously with a conditional expression";
+const HINT: &str = "Wrap the conditional expression in parentheses";
+const PARENTHESIZED_HINT: &str =
+  "Use a block body with an explicit `return` statement";
+const FIX_DESC: &str = "Wrap the body in parentheses";
+
+impl LintRule for NoConfusingArrow {
+  fn tags(&self) -> Tags {
+    &[tags::STYLISTIC]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows arrow functions whose body is a conditional \
+        expression without parentheses, because the `=>` can be confused \
+        with a comparison operator like `>=`.",
+      invalid_examples: &["const isBig = (a) => a > 10 ? \"yes\" : \"no\";"],
+      valid_examples: &[
+        "const isBig = (a) => (a > 10 ? \"yes\" : \"no\");",
+        "const isBig = (a) => {\n  return a > 10 ? \"yes\" : \"no\";\n};",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "allowParens": {
+          "description": "Allow conditional expressions wrapped in parentheses. Defaults to `true`",
+          "type": "boolean"
+        },
+        "onlyOneSimpleParam": {
+          "description": "Only report arrow functions with a single identifier parameter",
+          "type": "boolean"
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<NoConfusingArrow>(
+      options,
+    )?))
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    NoConfusingArrowHandler { rule: self }.traverse(program, context);
+  }
+}
+
+struct NoConfusingArrowHandler<'a> {
+  rule: &'a NoConfusingArrow,
+}
+
+impl<'a> Handler for NoConfusingArrowHandler<'a> {
+  fn arrow_expr(&mut self, arrow: &ast_view::ArrowExpr, ctx: &mut Context) {
+    let BlockStmtOrExpr::Expr(body) = &*arrow.inner.body else {
+      return;
+    };
+    let is_parenthesized = match &**body {
+      Expr::Cond(_) => false,
+      Expr::Paren(paren) if !self.rule.allow_parens && paren.expr.is_cond() => {
+        true
+      }
+      _ => return,
+    };
+
+    if self.rule.only_one_simple_param
+      && !matches!(arrow.inner.params.as_slice(), [Pat::Ident(_)])
+    {
+      return;
+    }
+
+    let range = body.range();
+    if is_parenthesized {
+      ctx.add_diagnostic_with_hint(range, CODE, MESSAGE, PARENTHESIZED_HINT);
+      return;
+    }
+    ctx.add_diagnostic_with_fixes(
+      range,
+      CODE,
+      MESSAGE,
+      Some(HINT.to_string()),
+      vec![LintFix {
+        description: FIX_DESC.into(),
+        changes: vec![LintFixChange {
+          new_text: format!("({})", range.text_fast(ctx.text_info())).into(),
+          range,
+        }],
+      }],
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_confusing_arrow_valid() {
+    assert_lint_ok! {
+      NoConfusingArrow::default(),
+      "var x = a => (1 ? 2 : 3);",
+      "var x = (a) => { return 1 ? 2 : 3; };",
+      "var x = a => a > 1;",
+      "var x = (a, b) => a >= b;",
+    };
+
+    assert_lint_ok! {
+      NoConfusingArrow {
+        only_one_simple_param: true,
+        ..Default::default()
+      },
+      "var x = () => 1 ? 2 : 3;",
+      "var x = (a, b) => a ? b : 3;",
+      "var x = ({ a }) => a ? 1 : 2;",
+    };
+  }
+
+  #[test]
+  fn no_confusing_arrow_invalid() {
+    assert_lint_err! {
+      NoConfusingArrow::default(),
+      "var x = a => 1 ? 2 : 3;": [
+        {
+          col: 13,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "var x = a => (1 ? 2 : 3);"),
+        }
+      ],
+      "var x = (a) => a > 1 ? b : c;": [
+        {
+          col: 15,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "var x = (a) => (a > 1 ? b : c);"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoConfusingArrow {
+        allow_parens: false,
+        ..Default::default()
+      },
+      "var x = a => (1 ? 2 : 3);": [
+        {
+          col: 13,
+          message: MESSAGE,
+          hint: PARENTHESIZED_HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoConfusingArrow {
+        only_one_simple_param: true,
+        ..Default::default()
+      },
+      "var x = a => a ? 1 : 2;": [
+        {
+          col: 13,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "var x = a => (a ? 1 : 2);"),
+        }
+      ],
+    };
+  }
+}
@@ -5,14 +5,23 @@ use super::{Context, LintRule};
 use crate::tags::{self, Tags};
 use crate::Program;
 use crate::ProgramRef;
-use deno_ast::swc::ast::{BinaryOp, CondExpr, Expr, IfStmt, Lit, UnaryOp};
+use deno_ast::swc::ast::{
+  ArrowExpr, BinaryOp, CondExpr, DoWhileStmt, Expr, ForStmt, Function, IfStmt,
+  Lit, UnaryOp, WhileStmt, YieldExpr,
+};
 use deno_ast::swc::visit::{noop_visit_type, Visit, VisitWith};
 use deno_ast::SourceRange;
 use deno_ast::SourceRangedForSpanned;
 use derive_more::Display;
-
-#[derive(Debug)]
-pub struct NoConstantCondition;
+use serde::Deserialize;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoConstantCondition {
+  /// Also checks the conditions of loops. This is off by default, since
+  /// `while (true)` loops are common.
+  pub check_loops: bool,
+}
 
 const CODE: &str = "no-constant-condition";
 
@@ -39,13 +48,36 @@ impl LintRule for NoConstantCondition {
     CODE
   }
 
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "checkLoops": {
+          "description": "Also checks the conditions of `while`, `do-while` and `for` loops",
+          "type": "boolean"
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<NoConstantCondition>(
+      options,
+    )?))
+  }
+
   fn lint_program_with_ast_view<'view>(
     &self,
     context: &mut Context<'view>,
     program: Program<'view>,
   ) {
     let program = program_ref(program);
-    let mut visitor = NoConstantConditionVisitor::new(context);
+    let mut visitor =
+      NoConstantConditionVisitor::new(context, self.check_loops);
     match program {
       ProgramRef::Module(m) => m.visit_with(&mut visitor),
       ProgramRef::Script(s) => s.visit_with(&mut visitor),
@@ -55,11 +87,49 @@ impl LintRule for NoConstantCondition {
 
 struct NoConstantConditionVisitor<'c, 'view> {
   context: &'c mut Context<'view>,
+  check_loops: bool,
+  /// The conditions of the loops with a constant condition that are being
+  /// visited, for each function. A loop that contains a `yield` of its
+  /// function isn't reported, since a generator can be stopped at a `yield`.
+  constant_loops: Vec<Vec<SourceRange>>,
 }
 
 impl<'c, 'view: 'c> NoConstantConditionVisitor<'c, 'view> {
-  fn new(context: &'c mut Context<'view>) -> Self {
-    Self { context }
+  fn new(context: &'c mut Context<'view>, check_loops: bool) -> Self {
+    Self {
+      context,
+      check_loops,
+      constant_loops: vec![vec![]],
+    }
+  }
+
+  fn enter_loop(&mut self, test: &Expr) {
+    if self.check_loops && Self::is_constant(test, None, true) {
+      if let Some(loops) = self.constant_loops.last_mut() {
+        loops.push(test.range());
+      }
+    }
+  }
+
+  fn exit_loop(&mut self, test: &Expr) {
+    let range = test.range();
+    let is_tracked = self
+      .constant_loops
+      .last()
+      .and_then(|loops| loops.last())
+      .is_some_and(|last| *last == range);
+    if is_tracked {
+      if let Some(loops) = self.constant_loops.last_mut() {
+        loops.pop();
+      }
+      self.add_diagnostic(range);
+    }
+  }
+
+  fn with_function_scope(&mut self, op: impl FnOnce(&mut Self)) {
+    self.constant_loops.push(vec![]);
+    op(self);
+    self.constant_loops.pop();
   }
 
   fn add_diagnostic(&mut self, range: SourceRange) {
@@ -218,37 +288,46 @@ impl<'c, 'view> Visit for NoConstantConditionVisitor<'c, 'view> {
     if_stmt.visit_children_with(self);
   }
 
-  /* TODO(bartlomieju): temporarly disabled because
-    deno_std uses while (true) {} loops
-  fn visit_while_stmt(
-    &mut self,
-    while_stmt: &WhileStmt,
-
-  ) {
-    self.report(&while_stmt.test)
+  fn visit_while_stmt(&mut self, while_stmt: &WhileStmt) {
+    self.enter_loop(&while_stmt.test);
     while_stmt.visit_children_with(self);
+    self.exit_loop(&while_stmt.test);
   }
 
-  fn visit_do_while_stmt(
-    &mut self,
-    do_while_stmt: &DoWhileStmt,
-
-  ) {
-    self.report(&do_while_stmt.test)
+  fn visit_do_while_stmt(&mut self, do_while_stmt: &DoWhileStmt) {
+    self.enter_loop(&do_while_stmt.test);
     do_while_stmt.visit_children_with(self);
+    self.exit_loop(&do_while_stmt.test);
   }
 
-  fn visit_for_stmt(
-    &mut self,
-    for_stmt: &ForStmt,
+  fn visit_for_stmt(&mut self, for_stmt: &ForStmt) {
+    // A `yield` in the initializer runs before the loop does.
+    for_stmt.init.visit_with(self);
+    if let Some(test) = &for_stmt.test {
+      self.enter_loop(test);
+    }
+    for_stmt.test.visit_with(self);
+    for_stmt.update.visit_with(self);
+    for_stmt.body.visit_with(self);
+    if let Some(test) = &for_stmt.test {
+      self.exit_loop(test);
+    }
+  }
 
-  ) {
-    if let Some(cond) = for_stmt.test.as_ref() {
-      self.report(cond)
+  fn visit_function(&mut self, function: &Function) {
+    self.with_function_scope(|v| function.visit_children_with(v));
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr) {
+    self.with_function_scope(|v| arrow_expr.visit_children_with(v));
+  }
+
+  fn visit_yield_expr(&mut self, yield_expr: &YieldExpr) {
+    if let Some(loops) = self.constant_loops.last_mut() {
+      loops.clear();
     }
-    for_stmt.visit_children_with(self);
+    yield_expr.visit_children_with(self);
   }
-  */
 }
 
 #[cfg(test)]
@@ -258,7 +337,7 @@ mod tests {
   #[test]
   fn no_constant_condition_valid() {
     assert_lint_ok! {
-      NoConstantCondition,
+      NoConstantCondition::default(),
       r#"if(a);"#,
       r#"if(a == 0);"#,
       r#"if(a = f());"#,
@@ -357,7 +436,7 @@ mod tests {
   #[test]
   fn no_constant_condition_invalid() {
     assert_lint_err! {
-      NoConstantCondition,
+      NoConstantCondition::default(),
       r"true ? 1 : 2;": [
         {
           col: 0,
@@ -754,12 +833,62 @@ mod tests {
     };
   }
 
+  #[test]
+  fn no_constant_condition_check_loops_valid() {
+    assert_lint_ok! {
+      NoConstantCondition { check_loops: true },
+      r#"while(a);"#,
+      r#"while(a = b);"#,
+      r#"while(`${a}`);"#,
+      r#"for(;x < 10;);"#,
+      r#"for(;;);"#,
+      r#"do{ }while(x)"#,
+      r#"function* foo(){while(true){yield 'foo';}}"#,
+      r#"function* foo(){for(;true;){yield 'foo';}}"#,
+      r#"function* foo(){do{yield 'foo';}while(true)}"#,
+      r#"function* foo(){while (true) { while(true) {yield;}}}"#,
+      r#"function* foo() {for (; yield; ) {}}"#,
+      r#"function* foo() {for (; ; yield) {}}"#,
+      r#"function* foo() {while (true) {function* foo() {yield;}yield;}}"#,
+      r#"function* foo() { for (let x = yield; x < 10; x++) {yield;}yield;}"#,
+      r#"function* foo() { for (let x = yield; ; x++) { yield; }}"#,
+    };
+  }
+
+  #[test]
+  fn no_constant_condition_check_loops_invalid() {
+    assert_lint_err! {
+      NoConstantCondition { check_loops: true },
+      NoConstantConditionMessage::Unexpected,
+      NoConstantConditionHint::Remove,
+      r#"while(true);"#: [{ col: 6 }],
+      r#"while(x = 1);"#: [{ col: 6 }],
+      r#"while([]);"#: [{ col: 6 }],
+      r#"while(() => {});"#: [{ col: 6 }],
+      r#"for(;true;);"#: [{ col: 5 }],
+      r#"for(;`foo`;);"#: [{ col: 5 }],
+      r#"do{}while(true)"#: [{ col: 10 }],
+      r#"do{}while(t = -2)"#: [{ col: 10 }],
+      r#"while (true) { while (1) {} }"#: [{ col: 7 }, { col: 22 }],
+      r#"function* foo(){while(true){} yield 'foo';}"#: [{ col: 22 }],
+      r#"function* foo(){while(true){if (true) {yield 'foo';}}}"#: [{ col: 32 }],
+      r#"function* foo(){while(true){yield 'foo';} while(true) {}}"#: [{ col: 48 }],
+      r#"var a = function* foo(){while(true){} yield 'foo';}"#: [{ col: 30 }],
+      r#"while (true) { function* foo() {yield;}}"#: [{ col: 7 }],
+      r#"function* foo() {for (let foo = yield; true;) {}}"#: [{ col: 39 }],
+      r#"function* foo() {for (foo = yield; true;) {}}"#: [{ col: 35 }],
+      r#"function foo() {while (true) {function* bar() {while (true) {yield;}}}}"#: [{ col: 23 }],
+      r#"function foo() {while (true) {const bar = function*() {while (true) {yield;}}}}"#: [{ col: 23 }],
+      r#"function* foo() { for (let foo = 1 + 2 + 3 + (yield); true; baz) {}}"#: [{ col: 54 }],
+    };
+  }
+
   // TODO(humancalico) make these tests pass
   #[test]
   #[ignore]
   fn should_pass_valid() {
     assert_lint_ok! {
-      NoConstantCondition,
+      NoConstantCondition::default(),
 
       // TODO(humancalico) more conditions should be added to pass these cases
       // https://github.com/eslint/eslint/blob/f4d7b9e1a599346b2f21ff9de003b311b51411e6/lib/rules/no-constant-condition.js#L135-L146
@@ -773,21 +902,6 @@ mod tests {
       r#"if(abc==='str' || false || def ==='str'){}"#,
       r#"if(true && abc==='str' || def ==='str'){}"#,
       r#"if(true && typeof abc==='string'){}"#,
-
-      // TODO(humancalico) add a configuration option for { checkLoops: false }
-      // https://eslint.org/docs/rules/no-constant-condition#checkloops
-      r#"while(true);"#,
-      r#"for(;true;);"#,
-      r#"do{}while(true)"#,
-      r#"function* foo(){while(true){yield 'foo';}}"#,
-      r#"function* foo(){for(;true;){yield 'foo';}}"#,
-      r#"function* foo(){do{yield 'foo';}while(true)}"#,
-      r#"function* foo(){while (true) { while(true) {yield;}}}"#,
-      r#"function* foo() {for (; yield; ) {}}"#,
-      r#"function* foo() {for (; ; yield) {}}"#,
-      r#"function* foo() {while (true) {function* foo() {yield;}yield;}}"#,
-      r#"function* foo() { for (let x = yield; x < 10; x++) {yield;}yield;}"#,
-      r#"function* foo() { for (let x = yield; ; x++) { yield; }}"#,
     };
   }
 
@@ -796,7 +910,7 @@ mod tests {
   #[ignore]
   fn should_pass_invalid() {
     assert_lint_err! {
-      NoConstantCondition,
+      NoConstantCondition::default(),
 
       // TODO(humancalico) more conditions should be added to pass these cases
       // https://github.com/eslint/eslint/blob/f4d7b9e1a599346b2f21ff9de003b311b51411e6/lib/rules/no-constant-condition.js#L135-L146
@@ -814,83 +928,6 @@ mod tests {
           hint: NoConstantConditionHint::Remove,
         }
       ],
-      r#"function* foo(){while(true){} yield 'foo';}"#: [
-        {
-          col: 0,
-          message: NoConstantConditionMessage::Unexpected,
-          hint: NoConstantConditionHint::Remove,
-        }
-      ],
-      r#"function* foo(){while(true){if (true) {yield 'foo';}}}"#: [
-        {
-          col: 0,
-          message: NoConstantConditionMessage::Unexpected,
-          hint: NoConstantConditionHint::Remove,
-        }
-      ],
-      r#"function* foo(){while(true){yield 'foo';} while(true) {}}"#: [
-        {
-          col: 0,
-          message: NoConstantConditionMessage::Unexpected,
-          hint: NoConstantConditionHint::Remove,
-        }
-      ],
-      r#"var a = function* foo(){while(true){} yield 'foo';}"#: [
-        {
-          col: 0,
-          message: NoConstantConditionMessage::Unexpected,
-          hint: NoConstantConditionHint::Remove,
-        }
-      ],
-      r#"while (true) { function* foo() {yield;}}"#: [
-        {
-          col: 0,
-          message: NoConstantConditionMessage::Unexpected,
-          hint: NoConstantConditionHint::Remove,
-        }
-      ],
-      r#"function* foo(){if (true) {yield 'foo';}}"#: [
-        {
-          col: 0,
-          message: NoConstantConditionMessage::Unexpected,
-          hint: NoConstantConditionHint::Remove,
-        }
-      ],
-      r#"function* foo() {for (let foo = yield; true;) {}}"#: [
-        {
-          col: 0,
-          message: NoConstantConditionMessage::Unexpected,
-          hint: NoConstantConditionHint::Remove,
-        }
-      ],
-      r#"function* foo() {for (foo = yield; true;) {}}"#: [
-        {
-          col: 0,
-          message: NoConstantConditionMessage::Unexpected,
-          hint: NoConstantConditionHint::Remove,
-        }
-      ],
-      r#"function foo() {while (true) {function* bar() {while (true) {yield;}}}}"#: [
-        {
-          col: 0,
-          message: NoConstantConditionMessage::Unexpected,
-          hint: NoConstantConditionHint::Remove,
-        }
-      ],
-      r#"function foo() {while (true) {const bar = function*() {while (true) {yield;}}}}"#: [
-        {
-          col: 0,
-          message: NoConstantConditionMessage::Unexpected,
-          hint: NoConstantConditionHint::Remove,
-        }
-      ],
-      r#"function* foo() { for (let foo = 1 + 2 + 3 + (yield); true; baz) {}}"#: [
-        {
-          col: 0,
-          message: NoConstantConditionMessage::Unexpected,
-          hint: NoConstantConditionHint::Remove,
-        }
-      ],
       r#"if ([,] + ''){}"#: [
         {
           col: 0,
@@ -2,14 +2,13 @@
 
 use super::{Context, LintRule};
 use crate::handler::{Handler, Traverse};
-use crate::swc_util::extract_regex;
+use crate::js_regex::{EcmaRegexValidator, RegexCharacter};
+use crate::swc_util::{extract_regex, extract_regex_flags};
 use crate::tags::{self, Tags};
 use crate::Program;
 use deno_ast::view::{CallExpr, Callee, Expr, NewExpr, Regex};
 use deno_ast::{SourceRange, SourceRanged};
 use derive_more::Display;
-use std::iter::Peekable;
-use std::str::Chars;
 
 #[derive(Debug)]
 pub struct NoControlRegex;
@@ -62,73 +61,49 @@ fn add_diagnostic(range: SourceRange, cp: u64, ctx: &mut Context) {
   );
 }
 
-fn check_regex(regex: &str, range: SourceRange, ctx: &mut Context) {
-  let mut iter = regex.chars().peekable();
-  while let Some(ch) = iter.next() {
-    if ch != '\\' {
-      continue;
-    }
-    match iter.next() {
-      Some('x') => {
-        if let Some(cp) = read_hex_n(&mut iter, 2) {
-          if cp <= 31 {
-            add_diagnostic(range, cp, ctx);
-            return;
-          }
-        }
-      }
-      Some('u') => {
-        let cp = match iter.peek() {
-          Some(&'{') => read_hex_until_brace(&mut iter),
-          Some(_) => read_hex_n(&mut iter, 4),
-          _ => None,
-        };
-        if let Some(cp) = cp {
-          if cp <= 31 {
-            add_diagnostic(range, cp, ctx);
-            return;
-          }
-        }
-      }
-      _ => continue,
-    }
+fn check_regex(
+  regex: &str,
+  flags: Option<&str>,
+  range: SourceRange,
+  ctx: &mut Context,
+) {
+  let u_flag = flags.is_some_and(|flags| flags.contains(['u', 'v']));
+  let mut validator = EcmaRegexValidator::new(ctx.ecma_version());
+  // Invalid patterns are reported by `no-invalid-regexp`.
+  let Ok(characters) = validator.parse_characters(regex, u_flag) else {
+    return;
+  };
+  if let Some(character) = characters.iter().find(|c| is_control(c)) {
+    add_diagnostic(range, character.value as u64, ctx);
   }
 }
 
-/// Read the next n characters and try to parse it as hexadecimal.
-fn read_hex_n(iter: &mut Peekable<Chars>, n: usize) -> Option<u64> {
-  let mut s = String::new();
-  for _ in 0..n {
-    let ch = iter.next()?;
-    s.push(ch);
-  }
-  u64::from_str_radix(s.as_str(), 16).ok()
-}
-
-/// Read characters until `}` and try to parse it as hexadecimal.
-fn read_hex_until_brace(iter: &mut Peekable<Chars>) -> Option<u64> {
-  iter.next(); // consume `{`
-  let mut s = String::new();
-  loop {
-    let ch = iter.next()?;
-    if ch == '}' {
-      break;
-    }
-    s.push(ch);
-  }
-  u64::from_str_radix(s.as_str(), 16).ok()
+/// Checks if the character is a control character written as is or with a
+/// hexadecimal escape, like `\x1f` or `\u001f`. Escapes like `\n` and `\t`
+/// are allowed, since they are clearly intended.
+fn is_control(character: &RegexCharacter) -> bool {
+  character.value <= 0x1f
+    && (character.raw.starts_with("\\x")
+      || character.raw.starts_with("\\u")
+      || character.raw.chars().eq(char::from_u32(character.value)))
 }
 
 impl Handler for NoControlRegexHandler {
   fn regex(&mut self, regex: &Regex, ctx: &mut Context) {
-    check_regex(regex.inner.exp.to_string().as_str(), regex.range(), ctx);
+    check_regex(
+      &regex.inner.exp,
+      Some(&regex.inner.flags),
+      regex.range(),
+      ctx,
+    );
   }
 
   fn new_expr(&mut self, new_expr: &NewExpr, ctx: &mut Context) {
     if let Expr::Ident(ident) = new_expr.callee {
       if let Some(args) = &new_expr.args {
         if let Some(regex) = extract_regex(ctx.scope(), ident, args) {
-          check_regex(regex.as_str(), new_expr.range(), ctx);
+          let flags = extract_regex_flags(args);
+          check_regex(&regex, flags.as_deref(), new_expr.range(), ctx);
         }
       }
     }
@@ -137,7 +112,8 @@ impl Handler for NoControlRegexHandler {
   fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
     if let Callee::Expr(Expr::Ident(ident)) = &call_expr.callee {
       if let Some(regex) = extract_regex(ctx.scope(), ident, call_expr.args) {
-        check_regex(regex.as_str(), call_expr.range(), ctx);
+        let flags = extract_regex_flags(call_expr.args);
+        check_regex(&regex, flags.as_deref(), call_expr.range(), ctx);
       }
     }
   }
@@ -147,44 +123,6 @@ impl Handler for NoControlRegexHandler {
 mod tests {
   use super::*;
 
-  #[test]
-  fn test_read_hex_n() {
-    let tests = [
-      (r#"1f"#, Some(0x1f)),
-      (r#"001f"#, Some(0x1f)),
-      (r#"1g"#, None),
-      (r#"001g"#, None),
-      (r#"1ff"#, Some(0x1ff)),
-      (r#"abcd"#, Some(0xabcd)),
-    ];
-
-    for &(input, expected) in tests.iter() {
-      assert_eq!(
-        read_hex_n(&mut input.chars().peekable(), input.len()),
-        expected
-      );
-    }
-  }
-
-  #[test]
-  fn test_read_hex_until_brace() {
-    let tests = [
-      (r#"{1f}"#, Some(0x1f)),
-      (r#"{001f}"#, Some(0x1f)),
-      (r#"{1g}"#, None),
-      (r#"{001g}"#, None),
-      (r#"{1ff}"#, Some(0x1ff)),
-      (r#"{abcd}"#, Some(0xabcd)),
-    ];
-
-    for &(input, expected) in tests.iter() {
-      assert_eq!(
-        read_hex_until_brace(&mut input.chars().peekable()),
-        expected,
-      );
-    }
-  }
-
   #[test]
   fn no_control_regex_valid() {
     assert_lint_ok! {
@@ -202,6 +140,11 @@ mod tests {
       r"new RegExp('[')",
       r"RegExp('[')",
       r"new (function foo(){})('\\x1f')",
+      // Without the `u` flag, `\u` is an escaped `u`.
+      r"/\u{001f}/",
+      r"/\t\n\r/",
+      r"/\cA/",
+      r"new RegExp('\\t')",
     };
   }
 
@@ -223,14 +166,14 @@ mod tests {
           hint: NoControlRegexHint::DisableOrRework,
         }
       ],
-      r"/\u{001f}/": [
+      r"/\u{001f}/u": [
         {
           col: 0,
           message: NoControlRegexMessage::Unexpected(0x1f),
           hint: NoControlRegexHint::DisableOrRework,
         }
       ],
-      r"/\u{0001f}/": [
+      r"/\u{0001f}/v": [
         {
           col: 0,
           message: NoControlRegexMessage::Unexpected(0x1f),
@@ -285,6 +228,28 @@ mod tests {
           message: NoControlRegexMessage::Unexpected(0x1f),
           hint: NoControlRegexHint::DisableOrRework,
         }
+      ],
+      r"RegExp('\\u{1f}', 'u')": [
+        {
+          col: 0,
+          message: NoControlRegexMessage::Unexpected(0x1f),
+          hint: NoControlRegexHint::DisableOrRework,
+        }
+      ],
+      r"/[a\x0c-z]/": [
+        {
+          col: 0,
+          message: NoControlRegexMessage::Unexpected(0xc),
+          hint: NoControlRegexHint::DisableOrRework,
+        }
+      ],
+      // The string contains the control character itself.
+      r"new RegExp('\x1b')": [
+        {
+          col: 0,
+          message: NoControlRegexMessage::Unexpected(0x1b),
+          hint: NoControlRegexHint::DisableOrRework,
+        }
       ]
     };
   }
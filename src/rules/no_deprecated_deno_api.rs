@@ -1,16 +1,22 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
+use super::program_ref;
 use super::Context;
 use super::LintRule;
+use super::RuleDocs;
 use crate::handler::Handler;
 use crate::handler::Traverse;
 use crate::tags;
 use crate::tags::Tags;
 use crate::Program;
+use crate::ProgramRef;
 
+use deno_ast::swc::ast::{Id, TsModuleDecl, TsModuleName};
+use deno_ast::swc::visit::{noop_visit_type, Visit, VisitWith};
 use deno_ast::view as ast_view;
 use deno_ast::SourceRanged;
 use if_chain::if_chain;
+use std::collections::HashSet;
 use std::convert::TryFrom;
 
 #[derive(Debug)]
@@ -20,19 +26,69 @@ const CODE: &str = "no-deprecated-deno-api";
 
 impl LintRule for NoDeprecatedDenoApi {
   fn tags(&self) -> Tags {
-    &[tags::RECOMMENDED]
+    &[tags::RECOMMENDED, tags::DENO]
   }
 
   fn code(&self) -> &'static str {
     CODE
   }
 
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Warns on usage of `Deno.*` APIs that were deprecated and \
+        removed in Deno 2, and suggests their replacement. Only applies to \
+        code running in Deno, which is why the rule is tagged `deno`.",
+      invalid_examples: &[
+        "const p = Deno.run({ cmd: [\"ls\"] });",
+        "await Deno.copy(reader, writer);",
+        "let file: Deno.File;",
+        "function read(r: Deno.Reader) {}",
+      ],
+      valid_examples: &[
+        "const p = new Deno.Command(\"ls\").spawn();",
+        "let file: Deno.FsFile;",
+      ],
+    }
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
     program: Program<'_>,
   ) {
-    NoDeprecatedDenoApiHandler.traverse(program, context);
+    let mut collector = DenoNamespaceCollector::default();
+    match program_ref(program) {
+      ProgramRef::Module(m) => m.visit_with(&mut collector),
+      ProgramRef::Script(s) => s.visit_with(&mut collector),
+    }
+    NoDeprecatedDenoApiHandler {
+      local_namespaces: collector.namespaces,
+    }
+    .traverse(program, context);
+  }
+}
+
+/// Collects the `Deno` namespaces declared in the file. They shadow the
+/// global `Deno` namespace, but aren't bindings of the scope analyzer.
+#[derive(Default)]
+struct DenoNamespaceCollector {
+  namespaces: HashSet<Id>,
+}
+
+impl Visit for DenoNamespaceCollector {
+  noop_visit_type!();
+
+  fn visit_ts_module_decl(&mut self, decl: &TsModuleDecl) {
+    // Namespaces in `declare global {}` augment the global ones.
+    if decl.global {
+      return;
+    }
+    if let TsModuleName::Ident(ident) = &decl.id {
+      if ident.sym == *"Deno" {
+        self.namespaces.insert(ident.to_id());
+      }
+    }
+    decl.visit_children_with(self);
   }
 }
 
@@ -61,6 +117,7 @@ fn extract_symbol<'a>(
 enum DeprecatedApi {
   Buffer,
   Close,
+  Closer,
   Copy,
   CustomInspect,
   Fdatasync,
@@ -86,16 +143,22 @@ enum DeprecatedApi {
   ReadSync,
   ReadAll,
   ReadAllSync,
+  Reader,
+  ReaderSync,
   Resources,
   Run,
   Seek,
   SeekSync,
+  Seeker,
+  SeekerSync,
   ServeHttp,
   Shutdown,
   Write,
   WriteSync,
   WriteAll,
   WriteAllSync,
+  Writer,
+  WriterSync,
 }
 
 impl TryFrom<(&str, &str)> for DeprecatedApi {
@@ -115,6 +178,7 @@ impl TryFrom<(&str, &str)> for DeprecatedApi {
     match prop_symbol {
       "Buffer" => Ok(DeprecatedApi::Buffer),
       "close" => Ok(DeprecatedApi::Close),
+      "Closer" => Ok(DeprecatedApi::Closer),
       "copy" => Ok(DeprecatedApi::Copy),
       "customInspect" => Ok(DeprecatedApi::CustomInspect),
       "fdatasync" => Ok(DeprecatedApi::Fdatasync),
@@ -140,16 +204,22 @@ impl TryFrom<(&str, &str)> for DeprecatedApi {
       "readSync" => Ok(DeprecatedApi::ReadSync),
       "readAll" => Ok(DeprecatedApi::ReadAll),
       "readAllSync" => Ok(DeprecatedApi::ReadAllSync),
+      "Reader" => Ok(DeprecatedApi::Reader),
+      "ReaderSync" => Ok(DeprecatedApi::ReaderSync),
       "resources" => Ok(DeprecatedApi::Resources),
       "run" => Ok(DeprecatedApi::Run),
       "seek" => Ok(DeprecatedApi::Seek),
       "seekSync" => Ok(DeprecatedApi::SeekSync),
+      "Seeker" => Ok(DeprecatedApi::Seeker),
+      "SeekerSync" => Ok(DeprecatedApi::SeekerSync),
       "serveHttp" => Ok(DeprecatedApi::ServeHttp),
       "shutdown" => Ok(DeprecatedApi::Shutdown),
       "write" => Ok(DeprecatedApi::Write),
       "writeSync" => Ok(DeprecatedApi::WriteSync),
       "writeAll" => Ok(DeprecatedApi::WriteAll),
       "writeAllSync" => Ok(DeprecatedApi::WriteAllSync),
+      "Writer" => Ok(DeprecatedApi::Writer),
+      "WriterSync" => Ok(DeprecatedApi::WriterSync),
       _ => Err(()),
     }
   }
@@ -190,6 +260,7 @@ impl DeprecatedApi {
       Buffer => "Deno.Buffer",
       Copy => "Deno.copy",
       Close => "Deno.close",
+      Closer => "Deno.Closer",
       CustomInspect => "Deno.customInspect",
       Fdatasync => "Deno.fdatasync",
       FdatasyncSync => "Deno.fdatasyncSync",
@@ -214,16 +285,22 @@ impl DeprecatedApi {
       ReadSync => "Deno.readSync",
       ReadAll => "Deno.readAll",
       ReadAllSync => "Deno.readAllSync",
+      Reader => "Deno.Reader",
+      ReaderSync => "Deno.ReaderSync",
       Resources => "Deno.resources",
       Run => "Deno.run",
       Seek => "Deno.seek",
       SeekSync => "Deno.seekSync",
+      Seeker => "Deno.Seeker",
+      SeekerSync => "Deno.SeekerSync",
       ServeHttp => "Deno.serveHttp",
       Shutdown => "Deno.shutdown",
       Write => "Deno.write",
       WriteSync => "Deno.writeSync",
       WriteAll => "Deno.writeAll",
       WriteAllSync => "Deno.writeAllSync",
+      Writer => "Deno.Writer",
+      WriterSync => "Deno.WriterSync",
     }
   }
 
@@ -235,6 +312,9 @@ impl DeprecatedApi {
         NameAndUrl("Buffer", "https://jsr.io/@std/io/doc/buffer/~/Buffer")
       }
       Close => Method(".close()"),
+      Closer => {
+        NameAndUrl("Closer", "https://jsr.io/@std/io/doc/types/~/Closer")
+      }
       Copy => NameAndUrl("copy()", "https://jsr.io/@std/io/doc/copy/~/copy"),
       CustomInspect => Name("Symbol.for(\"Deno.customInspect\")"),
       Fdatasync => Method(".syncData()"),
@@ -271,10 +351,24 @@ impl DeprecatedApi {
         "readAllSync()",
         "https://jsr.io/@std/io/doc/read-all/~/readAllSync",
       ),
+      Reader => {
+        NameAndUrl("Reader", "https://jsr.io/@std/io/doc/types/~/Reader")
+      }
+      ReaderSync => NameAndUrl(
+        "ReaderSync",
+        "https://jsr.io/@std/io/doc/types/~/ReaderSync",
+      ),
       Resources => None,
       Run => NameAndUrl("Deno.Command", "https://deno.land/api?s=Deno.Command"),
       Seek => Method(".seek()"),
       SeekSync => Method(".seekSync()"),
+      Seeker => {
+        NameAndUrl("Seeker", "https://jsr.io/@std/io/doc/types/~/Seeker")
+      }
+      SeekerSync => NameAndUrl(
+        "SeekerSync",
+        "https://jsr.io/@std/io/doc/types/~/SeekerSync",
+      ),
       ServeHttp => Name("Deno.serve()"),
       Shutdown => Method(".closeWrite()"),
       Write => Method(".write()"),
@@ -287,11 +381,26 @@ impl DeprecatedApi {
         "writeAllSync",
         "https://jsr.io/@std/io/doc/write-all/~/writeAllSync",
       ),
+      Writer => {
+        NameAndUrl("Writer", "https://jsr.io/@std/io/doc/types/~/Writer")
+      }
+      WriterSync => NameAndUrl(
+        "WriterSync",
+        "https://jsr.io/@std/io/doc/types/~/WriterSync",
+      ),
     }
   }
 }
 
-struct NoDeprecatedDenoApiHandler;
+struct NoDeprecatedDenoApiHandler {
+  local_namespaces: HashSet<Id>,
+}
+
+impl NoDeprecatedDenoApiHandler {
+  fn is_global(&self, id: &Id, ctx: &Context) -> bool {
+    ctx.scope().is_global(id) && !self.local_namespaces.contains(id)
+  }
+}
 
 impl Handler for NoDeprecatedDenoApiHandler {
   fn member_expr(
@@ -307,7 +416,7 @@ impl Handler for NoDeprecatedDenoApiHandler {
     use deno_ast::view::Expr;
     if_chain! {
       if let Expr::Ident(obj) = &member_expr.obj;
-      if ctx.scope().is_global(&obj.inner.to_id());
+      if self.is_global(&obj.inner.to_id(), ctx);
       let obj_symbol: &str = obj.sym();
       if let Some(prop_symbol) = extract_symbol(&member_expr.prop);
       if let Ok(deprecated_api) = DeprecatedApi::try_from((obj_symbol, prop_symbol));
@@ -336,16 +445,17 @@ impl Handler for NoDeprecatedDenoApiHandler {
   ) {
     if_chain! {
       if let ast_view::TsEntityName::Ident(ident) = qualified_name.left;
-      if ident.sym() == "Deno";
-      if qualified_name.right.sym() == "File";
-      if ctx.scope().is_global(&ident.inner.to_id());
+      if self.is_global(&ident.inner.to_id(), ctx);
+      let obj_symbol: &str = ident.sym();
+      let prop_symbol: &str = qualified_name.right.sym();
+      if let Ok(deprecated_api) = DeprecatedApi::try_from((obj_symbol, prop_symbol));
+      if let Some(hint) = deprecated_api.hint();
       then {
-        let deprecated_api = DeprecatedApi::File;
         ctx.add_diagnostic_with_hint(
           qualified_name.range(),
           CODE,
           deprecated_api.message(),
-          deprecated_api.hint().unwrap(),
+          hint,
         );
       }
     }
@@ -560,6 +670,9 @@ mod tests {
 
       // types
       r#"interface Deno {} let file: Deno.File;"#,
+      r#"namespace Deno { export interface Reader {} } let r: Deno.Reader;"#,
+      r#"namespace Deno { export function run() {} } Deno.run();"#,
+      "let r: Deno.FsFile;",
     };
   }
 
@@ -646,6 +759,25 @@ mod tests {
           hint: File.hint().unwrap()
         }
       ],
+      "function read(r: Deno.Reader) {}": [
+        {
+          col: 17,
+          message: Reader.message(),
+          hint: Reader.hint().unwrap()
+        }
+      ],
+      "let w: Deno.WriterSync & Deno.Closer;": [
+        {
+          col: 7,
+          message: WriterSync.message(),
+          hint: WriterSync.hint().unwrap()
+        },
+        {
+          col: 25,
+          message: Closer.message(),
+          hint: Closer.hint().unwrap()
+        }
+      ],
       "Deno.run(options);": [
         {
           col: 0,
@@ -845,6 +977,14 @@ Deno.readAll(reader);
         "Use `.syncDataSync()` from the given class instance instead",
       ),
       ("File", "Use `Deno.FsFile` instead"),
+      (
+        "Reader",
+        "Use `Reader` from https://jsr.io/@std/io/doc/types/~/Reader instead",
+      ),
+      (
+        "SeekerSync",
+        "Use `SeekerSync` from https://jsr.io/@std/io/doc/types/~/SeekerSync instead",
+      ),
       ("flock", "Use `.lock()` from the given class instance instead"),
       (
         "flockSync",
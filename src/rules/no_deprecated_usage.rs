@@ -0,0 +1,567 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{program_ref, Context, LintRule, RuleDocs};
+use crate::swc_util::StringRepr;
+use crate::tags::Tags;
+use crate::Program;
+use crate::ProgramRef;
+use deno_ast::swc::ast::{
+  Class, ClassDecl, ClassExpr, ClassMember, ClassMethod, Decl, DefaultDecl,
+  ExportDecl, ExportDefaultDecl, ExportNamedSpecifier, Expr, Function, Id,
+  Ident, MemberExpr, MemberProp, PrivateMethod, Stmt, SuperProp, SuperPropExpr,
+  TsModuleName,
+};
+use deno_ast::swc::utils::find_pat_ids;
+use deno_ast::swc::visit::{Visit, VisitWith};
+use deno_ast::{SourcePos, SourceRange, SourceRangedForSpanned};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug)]
+pub struct NoDeprecatedUsage;
+
+const CODE: &str = "no-deprecated-usage";
+const DEFAULT_HINT: &str =
+  "Check the documentation of the declaration for an alternative";
+
+impl LintRule for NoDeprecatedUsage {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows references to declarations that are marked \
+        as deprecated with a `@deprecated` JSDoc tag, showing the text of the \
+        tag as a hint. Only declarations of the same file are taken into \
+        account, i.e. functions, classes, variables, interfaces, type \
+        aliases, enums and namespaces. Deprecated methods of classes are \
+        reported where they are accessed on `this` or `super` in the class \
+        and its subclasses, on the class itself for static methods, and on \
+        `new` expressions of the class. Deprecated overload signatures are \
+        ignored, since they only apply to some of the calls.",
+      invalid_examples: &[
+        "/** @deprecated Use `fetchUser` instead. */\nfunction getUser(id) {}\n\ngetUser(1);",
+        "/** @deprecated */\ninterface Options {}\n\nconst options: Options = {};",
+        "class Api {\n  /** @deprecated Use `fetch` instead. */\n  static get(url) {}\n}\n\nApi.get(\"/users\");",
+      ],
+      valid_examples: &[
+        "/** @deprecated Use `fetchUser` instead. */\nfunction getUser(id) {}\n\nfetchUser(1);",
+        "/** Fetches a user. */\nfunction fetchUser(id) {}\n\nfetchUser(1);",
+      ],
+    }
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    let program = program_ref(program);
+    let mut collector = DeprecationCollector {
+      context,
+      deprecations: HashMap::new(),
+      member_deprecations: HashMap::new(),
+      declarations: HashSet::new(),
+    };
+    visit_program(program, &mut collector);
+    if collector.deprecations.is_empty()
+      && collector.member_deprecations.is_empty()
+    {
+      return;
+    }
+
+    let mut finder = UsageFinder {
+      deprecations: collector.deprecations,
+      member_deprecations: collector.member_deprecations,
+      declarations: collector.declarations,
+      class: ClassScope::default(),
+      usages: vec![],
+    };
+    visit_program(program, &mut finder);
+    for (range, name, hint) in finder.usages {
+      let hint = if hint.is_empty() {
+        DEFAULT_HINT.to_string()
+      } else {
+        hint
+      };
+      context.add_diagnostic_with_hint(
+        range,
+        CODE,
+        format!("`{}` is deprecated", name),
+        hint,
+      );
+    }
+  }
+}
+
+fn visit_program<V: Visit>(program: ProgramRef, visitor: &mut V) {
+  match program {
+    ProgramRef::Module(m) => m.visit_with(visitor),
+    ProgramRef::Script(s) => s.visit_with(visitor),
+  }
+}
+
+/// A deprecated method, keyed by the class and the name of the method, which
+/// starts with `#` for private methods.
+type MemberKey = (Id, String);
+
+struct MemberDeprecation {
+  hint: String,
+  is_static: bool,
+}
+
+/// Collects the declarations with a `@deprecated` tag, along with the text of
+/// the tag.
+struct DeprecationCollector<'a, 'view> {
+  context: &'a Context<'view>,
+  deprecations: HashMap<Id, String>,
+  member_deprecations: HashMap<MemberKey, MemberDeprecation>,
+  /// The names of all declarations, which are not usages of themselves.
+  declarations: HashSet<SourceRange>,
+}
+
+impl<'a, 'view> DeprecationCollector<'a, 'view> {
+  fn check_decl(&mut self, decl: &Decl, comment_pos: SourcePos) {
+    let ids: Vec<Ident> = match decl {
+      Decl::Fn(fn_decl) => {
+        // The body is missing in overload signatures, but also in ambient
+        // declarations, which aren't overloads.
+        if fn_decl.function.body.is_none() && !fn_decl.declare {
+          vec![]
+        } else {
+          vec![fn_decl.ident.clone()]
+        }
+      }
+      Decl::Class(class_decl) => {
+        self.check_members(
+          &class_decl.ident,
+          &class_decl.class,
+          class_decl.declare,
+        );
+        vec![class_decl.ident.clone()]
+      }
+      Decl::Var(var_decl) => var_decl
+        .decls
+        .iter()
+        .flat_map(|decl| find_pat_ids::<_, Ident>(&decl.name))
+        .collect(),
+      Decl::Using(using_decl) => using_decl
+        .decls
+        .iter()
+        .flat_map(|decl| find_pat_ids::<_, Ident>(&decl.name))
+        .collect(),
+      Decl::TsInterface(interface) => vec![interface.id.clone()],
+      Decl::TsTypeAlias(alias) => vec![alias.id.clone()],
+      Decl::TsEnum(ts_enum) => vec![ts_enum.id.clone()],
+      Decl::TsModule(module) => match &module.id {
+        TsModuleName::Ident(ident) => vec![ident.clone()],
+        TsModuleName::Str(_) => vec![],
+      },
+    };
+    self.record(&ids, comment_pos);
+  }
+
+  fn record(&mut self, idents: &[Ident], comment_pos: SourcePos) {
+    if idents.is_empty() {
+      return;
+    }
+    for ident in idents {
+      self.declarations.insert(ident.range());
+    }
+    let Some(hint) = self.deprecation_hint(comment_pos) else {
+      return;
+    };
+    for ident in idents {
+      self.deprecations.insert(ident.to_id(), hint.clone());
+    }
+  }
+
+  fn check_members(
+    &mut self,
+    class_ident: &Ident,
+    class: &Class,
+    declare: bool,
+  ) {
+    for member in &class.body {
+      let (name, start, is_static, is_abstract, function) = match member {
+        ClassMember::Method(method) => {
+          let Some(name) = method.key.string_repr() else {
+            continue;
+          };
+          (
+            name,
+            method.start(),
+            method.is_static,
+            method.is_abstract,
+            &method.function,
+          )
+        }
+        ClassMember::PrivateMethod(method) => (
+          format!("#{}", method.key.name),
+          method.start(),
+          method.is_static,
+          method.is_abstract,
+          &method.function,
+        ),
+        _ => continue,
+      };
+      // Like for functions, overload signatures have no body.
+      if function.body.is_none() && !declare && !is_abstract {
+        continue;
+      }
+      if let Some(hint) = self.deprecation_hint(start) {
+        self.member_deprecations.insert(
+          (class_ident.to_id(), name),
+          MemberDeprecation { hint, is_static },
+        );
+      }
+    }
+  }
+
+  /// Returns the text of the `@deprecated` tag of the JSDoc comment before
+  /// `comment_pos`, if there is one.
+  fn deprecation_hint(&self, comment_pos: SourcePos) -> Option<String> {
+    let deprecated = self
+      .context
+      .leading_jsdoc_at(comment_pos)?
+      .tags
+      .into_iter()
+      .find(|tag| tag.name == "deprecated")?;
+    Some(
+      deprecated
+        .description
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" "),
+    )
+  }
+}
+
+impl<'a, 'view> Visit for DeprecationCollector<'a, 'view> {
+  fn visit_stmt(&mut self, stmt: &Stmt) {
+    if let Stmt::Decl(decl) = stmt {
+      self.check_decl(decl, decl.start());
+    }
+    stmt.visit_children_with(self);
+  }
+
+  fn visit_export_decl(&mut self, export_decl: &ExportDecl) {
+    self.check_decl(&export_decl.decl, export_decl.start());
+    export_decl.visit_children_with(self);
+  }
+
+  fn visit_export_default_decl(&mut self, export_decl: &ExportDefaultDecl) {
+    let ident = match &export_decl.decl {
+      DefaultDecl::Fn(fn_expr) => fn_expr.ident.as_ref(),
+      DefaultDecl::Class(class_expr) => {
+        if let Some(ident) = &class_expr.ident {
+          self.check_members(ident, &class_expr.class, false);
+        }
+        class_expr.ident.as_ref()
+      }
+      DefaultDecl::TsInterfaceDecl(interface) => Some(&interface.id),
+    };
+    if let Some(ident) = ident {
+      self.record(&[ident.clone()], export_decl.start());
+    }
+    export_decl.visit_children_with(self);
+  }
+}
+
+/// The classes that `this` and `super` refer to at a position.
+#[derive(Clone, Default)]
+struct ClassScope {
+  this: Option<Id>,
+  super_class: Option<Id>,
+}
+
+struct UsageFinder {
+  deprecations: HashMap<Id, String>,
+  member_deprecations: HashMap<MemberKey, MemberDeprecation>,
+  declarations: HashSet<SourceRange>,
+  class: ClassScope,
+  usages: Vec<(SourceRange, String, String)>,
+}
+
+impl UsageFinder {
+  fn check_member(
+    &mut self,
+    class: Option<&Id>,
+    name: Option<String>,
+    range: SourceRange,
+    is_static: Option<bool>,
+  ) {
+    let (Some(class), Some(name)) = (class, name) else {
+      return;
+    };
+    let Some(deprecation) =
+      self.member_deprecations.get(&(class.clone(), name.clone()))
+    else {
+      return;
+    };
+    if is_static.map_or(true, |is_static| is_static == deprecation.is_static) {
+      self.usages.push((range, name, deprecation.hint.clone()));
+    }
+  }
+
+  fn visit_class_with_scope(&mut self, class: &Class, this: Option<Id>) {
+    let super_class = match class.super_class.as_deref() {
+      Some(Expr::Ident(ident)) => Some(ident.to_id()),
+      _ => None,
+    };
+    class.super_class.visit_with(self);
+    let scope =
+      std::mem::replace(&mut self.class, ClassScope { this, super_class });
+    class.decorators.visit_with(self);
+    class.body.visit_with(self);
+    self.class = scope;
+  }
+}
+
+impl Visit for UsageFinder {
+  fn visit_ident(&mut self, ident: &Ident) {
+    let Some(hint) = self.deprecations.get(&ident.to_id()) else {
+      return;
+    };
+    if self.declarations.contains(&ident.range()) {
+      return;
+    }
+    self
+      .usages
+      .push((ident.range(), ident.sym.to_string(), hint.clone()));
+  }
+
+  fn visit_member_expr(&mut self, member: &MemberExpr) {
+    let name = match &member.prop {
+      MemberProp::PrivateName(name) => Some(format!("#{}", name.name)),
+      prop => prop.string_repr(),
+    };
+    let range = member.prop.range();
+    match &*member.obj {
+      Expr::This(_) => {
+        let class = self.class.this.clone();
+        self.check_member(class.as_ref(), name, range, None);
+      }
+      Expr::Ident(ident) => {
+        self.check_member(Some(&ident.to_id()), name, range, Some(true));
+      }
+      Expr::New(new_expr) => {
+        if let Expr::Ident(ident) = &*new_expr.callee {
+          self.check_member(Some(&ident.to_id()), name, range, Some(false));
+        }
+      }
+      _ => {}
+    }
+    member.visit_children_with(self);
+  }
+
+  fn visit_super_prop_expr(&mut self, super_prop: &SuperPropExpr) {
+    if let SuperProp::Ident(ident) = &super_prop.prop {
+      let class = self.class.super_class.clone();
+      self.check_member(
+        class.as_ref(),
+        Some(ident.sym.to_string()),
+        ident.range(),
+        None,
+      );
+    }
+    super_prop.visit_children_with(self);
+  }
+
+  fn visit_class_decl(&mut self, class_decl: &ClassDecl) {
+    class_decl.ident.visit_with(self);
+    self.visit_class_with_scope(
+      &class_decl.class,
+      Some(class_decl.ident.to_id()),
+    );
+  }
+
+  fn visit_class_expr(&mut self, class_expr: &ClassExpr) {
+    class_expr.ident.visit_with(self);
+    let this = class_expr.ident.as_ref().map(|ident| ident.to_id());
+    self.visit_class_with_scope(&class_expr.class, this);
+  }
+
+  // Methods keep the `this` of their class, other functions don't.
+  fn visit_class_method(&mut self, method: &ClassMethod) {
+    method.key.visit_with(self);
+    method.function.visit_children_with(self);
+  }
+
+  fn visit_private_method(&mut self, method: &PrivateMethod) {
+    method.function.visit_children_with(self);
+  }
+
+  fn visit_function(&mut self, function: &Function) {
+    let scope = std::mem::take(&mut self.class);
+    function.visit_children_with(self);
+    self.class = scope;
+  }
+
+  // Re-exports like `export { foo }` pass the deprecation on to the importers
+  // instead of using the declaration.
+  fn visit_export_named_specifier(&mut self, _: &ExportNamedSpecifier) {}
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_deprecated_usage_valid() {
+    assert_lint_ok! {
+      NoDeprecatedUsage,
+      "/** @deprecated */\nfunction foo() {}",
+      "/** @deprecated */\nexport function foo() {}",
+      "/** Not deprecated. */\nfunction foo() {}\nfoo();",
+      "/* @deprecated */\nfunction foo() {}\nfoo();",
+      "/** @deprecated */\nfunction foo() {}\nfunction bar(foo) { foo(); }",
+      "/** @deprecated */\nconst foo = 1;\nexport { foo };",
+      "/** @deprecated */\nfunction foo(): void;\nfunction foo(a: number): void;\nfunction foo(a?: number) {}\nfoo(1);",
+      "/** @deprecated */\ninterface A {}\ninterface A { a: string }",
+      "const obj = {\n  /** @deprecated */\n  foo: 1,\n};\nobj.foo;",
+    };
+  }
+
+  #[test]
+  fn no_deprecated_usage_invalid() {
+    assert_lint_err! {
+      NoDeprecatedUsage,
+      "/** @deprecated Use `bar` instead. */\nfunction foo() {}\nfoo();": [
+        {
+          line: 3,
+          col: 0,
+          message: "`foo` is deprecated",
+          hint: "Use `bar` instead.",
+        }
+      ],
+      "/** @deprecated */\nexport class Foo {}\nnew Foo();": [
+        {
+          line: 3,
+          col: 4,
+          message: "`Foo` is deprecated",
+          hint: DEFAULT_HINT,
+        }
+      ],
+      "/**\n * Some value.\n * @deprecated Use\n *   `b` instead.\n */\nexport const a = 1, c = 2;\nconsole.log(a + c);": [
+        {
+          line: 7,
+          col: 12,
+          message: "`a` is deprecated",
+          hint: "Use `b` instead.",
+        },
+        {
+          line: 7,
+          col: 16,
+          message: "`c` is deprecated",
+          hint: "Use `b` instead.",
+        }
+      ],
+      "/** @deprecated */\ntype A = string;\nlet a: A;": [
+        {
+          line: 3,
+          col: 7,
+          message: "`A` is deprecated",
+          hint: DEFAULT_HINT,
+        }
+      ],
+      "/** @deprecated */\nenum E { A }\nE.A;": [
+        {
+          line: 3,
+          col: 0,
+          message: "`E` is deprecated",
+          hint: DEFAULT_HINT,
+        }
+      ],
+      "/** @deprecated */\nexport default function foo() {}\nfoo();": [
+        {
+          line: 3,
+          col: 0,
+          message: "`foo` is deprecated",
+          hint: DEFAULT_HINT,
+        }
+      ],
+      "function outer() {\n  /** @deprecated */\n  function inner() {}\n  inner();\n}": [
+        {
+          line: 4,
+          col: 2,
+          message: "`inner` is deprecated",
+          hint: DEFAULT_HINT,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_deprecated_usage_methods_valid() {
+    assert_lint_ok! {
+      NoDeprecatedUsage,
+      "class A {\n  /** @deprecated */\n  foo() {}\n}\nnew A();",
+      "class A {\n  /** @deprecated */\n  foo() {}\n}\nA.foo();",
+      "class A {\n  /** @deprecated */\n  static foo() {}\n}\nnew A().foo();",
+      "class A {\n  /** @deprecated */\n  foo() {}\n  bar() { function f() { this.foo(); } }\n}",
+      "class A {\n  /** @deprecated */\n  foo() {}\n}\nclass B {\n  bar() { this.foo(); }\n}",
+      "class A {\n  /** @deprecated */\n  foo(): void;\n  foo(a: number): void;\n  foo(a?: number) {}\n  bar() { this.foo(1); }\n}",
+      "class A {\n  /** Not deprecated. */\n  foo() {}\n  bar() { this.foo(); }\n}",
+    };
+  }
+
+  #[test]
+  fn no_deprecated_usage_methods_invalid() {
+    assert_lint_err! {
+      NoDeprecatedUsage,
+      "class A {\n  /** @deprecated Use `bar` instead. */\n  foo() {}\n  bar() { this.foo(); }\n}": [
+        {
+          line: 4,
+          col: 15,
+          message: "`foo` is deprecated",
+          hint: "Use `bar` instead.",
+        }
+      ],
+      "class A {\n  /** @deprecated */\n  static foo() {}\n}\nA.foo();": [
+        {
+          line: 5,
+          col: 2,
+          message: "`foo` is deprecated",
+          hint: DEFAULT_HINT,
+        }
+      ],
+      "class A {\n  /** @deprecated */\n  foo() {}\n}\nnew A().foo();": [
+        {
+          line: 5,
+          col: 8,
+          message: "`foo` is deprecated",
+          hint: DEFAULT_HINT,
+        }
+      ],
+      "class A {\n  /** @deprecated */\n  #foo() {}\n  bar = () => this.#foo();\n}": [
+        {
+          line: 4,
+          col: 19,
+          message: "`#foo` is deprecated",
+          hint: DEFAULT_HINT,
+        }
+      ],
+      "class A {\n  /** @deprecated */\n  foo() {}\n}\nclass B extends A {\n  foo() { super.foo(); }\n}": [
+        {
+          line: 6,
+          col: 16,
+          message: "`foo` is deprecated",
+          hint: DEFAULT_HINT,
+        }
+      ],
+      "export default class A {\n  /** @deprecated */\n  static foo() {}\n  static bar() { this.foo(); }\n}": [
+        {
+          line: 4,
+          col: 22,
+          message: "`foo` is deprecated",
+          hint: DEFAULT_HINT,
+        }
+      ],
+    };
+  }
+}
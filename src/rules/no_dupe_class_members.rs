@@ -2,6 +2,7 @@
 
 use super::program_ref;
 use super::{Context, LintRule};
+use crate::diagnostic::LintDiagnosticRelated;
 use crate::tags::{self, Tags};
 use crate::Program;
 use crate::ProgramRef;
@@ -65,12 +66,18 @@ impl<'c, 'view> NoDupeClassMembersVisitor<'c, 'view> {
     Self { context }
   }
 
-  fn add_diagnostic(&mut self, range: SourceRange, name: &str) {
-    self.context.add_diagnostic_with_hint(
+  fn add_diagnostic(
+    &mut self,
+    range: SourceRange,
+    name: &str,
+    related: Vec<LintDiagnosticRelated>,
+  ) {
+    self.context.add_diagnostic_with_related(
       range,
       CODE,
       NoDupeClassMembersMessage::Duplicate(name.to_string()),
-      NoDupeClassMembersHint::RenameOrRemove,
+      Some(NoDupeClassMembersHint::RenameOrRemove.to_string()),
+      related,
     );
   }
 }
@@ -101,14 +108,26 @@ impl<'a, 'b, 'view> ClassVisitor<'a, 'b, 'view> {
   fn aggregate_dupes(&mut self) {
     let root_visitor = &mut self.root_visitor;
 
-    self
-      .appeared_methods
-      .values()
-      .filter(|m| m.len() >= 2)
-      .flatten()
-      .for_each(|(range, name)| {
-        root_visitor.add_diagnostic(*range, name);
-      });
+    for methods in self.appeared_methods.values().filter(|m| m.len() >= 2) {
+      for (i, (range, name)) in methods.iter().enumerate() {
+        // Every definition points at the other ones.
+        let related = methods
+          .iter()
+          .enumerate()
+          .filter(|(j, _)| *j != i)
+          .map(|(j, (range, _))| LintDiagnosticRelated {
+            range: *range,
+            message: if j == 0 {
+              "First defined here"
+            } else {
+              "Also defined here"
+            }
+            .to_string(),
+          })
+          .collect();
+        root_visitor.add_diagnostic(*range, name, related);
+      }
+    }
   }
 }
 
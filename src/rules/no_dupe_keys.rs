@@ -1,6 +1,7 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
+use crate::diagnostic::LintDiagnosticRelated;
 use crate::handler::{Handler, Traverse};
 use crate::swc_util::StringRepr;
 use crate::tags::{self, Tags};
@@ -54,21 +55,34 @@ struct NoDupeKeysHandler;
 impl NoDupeKeysHandler {
   fn report(
     &mut self,
-    range: SourceRange,
+    obj_range: SourceRange,
     key: impl Into<String>,
+    first_range: SourceRange,
+    range: SourceRange,
     ctx: &mut Context,
   ) {
-    ctx.add_diagnostic_with_hint(
-      range,
+    ctx.add_diagnostic_with_related(
+      obj_range,
       CODE,
       NoDupeKeysMessage::Duplicate(key.into()),
-      NoDupeKeysHint::RemoveOrRename,
+      Some(NoDupeKeysHint::RemoveOrRename.to_string()),
+      vec![
+        LintDiagnosticRelated {
+          range: first_range,
+          message: "The key is first defined here".to_string(),
+        },
+        LintDiagnosticRelated {
+          range,
+          message: "The key is defined again here".to_string(),
+        },
+      ],
     );
   }
 
   fn check_key<S: Into<String>>(
     &mut self,
     obj_range: SourceRange,
+    range: SourceRange,
     key: Option<S>,
     keys: &mut HashMap<String, PropertyInfo>,
     ctx: &mut Context,
@@ -78,10 +92,20 @@ impl NoDupeKeysHandler {
 
       match keys.entry(key) {
         Entry::Occupied(occupied) => {
-          self.report(obj_range, occupied.key(), ctx);
+          self.report(
+            obj_range,
+            occupied.key(),
+            occupied.get().range,
+            range,
+            ctx,
+          );
         }
         Entry::Vacant(vacant) => {
-          vacant.insert(PropertyInfo::default());
+          vacant.insert(PropertyInfo {
+            range,
+            getter: false,
+            setter: false,
+          });
         }
       }
     }
@@ -90,6 +114,7 @@ impl NoDupeKeysHandler {
   fn check_getter<S: Into<String>>(
     &mut self,
     obj_range: SourceRange,
+    range: SourceRange,
     key: Option<S>,
     keys: &mut HashMap<String, PropertyInfo>,
     ctx: &mut Context,
@@ -102,11 +127,18 @@ impl NoDupeKeysHandler {
           if occupied.get().setter_only() {
             occupied.get_mut().getter = true;
           } else {
-            self.report(obj_range, occupied.key(), ctx);
+            self.report(
+              obj_range,
+              occupied.key(),
+              occupied.get().range,
+              range,
+              ctx,
+            );
           }
         }
         Entry::Vacant(vacant) => {
           vacant.insert(PropertyInfo {
+            range,
             getter: true,
             setter: false,
           });
@@ -118,6 +150,7 @@ impl NoDupeKeysHandler {
   fn check_setter<S: Into<String>>(
     &mut self,
     obj_range: SourceRange,
+    range: SourceRange,
     key: Option<S>,
     keys: &mut HashMap<String, PropertyInfo>,
     ctx: &mut Context,
@@ -130,11 +163,18 @@ impl NoDupeKeysHandler {
           if occupied.get().getter_only() {
             occupied.get_mut().setter = true;
           } else {
-            self.report(obj_range, occupied.key(), ctx);
+            self.report(
+              obj_range,
+              occupied.key(),
+              occupied.get().range,
+              range,
+              ctx,
+            );
           }
         }
         Entry::Vacant(vacant) => {
           vacant.insert(PropertyInfo {
+            range,
             getter: false,
             setter: true,
           });
@@ -144,8 +184,10 @@ impl NoDupeKeysHandler {
   }
 }
 
-#[derive(Clone, Copy, Default)]
+#[derive(Clone, Copy)]
 struct PropertyInfo {
+  /// Range of the key that first defined the property.
+  range: SourceRange,
   getter: bool,
   setter: bool,
 }
@@ -162,27 +204,57 @@ impl PropertyInfo {
 
 impl Handler for NoDupeKeysHandler {
   fn object_lit(&mut self, obj_lit: &ObjectLit, ctx: &mut Context) {
-    let range = obj_lit.range();
+    let obj_range = obj_lit.range();
     let mut keys: HashMap<String, PropertyInfo> = HashMap::new();
 
     for prop in obj_lit.props {
       if let PropOrSpread::Prop(prop) = prop {
         match prop {
           Prop::Shorthand(ident) => {
-            self.check_key(range, Some(ident.inner.as_ref()), &mut keys, ctx);
+            self.check_key(
+              obj_range,
+              ident.range(),
+              Some(ident.inner.as_ref()),
+              &mut keys,
+              ctx,
+            );
           }
           Prop::KeyValue(KeyValueProp { key, .. }) => {
-            self.check_key(range, key.string_repr(), &mut keys, ctx);
+            self.check_key(
+              obj_range,
+              key.range(),
+              key.string_repr(),
+              &mut keys,
+              ctx,
+            );
           }
           Prop::Assign(_) => {}
           Prop::Getter(GetterProp { key, .. }) => {
-            self.check_getter(range, key.string_repr(), &mut keys, ctx);
+            self.check_getter(
+              obj_range,
+              key.range(),
+              key.string_repr(),
+              &mut keys,
+              ctx,
+            );
           }
           Prop::Setter(SetterProp { key, .. }) => {
-            self.check_setter(range, key.string_repr(), &mut keys, ctx);
+            self.check_setter(
+              obj_range,
+              key.range(),
+              key.string_repr(),
+              &mut keys,
+              ctx,
+            );
           }
           Prop::Method(MethodProp { key, .. }) => {
-            self.check_key(range, key.string_repr(), &mut keys, ctx);
+            self.check_key(
+              obj_range,
+              key.range(),
+              key.string_repr(),
+              &mut keys,
+              ctx,
+            );
           }
         }
       }
@@ -390,4 +462,26 @@ let x = {
       ]
     };
   }
+
+  #[test]
+  fn no_dupe_keys_related() {
+    let (parsed_source, diagnostics) = crate::testing::lint(
+      Box::new(NoDupeKeys),
+      "var foo = {\n  bar: 1,\n  \"bar\": 2,\n};",
+      "file:///no_dupe_keys_test.ts",
+    );
+    let text_info = parsed_source.text_info_lazy();
+    let [diagnostic] = diagnostics.as_slice() else {
+      panic!("expected exactly one diagnostic");
+    };
+    let range = diagnostic.range.as_ref().unwrap().range;
+    assert_eq!(range.text_fast(text_info), "{\n  bar: 1,\n  \"bar\": 2,\n}");
+    let [first, duplicate] = diagnostic.details.related.as_slice() else {
+      panic!("expected exactly two related locations");
+    };
+    assert_eq!(first.range.text_fast(text_info), "bar");
+    assert_eq!(first.message, "The key is first defined here");
+    assert_eq!(duplicate.range.text_fast(text_info), "\"bar\"");
+    assert_eq!(duplicate.message, "The key is defined again here");
+  }
 }
@@ -0,0 +1,363 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{program_ref, Context, LintRule, RuleDocs};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::tags::Tags;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{ImportDecl, ImportSpecifier, ModuleDecl, ModuleItem};
+use deno_ast::{SourceRange, SourceRanged, SourceRangedForSpanned};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoDuplicateImports {
+  /// Allows an `import type` statement next to a regular import of the same
+  /// module.
+  pub allow_separate_type_imports: bool,
+}
+
+const CODE: &str = "no-duplicate-imports";
+const HINT: &str = "Merge the imports into a single import statement";
+const FIX_DESC: &str = "Merge into the first import";
+
+impl LintRule for NoDuplicateImports {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows importing the same module with multiple import \
+        statements, which is harder to read than a single statement. The \
+        fix merges the imported bindings into the first statement, turning \
+        `import type` bindings into inline `type` imports if needed. With \
+        the `allowSeparateTypeImports` option, an `import type` statement \
+        may stand next to a regular import of the same module.",
+      invalid_examples: &[
+        "import { parse } from \"./parser.ts\";\nimport { print } from \"./parser.ts\";",
+        "import { parse } from \"./parser.ts\";\nimport type { Ast } from \"./parser.ts\";",
+      ],
+      valid_examples: &[
+        "import { parse, print } from \"./parser.ts\";",
+        "import { parse, type Ast } from \"./parser.ts\";",
+      ],
+    }
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "allowSeparateTypeImports": {
+          "description": "Allows an `import type` statement next to a regular import of the same module",
+          "type": "boolean"
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<NoDuplicateImports>(
+      options,
+    )?))
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    let ProgramRef::Module(module) = program_ref(program) else {
+      return;
+    };
+    // The first import of each module, keyed by the specifier, whether the
+    // import is counted separately as a type import, and the attributes.
+    let mut first_imports = HashMap::<(&str, bool, Option<String>), _>::new();
+    for item in &module.body {
+      let ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) = item else {
+        continue;
+      };
+      let key = (
+        import_decl.src.value.as_str(),
+        self.allow_separate_type_imports && import_decl.type_only,
+        import_decl
+          .with
+          .as_ref()
+          .map(|with| with.range().text_fast(context.text_info()).to_string()),
+      );
+      let Some(first) = first_imports.get(&key).copied() else {
+        first_imports.insert(key, import_decl);
+        continue;
+      };
+      let fixes = merge_fix(first, import_decl, context).into_iter().collect();
+      context.add_diagnostic_with_fixes(
+        import_decl.src.range(),
+        CODE,
+        format!("`{}` is imported more than once", import_decl.src.value),
+        Some(HINT.to_string()),
+        fixes,
+      );
+    }
+  }
+}
+
+/// Creates a fix that merges the bindings of `duplicate` into `first`, and
+/// removes `duplicate`. Returns `None` if the bindings can't be combined in a
+/// single statement.
+fn merge_fix(
+  first: &ImportDecl,
+  duplicate: &ImportDecl,
+  ctx: &Context,
+) -> Option<LintFix> {
+  if ctx.has_comments_in_range(first.range())
+    || ctx.has_comments_in_range(duplicate.range())
+  {
+    return None;
+  }
+  let text_info = ctx.text_info();
+  let type_only = first.type_only && duplicate.type_only;
+
+  let mut default = None;
+  let mut namespace = None;
+  let mut named = Vec::new();
+  for import_decl in [first, duplicate] {
+    for specifier in &import_decl.specifiers {
+      let text = specifier.range().text_fast(text_info);
+      match specifier {
+        ImportSpecifier::Default(_) | ImportSpecifier::Namespace(_)
+          if import_decl.type_only && !type_only =>
+        {
+          // `import type X` can't be written inline.
+          return None;
+        }
+        ImportSpecifier::Default(_) => {
+          if default.replace(text).is_some() {
+            return None;
+          }
+        }
+        ImportSpecifier::Namespace(_) => {
+          if namespace.replace(text).is_some() {
+            return None;
+          }
+        }
+        ImportSpecifier::Named(_) if import_decl.type_only && !type_only => {
+          named.push(format!("type {}", text));
+        }
+        ImportSpecifier::Named(_) => named.push(text.to_string()),
+      }
+    }
+  }
+  if namespace.is_some() && !named.is_empty() {
+    return None;
+  }
+
+  let mut clauses = Vec::new();
+  clauses.extend(default.map(str::to_string));
+  clauses.extend(namespace.map(str::to_string));
+  if !named.is_empty() {
+    clauses.push(format!("{{ {} }}", named.join(", ")));
+  }
+  let src = first.src.range().text_fast(text_info);
+  let with = first
+    .with
+    .as_ref()
+    .map(|with| format!(" with {}", with.range().text_fast(text_info)))
+    .unwrap_or_default();
+  let new_text = if clauses.is_empty() {
+    format!("import {}{};", src, with)
+  } else {
+    format!(
+      "import {}{} from {}{};",
+      if type_only { "type " } else { "" },
+      clauses.join(", "),
+      src,
+      with
+    )
+  };
+
+  Some(LintFix {
+    description: FIX_DESC.into(),
+    changes: vec![
+      LintFixChange {
+        new_text: new_text.into(),
+        range: first.range(),
+      },
+      LintFixChange {
+        new_text: "".into(),
+        range: statement_line_range(duplicate.range(), ctx),
+      },
+    ],
+  })
+}
+
+/// Extends the range of a statement over the line break after it, so that
+/// removing it doesn't leave an empty line behind.
+fn statement_line_range(range: SourceRange, ctx: &Context) -> SourceRange {
+  let text_info = ctx.text_info();
+  let rest =
+    SourceRange::new(range.end, text_info.range().end).text_fast(text_info);
+  let line_break = if rest.starts_with("\r\n") {
+    2
+  } else if rest.starts_with('\n') {
+    1
+  } else {
+    0
+  };
+  SourceRange::new(range.start, range.end + line_break)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_duplicate_imports_valid() {
+    assert_lint_ok! {
+      NoDuplicateImports::default(),
+      "import { a, b } from './mod.ts';",
+      "import { a } from './a.ts';\nimport { b } from './b.ts';",
+      "import a, { b } from './mod.ts';",
+      "import data from './data.json' with { type: 'json' };\nimport './data.json';",
+      "export { a } from './mod.ts';\nimport { b } from './mod.ts';",
+      "const a = await import('./mod.ts');\nimport { b } from './mod.ts';",
+    };
+
+    assert_lint_ok! {
+      NoDuplicateImports {
+        allow_separate_type_imports: true,
+      },
+      "import { a } from './mod.ts';\nimport type { B } from './mod.ts';",
+      "import type { A } from './mod.ts';\nimport b from './mod.ts';",
+    };
+  }
+
+  #[test]
+  fn no_duplicate_imports_invalid() {
+    assert_lint_err! {
+      NoDuplicateImports::default(),
+      "import { a } from './mod.ts';\nimport { b as c } from './mod.ts';\nrun();": [
+        {
+          line: 2,
+          col: 23,
+          message: "`./mod.ts` is imported more than once",
+          hint: HINT,
+          fix: (FIX_DESC, "import { a, b as c } from './mod.ts';\nrun();"),
+        }
+      ],
+      "import a from './mod.ts';\nimport { b } from './mod.ts';": [
+        {
+          line: 2,
+          col: 18,
+          message: "`./mod.ts` is imported more than once",
+          hint: HINT,
+          fix: (FIX_DESC, "import a, { b } from './mod.ts';\n"),
+        }
+      ],
+      "import { b } from './mod.ts';\nimport * as ns from './mod.ts';": [
+        {
+          line: 2,
+          col: 20,
+          message: "`./mod.ts` is imported more than once",
+          hint: HINT,
+        }
+      ],
+      "import a from './mod.ts';\nimport * as ns from './mod.ts';": [
+        {
+          line: 2,
+          col: 20,
+          message: "`./mod.ts` is imported more than once",
+          hint: HINT,
+          fix: (FIX_DESC, "import a, * as ns from './mod.ts';\n"),
+        }
+      ],
+      "import './mod.ts';\nimport './mod.ts';": [
+        {
+          line: 2,
+          col: 7,
+          message: "`./mod.ts` is imported more than once",
+          hint: HINT,
+          fix: (FIX_DESC, "import './mod.ts';\n"),
+        }
+      ],
+      "import { a } from './mod.ts';\nimport type { B } from './mod.ts';": [
+        {
+          line: 2,
+          col: 23,
+          message: "`./mod.ts` is imported more than once",
+          hint: HINT,
+          fix: (FIX_DESC, "import { a, type B } from './mod.ts';\n"),
+        }
+      ],
+      "import type { A } from './mod.ts';\nimport type { B } from './mod.ts';": [
+        {
+          line: 2,
+          col: 23,
+          message: "`./mod.ts` is imported more than once",
+          hint: HINT,
+          fix: (FIX_DESC, "import type { A, B } from './mod.ts';\n"),
+        }
+      ],
+      "import type A from './mod.ts';\nimport { b } from './mod.ts';": [
+        {
+          line: 2,
+          col: 18,
+          message: "`./mod.ts` is imported more than once",
+          hint: HINT,
+        }
+      ],
+      "import { a } from './mod.ts';\n// b is used for c\nimport { b } from './mod.ts';": [
+        {
+          line: 3,
+          col: 18,
+          message: "`./mod.ts` is imported more than once",
+          hint: HINT,
+          fix: (FIX_DESC, "import { a, b } from './mod.ts';\n// b is used for c\n"),
+        }
+      ],
+      "import { a } from './mod.ts';\nimport { b } from './mod.ts';\nimport { c } from './mod.ts';": [
+        {
+          line: 2,
+          col: 18,
+          message: "`./mod.ts` is imported more than once",
+          hint: HINT,
+          fix: (FIX_DESC, "import { a, b } from './mod.ts';\nimport { c } from './mod.ts';"),
+        },
+        {
+          line: 3,
+          col: 18,
+          message: "`./mod.ts` is imported more than once",
+          hint: HINT,
+          fix: (FIX_DESC, "import { a, c } from './mod.ts';\nimport { b } from './mod.ts';\n"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoDuplicateImports {
+        allow_separate_type_imports: true,
+      },
+      "import type { A } from './mod.ts';\nimport { b } from './mod.ts';\nimport type { C } from './mod.ts';": [
+        {
+          line: 3,
+          col: 23,
+          message: "`./mod.ts` is imported more than once",
+          hint: HINT,
+          fix: (FIX_DESC, "import type { A, C } from './mod.ts';\nimport { b } from './mod.ts';\n"),
+        }
+      ],
+    };
+  }
+}
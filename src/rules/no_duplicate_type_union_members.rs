@@ -0,0 +1,337 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::ts_type_eq;
+use crate::Program;
+use deno_ast::swc::ast::{
+  TsKeywordTypeKind, TsType, TsUnionOrIntersectionType,
+};
+use deno_ast::view as ast_view;
+use deno_ast::{SourceRange, SourceRangedForSpanned};
+
+#[derive(Debug)]
+pub struct NoDuplicateTypeUnionMembers;
+
+const CODE: &str = "no-duplicate-type-union-members";
+
+impl LintRule for NoDuplicateTypeUnionMembers {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows duplicate members in union and intersection \
+        types, as well as an explicit `undefined` member in the type of an \
+        optional property or parameter, whose `?` already allows `undefined`. \
+        Members are compared structurally, so `A` and `(A)` are duplicates.",
+      invalid_examples: &[
+        "type Id = string | number | string;",
+        "type Admin = User & Permissions & User;",
+        "interface Options { timeout?: number | undefined }",
+        "function greet(name?: string | undefined) {}",
+      ],
+      valid_examples: &[
+        "type Id = string | number;",
+        "type Admin = User & Permissions;",
+        "interface Options { timeout?: number }",
+        "function greet(name: string | undefined) {}",
+      ],
+    }
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    NoDuplicateTypeUnionMembersHandler.traverse(program, context);
+  }
+}
+
+#[derive(Clone, Copy)]
+enum DiagnosticKind {
+  DuplicateUnionMember,
+  DuplicateIntersectionMember,
+  RedundantUndefined,
+}
+
+impl DiagnosticKind {
+  #[cfg(test)]
+  fn message_and_hint(&self) -> (&'static str, &'static str) {
+    (self.message(), self.hint())
+  }
+
+  fn message(&self) -> &'static str {
+    match *self {
+      DiagnosticKind::DuplicateUnionMember => "Duplicate member in union type",
+      DiagnosticKind::DuplicateIntersectionMember => {
+        "Duplicate member in intersection type"
+      }
+      DiagnosticKind::RedundantUndefined => {
+        "`undefined` is redundant in the type of an optional property or parameter"
+      }
+    }
+  }
+
+  fn hint(&self) -> &'static str {
+    match *self {
+      DiagnosticKind::DuplicateUnionMember
+      | DiagnosticKind::DuplicateIntersectionMember => {
+        "Remove the duplicate member"
+      }
+      DiagnosticKind::RedundantUndefined => {
+        "Remove `undefined`, the `?` modifier already allows it"
+      }
+    }
+  }
+
+  fn fix_description(&self) -> &'static str {
+    match *self {
+      DiagnosticKind::DuplicateUnionMember
+      | DiagnosticKind::DuplicateIntersectionMember => {
+        "Remove the duplicate member"
+      }
+      DiagnosticKind::RedundantUndefined => "Remove `undefined`",
+    }
+  }
+}
+
+struct NoDuplicateTypeUnionMembersHandler;
+
+impl Handler for NoDuplicateTypeUnionMembersHandler {
+  fn ts_union_type(
+    &mut self,
+    union_type: &ast_view::TsUnionType,
+    ctx: &mut Context,
+  ) {
+    check_duplicates(
+      &union_type.inner.types,
+      DiagnosticKind::DuplicateUnionMember,
+      ctx,
+    );
+  }
+
+  fn ts_intersection_type(
+    &mut self,
+    intersection_type: &ast_view::TsIntersectionType,
+    ctx: &mut Context,
+  ) {
+    check_duplicates(
+      &intersection_type.inner.types,
+      DiagnosticKind::DuplicateIntersectionMember,
+      ctx,
+    );
+  }
+
+  fn ts_type_ann(&mut self, type_ann: &ast_view::TsTypeAnn, ctx: &mut Context) {
+    let is_optional = match type_ann.parent() {
+      ast_view::Node::TsPropertySignature(prop) => prop.inner.optional,
+      ast_view::Node::ClassProp(prop) => prop.inner.is_optional,
+      ast_view::Node::BindingIdent(ident) => ident.id.inner.optional,
+      _ => false,
+    };
+    if !is_optional {
+      return;
+    }
+
+    let TsType::TsUnionOrIntersectionType(
+      TsUnionOrIntersectionType::TsUnionType(union_type),
+    ) = strip_parens(&type_ann.inner.type_ann)
+    else {
+      return;
+    };
+    let types = &union_type.types;
+    if types.iter().all(|ty| is_undefined(ty)) {
+      return;
+    }
+    // Only the first `undefined` is reported here, the others are duplicates.
+    if let Some(index) = types.iter().position(|ty| is_undefined(ty)) {
+      report(types, index, DiagnosticKind::RedundantUndefined, ctx);
+    }
+  }
+}
+
+fn check_duplicates(
+  types: &[Box<TsType>],
+  kind: DiagnosticKind,
+  ctx: &mut Context,
+) {
+  for (index, ty) in types.iter().enumerate() {
+    if types[..index].iter().any(|prev| ts_type_eq(prev, ty)) {
+      report(types, index, kind, ctx);
+    }
+  }
+}
+
+/// Reports `types[index]` with a fix that removes it together with the
+/// adjacent `|` or `&` operator.
+fn report(
+  types: &[Box<TsType>],
+  index: usize,
+  kind: DiagnosticKind,
+  ctx: &mut Context,
+) {
+  let range = types[index].range();
+  let removal_range = if index == 0 {
+    SourceRange::new(range.start, types[1].range().start)
+  } else {
+    SourceRange::new(types[index - 1].range().end, range.end)
+  };
+
+  ctx.add_diagnostic_with_fixes(
+    range,
+    CODE,
+    kind.message(),
+    Some(kind.hint().to_string()),
+    vec![LintFix {
+      description: kind.fix_description().into(),
+      changes: vec![LintFixChange {
+        new_text: "".into(),
+        range: removal_range,
+      }],
+    }],
+  );
+}
+
+fn strip_parens(mut ty: &TsType) -> &TsType {
+  while let TsType::TsParenthesizedType(paren) = ty {
+    ty = &paren.type_ann;
+  }
+  ty
+}
+
+fn is_undefined(ty: &TsType) -> bool {
+  matches!(
+    strip_parens(ty),
+    TsType::TsKeywordType(keyword)
+      if keyword.kind == TsKeywordTypeKind::TsUndefinedKeyword
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_duplicate_type_union_members_valid() {
+    assert_lint_ok! {
+      NoDuplicateTypeUnionMembers,
+      "type A = string | number;",
+      "type A = B & C;",
+      "type A = 'a' | 'b';",
+      "type A = 1 | '1';",
+      "type A = string[] | number[];",
+      "type A = { a: string } | { a: number };",
+      "type A = (B | C) | (C | B);",
+      "type A = B | (B & C);",
+      "type A = Foo<string> | Foo<number>;",
+      "type A = string | undefined;",
+      "let a: string | undefined;",
+      "function foo(a: string | undefined) {}",
+      "interface A { a: string | undefined }",
+      "interface A { a?: string }",
+      "interface A { a?: undefined }",
+      "class A { a?: string; b: string | undefined; }",
+      "function foo(a?: string) {}",
+    };
+  }
+
+  #[test]
+  fn no_duplicate_type_union_members_invalid() {
+    let (union_message, union_hint) =
+      DiagnosticKind::DuplicateUnionMember.message_and_hint();
+    let (intersection_message, intersection_hint) =
+      DiagnosticKind::DuplicateIntersectionMember.message_and_hint();
+    let (undefined_message, undefined_hint) =
+      DiagnosticKind::RedundantUndefined.message_and_hint();
+
+    assert_lint_err! {
+      NoDuplicateTypeUnionMembers,
+      "type A = string | number | string;": [
+        {
+          col: 27,
+          message: union_message,
+          hint: union_hint,
+          fix: ("Remove the duplicate member", "type A = string | number;"),
+        }
+      ],
+      "type A = 'a' | ('a');": [
+        {
+          col: 15,
+          message: union_message,
+          hint: union_hint,
+          fix: ("Remove the duplicate member", "type A = 'a';"),
+        }
+      ],
+      "type A = { a: string } | { a: string };": [
+        {
+          col: 25,
+          message: union_message,
+          hint: union_hint,
+          fix: ("Remove the duplicate member", "type A = { a: string };"),
+        }
+      ],
+      "type A = Foo<B> | Foo<B> | Foo<B>;": [
+        {
+          col: 18,
+          message: union_message,
+          hint: union_hint,
+          fix: ("Remove the duplicate member", "type A = Foo<B> | Foo<B>;"),
+        },
+        {
+          col: 27,
+          message: union_message,
+          hint: union_hint,
+          fix: ("Remove the duplicate member", "type A = Foo<B> | Foo<B>;"),
+        }
+      ],
+      "type A = B & C & B;": [
+        {
+          col: 17,
+          message: intersection_message,
+          hint: intersection_hint,
+          fix: ("Remove the duplicate member", "type A = B & C;"),
+        }
+      ],
+      "interface A { a?: string | undefined }": [
+        {
+          col: 27,
+          message: undefined_message,
+          hint: undefined_hint,
+          fix: ("Remove `undefined`", "interface A { a?: string }"),
+        }
+      ],
+      "interface A { a?: undefined | string }": [
+        {
+          col: 18,
+          message: undefined_message,
+          hint: undefined_hint,
+          fix: ("Remove `undefined`", "interface A { a?: string }"),
+        }
+      ],
+      "class A { a?: (string | undefined); }": [
+        {
+          col: 24,
+          message: undefined_message,
+          hint: undefined_hint,
+          fix: ("Remove `undefined`", "class A { a?: (string); }"),
+        }
+      ],
+      "function foo(a?: number | undefined) {}": [
+        {
+          col: 26,
+          message: undefined_message,
+          hint: undefined_hint,
+          fix: ("Remove `undefined`", "function foo(a?: number) {}"),
+        }
+      ],
+    };
+  }
+}
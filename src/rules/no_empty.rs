@@ -4,8 +4,10 @@ use super::{Context, LintRule};
 use crate::handler::{Handler, Traverse};
 use crate::tags::{self, Tags};
 use crate::Program;
-use deno_ast::view::{ArrowExpr, BlockStmt, Constructor, Function, SwitchStmt};
-use deno_ast::{SourceRanged, SourceRangedForSpanned};
+use deno_ast::view::{
+  ArrowExpr, BlockStmt, Constructor, Function, StaticBlock, SwitchStmt,
+};
+use deno_ast::SourceRanged;
 
 #[derive(Debug)]
 pub struct NoEmpty;
@@ -37,11 +39,13 @@ impl Handler for NoEmptyHandler {
     // Empty functions shouldn't be caught by this rule.
     // Because function's body is a block statement, we're gonna
     // manually visit each member; otherwise rule would produce errors
-    // for empty function or arrow body or constructor.
+    // for empty function or arrow body or constructor. Empty static blocks
+    // are reported by `no-empty-static-block` instead.
     if block_stmt.stmts.is_empty()
       && !block_stmt.parent().is::<Function>()
       && !block_stmt.parent().is::<ArrowExpr>()
       && !block_stmt.parent().is::<Constructor>()
+      && !block_stmt.parent().is::<StaticBlock>()
       && !block_stmt.contains_comments(ctx)
     {
       ctx.add_diagnostic_with_hint(
@@ -71,9 +75,7 @@ trait ContainsComments {
 
 impl ContainsComments for BlockStmt<'_> {
   fn contains_comments(&self, context: &Context) -> bool {
-    context
-      .all_comments()
-      .any(|comment| self.range().contains(&comment.range()))
+    context.has_comments_in_range(self.range())
   }
 }
 
@@ -116,6 +118,7 @@ if (foo) {
 }
       "#,
       "const testFunction = (): void => {};",
+      "class Foo { static {} }",
       r#"
       switch (foo) {
         case 1:
@@ -0,0 +1,100 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::handler::{Handler, Traverse};
+use crate::tags::Tags;
+use crate::Program;
+use deno_ast::view as ast_view;
+use deno_ast::SourceRanged;
+
+#[derive(Debug)]
+pub struct NoEmptyStaticBlock;
+
+const CODE: &str = "no-empty-static-block";
+const MESSAGE: &str = "Empty static block";
+const HINT: &str =
+  "Add code or comment to the empty static block, or remove it";
+
+impl LintRule for NoEmptyStaticBlock {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows empty static initialization blocks in classes, \
+        which are usually left over from refactoring. A static block that \
+        only contains a comment is allowed.",
+      invalid_examples: &["class Foo {\n  static {}\n}"],
+      valid_examples: &[
+        "class Foo {\n  static {\n    Foo.init();\n  }\n}",
+        "class Foo {\n  static {\n    // Nothing to initialize yet.\n  }\n}",
+      ],
+    }
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    NoEmptyStaticBlockHandler.traverse(program, context);
+  }
+}
+
+struct NoEmptyStaticBlockHandler;
+
+impl Handler for NoEmptyStaticBlockHandler {
+  fn static_block(
+    &mut self,
+    static_block: &ast_view::StaticBlock,
+    ctx: &mut Context,
+  ) {
+    if static_block.body.stmts.is_empty()
+      && !ctx.has_comments_in_range(static_block.body.range())
+    {
+      ctx.add_diagnostic_with_hint(static_block.range(), CODE, MESSAGE, HINT);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v9.0.0/tests/lib/rules/no-empty-static-block.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_empty_static_block_valid() {
+    assert_lint_ok! {
+      NoEmptyStaticBlock,
+      "class Foo { static { bar(); } }",
+      "class Foo { static { /* comment */ } }",
+      "class Foo { static {\n// comment\n} }",
+      "class Foo { static { bar(); } static { bar(); } }",
+      "class Foo { static() {} }",
+      "class Foo { static bar = {}; }",
+    };
+  }
+
+  #[test]
+  fn no_empty_static_block_invalid() {
+    assert_lint_err! {
+      NoEmptyStaticBlock,
+      MESSAGE,
+      HINT,
+      "class Foo { static {} }": [{ col: 12 }],
+      "class Foo { static { } }": [{ col: 12 }],
+      "class Foo { static { \n\n } }": [{ col: 12 }],
+      "class Foo { static { bar(); } static {} }": [{ col: 30 }],
+      "class Foo { static // comment\n {} }": [{ col: 12 }],
+      "const Foo = class { static {} };": [{ col: 20 }],
+    };
+  }
+}
@@ -0,0 +1,306 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::{Handler, Traverse};
+use crate::tags::Tags;
+use crate::Program;
+use deno_ast::view::{self as ast_view, Node, NodeTrait};
+use deno_ast::{SourceRange, SourceRanged, SourceRangedForSpanned};
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoExtraLabel;
+
+const CODE: &str = "no-extra-label";
+const HINT: &str =
+  "Remove the label, the statement is the innermost one it can refer to anyway";
+const FIX_DESC: &str = "Remove the label";
+
+#[derive(Display)]
+enum NoExtraLabelMessage {
+  #[display(fmt = "`{}` label is unnecessary", _0)]
+  Unnecessary(String),
+}
+
+impl LintRule for NoExtraLabel {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows labels on `break` and `continue` statements \
+        that refer to the innermost loop or `switch` statement, since \
+        `break` and `continue` without a label refer to it as well.",
+      invalid_examples: &[
+        "loop: while (true) {\n  if (done()) {\n    break loop;\n  }\n}",
+      ],
+      valid_examples: &[
+        "while (true) {\n  if (done()) {\n    break;\n  }\n}",
+        "outer: for (const row of rows) {\n  for (const cell of row) {\n    if (cell.empty) {\n      continue outer;\n    }\n  }\n}",
+      ],
+    }
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    NoExtraLabelHandler::default().traverse(program, context);
+  }
+}
+
+/// A statement that `break` or `continue` may refer to.
+struct Target {
+  /// Whether this is a loop or a `switch` statement, which unlabeled `break`
+  /// statements refer to.
+  breakable: bool,
+  label: Option<String>,
+}
+
+#[derive(Default)]
+struct NoExtraLabelHandler {
+  targets: Vec<Target>,
+}
+
+impl NoExtraLabelHandler {
+  fn check_label(
+    &self,
+    stmt_range: SourceRange,
+    label: Option<&ast_view::Ident>,
+    ctx: &mut Context,
+  ) {
+    let Some(label) = label else {
+      return;
+    };
+    let name = label.sym().to_string();
+    let is_label = |target: &Target| target.label.as_ref() == Some(&name);
+    let Some(target) = self
+      .targets
+      .iter()
+      .rev()
+      .find(|target| target.breakable || is_label(target))
+    else {
+      return;
+    };
+    if !target.breakable || !is_label(target) {
+      return;
+    }
+
+    // Remove everything between the `break` or `continue` keyword and the end
+    // of the label.
+    let fixes = ctx
+      .token_at(stmt_range.start)
+      .map(|keyword| SourceRange::new(keyword.end(), label.end()))
+      .filter(|range| !ctx.has_comments_in_range(*range))
+      .map(|range| LintFix {
+        description: FIX_DESC.into(),
+        changes: vec![LintFixChange {
+          new_text: "".into(),
+          range,
+        }],
+      })
+      .into_iter()
+      .collect();
+    ctx.add_diagnostic_with_fixes(
+      label.range(),
+      CODE,
+      NoExtraLabelMessage::Unnecessary(name),
+      Some(HINT.to_string()),
+      fixes,
+    );
+  }
+}
+
+fn is_breakable(node: Node) -> bool {
+  matches!(
+    node,
+    Node::ForStmt(_)
+      | Node::ForInStmt(_)
+      | Node::ForOfStmt(_)
+      | Node::WhileStmt(_)
+      | Node::DoWhileStmt(_)
+      | Node::SwitchStmt(_)
+  )
+}
+
+impl Handler for NoExtraLabelHandler {
+  fn on_enter_node(&mut self, node: Node, _ctx: &mut Context) {
+    if is_breakable(node) {
+      let label = match node.parent() {
+        Some(Node::LabeledStmt(labeled_stmt)) => {
+          Some(labeled_stmt.label.sym().to_string())
+        }
+        _ => None,
+      };
+      self.targets.push(Target {
+        breakable: true,
+        label,
+      });
+    } else if let Node::LabeledStmt(labeled_stmt) = node {
+      // Labels of loops and `switch` statements are pushed along with them.
+      if !is_breakable(labeled_stmt.body.as_node()) {
+        self.targets.push(Target {
+          breakable: false,
+          label: Some(labeled_stmt.label.sym().to_string()),
+        });
+      }
+    }
+  }
+
+  fn on_exit_node(&mut self, node: Node, _ctx: &mut Context) {
+    let is_target = match node {
+      Node::LabeledStmt(labeled_stmt) => {
+        !is_breakable(labeled_stmt.body.as_node())
+      }
+      _ => is_breakable(node),
+    };
+    if is_target {
+      self.targets.pop();
+    }
+  }
+
+  fn break_stmt(
+    &mut self,
+    break_stmt: &ast_view::BreakStmt,
+    ctx: &mut Context,
+  ) {
+    self.check_label(break_stmt.range(), break_stmt.label, ctx);
+  }
+
+  fn continue_stmt(
+    &mut self,
+    continue_stmt: &ast_view::ContinueStmt,
+    ctx: &mut Context,
+  ) {
+    self.check_label(continue_stmt.range(), continue_stmt.label, ctx);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v9.0.0/tests/lib/rules/no-extra-label.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_extra_label_valid() {
+    assert_lint_ok! {
+      NoExtraLabel,
+      "A: break A;",
+      "A: { if (a) break A; }",
+      "A: { while (b) { break A; } }",
+      "A: { switch (b) { case 0: break A; } }",
+      "A: while (a) { while (b) { break; } break; }",
+      "A: while (a) { while (b) { break A; } }",
+      "A: while (a) { while (b) { continue A; } }",
+      "A: while (a) { switch (b) { case 0: break A; } }",
+      "A: while (a) { switch (b) { case 0: continue A; } }",
+      "A: switch (a) { case 0: while (b) { break A; } }",
+      "A: switch (a) { case 0: switch (b) { case 0: break A; } }",
+      "A: for (;;) { while (b) { break A; } }",
+      "A: do { switch (b) { case 0: break A; break; } } while (a);",
+      "A: for (a in obj) { while (b) { break A; } }",
+      "A: for (a of ary) { switch (b) { case 0: break A; } }",
+      "A: while (a) { () => { B: while (b) { break; } }; }",
+    };
+  }
+
+  #[test]
+  fn no_extra_label_invalid() {
+    assert_lint_err! {
+      NoExtraLabel,
+      "A: while (a) break A;": [
+        {
+          col: 19,
+          message: variant!(NoExtraLabelMessage, Unnecessary, "A"),
+          hint: HINT,
+          fix: (FIX_DESC, "A: while (a) break;"),
+        }
+      ],
+      "A: while (a) { B: { continue A; } }": [
+        {
+          col: 29,
+          message: variant!(NoExtraLabelMessage, Unnecessary, "A"),
+          hint: HINT,
+          fix: (FIX_DESC, "A: while (a) { B: { continue; } }"),
+        }
+      ],
+      "X: while (x) { A: while (a) { B: { break A; break B; continue X; } } }": [
+        {
+          col: 41,
+          message: variant!(NoExtraLabelMessage, Unnecessary, "A"),
+          hint: HINT,
+          fix: (FIX_DESC, "X: while (x) { A: while (a) { B: { break; break B; continue X; } } }"),
+        }
+      ],
+      "A: do { break A; } while (a);": [
+        {
+          col: 14,
+          message: variant!(NoExtraLabelMessage, Unnecessary, "A"),
+          hint: HINT,
+          fix: (FIX_DESC, "A: do { break; } while (a);"),
+        }
+      ],
+      "A: for (;;) { break A; }": [
+        {
+          col: 20,
+          message: variant!(NoExtraLabelMessage, Unnecessary, "A"),
+          hint: HINT,
+          fix: (FIX_DESC, "A: for (;;) { break; }"),
+        }
+      ],
+      "A: for (a in obj) { break A; }": [
+        {
+          col: 26,
+          message: variant!(NoExtraLabelMessage, Unnecessary, "A"),
+          hint: HINT,
+          fix: (FIX_DESC, "A: for (a in obj) { break; }"),
+        }
+      ],
+      "A: for (a of ary) { break A; }": [
+        {
+          col: 26,
+          message: variant!(NoExtraLabelMessage, Unnecessary, "A"),
+          hint: HINT,
+          fix: (FIX_DESC, "A: for (a of ary) { break; }"),
+        }
+      ],
+      "A: switch (a) { case 0: break A; }": [
+        {
+          col: 30,
+          message: variant!(NoExtraLabelMessage, Unnecessary, "A"),
+          hint: HINT,
+          fix: (FIX_DESC, "A: switch (a) { case 0: break; }"),
+        }
+      ],
+      "A: while(true) { /*comment*/break A; }": [
+        {
+          col: 34,
+          message: variant!(NoExtraLabelMessage, Unnecessary, "A"),
+          hint: HINT,
+          fix: (FIX_DESC, "A: while(true) { /*comment*/break; }"),
+        }
+      ],
+      "A: while(true) { break/**/ A; }": [
+        {
+          col: 27,
+          message: variant!(NoExtraLabelMessage, Unnecessary, "A"),
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
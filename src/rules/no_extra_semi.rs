@@ -0,0 +1,215 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::{Handler, Traverse};
+use crate::tags::Tags;
+use crate::Program;
+use deno_ast::swc::parser::token::Token;
+use deno_ast::view::{self as ast_view, Node};
+use deno_ast::{SourceRanged, SourceRangedForSpanned};
+
+#[derive(Debug)]
+pub struct NoExtraSemi;
+
+const CODE: &str = "no-extra-semi";
+const MESSAGE: &str = "Unnecessary semicolon";
+const HINT: &str = "Remove the extra semicolon";
+const FIX_DESC: &str = "Remove the semicolon";
+
+impl LintRule for NoExtraSemi {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows unnecessary semicolons, i.e. empty statements \
+        in a list of statements and empty class members, like the ones after \
+        function declarations or method definitions. Empty statements that \
+        are the body of a loop, an `if` or a label are allowed, since \
+        removing them would change the code.",
+      invalid_examples: &[
+        "function save() {\n  write();\n};",
+        "class Foo {\n  bar() {};\n}",
+        "let x = 1;;",
+      ],
+      valid_examples: &[
+        "function save() {\n  write();\n}",
+        "class Foo {\n  bar() {}\n}",
+        "while (next());",
+      ],
+    }
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    NoExtraSemiHandler.traverse(program, context);
+  }
+}
+
+struct NoExtraSemiHandler;
+
+impl Handler for NoExtraSemiHandler {
+  fn empty_stmt(
+    &mut self,
+    empty_stmt: &ast_view::EmptyStmt,
+    ctx: &mut Context,
+  ) {
+    if !matches!(
+      empty_stmt.parent(),
+      Node::Module(_)
+        | Node::Script(_)
+        | Node::BlockStmt(_)
+        | Node::SwitchCase(_)
+        | Node::TsModuleBlock(_)
+        | Node::Class(_)
+    ) {
+      return;
+    }
+    // Only fix the statement if its range is exactly the `;` token.
+    let is_semi = ctx.token_at(empty_stmt.start()).is_some_and(|token| {
+      token.token == Token::Semi && token.end() == empty_stmt.end()
+    });
+    let fixes = if is_semi {
+      vec![LintFix {
+        description: FIX_DESC.into(),
+        changes: vec![LintFixChange {
+          new_text: "".into(),
+          range: empty_stmt.range(),
+        }],
+      }]
+    } else {
+      vec![]
+    };
+    ctx.add_diagnostic_with_fixes(
+      empty_stmt.range(),
+      CODE,
+      MESSAGE,
+      Some(HINT.to_string()),
+      fixes,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v9.0.0/tests/lib/rules/no-extra-semi.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_extra_semi_valid() {
+    assert_lint_ok! {
+      NoExtraSemi,
+      "var x = 5;",
+      "function foo(){}",
+      "for(;;);",
+      "while(0);",
+      "do;while(0);",
+      "for(a in b);",
+      "for(a of b);",
+      "if(true);",
+      "if(true); else;",
+      "foo: ;",
+      "with(foo);",
+      "class A { }",
+      "var A = class { };",
+      "class A { a() { this; } }",
+      "var A = class { a() { this; } };",
+      "class A { } a;",
+    };
+  }
+
+  #[test]
+  fn no_extra_semi_invalid() {
+    assert_lint_err! {
+      NoExtraSemi,
+      "var x = 5;;": [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "var x = 5;"),
+        }
+      ],
+      "function foo(){};": [
+        {
+          col: 16,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "function foo(){}"),
+        }
+      ],
+      "for(;;);;": [
+        {
+          col: 8,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "for(;;);"),
+        }
+      ],
+      "while(0);;": [
+        {
+          col: 9,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "while(0);"),
+        }
+      ],
+      "switch(a) { case 1: ; }": [
+        {
+          col: 20,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "switch(a) { case 1:  }"),
+        }
+      ],
+      "function f() { ; }": [
+        {
+          col: 15,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "function f() {  }"),
+        }
+      ],
+      "class A { ; }": [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "class A {  }"),
+        }
+      ],
+      "class A { a() {}; }": [
+        {
+          col: 16,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "class A { a() {} }"),
+        }
+      ],
+      "namespace N { ; }": [
+        {
+          col: 14,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "namespace N {  }"),
+        }
+      ],
+    };
+  }
+}
@@ -1,7 +1,8 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::program_ref;
-use super::{Context, LintRule};
+use super::{Context, LintRule, RuleDocs};
+use crate::diagnostic::LintDiagnosticRelated;
 use crate::tags::{self, Tags};
 use crate::Program;
 use crate::ProgramRef;
@@ -10,11 +11,45 @@ use deno_ast::swc::{
   ast::*,
   visit::{noop_visit_type, Visit, VisitWith},
 };
-use deno_ast::SourceRangedForSpanned;
+use deno_ast::{SourceRange, SourceRanged, SourceRangedForSpanned};
 use derive_more::Display;
+use regex::Regex;
+use serde::{Deserialize, Deserializer};
 
-#[derive(Debug)]
-pub struct NoFallthrough;
+#[derive(Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoFallthrough {
+  /// Allows cases without statements to fall through even when blank lines
+  /// or comments separate them from the next case. If disabled, only empty
+  /// cases directly followed by the next case on the same or the next line
+  /// are allowed.
+  pub allow_empty_case: bool,
+  /// Comments matching this regular expression mark an intentional
+  /// fallthrough, instead of the default `falls through`, `fall through` and
+  /// `fallthrough`.
+  #[serde(deserialize_with = "deserialize_pattern")]
+  pub comment_pattern: Option<Regex>,
+}
+
+impl Default for NoFallthrough {
+  fn default() -> Self {
+    Self {
+      allow_empty_case: true,
+      comment_pattern: None,
+    }
+  }
+}
+
+fn deserialize_pattern<'de, D>(
+  deserializer: D,
+) -> Result<Option<Regex>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  Option::<String>::deserialize(deserializer)?
+    .map(|pattern| Regex::new(&pattern).map_err(serde::de::Error::custom))
+    .transpose()
+}
 
 const CODE: &str = "no-fallthrough";
 
@@ -41,13 +76,61 @@ impl LintRule for NoFallthrough {
     CODE
   }
 
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows the statements of a `switch` case to fall \
+        through to the next case without a `break`, `return`, `throw` or \
+        `continue`, unless a comment like `/* falls through */` marks it as \
+        intentional. The `commentPattern` option sets a regular expression \
+        for such comments instead. Cases without statements may fall \
+        through; with `allowEmptyCase` disabled, they have to be directly \
+        followed by the next case, without blank lines or comments between \
+        them.",
+      invalid_examples: &[
+        "switch (a) {\n  case 1:\n    foo();\n  case 2:\n    bar();\n}",
+      ],
+      valid_examples: &[
+        "switch (a) {\n  case 1:\n    foo();\n    break;\n  case 2:\n    bar();\n}",
+        "switch (a) {\n  case 1:\n    foo();\n    // falls through\n  case 2:\n    bar();\n}",
+        "switch (a) {\n  case 1:\n  case 2:\n    bar();\n}",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "allowEmptyCase": {
+          "description": "Allow cases without statements to fall through even when blank lines or comments follow them. Defaults to `true`",
+          "type": "boolean"
+        },
+        "commentPattern": {
+          "description": "A regular expression for comments that mark an intentional fallthrough",
+          "type": "string"
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<NoFallthrough>(options)?))
+  }
+
   fn lint_program_with_ast_view<'view>(
     &self,
     context: &mut Context<'view>,
     program: Program<'view>,
   ) {
     let program = program_ref(program);
-    let mut visitor = NoFallthroughVisitor { context };
+    let mut visitor = NoFallthroughVisitor {
+      rule: self,
+      context,
+    };
     match program {
       ProgramRef::Module(m) => visitor.visit_module(m),
       ProgramRef::Script(s) => visitor.visit_script(s),
@@ -56,6 +139,7 @@ impl LintRule for NoFallthrough {
 }
 
 struct NoFallthroughVisitor<'c, 'view> {
+  rule: &'c NoFallthrough,
   context: &'c mut Context<'view>,
 }
 
@@ -66,18 +150,26 @@ impl<'c, 'view> Visit for NoFallthroughVisitor<'c, 'view> {
     let mut should_emit_err = false;
     let mut prev_range = None;
 
-    'cases: for case in cases.iter() {
+    'cases: for (index, case) in cases.iter().enumerate() {
       case.visit_with(self);
 
       if should_emit_err {
-        let comments = self.context.leading_comments_at(case.start());
-        if !allow_fall_through(comments) {
+        let comments = self
+          .context
+          .comment_lookups()
+          .leading_comments(case.range());
+        if !self.allow_fall_through(comments) {
           if let Some(prev_range) = prev_range.take() {
-            self.context.add_diagnostic_with_hint(
+            let related = LintDiagnosticRelated {
+              range: case_head_range(case, self.context),
+              message: "Execution falls through to this case".to_string(),
+            };
+            self.context.add_diagnostic_with_related(
               prev_range,
               CODE,
               NoFallthroughMessage::Unexpected,
-              NoFallthroughHint::BreakOrComment,
+              Some(NoFallthroughHint::BreakOrComment.to_string()),
+              vec![related],
             );
           }
         }
@@ -95,8 +187,11 @@ impl<'c, 'view> Visit for NoFallthroughVisitor<'c, 'view> {
         }
 
         if last {
-          let comments = self.context.trailing_comments_at(stmt.end());
-          if allow_fall_through(comments) {
+          let comments = self
+            .context
+            .comment_lookups()
+            .trailing_comments(stmt.range());
+          if self.allow_fall_through(comments) {
             should_emit_err = false;
             // User comment beats everything
             prev_range = Some(case.range());
@@ -108,7 +203,12 @@ impl<'c, 'view> Visit for NoFallthroughVisitor<'c, 'view> {
       let empty = case.cons.is_empty()
         || matches!(case.cons.as_slice(), [Stmt::Block(b)] if b.stmts.is_empty());
 
-      if empty {
+      if empty
+        && (self.rule.allow_empty_case
+          || cases
+            .get(index + 1)
+            .map_or(true, |next| self.is_on_next_line(case, next)))
+      {
         should_emit_err = false;
       }
 
@@ -117,15 +217,44 @@ impl<'c, 'view> Visit for NoFallthroughVisitor<'c, 'view> {
   }
 }
 
-fn allow_fall_through<'c>(
-  mut comments: impl Iterator<Item = &'c Comment>,
-) -> bool {
-  comments.any(|comment| {
-    let l = comment.text.to_ascii_lowercase();
-    l.contains("fallthrough")
-      || l.contains("falls through")
-      || l.contains("fall through")
-  })
+impl NoFallthroughVisitor<'_, '_> {
+  fn allow_fall_through<'c>(
+    &self,
+    mut comments: impl Iterator<Item = &'c Comment>,
+  ) -> bool {
+    match &self.rule.comment_pattern {
+      Some(pattern) => comments.any(|comment| pattern.is_match(&comment.text)),
+      None => comments.any(|comment| {
+        let l = comment.text.to_ascii_lowercase();
+        l.contains("fallthrough")
+          || l.contains("falls through")
+          || l.contains("fall through")
+      }),
+    }
+  }
+
+  /// Checks if the next case starts on the same line or the line after the
+  /// end of the case, without blank lines or comments between them.
+  fn is_on_next_line(&self, case: &SwitchCase, next: &SwitchCase) -> bool {
+    let text_info = self.context.text_info();
+    text_info.line_index(next.start()) <= text_info.line_index(case.end()) + 1
+      && self
+        .context
+        .comment_lookups()
+        .leading_comments(next.range())
+        .next()
+        .is_none()
+  }
+}
+
+/// Returns the range of `case x:` or `default:`, without the statements.
+fn case_head_range(case: &SwitchCase, context: &Context) -> SourceRange {
+  let test_end = case.test.as_ref().map_or(case.start(), |test| test.end());
+  let rest = SourceRange::new(test_end, case.end());
+  match rest.text_fast(context.text_info()).find(':') {
+    Some(index) => SourceRange::new(case.start(), test_end + index + 1),
+    None => case.range(),
+  }
 }
 
 #[cfg(test)]
@@ -135,7 +264,7 @@ mod tests {
   #[test]
   fn no_fallthrough_valid() {
     assert_lint_ok! {
-      NoFallthrough,
+      NoFallthrough::default(),
       "switch(foo) { case 0: a(); /* falls through */ case 1: b(); }",
       "switch(foo) { case 0: a()\n /* falls through */ case 1: b(); }",
       "switch(foo) { case 0: a(); /* fall through */ case 1: b(); }",
@@ -197,7 +326,7 @@ switch(someValue) {
   #[test]
   fn no_fallthrough_invalid() {
     assert_lint_err! {
-      NoFallthrough,
+      NoFallthrough::default(),
       "switch(foo) { case 0: a();\ncase 1: b() }": [
         {
           col: 14,
@@ -261,7 +390,7 @@ switch(someValue) {
   #[ignore = "It ends with break statement"]
   fn no_fallthrough_invalid_2() {
     assert_lint_err! {
-      NoFallthrough,
+      NoFallthrough::default(),
       "switch(foo) { case 0: do { break; } while (a); default: b() }": [
         {
           col: 47,
@@ -271,4 +400,67 @@ switch(someValue) {
       ]
     };
   }
+
+  #[test]
+  fn no_fallthrough_allow_empty_case() {
+    let rule = || NoFallthrough {
+      allow_empty_case: false,
+      ..Default::default()
+    };
+    assert_lint_ok! {
+      rule(),
+      "switch(foo) { case 0: case 1: a(); }",
+      "switch(foo) { case 0:\n case 1: a(); }",
+      "switch(foo) { case 0: {}\n case 1: a(); }",
+      "switch(foo) { case 0:\n\n /* falls through */\n case 1: a(); }",
+      "switch(foo) { case 0: a(); break;\n\n case 1: b(); }",
+    };
+    assert_lint_err! {
+      rule(),
+      "switch(foo) { case 0:\n\n case 1: a(); }": [
+        {
+          col: 14,
+          message: NoFallthroughMessage::Unexpected,
+          hint: NoFallthroughHint::BreakOrComment,
+        }
+      ],
+      "switch(foo) { case 0:\n // comment\n default: a(); }": [
+        {
+          col: 14,
+          message: NoFallthroughMessage::Unexpected,
+          hint: NoFallthroughHint::BreakOrComment,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_fallthrough_comment_pattern() {
+    let rule = || NoFallthrough {
+      comment_pattern: Some(Regex::new("(?i)continue to next").unwrap()),
+      ..Default::default()
+    };
+    assert_lint_ok! {
+      rule(),
+      "switch(foo) { case 0: a(); /* Continue to next */ case 1: b(); }",
+      "switch(foo) { case 0: a()\n // continue to next\n case 1: b(); }",
+    };
+    assert_lint_err! {
+      rule(),
+      "switch(foo) { case 0: a(); /* falls through */ case 1: b(); }": [
+        {
+          col: 14,
+          message: NoFallthroughMessage::Unexpected,
+          hint: NoFallthroughHint::BreakOrComment,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn comment_pattern_must_be_a_regex() {
+    let rule = NoFallthrough::default()
+      .with_options(serde_json::json!({ "commentPattern": "(" }));
+    assert!(rule.is_err());
+  }
 }
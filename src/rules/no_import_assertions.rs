@@ -1,12 +1,14 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
 use crate::handler::{Handler, Traverse};
 use crate::tags::{self, Tags};
 use crate::Program;
+use deno_ast::swc::ast::ObjectLit;
 use deno_ast::swc::parser::token::{IdentLike, KnownIdent, Token, Word};
 use deno_ast::view as ast_view;
-use deno_ast::{SourceRanged, SourceRangedForSpanned};
+use deno_ast::{SourceRange, SourceRanged, SourceRangedForSpanned};
 use if_chain::if_chain;
 
 #[derive(Debug)]
@@ -16,6 +18,7 @@ const CODE: &str = "no-import-assertions";
 const MESSAGE: &str =
   "The `assert` keyword is deprecated for import attributes";
 const HINT: &str = "Instead use the `with` keyword";
+const FIX_DESC: &str = "Replace `assert` with `with`";
 
 impl LintRule for NoImportAssertions {
   fn tags(&self) -> Tags {
@@ -26,6 +29,10 @@ impl LintRule for NoImportAssertions {
     CODE
   }
 
+  fn fixable(&self) -> bool {
+    true
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
@@ -43,22 +50,23 @@ impl Handler for NoImportAssertionsHandler {
     import_decl: &ast_view::ImportDecl,
     ctx: &mut Context,
   ) {
-    if_chain! {
-      if let Some(with) = import_decl.with;
-      if let Some(prev_token_and_span) = with.start().previous_token_fast(ctx.program());
-      if let Token::Word(word) = &prev_token_and_span.token;
-      if let Word::Ident(ident_like) = word;
-      if let IdentLike::Known(known_ident) = ident_like;
-      if matches!(known_ident, KnownIdent::Assert);
-      then {
-        ctx.add_diagnostic_with_hint(
-          prev_token_and_span.span.range(),
-          CODE,
-          MESSAGE,
-          HINT,
-        );
-      }
-    }
+    check_keyword(import_decl.inner.with.as_deref(), ctx);
+  }
+
+  fn named_export(
+    &mut self,
+    named_export: &ast_view::NamedExport,
+    ctx: &mut Context,
+  ) {
+    check_keyword(named_export.inner.with.as_deref(), ctx);
+  }
+
+  fn export_all(
+    &mut self,
+    export_all: &ast_view::ExportAll,
+    ctx: &mut Context,
+  ) {
+    check_keyword(export_all.inner.with.as_deref(), ctx);
   }
 
   fn call_expr(&mut self, call_expr: &ast_view::CallExpr, ctx: &mut Context) {
@@ -75,22 +83,16 @@ impl Handler for NoImportAssertionsHandler {
               match key_value_prop.key {
                 ast_view::PropName::Ident(ident) => {
                   if ident.sym().as_ref() == "assert" {
-                    ctx.add_diagnostic_with_hint(
-                      ident.range(),
-                      CODE,
-                      MESSAGE,
-                      HINT,
-                    );
+                    report(ident.range(), ident.range(), ctx);
                   }
                 },
                 ast_view::PropName::Str(str) => {
                   if str.value().as_ref() == "assert" {
-                    ctx.add_diagnostic_with_hint(
-                      str.range(),
-                      CODE,
-                      MESSAGE,
-                      HINT,
-                    );
+                    // Keep the quotes of the key.
+                    let range = str.range();
+                    let inner =
+                      SourceRange::new(range.start + 1, range.end - 1);
+                    report(range, inner, ctx);
                   }
                 }
                 _ => (),
@@ -103,6 +105,39 @@ impl Handler for NoImportAssertionsHandler {
   }
 }
 
+/// Reports the keyword in front of the attributes of an import or export
+/// declaration if it is `assert`.
+fn check_keyword(with: Option<&ObjectLit>, ctx: &mut Context) {
+  if_chain! {
+    if let Some(with) = with;
+    if let Some(prev_token_and_span) = with.start().previous_token_fast(ctx.program());
+    if let Token::Word(word) = &prev_token_and_span.token;
+    if let Word::Ident(ident_like) = word;
+    if let IdentLike::Known(known_ident) = ident_like;
+    if matches!(known_ident, KnownIdent::Assert);
+    then {
+      let range = prev_token_and_span.span.range();
+      report(range, range, ctx);
+    }
+  }
+}
+
+fn report(range: SourceRange, keyword: SourceRange, ctx: &mut Context) {
+  ctx.add_diagnostic_with_fixes(
+    range,
+    CODE,
+    MESSAGE,
+    Some(HINT.to_string()),
+    vec![LintFix {
+      description: FIX_DESC.into(),
+      changes: vec![LintFixChange {
+        new_text: "with".into(),
+        range: keyword,
+      }],
+    }],
+  );
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -113,6 +148,8 @@ mod tests {
       NoImportAssertions,
       r#"import foo from './foo.js';"#,
       r#"import foo from './foo.js' with { bar: 'bar' };"#,
+      r#"export { foo } from './foo.json' with { type: 'json' };"#,
+      r#"export * from './foo.js' with { bar: 'bar' };"#,
       r#"import('./foo.js');"#,
       r#"import('./foo.js', { with: { bar: 'bar' } });"#,
       r#"import('./foo.js', { "with": { bar: 'bar' } });"#,
@@ -129,18 +166,35 @@ mod tests {
         {
           line: 1,
           col: 27,
+          fix: (FIX_DESC, r#"import foo from './foo.js' with { bar: 'bar' };"#),
+        },
+      ],
+      r#"export { foo } from './foo.json' assert { type: 'json' };"#: [
+        {
+          line: 1,
+          col: 33,
+          fix: (FIX_DESC, r#"export { foo } from './foo.json' with { type: 'json' };"#),
+        },
+      ],
+      r#"export * from './foo.js' assert { bar: 'bar' };"#: [
+        {
+          line: 1,
+          col: 25,
+          fix: (FIX_DESC, r#"export * from './foo.js' with { bar: 'bar' };"#),
         },
       ],
       r#"import('./foo.js', { assert: { bar: 'bar' } });"#: [
         {
           line: 1,
           col: 21,
+          fix: (FIX_DESC, r#"import('./foo.js', { with: { bar: 'bar' } });"#),
         },
       ],
       r#"import('./foo.js', { "assert": { bar: 'bar' } });"#: [
         {
           line: 1,
           col: 21,
+          fix: (FIX_DESC, r#"import('./foo.js', { "with": { bar: 'bar' } });"#),
         },
       ],
     };
@@ -1,22 +1,49 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::program_ref;
-use super::{Context, LintRule};
+use super::{Context, LintRule, RuleDocs};
 use crate::tags::{self, Tags};
 use crate::Program;
 use crate::ProgramRef;
 use deno_ast::swc::ast::{
-  ArrowExpr, BlockStmtOrExpr, Constructor, Decl, DefaultDecl, FnDecl, Function,
-  ModuleDecl, ModuleItem, Script, Stmt, VarDecl, VarDeclKind,
+  ArrowExpr, BlockStmtOrExpr, Class, Constructor, Decl, DefaultDecl, Expr,
+  FnDecl, Function, Lit, ModuleDecl, ModuleItem, Script, Stmt, VarDecl,
+  VarDeclKind,
 };
 use deno_ast::swc::visit::{noop_visit_type, Visit, VisitWith};
+use deno_ast::MediaType;
 use deno_ast::SourceRange;
 use deno_ast::SourceRangedForSpanned;
 use derive_more::Display;
+use serde::Deserialize;
 use std::collections::HashSet;
 
-#[derive(Debug)]
-pub struct NoInnerDeclarations;
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoInnerDeclarations {
+  /// Whether `var` declarations in blocks are reported as well as function
+  /// declarations.
+  pub mode: Mode,
+  /// Whether function declarations in blocks are allowed in strict code,
+  /// where they are scoped to the block like `let` declarations.
+  pub block_scoped_functions: BlockScopedFunctions,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Mode {
+  Functions,
+  #[default]
+  Both,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BlockScopedFunctions {
+  Allow,
+  #[default]
+  Disallow,
+}
 
 const CODE: &str = "no-inner-declarations";
 
@@ -41,6 +68,54 @@ impl LintRule for NoInnerDeclarations {
     CODE
   }
 
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows function declarations and `var` declarations \
+        in nested blocks. Such declarations are hoisted out of the block, or \
+        in the case of functions in sloppy mode scripts, behave differently \
+        across runtimes. With the `functions` mode, only function \
+        declarations are reported. With `blockScopedFunctions` set to \
+        `allow`, function declarations in blocks are allowed in strict code, \
+        i.e. in ES modules and classes, and in CommonJS scripts or functions \
+        with a `\"use strict\"` directive, where they are scoped to the \
+        block.",
+      invalid_examples: &[
+        "if (test) {\n  function doSomething() {}\n}",
+        "while (test) {\n  var foo;\n}",
+      ],
+      valid_examples: &[
+        "function doSomething() {\n  function somethingElse() {}\n}",
+        "if (test) {\n  const doSomething = () => {};\n}",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "mode": {
+          "description": "Report function declarations only, or `var` declarations as well. Defaults to `both`",
+          "enum": ["functions", "both"]
+        },
+        "blockScopedFunctions": {
+          "description": "Allow function declarations in blocks of strict code. Defaults to `disallow`",
+          "enum": ["allow", "disallow"]
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<NoInnerDeclarations>(
+      options,
+    )?))
+  }
+
   fn lint_program_with_ast_view<'view>(
     &self,
     context: &mut Context<'view>,
@@ -53,8 +128,25 @@ impl LintRule for NoInnerDeclarations {
       ProgramRef::Script(s) => s.visit_with(&mut valid_visitor),
     }
 
-    let mut visitor =
-      NoInnerDeclarationsVisitor::new(context, valid_visitor.valid_decls);
+    // Files are ES modules, which are always strict, unless they are
+    // CommonJS scripts.
+    let (is_module, strict) = match program {
+      ProgramRef::Module(_) => (true, true),
+      ProgramRef::Script(_)
+        if !matches!(context.media_type(), MediaType::Cjs | MediaType::Cts) =>
+      {
+        (true, true)
+      }
+      ProgramRef::Script(s) => (false, has_use_strict(&s.body)),
+    };
+    let mut visitor = NoInnerDeclarationsVisitor {
+      context,
+      options: self,
+      valid_decls: valid_visitor.valid_decls,
+      in_function: false,
+      is_module,
+      strict,
+    };
     match program {
       ProgramRef::Module(m) => m.visit_with(&mut visitor),
       ProgramRef::Script(s) => s.visit_with(&mut visitor),
@@ -154,31 +246,43 @@ impl Visit for ValidDeclsVisitor {
   }
 }
 
+/// Checks if the statements start with a `"use strict"` directive.
+fn has_use_strict(stmts: &[Stmt]) -> bool {
+  stmts
+    .iter()
+    .map_while(|stmt| match stmt {
+      Stmt::Expr(expr_stmt) => match &*expr_stmt.expr {
+        Expr::Lit(Lit::Str(lit)) => Some(lit),
+        _ => None,
+      },
+      _ => None,
+    })
+    .any(|directive| {
+      directive
+        .raw
+        .as_ref()
+        .is_some_and(|raw| &raw[1..raw.len() - 1] == "use strict")
+    })
+}
+
 struct NoInnerDeclarationsVisitor<'c, 'view> {
   context: &'c mut Context<'view>,
+  options: &'c NoInnerDeclarations,
   valid_decls: HashSet<SourceRange>,
   in_function: bool,
-}
-
-impl<'c, 'view> NoInnerDeclarationsVisitor<'c, 'view> {
-  fn new(
-    context: &'c mut Context<'view>,
-    valid_decls: HashSet<SourceRange>,
-  ) -> Self {
-    Self {
-      context,
-      valid_decls,
-      in_function: false,
-    }
-  }
+  is_module: bool,
+  /// Whether the current code is strict mode code.
+  strict: bool,
 }
 
 impl<'c, 'view> NoInnerDeclarationsVisitor<'c, 'view> {
   fn add_diagnostic(&mut self, range: SourceRange, kind: &str) {
     let root = if self.in_function {
       "function"
-    } else {
+    } else if self.is_module {
       "module"
+    } else {
+      "script"
     };
 
     self.context.add_diagnostic_with_hint(
@@ -194,23 +298,38 @@ impl<'c, 'view> Visit for NoInnerDeclarationsVisitor<'c, 'view> {
   noop_visit_type!();
 
   fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr) {
-    let old = self.in_function;
+    let old = (self.in_function, self.strict);
     self.in_function = true;
+    if let BlockStmtOrExpr::BlockStmt(block) = &*arrow_expr.body {
+      self.strict |= has_use_strict(&block.stmts);
+    }
     arrow_expr.visit_children_with(self);
-    self.in_function = old;
+    (self.in_function, self.strict) = old;
   }
 
   fn visit_function(&mut self, function: &Function) {
-    let old = self.in_function;
+    let old = (self.in_function, self.strict);
     self.in_function = true;
+    if let Some(block) = &function.body {
+      self.strict |= has_use_strict(&block.stmts);
+    }
     function.visit_children_with(self);
-    self.in_function = old;
+    (self.in_function, self.strict) = old;
+  }
+
+  fn visit_class(&mut self, class: &Class) {
+    let old = self.strict;
+    self.strict = true;
+    class.visit_children_with(self);
+    self.strict = old;
   }
 
   fn visit_fn_decl(&mut self, decl: &FnDecl) {
     let range = decl.range();
 
-    if !self.valid_decls.contains(&range) {
+    let is_block_scoped = self.strict
+      && self.options.block_scoped_functions == BlockScopedFunctions::Allow;
+    if !self.valid_decls.contains(&range) && !is_block_scoped {
       self.add_diagnostic(range, "function");
     }
 
@@ -220,7 +339,10 @@ impl<'c, 'view> Visit for NoInnerDeclarationsVisitor<'c, 'view> {
   fn visit_var_decl(&mut self, decl: &VarDecl) {
     let range = decl.range();
 
-    if decl.kind == VarDeclKind::Var && !self.valid_decls.contains(&range) {
+    if self.options.mode == Mode::Both
+      && decl.kind == VarDeclKind::Var
+      && !self.valid_decls.contains(&range)
+    {
       self.add_diagnostic(range, "variable");
     }
 
@@ -235,7 +357,7 @@ mod tests {
   #[test]
   fn no_inner_declarations_valid() {
     assert_lint_ok! {
-      NoInnerDeclarations,
+      NoInnerDeclarations::default(),
       "function doSomething() { }",
       "function doSomething() { function somethingElse() { } }",
       "(function() { function doSomething() { } }());",
@@ -267,7 +389,7 @@ mod tests {
   #[test]
   fn no_inner_declarations_invalid() {
     assert_lint_err! {
-      NoInnerDeclarations,
+      NoInnerDeclarations::default(),
 
       // fn decls
       "if (test) { function doSomething() { } }": [
@@ -391,4 +513,90 @@ mod tests {
       ]
     };
   }
+
+  #[test]
+  fn no_inner_declarations_options() {
+    assert_lint_ok! {
+      NoInnerDeclarations {
+        mode: Mode::Functions,
+        ..Default::default()
+      },
+      "if (test) { var foo; }",
+      "function bar() { if (foo) var a; }",
+    };
+    assert_lint_err! {
+      NoInnerDeclarations {
+        mode: Mode::Functions,
+        ..Default::default()
+      },
+      "if (test) { function f() {} var foo; }": [
+        {
+          col: 12,
+          message: variant!(NoInnerDeclarationsMessage, Move, "function", "module"),
+          hint: NoInnerDeclarationsHint::Move,
+        }
+      ],
+    };
+
+    assert_lint_ok! {
+      NoInnerDeclarations {
+        block_scoped_functions: BlockScopedFunctions::Allow,
+        ..Default::default()
+      },
+      "if (test) { function f() {} }",
+      "function bar() { if (foo) { function f() {} } }",
+    };
+    assert_lint_err! {
+      NoInnerDeclarations {
+        block_scoped_functions: BlockScopedFunctions::Allow,
+        ..Default::default()
+      },
+      "if (test) { var foo; }": [
+        {
+          col: 12,
+          message: variant!(NoInnerDeclarationsMessage, Move, "variable", "module"),
+          hint: NoInnerDeclarationsHint::Move,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_inner_declarations_script() {
+    let allow = || NoInnerDeclarations {
+      block_scoped_functions: BlockScopedFunctions::Allow,
+      ..Default::default()
+    };
+    let lint = |source: &str| {
+      let (_, diagnostics) = crate::testing::lint(
+        Box::new(allow()),
+        source,
+        "file:///no_inner_declarations_test.cjs",
+      );
+      diagnostics
+    };
+    let diagnostics = lint("if (test) { function f() {} }");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+      diagnostics[0].details.message,
+      "Move function declaration to script root"
+    );
+    assert!(lint("'use strict';\nif (test) { function f() {} }").is_empty());
+    assert!(lint(
+      "function g() {\n  'use strict';\n  if (test) { function f() {} }\n}"
+    )
+    .is_empty());
+    assert!(
+      lint("class A { m() { if (test) { function f() {} } } }").is_empty()
+    );
+    assert_eq!(
+      lint("function g() { if (test) { function f() {} } }").len(),
+      1
+    );
+    // A directive only counts at the start of the body.
+    assert_eq!(
+      lint("foo();\n'use strict';\nif (test) { function f() {} }").len(),
+      1
+    );
+  }
 }
@@ -58,10 +58,8 @@ struct NoInvalidRegexpVisitor<'c, 'view> {
 
 impl<'c, 'view> NoInvalidRegexpVisitor<'c, 'view> {
   fn new(context: &'c mut Context<'view>) -> Self {
-    Self {
-      context,
-      validator: EcmaRegexValidator::new(EcmaVersion::Es2022),
-    }
+    let validator = EcmaRegexValidator::new(context.ecma_version());
+    Self { context, validator }
   }
 
   fn handle_call_or_new_expr(
@@ -191,4 +189,31 @@ let re = new RegExp('foo', x);",
       r"/(a)(a)(a)(a)(a)(a)(a)(a)(a)(a)\11/u": [{ col: 0, message: MESSAGE, hint: HINT }],
     }
   }
+
+  #[test]
+  fn no_invalid_regexp_ecma_version() {
+    let lint = |source: &str, ecma_version: EcmaVersion| {
+      let (_, diagnostics) = crate::testing::lint_with_ecma_version(
+        Box::new(NoInvalidRegexp),
+        source,
+        "file:///no_invalid_regexp_test.ts",
+        ecma_version,
+      );
+      diagnostics.len()
+    };
+    let newer_features = [
+      ("/a/s;", EcmaVersion::Es2018),
+      ("/(?<=a)b/;", EcmaVersion::Es2018),
+      ("/(?<a>b)\\k<a>/u;", EcmaVersion::Es2018),
+      ("/\\p{Letter}/u;", EcmaVersion::Es2018),
+      ("/a/d;", EcmaVersion::Es2022),
+      ("new RegExp('a', 'v');", EcmaVersion::Es2024),
+    ];
+    for (source, version) in newer_features {
+      assert_eq!(lint(source, version), 0, "{}", source);
+      assert_eq!(lint(source, EcmaVersion::Es2017), 1, "{}", source);
+    }
+    assert_eq!(lint("new RegExp('a', 'v');", EcmaVersion::Es2023), 1);
+    assert_eq!(lint("/a/u;", EcmaVersion::Es5), 1);
+  }
 }
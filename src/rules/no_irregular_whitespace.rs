@@ -3,14 +3,37 @@
 use super::{Context, LintRule};
 use crate::tags::{self, Tags};
 use crate::Program;
-use deno_ast::{RootNode, SourceRangedForSpanned};
+use deno_ast::swc::parser::token::Token;
+use deno_ast::SourceRangedForSpanned;
 use deno_ast::{SourceRange, SourceRanged};
 use derive_more::Display;
 use once_cell::sync::Lazy;
 use regex::{Matches, Regex};
+use serde::Deserialize;
 
-#[derive(Debug)]
-pub struct NoIrregularWhitespace;
+#[derive(Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoIrregularWhitespace {
+  /// Allows irregular whitespace in string literals.
+  pub skip_strings: bool,
+  /// Allows irregular whitespace in template literals.
+  pub skip_templates: bool,
+  /// Allows irregular whitespace in regular expression literals.
+  pub skip_reg_exps: bool,
+  /// Allows irregular whitespace in comments.
+  pub skip_comments: bool,
+}
+
+impl Default for NoIrregularWhitespace {
+  fn default() -> Self {
+    Self {
+      skip_strings: true,
+      skip_templates: true,
+      skip_reg_exps: true,
+      skip_comments: false,
+    }
+  }
+}
 
 const CODE: &str = "no-irregular-whitespace";
 const HINT: &str = "Change to a normal space or tab";
@@ -49,12 +72,55 @@ impl LintRule for NoIrregularWhitespace {
     CODE
   }
 
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "skipStrings": {
+          "description": "Allows irregular whitespace in string literals",
+          "type": "boolean"
+        },
+        "skipTemplates": {
+          "description": "Allows irregular whitespace in template literals",
+          "type": "boolean"
+        },
+        "skipRegExps": {
+          "description": "Allows irregular whitespace in regular expression literals",
+          "type": "boolean"
+        },
+        "skipComments": {
+          "description": "Allows irregular whitespace in comments",
+          "type": "boolean"
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<NoIrregularWhitespace>(
+      options,
+    )?))
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
-    program: Program,
+    _program: Program,
   ) {
     let file_range = context.text_info().range();
+    let tokens = context.tokens();
+    let comment_ranges = if self.skip_comments {
+      context
+        .all_comments()
+        .map(|comment| comment.range())
+        .collect::<Vec<_>>()
+    } else {
+      Vec::new()
+    };
     let mut check_range = |range: SourceRange| {
       let whitespace_text = range.text_fast(context.text_info()).to_string();
       for whitespace_matches in
@@ -66,6 +132,12 @@ impl LintRule for NoIrregularWhitespace {
             range.start + whitespace_range.start,
             range.start + whitespace_range.end,
           );
+          if comment_ranges
+            .iter()
+            .any(|comment_range| comment_range.contains(&range))
+          {
+            continue;
+          }
           context.add_diagnostic_with_hint(
             range,
             CODE,
@@ -77,8 +149,17 @@ impl LintRule for NoIrregularWhitespace {
     };
     let mut last_end = file_range.start.as_source_pos();
 
-    for token in program.token_container().tokens {
+    for token in tokens {
       check_range(SourceRange::new(last_end, token.start()));
+      let is_skipped = match &token.token {
+        Token::Str { .. } => self.skip_strings,
+        Token::Template { .. } => self.skip_templates,
+        Token::Regex(..) => self.skip_reg_exps,
+        _ => true,
+      };
+      if !is_skipped {
+        check_range(token.range());
+      }
       last_end = token.end();
     }
 
@@ -93,7 +174,7 @@ mod tests {
   #[test]
   fn no_irregular_whitespace_valid() {
     assert_lint_ok! {
-      NoIrregularWhitespace,
+      NoIrregularWhitespace::default(),
       "'\\u{000B}';",
       "'\\u{000C}';",
       "'\\u{0085}';",
@@ -146,7 +227,7 @@ mod tests {
   #[test]
   fn no_irregular_whitespace_invalid() {
     assert_lint_err! {
-      NoIrregularWhitespace,
+      NoIrregularWhitespace::default(),
       "var any \u{000B} = 'thing';": [
         {
           col: 8,
@@ -323,4 +404,104 @@ mod tests {
       ]
     };
   }
+
+  #[test]
+  fn no_irregular_whitespace_options_valid() {
+    assert_lint_ok! {
+      NoIrregularWhitespace {
+        skip_comments: true,
+        ..Default::default()
+      },
+      "// foo\u{00A0}bar",
+      "/* foo\u{3000}bar */ var x;",
+      "var x; /** \u{2028} */",
+    };
+
+    assert_lint_ok! {
+      NoIrregularWhitespace {
+        skip_strings: false,
+        ..Default::default()
+      },
+      "`\u{00A0}`;",
+      "/\u{00A0}/;",
+      "'\\u{00A0}';",
+    };
+  }
+
+  #[test]
+  fn no_irregular_whitespace_options_invalid() {
+    assert_lint_err! {
+      NoIrregularWhitespace::default(),
+      "// foo\u{00A0}bar": [
+        {
+          col: 6,
+          message: NoIrregularWhitespaceMessage::NotAllowed,
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoIrregularWhitespace {
+        skip_strings: false,
+        ..Default::default()
+      },
+      "var a = 'b\u{00A0}c';": [
+        {
+          col: 10,
+          message: NoIrregularWhitespaceMessage::NotAllowed,
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoIrregularWhitespace {
+        skip_templates: false,
+        ..Default::default()
+      },
+      "`\u{00A0}`;": [
+        {
+          col: 1,
+          message: NoIrregularWhitespaceMessage::NotAllowed,
+          hint: HINT,
+        }
+      ],
+      "`a${b}\u{3000}`;": [
+        {
+          col: 6,
+          message: NoIrregularWhitespaceMessage::NotAllowed,
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoIrregularWhitespace {
+        skip_reg_exps: false,
+        ..Default::default()
+      },
+      "/a\u{00A0}b/;": [
+        {
+          col: 2,
+          message: NoIrregularWhitespaceMessage::NotAllowed,
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoIrregularWhitespace {
+        skip_comments: true,
+        ..Default::default()
+      },
+      "var any \u{3000} = 'thing'; // \u{3000}": [
+        {
+          col: 8,
+          message: NoIrregularWhitespaceMessage::NotAllowed,
+          hint: HINT,
+        }
+      ],
+    };
+  }
 }
@@ -0,0 +1,168 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::{Handler, Traverse};
+use crate::tags::{self, Tags};
+use crate::Program;
+use deno_ast::swc::ast::Stmt;
+use deno_ast::view as ast_view;
+use deno_ast::{SourceRanged, SourceRangedForSpanned};
+
+#[derive(Debug)]
+pub struct NoLonelyIf;
+
+const CODE: &str = "no-lonely-if";
+const MESSAGE: &str =
+  "Unexpected `if` as the only statement in an `else` block";
+const HINT: &str = "Combine the `else` and the `if` into `else if`";
+const FIX_DESC: &str = "Use `else if`";
+
+impl LintRule for NoLonelyIf {
+  fn tags(&self) -> Tags {
+    &[tags::STYLISTIC]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows `if` statements as the only statement in an \
+        `else` block. Such a statement can be written as `else if` instead, \
+        which saves a level of nesting.",
+      invalid_examples: &[
+        "if (a) {\n  foo();\n} else {\n  if (b) {\n    bar();\n  }\n}",
+      ],
+      valid_examples: &[
+        "if (a) {\n  foo();\n} else if (b) {\n  bar();\n}",
+        "if (a) {\n  foo();\n} else {\n  if (b) {\n    bar();\n  }\n  baz();\n}",
+      ],
+    }
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoLonelyIfHandler.traverse(program, context);
+  }
+}
+
+struct NoLonelyIfHandler;
+
+impl Handler for NoLonelyIfHandler {
+  fn if_stmt(&mut self, if_stmt: &ast_view::IfStmt, ctx: &mut Context) {
+    let Some(Stmt::Block(block)) = if_stmt.inner.alt.as_deref() else {
+      return;
+    };
+    let [Stmt::If(lonely_if)] = block.stmts.as_slice() else {
+      return;
+    };
+
+    let block_range = block.range();
+    let range = lonely_if.range();
+    // Comments in the block around the `if` would be lost.
+    let has_comments = ctx
+      .comment_lookups()
+      .has_comment_matching(block_range, |comment| {
+        !range.contains(&comment.range())
+      });
+    let fixes = if has_comments {
+      vec![]
+    } else {
+      vec![LintFix {
+        description: FIX_DESC.into(),
+        changes: vec![LintFixChange {
+          new_text: range.text_fast(ctx.text_info()).to_string().into(),
+          range: block_range,
+        }],
+      }]
+    };
+    ctx.add_diagnostic_with_fixes(
+      range,
+      CODE,
+      MESSAGE,
+      Some(HINT.to_string()),
+      fixes,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_lonely_if_valid() {
+    assert_lint_ok! {
+      NoLonelyIf,
+      "if (a) {} else if (b) {}",
+      "if (a) {} else { if (b) {} foo(); }",
+      "if (a) {} else { foo(); if (b) {} }",
+      "if (a) { if (b) {} }",
+      "if (a) {} else { { if (b) {} } }",
+      "if (a) {} else {}",
+    };
+  }
+
+  #[test]
+  fn no_lonely_if_invalid() {
+    assert_lint_err! {
+      NoLonelyIf,
+      "if (a) {} else { if (b) {} }": [
+        {
+          col: 17,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "if (a) {} else if (b) {}"),
+        }
+      ],
+      "if (a) {} else { if (b) { foo(); } else { bar(); } }": [
+        {
+          col: 17,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "if (a) {} else if (b) { foo(); } else { bar(); }"),
+        }
+      ],
+      "if (a) {} else {\n  if (b) foo();\n}": [
+        {
+          line: 2,
+          col: 2,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "if (a) {} else if (b) foo();"),
+        }
+      ],
+      "if (a) {} else { if (b) {} else { if (c) {} } }": [
+        {
+          col: 17,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "if (a) {} else if (b) {} else { if (c) {} }"),
+        },
+        {
+          col: 34,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "if (a) {} else { if (b) {} else if (c) {} }"),
+        }
+      ],
+      "if (a) {} else {\n  // b is rare\n  if (b) {}\n}": [
+        {
+          line: 3,
+          col: 2,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
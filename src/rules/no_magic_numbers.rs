@@ -0,0 +1,609 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::handler::{Handler, Traverse};
+use crate::tags::Tags;
+use crate::Program;
+use deno_ast::swc::ast::{
+  AssignTarget, Callee, Expr, MemberProp, SimpleAssignTarget, UnaryOp,
+  VarDeclKind,
+};
+use deno_ast::view::{self as ast_view, Node, NodeTrait};
+use deno_ast::{SourceRanged, SourceRangedForSpanned};
+use serde::Deserialize;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoMagicNumbers {
+  /// Numbers that are never reported. BigInts are written as strings like
+  /// `"1n"`.
+  pub ignore: Vec<IgnoredNumber>,
+  /// Allows numbers used as array indexes, e.g. `items[2]`.
+  pub ignore_array_indexes: bool,
+  /// Allows numbers used as default values of parameters and destructured
+  /// variables.
+  pub ignore_default_values: bool,
+  /// Allows numbers used as values of TypeScript enum members.
+  pub ignore_enums: bool,
+  /// Requires variables that are initialized with a number to be declared
+  /// with `const`.
+  pub enforce_const: bool,
+  /// Also reports numbers used as values of object properties, class fields
+  /// and assignments to properties.
+  pub detect_objects: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum IgnoredNumber {
+  Number(f64),
+  BigInt(String),
+}
+
+const CODE: &str = "no-magic-numbers";
+
+const MESSAGE: &str = "No magic number";
+const HINT: &str =
+  "Move the number into a constant with a name that explains its meaning";
+const USE_CONST_MESSAGE: &str =
+  "Number constants must be declared with `const`";
+const USE_CONST_HINT: &str = "Declare the variable with `const`";
+
+impl LintRule for NoMagicNumbers {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows numbers whose meaning isn't explained by a \
+        name, also known as magic numbers. Numbers used to initialize a \
+        variable are named by it, and so are the values of object \
+        properties, class fields and assignments to properties unless the \
+        `detectObjects` option is set. Numbers in type positions, like \
+        literal types, are never reported. Specific numbers can be allowed \
+        with the `ignore` option, and array indexes, default values and \
+        enum member values with the `ignoreArrayIndexes`, \
+        `ignoreDefaultValues` and `ignoreEnums` options. The \
+        `enforceConst` option requires the variables naming numbers to be \
+        declared with `const`.",
+      invalid_examples: &[
+        "const total = price * 1.19;",
+        "setTimeout(retry, 3000);",
+        "let attempts;\nattempts = 5;",
+      ],
+      valid_examples: &[
+        "const VAT_RATE = 1.19;\nconst total = price * VAT_RATE;",
+        "const RETRY_DELAY_MS = 3000;\nsetTimeout(retry, RETRY_DELAY_MS);",
+        "const config = { attempts: 5 };",
+        "type Digit = 0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9;",
+        "const n = parseInt(input, 10);",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "ignore": {
+          "description": "Numbers that are never reported. BigInts are written as strings like \"1n\"",
+          "type": "array",
+          "items": {
+            "anyOf": [
+              { "type": "number" },
+              { "type": "string", "pattern": "^[+-]?(?:0|[1-9][0-9]*)n$" }
+            ]
+          },
+          "uniqueItems": true
+        },
+        "ignoreArrayIndexes": {
+          "description": "Allow numbers used as array indexes",
+          "type": "boolean"
+        },
+        "ignoreDefaultValues": {
+          "description": "Allow numbers used as default values",
+          "type": "boolean"
+        },
+        "ignoreEnums": {
+          "description": "Allow numbers used as values of enum members",
+          "type": "boolean"
+        },
+        "enforceConst": {
+          "description": "Require variables initialized with a number to be declared with `const`",
+          "type": "boolean"
+        },
+        "detectObjects": {
+          "description": "Also report numbers used as values of object properties, class fields and assignments to properties",
+          "type": "boolean"
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<NoMagicNumbers>(options)?))
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    NoMagicNumbersHandler { rule: self }.traverse(program, context);
+  }
+}
+
+/// The value of a numeric literal, including a preceding unary minus.
+enum NumericValue {
+  Number(f64),
+  /// The decimal digits of a BigInt, with a leading `-` if it's negative.
+  BigInt(String),
+}
+
+impl NumericValue {
+  fn negate(self) -> Self {
+    match self {
+      NumericValue::Number(value) => NumericValue::Number(-value),
+      NumericValue::BigInt(digits) => match digits.strip_prefix('-') {
+        Some(digits) => NumericValue::BigInt(digits.to_string()),
+        None => NumericValue::BigInt(format!("-{}", digits)),
+      },
+    }
+  }
+
+  fn matches(&self, ignored: &IgnoredNumber) -> bool {
+    match (self, ignored) {
+      (NumericValue::Number(value), IgnoredNumber::Number(ignored)) => {
+        value == ignored
+      }
+      (NumericValue::BigInt(digits), IgnoredNumber::BigInt(ignored)) => {
+        let ignored = ignored.strip_prefix('+').unwrap_or(ignored);
+        ignored.strip_suffix('n') == Some(digits.as_str())
+      }
+      _ => false,
+    }
+  }
+
+  fn is_array_index(&self) -> bool {
+    const MAX_ARRAY_INDEX: f64 = 4294967294.0;
+    match self {
+      NumericValue::Number(value) => {
+        value.fract() == 0.0 && (0.0..=MAX_ARRAY_INDEX).contains(value)
+      }
+      NumericValue::BigInt(digits) => digits
+        .parse::<u64>()
+        .map_or(false, |value| value as f64 <= MAX_ARRAY_INDEX),
+    }
+  }
+}
+
+struct NoMagicNumbersHandler<'a> {
+  rule: &'a NoMagicNumbers,
+}
+
+impl<'a> NoMagicNumbersHandler<'a> {
+  fn check(&self, node: Node, value: NumericValue, ctx: &mut Context) {
+    let mut number = node;
+    let mut value = value;
+    let mut parent = expr_parent(number);
+    if let Some(Node::UnaryExpr(unary)) = parent {
+      if unary.op() == UnaryOp::Minus {
+        number = unary.as_node();
+        value = value.negate();
+        parent = expr_parent(number);
+      }
+    }
+
+    if self
+      .rule
+      .ignore
+      .iter()
+      .any(|ignored| value.matches(ignored))
+    {
+      return;
+    }
+
+    let range = number.range();
+    let Some(parent) = parent else {
+      return;
+    };
+    let is_allowed = match parent {
+      // Numbers in types and property keys.
+      Node::TsLitType(_)
+      | Node::MethodProp(_)
+      | Node::GetterProp(_)
+      | Node::SetterProp(_)
+      | Node::ClassMethod(_)
+      | Node::TsPropertySignature(_)
+      | Node::TsMethodSignature(_)
+      | Node::TsGetterSignature(_)
+      | Node::TsSetterSignature(_)
+      | Node::JSXExprContainer(_) => true,
+      Node::TsEnumMember(_) => self.rule.ignore_enums,
+      Node::AssignPat(_) | Node::AssignPatProp(_) => {
+        self.rule.ignore_default_values
+      }
+      Node::ExprOrSpread(arg) => is_parse_int_radix(arg),
+      Node::ComputedPropName(computed) => {
+        self.rule.ignore_array_indexes
+          && value.is_array_index()
+          && matches!(
+            computed.parent(),
+            Node::MemberExpr(_) | Node::SuperPropExpr(_)
+          )
+      }
+      Node::VarDeclarator(declarator) => {
+        if self.rule.enforce_const
+          && declarator
+            .parent()
+            .to::<ast_view::VarDecl>()
+            .is_some_and(|decl| decl.decl_kind() != VarDeclKind::Const)
+        {
+          ctx.add_diagnostic_with_hint(
+            range,
+            CODE,
+            USE_CONST_MESSAGE,
+            USE_CONST_HINT,
+          );
+        }
+        return;
+      }
+      Node::KeyValueProp(prop) => {
+        !prop.inner.value.range().contains(&range) || !self.rule.detect_objects
+      }
+      Node::ClassProp(prop) => {
+        !prop
+          .inner
+          .value
+          .as_ref()
+          .map_or(false, |value| value.range().contains(&range))
+          || !self.rule.detect_objects
+      }
+      Node::PrivateProp(_) => !self.rule.detect_objects,
+      Node::AssignExpr(assign) => {
+        !matches!(
+          assign.inner.left,
+          AssignTarget::Simple(SimpleAssignTarget::Ident(_))
+        ) && !self.rule.detect_objects
+      }
+      _ => false,
+    };
+    if !is_allowed {
+      let message =
+        format!("{}: {}", MESSAGE, range.text_fast(ctx.text_info()));
+      ctx.add_diagnostic_with_hint(range, CODE, message, HINT);
+    }
+  }
+}
+
+impl<'a> Handler for NoMagicNumbersHandler<'a> {
+  fn number(&mut self, number: &ast_view::Number, ctx: &mut Context) {
+    self.check(
+      number.as_node(),
+      NumericValue::Number(number.inner.value),
+      ctx,
+    );
+  }
+
+  fn big_int(&mut self, big_int: &ast_view::BigInt, ctx: &mut Context) {
+    self.check(
+      big_int.as_node(),
+      NumericValue::BigInt(big_int.inner.value.to_string()),
+      ctx,
+    );
+  }
+}
+
+/// Returns the parent of an expression, skipping parentheses and TypeScript
+/// expressions that don't change its value, like `as const`.
+fn expr_parent(node: Node) -> Option<Node> {
+  let mut parent = node.parent()?;
+  while matches!(
+    parent,
+    Node::ParenExpr(_)
+      | Node::TsAsExpr(_)
+      | Node::TsConstAssertion(_)
+      | Node::TsSatisfiesExpr(_)
+      | Node::TsTypeAssertion(_)
+      | Node::TsNonNullExpr(_)
+  ) {
+    parent = parent.parent()?;
+  }
+  Some(parent)
+}
+
+/// Checks if the argument is the radix of `parseInt()` or
+/// `Number.parseInt()`.
+fn is_parse_int_radix(arg: &ast_view::ExprOrSpread) -> bool {
+  let Some(Node::CallExpr(call)) = arg.as_node().parent() else {
+    return false;
+  };
+  let is_parse_int = match &call.inner.callee {
+    Callee::Expr(callee) => match &**callee {
+      Expr::Ident(ident) => ident.sym == *"parseInt",
+      Expr::Member(member) => {
+        matches!(&*member.obj, Expr::Ident(obj) if obj.sym == *"Number")
+          && matches!(&member.prop, MemberProp::Ident(prop) if prop.sym == *"parseInt")
+      }
+      _ => false,
+    },
+    _ => false,
+  };
+  is_parse_int
+    && call
+      .inner
+      .args
+      .get(1)
+      .map_or(false, |radix| radix.start() == arg.start())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v9.9.0/tests/lib/rules/no-magic-numbers.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_magic_numbers_valid() {
+    assert_lint_ok! {
+      NoMagicNumbers::default(),
+      "var x = parseInt(y, 10);",
+      "var x = parseInt(y, -10);",
+      "var x = Number.parseInt(y, 10);",
+      "const foo = 42;",
+      "var foo = 42;",
+      "const foo = -42;",
+      "const foo = 42n;",
+      "const foo = (42);",
+      "const foo = 42 as const;",
+      "var data = { key: 10 };",
+      "var data = { 10: 'a' };",
+      "class A { b = 10; #c = 20; 30() {} }",
+      "foo.bar = 4;",
+      "type A = 1 | 2 | -3;",
+      "type A = B[0];",
+      "interface A { 1: string; }",
+      "let a: 10 = 10;",
+    };
+
+    assert_lint_ok! {
+      NoMagicNumbers::default(),
+      filename: "file:///foo.tsx",
+      "var a = <input maxLength={10} />;",
+      "var a = <div objectProp={{ test: 1 }}></div>;",
+    };
+
+    assert_lint_ok! {
+      NoMagicNumbers {
+        ignore: vec![
+          IgnoredNumber::Number(0.0),
+          IgnoredNumber::Number(-1.0),
+          IgnoredNumber::BigInt("2n".to_string()),
+        ],
+        ..Default::default()
+      },
+      "foo(0);",
+      "foo(-1);",
+      "foo(2n);",
+    };
+
+    assert_lint_ok! {
+      NoMagicNumbers {
+        ignore_array_indexes: true,
+        ..Default::default()
+      },
+      "foo[0];",
+      "foo[4294967294];",
+      "foo?.[1];",
+      "foo[1n];",
+    };
+
+    assert_lint_ok! {
+      NoMagicNumbers {
+        ignore_default_values: true,
+        ..Default::default()
+      },
+      "function foo(a = 5) {}",
+      "const { a = 5 } = b;",
+      "const [a = 5] = b;",
+    };
+
+    assert_lint_ok! {
+      NoMagicNumbers {
+        ignore_enums: true,
+        ..Default::default()
+      },
+      "enum A { B = 1, C = -2 }",
+    };
+
+    assert_lint_ok! {
+      NoMagicNumbers {
+        enforce_const: true,
+        ..Default::default()
+      },
+      "const foo = 42;",
+    };
+  }
+
+  #[test]
+  fn no_magic_numbers_invalid() {
+    assert_lint_err! {
+      NoMagicNumbers::default(),
+      "var foo = bar * 2;": [
+        {
+          col: 16,
+          message: "No magic number: 2",
+          hint: HINT,
+        }
+      ],
+      "foo(-1);": [
+        {
+          col: 4,
+          message: "No magic number: -1",
+          hint: HINT,
+        }
+      ],
+      "foo(0x1A, 1n);": [
+        {
+          col: 4,
+          message: "No magic number: 0x1A",
+          hint: HINT,
+        },
+        {
+          col: 10,
+          message: "No magic number: 1n",
+          hint: HINT,
+        }
+      ],
+      "var a; a = 5;": [
+        {
+          col: 11,
+          message: "No magic number: 5",
+          hint: HINT,
+        }
+      ],
+      "var x = [1, 2];": [
+        {
+          col: 9,
+          message: "No magic number: 1",
+          hint: HINT,
+        },
+        {
+          col: 12,
+          message: "No magic number: 2",
+          hint: HINT,
+        }
+      ],
+      "foo[0];": [
+        {
+          col: 4,
+          message: "No magic number: 0",
+          hint: HINT,
+        }
+      ],
+      "var x = parseInt(10, y);": [
+        {
+          col: 17,
+          message: "No magic number: 10",
+          hint: HINT,
+        }
+      ],
+      "function foo(a = 5) {}": [
+        {
+          col: 17,
+          message: "No magic number: 5",
+          hint: HINT,
+        }
+      ],
+      "enum A { B = 1 }": [
+        {
+          col: 13,
+          message: "No magic number: 1",
+          hint: HINT,
+        }
+      ],
+      "if (foo > (3 as number)) {}": [
+        {
+          col: 11,
+          message: "No magic number: 3",
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoMagicNumbers {
+        ignore_array_indexes: true,
+        ..Default::default()
+      },
+      "foo[-1];": [
+        {
+          col: 4,
+          message: "No magic number: -1",
+          hint: HINT,
+        }
+      ],
+      "foo[1.5];": [
+        {
+          col: 4,
+          message: "No magic number: 1.5",
+          hint: HINT,
+        }
+      ],
+      "foo[4294967295];": [
+        {
+          col: 4,
+          message: "No magic number: 4294967295",
+          hint: HINT,
+        }
+      ],
+      "const a = { [1]: b };": [
+        {
+          col: 13,
+          message: "No magic number: 1",
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoMagicNumbers {
+        enforce_const: true,
+        ..Default::default()
+      },
+      "var foo = 42;": [
+        {
+          col: 10,
+          message: USE_CONST_MESSAGE,
+          hint: USE_CONST_HINT,
+        }
+      ],
+      "let foo = -42n;": [
+        {
+          col: 10,
+          message: USE_CONST_MESSAGE,
+          hint: USE_CONST_HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoMagicNumbers {
+        detect_objects: true,
+        ..Default::default()
+      },
+      "var data = { key: 10 };": [
+        {
+          col: 18,
+          message: "No magic number: 10",
+          hint: HINT,
+        }
+      ],
+      "foo.bar = 4;": [
+        {
+          col: 10,
+          message: "No magic number: 4",
+          hint: HINT,
+        }
+      ],
+      "class A { b = 10; }": [
+        {
+          col: 14,
+          message: "No magic number: 10",
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,379 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::{Handler, Traverse};
+use crate::js_regex::{
+  is_combining_mark, EcmaRegexValidator, EcmaVersion, RegexCharacter,
+};
+use crate::swc_util::{extract_regex, extract_regex_flags};
+use crate::tags::Tags;
+use crate::Program;
+use deno_ast::view::{CallExpr, Callee, Expr, NewExpr, Regex};
+use deno_ast::{SourceRange, SourceRanged};
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoMisleadingCharacterClass;
+
+const CODE: &str = "no-misleading-character-class";
+const FIX_DESC: &str = "Add the `u` flag";
+
+#[derive(Display, Clone, Copy, PartialEq, Eq)]
+enum NoMisleadingCharacterClassMessage {
+  #[display(fmt = "Unexpected surrogate pair in character class")]
+  SurrogatePair,
+  #[display(fmt = "Unexpected combined character in character class")]
+  CombiningMark,
+  #[display(fmt = "Unexpected modified emoji in character class")]
+  EmojiModifier,
+  #[display(fmt = "Unexpected flag emoji in character class")]
+  RegionalIndicator,
+  #[display(fmt = "Unexpected joined character sequence in character class")]
+  ZeroWidthJoiner,
+}
+
+#[derive(Display)]
+enum NoMisleadingCharacterClassHint {
+  #[display(
+    fmt = "Add the `u` flag, so that the pair is matched as one character"
+  )]
+  AddUnicodeFlag,
+  #[display(
+    fmt = "A character class matches a single character, so use an alternation like `(?:a|b)` to match character sequences"
+  )]
+  UseAlternation,
+}
+
+impl NoMisleadingCharacterClassMessage {
+  fn hint(self) -> NoMisleadingCharacterClassHint {
+    match self {
+      Self::SurrogatePair => NoMisleadingCharacterClassHint::AddUnicodeFlag,
+      _ => NoMisleadingCharacterClassHint::UseAlternation,
+    }
+  }
+}
+
+impl LintRule for NoMisleadingCharacterClass {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows characters in character classes that are \
+        made of multiple code points. A character class matches a single \
+        code point, so `[Á]` written with a combining accent matches `A` or \
+        the accent alone, and `[👍]` without the `u` flag matches either half \
+        of the surrogate pair. Combining marks, emoji modifiers, flag emoji \
+        and sequences joined with a zero width joiner are reported with or \
+        without the `u` flag, since the flag doesn't combine them. For regex \
+        literals with surrogate pairs, adding the `u` flag is offered as a \
+        suggestion, since it changes how the whole pattern is interpreted.",
+      invalid_examples: &[
+        "/^[A\\u0301]$/u;",
+        "/^[\\uD83D\\uDC4D]$/;",
+        "/^[🇯🇵]$/u;",
+      ],
+      valid_examples: &["/^(?:A\\u0301)$/u;", "/^[👍]$/u;", "/^[abc]$/;"],
+    }
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    NoMisleadingCharacterClassHandler.traverse(program, context);
+  }
+}
+
+struct NoMisleadingCharacterClassHandler;
+
+impl Handler for NoMisleadingCharacterClassHandler {
+  fn regex(&mut self, regex: &Regex, ctx: &mut Context) {
+    let pattern: &str = &regex.inner.exp;
+    let flags: &str = &regex.inner.flags;
+    for kind in check_regex(pattern, Some(flags), ctx.ecma_version()) {
+      // Adding the flag is only offered if the pattern stays valid.
+      let can_add_flag = kind
+        == NoMisleadingCharacterClassMessage::SurrogatePair
+        && ctx.ecma_version() >= EcmaVersion::Es2015
+        && EcmaRegexValidator::new(ctx.ecma_version())
+          .validate_pattern(pattern, true)
+          .is_ok();
+      let suggestions = if can_add_flag {
+        let end = regex.range().end();
+        vec![LintFix {
+          description: FIX_DESC.into(),
+          changes: vec![LintFixChange {
+            new_text: "u".into(),
+            range: SourceRange::new(end, end),
+          }],
+        }]
+      } else {
+        vec![]
+      };
+      ctx.add_diagnostic_with_suggestions(
+        regex.range(),
+        CODE,
+        kind.to_string(),
+        Some(kind.hint().to_string()),
+        suggestions,
+      );
+    }
+  }
+
+  fn new_expr(&mut self, new_expr: &NewExpr, ctx: &mut Context) {
+    if let Expr::Ident(ident) = new_expr.callee {
+      if let Some(args) = &new_expr.args {
+        if let Some(regex) = extract_regex(ctx.scope(), ident, args) {
+          let flags = extract_regex_flags(args);
+          report(&regex, flags.as_deref(), new_expr.range(), ctx);
+        }
+      }
+    }
+  }
+
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    if let Callee::Expr(Expr::Ident(ident)) = &call_expr.callee {
+      if let Some(regex) = extract_regex(ctx.scope(), ident, call_expr.args) {
+        let flags = extract_regex_flags(call_expr.args);
+        report(&regex, flags.as_deref(), call_expr.range(), ctx);
+      }
+    }
+  }
+}
+
+fn report(
+  pattern: &str,
+  flags: Option<&str>,
+  range: SourceRange,
+  ctx: &mut Context,
+) {
+  for kind in check_regex(pattern, flags, ctx.ecma_version()) {
+    ctx.add_diagnostic_with_hint(range, CODE, kind, kind.hint());
+  }
+}
+
+/// Returns the kinds of misleading characters found in the character classes
+/// of the pattern, each kind once.
+fn check_regex(
+  pattern: &str,
+  flags: Option<&str>,
+  ecma_version: EcmaVersion,
+) -> Vec<NoMisleadingCharacterClassMessage> {
+  // Without knowing the flags, surrogate pairs might be intended to be
+  // matched with the `u` flag.
+  let Some(flags) = flags else {
+    return vec![];
+  };
+  let u_flag = flags.contains(['u', 'v']);
+  let mut validator = EcmaRegexValidator::new(ecma_version);
+  // Invalid patterns are reported by `no-invalid-regexp`.
+  let Ok(characters) = validator.parse_characters(pattern, u_flag) else {
+    return vec![];
+  };
+
+  let mut kinds = Vec::new();
+  for sequence in class_sequences(&characters) {
+    for kind in misleading_kinds(sequence, u_flag) {
+      if !kinds.contains(&kind) {
+        kinds.push(kind);
+      }
+    }
+  }
+  kinds
+}
+
+/// Splits the characters into runs of adjacent characters in the same
+/// character class. Ranges like `a-z` and escapes like `\d` end a run.
+fn class_sequences(
+  characters: &[RegexCharacter],
+) -> impl Iterator<Item = &[RegexCharacter]> {
+  characters
+    .chunk_by(|a, b| a.class == b.class && a.end == b.start)
+    .filter(|sequence| sequence[0].class.is_some())
+}
+
+fn misleading_kinds(
+  sequence: &[RegexCharacter],
+  u_flag: bool,
+) -> Vec<NoMisleadingCharacterClassMessage> {
+  let mut kinds = Vec::new();
+  for (i, pair) in sequence.windows(2).enumerate() {
+    let (previous, current) = (pair[0].value, pair[1].value);
+    if !u_flag && is_lead_surrogate(previous) && is_trail_surrogate(current) {
+      kinds.push(NoMisleadingCharacterClassMessage::SurrogatePair);
+    }
+    if is_combining_mark(current.into()) && !is_combining_mark(previous.into())
+    {
+      kinds.push(NoMisleadingCharacterClassMessage::CombiningMark);
+    }
+    if is_emoji_modifier(current) && !is_emoji_modifier(previous) {
+      kinds.push(NoMisleadingCharacterClassMessage::EmojiModifier);
+    }
+    if is_regional_indicator(previous) && is_regional_indicator(current) {
+      kinds.push(NoMisleadingCharacterClassMessage::RegionalIndicator);
+    }
+    let next = sequence.get(i + 2).map(|c| c.value);
+    if current == ZERO_WIDTH_JOINER
+      && previous != ZERO_WIDTH_JOINER
+      && next.is_some_and(|next| next != ZERO_WIDTH_JOINER)
+    {
+      kinds.push(NoMisleadingCharacterClassMessage::ZeroWidthJoiner);
+    }
+  }
+  kinds
+}
+
+const ZERO_WIDTH_JOINER: u32 = 0x200d;
+
+fn is_lead_surrogate(value: u32) -> bool {
+  (0xd800..=0xdbff).contains(&value)
+}
+
+fn is_trail_surrogate(value: u32) -> bool {
+  (0xdc00..=0xdfff).contains(&value)
+}
+
+fn is_emoji_modifier(value: u32) -> bool {
+  (0x1f3fb..=0x1f3ff).contains(&value)
+}
+
+fn is_regional_indicator(value: u32) -> bool {
+  (0x1f1e6..=0x1f1ff).contains(&value)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_misleading_character_class_valid() {
+    assert_lint_ok! {
+      NoMisleadingCharacterClass,
+      r"/[abc]/",
+      r"/[👍]/u",
+      r"/[👍]/v",
+      r"/[\u{1F44D}]/u",
+      r"/👍/",
+      "/A\u{301}/",
+      "/(?:A\u{301})/u",
+      // A combining mark on its own.
+      "/[\u{301}]/",
+      "/[\u{301}\u{302}]/u",
+      // Ranges and escapes don't combine with their neighbours.
+      r"/[\u{1F1E6}-\u{1F1FF}]/u",
+      "/[\\d\u{301}]/",
+      r"/[\uD83D-\uDC4D]/",
+      "/[\u{200d}]/u",
+      r"new RegExp('[👍]', 'u')",
+      r"new RegExp('[👍]', flags)",
+      // Invalid patterns are reported by `no-invalid-regexp`.
+      r"new RegExp('[👍')",
+    };
+  }
+
+  #[test]
+  fn no_misleading_character_class_invalid() {
+    use NoMisleadingCharacterClassMessage::*;
+    assert_lint_err! {
+      NoMisleadingCharacterClass,
+      r"/[👍]/": [
+        {
+          col: 0,
+          message: SurrogatePair,
+          hint: SurrogatePair.hint(),
+          suggestion: (FIX_DESC, r"/[👍]/u"),
+        }
+      ],
+      r"/^[👍]$/g": [
+        {
+          col: 0,
+          message: SurrogatePair,
+          hint: SurrogatePair.hint(),
+          suggestion: (FIX_DESC, r"/^[👍]$/gu"),
+        }
+      ],
+      // The pattern isn't valid with the `u` flag.
+      r"/[👍]\a/": [
+        {
+          col: 0,
+          message: SurrogatePair,
+          hint: SurrogatePair.hint(),
+        }
+      ],
+      "/[A\u{301}]/u": [
+        {
+          col: 0,
+          message: CombiningMark,
+          hint: CombiningMark.hint(),
+        }
+      ],
+      "/[A\u{301}]/": [
+        {
+          col: 0,
+          message: CombiningMark,
+          hint: CombiningMark.hint(),
+        }
+      ],
+      "/[\u{1F476}\u{1F3FB}]/u": [
+        {
+          col: 0,
+          message: EmojiModifier,
+          hint: EmojiModifier.hint(),
+        }
+      ],
+      "/[\u{1F1EF}\u{1F1F5}]/u": [
+        {
+          col: 0,
+          message: RegionalIndicator,
+          hint: RegionalIndicator.hint(),
+        }
+      ],
+      "/[👨\u{200d}👩]/u": [
+        {
+          col: 0,
+          message: ZeroWidthJoiner,
+          hint: ZeroWidthJoiner.hint(),
+        }
+      ],
+      r"new RegExp('[👍]')": [
+        {
+          col: 0,
+          message: SurrogatePair,
+          hint: SurrogatePair.hint(),
+        }
+      ],
+      "RegExp('[A\u{301}]', 'u')": [
+        {
+          col: 0,
+          message: CombiningMark,
+          hint: CombiningMark.hint(),
+        }
+      ],
+      "/[A\u{301}👍]/": [
+        {
+          col: 0,
+          message: CombiningMark,
+          hint: CombiningMark.hint(),
+        },
+        {
+          col: 0,
+          message: SurrogatePair,
+          hint: SurrogatePair.hint(),
+          suggestion: (FIX_DESC, "/[A\u{301}👍]/u"),
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,324 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{program_ref, Context, LintRule, RuleDocs};
+use crate::handler::{Handler, Traverse};
+use crate::tags::Tags;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{
+  BinaryOp, Callee, Expr, FnDecl, Id, MemberProp, Pat, UnaryOp, VarDecl,
+  VarDeclKind,
+};
+use deno_ast::swc::visit::{noop_visit_type, Visit, VisitWith};
+use deno_ast::view as ast_view;
+use deno_ast::SourceRangedForSpanned;
+use std::collections::HashSet;
+
+#[derive(Debug)]
+pub struct NoMisusedPromises;
+
+const CODE: &str = "no-misused-promises";
+const MESSAGE: &str = "A promise is used as a condition, which is always true";
+const HINT: &str = "Did you forget to `await` the promise?";
+
+impl LintRule for NoMisusedPromises {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows promises in conditions, like the test of an \
+        `if`, a loop or a ternary, the operand of `!` and the operands of \
+        `&&` and `||`. A promise is an object, so it is always truthy, and \
+        usually the `await` is missing. Without type information, \
+        expressions are only known to be promises if they call an `async` \
+        function declared in the same file, call `.then()`, create a \
+        promise with `new Promise()` or `Promise.resolve()` and the like, or \
+        are a `const` variable initialized with any of these.",
+      invalid_examples: &[
+        "async function isAllowed(user) {\n  return await db.hasRole(user, \"admin\");\n}\n\nif (isAllowed(user)) {\n  deleteEverything();\n}",
+        "const ready = fetch(url).then((res) => res.ok);\nwhile (!ready) {\n  wait();\n}",
+      ],
+      valid_examples: &[
+        "async function isAllowed(user) {\n  return await db.hasRole(user, \"admin\");\n}\n\nif (await isAllowed(user)) {\n  deleteEverything();\n}",
+      ],
+    }
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    NoMisusedPromisesHandler {
+      promises: Promises::collect(program_ref(program), context),
+    }
+    .traverse(program, context);
+  }
+}
+
+struct NoMisusedPromisesHandler {
+  promises: Promises,
+}
+
+impl NoMisusedPromisesHandler {
+  /// Reports the expression if it is a promise used as a condition. The
+  /// result of `a && b` or `a || b` is a condition too, but the left operand
+  /// is checked on its own by `bin_expr`.
+  fn check_condition(&self, expr: &Expr, ctx: &mut Context) {
+    let expr = expr.unwrap_parens();
+    match expr {
+      Expr::Bin(bin)
+        if matches!(bin.op, BinaryOp::LogicalAnd | BinaryOp::LogicalOr) =>
+      {
+        self.check_condition(&bin.right, ctx);
+      }
+      _ if self.promises.is_promise(expr, ctx) => {
+        ctx.add_diagnostic_with_hint(expr.range(), CODE, MESSAGE, HINT);
+      }
+      _ => {}
+    }
+  }
+}
+
+impl Handler for NoMisusedPromisesHandler {
+  fn if_stmt(&mut self, if_stmt: &ast_view::IfStmt, ctx: &mut Context) {
+    self.check_condition(&if_stmt.inner.test, ctx);
+  }
+
+  fn while_stmt(
+    &mut self,
+    while_stmt: &ast_view::WhileStmt,
+    ctx: &mut Context,
+  ) {
+    self.check_condition(&while_stmt.inner.test, ctx);
+  }
+
+  fn do_while_stmt(
+    &mut self,
+    do_while_stmt: &ast_view::DoWhileStmt,
+    ctx: &mut Context,
+  ) {
+    self.check_condition(&do_while_stmt.inner.test, ctx);
+  }
+
+  fn for_stmt(&mut self, for_stmt: &ast_view::ForStmt, ctx: &mut Context) {
+    if let Some(test) = &for_stmt.inner.test {
+      self.check_condition(test, ctx);
+    }
+  }
+
+  fn cond_expr(&mut self, cond_expr: &ast_view::CondExpr, ctx: &mut Context) {
+    self.check_condition(&cond_expr.inner.test, ctx);
+  }
+
+  fn unary_expr(
+    &mut self,
+    unary_expr: &ast_view::UnaryExpr,
+    ctx: &mut Context,
+  ) {
+    if unary_expr.inner.op == UnaryOp::Bang {
+      self.check_condition(&unary_expr.inner.arg, ctx);
+    }
+  }
+
+  fn bin_expr(&mut self, bin_expr: &ast_view::BinExpr, ctx: &mut Context) {
+    if matches!(
+      bin_expr.inner.op,
+      BinaryOp::LogicalAnd | BinaryOp::LogicalOr
+    ) {
+      self.check_condition(&bin_expr.inner.left, ctx);
+    }
+  }
+}
+
+/// The bindings of a file that are known to be or to return promises.
+struct Promises {
+  /// Async functions.
+  async_fns: HashSet<Id>,
+  /// `const` variables initialized with a promise.
+  consts: HashSet<Id>,
+}
+
+impl Promises {
+  fn collect(program: ProgramRef, ctx: &Context) -> Self {
+    let mut async_fns = AsyncFnCollector::default();
+    match program {
+      ProgramRef::Module(m) => m.visit_with(&mut async_fns),
+      ProgramRef::Script(s) => s.visit_with(&mut async_fns),
+    }
+    let mut consts = PromiseConstCollector {
+      promises: Promises {
+        async_fns: async_fns.ids,
+        consts: HashSet::new(),
+      },
+      ctx,
+    };
+    match program {
+      ProgramRef::Module(m) => m.visit_with(&mut consts),
+      ProgramRef::Script(s) => s.visit_with(&mut consts),
+    }
+    consts.promises
+  }
+
+  fn is_promise(&self, expr: &Expr, ctx: &Context) -> bool {
+    match expr.unwrap_parens() {
+      Expr::Ident(ident) => self.consts.contains(&ident.to_id()),
+      Expr::New(new_expr) => {
+        matches!(
+          &*new_expr.callee,
+          Expr::Ident(callee)
+            if callee.sym == *"Promise" && ctx.scope().is_global(&callee.to_id())
+        )
+      }
+      Expr::Call(call_expr) => {
+        let Callee::Expr(callee) = &call_expr.callee else {
+          return false;
+        };
+        match callee.unwrap_parens() {
+          Expr::Ident(ident) => self.async_fns.contains(&ident.to_id()),
+          Expr::Member(member) => {
+            let MemberProp::Ident(prop) = &member.prop else {
+              return false;
+            };
+            match &*prop.sym {
+              "then" => true,
+              "catch" | "finally" => self.is_promise(&member.obj, ctx),
+              "resolve" | "reject" | "all" | "allSettled" | "any" | "race" => {
+                matches!(
+                  &*member.obj,
+                  Expr::Ident(obj)
+                    if obj.sym == *"Promise" && ctx.scope().is_global(&obj.to_id())
+                )
+              }
+              _ => false,
+            }
+          }
+          _ => false,
+        }
+      }
+      _ => false,
+    }
+  }
+}
+
+/// Collects the async functions of a file, e.g. `async function f() {}` or
+/// `const f = async () => {}`.
+#[derive(Default)]
+struct AsyncFnCollector {
+  ids: HashSet<Id>,
+}
+
+impl Visit for AsyncFnCollector {
+  noop_visit_type!();
+
+  fn visit_fn_decl(&mut self, fn_decl: &FnDecl) {
+    if fn_decl.function.is_async && !fn_decl.function.is_generator {
+      self.ids.insert(fn_decl.ident.to_id());
+    }
+    fn_decl.visit_children_with(self);
+  }
+
+  fn visit_var_decl(&mut self, var_decl: &VarDecl) {
+    if var_decl.kind == VarDeclKind::Const {
+      for declarator in &var_decl.decls {
+        let Pat::Ident(binding) = &declarator.name else {
+          continue;
+        };
+        let is_async = match declarator.init.as_deref().map(Expr::unwrap_parens)
+        {
+          Some(Expr::Arrow(arrow)) => arrow.is_async,
+          Some(Expr::Fn(fn_expr)) => {
+            fn_expr.function.is_async && !fn_expr.function.is_generator
+          }
+          _ => false,
+        };
+        if is_async {
+          self.ids.insert(binding.id.to_id());
+        }
+      }
+    }
+    var_decl.visit_children_with(self);
+  }
+}
+
+/// Collects the `const` variables that are initialized with a promise, e.g.
+/// `const p = load();` where `load` is an async function.
+struct PromiseConstCollector<'a, 'view> {
+  promises: Promises,
+  ctx: &'a Context<'view>,
+}
+
+impl Visit for PromiseConstCollector<'_, '_> {
+  noop_visit_type!();
+
+  fn visit_var_decl(&mut self, var_decl: &VarDecl) {
+    if var_decl.kind == VarDeclKind::Const {
+      for declarator in &var_decl.decls {
+        let (Pat::Ident(binding), Some(init)) =
+          (&declarator.name, &declarator.init)
+        else {
+          continue;
+        };
+        if self.promises.is_promise(init, self.ctx) {
+          self.promises.consts.insert(binding.id.to_id());
+        }
+      }
+    }
+    var_decl.visit_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_misused_promises_valid() {
+    assert_lint_ok! {
+      NoMisusedPromises,
+      "async function f() {}\nif (await f()) {}",
+      "async function f() {}\nconst p = f();\nif (await p) {}",
+      "function f() {}\nif (f()) {}",
+      "function* g() {}\nif (g()) {}",
+      "async function* g() {}\nif (g()) {}",
+      "async function f() {}\nconst p = f();\np ?? x;",
+      "async function f() {}\nx && f();",
+      "let p = Promise.resolve();\np = null;\nif (p) {}",
+      "if (obj.catch(e)) {}",
+      "const Promise = { resolve() { return 1; } };\nif (Promise.resolve()) {}",
+      "async function f() {}\nconst g = () => f();\nif (g()) {}",
+      "async function f() {}\nfunction h(f) { if (f()) {} }",
+      "const p = f();\nif (p) {}",
+    };
+  }
+
+  #[test]
+  fn no_misused_promises_invalid() {
+    assert_lint_err! {
+      NoMisusedPromises,
+      MESSAGE,
+      HINT,
+      "async function f() {}\nif (f()) {}": [{ line: 2, col: 4 }],
+      "const f = async () => true;\nwhile (f()) {}": [{ line: 2, col: 7 }],
+      "const f = async function() {};\ndo {} while (f());": [{ line: 2, col: 13 }],
+      "async function f() {}\nfor (; f(); ) {}": [{ line: 2, col: 7 }],
+      "async function f() {}\nconst x = f() ? 1 : 2;": [{ line: 2, col: 10 }],
+      "async function f() {}\nif (!f()) {}": [{ line: 2, col: 5 }],
+      "async function f() {}\nconst p = f();\nif (!p) {}": [{ line: 3, col: 5 }],
+      "if (fetch(url).then((res) => res.ok)) {}": [{ col: 4 }],
+      "if (fetch(url).then(check).catch(log)) {}": [{ col: 4 }],
+      "if (new Promise(executor)) {}": [{ col: 4 }],
+      "if (Promise.all(promises)) {}": [{ col: 4 }],
+      "async function f() {}\nif (x && (f())) {}": [{ line: 2, col: 10 }],
+      "async function f() {}\nif (f() || x) {}": [{ line: 2, col: 4 }],
+      "async function f() {}\nf() && x;": [{ line: 2, col: 0 }],
+      "async function f() {}\nif ((f() && x) || y) {}": [{ line: 2, col: 5 }],
+      "class A { async m() { const p = Promise.resolve(1); return p ? 1 : 2; } }": [{ col: 59 }],
+    };
+  }
+}
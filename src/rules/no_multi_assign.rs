@@ -0,0 +1,236 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::find_lhs_ids;
+use crate::tags::Tags;
+use crate::Program;
+use deno_ast::swc::ast::Ident;
+use deno_ast::view::{AssignExpr, Node, NodeTrait};
+use deno_ast::SourceRanged;
+use serde::Deserialize;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoMultiAssign {
+  /// Only reports chained assignments in variable declarations and class
+  /// property initializers, e.g. `const a = b = c`, but not `a = b = c`.
+  pub ignore_non_declaration: bool,
+}
+
+const CODE: &str = "no-multi-assign";
+const HINT: &str = "Split the chained assignment into separate assignments";
+
+impl LintRule for NoMultiAssign {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows chained assignments like `a = b = c`. They are \
+        hard to read, and in declarations like `const a = b = c` only `a` \
+        is declared while `b` is assigned, possibly to an implicit global.",
+      invalid_examples: &["let a, b;\na = b = 0;", "let b;\nconst a = b = 0;"],
+      valid_examples: &[
+        "let a, b;\na = 0;\nb = 0;",
+        "const a = 0;\nconst b = a;",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "ignoreNonDeclaration": {
+          "description": "Only report chained assignments in declarations and class property initializers",
+          "type": "boolean"
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<NoMultiAssign>(options)?))
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    NoMultiAssignHandler { rule: self }.traverse(program, context);
+  }
+}
+
+struct NoMultiAssignHandler<'a> {
+  rule: &'a NoMultiAssign,
+}
+
+impl<'a> Handler for NoMultiAssignHandler<'a> {
+  fn assign_expr(&mut self, assign_expr: &AssignExpr, ctx: &mut Context) {
+    let mut node = assign_expr.as_node();
+    while let Some(parent @ Node::ParenExpr(_)) = node.parent() {
+      node = parent;
+    }
+    let is_chained = match node.parent() {
+      Some(
+        Node::VarDeclarator(_) | Node::ClassProp(_) | Node::PrivateProp(_),
+      ) => true,
+      Some(Node::AssignExpr(_)) => !self.rule.ignore_non_declaration,
+      _ => false,
+    };
+    if !is_chained {
+      return;
+    }
+
+    let ids: Vec<Ident> = find_lhs_ids(&assign_expr.left);
+    let message = if ids.is_empty() {
+      "Unexpected chained assignment".to_string()
+    } else {
+      format!(
+        "Unexpected chained assignment to {}",
+        ids
+          .iter()
+          .map(|id| format!("`{}`", id.sym))
+          .collect::<Vec<_>>()
+          .join(", ")
+      )
+    };
+    ctx.add_diagnostic_with_hint(assign_expr.range(), CODE, message, HINT);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v9.9.0/tests/lib/rules/no-multi-assign.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_multi_assign_valid() {
+    assert_lint_ok! {
+      NoMultiAssign::default(),
+      "a = b;",
+      "a = b; c = d;",
+      "const a = b, c = d;",
+      "const a = (b, c);",
+      "for (a = 0, b = 0; a < b; a++) {}",
+      "foo(a = b);",
+      "const x = { a: b = c };",
+      "class A { a = b; }",
+    };
+
+    assert_lint_ok! {
+      NoMultiAssign {
+        ignore_non_declaration: true,
+      },
+      "a = b = c;",
+      "a.x = b.y = c;",
+    };
+  }
+
+  #[test]
+  fn no_multi_assign_invalid() {
+    assert_lint_err! {
+      NoMultiAssign::default(),
+      "a = b = c;": [
+        {
+          col: 4,
+          message: "Unexpected chained assignment to `b`",
+          hint: HINT,
+        }
+      ],
+      "const a = b = c;": [
+        {
+          col: 10,
+          message: "Unexpected chained assignment to `b`",
+          hint: HINT,
+        }
+      ],
+      "let a = (b = c);": [
+        {
+          col: 9,
+          message: "Unexpected chained assignment to `b`",
+          hint: HINT,
+        }
+      ],
+      "a.x = b.y = c;": [
+        {
+          col: 6,
+          message: "Unexpected chained assignment",
+          hint: HINT,
+        }
+      ],
+      "[a, b] = [c, d] = e;": [
+        {
+          col: 9,
+          message: "Unexpected chained assignment to `c`, `d`",
+          hint: HINT,
+        }
+      ],
+      "a += b -= c;": [
+        {
+          col: 5,
+          message: "Unexpected chained assignment to `b`",
+          hint: HINT,
+        }
+      ],
+      "class A { a = b = c; #d = e = f; }": [
+        {
+          col: 14,
+          message: "Unexpected chained assignment to `b`",
+          hint: HINT,
+        },
+        {
+          col: 26,
+          message: "Unexpected chained assignment to `e`",
+          hint: HINT,
+        }
+      ],
+      "const a = b = c = d;": [
+        {
+          col: 10,
+          message: "Unexpected chained assignment to `b`",
+          hint: HINT,
+        },
+        {
+          col: 14,
+          message: "Unexpected chained assignment to `c`",
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoMultiAssign {
+        ignore_non_declaration: true,
+      },
+      "const a = b = c = d;": [
+        {
+          col: 10,
+          message: "Unexpected chained assignment to `b`",
+          hint: HINT,
+        }
+      ],
+      "class A { a = b = c; }": [
+        {
+          col: 14,
+          message: "Unexpected chained assignment to `b`",
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
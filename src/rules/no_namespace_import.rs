@@ -0,0 +1,349 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{program_ref, Context, LintRule, RuleDocs};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::tags::Tags;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{
+  Id, Ident, ImportDecl, ImportSpecifier, MemberExpr, MemberProp, ModuleDecl,
+  ModuleItem, TsEntityName, TsQualifiedName,
+};
+use deno_ast::swc::atoms::Atom;
+use deno_ast::swc::visit::{Visit, VisitWith};
+use deno_ast::{SourceRange, SourceRanged, SourceRangedForSpanned};
+use serde::Deserialize;
+use std::collections::HashSet;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoNamespaceImport {
+  /// Specifiers of the modules that may not be imported as a namespace, e.g.
+  /// `npm:lodash-es`.
+  pub modules: Vec<String>,
+}
+
+const CODE: &str = "no-namespace-import";
+const HINT: &str = "Import the used members by name instead, so that bundlers can remove the unused exports";
+const FIX_DESC: &str = "Replace with named imports";
+
+impl LintRule for NoNamespaceImport {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows namespace imports like `import * as _ from \
+        \"npm:lodash-es\";` of the modules listed in the `modules` option. \
+        A namespace import keeps bundlers from removing the unused exports \
+        of large modules. The fix replaces the namespace import with named \
+        imports if every use of the namespace is a member access like \
+        `_.debounce`, and the names don't collide with other bindings. \
+        Specifiers must match exactly, and nothing is reported without \
+        modules.",
+      invalid_examples: &[],
+      valid_examples: &[],
+    }
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "modules": {
+          "description": "Specifiers of the modules that may not be imported as a namespace, e.g. `npm:lodash-es`",
+          "type": "array",
+          "items": { "type": "string" }
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<NoNamespaceImport>(
+      options,
+    )?))
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    if self.modules.is_empty() {
+      return;
+    }
+    let program = program_ref(program);
+    let ProgramRef::Module(module) = program else {
+      return;
+    };
+    for item in &module.body {
+      let ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) = item else {
+        continue;
+      };
+      let specifier = import_decl.src.value.as_str();
+      if !self.modules.iter().any(|module| module == specifier) {
+        continue;
+      }
+      for import_specifier in &import_decl.specifiers {
+        let ImportSpecifier::Namespace(namespace) = import_specifier else {
+          continue;
+        };
+        let fixes =
+          named_imports_fix(import_decl, &namespace.local, program, context)
+            .into_iter()
+            .collect();
+        context.add_diagnostic_with_fixes(
+          namespace.range(),
+          CODE,
+          format!("Namespace import of `{}` is not allowed", specifier),
+          Some(HINT.to_string()),
+          fixes,
+        );
+      }
+    }
+  }
+}
+
+/// Creates a fix that imports the members used through the namespace by name,
+/// if all of the uses are statically known.
+fn named_imports_fix(
+  import_decl: &ImportDecl,
+  namespace: &Ident,
+  program: ProgramRef,
+  ctx: &Context,
+) -> Option<LintFix> {
+  if ctx.has_comments_in_range(import_decl.range()) {
+    return None;
+  }
+  let mut usages = NamespaceUsages {
+    namespace: namespace.to_id(),
+    members: Vec::new(),
+    names: HashSet::new(),
+    is_dynamic: false,
+  };
+  match program {
+    ProgramRef::Module(m) => m.visit_with(&mut usages),
+    ProgramRef::Script(s) => s.visit_with(&mut usages),
+  }
+  if usages.is_dynamic || usages.members.is_empty() {
+    return None;
+  }
+
+  let mut imported = Vec::<&Atom>::new();
+  for (_, name) in &usages.members {
+    if imported.contains(&name) {
+      continue;
+    }
+    // The name would be shadowed by or collide with another binding.
+    if usages.names.contains(name) || Ident::verify_symbol(name).is_err() {
+      return None;
+    }
+    imported.push(name);
+  }
+
+  let text_info = ctx.text_info();
+  let mut clauses = Vec::new();
+  for specifier in &import_decl.specifiers {
+    if let ImportSpecifier::Default(default) = specifier {
+      clauses.push(default.range().text_fast(text_info).to_string());
+    }
+  }
+  clauses.push(format!(
+    "{{ {} }}",
+    imported
+      .iter()
+      .map(|name| name.as_str())
+      .collect::<Vec<_>>()
+      .join(", ")
+  ));
+  let with = import_decl
+    .with
+    .as_ref()
+    .map(|with| format!(" with {}", with.range().text_fast(text_info)))
+    .unwrap_or_default();
+  let new_import = format!(
+    "import {}{} from {}{};",
+    if import_decl.type_only { "type " } else { "" },
+    clauses.join(", "),
+    import_decl.src.range().text_fast(text_info),
+    with
+  );
+
+  let mut changes = vec![LintFixChange {
+    new_text: new_import.into(),
+    range: import_decl.range(),
+  }];
+  changes.extend(usages.members.into_iter().map(|(range, name)| {
+    LintFixChange {
+      new_text: name.to_string().into(),
+      range,
+    }
+  }));
+  Some(LintFix {
+    description: FIX_DESC.into(),
+    changes,
+  })
+}
+
+/// Collects the member accesses of a namespace, e.g. `ns.member` or
+/// `ns.Type`, and the names of all other identifiers.
+struct NamespaceUsages {
+  namespace: Id,
+  members: Vec<(SourceRange, Atom)>,
+  names: HashSet<Atom>,
+  /// Whether the namespace is used other than by accessing a member by name,
+  /// e.g. `ns[key]` or `Object.keys(ns)`.
+  is_dynamic: bool,
+}
+
+impl Visit for NamespaceUsages {
+  fn visit_import_decl(&mut self, import_decl: &ImportDecl) {
+    for specifier in &import_decl.specifiers {
+      let local = match specifier {
+        ImportSpecifier::Named(named) => &named.local,
+        ImportSpecifier::Default(default) => &default.local,
+        ImportSpecifier::Namespace(namespace) => &namespace.local,
+      };
+      if local.to_id() != self.namespace {
+        self.names.insert(local.sym.clone());
+      }
+    }
+  }
+
+  fn visit_member_expr(&mut self, member: &MemberExpr) {
+    match (&*member.obj, &member.prop) {
+      (obj, MemberProp::Ident(prop))
+        if obj
+          .as_ident()
+          .is_some_and(|obj| obj.to_id() == self.namespace) =>
+      {
+        self.members.push((member.range(), prop.sym.clone()));
+      }
+      _ => member.visit_children_with(self),
+    }
+  }
+
+  fn visit_ts_qualified_name(&mut self, name: &TsQualifiedName) {
+    match &name.left {
+      TsEntityName::Ident(left) if left.to_id() == self.namespace => {
+        self.members.push((name.range(), name.right.sym.clone()));
+      }
+      _ => name.visit_children_with(self),
+    }
+  }
+
+  fn visit_ident(&mut self, ident: &Ident) {
+    if ident.to_id() == self.namespace {
+      self.is_dynamic = true;
+    } else {
+      self.names.insert(ident.sym.clone());
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn rule() -> NoNamespaceImport {
+    NoNamespaceImport {
+      modules: vec!["npm:lodash-es".to_string()],
+    }
+  }
+
+  #[test]
+  fn no_namespace_import_valid() {
+    assert_lint_ok! {
+      NoNamespaceImport::default(),
+      "import * as _ from 'npm:lodash-es';",
+    };
+
+    assert_lint_ok! {
+      rule(),
+      "import { debounce } from 'npm:lodash-es';",
+      "import _ from 'npm:lodash-es';",
+      "import * as path from 'jsr:@std/path';",
+      "import * as _ from 'npm:lodash-es@4';",
+      "export * as _ from 'npm:lodash-es';",
+    };
+  }
+
+  #[test]
+  fn no_namespace_import_invalid() {
+    assert_lint_err! {
+      rule(),
+      "import * as _ from 'npm:lodash-es';\n_.debounce(f);\n_.throttle(_.debounce(g));": [
+        {
+          col: 7,
+          message: "Namespace import of `npm:lodash-es` is not allowed",
+          hint: HINT,
+          fix: (FIX_DESC, "import { debounce, throttle } from 'npm:lodash-es';\ndebounce(f);\nthrottle(debounce(g));"),
+        }
+      ],
+      "import type * as types from 'npm:lodash-es';\nlet f: types.DebouncedFunc;": [
+        {
+          col: 12,
+          message: "Namespace import of `npm:lodash-es` is not allowed",
+          hint: HINT,
+          fix: (FIX_DESC, "import type { DebouncedFunc } from 'npm:lodash-es';\nlet f: DebouncedFunc;"),
+        }
+      ],
+      "import lodash, * as _ from 'npm:lodash-es';\n_.chunk(lodash);": [
+        {
+          col: 15,
+          message: "Namespace import of `npm:lodash-es` is not allowed",
+          hint: HINT,
+          fix: (FIX_DESC, "import lodash, { chunk } from 'npm:lodash-es';\nchunk(lodash);"),
+        }
+      ],
+      "import * as _ from 'npm:lodash-es';\nObject.keys(_);": [
+        {
+          col: 7,
+          message: "Namespace import of `npm:lodash-es` is not allowed",
+          hint: HINT,
+        }
+      ],
+      "import * as _ from 'npm:lodash-es';\n_[name]();": [
+        {
+          col: 7,
+          message: "Namespace import of `npm:lodash-es` is not allowed",
+          hint: HINT,
+        }
+      ],
+      "import * as _ from 'npm:lodash-es';\nfunction f(chunk) { return _.chunk(chunk); }": [
+        {
+          col: 7,
+          message: "Namespace import of `npm:lodash-es` is not allowed",
+          hint: HINT,
+        }
+      ],
+      "import * as _ from 'npm:lodash-es';\n_.default();": [
+        {
+          col: 7,
+          message: "Namespace import of `npm:lodash-es` is not allowed",
+          hint: HINT,
+        }
+      ],
+      "import * as _ from 'npm:lodash-es';": [
+        {
+          col: 7,
+          message: "Namespace import of `npm:lodash-es` is not allowed",
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
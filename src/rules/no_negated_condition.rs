@@ -0,0 +1,260 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::{Handler, Traverse};
+use crate::tags::{self, Tags};
+use crate::Program;
+use deno_ast::swc::ast::{BinaryOp, Expr, Stmt, UnaryOp};
+use deno_ast::view as ast_view;
+use deno_ast::{SourceRange, SourceRanged, SourceRangedForSpanned};
+
+#[derive(Debug)]
+pub struct NoNegatedCondition;
+
+const CODE: &str = "no-negated-condition";
+const MESSAGE: &str = "Unexpected negated condition";
+const HINT: &str =
+  "Remove the negation and swap the branches, so the condition reads positively";
+const FIX_DESC: &str = "Remove the negation and swap the branches";
+
+impl LintRule for NoNegatedCondition {
+  fn tags(&self) -> Tags {
+    &[tags::STYLISTIC]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows negated conditions in `if` statements that \
+        have an `else` branch and in conditional expressions. A condition \
+        like `!a` or `a !== b` with two branches is easier to read when the \
+        negation is removed and the branches are swapped. `if` statements \
+        followed by `else if` are allowed, since their branches can't be \
+        swapped without changing the chain.",
+      invalid_examples: &[
+        "if (!a) {\n  foo();\n} else {\n  bar();\n}",
+        "const b = a !== 1 ? foo() : bar();",
+      ],
+      valid_examples: &[
+        "if (a) {\n  bar();\n} else {\n  foo();\n}",
+        "if (!a) {\n  foo();\n}",
+        "if (!a) {\n  foo();\n} else if (b) {\n  bar();\n}",
+        "const b = a === 1 ? bar() : foo();",
+      ],
+    }
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    NoNegatedConditionHandler.traverse(program, context);
+  }
+}
+
+struct NoNegatedConditionHandler;
+
+impl Handler for NoNegatedConditionHandler {
+  fn if_stmt(&mut self, if_stmt: &ast_view::IfStmt, ctx: &mut Context) {
+    let Some(alt) = if_stmt.inner.alt.as_deref() else {
+      return;
+    };
+    if matches!(alt, Stmt::If(_)) {
+      return;
+    }
+    report(
+      ctx,
+      if_stmt.range(),
+      &if_stmt.inner.test,
+      if_stmt.inner.cons.range(),
+      alt.range(),
+    );
+  }
+
+  fn cond_expr(&mut self, cond_expr: &ast_view::CondExpr, ctx: &mut Context) {
+    report(
+      ctx,
+      cond_expr.range(),
+      &cond_expr.inner.test,
+      cond_expr.inner.cons.range(),
+      cond_expr.inner.alt.range(),
+    );
+  }
+}
+
+fn report(
+  ctx: &mut Context,
+  range: SourceRange,
+  test: &Expr,
+  cons: SourceRange,
+  alt: SourceRange,
+) {
+  let Some(test_change) = remove_negation(ctx, test) else {
+    return;
+  };
+
+  // Comments outside of the branches would end up next to the wrong branch.
+  let has_comments =
+    ctx
+      .comment_lookups()
+      .has_comment_matching(range, |comment| {
+        let comment = comment.range();
+        !cons.contains(&comment) && !alt.contains(&comment)
+      });
+  let fixes = if has_comments {
+    vec![]
+  } else {
+    let text_info = ctx.text_info();
+    vec![LintFix {
+      description: FIX_DESC.into(),
+      changes: vec![
+        test_change,
+        LintFixChange {
+          new_text: alt.text_fast(text_info).to_string().into(),
+          range: cons,
+        },
+        LintFixChange {
+          new_text: cons.text_fast(text_info).to_string().into(),
+          range: alt,
+        },
+      ],
+    }]
+  };
+  ctx.add_diagnostic_with_fixes(
+    range,
+    CODE,
+    MESSAGE,
+    Some(HINT.to_string()),
+    fixes,
+  );
+}
+
+/// Returns the change that removes the negation from the condition, if it
+/// is negated.
+fn remove_negation(ctx: &Context, test: &Expr) -> Option<LintFixChange> {
+  match test {
+    Expr::Unary(unary) if unary.op == UnaryOp::Bang => Some(LintFixChange {
+      new_text: unary
+        .arg
+        .range()
+        .text_fast(ctx.text_info())
+        .to_string()
+        .into(),
+      range: unary.range(),
+    }),
+    Expr::Bin(bin) => {
+      let (negated, positive) = match bin.op {
+        BinaryOp::NotEq => ("!=", "=="),
+        BinaryOp::NotEqEq => ("!==", "==="),
+        _ => return None,
+      };
+      let range =
+        SourceRange::new(bin.left.range().end(), bin.right.range().start());
+      let text = range.text_fast(ctx.text_info());
+      Some(LintFixChange {
+        new_text: text.replacen(negated, positive, 1).into(),
+        range,
+      })
+    }
+    _ => None,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_negated_condition_valid() {
+    assert_lint_ok! {
+      NoNegatedCondition,
+      "if (a) {} else {}",
+      "if (!a) {}",
+      "if (!a) {} else if (b) {}",
+      "if (a != b) {} else if (c) {} else {}",
+      "if (a && !b) {} else {}",
+      "const c = a ? b : c;",
+      "const c = a === b ? c : d;",
+      "const c = -a ? b : c;",
+    };
+  }
+
+  #[test]
+  fn no_negated_condition_invalid() {
+    assert_lint_err! {
+      NoNegatedCondition,
+      "if (!a) { foo(); } else { bar(); }": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "if (a) { bar(); } else { foo(); }"),
+        }
+      ],
+      "if (!(a && b)) foo(); else bar();": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "if ((a && b)) bar(); else foo();"),
+        }
+      ],
+      "if (a != b) { foo(); } else { bar(); }": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "if (a == b) { bar(); } else { foo(); }"),
+        }
+      ],
+      "if (a !== b) { foo(); } else { bar(); }": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "if (a === b) { bar(); } else { foo(); }"),
+        }
+      ],
+      "const c = !a ? b : c;": [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "const c = a ? c : b;"),
+        }
+      ],
+      "const c = a !== 1 ? foo() : bar();": [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "const c = a === 1 ? bar() : foo();"),
+        }
+      ],
+      "if (!a) {\n  // a is missing\n  foo();\n} else {\n  bar();\n}": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "if (a) {\n  bar();\n} else {\n  // a is missing\n  foo();\n}"),
+        }
+      ],
+      "if (!a) foo(); // a is missing\nelse bar();": [
+        {
+          col: 0,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
@@ -2,6 +2,7 @@
 use super::program_ref;
 use super::Context;
 use super::LintRule;
+use super::RuleDocs;
 use crate::diagnostic::LintFix;
 use crate::diagnostic::LintFixChange;
 use crate::handler::Handler;
@@ -9,16 +10,24 @@ use crate::handler::Traverse;
 use crate::tags;
 use crate::tags::Tags;
 use crate::Program;
+use serde::Deserialize;
 use std::borrow::Cow;
 
 use deno_ast::view as ast_view;
+use deno_ast::MediaType;
+use deno_ast::ModuleSpecifier;
 use deno_ast::SourcePos;
 use deno_ast::SourceRange;
 use deno_ast::SourceRanged;
 use deno_ast::SourceRangedForSpanned;
 
-#[derive(Debug)]
-pub struct NoNodeGlobals;
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoNodeGlobals {
+  /// Directories whose files are allowed to use Node globals, e.g. `npm` for
+  /// a compatibility layer that is only published to npm.
+  pub ignore_dirs: Vec<String>,
+}
 
 const CODE: &str = "no-node-globals";
 const MESSAGE: &str = "NodeJS globals are not available in Deno";
@@ -28,6 +37,9 @@ static NODE_GLOBALS: phf::Map<&'static str, FixKind> = phf::phf_map! {
   "global" => FixKind::Replace("globalThis"),
   "setImmediate" => FixKind::Import { module: "node:timers", import: "{ setImmediate }" },
   "clearImmediate" => FixKind::Import { module: "node:timers", import: "{ clearImmediate }" },
+  "__dirname" => FixKind::Replace("import.meta.dirname"),
+  "__filename" => FixKind::Replace("import.meta.filename"),
+  "require" => FixKind::Manual("Use an `import` statement, or `createRequire` from \"node:module\" for CommonJS modules"),
 };
 
 impl LintRule for NoNodeGlobals {
@@ -36,6 +48,14 @@ impl LintRule for NoNodeGlobals {
     context: &mut Context<'view>,
     program: Program<'view>,
   ) {
+    // CommonJS modules get `require` and `__dirname` from their module
+    // wrapper, and can't use the `import` statements of the fixes.
+    if matches!(context.media_type(), MediaType::Cjs | MediaType::Cts)
+      || self.is_ignored(context.specifier())
+    {
+      return;
+    }
+
     NoNodeGlobalsHandler {
       most_recent_import_range: None,
     }
@@ -46,11 +66,76 @@ impl LintRule for NoNodeGlobals {
     CODE
   }
 
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows the use of NodeJS globals like `Buffer`, \
+        `__dirname` or `require`, which are not available in Deno. Most of \
+        them can be imported from the corresponding `node:` module or have \
+        a web standard equivalent. CommonJS modules (`.cjs` and `.cts` \
+        files) are not checked.",
+      invalid_examples: &[
+        "const buf = Buffer.from(\"hello\");",
+        "const dir = __dirname;",
+        "const fs = require(\"node:fs\");",
+      ],
+      valid_examples: &[
+        "import { Buffer } from \"node:buffer\";\nconst buf = Buffer.from(\"hello\");",
+        "const dir = import.meta.dirname;",
+        "import * as fs from \"node:fs\";",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "ignoreDirs": {
+          "description": "Directories whose files are allowed to use Node globals, e.g. `npm`",
+          "type": "array",
+          "items": { "type": "string" }
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<NoNodeGlobals>(options)?))
+  }
+
   fn tags(&self) -> Tags {
     &[tags::RECOMMENDED]
   }
 }
 
+impl NoNodeGlobals {
+  fn is_ignored(&self, specifier: &ModuleSpecifier) -> bool {
+    let Some(segments) = specifier.path_segments() else {
+      return false;
+    };
+    let segments = segments.collect::<Vec<_>>();
+    // The last segment is the file name.
+    let dirs = &segments[..segments.len().saturating_sub(1)];
+
+    self.ignore_dirs.iter().any(|ignored| {
+      let ignored = ignored
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>();
+      !ignored.is_empty()
+        && dirs.windows(ignored.len()).any(|w| w == ignored.as_slice())
+    })
+  }
+}
+
 struct NoNodeGlobalsHandler {
   most_recent_import_range: Option<SourceRange>,
 }
@@ -62,6 +147,8 @@ enum FixKind {
     import: &'static str,
   },
   Replace(&'static str),
+  /// No automatic fix is available, only a hint.
+  Manual(&'static str),
 }
 
 #[derive(Default)]
@@ -79,6 +166,7 @@ impl FixKind {
         format!("Add `import {} from \"{}\";`", import, module)
       }
       FixKind::Replace(new) => format!("Use {new} instead"),
+      FixKind::Manual(hint) => hint.to_string(),
     }
   }
 
@@ -88,6 +176,7 @@ impl FixKind {
         format!("Import from \"{module}\"")
       }
       FixKind::Replace(new) => format!("Replace with {new}"),
+      FixKind::Manual(_) => unreachable!("manual fixes have no description"),
     }
   }
 
@@ -102,6 +191,7 @@ impl FixKind {
         format!("{leading}import {import} from \"{module}\";{trailing}").into()
       }
       FixKind::Replace(new_text) => new_text.into(),
+      FixKind::Manual(_) => unreachable!("manual fixes have no text changes"),
     }
   }
 }
@@ -159,6 +249,11 @@ impl NoNodeGlobalsHandler {
     range: SourceRange,
     fix_kind: FixKind,
   ) {
+    if let FixKind::Manual(hint) = fix_kind {
+      ctx.add_diagnostic_with_hint(range, CODE, MESSAGE, hint);
+      return;
+    }
+
     let change = self.fix_change(ctx, range, fix_kind);
 
     ctx.add_diagnostic_with_fixes(
@@ -196,19 +291,54 @@ mod tests {
   #[test]
   fn valid() {
     assert_lint_ok! {
-      NoNodeGlobals,
+      NoNodeGlobals::default(),
       "import { Buffer } from 'node:buffer';\nconst b = Buffer;",
       "const Buffer = {};\nconst b = Buffer;",
       "const global = globalThis;\nconst c = global;",
       "const setImmediate = () => {};\nconst d = setImmediate;",
       "const clearImmediate = () => {};\nconst e = clearImmediate;",
-    }
+      "const __dirname = '.';\nconst f = __dirname;",
+      "import { createRequire } from 'node:module';\nconst require = createRequire(import.meta.url);\nrequire('foo');",
+    };
+
+    assert_lint_ok! {
+      NoNodeGlobals {
+        ignore_dirs: vec!["npm".to_string()],
+      },
+      filename: "file:///project/npm/src/mod.ts",
+      "const b = Buffer;",
+      "const f = __dirname;",
+    };
+
+    assert_lint_ok! {
+      NoNodeGlobals::default(),
+      filename: "file:///project/src/mod.cjs",
+      "const fs = require('node:fs');\nconst f = __dirname;",
+      "const b = Buffer;",
+    };
+
+    assert_lint_ok! {
+      NoNodeGlobals::default(),
+      filename: "file:///project/src/mod.cts",
+      "const f = __filename;",
+    };
+  }
+
+  #[test]
+  fn ignore_dirs() {
+    let rule = NoNodeGlobals {
+      ignore_dirs: vec!["vendor/npm".to_string()],
+    };
+    let specifier = |s: &str| ModuleSpecifier::parse(s).unwrap();
+    assert!(rule.is_ignored(&specifier("file:///a/vendor/npm/mod.ts")));
+    assert!(!rule.is_ignored(&specifier("file:///a/npm/mod.ts")));
+    assert!(!rule.is_ignored(&specifier("file:///a/vendor/npm.ts")));
   }
 
   #[test]
   fn invalid() {
     assert_lint_err! {
-      NoNodeGlobals,
+      NoNodeGlobals::default(),
       "const b = Buffer;": [
         {
           col: 10,
@@ -279,6 +409,26 @@ mod tests {
           ),
         }
       ],
+      "const f = __dirname;": [
+        {
+          col: 10,
+          line: 1,
+          message: MESSAGE,
+          hint: "Use import.meta.dirname instead",
+          fix: (
+            "Replace with import.meta.dirname",
+            "const f = import.meta.dirname;"
+          ),
+        }
+      ],
+      "const fs = require('node:fs');": [
+        {
+          col: 11,
+          line: 1,
+          message: MESSAGE,
+          hint: "Use an `import` statement, or `createRequire` from \"node:module\" for CommonJS modules",
+        }
+      ],
       "// A copyright notice\n\nconst a = setImmediate;": [
         {
           col: 10,
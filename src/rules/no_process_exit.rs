@@ -0,0 +1,323 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::glob;
+use crate::handler::{Handler, Traverse};
+use crate::tags::Tags;
+use crate::Program;
+use deno_ast::swc::ast::{
+  Callee, Expr, Id, ImportSpecifier, MemberProp, MetaPropKind, ModuleExportName,
+};
+use deno_ast::view::{self as ast_view, Node, NodeTrait};
+use deno_ast::{ModuleSpecifier, SourceRanged};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoProcessExit {
+  /// Glob patterns of the entry points of the program, which may exit the
+  /// process, e.g. `**/main.ts`.
+  pub entrypoints: Vec<String>,
+}
+
+const CODE: &str = "no-process-exit";
+const HINT: &str = "Throw an error or return a status instead, and exit in the entry point of the program";
+
+impl LintRule for NoProcessExit {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows exiting the process with `Deno.exit()` or \
+        `process.exit()` outside of the entry points of a program. Library \
+        code that exits takes away the chance of its users to handle the \
+        error, and skips pending cleanup like `finally` blocks. Entry points \
+        are configured with glob patterns in the `entrypoints` option, e.g. \
+        `**/main.ts`, and code inside `if (import.meta.main)` is allowed to \
+        exit as well.",
+      invalid_examples: &[
+        "export function loadConfig(path) {\n  if (!exists(path)) {\n    Deno.exit(1);\n  }\n}",
+        "import process from \"node:process\";\n\nprocess.exit(0);",
+      ],
+      valid_examples: &[
+        "export function loadConfig(path) {\n  if (!exists(path)) {\n    throw new Error(`Config not found: ${path}`);\n  }\n}",
+        "if (import.meta.main) {\n  Deno.exit(await main());\n}",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "entrypoints": {
+          "description": "Glob patterns of the entry points that may exit the process, e.g. `**/main.ts`",
+          "type": "array",
+          "items": { "type": "string" }
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<NoProcessExit>(options)?))
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    if self.is_entrypoint(context.specifier()) {
+      return;
+    }
+    NoProcessExitHandler {
+      process_imports: HashMap::new(),
+    }
+    .traverse(program, context);
+  }
+}
+
+impl NoProcessExit {
+  fn is_entrypoint(&self, specifier: &ModuleSpecifier) -> bool {
+    self
+      .entrypoints
+      .iter()
+      .any(|pattern| glob::matches_specifier(pattern, specifier))
+  }
+}
+
+/// A binding that is imported from `node:process`.
+enum ProcessImport {
+  /// A default or namespace import, e.g. `process`.
+  Module,
+  /// A named import of `exit`.
+  Exit,
+}
+
+struct NoProcessExitHandler {
+  process_imports: HashMap<Id, ProcessImport>,
+}
+
+impl NoProcessExitHandler {
+  /// Returns the name of the exit function called by the expression, e.g.
+  /// `Deno.exit`.
+  fn exit_function(&self, callee: &Expr, ctx: &Context) -> Option<String> {
+    match callee {
+      Expr::Ident(ident) => match self.process_imports.get(&ident.to_id()) {
+        Some(ProcessImport::Exit) => Some(ident.sym.to_string()),
+        _ => None,
+      },
+      Expr::Member(member) => {
+        let (Expr::Ident(obj), MemberProp::Ident(prop)) =
+          (&*member.obj, &member.prop)
+        else {
+          return None;
+        };
+        if prop.sym != *"exit" {
+          return None;
+        }
+        let is_exit = match self.process_imports.get(&obj.to_id()) {
+          Some(ProcessImport::Module) => true,
+          Some(ProcessImport::Exit) => false,
+          None => {
+            matches!(&*obj.sym, "Deno" | "process")
+              && ctx.scope().is_global(&obj.to_id())
+          }
+        };
+        is_exit.then(|| format!("{}.exit", obj.sym))
+      }
+      _ => None,
+    }
+  }
+}
+
+impl Handler for NoProcessExitHandler {
+  fn import_decl(
+    &mut self,
+    import_decl: &ast_view::ImportDecl,
+    _ctx: &mut Context,
+  ) {
+    let import_decl = import_decl.inner;
+    if import_decl.type_only
+      || !matches!(&*import_decl.src.value, "node:process" | "process")
+    {
+      return;
+    }
+    for specifier in &import_decl.specifiers {
+      match specifier {
+        ImportSpecifier::Default(default) => {
+          self
+            .process_imports
+            .insert(default.local.to_id(), ProcessImport::Module);
+        }
+        ImportSpecifier::Namespace(namespace) => {
+          self
+            .process_imports
+            .insert(namespace.local.to_id(), ProcessImport::Module);
+        }
+        ImportSpecifier::Named(named) => {
+          let name = match &named.imported {
+            Some(ModuleExportName::Ident(ident)) => &ident.sym,
+            Some(ModuleExportName::Str(str)) => &str.value,
+            None => &named.local.sym,
+          };
+          if &**name == "exit" && !named.is_type_only {
+            self
+              .process_imports
+              .insert(named.local.to_id(), ProcessImport::Exit);
+          }
+        }
+      }
+    }
+  }
+
+  fn call_expr(&mut self, call_expr: &ast_view::CallExpr, ctx: &mut Context) {
+    let Callee::Expr(callee) = &call_expr.inner.callee else {
+      return;
+    };
+    let Some(name) = self.exit_function(callee, ctx) else {
+      return;
+    };
+    if is_inside_import_meta_main(call_expr.as_node()) {
+      return;
+    }
+    ctx.add_diagnostic_with_hint(
+      call_expr.range(),
+      CODE,
+      format!("`{}()` is not allowed outside of entry points", name),
+      HINT,
+    );
+  }
+}
+
+/// Checks if the node is inside of `if (import.meta.main) { ... }`, which is
+/// only run if the module is the entry point.
+fn is_inside_import_meta_main(node: Node) -> bool {
+  let mut child = node;
+  for ancestor in node.ancestors() {
+    if let Node::IfStmt(if_stmt) = ancestor {
+      if if_stmt.cons.range().contains(&child.range())
+        && is_import_meta_main(if_stmt.inner.test.unwrap_parens())
+      {
+        return true;
+      }
+    }
+    child = ancestor;
+  }
+  false
+}
+
+fn is_import_meta_main(expr: &Expr) -> bool {
+  let Expr::Member(member) = expr else {
+    return false;
+  };
+  matches!(
+    (&*member.obj, &member.prop),
+    (Expr::MetaProp(meta), MemberProp::Ident(prop))
+      if meta.kind == MetaPropKind::ImportMeta && prop.sym == *"main"
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_process_exit_valid() {
+    assert_lint_ok! {
+      NoProcessExit::default(),
+      "throw new Error('failed');",
+      "Deno.exitCode = 1;",
+      "const Deno = { exit() {} }; Deno.exit(1);",
+      "function f(process) { process.exit(1); }",
+      "import { exit } from './exit.ts'; exit(1);",
+      "import { argv } from 'node:process'; argv.exit(1);",
+      "if (import.meta.main) { Deno.exit(1); }",
+      "if (import.meta.main) { main().catch(() => process.exit(1)); }",
+      "if ((import.meta.main)) Deno.exit(1);",
+    };
+
+    assert_lint_ok! {
+      NoProcessExit {
+        entrypoints: vec!["**/main.ts".to_string(), "cli/*.ts".to_string()],
+      },
+      filename: "file:///project/cli/run.ts",
+      "Deno.exit(1);",
+    };
+  }
+
+  #[test]
+  fn no_process_exit_invalid() {
+    assert_lint_err! {
+      NoProcessExit::default(),
+      "Deno.exit(1);": [
+        {
+          col: 0,
+          message: "`Deno.exit()` is not allowed outside of entry points",
+          hint: HINT,
+        }
+      ],
+      "function f() { process.exit(); }": [
+        {
+          col: 15,
+          message: "`process.exit()` is not allowed outside of entry points",
+          hint: HINT,
+        }
+      ],
+      "import proc from 'node:process'; proc.exit(1);": [
+        {
+          col: 33,
+          message: "`proc.exit()` is not allowed outside of entry points",
+          hint: HINT,
+        }
+      ],
+      "import { exit as quit } from 'node:process'; quit(1);": [
+        {
+          col: 45,
+          message: "`quit()` is not allowed outside of entry points",
+          hint: HINT,
+        }
+      ],
+      "if (import.meta.main) {} else { Deno.exit(1); }": [
+        {
+          col: 32,
+          message: "`Deno.exit()` is not allowed outside of entry points",
+          hint: HINT,
+        }
+      ],
+      "if (!import.meta.main) Deno.exit(1);": [
+        {
+          col: 23,
+          message: "`Deno.exit()` is not allowed outside of entry points",
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoProcessExit {
+        entrypoints: vec!["**/main.ts".to_string()],
+      },
+      filename: "file:///project/src/lib.ts",
+      "Deno.exit(1);": [
+        {
+          col: 0,
+          message: "`Deno.exit()` is not allowed outside of entry points",
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
@@ -38,6 +38,10 @@ impl LintRule for NoProcessGlobal {
     CODE
   }
 
+  fn fixable(&self) -> bool {
+    true
+  }
+
   fn tags(&self) -> Tags {
     &[tags::RECOMMENDED]
   }
@@ -0,0 +1,292 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::glob;
+use crate::handler::{Handler, Traverse};
+use crate::tags::Tags;
+use crate::Program;
+use deno_ast::swc::ast::{Callee, Expr, Lit, Str};
+use deno_ast::view as ast_view;
+use deno_ast::{ModuleSpecifier, SourceRangedForSpanned};
+use serde::Deserialize;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoRestrictedPaths {
+  /// Pairs of paths, where the files in `target` may not import the modules
+  /// in `from`.
+  pub zones: Vec<Zone>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct Zone {
+  /// Glob pattern of the importing files, e.g. `src/core`.
+  pub target: String,
+  /// Glob pattern of the modules that may not be imported, e.g. `src/cli`.
+  pub from: String,
+  /// Explanation shown as the hint of the diagnostic.
+  #[serde(default)]
+  pub message: Option<String>,
+}
+
+const CODE: &str = "no-restricted-paths";
+const DEFAULT_HINT: &str =
+  "Move the shared code to a module that both directories may import";
+
+impl LintRule for NoRestrictedPaths {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Restricts which directories may import from which, to \
+        enforce the layering of a project. Each zone of the `zones` option \
+        has a `target` and a `from` path, and the files in `target` may not \
+        import, re-export or dynamically import the modules in `from`, e.g. \
+        `{ \"target\": \"src/core\", \"from\": \"src/cli\" }`. Paths are glob \
+        patterns matching a file or any of its directories, and are relative \
+        unless they start with `/`. An optional `message` is shown as the \
+        hint. Only relative and `file:` specifiers are checked, since bare \
+        specifiers depend on the import map. Nothing is reported without \
+        zones.",
+      invalid_examples: &[],
+      valid_examples: &[],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "zones": {
+          "description": "Pairs of paths, where the files in `target` may not import the modules in `from`",
+          "type": "array",
+          "items": {
+            "type": "object",
+            "properties": {
+              "target": {
+                "description": "Glob pattern of the importing files, e.g. `src/core`",
+                "type": "string"
+              },
+              "from": {
+                "description": "Glob pattern of the modules that may not be imported, e.g. `src/cli`",
+                "type": "string"
+              },
+              "message": {
+                "description": "Explanation shown as the hint of the diagnostic",
+                "type": "string"
+              }
+            },
+            "required": ["target", "from"],
+            "additionalProperties": false
+          }
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<NoRestrictedPaths>(
+      options,
+    )?))
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    let zones = self
+      .zones
+      .iter()
+      .filter(|zone| matches_path(&zone.target, context.specifier()))
+      .collect::<Vec<_>>();
+    if zones.is_empty() {
+      return;
+    }
+    NoRestrictedPathsHandler { zones }.traverse(program, context);
+  }
+}
+
+/// Checks if the path of the specifier, or one of its directories, matches
+/// the glob pattern.
+fn matches_path(pattern: &str, specifier: &ModuleSpecifier) -> bool {
+  let pattern = pattern.trim_end_matches('/');
+  glob::matches_specifier(pattern, specifier)
+    || glob::matches_specifier(&format!("{}/**", pattern), specifier)
+}
+
+struct NoRestrictedPathsHandler<'a> {
+  zones: Vec<&'a Zone>,
+}
+
+impl<'a> NoRestrictedPathsHandler<'a> {
+  fn check(&self, src: &Str, ctx: &mut Context) {
+    let value = src.value.as_str();
+    let is_relative = value.starts_with("./") || value.starts_with("../");
+    if !is_relative && !value.starts_with("file:") {
+      return;
+    }
+    let Ok(imported) = ctx.specifier().join(value) else {
+      return;
+    };
+    let Some(zone) = self
+      .zones
+      .iter()
+      .find(|zone| matches_path(&zone.from, &imported))
+    else {
+      return;
+    };
+    ctx.add_diagnostic_with_hint(
+      src.range(),
+      CODE,
+      format!(
+        "Importing from `{}` is not allowed in `{}`",
+        zone.from, zone.target
+      ),
+      zone.message.as_deref().unwrap_or(DEFAULT_HINT),
+    );
+  }
+}
+
+impl<'a> Handler for NoRestrictedPathsHandler<'a> {
+  fn import_decl(
+    &mut self,
+    import_decl: &ast_view::ImportDecl,
+    ctx: &mut Context,
+  ) {
+    self.check(&import_decl.inner.src, ctx);
+  }
+
+  fn named_export(
+    &mut self,
+    named_export: &ast_view::NamedExport,
+    ctx: &mut Context,
+  ) {
+    if let Some(src) = &named_export.inner.src {
+      self.check(src, ctx);
+    }
+  }
+
+  fn export_all(
+    &mut self,
+    export_all: &ast_view::ExportAll,
+    ctx: &mut Context,
+  ) {
+    self.check(&export_all.inner.src, ctx);
+  }
+
+  fn call_expr(&mut self, call_expr: &ast_view::CallExpr, ctx: &mut Context) {
+    let call_expr = call_expr.inner;
+    if !matches!(call_expr.callee, Callee::Import(_)) {
+      return;
+    }
+    if let Some(Expr::Lit(Lit::Str(src))) =
+      call_expr.args.first().map(|arg| &*arg.expr)
+    {
+      self.check(src, ctx);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn rule() -> NoRestrictedPaths {
+    NoRestrictedPaths {
+      zones: vec![
+        Zone {
+          target: "src/core".to_string(),
+          from: "src/cli".to_string(),
+          message: None,
+        },
+        Zone {
+          target: "src/**/*_test.ts".to_string(),
+          from: "testdata/private".to_string(),
+          message: Some("Use the public fixtures instead".to_string()),
+        },
+      ],
+    }
+  }
+
+  #[test]
+  fn no_restricted_paths_valid() {
+    assert_lint_ok! {
+      NoRestrictedPaths::default(),
+      "import { run } from './cli/main.ts';",
+    };
+    assert_lint_ok! {
+      rule(),
+      filename: "file:///project/src/core/mod.ts",
+      "import { parse } from './parser.ts';",
+      "import { util } from '../util/mod.ts';",
+      "import { run } from 'cli';",
+      "import { run } from 'https://example.com/src/cli/main.ts';",
+      "import(specifier);",
+    };
+    assert_lint_ok! {
+      rule(),
+      filename: "file:///project/src/cli/main.ts",
+      "import { parse } from '../core/parser.ts';",
+      "import { run } from './run.ts';",
+    };
+  }
+
+  #[test]
+  fn no_restricted_paths_invalid() {
+    assert_lint_err! {
+      rule(),
+      filename: "file:///project/src/core/parser/mod.ts",
+      "import { run } from '../../cli/main.ts';": [
+        {
+          col: 20,
+          message: "Importing from `src/cli` is not allowed in `src/core`",
+          hint: DEFAULT_HINT,
+        }
+      ],
+      "export * from '../../cli/mod.ts';": [
+        {
+          col: 14,
+          message: "Importing from `src/cli` is not allowed in `src/core`",
+          hint: DEFAULT_HINT,
+        }
+      ],
+      "export { run } from '../../cli/mod.ts';": [
+        {
+          col: 20,
+          message: "Importing from `src/cli` is not allowed in `src/core`",
+          hint: DEFAULT_HINT,
+        }
+      ],
+      "const cli = await import('file:///project/src/cli/mod.ts');": [
+        {
+          col: 25,
+          message: "Importing from `src/cli` is not allowed in `src/core`",
+          hint: DEFAULT_HINT,
+        }
+      ],
+    };
+    assert_lint_err! {
+      rule(),
+      filename: "file:///project/src/core/parser_test.ts",
+      "import data from '../../testdata/private/data.json' with { type: 'json' };": [
+        {
+          col: 17,
+          message: "Importing from `testdata/private` is not allowed in `src/**/*_test.ts`",
+          hint: "Use the public fixtures instead",
+        }
+      ],
+    };
+  }
+}
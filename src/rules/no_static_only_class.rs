@@ -0,0 +1,128 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::handler::{Handler, Traverse};
+use crate::tags::{self, Tags};
+use crate::Program;
+use deno_ast::swc::ast::ClassMember;
+use deno_ast::view as ast_view;
+use deno_ast::SourceRanged;
+
+#[derive(Debug)]
+pub struct NoStaticOnlyClass;
+
+const CODE: &str = "no-static-only-class";
+const MESSAGE: &str = "Class only has static members";
+const HINT: &str =
+  "Use module-level functions and variables, or a plain object, instead";
+
+impl LintRule for NoStaticOnlyClass {
+  fn tags(&self) -> Tags {
+    &[tags::STYLISTIC]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows classes that only contain static members. \
+        Such classes are never instantiated and only act as a namespace, \
+        which modules already provide: export the functions and values \
+        directly instead. Classes that extend another class, have \
+        decorators, or contain a static initialization block are allowed.",
+      invalid_examples: &[
+        "class MathUtils {\n  static PI = 3.14;\n  static double(x: number) {\n    return x * 2;\n  }\n}",
+      ],
+      valid_examples: &[
+        "export const PI = 3.14;\nexport function double(x: number) {\n  return x * 2;\n}",
+        "class Counter {\n  static instances = 0;\n  count = 0;\n}",
+      ],
+    }
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    NoStaticOnlyClassHandler.traverse(program, context);
+  }
+}
+
+struct NoStaticOnlyClassHandler;
+
+impl Handler for NoStaticOnlyClassHandler {
+  fn class(&mut self, class: &ast_view::Class, ctx: &mut Context) {
+    let inner = class.inner;
+    if inner.super_class.is_some()
+      || inner.is_abstract
+      || !inner.decorators.is_empty()
+    {
+      return;
+    }
+    let mut members = inner
+      .body
+      .iter()
+      .filter(|member| !matches!(member, ClassMember::Empty(_)))
+      .peekable();
+    if members.peek().is_none() {
+      return;
+    }
+    if members.all(is_static_member) {
+      ctx.add_diagnostic_with_hint(class.range(), CODE, MESSAGE, HINT);
+    }
+  }
+}
+
+fn is_static_member(member: &ClassMember) -> bool {
+  match member {
+    ClassMember::Method(method) => method.is_static,
+    ClassMember::PrivateMethod(method) => method.is_static,
+    ClassMember::ClassProp(prop) => prop.is_static,
+    ClassMember::PrivateProp(prop) => prop.is_static,
+    ClassMember::AutoAccessor(accessor) => accessor.is_static,
+    ClassMember::TsIndexSignature(signature) => signature.is_static,
+    ClassMember::Constructor(_)
+    | ClassMember::StaticBlock(_)
+    | ClassMember::Empty(_) => false,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_static_only_class_valid() {
+    assert_lint_ok! {
+      NoStaticOnlyClass,
+      "class A {}",
+      "class A { a = 1; }",
+      "class A { static a = 1; b() {} }",
+      "class A { static a = 1; constructor() {} }",
+      "class A { static a = 1; #b = 2; }",
+      "class A extends B { static a = 1; }",
+      "@decorator class A { static a = 1; }",
+      "abstract class A { static a = 1; }",
+      "class A { static a = 1; static { init(); } }",
+    };
+  }
+
+  #[test]
+  fn no_static_only_class_invalid() {
+    assert_lint_err! {
+      NoStaticOnlyClass,
+      MESSAGE,
+      HINT,
+      "class A { static a = 1; }": [{ col: 0 }],
+      "class A { static a() {} }": [{ col: 0 }],
+      "class A { static #a = 1; static b() { return A.#a; } }": [{ col: 0 }],
+      "class A { static get a() { return 1; }; }": [{ col: 0 }],
+      "class A { private static a = 1; public static b() {} }": [{ col: 0 }],
+      "export class A { static a = 1; }": [{ col: 7 }],
+      "const A = class { static a = 1; };": [{ col: 10 }],
+    };
+  }
+}
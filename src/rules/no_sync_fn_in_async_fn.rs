@@ -1,15 +1,26 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
-use super::{Context, LintRule};
+use super::{program_ref, Context, LintRule, RuleDocs};
 use crate::handler::{Handler, Traverse};
 use crate::tags::Tags;
 use crate::Program;
+use crate::ProgramRef;
+use deno_ast::swc::ast::{
+  Id, ImportSpecifier, ModuleDecl, ModuleExportName, ModuleItem,
+};
 use deno_ast::view::NodeTrait;
 use deno_ast::{view as ast_view, SourceRanged};
 use if_chain::if_chain;
+use serde::Deserialize;
+use std::collections::HashMap;
 
-#[derive(Debug)]
-pub struct NoSyncFnInAsyncFn;
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoSyncFnInAsyncFn {
+  /// Names of sync functions that may be used in async functions, e.g.
+  /// `statSync` for a quick check at startup.
+  pub allow: Vec<String>,
+}
 
 const CODE: &str = "no-sync-fn-in-async-fn";
 const MESSAGE: &str =
@@ -24,13 +35,105 @@ impl LintRule for NoSyncFnInAsyncFn {
     CODE
   }
 
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows the sync file system and process APIs of the \
+        `Deno` namespace, like `Deno.readTextFileSync`, and of the `node:fs` \
+        module, like `readFileSync`, inside async functions. They block the \
+        event loop until they are done, while the async equivalents let other \
+        tasks run in the meantime. Functions that are fine to use can be \
+        listed in the `allow` option, e.g. `[\"statSync\"]`.",
+      invalid_examples: &[
+        "async function load() {\n  return Deno.readTextFileSync(\"config.json\");\n}",
+        "import { readFileSync } from \"node:fs\";\n\nasync function load() {\n  return readFileSync(\"config.json\", \"utf8\");\n}",
+      ],
+      valid_examples: &[
+        "async function load() {\n  return await Deno.readTextFile(\"config.json\");\n}",
+        "import { readFile } from \"node:fs/promises\";\n\nasync function load() {\n  return await readFile(\"config.json\", \"utf8\");\n}",
+        "function load() {\n  return Deno.readTextFileSync(\"config.json\");\n}",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "allow": {
+          "description": "Names of sync functions that may be used in async functions, e.g. `statSync`",
+          "type": "array",
+          "items": { "type": "string" }
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<NoSyncFnInAsyncFn>(
+      options,
+    )?))
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
     program: Program<'_>,
   ) {
-    NoSyncFnInAsyncFnHandler.traverse(program, context);
+    NoSyncFnInAsyncFnHandler {
+      allow: &self.allow,
+      node_fs_imports: collect_node_fs_imports(program_ref(program)),
+    }
+    .traverse(program, context);
+  }
+}
+
+/// A binding that is imported from `node:fs`.
+enum NodeFsImport {
+  /// A default or namespace import, e.g. `fs` in `import * as fs from "fs"`.
+  Module,
+  /// A named import with the name of the export, e.g. `readFileSync`.
+  Named(String),
+}
+
+fn collect_node_fs_imports(program: ProgramRef) -> HashMap<Id, NodeFsImport> {
+  let mut imports = HashMap::new();
+  let ProgramRef::Module(module) = program else {
+    return imports;
+  };
+  for item in &module.body {
+    let ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) = item else {
+      continue;
+    };
+    if import_decl.type_only
+      || !matches!(&*import_decl.src.value, "node:fs" | "fs")
+    {
+      continue;
+    }
+    for specifier in &import_decl.specifiers {
+      match specifier {
+        ImportSpecifier::Default(default) => {
+          imports.insert(default.local.to_id(), NodeFsImport::Module);
+        }
+        ImportSpecifier::Namespace(namespace) => {
+          imports.insert(namespace.local.to_id(), NodeFsImport::Module);
+        }
+        ImportSpecifier::Named(named) if !named.is_type_only => {
+          let name = match &named.imported {
+            Some(ModuleExportName::Ident(ident)) => ident.sym.to_string(),
+            Some(ModuleExportName::Str(str)) => str.value.to_string(),
+            None => named.local.sym.to_string(),
+          };
+          imports.insert(named.local.to_id(), NodeFsImport::Named(name));
+        }
+        ImportSpecifier::Named(_) => {}
+      }
+    }
   }
+  imports
 }
 
 /// Extracts a symbol from the given member prop if the symbol is statically determined (otherwise,
@@ -55,30 +158,47 @@ fn extract_symbol<'a>(
   }
 }
 
-struct NoSyncFnInAsyncFnHandler;
+fn inside_async_fn(node: ast_view::Node) -> bool {
+  use deno_ast::view::Node::*;
+  match node {
+    Function(function) => function.is_async(),
+    ArrowExpr(arrow) => arrow.is_async(),
+    _ => {
+      let parent = match node.parent() {
+        Some(p) => p,
+        None => return false,
+      };
+      inside_async_fn(parent)
+    }
+  }
+}
+
+/// Returns the name of the async equivalent of a function of `node:fs`.
+fn node_fs_async_name(name: &str) -> Option<&str> {
+  match name {
+    // `exists` is deprecated, and not part of `node:fs/promises`.
+    "existsSync" => Some("access"),
+    _ => name.strip_suffix("Sync"),
+  }
+}
+
+struct NoSyncFnInAsyncFnHandler<'a> {
+  allow: &'a [String],
+  node_fs_imports: HashMap<Id, NodeFsImport>,
+}
 
-impl Handler for NoSyncFnInAsyncFnHandler {
+impl<'a> NoSyncFnInAsyncFnHandler<'a> {
+  fn is_allowed(&self, name: &str) -> bool {
+    self.allow.iter().any(|allowed| allowed == name)
+  }
+}
+
+impl<'a> Handler for NoSyncFnInAsyncFnHandler<'a> {
   fn member_expr(
     &mut self,
     member_expr: &ast_view::MemberExpr,
     ctx: &mut Context,
   ) {
-    fn inside_async_fn(node: ast_view::Node) -> bool {
-      use deno_ast::view::Node::*;
-      match node {
-        FnDecl(decl) => decl.function.is_async(),
-        FnExpr(decl) => decl.function.is_async(),
-        ArrowExpr(decl) => decl.is_async(),
-        _ => {
-          let parent = match node.parent() {
-            Some(p) => p,
-            None => return false,
-          };
-          inside_async_fn(parent)
-        }
-      }
-    }
-
     // Not check chained member expressions (e.g. `foo.bar.baz`)
     if member_expr.parent().is::<ast_view::MemberExpr>() {
       return;
@@ -87,18 +207,44 @@ impl Handler for NoSyncFnInAsyncFnHandler {
     use deno_ast::view::Expr;
     if_chain! {
       if let Expr::Ident(obj) = &member_expr.obj;
-      if ctx.scope().is_global(&obj.inner.to_id());
-      let obj_symbol: &str = obj.sym();
-      if obj_symbol == "Deno";
       if let Some(prop_symbol) = extract_symbol(&member_expr.prop);
-      if let Some(async_name) = prop_symbol.strip_suffix("Sync");
+      if !self.is_allowed(prop_symbol);
       if inside_async_fn(member_expr.as_node());
+      then {
+        let id = obj.inner.to_id();
+        let hint = if obj.sym() == "Deno" && ctx.scope().is_global(&id) {
+          prop_symbol.strip_suffix("Sync").map(|async_name| {
+            format!("Consider changing this to an async equivalent: `await Deno.{}(..)`",
+              async_name)
+          })
+        } else if let Some(NodeFsImport::Module) = self.node_fs_imports.get(&id) {
+          node_fs_async_name(prop_symbol).map(|async_name| {
+            format!("Consider changing this to an async equivalent: `await {}.promises.{}(..)`",
+              obj.sym(), async_name)
+          })
+        } else {
+          None
+        };
+        if let Some(hint) = hint {
+          ctx.add_diagnostic_with_hint(member_expr.range(), CODE, MESSAGE, hint);
+        }
+      }
+    }
+  }
+
+  fn ident(&mut self, ident: &ast_view::Ident, ctx: &mut Context) {
+    if_chain! {
+      if let Some(NodeFsImport::Named(name)) =
+        self.node_fs_imports.get(&ident.inner.to_id());
+      if !self.is_allowed(name);
+      if let Some(async_name) = node_fs_async_name(name);
+      if inside_async_fn(ident.as_node());
       then {
         ctx.add_diagnostic_with_hint(
-          member_expr.range(),
+          ident.range(),
           CODE,
           MESSAGE,
-          format!("Consider changing this to an async equivalent: `await Deno.{}(..)`",
+          format!("Consider changing this to an async equivalent: `await {}(..)` from \"node:fs/promises\"",
             async_name),
         );
       }
@@ -113,7 +259,7 @@ mod tests {
   #[test]
   fn no_sync_fn_in_async_fn_is_valid() {
     assert_lint_ok! {
-    NoSyncFnInAsyncFn,
+    NoSyncFnInAsyncFn::default(),
           r#"
       function foo(things) {
         Deno.readTextFileSync("");
@@ -142,7 +288,7 @@ mod tests {
   #[test]
   fn no_sync_fn_in_async_fn_is_invalid() {
     assert_lint_err! {
-      NoSyncFnInAsyncFn,
+      NoSyncFnInAsyncFn::default(),
       MESSAGE,
       "Consider changing this to an async equivalent: `await Deno.readTextFile(..)`",
       r#"
@@ -162,4 +308,84 @@ mod tests {
       "#: [{ line: 3, col: 8 }],
     }
   }
+  #[test]
+  fn no_sync_fn_in_async_fn_node_fs() {
+    assert_lint_ok! {
+      NoSyncFnInAsyncFn::default(),
+      r#"import { readFileSync } from "node:fs"; function foo() { readFileSync(""); }"#,
+      r#"import { readFile } from "node:fs/promises"; async function foo() { await readFile(""); }"#,
+      r#"import * as fs from "node:fs"; async function foo() { await fs.promises.readFile(""); }"#,
+      r#"import { readFileSync } from "./fs.ts"; async function foo() { readFileSync(""); }"#,
+      r#"import { readFileSync } from "node:fs"; async function foo(readFileSync) { readFileSync(""); }"#,
+      r#"const Deno = {}; async function foo() { Deno.readTextFileSync(""); }"#,
+    };
+
+    assert_lint_err! {
+      NoSyncFnInAsyncFn::default(),
+      r#"import { readFileSync } from "node:fs"; async function foo() { readFileSync(""); }"#: [
+        {
+          col: 63,
+          message: MESSAGE,
+          hint: "Consider changing this to an async equivalent: `await readFile(..)` from \"node:fs/promises\"",
+        }
+      ],
+      r#"import { writeFileSync as write } from "fs"; const foo = async () => write("", "");"#: [
+        {
+          col: 69,
+          message: MESSAGE,
+          hint: "Consider changing this to an async equivalent: `await writeFile(..)` from \"node:fs/promises\"",
+        }
+      ],
+      r#"import fs from "node:fs"; async function foo() { fs.existsSync(""); }"#: [
+        {
+          col: 49,
+          message: MESSAGE,
+          hint: "Consider changing this to an async equivalent: `await fs.promises.access(..)`",
+        }
+      ],
+      r#"import * as nodeFs from "node:fs"; class A { async foo() { nodeFs.mkdirSync(""); } }"#: [
+        {
+          col: 59,
+          message: MESSAGE,
+          hint: "Consider changing this to an async equivalent: `await nodeFs.promises.mkdir(..)`",
+        }
+      ],
+      r#"const obj = { async foo() { Deno.statSync(""); } };"#: [
+        {
+          col: 28,
+          message: MESSAGE,
+          hint: "Consider changing this to an async equivalent: `await Deno.stat(..)`",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_sync_fn_in_async_fn_allow() {
+    let rule = || NoSyncFnInAsyncFn {
+      allow: vec!["statSync".to_string(), "existsSync".to_string()],
+    };
+    assert_lint_ok! {
+      rule(),
+      r#"async function foo() { Deno.statSync(""); }"#,
+      r#"import { existsSync } from "node:fs"; async function foo() { existsSync(""); }"#,
+    };
+    assert_lint_err! {
+      rule(),
+      r#"async function foo() { Deno.readTextFileSync(""); }"#: [
+        {
+          col: 23,
+          message: MESSAGE,
+          hint: "Consider changing this to an async equivalent: `await Deno.readTextFile(..)`",
+        }
+      ],
+    };
+
+    let rule = NoSyncFnInAsyncFn::default()
+      .with_options(serde_json::json!({ "allow": ["statSync"] }));
+    assert!(rule.is_ok());
+    let rule = NoSyncFnInAsyncFn::default()
+      .with_options(serde_json::json!({ "deny": ["statSync"] }));
+    assert!(rule.is_err());
+  }
 }
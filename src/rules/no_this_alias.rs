@@ -1,18 +1,35 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
-use super::{Context, LintRule};
+use super::{Context, LintRule, RuleDocs};
 use crate::handler::{Handler, Traverse};
 use crate::tags::{self, Tags};
 use crate::Program;
 use deno_ast::view::{Expr, Pat, VarDecl};
 use deno_ast::SourceRanged;
-use if_chain::if_chain;
+use serde::Deserialize;
 
-#[derive(Debug)]
-pub struct NoThisAlias;
+#[derive(Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoThisAlias {
+  /// Allows destructuring `this`, e.g. `const { props } = this`.
+  pub allow_destructuring: bool,
+  /// Names that `this` may be assigned to, e.g. `self`.
+  pub allowed_names: Vec<String>,
+}
+
+impl Default for NoThisAlias {
+  fn default() -> Self {
+    Self {
+      allow_destructuring: true,
+      allowed_names: vec![],
+    }
+  }
+}
 
 const CODE: &str = "no-this-alias";
 const MESSAGE: &str = "assign `this` to declare a value is not allowed";
+const DESTRUCTURING_MESSAGE: &str =
+  "destructure `this` to declare values is not allowed";
 
 impl LintRule for NoThisAlias {
   fn tags(&self) -> Tags {
@@ -23,27 +40,78 @@ impl LintRule for NoThisAlias {
     CODE
   }
 
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows assigning `this` to a variable. Arrow \
+        functions keep the `this` of the enclosing function, so aliases like \
+        `const self = this` are rarely needed. Names listed in the \
+        `allowedNames` option may still be used. Destructuring `this` is \
+        allowed unless `allowDestructuring` is `false`.",
+      invalid_examples: &[
+        "const self = this;\nsetTimeout(function () {\n  self.run();\n});",
+      ],
+      valid_examples: &[
+        "setTimeout(() => {\n  this.run();\n});",
+        "const { props, state } = this;",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "allowDestructuring": {
+          "description": "Allow destructuring `this`. Defaults to `true`",
+          "type": "boolean"
+        },
+        "allowedNames": {
+          "description": "Names that `this` may be assigned to, e.g. `self`",
+          "type": "array",
+          "items": { "type": "string" }
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<NoThisAlias>(options)?))
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
     program: Program,
   ) {
-    NoThisAliasHandler.traverse(program, context);
+    NoThisAliasHandler { options: self }.traverse(program, context);
   }
 }
 
-struct NoThisAliasHandler;
+struct NoThisAliasHandler<'a> {
+  options: &'a NoThisAlias,
+}
 
-impl Handler for NoThisAliasHandler {
+impl Handler for NoThisAliasHandler<'_> {
   fn var_decl(&mut self, var_decl: &VarDecl, ctx: &mut Context) {
     for decl in var_decl.decls {
-      if_chain! {
-        if let Some(init) = &decl.init;
-        if matches!(&init, Expr::This(_));
-        if matches!(&decl.name, Pat::Ident(_));
-        then {
-          ctx.add_diagnostic(var_decl.range(), CODE, MESSAGE);
+      if !matches!(&decl.init, Some(Expr::This(_))) {
+        continue;
+      }
+      match &decl.name {
+        Pat::Ident(ident) => {
+          let name = ident.id.sym();
+          if !self.options.allowed_names.iter().any(|n| *n == **name) {
+            ctx.add_diagnostic(var_decl.range(), CODE, MESSAGE);
+          }
         }
+        Pat::Object(_) | Pat::Array(_) if !self.options.allow_destructuring => {
+          ctx.add_diagnostic(var_decl.range(), CODE, DESTRUCTURING_MESSAGE);
+        }
+        _ => {}
       }
     }
   }
@@ -56,7 +124,7 @@ mod tests {
   #[test]
   fn no_this_alias_valid() {
     assert_lint_ok! {
-      NoThisAlias,
+      NoThisAlias::default(),
       "const self = foo(this);",
       "const self = 'this';",
       "const { props, state } = this;",
@@ -67,7 +135,7 @@ mod tests {
   #[test]
   fn no_this_alias_invalid() {
     assert_lint_err! {
-      NoThisAlias,
+      NoThisAlias::default(),
       "const self = this;": [
         {
           col: 0,
@@ -139,4 +207,45 @@ class TestClass {
       ]
     };
   }
+
+  #[test]
+  fn no_this_alias_options() {
+    assert_lint_ok! {
+      NoThisAlias {
+        allowed_names: vec!["self".to_string()],
+        ..Default::default()
+      },
+      "const self = this;",
+    };
+    assert_lint_err! {
+      NoThisAlias {
+        allowed_names: vec!["self".to_string()],
+        ..Default::default()
+      },
+      "const that = this;": [
+        {
+          col: 0,
+          message: MESSAGE,
+        }
+      ],
+    };
+    assert_lint_err! {
+      NoThisAlias {
+        allow_destructuring: false,
+        ..Default::default()
+      },
+      "const { props, state } = this;": [
+        {
+          col: 0,
+          message: DESTRUCTURING_MESSAGE,
+        }
+      ],
+      "const [foo] = this;": [
+        {
+          col: 0,
+          message: DESTRUCTURING_MESSAGE,
+        }
+      ],
+    };
+  }
 }
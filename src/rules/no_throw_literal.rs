@@ -1,9 +1,10 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
-use super::{Context, LintRule};
+use super::{program_ref, Context, LintRule};
+use crate::error_value::{LocalClasses, NonErrorValue};
 use crate::handler::{Handler, Traverse};
 use crate::Program;
-use deno_ast::view::{Expr, ThrowStmt};
+use deno_ast::view::ThrowStmt;
 use deno_ast::SourceRanged;
 use derive_more::Display;
 
@@ -31,27 +32,27 @@ impl LintRule for NoThrowLiteral {
     context: &mut Context,
     program: Program,
   ) {
-    NoThrowLiteralHandler.traverse(program, context);
+    NoThrowLiteralHandler {
+      classes: LocalClasses::collect(program_ref(program)),
+    }
+    .traverse(program, context);
   }
 }
 
-struct NoThrowLiteralHandler;
+struct NoThrowLiteralHandler {
+  classes: LocalClasses,
+}
 
 impl Handler for NoThrowLiteralHandler {
   fn throw_stmt(&mut self, throw_stmt: &ThrowStmt, ctx: &mut Context) {
-    match throw_stmt.arg {
-      Expr::Lit(_) => ctx.add_diagnostic(
-        throw_stmt.range(),
-        CODE,
-        NoThrowLiteralMessage::ErrObjectExpected,
-      ),
-      Expr::Ident(ident) if *ident.sym() == *"undefined" => ctx.add_diagnostic(
-        throw_stmt.range(),
-        CODE,
-        NoThrowLiteralMessage::Undefined,
-      ),
-      _ => {}
-    }
+    let message = match self.classes.non_error_value(&throw_stmt.inner.arg) {
+      Some(NonErrorValue::Undefined) => NoThrowLiteralMessage::Undefined,
+      Some(NonErrorValue::Literal | NonErrorValue::Other) => {
+        NoThrowLiteralMessage::ErrObjectExpected
+      }
+      None => return,
+    };
+    ctx.add_diagnostic(throw_stmt.range(), CODE, message);
   }
 }
 
@@ -64,6 +65,15 @@ mod tests {
     assert_lint_ok! {
       NoThrowLiteral,
       "throw e",
+      "throw new Error('failed')",
+      "throw error.cause",
+      "throw createError()",
+      "throw a || new Error()",
+      "throw (a, b)",
+      "class MyError extends Error {}\nthrow new MyError();",
+      "class Base extends Error {}\nclass MyError extends Base {}\nthrow new MyError();",
+      "class MyError extends mixin(Base) {}\nthrow new MyError();",
+      "throw new Unknown();",
     };
   }
 
@@ -96,6 +106,43 @@ mod tests {
         col: 0,
         message: NoThrowLiteralMessage::Undefined,
       }],
+      r#"throw `failed: ${reason}`"#: [
+      {
+        col: 0,
+        message: NoThrowLiteralMessage::ErrObjectExpected,
+      }],
+      r#"throw { message: 'failed' }"#: [
+      {
+        col: 0,
+        message: NoThrowLiteralMessage::ErrObjectExpected,
+      }],
+      r#"throw 'failed: ' + reason"#: [
+      {
+        col: 0,
+        message: NoThrowLiteralMessage::ErrObjectExpected,
+      }],
+      r#"throw cond ? 'a' : 'b'"#: [
+      {
+        col: 0,
+        message: NoThrowLiteralMessage::ErrObjectExpected,
+      }],
+      r#"throw ('failed' as unknown)"#: [
+      {
+        col: 0,
+        message: NoThrowLiteralMessage::ErrObjectExpected,
+      }],
+      "class Failure {}\nthrow new Failure();": [
+      {
+        line: 2,
+        col: 0,
+        message: NoThrowLiteralMessage::ErrObjectExpected,
+      }],
+      "class Base {}\nconst Failure = class extends Base {};\nthrow new Failure();": [
+      {
+        line: 3,
+        col: 0,
+        message: NoThrowLiteralMessage::ErrObjectExpected,
+      }],
     }
   }
 }
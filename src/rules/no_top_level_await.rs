@@ -1,15 +1,26 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
-use super::{Context, LintRule};
+use super::{Context, LintRule, RuleDocs};
+use crate::glob;
 use crate::handler::{Handler, Traverse};
 use crate::Program;
 use deno_ast::view::NodeTrait;
 use deno_ast::view::{self as ast_view};
+use deno_ast::ModuleSpecifier;
 use deno_ast::SourceRanged;
 use if_chain::if_chain;
+use serde::Deserialize;
 
-#[derive(Debug)]
-pub struct NoTopLevelAwait;
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoTopLevelAwait {
+  /// Glob patterns of the files to check, e.g. `src/**` for the sources of a
+  /// library. All files are checked if empty.
+  pub include: Vec<String>,
+  /// Glob patterns of files that may use top level await even if they are
+  /// matched by `include`, e.g. `**/main.ts` for executable entry points.
+  pub exclude: Vec<String>,
+}
 
 const CODE: &str = "no-top-level-await";
 const MESSAGE: &str = "Top level await is not allowed";
@@ -19,15 +30,78 @@ impl LintRule for NoTopLevelAwait {
     CODE
   }
 
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows top level `await`. A module that uses top level \
+        `await` blocks the evaluation of every module that imports it, so it \
+        should be avoided in libraries. Use the `include` option to only \
+        check the sources of a library (e.g. `src/**`), and `exclude` to \
+        allow it in entry points that are executed directly.",
+      invalid_examples: &[
+        "const config = await loadConfig();",
+        "for await (const chunk of stream) {}",
+      ],
+      valid_examples: &[
+        "export async function getConfig() {\n  return await loadConfig();\n}",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "include": {
+          "description": "Glob patterns of the files to check, e.g. `src/**`. All files are checked if empty",
+          "type": "array",
+          "items": { "type": "string" }
+        },
+        "exclude": {
+          "description": "Glob patterns of files that may use top level await, e.g. `**/main.ts`",
+          "type": "array",
+          "items": { "type": "string" }
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<NoTopLevelAwait>(
+      options,
+    )?))
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
     program: Program<'_>,
   ) {
+    if !self.is_checked(context.specifier()) {
+      return;
+    }
     NoTopLevelAwaitHandler.traverse(program, context);
   }
 }
 
+impl NoTopLevelAwait {
+  fn is_checked(&self, specifier: &ModuleSpecifier) -> bool {
+    let is_included = self.include.is_empty()
+      || self
+        .include
+        .iter()
+        .any(|pattern| glob::matches_specifier(pattern, specifier));
+    is_included
+      && !self
+        .exclude
+        .iter()
+        .any(|pattern| glob::matches_specifier(pattern, specifier))
+  }
+}
+
 struct NoTopLevelAwaitHandler;
 
 impl Handler for NoTopLevelAwaitHandler {
@@ -76,7 +150,7 @@ mod tests {
   #[test]
   fn no_top_level_await_valid() {
     assert_lint_ok! {
-      NoTopLevelAwait,
+      NoTopLevelAwait::default(),
       r#"async function foo() { await bar(); }"#,
       r#"const foo = async function () { await bar()};"#,
       r#"const foo = () => { await bar()};"#,
@@ -93,7 +167,7 @@ mod tests {
   #[test]
   fn no_top_level_await_invalid() {
     assert_lint_err! {
-      NoTopLevelAwait,
+      NoTopLevelAwait::default(),
       r#"await foo()"#: [
         {
           col: 0,
@@ -108,4 +182,33 @@ mod tests {
       ],
     };
   }
+
+  #[test]
+  fn no_top_level_await_library_mode() {
+    let rule = || NoTopLevelAwait {
+      include: vec!["src/**".to_string()],
+      exclude: vec!["**/main.ts".to_string()],
+    };
+
+    assert_lint_ok! {
+      rule(),
+      filename: "file:///project/scripts/build.ts",
+      "await build();",
+    };
+    assert_lint_ok! {
+      rule(),
+      filename: "file:///project/src/main.ts",
+      "await serve();",
+    };
+    assert_lint_err! {
+      rule(),
+      filename: "file:///project/src/utils/config.ts",
+      r#"export const config = await load();"#: [
+        {
+          col: 22,
+          message: MESSAGE,
+        },
+      ],
+    };
+  }
 }
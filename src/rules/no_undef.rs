@@ -1,8 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::program_ref;
-use super::{Context, LintRule};
-use crate::globals::GLOBALS;
+use super::{Context, LintRule, RuleDocs};
+use crate::globals::{BROWSER_GLOBALS, GLOBALS, NODE_GLOBALS};
+use crate::tags::Tags;
 use crate::Program;
 use crate::ProgramRef;
 use deno_ast::swc::{
@@ -10,13 +11,114 @@ use deno_ast::swc::{
   visit::{noop_visit_type, Visit, VisitWith},
 };
 use deno_ast::SourceRangedForSpanned;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoUndef {
+  /// Names of additional globals, e.g. the ones a bundler defines.
+  pub globals: Vec<String>,
+  /// The environments whose globals are defined.
+  pub env: Vec<Env>,
+}
+
+impl Default for NoUndef {
+  fn default() -> Self {
+    NoUndef {
+      globals: vec![],
+      env: vec![Env::Deno],
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Env {
+  /// The globals of Deno, including the `Deno` namespace.
+  Deno,
+  /// The web APIs and the DOM.
+  Browser,
+  /// The web APIs available in Node.js, and globals like `process` and
+  /// `require`.
+  Node,
+}
+
+impl Env {
+  fn has_global(self, name: &str) -> bool {
+    let is_shared_global = |name: &str| {
+      name != "Deno" && GLOBALS.iter().any(|(global, _)| *global == name)
+    };
+    match self {
+      Env::Deno => GLOBALS.iter().any(|(global, _)| *global == name),
+      Env::Browser => {
+        is_shared_global(name)
+          || BROWSER_GLOBALS.iter().any(|global| *global == name)
+      }
+      Env::Node => {
+        is_shared_global(name)
+          || NODE_GLOBALS.iter().any(|global| *global == name)
+      }
+    }
+  }
+}
 
-#[derive(Debug)]
-pub struct NoUndef;
+const CODE: &str = "no-undef";
 
 impl LintRule for NoUndef {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
   fn code(&self) -> &'static str {
-    "no-undef"
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows references to variables that aren't declared \
+        in any scope of the file and aren't globals of the environments the \
+        code runs in. They're usually typos, or globals of another \
+        environment, and throw a `ReferenceError` at runtime. The globals of \
+        Deno are defined by default. Other environments can be selected with \
+        the `env` option (`deno`, `browser` and `node`), and additional \
+        globals with the `globals` option.",
+      invalid_examples: &[
+        "const total = price * quantity;",
+        "document.title = \"Hello\";",
+      ],
+      valid_examples: &[
+        "const price = 2;\nconst quantity = 3;\nconst total = price * quantity;",
+        "console.log(Deno.args);",
+        "if (typeof document !== \"undefined\") {}",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "globals": {
+          "description": "Names of additional globals, e.g. the ones a bundler defines",
+          "type": "array",
+          "items": { "type": "string" }
+        },
+        "env": {
+          "description": "The environments whose globals are defined",
+          "type": "array",
+          "items": { "enum": ["deno", "browser", "node"] },
+          "default": ["deno"]
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<NoUndef>(options)?))
   }
 
   fn lint_program_with_ast_view<'view>(
@@ -25,7 +127,7 @@ impl LintRule for NoUndef {
     program: Program<'view>,
   ) {
     let program = program_ref(program);
-    let mut visitor = NoUndefVisitor::new(context);
+    let mut visitor = NoUndefVisitor::new(self, context);
     match program {
       ProgramRef::Module(m) => m.visit_with(&mut visitor),
       ProgramRef::Script(s) => s.visit_with(&mut visitor),
@@ -33,13 +135,21 @@ impl LintRule for NoUndef {
   }
 }
 
+impl NoUndef {
+  fn is_global(&self, name: &str) -> bool {
+    self.globals.iter().any(|global| global == name)
+      || self.env.iter().any(|env| env.has_global(name))
+  }
+}
+
 struct NoUndefVisitor<'c, 'view> {
+  rule: &'c NoUndef,
   context: &'c mut Context<'view>,
 }
 
 impl<'c, 'view> NoUndefVisitor<'c, 'view> {
-  fn new(context: &'c mut Context<'view>) -> Self {
-    Self { context }
+  fn new(rule: &'c NoUndef, context: &'c mut Context<'view>) -> Self {
+    Self { rule, context }
   }
 
   fn check(&mut self, ident: &Ident) {
@@ -62,13 +172,13 @@ impl<'c, 'view> NoUndefVisitor<'c, 'view> {
     }
 
     // Globals
-    if GLOBALS.iter().any(|(name, _)| name == &&*ident.sym) {
+    if self.rule.is_global(&ident.sym) {
       return;
     }
 
     self.context.add_diagnostic(
       ident.range(),
-      "no-undef",
+      CODE,
       format!("{} is not defined", ident.sym),
     )
   }
@@ -149,7 +259,7 @@ mod tests {
   #[test]
   fn no_undef_valid() {
     assert_lint_ok! {
-      NoUndef,
+      NoUndef::default(),
       "var a = 1, b = 2; a;",
       "function a(){}  a();",
       "function f(b) { b; }",
@@ -305,7 +415,7 @@ mod tests {
   #[test]
   fn no_undef_invalid() {
     assert_lint_err! {
-      NoUndef,
+      NoUndef::default(),
       "a = 1;": [
         {
           col: 0,
@@ -390,4 +500,45 @@ mod tests {
       ],
     };
   }
+
+  #[test]
+  fn no_undef_env() {
+    assert_lint_ok! {
+      NoUndef {
+        globals: vec![],
+        env: vec![Env::Browser],
+      },
+      "document.title = 'Hello';",
+      "localStorage.setItem('a', 'b');",
+      "console.log(new URL(location.href));",
+    };
+    assert_lint_ok! {
+      NoUndef {
+        globals: vec![],
+        env: vec![Env::Node, Env::Deno],
+      },
+      "const fs = require('fs'); module.exports = fs;",
+      "process.exit(Deno.args.length);",
+    };
+    assert_lint_ok! {
+      NoUndef {
+        globals: vec!["__APP_VERSION__".to_string()],
+        ..Default::default()
+      },
+      "console.log(__APP_VERSION__);",
+    };
+    assert_lint_err! {
+      NoUndef {
+        globals: vec![],
+        env: vec![Env::Browser],
+      },
+      "Deno.exit(1);": [{ col: 0, message: "Deno is not defined" }],
+      "process.exit(1);": [{ col: 0, message: "process is not defined" }],
+    };
+    assert_lint_err! {
+      NoUndef::default(),
+      "document.title = 'Hello';": [{ col: 0, message: "document is not defined" }],
+      "require('fs');": [{ col: 0, message: "require is not defined" }],
+    };
+  }
 }
@@ -3,6 +3,7 @@
 use super::program_ref;
 use super::Context;
 use super::LintRule;
+use super::RuleDocs;
 use crate::tags;
 use crate::tags::Tags;
 use crate::Program;
@@ -12,6 +13,7 @@ use deno_ast::swc::ast::Stmt;
 use deno_ast::swc::ast::VarDeclKind;
 use deno_ast::swc::visit::Visit;
 use deno_ast::swc::visit::VisitWith;
+use deno_ast::SourceRange;
 use deno_ast::SourceRangedForSpanned;
 
 #[derive(Debug)]
@@ -19,6 +21,7 @@ pub struct NoUnreachable;
 
 const CODE: &str = "no-unreachable";
 const MESSAGE: &str = "This statement is unreachable";
+const MESSAGE_MULTIPLE: &str = "These statements are unreachable";
 
 impl LintRule for NoUnreachable {
   fn tags(&self) -> Tags {
@@ -29,6 +32,25 @@ impl LintRule for NoUnreachable {
     CODE
   }
 
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows statements that can never be executed, because \
+        they come after a `return`, `throw`, `break` or `continue` statement \
+        or after an infinite loop. A run of consecutive unreachable \
+        statements is reported once. Function declarations and `var` \
+        declarations without an initializer are hoisted, so they are never \
+        reported, and they split the statements around them into separate \
+        runs.",
+      invalid_examples: &[
+        "function foo() {\n  return true;\n  console.log(\"done\");\n}",
+        "function bar() {\n  throw new Error(\"Oops!\");\n  console.log(\"done\");\n}",
+      ],
+      valid_examples: &[
+        "function foo() {\n  return bar();\n  function bar() {\n    return 1;\n  }\n}",
+      ],
+    }
+  }
+
   fn lint_program_with_ast_view<'view>(
     &self,
     context: &mut Context<'view>,
@@ -40,16 +62,61 @@ impl LintRule for NoUnreachable {
       ProgramRef::Module(m) => visitor.visit_module(m),
       ProgramRef::Script(s) => visitor.visit_script(s),
     }
+    let ranges = visitor.ranges;
+    for (range, count) in collapse(context, ranges) {
+      let message = if count == 1 {
+        MESSAGE
+      } else {
+        MESSAGE_MULTIPLE
+      };
+      context.add_diagnostic(range, CODE, message);
+    }
+  }
+}
+
+/// Merges the ranges of consecutive unreachable statements, so that each run
+/// is reported once, and drops the ones inside of another unreachable
+/// statement. Returns the merged ranges with the number of statements in
+/// them.
+fn collapse(
+  context: &Context,
+  mut ranges: Vec<SourceRange>,
+) -> Vec<(SourceRange, usize)> {
+  ranges.sort_by_key(|range| (range.start, std::cmp::Reverse(range.end)));
+  let mut collapsed: Vec<(SourceRange, usize)> = Vec::new();
+  for range in ranges {
+    if let Some((last, count)) = collapsed.last_mut() {
+      if range.end <= last.end {
+        continue;
+      }
+      // Statements are consecutive if only whitespace and comments are
+      // between them. Hoisted declarations in between aren't reported, so
+      // they end the run.
+      let is_consecutive = context
+        .previous_token(range.start)
+        .map_or(false, |token| token.end() <= last.end);
+      if is_consecutive {
+        *last = SourceRange::new(last.start, range.end);
+        *count += 1;
+        continue;
+      }
+    }
+    collapsed.push((range, 1));
   }
+  collapsed
 }
 
 struct NoUnreachableVisitor<'c, 'view> {
-  context: &'c mut Context<'view>,
+  context: &'c Context<'view>,
+  ranges: Vec<SourceRange>,
 }
 
 impl<'c, 'view> NoUnreachableVisitor<'c, 'view> {
-  fn new(context: &'c mut Context<'view>) -> Self {
-    Self { context }
+  fn new(context: &'c Context<'view>) -> Self {
+    Self {
+      context,
+      ranges: Vec::new(),
+    }
   }
 }
 
@@ -77,7 +144,7 @@ impl<'c, 'view> Visit for NoUnreachableVisitor<'c, 'view> {
 
     if let Some(meta) = self.context.control_flow().meta(stmt.start()) {
       if meta.unreachable {
-        self.context.add_diagnostic(stmt.range(), CODE, MESSAGE)
+        self.ranges.push(stmt.range());
       }
     }
   }
@@ -479,7 +546,7 @@ function foo(trueOrFalse: boolean) {
       b()
       // comment
       c();
-  }": [{ line: 3, col: 6, message: MESSAGE }, {line: 4, col: 6, message: MESSAGE }, { line: 6, col: 6, message: MESSAGE }],
+  }": [{ line: 3, col: 6, message: MESSAGE_MULTIPLE }],
         "function foo() {
       if (a) {
           return
@@ -489,7 +556,7 @@ function foo(trueOrFalse: boolean) {
           throw err
           d();
       }
-  }": [{ line: 4, col: 10, message: MESSAGE }, { line: 5, col: 10, message: MESSAGE }, { line: 8, col: 10, message: MESSAGE }],
+  }": [{ line: 4, col: 10, message: MESSAGE_MULTIPLE }, { line: 8, col: 10, message: MESSAGE }],
         "function foo() {
       if (a) {
           return
@@ -500,7 +567,7 @@ function foo(trueOrFalse: boolean) {
           d();
       }
       e();
-  }": [{ line: 4, col: 10, message: MESSAGE }, { line: 5, col: 10, message: MESSAGE }, { line: 8, col: 10, message: MESSAGE}, { line: 10, col: 6, message: MESSAGE }],
+  }": [{ line: 4, col: 10, message: MESSAGE_MULTIPLE }, { line: 8, col: 10, message: MESSAGE}, { line: 10, col: 6, message: MESSAGE }],
         "function* foo() {
       try {
           return;
@@ -523,6 +590,9 @@ function foo(trueOrFalse: boolean) {
           return err;
       }
   }": [{ line: 4, col: 10, message: MESSAGE }, { line: 6, col: 10, message: MESSAGE }],
+        "function foo() { return; if (a) { b(); } c(); }": [{ col: 25, message: MESSAGE_MULTIPLE }],
+        "function foo() { return; a(); function bar() {} b(); }": [{ col: 25, message: MESSAGE }, { col: 48, message: MESSAGE }],
+        "function foo() { return; var x; a(); b(); }": [{ col: 32, message: MESSAGE_MULTIPLE }],
       // https://github.com/denoland/deno_lint/issues/348
         r#"
 const obj = {
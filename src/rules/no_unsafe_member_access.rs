@@ -0,0 +1,258 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{program_ref, Context, LintRule, RuleDocs};
+use crate::handler::{Handler, Traverse};
+use crate::tags::Tags;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{
+  BindingIdent, Callee, Expr, Id, MemberProp, TsKeywordTypeKind, TsType,
+};
+use deno_ast::swc::visit::{noop_visit_type, Visit, VisitWith};
+use deno_ast::view as ast_view;
+use deno_ast::SourceRanged;
+use derive_more::Display;
+use std::collections::HashSet;
+
+#[derive(Debug)]
+pub struct NoUnsafeMemberAccess;
+
+const CODE: &str = "no-unsafe-member-access";
+const HINT: &str = "Give the value a more specific type, or use `unknown` and narrow it before using it";
+
+#[derive(Display)]
+enum NoUnsafeMemberAccessMessage {
+  #[display(fmt = "Unsafe member access `.{}` on an `any` value", _0)]
+  Member(String),
+  #[display(fmt = "Unsafe computed member access on an `any` value")]
+  ComputedMember,
+  #[display(fmt = "Unsafe call of an `any` value")]
+  Call,
+}
+
+impl LintRule for NoUnsafeMemberAccess {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows accessing members of and calling values typed \
+        as `any`, since the compiler can't check these uses. Without type \
+        information, this is a best-effort check: only variables and \
+        parameters annotated with `: any`, and expressions asserted with `as \
+        any` or `<any>` are known to be `any`. Each chain of accesses is \
+        reported once, at the first access.",
+      invalid_examples: &[
+        "function handle(event: any) {\n  return event.target;\n}",
+        "(window as any).myGlobal = 1;",
+      ],
+      valid_examples: &[
+        "function handle(event: Event) {\n  return event.target;\n}",
+        "function handle(event: unknown) {\n  if (event instanceof Event) {\n    return event.target;\n  }\n}",
+      ],
+    }
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    NoUnsafeMemberAccessHandler {
+      any_bindings: AnyBindings::collect(program_ref(program)),
+    }
+    .traverse(program, context);
+  }
+}
+
+struct NoUnsafeMemberAccessHandler {
+  any_bindings: HashSet<Id>,
+}
+
+impl NoUnsafeMemberAccessHandler {
+  /// Checks if the expression is known to be `any`, i.e. it is a binding
+  /// annotated with `any`, or an `any` assertion.
+  fn is_any(&self, expr: &Expr) -> bool {
+    match expr.unwrap_parens() {
+      Expr::Ident(ident) => self.any_bindings.contains(&ident.to_id()),
+      Expr::TsAs(ts_as) => is_any_type(&ts_as.type_ann),
+      Expr::TsTypeAssertion(assertion) => is_any_type(&assertion.type_ann),
+      Expr::TsNonNull(non_null) => self.is_any(&non_null.expr),
+      _ => false,
+    }
+  }
+}
+
+impl Handler for NoUnsafeMemberAccessHandler {
+  fn member_expr(
+    &mut self,
+    member_expr: &ast_view::MemberExpr,
+    ctx: &mut Context,
+  ) {
+    if !self.is_any(&member_expr.inner.obj) {
+      return;
+    }
+    let message = match &member_expr.inner.prop {
+      MemberProp::Ident(prop) => {
+        NoUnsafeMemberAccessMessage::Member(prop.sym.to_string())
+      }
+      MemberProp::PrivateName(name) => {
+        NoUnsafeMemberAccessMessage::Member(format!("#{}", name.name))
+      }
+      MemberProp::Computed(_) => NoUnsafeMemberAccessMessage::ComputedMember,
+    };
+    ctx.add_diagnostic_with_hint(member_expr.range(), CODE, message, HINT);
+  }
+
+  fn call_expr(&mut self, call_expr: &ast_view::CallExpr, ctx: &mut Context) {
+    let Callee::Expr(callee) = &call_expr.inner.callee else {
+      return;
+    };
+    if self.is_any(callee) {
+      ctx.add_diagnostic_with_hint(
+        call_expr.range(),
+        CODE,
+        NoUnsafeMemberAccessMessage::Call,
+        HINT,
+      );
+    }
+  }
+}
+
+fn is_any_type(ty: &TsType) -> bool {
+  match ty {
+    TsType::TsKeywordType(keyword) => {
+      keyword.kind == TsKeywordTypeKind::TsAnyKeyword
+    }
+    TsType::TsParenthesizedType(paren) => is_any_type(&paren.type_ann),
+    _ => false,
+  }
+}
+
+/// Collects the bindings that are annotated with `any`.
+#[derive(Default)]
+struct AnyBindings {
+  ids: HashSet<Id>,
+}
+
+impl AnyBindings {
+  fn collect(program: ProgramRef) -> HashSet<Id> {
+    let mut collector = Self::default();
+    match program {
+      ProgramRef::Module(m) => m.visit_with(&mut collector),
+      ProgramRef::Script(s) => s.visit_with(&mut collector),
+    }
+    collector.ids
+  }
+}
+
+impl Visit for AnyBindings {
+  noop_visit_type!();
+
+  fn visit_binding_ident(&mut self, binding: &BindingIdent) {
+    if binding
+      .type_ann
+      .as_ref()
+      .is_some_and(|type_ann| is_any_type(&type_ann.type_ann))
+    {
+      self.ids.insert(binding.id.to_id());
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_unsafe_member_access_valid() {
+    assert_lint_ok! {
+      NoUnsafeMemberAccess,
+      "function f(x: unknown) { if (typeof x === 'string') x.length; }",
+      "function f(x: string) { x.length; x.trim(); }",
+      "function f(x) { x.length; }",
+      "const x: any[] = []; x.length;",
+      "const x = y as unknown as Foo; x.bar;",
+      "function f(x: any) { return x; }",
+      "function f(x: any) { const g = (x: string) => x.length; }",
+      "let x: any; const y = x as Foo; y.bar;",
+      "const f: any = 1; f;",
+    };
+  }
+
+  #[test]
+  fn no_unsafe_member_access_invalid() {
+    assert_lint_err! {
+      NoUnsafeMemberAccess,
+      "function f(x: any) { x.length; }": [
+        {
+          col: 21,
+          message: NoUnsafeMemberAccessMessage::Member("length".to_string()),
+          hint: HINT,
+        }
+      ],
+      "let x: any;\nx.a.b.c;": [
+        {
+          line: 2,
+          col: 0,
+          message: NoUnsafeMemberAccessMessage::Member("a".to_string()),
+          hint: HINT,
+        }
+      ],
+      "(window as any).foo = 1;": [
+        {
+          col: 0,
+          message: NoUnsafeMemberAccessMessage::Member("foo".to_string()),
+          hint: HINT,
+        }
+      ],
+      "(<any>window)[key];": [
+        {
+          col: 0,
+          message: NoUnsafeMemberAccessMessage::ComputedMember,
+          hint: HINT,
+        }
+      ],
+      "const f = (x: (any)) => x?.y;": [
+        {
+          col: 24,
+          message: NoUnsafeMemberAccessMessage::Member("y".to_string()),
+          hint: HINT,
+        }
+      ],
+      "function f(callback: any) { callback(1); }": [
+        {
+          col: 28,
+          message: NoUnsafeMemberAccessMessage::Call,
+          hint: HINT,
+        }
+      ],
+      "(foo as any)();": [
+        {
+          col: 0,
+          message: NoUnsafeMemberAccessMessage::Call,
+          hint: HINT,
+        }
+      ],
+      "declare const x: any;\nx!.y;": [
+        {
+          line: 2,
+          col: 0,
+          message: NoUnsafeMemberAccessMessage::Member("y".to_string()),
+          hint: HINT,
+        }
+      ],
+      "class A { m(this: A, x: any) { x.y(); } }": [
+        {
+          col: 31,
+          message: NoUnsafeMemberAccessMessage::Member("y".to_string()),
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,304 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::{Handler, Traverse};
+use crate::js_regex::{byte_offset, EcmaRegexValidator, RegexBackreference};
+use crate::tags::Tags;
+use crate::Program;
+use deno_ast::view::{CallExpr, Callee, Expr, Lit, MemberProp, Regex};
+use deno_ast::{SourceRange, SourceRanged};
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoUnusedCapturingGroup;
+
+const CODE: &str = "no-unused-capturing-group";
+const HINT: &str =
+  "Use a non-capturing group `(?:...)`, since the captured text isn't used";
+const FIX_DESC: &str = "Use a non-capturing group";
+
+#[derive(Display)]
+enum NoUnusedCapturingGroupMessage {
+  #[display(fmt = "Capturing group {} is never used", _0)]
+  Unused(u32),
+  #[display(fmt = "Capturing group `{}` is never used", _0)]
+  UnusedNamed(String),
+}
+
+impl LintRule for NoUnusedCapturingGroup {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows capturing groups whose captured text is never \
+        used. Only regex literals whose captures can't be observed are \
+        checked: regexes passed to `search`, regexes whose `test` method is \
+        called, and regexes passed to `replace` or `replaceAll` with a \
+        string replacement. A group is used when it is referenced by a \
+        backreference like `\\1` or `\\k<name>` in the pattern, or by `$1` or \
+        `$<name>` in the replacement. Unused groups are fixed by making them \
+        non-capturing, unless a later group is referenced by its number.",
+      invalid_examples: &[
+        "/(\\d+)-\\d+/.test(value);",
+        "value.replace(/(a)(b)/, \"$2\");",
+      ],
+      valid_examples: &[
+        "/(?:\\d+)-\\d+/.test(value);",
+        "/(\\w)\\1/.test(value);",
+        "value.replace(/(a)(b)/, \"$2$1\");",
+        "const match = /(\\d+)/.exec(value);",
+      ],
+    }
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    NoUnusedCapturingGroupHandler.traverse(program, context);
+  }
+}
+
+struct NoUnusedCapturingGroupHandler;
+
+impl Handler for NoUnusedCapturingGroupHandler {
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    let Callee::Expr(Expr::Member(member)) = &call_expr.callee else {
+      return;
+    };
+    let MemberProp::Ident(prop) = &member.prop else {
+      return;
+    };
+    let regex_arg = || match call_expr.args.first().map(|arg| &arg.expr) {
+      Some(Expr::Lit(Lit::Regex(regex))) => Some(*regex),
+      _ => None,
+    };
+    let (regex, replacement) = match prop.sym().as_ref() {
+      "test" => match &member.obj {
+        Expr::Lit(Lit::Regex(regex)) => (*regex, None),
+        _ => return,
+      },
+      "search" => match regex_arg() {
+        Some(regex) => (regex, None),
+        None => return,
+      },
+      "replace" | "replaceAll" => {
+        match (regex_arg(), call_expr.args.get(1).map(|arg| &arg.expr)) {
+          (Some(regex), Some(Expr::Lit(Lit::Str(replacement)))) => {
+            (regex, Some(replacement.value().to_string()))
+          }
+          _ => return,
+        }
+      }
+      _ => return,
+    };
+    check_regex(regex, replacement.as_deref(), ctx);
+  }
+}
+
+fn check_regex(regex: &Regex, replacement: Option<&str>, ctx: &mut Context) {
+  let pattern: &str = &regex.inner.exp;
+  let u_flag = regex.inner.flags.contains(['u', 'v']);
+  let mut validator = EcmaRegexValidator::new(ctx.ecma_version());
+  // Invalid patterns are reported by `no-invalid-regexp`.
+  let Ok(parsed) = validator.parse(pattern, u_flag) else {
+    return;
+  };
+
+  let mut groups = parsed
+    .groups
+    .iter()
+    .filter_map(|group| Some((group, group.capturing.as_ref()?)))
+    .collect::<Vec<_>>();
+  groups.sort_by_key(|(_, (index, _))| *index);
+
+  let mut references = parsed.backreferences;
+  if let Some(replacement) = replacement {
+    references.extend(replacement_references(replacement, groups.len() as u32));
+  }
+  let is_referenced = |index: u32, name: Option<&String>| {
+    references.iter().any(|reference| match reference {
+      RegexBackreference::Index(i) => *i == index,
+      RegexBackreference::Name(n) => Some(n) == name,
+    })
+  };
+  // Making a group non-capturing changes the numbers of the groups after it.
+  let last_referenced_index = references
+    .iter()
+    .filter_map(|reference| match reference {
+      RegexBackreference::Index(i) => Some(*i),
+      RegexBackreference::Name(_) => None,
+    })
+    .max()
+    .unwrap_or(0);
+
+  // The pattern starts after the opening `/`.
+  let pattern_start = regex.range().start + 1;
+  for (group, (index, name)) in groups {
+    if is_referenced(*index, name.as_ref()) {
+      continue;
+    }
+    let start = pattern_start + byte_offset(pattern, group.start, u_flag);
+    let end = pattern_start + byte_offset(pattern, group.end, u_flag);
+    let content_start =
+      pattern_start + byte_offset(pattern, group.content_start, u_flag);
+    let fixes = if *index > last_referenced_index {
+      vec![LintFix {
+        description: FIX_DESC.into(),
+        changes: vec![LintFixChange {
+          new_text: "(?:".into(),
+          range: SourceRange::new(start, content_start),
+        }],
+      }]
+    } else {
+      vec![]
+    };
+    let message = match name {
+      Some(name) => NoUnusedCapturingGroupMessage::UnusedNamed(name.clone()),
+      None => NoUnusedCapturingGroupMessage::Unused(*index),
+    };
+    ctx.add_diagnostic_with_fixes(
+      SourceRange::new(start, end),
+      CODE,
+      message,
+      Some(HINT.to_string()),
+      fixes,
+    );
+  }
+}
+
+/// Returns the groups referenced by a replacement string of `replace`, like
+/// `$1` or `$<name>`, given the number of groups of the pattern.
+fn replacement_references(
+  replacement: &str,
+  num_groups: u32,
+) -> Vec<RegexBackreference> {
+  let mut references = Vec::new();
+  let mut rest = replacement;
+  while let Some(position) = rest.find('$') {
+    rest = &rest[position + 1..];
+    if let Some(after) = rest.strip_prefix('$') {
+      rest = after;
+    } else if let Some(after) = rest.strip_prefix('<') {
+      if let Some(end) = after.find('>') {
+        references.push(RegexBackreference::Name(after[..end].to_string()));
+        rest = &after[end + 1..];
+      }
+    } else {
+      let digits = rest
+        .chars()
+        .take(2)
+        .take_while(|c| c.is_ascii_digit())
+        .collect::<String>();
+      // `$12` refers to group 12 if it exists, and to group 1 otherwise.
+      let two_digits = digits.parse::<u32>().ok().filter(|_| digits.len() == 2);
+      let one_digit = digits.get(..1).and_then(|d| d.parse::<u32>().ok());
+      if let Some(index) = two_digits.filter(|i| (1..=num_groups).contains(i)) {
+        references.push(RegexBackreference::Index(index));
+        rest = &rest[2..];
+      } else if let Some(index) =
+        one_digit.filter(|i| (1..=num_groups).contains(i))
+      {
+        references.push(RegexBackreference::Index(index));
+        rest = &rest[1..];
+      }
+    }
+  }
+  references
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_unused_capturing_group_valid() {
+    assert_lint_ok! {
+      NoUnusedCapturingGroup,
+      r"/(a)\1/.test(value);",
+      r"/(?<char>a)\k<char>/.test(value);",
+      r"/(?:a)b/.test(value);",
+      r"value.search(/(?:a)/);",
+      r#"value.replace(/(a)(b)/, "$2$1");"#,
+      r#"value.replace(/(?<first>a)/, "$<first>");"#,
+      r#"value.replaceAll(/(a)/g, "[$1]");"#,
+      r"value.replace(/(a)/, (match, a) => a);",
+      r"value.replace(/(a)/, replacement);",
+      r"/(a)/.exec(value);",
+      r"value.match(/(a)/);",
+      r"const regex = /(a)/;",
+      r"/(a/.test(value);",
+    };
+  }
+
+  #[test]
+  fn no_unused_capturing_group_invalid() {
+    assert_lint_err! {
+      NoUnusedCapturingGroup,
+      r"/(\d+)-\d+/.test(value);": [
+        {
+          col: 1,
+          message: NoUnusedCapturingGroupMessage::Unused(1),
+          hint: HINT,
+          fix: (FIX_DESC, r"/(?:\d+)-\d+/.test(value);"),
+        }
+      ],
+      r"value.search(/a(?<b>b)/u);": [
+        {
+          col: 15,
+          message: NoUnusedCapturingGroupMessage::UnusedNamed("b".to_string()),
+          hint: HINT,
+          fix: (FIX_DESC, r"value.search(/a(?:b)/u);"),
+        }
+      ],
+      r#"value.replace(/(a)(b)/, "$2");"#: [
+        {
+          col: 15,
+          message: NoUnusedCapturingGroupMessage::Unused(1),
+          hint: HINT,
+        }
+      ],
+      r#"value.replace(/(a)(b)/, "$1$$2");"#: [
+        {
+          col: 18,
+          message: NoUnusedCapturingGroupMessage::Unused(2),
+          hint: HINT,
+          fix: (FIX_DESC, r#"value.replace(/(a)(?:b)/, "$1$$2");"#),
+        }
+      ],
+      r"/((a)|b)\2/.test(value);": [
+        {
+          col: 1,
+          message: NoUnusedCapturingGroupMessage::Unused(1),
+          hint: HINT,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn replacement_references_test() {
+    use RegexBackreference::*;
+    assert_eq!(replacement_references("$1", 1), vec![Index(1)]);
+    assert_eq!(replacement_references("$12", 1), vec![Index(1)]);
+    assert_eq!(replacement_references("$12", 12), vec![Index(12)]);
+    assert_eq!(replacement_references("$0$$1", 2), vec![]);
+    assert_eq!(
+      replacement_references("$<a>-$2", 2),
+      vec![Name("a".to_string()), Index(2)]
+    );
+    assert_eq!(replacement_references("$&$`$'", 1), vec![]);
+  }
+}
@@ -1,10 +1,12 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
 use crate::handler::{Handler, Traverse};
 use crate::tags::{self, Tags};
 use crate::Program;
-use deno_ast::{view as ast_view, SourceRanged};
+use deno_ast::swc::ast::{Expr, Lit};
+use deno_ast::{view as ast_view, SourceRange, SourceRanged};
 use derive_more::Display;
 use if_chain::if_chain;
 
@@ -12,6 +14,7 @@ use if_chain::if_chain;
 pub struct NoUnusedLabels;
 
 const CODE: &str = "no-unused-labels";
+const FIX_DESC: &str = "Remove the unused label";
 
 #[derive(Display)]
 enum NoUnusedLabelsMessage {
@@ -28,6 +31,10 @@ impl LintRule for NoUnusedLabels {
     CODE
   }
 
+  fn fixable(&self) -> bool {
+    true
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
@@ -96,16 +103,44 @@ impl Handler for NoUnusedLabelsHandler {
       if let Some(label) = self.labels.pop();
       if !label.used;
       then {
-        ctx.add_diagnostic(
+        let fixes = removal_fix(labeled_stmt, ctx).into_iter().collect();
+        ctx.add_diagnostic_with_fixes(
           labeled_stmt.range(),
           CODE,
           NoUnusedLabelsMessage::Unused(label.name),
+          None,
+          fixes,
         );
       }
     }
   }
 }
 
+/// Removes the label and the colon in front of the statement. There's no fix
+/// if that would drop comments, or if the statement is a string literal that
+/// would turn into a directive like `"use strict"`.
+fn removal_fix(
+  labeled_stmt: &ast_view::LabeledStmt,
+  ctx: &Context,
+) -> Option<LintFix> {
+  let range = SourceRange::new(labeled_stmt.start(), labeled_stmt.body.start());
+  if ctx.has_comments_in_range(range) {
+    return None;
+  }
+  if let ast_view::Stmt::Expr(expr_stmt) = labeled_stmt.body {
+    if matches!(&*expr_stmt.inner.expr, Expr::Lit(Lit::Str(_))) {
+      return None;
+    }
+  }
+  Some(LintFix {
+    description: FIX_DESC.into(),
+    changes: vec![LintFixChange {
+      new_text: "".into(),
+      range,
+    }],
+  })
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -131,27 +166,44 @@ mod tests {
         {
           col: 0,
           message: variant!(NoUnusedLabelsMessage, Unused, "LABEL"),
+          fix: (FIX_DESC, "var a = 0;"),
         }
       ],
       "LABEL: if (something) { a(); }": [
         {
           col: 0,
           message: variant!(NoUnusedLabelsMessage, Unused, "LABEL"),
+          fix: (FIX_DESC, "if (something) { a(); }"),
         }
       ],
       "LABEL: for (let i = 0; i < 5; i++) { a(); b(); }": [
         {
           col: 0,
           message: variant!(NoUnusedLabelsMessage, Unused, "LABEL"),
+          fix: (FIX_DESC, "for (let i = 0; i < 5; i++) { a(); b(); }"),
         }
       ],
       "A: for (var i = 0; i < 10; ++i) { B: break A; }": [
         {
           col: 34,
           message: variant!(NoUnusedLabelsMessage, Unused, "B"),
+          fix: (FIX_DESC, "A: for (var i = 0; i < 10; ++i) { break A; }"),
         }
       ],
       "A: { let A = 0; console.log(A); }": [
+        {
+          col: 0,
+          message: variant!(NoUnusedLabelsMessage, Unused, "A"),
+          fix: (FIX_DESC, "{ let A = 0; console.log(A); }"),
+        }
+      ],
+      "A /* comment */: foo();": [
+        {
+          col: 0,
+          message: variant!(NoUnusedLabelsMessage, Unused, "A"),
+        }
+      ],
+      "A: 'use strict';": [
         {
           col: 0,
           message: variant!(NoUnusedLabelsMessage, Unused, "A"),
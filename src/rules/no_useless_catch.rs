@@ -0,0 +1,312 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::{Handler, Traverse};
+use crate::tags::Tags;
+use crate::Program;
+use deno_ast::swc::ast::{
+  BlockStmt, CatchClause, Decl, Expr, Pat, Stmt, VarDeclKind,
+};
+use deno_ast::view::{self as ast_view, Node};
+use deno_ast::{SourceRange, SourceRanged, SourceRangedForSpanned};
+
+#[derive(Debug)]
+pub struct NoUselessCatch;
+
+const CODE: &str = "no-useless-catch";
+
+enum UselessCatchKind {
+  /// The `try` statement has no `finally` block, so it can be replaced by
+  /// the statements of the `try` block.
+  Wrapper,
+  /// The `catch` clause can be removed from the `try`/`finally` statement.
+  Clause,
+}
+
+impl UselessCatchKind {
+  fn message(&self) -> &'static str {
+    match self {
+      UselessCatchKind::Wrapper => "Unnecessary try/catch wrapper",
+      UselessCatchKind::Clause => "Unnecessary catch clause",
+    }
+  }
+
+  fn hint(&self) -> &'static str {
+    match self {
+      UselessCatchKind::Wrapper => {
+        "Remove the try/catch, since the catch clause only rethrows the error"
+      }
+      UselessCatchKind::Clause => {
+        "Remove the catch clause, since it only rethrows the error"
+      }
+    }
+  }
+
+  fn fix_description(&self) -> &'static str {
+    match self {
+      UselessCatchKind::Wrapper => "Remove the try/catch wrapper",
+      UselessCatchKind::Clause => "Remove the catch clause",
+    }
+  }
+}
+
+impl LintRule for NoUselessCatch {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows `catch` clauses that only rethrow the caught \
+        error, which behaves the same as not catching it. Without a \
+        `finally` block, the whole `try` statement can be replaced by the \
+        statements of the `try` block, otherwise only the `catch` clause \
+        can be removed.",
+      invalid_examples: &[
+        "try {\n  doSomething();\n} catch (e) {\n  throw e;\n}",
+        "try {\n  doSomething();\n} catch (e) {\n  throw e;\n} finally {\n  cleanUp();\n}",
+      ],
+      valid_examples: &[
+        "try {\n  doSomething();\n} catch (e) {\n  console.error(e);\n  throw e;\n}",
+        "try {\n  doSomething();\n} finally {\n  cleanUp();\n}",
+      ],
+    }
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    NoUselessCatchHandler.traverse(program, context);
+  }
+}
+
+struct NoUselessCatchHandler;
+
+impl Handler for NoUselessCatchHandler {
+  fn try_stmt(&mut self, try_stmt: &ast_view::TryStmt, ctx: &mut Context) {
+    let Some(handler) = &try_stmt.inner.handler else {
+      return;
+    };
+    if !only_rethrows(handler) {
+      return;
+    }
+
+    let (kind, range, fix_change) = match &try_stmt.inner.finalizer {
+      None => (
+        UselessCatchKind::Wrapper,
+        try_stmt.range(),
+        unwrap_change(try_stmt, ctx),
+      ),
+      Some(_) => {
+        let removed =
+          SourceRange::new(try_stmt.inner.block.end(), handler.end());
+        let has_comments = ctx.has_comments_in_range(removed);
+        (
+          UselessCatchKind::Clause,
+          handler.range(),
+          (!has_comments).then(|| LintFixChange {
+            new_text: "".into(),
+            range: removed,
+          }),
+        )
+      }
+    };
+    let fixes = fix_change
+      .map(|change| LintFix {
+        description: kind.fix_description().into(),
+        changes: vec![change],
+      })
+      .into_iter()
+      .collect();
+    ctx.add_diagnostic_with_fixes(
+      range,
+      CODE,
+      kind.message(),
+      Some(kind.hint().to_string()),
+      fixes,
+    );
+  }
+}
+
+/// Checks if the catch clause consists of `throw e;` only, where `e` is the
+/// caught error.
+fn only_rethrows(handler: &CatchClause) -> bool {
+  let Some(Pat::Ident(param)) = &handler.param else {
+    return false;
+  };
+  let [Stmt::Throw(throw_stmt)] = handler.body.stmts.as_slice() else {
+    return false;
+  };
+  matches!(
+    throw_stmt.arg.unwrap_parens(),
+    Expr::Ident(ident) if ident.to_id() == param.id.to_id()
+  )
+}
+
+/// Replaces the `try` statement with the statements of its `try` block. The
+/// block is kept if its declarations would otherwise leak into the enclosing
+/// scope, or if the `try` statement isn't in a list of statements.
+fn unwrap_change(
+  try_stmt: &ast_view::TryStmt,
+  ctx: &Context,
+) -> Option<LintFixChange> {
+  let block = &try_stmt.inner.block;
+  let (Some(first), Some(last)) = (block.stmts.first(), block.stmts.last())
+  else {
+    return Some(LintFixChange {
+      new_text: "".into(),
+      range: try_stmt.range(),
+    });
+  };
+  let statements = SourceRange::new(first.start(), last.end());
+  let text_info = ctx.text_info();
+  let is_in_list = matches!(
+    try_stmt.parent(),
+    Node::BlockStmt(_)
+      | Node::SwitchCase(_)
+      | Node::Module(_)
+      | Node::Script(_)
+  );
+  let new_text = if is_in_list && !has_block_scoped_decls(block) {
+    // Comments outside of the statements would be lost.
+    if ctx
+      .comment_lookups()
+      .has_comment_matching(try_stmt.range(), |comment| {
+        !statements.contains(&comment.range())
+      })
+    {
+      return None;
+    }
+    statements.text_fast(text_info)
+  } else {
+    if ctx
+      .comment_lookups()
+      .has_comment_matching(try_stmt.range(), |comment| {
+        !block.range().contains(&comment.range())
+      })
+    {
+      return None;
+    }
+    block.range().text_fast(text_info)
+  };
+  Some(LintFixChange {
+    new_text: new_text.to_string().into(),
+    range: try_stmt.range(),
+  })
+}
+
+fn has_block_scoped_decls(block: &BlockStmt) -> bool {
+  block.stmts.iter().any(|stmt| match stmt {
+    Stmt::Decl(Decl::Var(var_decl)) => var_decl.kind != VarDeclKind::Var,
+    Stmt::Decl(Decl::TsInterface(_) | Decl::TsTypeAlias(_)) => false,
+    Stmt::Decl(_) => true,
+    _ => false,
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v9.0.0/tests/lib/rules/no-useless-catch.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_useless_catch_valid() {
+    assert_lint_ok! {
+      NoUselessCatch,
+      "try { foo(); } catch (err) { console.error(err); }",
+      "try { foo(); } catch (err) { console.error(err); throw err; }",
+      "try { foo(); } catch (err) { throw bar; }",
+      "try { foo(); } catch (err) { throw new Error(err); }",
+      "try { foo(); } catch { throw err; }",
+      "try { foo(); } catch ({ message }) { throw message; }",
+      "try { foo(); } catch (err) { } finally { bar(); }",
+      "try { foo(); } finally { bar(); }",
+      "try { foo(); } catch (err) { { const err = 1; } throw err; }",
+    };
+  }
+
+  #[test]
+  fn no_useless_catch_invalid() {
+    assert_lint_err! {
+      NoUselessCatch,
+      "try {\n  foo();\n  bar();\n} catch (err) {\n  throw err;\n}": [
+        {
+          line: 1,
+          col: 0,
+          message: "Unnecessary try/catch wrapper",
+          hint: "Remove the try/catch, since the catch clause only rethrows the error",
+          fix: ("Remove the try/catch wrapper", "foo();\n  bar();"),
+        }
+      ],
+      "function f() { try { return foo(); } catch (err) { throw (err); } }": [
+        {
+          col: 15,
+          message: "Unnecessary try/catch wrapper",
+          hint: "Remove the try/catch, since the catch clause only rethrows the error",
+          fix: ("Remove the try/catch wrapper", "function f() { return foo(); }"),
+        }
+      ],
+      "try { const a = foo(); bar(a); } catch (err) { throw err; }": [
+        {
+          col: 0,
+          message: "Unnecessary try/catch wrapper",
+          hint: "Remove the try/catch, since the catch clause only rethrows the error",
+          fix: ("Remove the try/catch wrapper", "{ const a = foo(); bar(a); }"),
+        }
+      ],
+      "if (x) try { foo(); } catch (err) { throw err; }": [
+        {
+          col: 7,
+          message: "Unnecessary try/catch wrapper",
+          hint: "Remove the try/catch, since the catch clause only rethrows the error",
+          fix: ("Remove the try/catch wrapper", "if (x) { foo(); }"),
+        }
+      ],
+      "try {} catch (err) { throw err; }": [
+        {
+          col: 0,
+          message: "Unnecessary try/catch wrapper",
+          hint: "Remove the try/catch, since the catch clause only rethrows the error",
+          fix: ("Remove the try/catch wrapper", ""),
+        }
+      ],
+      "try {\n  // may throw\n  foo();\n} catch (err) {\n  throw err;\n}": [
+        {
+          line: 1,
+          col: 0,
+          message: "Unnecessary try/catch wrapper",
+          hint: "Remove the try/catch, since the catch clause only rethrows the error",
+        }
+      ],
+      "try { foo(); } catch (err) { throw err; } finally { bar(); }": [
+        {
+          col: 15,
+          message: "Unnecessary catch clause",
+          hint: "Remove the catch clause, since it only rethrows the error",
+          fix: ("Remove the catch clause", "try { foo(); } finally { bar(); }"),
+        }
+      ],
+      "try { foo(); } catch (err) { throw err; /* rethrow */ } finally { bar(); }": [
+        {
+          col: 15,
+          message: "Unnecessary catch clause",
+          hint: "Remove the catch clause, since it only rethrows the error",
+        }
+      ],
+    };
+  }
+}
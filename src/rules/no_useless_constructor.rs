@@ -0,0 +1,270 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::removal_range;
+use crate::tags::{self, Tags};
+use crate::Program;
+use deno_ast::swc::ast::{
+  Accessibility, CallExpr, Callee, ClassMember, Constructor, Expr,
+  ExprOrSpread, ParamOrTsParamProp, Pat, Stmt,
+};
+use deno_ast::view as ast_view;
+use deno_ast::SourceRangedForSpanned;
+
+#[derive(Debug)]
+pub struct NoUselessConstructor;
+
+const CODE: &str = "no-useless-constructor";
+const MESSAGE: &str = "Useless constructor";
+const HINT: &str =
+  "Remove the constructor, the class gets the same constructor by default";
+const FIX_DESC: &str = "Remove the constructor";
+
+impl LintRule for NoUselessConstructor {
+  fn tags(&self) -> Tags {
+    &[tags::STYLISTIC]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows constructors that behave like the default \
+        constructor: empty constructors of classes without a superclass, \
+        and constructors of derived classes that only pass their parameters \
+        on to `super()`. Constructors with parameter properties, decorated \
+        parameters, or `private` or `protected` accessibility change how the \
+        class can be used, so they are allowed.",
+      invalid_examples: &[
+        "class A {\n  constructor() {}\n}",
+        "class B extends A {\n  constructor(a, b) {\n    super(a, b);\n  }\n}",
+      ],
+      valid_examples: &[
+        "class A {\n  constructor() {\n    init();\n  }\n}",
+        "class B extends A {\n  constructor(a) {\n    super(a, 1);\n  }\n}",
+        "class C {\n  constructor(private name: string) {}\n}",
+      ],
+    }
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    NoUselessConstructorHandler.traverse(program, context);
+  }
+}
+
+struct NoUselessConstructorHandler;
+
+impl Handler for NoUselessConstructorHandler {
+  fn class(&mut self, class: &ast_view::Class, ctx: &mut Context) {
+    let constructors = class
+      .inner
+      .body
+      .iter()
+      .filter_map(|member| match member {
+        ClassMember::Constructor(constructor) => Some(constructor),
+        _ => None,
+      })
+      .collect::<Vec<_>>();
+    // Removing the implementation of an overloaded constructor would leave
+    // the overload signatures behind.
+    let [constructor] = constructors.as_slice() else {
+      return;
+    };
+    if !is_useless(constructor, class.inner.super_class.is_some()) {
+      return;
+    }
+
+    let range = constructor.range();
+    let fixes = if ctx.has_comments_in_range(range) {
+      vec![]
+    } else {
+      vec![LintFix {
+        description: FIX_DESC.into(),
+        changes: vec![LintFixChange {
+          new_text: "".into(),
+          range: removal_range(range, ctx.text_info()),
+        }],
+      }]
+    };
+    ctx.add_diagnostic_with_fixes(
+      range,
+      CODE,
+      MESSAGE,
+      Some(HINT.to_string()),
+      fixes,
+    );
+  }
+}
+
+fn is_useless(constructor: &Constructor, is_derived: bool) -> bool {
+  if matches!(
+    constructor.accessibility,
+    Some(Accessibility::Private | Accessibility::Protected)
+  ) {
+    return false;
+  }
+  let Some(body) = &constructor.body else {
+    return false;
+  };
+  let mut params = Vec::new();
+  for param in &constructor.params {
+    match param {
+      ParamOrTsParamProp::Param(param) if param.decorators.is_empty() => {
+        params.push(&param.pat);
+      }
+      _ => return false,
+    }
+  }
+
+  if !is_derived {
+    return body.stmts.is_empty();
+  }
+  // The constructor of a derived class has to call `super()`.
+  match body.stmts.as_slice() {
+    [Stmt::Expr(expr_stmt)] => match &*expr_stmt.expr {
+      Expr::Call(CallExpr {
+        callee: Callee::Super(_),
+        args,
+        ..
+      }) => passes_params(&params, args),
+      _ => false,
+    },
+    _ => false,
+  }
+}
+
+/// Checks if the arguments of the `super()` call are the parameters of the
+/// constructor in the same order, or `...arguments`.
+fn passes_params(params: &[&Pat], args: &[ExprOrSpread]) -> bool {
+  if let [ExprOrSpread {
+    spread: Some(_),
+    expr,
+  }] = args
+  {
+    if matches!(&**expr, Expr::Ident(ident) if ident.sym == *"arguments") {
+      return true;
+    }
+  }
+  params.len() == args.len() && params.iter().zip(args).all(|(param, arg)| {
+    let Expr::Ident(arg_ident) = &*arg.expr else {
+      return false;
+    };
+    match (param, arg.spread.is_some()) {
+      (Pat::Ident(ident), false) => ident.id.sym == arg_ident.sym,
+      (Pat::Rest(rest), true) => {
+        matches!(&*rest.arg, Pat::Ident(ident) if ident.id.sym == arg_ident.sym)
+      }
+      _ => false,
+    }
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v9.0.0/tests/lib/rules/no-useless-constructor.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_useless_constructor_valid() {
+    assert_lint_ok! {
+      NoUselessConstructor,
+      "class A {}",
+      "class A { constructor() { doSomething(); } }",
+      "class A extends B { constructor() {} }",
+      "class A extends B { constructor() { super('foo'); } }",
+      "class A extends B { constructor(foo, bar) { super(foo, bar, 1); } }",
+      "class A extends B { constructor(foo, bar) { super(bar, foo); } }",
+      "class A extends B { constructor(foo, bar) { super(foo); } }",
+      "class A extends B { constructor() { super(); doSomething(); } }",
+      "class A extends B { constructor(...args) { super(args); } }",
+      "class A extends B { constructor(foo = 1) { super(foo); } }",
+      "class A extends B { constructor({ foo }) { super(foo); } }",
+      "class A { private constructor() {} }",
+      "class A { protected constructor() {} }",
+      "class A { constructor(private foo: string) {} }",
+      "class A { constructor(@Inject() foo: string) {} }",
+      "class A extends B { constructor(readonly foo: string) { super(foo); } }",
+      "declare class A { constructor(); }",
+      "class A { constructor(a: string); constructor(a: any) {} }",
+    };
+  }
+
+  #[test]
+  fn no_useless_constructor_invalid() {
+    assert_lint_err! {
+      NoUselessConstructor,
+      MESSAGE,
+      HINT,
+      "class A { constructor() {} }": [
+        {
+          col: 10,
+          fix: (FIX_DESC, "class A { }"),
+        }
+      ],
+      "class A { public constructor() {} }": [
+        {
+          col: 10,
+          fix: (FIX_DESC, "class A { }"),
+        }
+      ],
+      "class A extends B { constructor() { super(); } }": [
+        {
+          col: 20,
+          fix: (FIX_DESC, "class A extends B { }"),
+        }
+      ],
+      "class A extends B { constructor(foo) { super(foo); } }": [
+        {
+          col: 20,
+          fix: (FIX_DESC, "class A extends B { }"),
+        }
+      ],
+      "class A extends B { constructor(foo: string, bar: number) { super(foo, bar); } }": [
+        {
+          col: 20,
+          fix: (FIX_DESC, "class A extends B { }"),
+        }
+      ],
+      "class A extends B { constructor(...args) { super(...args); } }": [
+        {
+          col: 20,
+          fix: (FIX_DESC, "class A extends B { }"),
+        }
+      ],
+      "class A extends B { constructor(a, b) { super(...arguments); } }": [
+        {
+          col: 20,
+          fix: (FIX_DESC, "class A extends B { }"),
+        }
+      ],
+      "class A {\n  foo() {}\n\n  constructor() {}\n}": [
+        {
+          line: 4,
+          col: 2,
+          fix: (FIX_DESC, "class A {\n  foo() {}\n\n}"),
+        }
+      ],
+      "class A {\n  constructor() {\n    // TODO\n  }\n}": [
+        {
+          line: 2,
+          col: 2,
+        }
+      ],
+    };
+  }
+}
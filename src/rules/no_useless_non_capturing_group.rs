@@ -0,0 +1,296 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::{Handler, Traverse};
+use crate::js_regex::{
+  byte_offset, EcmaRegexValidator, EcmaVersion, RegexGroup,
+};
+use crate::swc_util::{extract_regex, extract_regex_flags};
+use crate::tags::Tags;
+use crate::Program;
+use deno_ast::view::{CallExpr, Callee, Expr, NewExpr, Regex};
+use deno_ast::{SourceRange, SourceRanged};
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoUselessNonCapturingGroup;
+
+const CODE: &str = "no-useless-non-capturing-group";
+const HINT: &str =
+  "Remove the group, since it doesn't change what the pattern matches";
+const FIX_DESC: &str = "Remove the group";
+
+#[derive(Display)]
+enum NoUselessNonCapturingGroupMessage {
+  #[display(fmt = "Unnecessary non-capturing group `{}`", _0)]
+  Unnecessary(String),
+}
+
+impl LintRule for NoUselessNonCapturingGroup {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows non-capturing groups that wrap a single atom, \
+        like `(?:a)` or `(?:[abc])+`. Without an alternation inside the \
+        group, and without quantifiers on both the group and the atom, the \
+        group doesn't change what the pattern matches and only makes it \
+        harder to read. Groups in regex literals are fixed by removing the \
+        group.",
+      invalid_examples: &["/(?:a)b/;", "/(?:[abc])+/;", "/(?:\\d+)-/;"],
+      valid_examples: &["/(?:ab)+/;", "/(?:a|b)c/;", "/(?:a+)?/;", "/(a)/;"],
+    }
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    NoUselessNonCapturingGroupHandler.traverse(program, context);
+  }
+}
+
+struct NoUselessNonCapturingGroupHandler;
+
+impl Handler for NoUselessNonCapturingGroupHandler {
+  fn regex(&mut self, regex: &Regex, ctx: &mut Context) {
+    let pattern: &str = &regex.inner.exp;
+    let u_flag = regex.inner.flags.contains(['u', 'v']);
+    // The pattern starts after the opening `/`.
+    let pattern_start = regex.range().start + 1;
+    for group in useless_groups(pattern, u_flag, ctx.ecma_version()) {
+      let start = byte_offset(pattern, group.start, u_flag);
+      let content_start = byte_offset(pattern, group.content_start, u_flag);
+      let content_end = byte_offset(pattern, group.end - 1, u_flag);
+      let end = byte_offset(pattern, group.end, u_flag);
+      let content = &pattern[content_start..content_end];
+
+      let fixes = if can_unwrap(&pattern[..start], content, &pattern[end..]) {
+        vec![LintFix {
+          description: FIX_DESC.into(),
+          changes: vec![LintFixChange {
+            new_text: content.to_string().into(),
+            range: SourceRange::new(pattern_start + start, pattern_start + end),
+          }],
+        }]
+      } else {
+        vec![]
+      };
+      ctx.add_diagnostic_with_fixes(
+        SourceRange::new(pattern_start + start, pattern_start + end),
+        CODE,
+        NoUselessNonCapturingGroupMessage::Unnecessary(
+          pattern[start..end].to_string(),
+        ),
+        Some(HINT.to_string()),
+        fixes,
+      );
+    }
+  }
+
+  fn new_expr(&mut self, new_expr: &NewExpr, ctx: &mut Context) {
+    if let Expr::Ident(ident) = new_expr.callee {
+      if let Some(args) = &new_expr.args {
+        if let Some(regex) = extract_regex(ctx.scope(), ident, args) {
+          let flags = extract_regex_flags(args);
+          report(&regex, flags.as_deref(), new_expr.range(), ctx);
+        }
+      }
+    }
+  }
+
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    if let Callee::Expr(Expr::Ident(ident)) = &call_expr.callee {
+      if let Some(regex) = extract_regex(ctx.scope(), ident, call_expr.args) {
+        let flags = extract_regex_flags(call_expr.args);
+        report(&regex, flags.as_deref(), call_expr.range(), ctx);
+      }
+    }
+  }
+}
+
+/// Reports the useless groups of a pattern passed to `RegExp`. The source of
+/// the string may contain escapes, so no fixes are offered.
+fn report(
+  pattern: &str,
+  flags: Option<&str>,
+  range: SourceRange,
+  ctx: &mut Context,
+) {
+  let u_flag = flags.is_some_and(|flags| flags.contains(['u', 'v']));
+  for group in useless_groups(pattern, u_flag, ctx.ecma_version()) {
+    let start = byte_offset(pattern, group.start, u_flag);
+    let end = byte_offset(pattern, group.end, u_flag);
+    ctx.add_diagnostic_with_hint(
+      range,
+      CODE,
+      NoUselessNonCapturingGroupMessage::Unnecessary(
+        pattern[start..end].to_string(),
+      ),
+      HINT,
+    );
+  }
+}
+
+fn useless_groups(
+  pattern: &str,
+  u_flag: bool,
+  ecma_version: EcmaVersion,
+) -> Vec<RegexGroup> {
+  let mut validator = EcmaRegexValidator::new(ecma_version);
+  // Invalid patterns are reported by `no-invalid-regexp`.
+  let Ok(parsed) = validator.parse(pattern, u_flag) else {
+    return vec![];
+  };
+  let mut groups = parsed
+    .groups
+    .into_iter()
+    .filter(is_useless)
+    .collect::<Vec<_>>();
+  groups.sort_by_key(|group| group.start);
+  groups
+}
+
+/// Checks if the group is a non-capturing group containing a single atom,
+/// where only one of the group and the atom is quantified.
+fn is_useless(group: &RegexGroup) -> bool {
+  if group.capturing.is_some() {
+    return false;
+  }
+  let [terms] = group.alternatives.as_slice() else {
+    return false;
+  };
+  let [term] = terms.as_slice() else {
+    return false;
+  };
+  !(term.assertion || term.quantified && group.quantified)
+}
+
+/// Checks if the content of a group can replace the group without merging
+/// with the text around it, e.g. `\1(?:0)` can't become `\10`.
+fn can_unwrap(before: &str, content: &str, after: &str) -> bool {
+  let starts_with_digit = content.starts_with(|c: char| c.is_ascii_digit());
+  let ends_with_digit = content.ends_with(|c: char| c.is_ascii_digit());
+  !(before.ends_with('\\')
+    || before.ends_with("\\c")
+    || (!before.is_empty() && (starts_with_digit || content.starts_with('{')))
+    || (ends_with_digit
+      && after.starts_with(|c: char| c.is_ascii_digit() || c == '{')))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn message(group: &str) -> String {
+    NoUselessNonCapturingGroupMessage::Unnecessary(group.to_string())
+      .to_string()
+  }
+
+  #[test]
+  fn no_useless_non_capturing_group_valid() {
+    assert_lint_ok! {
+      NoUselessNonCapturingGroup,
+      r"/(?:ab)/",
+      r"/(?:ab)+/",
+      r"/(?:a|b)c/",
+      r"/(?:a+)?/",
+      r"/(?:a+){2}/",
+      r"/(?:)/",
+      r"/(?:^)a/",
+      r"/(a)/",
+      r"/(?<name>a)/",
+      r"/(?=a)/",
+      r"new RegExp('(?:a|b)')",
+      r"new RegExp('(?:a')",
+    };
+  }
+
+  #[test]
+  fn no_useless_non_capturing_group_invalid() {
+    assert_lint_err! {
+      NoUselessNonCapturingGroup,
+      r"/(?:a)b/": [
+        {
+          col: 1,
+          message: message("(?:a)"),
+          hint: HINT,
+          fix: (FIX_DESC, r"/ab/"),
+        }
+      ],
+      r"/(?:[abc])+/u": [
+        {
+          col: 1,
+          message: message("(?:[abc])"),
+          hint: HINT,
+          fix: (FIX_DESC, r"/[abc]+/u"),
+        }
+      ],
+      r"/x(?:\d+)-/": [
+        {
+          col: 2,
+          message: message(r"(?:\d+)"),
+          hint: HINT,
+          fix: (FIX_DESC, r"/x\d+-/"),
+        }
+      ],
+      r"/(?:a)?/": [
+        {
+          col: 1,
+          message: message("(?:a)"),
+          hint: HINT,
+          fix: (FIX_DESC, r"/a?/"),
+        }
+      ],
+      r"/(?:(?:a|b))c/": [
+        {
+          col: 1,
+          message: message("(?:(?:a|b))"),
+          hint: HINT,
+          fix: (FIX_DESC, r"/(?:a|b)c/"),
+        }
+      ],
+      "const r = /(?:ü)é(?:a)/;": [
+        {
+          col: 11,
+          message: message("(?:ü)"),
+          hint: HINT,
+          fix: (FIX_DESC, "const r = /üé(?:a)/;"),
+        },
+        {
+          col: 17,
+          message: message("(?:a)"),
+          hint: HINT,
+          fix: (FIX_DESC, "const r = /(?:ü)éa/;"),
+        }
+      ],
+      // Removing the group would create the escape `\10`.
+      r"/(a)\1(?:0)/": [
+        {
+          col: 6,
+          message: message("(?:0)"),
+          hint: HINT,
+        }
+      ],
+      r"new RegExp('(?:a)b')": [
+        {
+          col: 0,
+          message: message("(?:a)"),
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,301 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::removal_range;
+use crate::tags::Tags;
+use crate::Program;
+use deno_ast::swc::ast::Stmt;
+use deno_ast::view::{self as ast_view, Node, NodeTrait};
+use deno_ast::{SourceRanged, SourceRangedForSpanned};
+
+#[derive(Debug)]
+pub struct NoUselessReturn;
+
+const CODE: &str = "no-useless-return";
+const MESSAGE: &str = "Unnecessary return statement";
+const HINT: &str = "Remove the return statement, the function ends here anyway";
+const FIX_DESC: &str = "Remove the return statement";
+
+impl LintRule for NoUselessReturn {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows `return;` statements that are the last \
+        reachable statement of a function, since the function returns \
+        `undefined` at that point anyway. This includes returns at the end \
+        of `if` branches, of `try` and `catch` blocks without a `finally` \
+        block, and of the last `case` of a `switch` statement, as long as \
+        nothing runs after them.",
+      invalid_examples: &[
+        "function save(data) {\n  write(data);\n  return;\n}",
+        "function check(value) {\n  if (value) {\n    log(value);\n    return;\n  }\n}",
+      ],
+      valid_examples: &[
+        "function save(data) {\n  write(data);\n}",
+        "function check(value) {\n  if (!value) {\n    return;\n  }\n  log(value);\n}",
+      ],
+    }
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    NoUselessReturnHandler.traverse(program, context);
+  }
+}
+
+struct NoUselessReturnHandler;
+
+impl Handler for NoUselessReturnHandler {
+  fn return_stmt(
+    &mut self,
+    return_stmt: &ast_view::ReturnStmt,
+    ctx: &mut Context,
+  ) {
+    if return_stmt.arg.is_some() {
+      return;
+    }
+    let is_unreachable = ctx
+      .control_flow()
+      .meta(return_stmt.start())
+      .map_or(false, |meta| meta.unreachable);
+    if is_unreachable || !is_in_tail_position(return_stmt.as_node()) {
+      return;
+    }
+
+    // Removing a statement that is the body of e.g. an `if` leaves invalid
+    // code behind.
+    let is_in_list = matches!(
+      return_stmt.parent(),
+      Node::BlockStmt(_) | Node::SwitchCase(_)
+    );
+    let fixes = if is_in_list && !ctx.has_comments_in_range(return_stmt.range())
+    {
+      vec![LintFix {
+        description: FIX_DESC.into(),
+        changes: vec![LintFixChange {
+          new_text: "".into(),
+          range: removal_range(return_stmt.range(), ctx.text_info()),
+        }],
+      }]
+    } else {
+      vec![]
+    };
+    ctx.add_diagnostic_with_fixes(
+      return_stmt.range(),
+      CODE,
+      MESSAGE,
+      Some(HINT.to_string()),
+      fixes,
+    );
+  }
+}
+
+/// Checks if the function ends after the statement, without running any other
+/// code in between.
+fn is_in_tail_position(node: Node) -> bool {
+  let Some(parent) = node.parent() else {
+    return false;
+  };
+  let is_last = |stmts: &[Stmt]| {
+    stmts
+      .last()
+      .is_some_and(|last| last.range() == node.range())
+  };
+  match parent {
+    Node::BlockStmt(block) => {
+      is_last(&block.inner.stmts)
+        && match parent.parent() {
+          Some(
+            Node::Function(_)
+            | Node::ArrowExpr(_)
+            | Node::Constructor(_)
+            | Node::GetterProp(_)
+            | Node::SetterProp(_),
+          ) => true,
+          _ => is_in_tail_position(parent),
+        }
+    }
+    Node::IfStmt(_) | Node::LabeledStmt(_) => is_in_tail_position(parent),
+    // A `finally` block runs after the `try` block, and may throw.
+    Node::TryStmt(try_stmt) => {
+      try_stmt.inner.finalizer.is_none()
+        && try_stmt.inner.block.range() == node.range()
+        && is_in_tail_position(parent)
+    }
+    Node::CatchClause(_) => match parent.parent() {
+      Some(Node::TryStmt(try_stmt)) => {
+        try_stmt.inner.finalizer.is_none()
+          && is_in_tail_position(try_stmt.as_node())
+      }
+      _ => false,
+    },
+    Node::SwitchCase(case) => {
+      is_last(&case.inner.cons)
+        && match parent.parent() {
+          Some(Node::SwitchStmt(switch_stmt)) => {
+            switch_stmt
+              .inner
+              .cases
+              .last()
+              .is_some_and(|last| last.range() == case.range())
+              && is_in_tail_position(switch_stmt.as_node())
+          }
+          _ => false,
+        }
+    }
+    _ => false,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v9.0.0/tests/lib/rules/no-useless-return.js
+  // MIT Licensed.
+
+  #[test]
+  fn no_useless_return_valid() {
+    assert_lint_ok! {
+      NoUselessReturn,
+      "function foo() { return 5; }",
+      "function foo() { return null; }",
+      "function foo() { return doSomething(); }",
+      "function foo() { if (bar) { doSomething(); return; } else { doSomethingElse(); } qux(); }",
+      "function foo() { if (condition) { bar(); return; } baz(); }",
+      "function foo() { switch (bar) { case 1: doSomething(); return; default: doSomethingElse(); } }",
+      "function foo() { switch (bar) { default: doSomething(); return; case 1: doSomething(); } }",
+      "function foo() { for (const x of xs) { return; } }",
+      "function foo() { while (foo) { return; } }",
+      "function foo() { try { return; } finally { bar(); } }",
+      "function foo() { try { bar(); } finally { return; } }",
+      "function foo() { try { bar(); } catch (e) { return; } finally { baz(); } }",
+      "function foo() { return; doSomething(); }",
+      "function foo() { throw new Error(); return; }",
+      "() => { foo(); return bar; }",
+      "function foo() { function bar() {} return; bar(); }",
+    };
+  }
+
+  #[test]
+  fn no_useless_return_invalid() {
+    assert_lint_err! {
+      NoUselessReturn,
+      "function foo() {\n  bar();\n  return;\n}": [
+        {
+          line: 3,
+          col: 2,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "function foo() {\n  bar();\n}"),
+        }
+      ],
+      "function foo() { return; }": [
+        {
+          col: 17,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "function foo() { }"),
+        }
+      ],
+      "const foo = () => { bar(); return; };": [
+        {
+          col: 27,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "const foo = () => { bar(); };"),
+        }
+      ],
+      "class A { constructor() { this.a = 1; return; } method() { return; } }": [
+        {
+          col: 38,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "class A { constructor() { this.a = 1; } method() { return; } }"),
+        },
+        {
+          col: 59,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "class A { constructor() { this.a = 1; return; } method() { } }"),
+        }
+      ],
+      "function foo() { if (bar) { baz(); return; } else { qux(); return; } }": [
+        {
+          col: 35,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "function foo() { if (bar) { baz(); } else { qux(); return; } }"),
+        },
+        {
+          col: 59,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "function foo() { if (bar) { baz(); return; } else { qux(); } }"),
+        }
+      ],
+      "function foo() { if (bar) return; }": [
+        {
+          col: 26,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+      "function foo() { switch (bar) { case 1: baz(); break; default: qux(); return; } }": [
+        {
+          col: 70,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "function foo() { switch (bar) { case 1: baz(); break; default: qux(); } }"),
+        }
+      ],
+      "function foo() { try { bar(); return; } catch (e) { baz(); return; } }": [
+        {
+          col: 30,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "function foo() { try { bar(); } catch (e) { baz(); return; } }"),
+        },
+        {
+          col: 59,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "function foo() { try { bar(); return; } catch (e) { baz(); } }"),
+        }
+      ],
+      "function foo() { label: { bar(); return; } }": [
+        {
+          col: 33,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "function foo() { label: { bar(); } }"),
+        }
+      ],
+      "function foo() {\n  bar();\n  return /* done */;\n}": [
+        {
+          line: 3,
+          col: 2,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
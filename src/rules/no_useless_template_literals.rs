@@ -0,0 +1,319 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::{Handler, Traverse};
+use crate::linter::EcmaVersion;
+use crate::swc_util::{
+  ends_with_unescaped_dollar, string_to_template_text,
+  template_to_string_literal,
+};
+use crate::tags::{self, Tags};
+use crate::Program;
+use deno_ast::swc::ast::{Expr, Lit};
+use deno_ast::view::{self as ast_view, Node, NodeTrait};
+use deno_ast::{SourceRange, SourceRanged, SourceRangedForSpanned};
+
+#[derive(Debug)]
+pub struct NoUselessTemplateLiterals;
+
+const CODE: &str = "no-useless-template-literals";
+
+impl LintRule for NoUselessTemplateLiterals {
+  fn tags(&self) -> Tags {
+    &[tags::STYLISTIC]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows template literals that could be plain strings, \
+        i.e. ones without interpolations that fit on a single line, as well \
+        as interpolations of string literals like `${\"x\"}`, whose content \
+        can be written into the template directly. Tagged templates are \
+        never reported, since the tag function sees the difference.",
+      invalid_examples: &[
+        "const greeting = `Hello`;",
+        "const message = `Hello ${\"world\"}`;",
+      ],
+      valid_examples: &[
+        "const greeting = \"Hello\";",
+        "const message = `Hello ${name}`;",
+        "const lines = `first\nsecond`;",
+        "const path = String.raw`C:\\Users`;",
+      ],
+    }
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    NoUselessTemplateLiteralsHandler.traverse(program, context);
+  }
+}
+
+enum DiagnosticKind {
+  NoInterpolation,
+  StringInterpolation,
+}
+
+impl DiagnosticKind {
+  #[cfg(test)]
+  fn message_and_hint(&self) -> (&'static str, &'static str) {
+    (self.message(), self.hint())
+  }
+
+  fn message(&self) -> &'static str {
+    match *self {
+      DiagnosticKind::NoInterpolation => {
+        "Template literal has no interpolations"
+      }
+      DiagnosticKind::StringInterpolation => {
+        "Unnecessary interpolation of a string literal"
+      }
+    }
+  }
+
+  fn hint(&self) -> &'static str {
+    match *self {
+      DiagnosticKind::NoInterpolation => "Use a plain string literal instead",
+      DiagnosticKind::StringInterpolation => {
+        "Write the string into the template literal directly"
+      }
+    }
+  }
+
+  fn fix_desc(&self) -> &'static str {
+    match *self {
+      DiagnosticKind::NoInterpolation => "Convert to a string literal",
+      DiagnosticKind::StringInterpolation => "Inline the string",
+    }
+  }
+}
+
+struct NoUselessTemplateLiteralsHandler;
+
+impl Handler for NoUselessTemplateLiteralsHandler {
+  fn tpl(&mut self, tpl: &ast_view::Tpl, ctx: &mut Context) {
+    let parent = tpl.as_node().parent();
+    if let Some(Node::TaggedTpl(_)) = parent {
+      return;
+    }
+    let tpl = tpl.inner;
+
+    if let [quasi] = tpl.quasis.as_slice() {
+      if quasi.raw.contains(['\n', '\r']) {
+        return;
+      }
+      // A template literal in an expression statement would turn into a
+      // directive like "use strict" when converted.
+      let is_expr_stmt = matches!(parent, Some(Node::ExprStmt(_)));
+      let new_text = template_to_string_literal(&quasi.raw)
+        .filter(|_| !is_expr_stmt && !ctx.has_comments_in_range(tpl.range()));
+      report(DiagnosticKind::NoInterpolation, tpl.range(), new_text, ctx);
+      return;
+    }
+
+    for (i, expr) in tpl.exprs.iter().enumerate() {
+      let Expr::Lit(Lit::Str(str)) = &**expr else {
+        continue;
+      };
+      // From the end of the text before `${` to the start of the text after
+      // `}`.
+      let range =
+        SourceRange::new(tpl.quasis[i].end(), tpl.quasis[i + 1].start());
+      let raw = str.range().text_fast(ctx.text_info());
+      let text = string_to_template_text(&raw[1..raw.len() - 1]);
+      // Inlining `"$"` before `{`, or `"{"` after `$`, would form `${`.
+      let forms_interpolation = (ends_with_unescaped_dollar(&text)
+        && tpl.quasis[i + 1].raw.starts_with('{'))
+        || (text.starts_with('{')
+          && ends_with_unescaped_dollar(&tpl.quasis[i].raw));
+      // The fix keeps the template literal, which is only available from
+      // ES2015 on.
+      let new_text = Some(text).filter(|_| {
+        !forms_interpolation
+          && !ctx.has_comments_in_range(range)
+          && ctx.ecma_version() >= EcmaVersion::Es2015
+      });
+      report(DiagnosticKind::StringInterpolation, range, new_text, ctx);
+    }
+  }
+}
+
+fn report(
+  kind: DiagnosticKind,
+  range: SourceRange,
+  new_text: Option<String>,
+  ctx: &mut Context,
+) {
+  let fixes = new_text
+    .map(|new_text| LintFix {
+      description: kind.fix_desc().into(),
+      changes: vec![LintFixChange {
+        new_text: new_text.into(),
+        range,
+      }],
+    })
+    .into_iter()
+    .collect();
+  ctx.add_diagnostic_with_fixes(
+    range,
+    CODE,
+    kind.message(),
+    Some(kind.hint().to_string()),
+    fixes,
+  );
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_useless_template_literals_valid() {
+    assert_lint_ok! {
+      NoUselessTemplateLiterals,
+      "const a = 'a';",
+      "const a = `a${b}`;",
+      "const a = `a\nb`;",
+      "const a = tag`a`;",
+      "const a = String.raw`a${'b'}`;",
+      "const a = `a${1}`;",
+      "type A = `a`;",
+    };
+  }
+
+  #[test]
+  fn no_useless_template_literals_invalid() {
+    let (no_interpolation_message, no_interpolation_hint) =
+      DiagnosticKind::NoInterpolation.message_and_hint();
+    let (string_message, string_hint) =
+      DiagnosticKind::StringInterpolation.message_and_hint();
+
+    assert_lint_err! {
+      NoUselessTemplateLiterals,
+      "const a = `a`;": [
+        {
+          col: 10,
+          message: no_interpolation_message,
+          hint: no_interpolation_hint,
+          fix: ("Convert to a string literal", "const a = \"a\";"),
+        }
+      ],
+      "const a = `say \"hi\"`;": [
+        {
+          col: 10,
+          message: no_interpolation_message,
+          hint: no_interpolation_hint,
+          fix: ("Convert to a string literal", "const a = 'say \"hi\"';"),
+        }
+      ],
+      "const a = `\\`a\\` costs \\$1\\n`;": [
+        {
+          col: 10,
+          message: no_interpolation_message,
+          hint: no_interpolation_hint,
+          fix: ("Convert to a string literal", "const a = \"`a` costs $1\\n\";"),
+        }
+      ],
+      "const a = `it's \"a\"`;": [
+        {
+          col: 10,
+          message: no_interpolation_message,
+          hint: no_interpolation_hint,
+        }
+      ],
+      "`use strict`;": [
+        {
+          col: 0,
+          message: no_interpolation_message,
+          hint: no_interpolation_hint,
+        }
+      ],
+      "const a = `a${'b'}c`;": [
+        {
+          col: 12,
+          message: string_message,
+          hint: string_hint,
+          fix: ("Inline the string", "const a = `abc`;"),
+        }
+      ],
+      "const a = `${\"`${x}`\"}${b}`;": [
+        {
+          col: 11,
+          message: string_message,
+          hint: string_hint,
+          fix: ("Inline the string", "const a = `\\`\\${x}\\`${b}`;"),
+        }
+      ],
+      "const a = `${\"$\"}{x}`;": [
+        {
+          col: 11,
+          message: string_message,
+          hint: string_hint,
+        }
+      ],
+      "const a = `$${'{x}'}`;": [
+        {
+          col: 12,
+          message: string_message,
+          hint: string_hint,
+        }
+      ],
+      "const a = `\\$${'{x}'}`;": [
+        {
+          col: 13,
+          message: string_message,
+          hint: string_hint,
+          fix: ("Inline the string", "const a = `\\${x}`;"),
+        }
+      ],
+      "const a = `${'a'}${b}${'c'}`;": [
+        {
+          col: 11,
+          message: string_message,
+          hint: string_hint,
+          fix: ("Inline the string", "const a = `a${b}${'c'}`;"),
+        },
+        {
+          col: 21,
+          message: string_message,
+          hint: string_hint,
+          fix: ("Inline the string", "const a = `${'a'}${b}c`;"),
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_useless_template_literals_ecma_version() {
+    let lint = |source: &str, ecma_version: EcmaVersion| {
+      let (_, diagnostics) = crate::testing::lint_with_ecma_version(
+        Box::new(NoUselessTemplateLiterals),
+        source,
+        "file:///no_useless_template_literals_test.ts",
+        ecma_version,
+      );
+      diagnostics
+    };
+    // Inlining the string keeps the template literal, so it's only offered
+    // from ES2015 on. Converting to a string literal is always offered.
+    let diagnostics = lint("const a = `a${\"b\"}${c}`;", EcmaVersion::Es5);
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0].details.fixes.is_empty());
+    let diagnostics = lint("const a = `a${\"b\"}${c}`;", EcmaVersion::Es2015);
+    assert_eq!(diagnostics[0].details.fixes.len(), 1);
+    let diagnostics = lint("const a = `a`;", EcmaVersion::Es5);
+    assert_eq!(diagnostics[0].details.fixes.len(), 1);
+  }
+}
@@ -0,0 +1,225 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::handler::{Handler, Traverse};
+use crate::tags::Tags;
+use crate::Program;
+use deno_ast::swc::ast::{Expr, Lit};
+use deno_ast::view as ast_view;
+use deno_ast::SourceRanged;
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoUselessYieldStar;
+
+const CODE: &str = "no-useless-yield-star";
+
+#[derive(Display)]
+enum NoUselessYieldStarMessage {
+  #[display(fmt = "`yield*` delegates to a value that isn't iterable")]
+  NotIterable,
+  #[display(fmt = "`yield*` delegates to an empty iterable")]
+  Empty,
+}
+
+#[derive(Display)]
+enum NoUselessYieldStarHint {
+  #[display(
+    fmt = "This throws a `TypeError`, use `yield` to yield the value itself"
+  )]
+  NotIterable,
+  #[display(fmt = "Remove the `yield*`, since it doesn't yield anything")]
+  Empty,
+}
+
+impl LintRule for NoUselessYieldStar {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows `yield*` with values that are obviously not \
+        iterable, like numbers, booleans, `null`, `undefined`, object \
+        literals and functions, which throw a `TypeError` at runtime. \
+        Delegating to an empty array or string literal is reported too, \
+        since it doesn't yield anything.",
+      invalid_examples: &[
+        "function* numbers() {\n  yield* 1;\n}",
+        "function* items() {\n  yield* { id: 1 };\n}",
+        "function* nothing() {\n  yield* [];\n}",
+      ],
+      valid_examples: &[
+        "function* numbers() {\n  yield 1;\n  yield* [2, 3];\n}",
+        "function* items(list) {\n  yield* list;\n}",
+      ],
+    }
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    NoUselessYieldStarHandler.traverse(program, context);
+  }
+}
+
+struct NoUselessYieldStarHandler;
+
+impl Handler for NoUselessYieldStarHandler {
+  fn yield_expr(
+    &mut self,
+    yield_expr: &ast_view::YieldExpr,
+    ctx: &mut Context,
+  ) {
+    if !yield_expr.inner.delegate {
+      return;
+    }
+    let Some(arg) = &yield_expr.inner.arg else {
+      return;
+    };
+    let (message, hint) = match arg.unwrap_parens() {
+      Expr::Lit(Lit::Str(str)) if str.value.is_empty() => (
+        NoUselessYieldStarMessage::Empty,
+        NoUselessYieldStarHint::Empty,
+      ),
+      Expr::Tpl(tpl)
+        if tpl.exprs.is_empty()
+          && tpl.quasis.iter().all(|quasi| quasi.raw.is_empty()) =>
+      {
+        (
+          NoUselessYieldStarMessage::Empty,
+          NoUselessYieldStarHint::Empty,
+        )
+      }
+      Expr::Array(array) if array.elems.is_empty() => (
+        NoUselessYieldStarMessage::Empty,
+        NoUselessYieldStarHint::Empty,
+      ),
+      Expr::Lit(
+        Lit::Num(_)
+        | Lit::BigInt(_)
+        | Lit::Bool(_)
+        | Lit::Null(_)
+        | Lit::Regex(_),
+      )
+      | Expr::Object(_)
+      | Expr::Arrow(_)
+      | Expr::Fn(_)
+      | Expr::Class(_) => (
+        NoUselessYieldStarMessage::NotIterable,
+        NoUselessYieldStarHint::NotIterable,
+      ),
+      Expr::Ident(ident) if ident.sym == *"undefined" => (
+        NoUselessYieldStarMessage::NotIterable,
+        NoUselessYieldStarHint::NotIterable,
+      ),
+      _ => return,
+    };
+    ctx.add_diagnostic_with_hint(yield_expr.range(), CODE, message, hint);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_useless_yield_star_valid() {
+    assert_lint_ok! {
+      NoUselessYieldStar,
+      "function* f() { yield 1; }",
+      "function* f() { yield; }",
+      "function* f() { yield* [1, 2]; }",
+      "function* f() { yield* 'abc'; }",
+      "function* f() { yield* `${a}`; }",
+      "function* f(xs) { yield* xs; }",
+      "function* f() { yield* g(); }",
+      "function* f() { yield* new Set([1]); }",
+      "async function* f() { yield* stream; }",
+      "function* f() { yield null; }",
+    };
+  }
+
+  #[test]
+  fn no_useless_yield_star_invalid() {
+    assert_lint_err! {
+      NoUselessYieldStar,
+      "function* f() { yield* 1; }": [
+        {
+          col: 16,
+          message: NoUselessYieldStarMessage::NotIterable,
+          hint: NoUselessYieldStarHint::NotIterable,
+        }
+      ],
+      "function* f() { yield* (null); }": [
+        {
+          col: 16,
+          message: NoUselessYieldStarMessage::NotIterable,
+          hint: NoUselessYieldStarHint::NotIterable,
+        }
+      ],
+      "function* f() { yield* undefined; }": [
+        {
+          col: 16,
+          message: NoUselessYieldStarMessage::NotIterable,
+          hint: NoUselessYieldStarHint::NotIterable,
+        }
+      ],
+      "function* f() { yield* true; }": [
+        {
+          col: 16,
+          message: NoUselessYieldStarMessage::NotIterable,
+          hint: NoUselessYieldStarHint::NotIterable,
+        }
+      ],
+      "async function* f() { yield* { a: 1 }; }": [
+        {
+          col: 22,
+          message: NoUselessYieldStarMessage::NotIterable,
+          hint: NoUselessYieldStarHint::NotIterable,
+        }
+      ],
+      "function* f() { yield* function* () { yield 1; }; }": [
+        {
+          col: 16,
+          message: NoUselessYieldStarMessage::NotIterable,
+          hint: NoUselessYieldStarHint::NotIterable,
+        }
+      ],
+      "function* f() { yield* /abc/; }": [
+        {
+          col: 16,
+          message: NoUselessYieldStarMessage::NotIterable,
+          hint: NoUselessYieldStarHint::NotIterable,
+        }
+      ],
+      "function* f() { yield* []; }": [
+        {
+          col: 16,
+          message: NoUselessYieldStarMessage::Empty,
+          hint: NoUselessYieldStarHint::Empty,
+        }
+      ],
+      "function* f() { yield* ''; }": [
+        {
+          col: 16,
+          message: NoUselessYieldStarMessage::Empty,
+          hint: NoUselessYieldStarHint::Empty,
+        }
+      ],
+      "function* f() { yield* ``; }": [
+        {
+          col: 16,
+          message: NoUselessYieldStarMessage::Empty,
+          hint: NoUselessYieldStarHint::Empty,
+        }
+      ],
+    };
+  }
+}
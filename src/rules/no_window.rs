@@ -12,7 +12,6 @@ use crate::Program;
 use deno_ast::view as ast_view;
 use deno_ast::SourceRange;
 use deno_ast::SourceRanged;
-use if_chain::if_chain;
 
 #[derive(Debug)]
 pub struct NoWindow;
@@ -31,6 +30,14 @@ impl LintRule for NoWindow {
     CODE
   }
 
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn supersedes(&self) -> &'static [&'static str] {
+    &["no-window-prefix"]
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
@@ -43,7 +50,12 @@ impl LintRule for NoWindow {
 struct NoWindowGlobalHandler;
 
 impl NoWindowGlobalHandler {
-  fn add_diagnostic(&self, ctx: &mut Context, range: SourceRange) {
+  fn add_diagnostic(
+    &self,
+    ctx: &mut Context,
+    range: SourceRange,
+    new_text: &'static str,
+  ) {
     ctx.add_diagnostic_with_fixes(
       range,
       CODE,
@@ -52,7 +64,7 @@ impl NoWindowGlobalHandler {
       vec![LintFix {
         description: FIX_DESC.into(),
         changes: vec![LintFixChange {
-          new_text: "globalThis".into(),
+          new_text: new_text.into(),
           range,
         }],
       }],
@@ -61,27 +73,38 @@ impl NoWindowGlobalHandler {
 }
 
 impl Handler for NoWindowGlobalHandler {
-  fn member_expr(&mut self, expr: &ast_view::MemberExpr, ctx: &mut Context) {
-    use deno_ast::view::Expr;
-    if_chain! {
-      if let Expr::Ident(ident) = &expr.obj;
-      if ident.sym() == "window";
-      if ctx.scope().is_global(&ident.inner.to_id());
-      then {
-        self.add_diagnostic(ctx, ident.range());
-      }
+  fn ident(&mut self, ident: &ast_view::Ident, ctx: &mut Context) {
+    use deno_ast::swc::ast::UnaryOp;
+    use deno_ast::view::Node;
+    if ident.sym() != "window" || !ctx.scope().is_global(&ident.inner.to_id()) {
+      return;
     }
-  }
-
-  fn expr_stmt(&mut self, expr: &ast_view::ExprStmt, ctx: &mut Context) {
-    use deno_ast::view::Expr;
-    if_chain! {
-      if let Expr::Ident(ident) = &expr.expr;
-      if ident.sym() == "window";
-      if ctx.scope().is_global(&ident.inner.to_id());
-      then {
-        self.add_diagnostic(ctx, ident.range());
+    match ident.parent() {
+      // `typeof window` is used to check if the code runs in a browser.
+      Node::UnaryExpr(unary) if unary.inner.op == UnaryOp::TypeOf => {}
+      // Identifiers that aren't references to a variable.
+      Node::LabeledStmt(_)
+      | Node::BreakStmt(_)
+      | Node::ContinueStmt(_)
+      | Node::ExportNamedSpecifier(_)
+      | Node::JSXOpeningElement(_)
+      | Node::JSXClosingElement(_)
+      | Node::JSXMemberExpr(_) => {}
+      // Types, which are checked by the type checker.
+      Node::TsTypeRef(_)
+      | Node::TsTypeQuery(_)
+      | Node::TsQualifiedName(_)
+      | Node::TsExprWithTypeArgs(_)
+      | Node::TsPropertySignature(_)
+      | Node::TsMethodSignature(_)
+      | Node::TsGetterSignature(_)
+      | Node::TsSetterSignature(_)
+      | Node::TsEnumMember(_) => {}
+      // A shorthand property like `{ window }`.
+      Node::ObjectLit(_) => {
+        self.add_diagnostic(ctx, ident.range(), "window: globalThis");
       }
+      _ => self.add_diagnostic(ctx, ident.range(), "globalThis"),
     }
   }
 }
@@ -108,7 +131,16 @@ mod tests {
 
       // https://github.com/denoland/deno_lint/issues/1232
       "const params: { window: number } = { window: 23 };",
-      "x.window"
+      "x.window",
+
+      "if (typeof window === 'undefined') {}",
+      "const isBrowser = typeof window !== 'undefined';",
+      "let window; window = globalThis;",
+      "function f(window) { return window.fetch(); }",
+      "window: for (;;) { break window; }",
+      "type T = typeof window;",
+      "interface A { window: Window; }",
+      "import { window } from './window.ts'; window.fetch();",
     };
   }
 
@@ -159,6 +191,42 @@ globalThis;"),
           fix: (FIX_DESC, "globalThis.console.log()"),
         }
       ],
+      "const w = window;": [
+        {
+          col: 10,
+          fix: (FIX_DESC, "const w = globalThis;"),
+        }
+      ],
+      "foo(window);": [
+        {
+          col: 4,
+          fix: (FIX_DESC, "foo(globalThis);"),
+        }
+      ],
+      "const { document } = window;": [
+        {
+          col: 21,
+          fix: (FIX_DESC, "const { document } = globalThis;"),
+        }
+      ],
+      "if (window === undefined) {}": [
+        {
+          col: 4,
+          fix: (FIX_DESC, "if (globalThis === undefined) {}"),
+        }
+      ],
+      "typeof window.document;": [
+        {
+          col: 7,
+          fix: (FIX_DESC, "typeof globalThis.document;"),
+        }
+      ],
+      "const env = { window };": [
+        {
+          col: 14,
+          fix: (FIX_DESC, "const env = { window: globalThis };"),
+        }
+      ],
     };
   }
 }
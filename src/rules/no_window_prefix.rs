@@ -34,6 +34,10 @@ impl LintRule for NoWindowPrefix {
     CODE
   }
 
+  fn fixable(&self) -> bool {
+    true
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
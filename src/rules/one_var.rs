@@ -0,0 +1,588 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{program_ref, Context, LintRule, RuleDocs};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::tags::{self, Tags};
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{
+  ArrowExpr, BlockStmt, Constructor, Decl, ExportDecl, ForStmt, Function,
+  GetterProp, ModuleDecl, ModuleItem, SetterProp, StaticBlock, Stmt,
+  SwitchStmt, TsModuleBlock, VarDecl, VarDeclKind, VarDeclOrExpr,
+};
+use deno_ast::swc::visit::{noop_visit_type, Visit, VisitWith};
+use deno_ast::{SourceRange, SourceRanged, SourceRangedForSpanned};
+use serde::Deserialize;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct OneVar {
+  /// The style of `var` declarations.
+  pub var: Style,
+  /// The style of `let` declarations.
+  #[serde(rename = "let")]
+  pub let_: Style,
+  /// The style of `const` declarations.
+  #[serde(rename = "const")]
+  pub const_: Style,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Style {
+  /// One declaration statement per scope.
+  #[default]
+  Always,
+  /// One variable per declaration statement.
+  Never,
+  /// Consecutive declaration statements are combined.
+  Consecutive,
+}
+
+impl OneVar {
+  fn style(&self, kind: VarDeclKind) -> Style {
+    match kind {
+      VarDeclKind::Var => self.var,
+      VarDeclKind::Let => self.let_,
+      VarDeclKind::Const => self.const_,
+    }
+  }
+}
+
+const CODE: &str = "one-var";
+
+impl LintRule for OneVar {
+  fn tags(&self) -> Tags {
+    &[tags::STYLISTIC]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Enforces how many variables are declared per `var`, \
+        `let` and `const` statement, configurable per kind: `always` \
+        declares all variables of a scope in one statement, `consecutive` \
+        combines declarations that directly follow each other, and `never` \
+        declares each variable in its own statement. This rule replaces \
+        `single-var-declarator`, which is now an alias of `one-var` with \
+        `never` for all kinds.",
+      invalid_examples: &[
+        "function f() {\n  let a = 1;\n  let b = 2;\n}",
+        "function f() {\n  let a = 1;\n  g();\n  let b = 2;\n}",
+      ],
+      valid_examples: &[
+        "function f() {\n  let a = 1, b = 2;\n}",
+        "function f() {\n  const a = 1;\n  let b = 2;\n}",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    let style = serde_json::json!({
+      "enum": ["always", "never", "consecutive"]
+    });
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "var": style,
+        "let": style,
+        "const": style
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<OneVar>(options)?))
+  }
+
+  fn lint_program_with_ast_view<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: Program<'view>,
+  ) {
+    let mut visitor = OneVarVisitor {
+      rule: self,
+      context,
+      scopes: vec![Scope {
+        is_function: true,
+        ..Default::default()
+      }],
+    };
+    match program_ref(program) {
+      ProgramRef::Module(m) => m.visit_with(&mut visitor),
+      ProgramRef::Script(s) => s.visit_with(&mut visitor),
+    }
+  }
+}
+
+fn kind_name(kind: VarDeclKind) -> &'static str {
+  match kind {
+    VarDeclKind::Var => "var",
+    VarDeclKind::Let => "let",
+    VarDeclKind::Const => "const",
+  }
+}
+
+/// A declaration statement, along with the `export` in front of it.
+struct Declaration<'a> {
+  decl: &'a VarDecl,
+  range: SourceRange,
+  is_exported: bool,
+}
+
+impl<'a> Declaration<'a> {
+  fn from_stmt(stmt: &'a Stmt) -> Option<Self> {
+    match stmt {
+      Stmt::Decl(Decl::Var(decl)) => Some(Self {
+        decl,
+        range: decl.range(),
+        is_exported: false,
+      }),
+      _ => None,
+    }
+  }
+
+  fn from_module_item(item: &'a ModuleItem) -> Option<Self> {
+    match item {
+      ModuleItem::Stmt(stmt) => Self::from_stmt(stmt),
+      ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+        decl: Decl::Var(decl),
+        span,
+        ..
+      })) => Some(Self {
+        decl,
+        range: span.range(),
+        is_exported: true,
+      }),
+      _ => None,
+    }
+  }
+}
+
+/// Which kinds of declarations a scope has.
+#[derive(Default)]
+struct Scope {
+  /// Function scopes contain the `var` declarations, while all scopes
+  /// contain `let` and `const` declarations.
+  is_function: bool,
+  has_var: bool,
+  has_let: bool,
+  has_const: bool,
+}
+
+struct OneVarVisitor<'c, 'view> {
+  rule: &'c OneVar,
+  context: &'c mut Context<'view>,
+  scopes: Vec<Scope>,
+}
+
+impl<'c, 'view> OneVarVisitor<'c, 'view> {
+  fn with_scope(&mut self, is_function: bool, f: impl FnOnce(&mut Self)) {
+    self.scopes.push(Scope {
+      is_function,
+      ..Default::default()
+    });
+    f(self);
+    self.scopes.pop();
+  }
+
+  /// Marks the kind of declaration as seen in its scope, and returns whether
+  /// it was seen before.
+  fn mark_seen(&mut self, kind: VarDeclKind) -> bool {
+    let scope = if kind == VarDeclKind::Var {
+      self.scopes.iter_mut().rev().find(|scope| scope.is_function)
+    } else {
+      self.scopes.last_mut()
+    };
+    // The outermost scope is a function scope, so there always is one.
+    let scope = scope.unwrap();
+    let seen = match kind {
+      VarDeclKind::Var => &mut scope.has_var,
+      VarDeclKind::Let => &mut scope.has_let,
+      VarDeclKind::Const => &mut scope.has_const,
+    };
+    std::mem::replace(seen, true)
+  }
+
+  fn check_declarations<'a>(
+    &mut self,
+    declarations: impl Iterator<Item = Option<Declaration<'a>>>,
+  ) {
+    let mut prev: Option<Declaration> = None;
+    for current in declarations {
+      if let Some(current) = &current {
+        self.check_declaration(current, prev.as_ref());
+      }
+      prev = current;
+    }
+  }
+
+  fn check_declaration(
+    &mut self,
+    current: &Declaration,
+    prev: Option<&Declaration>,
+  ) {
+    let kind = current.decl.kind;
+    let prev = prev.filter(|prev| prev.decl.kind == kind);
+    let seen = self.mark_seen(kind);
+    match self.rule.style(kind) {
+      Style::Never => self.check_never(current.decl, Some(current)),
+      Style::Always if seen => self.report_combine(current, prev, false),
+      Style::Consecutive if prev.is_some() => {
+        self.report_combine(current, prev, true)
+      }
+      Style::Always | Style::Consecutive => {}
+    }
+  }
+
+  fn report_combine(
+    &mut self,
+    current: &Declaration,
+    prev: Option<&Declaration>,
+    consecutive: bool,
+  ) {
+    let kind = kind_name(current.decl.kind);
+    let fixes = prev
+      .and_then(|prev| self.combine_fix(prev, current))
+      .into_iter()
+      .collect();
+    let hint = if consecutive {
+      format!("Declare consecutive `{}` variables in one statement", kind)
+    } else {
+      format!(
+        "Declare all `{}` variables of the scope in one statement",
+        kind
+      )
+    };
+    self.context.add_diagnostic_with_fixes(
+      current.range,
+      CODE,
+      format!("Combine this with the previous `{}` declaration", kind),
+      Some(hint),
+      fixes,
+    );
+  }
+
+  /// Merges a declaration into the one directly before it by replacing the
+  /// text between their declarators, e.g. `;\nlet `, with a comma.
+  fn combine_fix(
+    &self,
+    prev: &Declaration,
+    current: &Declaration,
+  ) -> Option<LintFix> {
+    if current.is_exported
+      || prev.is_exported
+      || current.decl.declare
+      || prev.decl.declare
+    {
+      return None;
+    }
+    let range = SourceRange::new(
+      prev.decl.decls.last()?.end(),
+      current.decl.decls.first()?.start(),
+    );
+    if self.context.has_comments_in_range(range) {
+      return None;
+    }
+    Some(LintFix {
+      description: "Combine the declarations".into(),
+      changes: vec![LintFixChange {
+        new_text: ", ".into(),
+        range,
+      }],
+    })
+  }
+
+  /// `statement` is the declaration if it is a statement, as opposed to the
+  /// initializer of a `for` loop, in which case it can be fixed.
+  fn check_never(&mut self, decl: &VarDecl, statement: Option<&Declaration>) {
+    if decl.decls.len() < 2 {
+      return;
+    }
+    let kind = kind_name(decl.kind);
+    let fixes = statement
+      .and_then(|statement| self.split_fix(statement))
+      .into_iter()
+      .collect();
+    self.context.add_diagnostic_with_fixes(
+      decl.range(),
+      CODE,
+      format!("Split `{}` declarations into multiple statements", kind),
+      Some("Declare each variable in its own statement".to_string()),
+      fixes,
+    );
+  }
+
+  /// Repeats the text in front of the first declarator, e.g. `export const `,
+  /// for every declarator.
+  fn split_fix(&self, statement: &Declaration) -> Option<LintFix> {
+    if self.context.has_comments_in_range(statement.range) {
+      return None;
+    }
+    let text_info = self.context.text_info();
+    let prefix =
+      SourceRange::new(statement.range.start, statement.decl.decls[0].start())
+        .text_fast(text_info);
+    let new_text = statement
+      .decl
+      .decls
+      .iter()
+      .map(|declarator| {
+        format!("{}{};", prefix, declarator.range().text_fast(text_info))
+      })
+      .collect::<Vec<_>>()
+      .join(" ");
+    Some(LintFix {
+      description: "Split the declarations".into(),
+      changes: vec![LintFixChange {
+        new_text: new_text.into(),
+        range: statement.range,
+      }],
+    })
+  }
+}
+
+impl<'c, 'view> Visit for OneVarVisitor<'c, 'view> {
+  noop_visit_type!();
+
+  fn visit_module_items(&mut self, items: &[ModuleItem]) {
+    self.check_declarations(items.iter().map(Declaration::from_module_item));
+    for item in items {
+      item.visit_with(self);
+    }
+  }
+
+  fn visit_stmts(&mut self, stmts: &[Stmt]) {
+    self.check_declarations(stmts.iter().map(Declaration::from_stmt));
+    for stmt in stmts {
+      stmt.visit_with(self);
+    }
+  }
+
+  fn visit_for_stmt(&mut self, for_stmt: &ForStmt) {
+    if let Some(VarDeclOrExpr::VarDecl(decl)) = &for_stmt.init {
+      if self.rule.style(decl.kind) == Style::Never {
+        self.check_never(decl, None);
+      }
+    }
+    self.with_scope(false, |a| for_stmt.visit_children_with(a));
+  }
+
+  fn visit_block_stmt(&mut self, block: &BlockStmt) {
+    self.with_scope(false, |a| block.visit_children_with(a));
+  }
+
+  fn visit_switch_stmt(&mut self, switch_stmt: &SwitchStmt) {
+    self.with_scope(false, |a| switch_stmt.visit_children_with(a));
+  }
+
+  fn visit_function(&mut self, function: &Function) {
+    self.with_scope(true, |a| function.visit_children_with(a));
+  }
+
+  fn visit_arrow_expr(&mut self, arrow: &ArrowExpr) {
+    self.with_scope(true, |a| arrow.visit_children_with(a));
+  }
+
+  fn visit_constructor(&mut self, constructor: &Constructor) {
+    self.with_scope(true, |a| constructor.visit_children_with(a));
+  }
+
+  fn visit_getter_prop(&mut self, getter: &GetterProp) {
+    self.with_scope(true, |a| getter.visit_children_with(a));
+  }
+
+  fn visit_setter_prop(&mut self, setter: &SetterProp) {
+    self.with_scope(true, |a| setter.visit_children_with(a));
+  }
+
+  fn visit_static_block(&mut self, static_block: &StaticBlock) {
+    self.with_scope(true, |a| static_block.visit_children_with(a));
+  }
+
+  fn visit_ts_module_block(&mut self, block: &TsModuleBlock) {
+    self.with_scope(true, |a| block.visit_children_with(a));
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v9.9.0/tests/lib/rules/one-var.js
+  // MIT Licensed.
+
+  fn never() -> OneVar {
+    OneVar {
+      var: Style::Never,
+      let_: Style::Never,
+      const_: Style::Never,
+    }
+  }
+
+  fn consecutive() -> OneVar {
+    OneVar {
+      var: Style::Consecutive,
+      let_: Style::Consecutive,
+      const_: Style::Consecutive,
+    }
+  }
+
+  #[test]
+  fn one_var_valid() {
+    assert_lint_ok! {
+      OneVar::default(),
+      "var a = 1, b = 2;",
+      "let a = 1;\nconst b = 2;",
+      "function f() { var a; }\nfunction g() { var b; }",
+      "let a;\n{ let b; }",
+      "var a;\nfunction f() { var b; }",
+      "const a = () => { let b; };\nlet c;",
+      "for (let i = 0; i < 1; i++) {}\nlet j;",
+      "class A { static { var a; } }\nvar b;",
+    };
+
+    assert_lint_ok! {
+      never(),
+      "var a = 1;\nvar b = 2;",
+      "let a;\nlet b;",
+      "for (var a of b) {}",
+    };
+
+    assert_lint_ok! {
+      consecutive(),
+      "var a = 1, b = 2;",
+      "let a;\nfoo();\nlet b;",
+      "let a;\nconst b = 1;\nlet c;",
+    };
+
+    assert_lint_ok! {
+      OneVar {
+        const_: Style::Never,
+        ..Default::default()
+      },
+      "const a = 1;\nconst b = 2;\nlet c, d;",
+    };
+  }
+
+  #[test]
+  fn one_var_invalid() {
+    assert_lint_err! {
+      OneVar::default(),
+      "var a;\nvar b;": [
+        {
+          line: 2,
+          col: 0,
+          message: "Combine this with the previous `var` declaration",
+          hint: "Declare all `var` variables of the scope in one statement",
+          fix: ("Combine the declarations", "var a, b;"),
+        }
+      ],
+      "let a = 1;\nfoo();\nlet b = 2;": [
+        {
+          line: 3,
+          col: 0,
+          message: "Combine this with the previous `let` declaration",
+          hint: "Declare all `let` variables of the scope in one statement",
+        }
+      ],
+      "var a;\nif (b) { var c; }": [
+        {
+          line: 2,
+          col: 9,
+          message: "Combine this with the previous `var` declaration",
+          hint: "Declare all `var` variables of the scope in one statement",
+        }
+      ],
+      "export const a = 1;\nexport const b = 2;": [
+        {
+          line: 2,
+          col: 0,
+          message: "Combine this with the previous `const` declaration",
+          hint: "Declare all `const` variables of the scope in one statement",
+        }
+      ],
+      "let a /* a */;\nlet b;": [
+        {
+          line: 2,
+          col: 0,
+          message: "Combine this with the previous `let` declaration",
+          hint: "Declare all `let` variables of the scope in one statement",
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      never(),
+      "var a = 1, b = 2;": [
+        {
+          col: 0,
+          message: "Split `var` declarations into multiple statements",
+          hint: "Declare each variable in its own statement",
+          fix: ("Split the declarations", "var a = 1; var b = 2;"),
+        }
+      ],
+      "export const a = 1, b = 2;": [
+        {
+          col: 7,
+          message: "Split `const` declarations into multiple statements",
+          hint: "Declare each variable in its own statement",
+          fix: ("Split the declarations", "export const a = 1; export const b = 2;"),
+        }
+      ],
+      "function f() { let a: number, b: string }": [
+        {
+          col: 15,
+          message: "Split `let` declarations into multiple statements",
+          hint: "Declare each variable in its own statement",
+          fix: ("Split the declarations", "function f() { let a: number; let b: string; }"),
+        }
+      ],
+      "for (let i = 0, j = 1; i < j; i++) {}": [
+        {
+          col: 5,
+          message: "Split `let` declarations into multiple statements",
+          hint: "Declare each variable in its own statement",
+        }
+      ],
+      "let a, /* b */ b;": [
+        {
+          col: 0,
+          message: "Split `let` declarations into multiple statements",
+          hint: "Declare each variable in its own statement",
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      consecutive(),
+      "let a;\nlet b;\nlet c;": [
+        {
+          line: 2,
+          col: 0,
+          message: "Combine this with the previous `let` declaration",
+          hint: "Declare consecutive `let` variables in one statement",
+          fix: ("Combine the declarations", "let a, b;\nlet c;"),
+        },
+        {
+          line: 3,
+          col: 0,
+          message: "Combine this with the previous `let` declaration",
+          hint: "Declare consecutive `let` variables in one statement",
+          fix: ("Combine the declarations", "let a;\nlet b, c;"),
+        }
+      ],
+    };
+  }
+}
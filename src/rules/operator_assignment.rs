@@ -0,0 +1,422 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::{Handler, Traverse};
+use crate::linter::EcmaVersion;
+use crate::swc_util::{
+  assign_target_expr, binary_op_precedence, expr_precedence, is_same_reference,
+  is_simple_reference,
+};
+use crate::tags::{self, Tags};
+use crate::Program;
+use deno_ast::swc::ast::{AssignOp, BinaryOp, Expr};
+use deno_ast::view as ast_view;
+use deno_ast::{SourceRange, SourceRanged, SourceRangedForSpanned};
+use serde::Deserialize;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct OperatorAssignment {
+  /// Whether operator assignments like `x += y` are required where possible,
+  /// or disallowed.
+  pub style: Style,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Style {
+  #[default]
+  Always,
+  Never,
+}
+
+const CODE: &str = "operator-assignment";
+
+impl LintRule for OperatorAssignment {
+  fn tags(&self) -> Tags {
+    &[tags::STYLISTIC]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Requires operator assignments like `x += y` instead of \
+        `x = x + y` where possible. With the `never` style, operator \
+        assignments are disallowed instead. Logical assignments like `x ||= \
+        y` are not affected.",
+      invalid_examples: &["let count = 0;\ncount = count + 1;"],
+      valid_examples: &[
+        "let count = 0;\ncount += 1;",
+        "let total = 0;\ntotal = 1 + total;",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "style": {
+          "description": "Require or disallow operator assignments. Defaults to `always`",
+          "enum": ["always", "never"]
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<OperatorAssignment>(
+      options,
+    )?))
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    OperatorAssignmentHandler { rule: self }.traverse(program, context);
+  }
+}
+
+struct OperatorAssignmentHandler<'a> {
+  rule: &'a OperatorAssignment,
+}
+
+impl<'a> Handler for OperatorAssignmentHandler<'a> {
+  fn assign_expr(
+    &mut self,
+    assign_expr: &ast_view::AssignExpr,
+    ctx: &mut Context,
+  ) {
+    let assign = assign_expr.inner;
+    let Some(left) = assign_target_expr(&assign.left) else {
+      return;
+    };
+    let range = assign.range();
+    let can_fix =
+      is_simple_reference(&left) && !ctx.has_comments_in_range(range);
+
+    match self.rule.style {
+      Style::Always => {
+        if assign.op != AssignOp::Assign {
+          return;
+        }
+        let Expr::Bin(bin) = assign.right.unwrap_parens() else {
+          return;
+        };
+        // `**=` is only available from ES2016 on.
+        if !has_shorthand(bin.op)
+          || (bin.op == BinaryOp::Exp
+            && ctx.ecma_version() < EcmaVersion::Es2016)
+        {
+          return;
+        }
+        let message = format!(
+          "Assignment (=) can be replaced with operator assignment ({}=)",
+          bin.op
+        );
+        let hint = format!("Use `{}=` instead", bin.op);
+        if is_same_reference(&left, &bin.left) {
+          let text_info = ctx.text_info();
+          let new_text = format!(
+            "{} {}= {}",
+            assign.left.range().text_fast(text_info),
+            bin.op,
+            bin.right.range().text_fast(text_info)
+          );
+          let fix = can_fix.then(|| LintFix {
+            description: "Use operator assignment".into(),
+            changes: vec![LintFixChange {
+              new_text: new_text.into(),
+              range,
+            }],
+          });
+          report(range, message, hint, fix, ctx);
+        } else if is_commutative(bin.op) && is_same_reference(&left, &bin.right)
+        {
+          report(range, message, hint, None, ctx);
+        }
+      }
+      Style::Never => {
+        let Some(op) = binary_op_of(assign.op) else {
+          return;
+        };
+        let text_info = ctx.text_info();
+        let left_text = assign.left.range().text_fast(text_info);
+        let right_text = assign.right.range().text_fast(text_info);
+        let right_text =
+          if expr_precedence(&assign.right) <= binary_op_precedence(op) {
+            format!("({})", right_text)
+          } else {
+            right_text.to_string()
+          };
+        let new_text =
+          format!("{} = {} {} {}", left_text, left_text, op, right_text);
+        let fix = can_fix.then(|| LintFix {
+          description: "Replace with regular assignment".into(),
+          changes: vec![LintFixChange {
+            new_text: new_text.into(),
+            range,
+          }],
+        });
+        report(
+          range,
+          format!("Unexpected operator assignment ({}=) shorthand", op),
+          format!("Use `{} = {} {} ...` instead", left_text, left_text, op),
+          fix,
+          ctx,
+        );
+      }
+    }
+  }
+}
+
+fn report(
+  range: SourceRange,
+  message: String,
+  hint: String,
+  fix: Option<LintFix>,
+  ctx: &mut Context,
+) {
+  ctx.add_diagnostic_with_fixes(
+    range,
+    CODE,
+    message,
+    Some(hint),
+    fix.into_iter().collect(),
+  );
+}
+
+fn has_shorthand(op: BinaryOp) -> bool {
+  matches!(
+    op,
+    BinaryOp::Add
+      | BinaryOp::Sub
+      | BinaryOp::Mul
+      | BinaryOp::Div
+      | BinaryOp::Mod
+      | BinaryOp::Exp
+      | BinaryOp::LShift
+      | BinaryOp::RShift
+      | BinaryOp::ZeroFillRShift
+      | BinaryOp::BitAnd
+      | BinaryOp::BitOr
+      | BinaryOp::BitXor
+  )
+}
+
+/// Operators for which `x = y op x` is the same as `x = x op y`. `+` is not
+/// one of them, because of string concatenation.
+fn is_commutative(op: BinaryOp) -> bool {
+  matches!(
+    op,
+    BinaryOp::Mul | BinaryOp::BitAnd | BinaryOp::BitOr | BinaryOp::BitXor
+  )
+}
+
+/// Returns the binary operator of an operator assignment. Logical
+/// assignments like `x ||= y` are excluded, since they only assign
+/// conditionally.
+fn binary_op_of(op: AssignOp) -> Option<BinaryOp> {
+  match op {
+    AssignOp::AddAssign => Some(BinaryOp::Add),
+    AssignOp::SubAssign => Some(BinaryOp::Sub),
+    AssignOp::MulAssign => Some(BinaryOp::Mul),
+    AssignOp::DivAssign => Some(BinaryOp::Div),
+    AssignOp::ModAssign => Some(BinaryOp::Mod),
+    AssignOp::ExpAssign => Some(BinaryOp::Exp),
+    AssignOp::LShiftAssign => Some(BinaryOp::LShift),
+    AssignOp::RShiftAssign => Some(BinaryOp::RShift),
+    AssignOp::ZeroFillRShiftAssign => Some(BinaryOp::ZeroFillRShift),
+    AssignOp::BitAndAssign => Some(BinaryOp::BitAnd),
+    AssignOp::BitOrAssign => Some(BinaryOp::BitOr),
+    AssignOp::BitXorAssign => Some(BinaryOp::BitXor),
+    AssignOp::Assign
+    | AssignOp::AndAssign
+    | AssignOp::OrAssign
+    | AssignOp::NullishAssign => None,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v9.9.0/tests/lib/rules/operator-assignment.js
+  // MIT Licensed.
+
+  #[test]
+  fn operator_assignment_valid() {
+    assert_lint_ok! {
+      OperatorAssignment::default(),
+      "x = y;",
+      "x = x;",
+      "x += y;",
+      "x = y + x;",
+      "x = x + y + z;",
+      "x = x && y;",
+      "x = x < y;",
+      "x.y = x.z + 1;",
+      "x[0] = x[1] * 2;",
+      "x ||= y;",
+      "x[f()] = x[f()] + 1;",
+      "x[i++] = x[i++] + 1;",
+      "x.y[f()] = 2 * x.y[f()];",
+      "f().x = f().x + 1;",
+    };
+
+    assert_lint_ok! {
+      OperatorAssignment {
+        style: Style::Never,
+      },
+      "x = x + y;",
+      "x ||= y;",
+      "x &&= y;",
+      "x ??= y;",
+    };
+  }
+
+  #[test]
+  fn operator_assignment_invalid() {
+    assert_lint_err! {
+      OperatorAssignment::default(),
+      "x = x + y;": [
+        {
+          col: 0,
+          message: "Assignment (=) can be replaced with operator assignment (+=)",
+          hint: "Use `+=` instead",
+          fix: ("Use operator assignment", "x += y;"),
+        }
+      ],
+      "x.y = x.y * 2;": [
+        {
+          col: 0,
+          message: "Assignment (=) can be replaced with operator assignment (*=)",
+          hint: "Use `*=` instead",
+          fix: ("Use operator assignment", "x.y *= 2;"),
+        }
+      ],
+      "this.x = this.x - 1;": [
+        {
+          col: 0,
+          message: "Assignment (=) can be replaced with operator assignment (-=)",
+          hint: "Use `-=` instead",
+          fix: ("Use operator assignment", "this.x -= 1;"),
+        }
+      ],
+      "x[0] = x[0] % (y + 1);": [
+        {
+          col: 0,
+          message: "Assignment (=) can be replaced with operator assignment (%=)",
+          hint: "Use `%=` instead",
+          fix: ("Use operator assignment", "x[0] %= (y + 1);"),
+        }
+      ],
+      "x = (x) ** y;": [
+        {
+          col: 0,
+          message: "Assignment (=) can be replaced with operator assignment (**=)",
+          hint: "Use `**=` instead",
+          fix: ("Use operator assignment", "x **= y;"),
+        }
+      ],
+      "x = y * x;": [
+        {
+          col: 0,
+          message: "Assignment (=) can be replaced with operator assignment (*=)",
+          hint: "Use `*=` instead",
+        }
+      ],
+      "a.b.c = a.b.c + 1;": [
+        {
+          col: 0,
+          message: "Assignment (=) can be replaced with operator assignment (+=)",
+          hint: "Use `+=` instead",
+        }
+      ],
+      "x = x + /* one */ 1;": [
+        {
+          col: 0,
+          message: "Assignment (=) can be replaced with operator assignment (+=)",
+          hint: "Use `+=` instead",
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      OperatorAssignment {
+        style: Style::Never,
+      },
+      "x += y;": [
+        {
+          col: 0,
+          message: "Unexpected operator assignment (+=) shorthand",
+          hint: "Use `x = x + ...` instead",
+          fix: ("Replace with regular assignment", "x = x + y;"),
+        }
+      ],
+      "x *= y + 1;": [
+        {
+          col: 0,
+          message: "Unexpected operator assignment (*=) shorthand",
+          hint: "Use `x = x * ...` instead",
+          fix: ("Replace with regular assignment", "x = x * (y + 1);"),
+        }
+      ],
+      "x -= y - z;": [
+        {
+          col: 0,
+          message: "Unexpected operator assignment (-=) shorthand",
+          hint: "Use `x = x - ...` instead",
+          fix: ("Replace with regular assignment", "x = x - (y - z);"),
+        }
+      ],
+      "x.y **= (2);": [
+        {
+          col: 0,
+          message: "Unexpected operator assignment (**=) shorthand",
+          hint: "Use `x.y = x.y ** ...` instead",
+          fix: ("Replace with regular assignment", "x.y = x.y ** (2);"),
+        }
+      ],
+      "a.b.c >>>= 1;": [
+        {
+          col: 0,
+          message: "Unexpected operator assignment (>>>=) shorthand",
+          hint: "Use `a.b.c = a.b.c >>> ...` instead",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn operator_assignment_ecma_version() {
+    let lint = |source: &str, ecma_version: EcmaVersion| {
+      let (_, diagnostics) = crate::testing::lint_with_ecma_version(
+        Box::new(OperatorAssignment::default()),
+        source,
+        "file:///operator_assignment_test.ts",
+        ecma_version,
+      );
+      diagnostics
+    };
+    // `**=` is only available from ES2016 on.
+    assert!(lint("x = x ** 2;", EcmaVersion::Es5).is_empty());
+    assert_eq!(lint("x = x ** 2;", EcmaVersion::Es2016).len(), 1);
+    assert_eq!(lint("x = x * 2;", EcmaVersion::Es5).len(), 1);
+  }
+}
@@ -0,0 +1,432 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::{Handler, Traverse};
+use crate::linter::EcmaVersion;
+use crate::tags::Tags;
+use crate::this_context::ThisContext;
+use crate::Program;
+use deno_ast::swc::ast::{ExprOrSpread, Function, Id, Ident, MemberProp, Pat};
+use deno_ast::swc::parser::token::Token;
+use deno_ast::swc::visit::{noop_visit_type, Visit, VisitWith};
+use deno_ast::view::{self as ast_view, Node, NodeTrait};
+use deno_ast::{SourceRange, SourceRanged, SourceRangedForSpanned};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct PreferArrowCallback {
+  /// Allows named function expressions as callbacks.
+  pub allow_named_functions: bool,
+  /// Allows callbacks that use `this` without being bound with
+  /// `.bind(this)`. If disabled, they are reported without a fix.
+  pub allow_unbound_this: bool,
+}
+
+impl Default for PreferArrowCallback {
+  fn default() -> Self {
+    Self {
+      allow_named_functions: false,
+      allow_unbound_this: true,
+    }
+  }
+}
+
+const CODE: &str = "prefer-arrow-callback";
+const MESSAGE: &str = "Unexpected function expression used as a callback";
+const HINT: &str = "Use an arrow function instead";
+const FIX_DESC: &str = "Convert to an arrow function";
+
+impl LintRule for PreferArrowCallback {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Requires arrow functions to be used as callbacks instead \
+        of function expressions. Function expressions that depend on the \
+        context they're called with, i.e. use `arguments`, `new.target` or \
+        `this` without being bound with `.bind(this)`, as well as generators \
+        and functions that refer to their own name are not reported, because \
+        they can't be turned into arrow functions.",
+      invalid_examples: &[
+        "items.map(function (item) {\n  return item.id;\n});",
+        "button.addEventListener(\"click\", function () {\n  this.open();\n}.bind(this));",
+      ],
+      valid_examples: &[
+        "items.map((item) => item.id);",
+        "button.addEventListener(\"click\", function () {\n  this.classList.toggle(\"open\");\n});",
+        "items.forEach(function visit(item) {\n  item.children.forEach(visit);\n});",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "allowNamedFunctions": {
+          "description": "Allow named function expressions as callbacks",
+          "type": "boolean"
+        },
+        "allowUnboundThis": {
+          "description": "Allow callbacks that use `this` without `.bind(this)`. Defaults to `true`",
+          "type": "boolean"
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<PreferArrowCallback>(
+      options,
+    )?))
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    PreferArrowCallbackHandler { rule: self }.traverse(program, context);
+  }
+}
+
+struct PreferArrowCallbackHandler<'a> {
+  rule: &'a PreferArrowCallback,
+}
+
+impl<'a> Handler for PreferArrowCallbackHandler<'a> {
+  fn fn_expr(&mut self, fn_expr: &ast_view::FnExpr, ctx: &mut Context) {
+    // Arrow functions are only available from ES2015 on.
+    if ctx.ecma_version() < EcmaVersion::Es2015 {
+      return;
+    }
+    let function = fn_expr.function.inner;
+    if function.is_generator
+      || has_this_param(function)
+      || (self.rule.allow_named_functions && fn_expr.ident.is_some())
+    {
+      return;
+    }
+    let Some(callback) = callback_of(fn_expr.as_node()) else {
+      return;
+    };
+
+    let this_context = ThisContext::of_function(function);
+    if this_context.uses_non_this_bindings() {
+      return;
+    }
+    if let Some(ident) = fn_expr.inner.ident.as_ref() {
+      if is_referenced(ident.to_id(), function) {
+        return;
+      }
+    }
+
+    let range = fn_expr.range();
+    if this_context.uses_this && !callback.is_bound {
+      if !self.rule.allow_unbound_this {
+        ctx.add_diagnostic_with_hint(range, CODE, MESSAGE, HINT);
+      }
+      return;
+    }
+
+    let fixes = arrow_text(fn_expr, ctx)
+      .map(|new_text| LintFix {
+        description: FIX_DESC.into(),
+        changes: vec![LintFixChange {
+          new_text: new_text.into(),
+          range: callback.range,
+        }],
+      })
+      .into_iter()
+      .collect();
+    ctx.add_diagnostic_with_fixes(
+      range,
+      CODE,
+      MESSAGE,
+      Some(HINT.to_string()),
+      fixes,
+    );
+  }
+}
+
+struct Callback {
+  /// The range of the expression that is replaced by the arrow function,
+  /// which includes `.bind(this)` if present.
+  range: SourceRange,
+  is_bound: bool,
+}
+
+/// Returns the callback if the function expression is passed as an argument
+/// to a call, either directly or bound with `.bind(this)`.
+fn callback_of(fn_expr: Node) -> Option<Callback> {
+  let mut node = outermost_paren(fn_expr);
+  let mut callback = Callback {
+    range: fn_expr.range(),
+    is_bound: false,
+  };
+
+  if let Some(Node::MemberExpr(member)) = node.parent() {
+    let is_bind = matches!(
+      &member.inner.prop,
+      MemberProp::Ident(prop) if prop.sym == *"bind"
+    );
+    let Some(Node::CallExpr(call)) = member.as_node().parent() else {
+      return None;
+    };
+    let is_this_arg = matches!(
+      call.inner.args.as_slice(),
+      [ExprOrSpread { spread: None, expr }] if expr.is_this()
+    );
+    if !is_bind || !is_this_arg || member.inner.obj.range() != node.range() {
+      return None;
+    }
+    callback = Callback {
+      range: call.range(),
+      is_bound: true,
+    };
+    node = outermost_paren(call.as_node());
+  }
+
+  let Some(Node::ExprOrSpread(arg)) = node.parent() else {
+    return None;
+  };
+  if arg.inner.spread.is_some() {
+    return None;
+  }
+  match arg.as_node().parent() {
+    Some(Node::CallExpr(_) | Node::NewExpr(_) | Node::OptCall(_)) => {
+      Some(callback)
+    }
+    _ => None,
+  }
+}
+
+fn outermost_paren(mut node: Node) -> Node {
+  while let Some(parent @ Node::ParenExpr(_)) = node.parent() {
+    node = parent;
+  }
+  node
+}
+
+/// TypeScript's `this` parameter only declares the type of `this`, which
+/// arrow functions can't do.
+fn has_this_param(function: &Function) -> bool {
+  matches!(
+    function.params.first().map(|param| &param.pat),
+    Some(Pat::Ident(ident)) if ident.id.sym == *"this"
+  )
+}
+
+/// Checks if the name of a function expression is used inside of it, e.g.
+/// for recursion, in which case it can't be removed.
+fn is_referenced(id: Id, function: &Function) -> bool {
+  struct Finder {
+    id: Id,
+    found: bool,
+  }
+
+  impl Visit for Finder {
+    noop_visit_type!();
+
+    fn visit_ident(&mut self, ident: &Ident) {
+      if ident.to_id() == self.id {
+        self.found = true;
+      }
+    }
+  }
+
+  let mut finder = Finder { id, found: false };
+  function.visit_with(&mut finder);
+  finder.found
+}
+
+/// Builds the arrow function, keeping the type parameters, parameters, return
+/// type and body of the function expression as they are written.
+fn arrow_text(fn_expr: &ast_view::FnExpr, ctx: &Context) -> Option<String> {
+  let function = fn_expr.function;
+  let body = function.body?;
+  let signature_start = match function.type_params {
+    Some(type_params) => type_params.start(),
+    None => function
+      .tokens_fast(ctx.program())
+      .iter()
+      .find(|token| token.token == Token::LParen)?
+      .start(),
+  };
+  let text_info = ctx.text_info();
+  let signature =
+    SourceRange::new(signature_start, body.start()).text_fast(text_info);
+  Some(format!(
+    "{}{} => {}",
+    if function.is_async() { "async " } else { "" },
+    signature.trim_end(),
+    body.range().text_fast(text_info)
+  ))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_arrow_callback_valid() {
+    assert_lint_ok! {
+      PreferArrowCallback::default(),
+      "foo(a => a);",
+      "foo(() => {});",
+      "const f = function () {};",
+      "foo(function () { return this.a; });",
+      "foo(function () { return arguments[0]; });",
+      "foo(function () { return new.target; });",
+      "foo(function* () {});",
+      "foo(function bar() { bar(); });",
+      "foo(function () {}.bind(obj));",
+      "foo(function () {}.call(this));",
+      "foo(function (this: Foo) {});",
+      "foo(...function () {});",
+      "[function () {}];",
+    };
+
+    assert_lint_ok! {
+      PreferArrowCallback {
+        allow_named_functions: true,
+        ..Default::default()
+      },
+      "foo(function bar() {});",
+    };
+  }
+
+  #[test]
+  fn prefer_arrow_callback_invalid() {
+    assert_lint_err! {
+      PreferArrowCallback::default(),
+      "foo(function () {});": [
+        {
+          col: 4,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "foo(() => {});"),
+        }
+      ],
+      "foo(function bar(a, b) { return a + b; });": [
+        {
+          col: 4,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "foo((a, b) => { return a + b; });"),
+        }
+      ],
+      "foo(async function (a) { await a; });": [
+        {
+          col: 4,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "foo(async (a) => { await a; });"),
+        }
+      ],
+      "foo(function <T>(a: T): T { return a; });": [
+        {
+          col: 4,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "foo(<T>(a: T): T => { return a; });"),
+        }
+      ],
+      "new Promise(function (resolve) { resolve(); });": [
+        {
+          col: 12,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "new Promise((resolve) => { resolve(); });"),
+        }
+      ],
+      "foo?.(function () {});": [
+        {
+          col: 6,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "foo?.(() => {});"),
+        }
+      ],
+      "foo((function () {}));": [
+        {
+          col: 5,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "foo((() => {}));"),
+        }
+      ],
+      "foo(function () { return this.a; }.bind(this));": [
+        {
+          col: 4,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "foo(() => { return this.a; });"),
+        }
+      ],
+      "foo((function () { return this.a; }).bind(this));": [
+        {
+          col: 5,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "foo(() => { return this.a; });"),
+        }
+      ],
+      "foo(function () { return function () { return this; }; });": [
+        {
+          col: 4,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "foo(() => { return function () { return this; }; });"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      PreferArrowCallback {
+        allow_unbound_this: false,
+        ..Default::default()
+      },
+      "foo(function () { return this.a; });": [
+        {
+          col: 4,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn prefer_arrow_callback_ecma_version() {
+    let lint = |source: &str, ecma_version: EcmaVersion| {
+      let (_, diagnostics) = crate::testing::lint_with_ecma_version(
+        Box::new(PreferArrowCallback::default()),
+        source,
+        "file:///prefer_arrow_callback_test.ts",
+        ecma_version,
+      );
+      diagnostics
+    };
+    // Arrow functions are only available from ES2015 on.
+    let source = "foo(function (a) { return a; });";
+    assert!(lint(source, EcmaVersion::Es5).is_empty());
+    assert_eq!(lint(source, EcmaVersion::Es2015).len(), 1);
+  }
+}
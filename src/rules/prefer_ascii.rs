@@ -1,11 +1,32 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
-use super::{Context, LintRule};
+use super::{Context, LintRule, RuleDocs};
 use crate::Program;
-use deno_ast::SourceRange;
+use deno_ast::swc::parser::token::{Token, TokenAndSpan};
+use deno_ast::{SourcePos, SourceRange, SourceRangedForSpanned};
+use serde::Deserialize;
 
-#[derive(Debug)]
-pub struct PreferAscii;
+#[derive(Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct PreferAscii {
+  /// Checks identifiers, including keywords and JSX names.
+  pub identifiers: bool,
+  /// Checks string literals, template literals, regular expressions and JSX
+  /// text.
+  pub strings: bool,
+  /// Checks comments.
+  pub comments: bool,
+}
+
+impl Default for PreferAscii {
+  fn default() -> Self {
+    Self {
+      identifiers: true,
+      strings: true,
+      comments: true,
+    }
+  }
+}
 
 const CODE: &str = "prefer-ascii";
 const MESSAGE: &str = "Non-ASCII characters are not allowed";
@@ -17,27 +38,89 @@ fn hint(c: char) -> String {
   )
 }
 
+/// Where in the source a character is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Location {
+  Identifier,
+  String,
+  Comment,
+  /// Anywhere else, e.g. in whitespace between tokens.
+  Other,
+}
+
 impl LintRule for PreferAscii {
   fn code(&self) -> &'static str {
     CODE
   }
 
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows non-ASCII characters in the source, for \
+        codebases that need to stay ASCII-only. Each character is reported \
+        along with its code point. Identifiers, strings and comments can be \
+        allowed to contain non-ASCII characters with the `identifiers`, \
+        `strings` and `comments` options.",
+      invalid_examples: &["const π = Math.PI;", "// “smart quotes”"],
+      valid_examples: &["const pi = Math.PI;", "// \"plain quotes\""],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "identifiers": {
+          "description": "Checks identifiers, including keywords and JSX names",
+          "type": "boolean"
+        },
+        "strings": {
+          "description": "Checks string literals, template literals, regular expressions and JSX text",
+          "type": "boolean"
+        },
+        "comments": {
+          "description": "Checks comments",
+          "type": "boolean"
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<PreferAscii>(options)?))
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
     _program: Program<'_>,
   ) {
-    let mut not_asciis = Vec::new();
+    let tokens = context.tokens();
+    let mut comment_ranges = context
+      .all_comments()
+      .map(|comment| comment.range())
+      .collect::<Vec<_>>();
+    comment_ranges.sort_by_key(|range| range.start);
 
+    let mut not_asciis = Vec::new();
     let text_info = context.text_info();
-    let mut src_chars = text_info.text_str().char_indices().peekable();
     let start_pos = text_info.range().start;
-    while let Some((i, c)) = src_chars.next() {
-      if let Some(&(pi, _)) = src_chars.peek() {
-        if (pi > i + 1) || !c.is_ascii() {
-          let range = SourceRange::new(start_pos + i, start_pos + pi);
-          not_asciis.push((c, range));
-        }
+    for (i, c) in text_info.text_str().char_indices() {
+      if c.is_ascii() {
+        continue;
+      }
+      let range = SourceRange::new(start_pos + i, start_pos + i + c.len_utf8());
+      let is_checked = match location(range.start, tokens, &comment_ranges) {
+        Location::Identifier => self.identifiers,
+        Location::String => self.strings,
+        Location::Comment => self.comments,
+        Location::Other => true,
+      };
+      if is_checked {
+        not_asciis.push((c, range));
       }
     }
 
@@ -47,6 +130,32 @@ impl LintRule for PreferAscii {
   }
 }
 
+fn location(
+  pos: SourcePos,
+  tokens: &[TokenAndSpan],
+  comment_ranges: &[SourceRange],
+) -> Location {
+  let index = comment_ranges.partition_point(|range| range.end <= pos);
+  if comment_ranges
+    .get(index)
+    .is_some_and(|range| range.start <= pos)
+  {
+    return Location::Comment;
+  }
+  let index = tokens.partition_point(|token| token.end() <= pos);
+  match tokens.get(index) {
+    Some(token) if token.start() <= pos => match &token.token {
+      Token::Word(_) | Token::JSXName { .. } => Location::Identifier,
+      Token::Str { .. }
+      | Token::Template { .. }
+      | Token::Regex(..)
+      | Token::JSXText { .. } => Location::String,
+      _ => Location::Other,
+    },
+    _ => Location::Other,
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -54,7 +163,7 @@ mod tests {
   #[test]
   fn prefer_ascii_valid() {
     assert_lint_ok! {
-      PreferAscii,
+      PreferAscii::default(),
       r#"const pi = Math.PI;"#,
       r#"const ninja = "ninja";"#,
       r#"
@@ -70,7 +179,7 @@ function hello(name: string) {
   #[test]
   fn prefer_ascii_invalid() {
     assert_lint_err! {
-      PreferAscii,
+      PreferAscii::default(),
       r#"const π = Math.PI;"#: [
         {
           line: 1,
@@ -161,4 +270,84 @@ function hello(name: string) {
       ],
     };
   }
+
+  #[test]
+  fn prefer_ascii_options_valid() {
+    assert_lint_ok! {
+      PreferAscii {
+        identifiers: false,
+        ..Default::default()
+      },
+      "const π = Math.PI;",
+      "function こんにちは() {}",
+      "class A { #π = 3; }",
+    };
+
+    assert_lint_ok! {
+      PreferAscii {
+        strings: false,
+        ..Default::default()
+      },
+      r#"const ninja = "🥷";"#,
+      "const greeting = `こんにちは ${name}`;",
+      "const re = /π/;",
+    };
+
+    assert_lint_ok! {
+      PreferAscii {
+        comments: false,
+        ..Default::default()
+      },
+      "// “comments” are allowed",
+      "/* “comments” are allowed */ const a = 1;",
+    };
+  }
+
+  #[test]
+  fn prefer_ascii_options_invalid() {
+    assert_lint_err! {
+      PreferAscii {
+        identifiers: false,
+        ..Default::default()
+      },
+      r#"const π = "π";"#: [
+        {
+          col: 11,
+          message: MESSAGE,
+          hint: hint('π'),
+        },
+      ],
+    };
+
+    assert_lint_err! {
+      PreferAscii {
+        strings: false,
+        comments: false,
+        ..Default::default()
+      },
+      r#"const π = "π"; // π"#: [
+        {
+          col: 6,
+          message: MESSAGE,
+          hint: hint('π'),
+        },
+      ],
+    };
+
+    assert_lint_err! {
+      PreferAscii {
+        identifiers: false,
+        strings: false,
+        comments: false,
+      },
+      // Non-ASCII whitespace is always reported.
+      "const a\u{3000}= 1;": [
+        {
+          col: 7,
+          message: MESSAGE,
+          hint: hint('\u{3000}'),
+        },
+      ],
+    };
+  }
 }
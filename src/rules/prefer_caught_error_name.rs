@@ -0,0 +1,303 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::{Handler, Traverse};
+use crate::tags::{self, Tags};
+use crate::Program;
+use deno_ast::swc::ast::{AssignPatProp, Id, Ident, Prop};
+use deno_ast::swc::visit::{noop_visit_type, Visit, VisitWith};
+use deno_ast::view::{self as ast_view, Pat};
+use deno_ast::{SourceRange, SourceRanged, SourceRangedForSpanned};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct PreferCaughtErrorName {
+  /// The name the parameter of `catch` clauses has to have.
+  pub name: String,
+}
+
+impl Default for PreferCaughtErrorName {
+  fn default() -> Self {
+    PreferCaughtErrorName {
+      name: "error".to_string(),
+    }
+  }
+}
+
+const CODE: &str = "prefer-caught-error-name";
+
+impl LintRule for PreferCaughtErrorName {
+  fn tags(&self) -> Tags {
+    &[tags::STYLISTIC]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Enforces a consistent name for the parameter of `catch` \
+        clauses, `error` by default, so caught errors are easy to recognize \
+        and search for. The name is configured with the `name` option. \
+        Parameters starting with `_` are allowed, since they mark errors that \
+        are deliberately unused, and destructured parameters aren't checked. \
+        The fix renames the parameter and all of its references, unless the \
+        clause already uses the expected name for something else.",
+      invalid_examples: &[
+        "try {\n  run();\n} catch (e) {\n  console.error(e);\n}",
+      ],
+      valid_examples: &[
+        "try {\n  run();\n} catch (error) {\n  console.error(error);\n}",
+        "try {\n  run();\n} catch (_e) {\n  // Ignored.\n}",
+        "try {\n  run();\n} catch {\n  // Ignored.\n}",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "name": {
+          "description": "The name of the parameter of `catch` clauses. Defaults to `error`",
+          "type": "string"
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<PreferCaughtErrorName>(
+      options,
+    )?))
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    PreferCaughtErrorNameHandler { rule: self }.traverse(program, context);
+  }
+}
+
+struct PreferCaughtErrorNameHandler<'a> {
+  rule: &'a PreferCaughtErrorName,
+}
+
+impl Handler for PreferCaughtErrorNameHandler<'_> {
+  fn catch_clause(
+    &mut self,
+    catch_clause: &ast_view::CatchClause,
+    ctx: &mut Context,
+  ) {
+    let Some(Pat::Ident(param)) = catch_clause.param else {
+      return;
+    };
+    let param = param.id;
+    let name = self.rule.name.as_str();
+    if *param.sym() == *name || param.sym().starts_with('_') {
+      return;
+    }
+
+    let mut collector = ReferenceCollector {
+      id: param.inner.to_id(),
+      name,
+      references: vec![],
+      has_conflict: false,
+    };
+    catch_clause.inner.visit_with(&mut collector);
+
+    let message = format!(
+      "The parameter of the `catch` clause should be named `{}`",
+      name
+    );
+    let hint = format!("Rename `{}` to `{}`", param.sym(), name);
+    let fixes = if collector.has_conflict {
+      vec![]
+    } else {
+      vec![LintFix {
+        description: hint.clone().into(),
+        changes: collector
+          .references
+          .into_iter()
+          .map(|reference| LintFixChange {
+            new_text: if reference.is_shorthand {
+              format!("{}: {}", param.sym(), name).into()
+            } else {
+              name.to_string().into()
+            },
+            range: reference.range,
+          })
+          .collect(),
+      }]
+    };
+    ctx.add_diagnostic_with_fixes(
+      param.range(),
+      CODE,
+      message,
+      Some(hint),
+      fixes,
+    );
+  }
+}
+
+struct Reference {
+  range: SourceRange,
+  /// The reference is a shorthand property, e.g. `{ e }`, so the key has to
+  /// be kept when it's renamed.
+  is_shorthand: bool,
+}
+
+/// Collects the references of the parameter of a `catch` clause, and checks
+/// if the expected name is already used in the clause, where renaming the
+/// parameter would shadow or be shadowed by another variable.
+struct ReferenceCollector<'a> {
+  id: Id,
+  name: &'a str,
+  references: Vec<Reference>,
+  has_conflict: bool,
+}
+
+impl Visit for ReferenceCollector<'_> {
+  noop_visit_type!();
+
+  fn visit_ident(&mut self, ident: &Ident) {
+    if ident.to_id() == self.id {
+      self.references.push(Reference {
+        range: ident.range(),
+        is_shorthand: false,
+      });
+    } else if *ident.sym == *self.name {
+      self.has_conflict = true;
+    }
+  }
+
+  fn visit_prop(&mut self, prop: &Prop) {
+    match prop {
+      Prop::Shorthand(ident) if ident.to_id() == self.id => {
+        self.references.push(Reference {
+          range: ident.range(),
+          is_shorthand: true,
+        });
+      }
+      _ => prop.visit_children_with(self),
+    }
+  }
+
+  fn visit_assign_pat_prop(&mut self, prop: &AssignPatProp) {
+    if prop.key.to_id() == self.id {
+      self.references.push(Reference {
+        range: prop.key.range(),
+        is_shorthand: true,
+      });
+      prop.value.visit_with(self);
+    } else {
+      prop.visit_children_with(self);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_caught_error_name_valid() {
+    assert_lint_ok! {
+      PreferCaughtErrorName::default(),
+      "try {} catch (error) {}",
+      "try {} catch (error) { console.error(error); }",
+      "try {} catch {}",
+      "try {} catch (_) {}",
+      "try {} catch (_e) {}",
+      "try {} catch ({ message }) { console.error(message); }",
+      "promise.catch((e) => console.error(e));",
+    };
+    assert_lint_ok! {
+      PreferCaughtErrorName { name: "err".to_string() },
+      "try {} catch (err) { throw err; }",
+    };
+  }
+
+  #[test]
+  fn prefer_caught_error_name_invalid() {
+    assert_lint_err! {
+      PreferCaughtErrorName::default(),
+      "try {} catch (e) {}": [
+        {
+          col: 14,
+          message: "The parameter of the `catch` clause should be named `error`",
+          hint: "Rename `e` to `error`",
+          fix: ("Rename `e` to `error`", "try {} catch (error) {}"),
+        }
+      ],
+      "try {} catch (e) { console.error(e); throw new Error('Failed', { cause: e }); }": [
+        {
+          col: 14,
+          message: "The parameter of the `catch` clause should be named `error`",
+          hint: "Rename `e` to `error`",
+          fix: (
+            "Rename `e` to `error`",
+            "try {} catch (error) { console.error(error); throw new Error('Failed', { cause: error }); }"
+          ),
+        }
+      ],
+      "try {} catch (e) { log({ e }); }": [
+        {
+          col: 14,
+          message: "The parameter of the `catch` clause should be named `error`",
+          hint: "Rename `e` to `error`",
+          fix: ("Rename `e` to `error`", "try {} catch (error) { log({ e: error }); }"),
+        }
+      ],
+      "try {} catch (e) { const f = () => e; f(); }": [
+        {
+          col: 14,
+          message: "The parameter of the `catch` clause should be named `error`",
+          hint: "Rename `e` to `error`",
+          fix: ("Rename `e` to `error`", "try {} catch (error) { const f = () => error; f(); }"),
+        }
+      ],
+      // A shadowing variable of the same name isn't renamed.
+      "try {} catch (e) { [1].map((e) => e); throw e; }": [
+        {
+          col: 14,
+          message: "The parameter of the `catch` clause should be named `error`",
+          hint: "Rename `e` to `error`",
+          fix: ("Rename `e` to `error`", "try {} catch (error) { [1].map((e) => e); throw error; }"),
+        }
+      ],
+      // The expected name is already used in the clause.
+      "const error = 1; try {} catch (e) { console.log(error, e); }": [
+        {
+          col: 31,
+          message: "The parameter of the `catch` clause should be named `error`",
+          hint: "Rename `e` to `error`",
+        }
+      ],
+    };
+    assert_lint_err! {
+      PreferCaughtErrorName { name: "err".to_string() },
+      "try {} catch (error) { throw error; }": [
+        {
+          col: 14,
+          message: "The parameter of the `catch` clause should be named `err`",
+          hint: "Rename `error` to `err`",
+          fix: ("Rename `error` to `err`", "try {} catch (err) { throw err; }"),
+        }
+      ],
+    };
+  }
+}
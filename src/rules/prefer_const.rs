@@ -2,6 +2,7 @@
 
 use super::program_ref;
 use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
 use crate::tags::{self, Tags};
 use crate::Program;
 use crate::ProgramRef;
@@ -21,16 +22,33 @@ use deno_ast::swc::visit::{Visit, VisitWith};
 use deno_ast::SourceRangedForSpanned;
 use deno_ast::{SourceRange, SourceRanged};
 use derive_more::Display;
+use serde::Deserialize;
 use std::cell::RefCell;
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::iter;
 use std::mem;
 use std::rc::Rc;
 
-#[derive(Debug)]
-pub struct PreferConst;
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct PreferConst {
+  pub destructuring: Destructuring,
+}
+
+/// How variables declared by the same destructuring pattern are reported.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Destructuring {
+  /// Reports the variables that are never reassigned, even if others of the
+  /// same pattern are.
+  Any,
+  /// Reports the variables only if none of the same pattern are reassigned.
+  #[default]
+  All,
+}
 
 const CODE: &str = "prefer-const";
+const FIX_DESC: &str = "Replace `let` with `const`";
 
 #[derive(Display)]
 enum PreferConstMessage {
@@ -53,6 +71,30 @@ impl LintRule for PreferConst {
     CODE
   }
 
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "destructuring": {
+          "description": "With `any`, the variables of a destructuring pattern that are never reassigned are reported even if others of the pattern are. With `all`, they are reported only if none of them are reassigned",
+          "enum": ["any", "all"]
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<PreferConst>(options)?))
+  }
+
   fn lint_program_with_ast_view<'view>(
     &self,
     context: &mut Context<'view>,
@@ -60,6 +102,7 @@ impl LintRule for PreferConst {
   ) {
     let program = program_ref(program);
     let mut collector = VariableCollector::new();
+    collector.group_destructuring = self.destructuring == Destructuring::All;
     match program {
       ProgramRef::Module(m) => collector.visit_module(m),
       ProgramRef::Script(s) => collector.visit_script(s),
@@ -69,6 +112,7 @@ impl LintRule for PreferConst {
       context,
       mem::take(&mut collector.scopes),
       mem::take(&mut collector.var_groups),
+      mem::take(&mut collector.let_decls),
     );
     match program {
       ProgramRef::Module(m) => visitor.visit_module(m),
@@ -257,11 +301,44 @@ enum ScopeRange {
   Block(SourceRange),
 }
 
+/// A `let` declaration, used to fix the declarations whose variables are all
+/// reported.
+#[derive(Debug)]
+struct LetDecl {
+  /// Range of the `let` keyword.
+  keyword: SourceRange,
+  /// Ranges of the declared variables.
+  idents: Vec<SourceRange>,
+  /// `false` if `const` can't be used even if no variable is reassigned,
+  /// e.g. because a declarator has no initializer.
+  fixable: bool,
+}
+
+impl LetDecl {
+  fn new(var_decl: &VarDecl, is_loop_head: bool) -> Self {
+    let mut idents = Vec::new();
+    for decl in &var_decl.decls {
+      extract_idents_from_pat(&mut idents, &decl.name);
+    }
+    let start = var_decl.start();
+    Self {
+      keyword: SourceRange::new(start, start + "let".len()),
+      idents: idents.into_iter().map(|ident| ident.range()).collect(),
+      fixable: !var_decl.declare
+        && (is_loop_head || var_decl.decls.iter().all(|d| d.init.is_some())),
+    }
+  }
+}
+
 #[derive(Debug)]
 struct VariableCollector {
   scopes: BTreeMap<ScopeRange, Scope>,
   cur_scope: ScopeRange,
   var_groups: DisjointSet,
+  /// Whether the variables of a destructuring pattern are grouped, see
+  /// `Destructuring::All`.
+  group_destructuring: bool,
+  let_decls: Vec<LetDecl>,
 }
 
 impl VariableCollector {
@@ -270,6 +347,8 @@ impl VariableCollector {
       scopes: BTreeMap::new(),
       cur_scope: ScopeRange::Global,
       var_groups: DisjointSet::new(),
+      group_destructuring: true,
+      let_decls: Vec::new(),
     }
   }
 
@@ -306,7 +385,13 @@ impl VariableCollector {
 
     let mut idents = Vec::new();
     extract_idents_from_pat(&mut idents, pat);
-    self.insert_vars(&idents, status);
+    if self.group_destructuring {
+      self.insert_vars(&idents, status);
+    } else {
+      for ident in idents {
+        self.insert_var(ident, status);
+      }
+    }
   }
 
   fn with_child_scope<F, S>(&mut self, node: S, op: F)
@@ -393,6 +478,7 @@ impl Visit for VariableCollector {
         Some(VarDeclOrExpr::VarDecl(var_decl)) => {
           var_decl.visit_children_with(a);
           if var_decl.kind == VarDeclKind::Let {
+            a.let_decls.push(LetDecl::new(var_decl, false));
             let mut idents = Vec::new();
             let mut has_init = false;
             for decl in &var_decl.decls {
@@ -432,6 +518,7 @@ impl Visit for VariableCollector {
     self.with_child_scope(for_of_stmt, |a| {
       if let ForHead::VarDecl(var_decl) = &for_of_stmt.left {
         if var_decl.kind == VarDeclKind::Let {
+          a.let_decls.push(LetDecl::new(var_decl, true));
           for decl in &var_decl.decls {
             a.extract_decl_idents(&decl.name, true);
           }
@@ -452,6 +539,7 @@ impl Visit for VariableCollector {
     self.with_child_scope(for_in_stmt, |a| {
       if let ForHead::VarDecl(var_decl) = &for_in_stmt.left {
         if var_decl.kind == VarDeclKind::Let {
+          a.let_decls.push(LetDecl::new(var_decl, true));
           for decl in &var_decl.decls {
             a.extract_decl_idents(&decl.name, true);
           }
@@ -595,6 +683,7 @@ impl Visit for VariableCollector {
   fn visit_var_decl(&mut self, var_decl: &VarDecl) {
     var_decl.visit_children_with(self);
     if var_decl.kind == VarDeclKind::Let {
+      self.let_decls.push(LetDecl::new(var_decl, false));
       for decl in &var_decl.decls {
         self.extract_decl_idents(&decl.name, decl.init.is_some());
       }
@@ -606,6 +695,7 @@ struct PreferConstVisitor<'c, 'view> {
   scopes: BTreeMap<ScopeRange, Scope>,
   cur_scope: ScopeRange,
   var_groups: DisjointSet,
+  let_decls: Vec<LetDecl>,
   context: &'c mut Context<'view>,
   scope_analysis_error_occurred: bool,
 }
@@ -704,26 +794,64 @@ impl<'c, 'view> PreferConstVisitor<'c, 'view> {
     context: &'c mut Context<'view>,
     scopes: BTreeMap<ScopeRange, Scope>,
     var_groups: DisjointSet,
+    let_decls: Vec<LetDecl>,
   ) -> Self {
     Self {
       context,
       scopes,
       var_groups,
+      let_decls,
       cur_scope: ScopeRange::Global,
       scope_analysis_error_occurred: false,
     }
   }
 
-  fn report(&mut self, range: SourceRange) {
+  fn report_all(&mut self) {
+    let ranges = self.var_groups.dump();
+    let reported = ranges.iter().copied().collect::<HashSet<_>>();
+    for range in ranges {
+      let fixes = self.fix(range, &reported).into_iter().collect();
+      self.report(range, fixes);
+    }
+  }
+
+  fn report(&mut self, range: SourceRange, fixes: Vec<LintFix>) {
     let range_text = range.text_fast(self.context.text_info()).to_string();
-    self.context.add_diagnostic_with_hint(
+    self.context.add_diagnostic_with_fixes(
       range,
       CODE,
       PreferConstMessage::NeverReassigned(range_text),
-      PreferConstHint::UseConst,
+      Some(PreferConstHint::UseConst.to_string()),
+      fixes,
     );
   }
 
+  /// Replaces `let` with `const` if every variable of the declaration is
+  /// reported.
+  fn fix(
+    &self,
+    range: SourceRange,
+    reported: &HashSet<SourceRange>,
+  ) -> Option<LintFix> {
+    let let_decl = self
+      .let_decls
+      .iter()
+      .find(|let_decl| let_decl.idents.contains(&range))?;
+    if !let_decl.fixable
+      || !let_decl.idents.iter().all(|ident| reported.contains(ident))
+      || let_decl.keyword.text_fast(self.context.text_info()) != "let"
+    {
+      return None;
+    }
+    Some(LintFix {
+      description: FIX_DESC.into(),
+      changes: vec![LintFixChange {
+        new_text: "const".into(),
+        range: let_decl.keyword,
+      }],
+    })
+  }
+
   fn with_child_scope<F, S>(&mut self, node: &S, op: F)
   where
     S: SourceRangedForSpanned,
@@ -801,17 +929,13 @@ impl<'c, 'view> Visit for PreferConstVisitor<'c, 'view> {
   fn visit_module(&mut self, module: &Module) {
     module.visit_children_with(self);
     // After visiting all nodes, reports errors.
-    for range in self.var_groups.dump() {
-      self.report(range);
-    }
+    self.report_all();
   }
 
   fn visit_script(&mut self, script: &Script) {
     script.visit_children_with(self);
     // After visiting all nodes, reports errors.
-    for range in self.var_groups.dump() {
-      self.report(range);
-    }
+    self.report_all();
   }
 
   fn visit_assign_expr(&mut self, assign_expr: &AssignExpr) {
@@ -1848,7 +1972,7 @@ mod prefer_const_tests {
   #[test]
   fn prefer_const_valid() {
     assert_lint_ok! {
-      PreferConst,
+      PreferConst::default(),
       r#"var x = 0;"#,
       r#"let x;"#,
       r#"let x = 0; x += 1;"#,
@@ -2003,12 +2127,13 @@ mod prefer_const_tests {
   #[test]
   fn prefer_const_invalid() {
     assert_lint_err! {
-      PreferConst,
+      PreferConst::default(),
       r#"let x = 1;"#: [
         {
           col: 4,
           message: variant!(PreferConstMessage, NeverReassigned, "x"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"const x = 1;"#),
         }
       ],
       r#"let x = 1; foo(x);"#: [
@@ -2016,6 +2141,7 @@ mod prefer_const_tests {
           col: 4,
           message: variant!(PreferConstMessage, NeverReassigned, "x"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"const x = 1; foo(x);"#),
         }
       ],
       r#"for (let i in [1,2,3]) { foo(i); }"#: [
@@ -2023,6 +2149,7 @@ mod prefer_const_tests {
           col: 9,
           message: variant!(PreferConstMessage, NeverReassigned, "i"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"for (const i in [1,2,3]) { foo(i); }"#),
         }
       ],
       r#"for (let x of [1,2,3]) { foo(x); }"#: [
@@ -2030,6 +2157,7 @@ mod prefer_const_tests {
           col: 9,
           message: variant!(PreferConstMessage, NeverReassigned, "x"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"for (const x of [1,2,3]) { foo(x); }"#),
         }
       ],
       r#"(function() { let x = 1; foo(x); })();"#: [
@@ -2037,6 +2165,7 @@ mod prefer_const_tests {
           col: 18,
           message: variant!(PreferConstMessage, NeverReassigned, "x"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"(function() { const x = 1; foo(x); })();"#),
         }
       ],
       r#"(function() { for (let i in [1,2,3]) { foo(i); } })();"#: [
@@ -2044,6 +2173,7 @@ mod prefer_const_tests {
           col: 23,
           message: variant!(PreferConstMessage, NeverReassigned, "i"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"(function() { for (const i in [1,2,3]) { foo(i); } })();"#),
         }
       ],
       r#"(function() { for (let x of [1,2,3]) { foo(x); } })();"#: [
@@ -2051,6 +2181,7 @@ mod prefer_const_tests {
           col: 23,
           message: variant!(PreferConstMessage, NeverReassigned, "x"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"(function() { for (const x of [1,2,3]) { foo(x); } })();"#),
         }
       ],
       r#"let f = (function() { let g = x; })(); f = 1;"#: [
@@ -2058,6 +2189,7 @@ mod prefer_const_tests {
           col: 26,
           message: variant!(PreferConstMessage, NeverReassigned, "g"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"let f = (function() { const g = x; })(); f = 1;"#),
         }
       ],
       r#"let x = 0; { let x = 1; foo(x); } x = 0;"#: [
@@ -2065,6 +2197,7 @@ mod prefer_const_tests {
           col: 17,
           message: variant!(PreferConstMessage, NeverReassigned, "x"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"let x = 0; { const x = 1; foo(x); } x = 0;"#),
         }
       ],
       r#"for (let i = 0; i < 10; ++i) { let x = 1; foo(x); }"#: [
@@ -2072,6 +2205,7 @@ mod prefer_const_tests {
           col: 35,
           message: variant!(PreferConstMessage, NeverReassigned, "x"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"for (let i = 0; i < 10; ++i) { const x = 1; foo(x); }"#),
         }
       ],
       r#"for (let i in [1,2,3]) { let x = 1; foo(x); }"#: [
@@ -2079,11 +2213,13 @@ mod prefer_const_tests {
           col: 9,
           message: variant!(PreferConstMessage, NeverReassigned, "i"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"for (const i in [1,2,3]) { let x = 1; foo(x); }"#),
         },
         {
           col: 29,
           message: variant!(PreferConstMessage, NeverReassigned, "x"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"for (let i in [1,2,3]) { const x = 1; foo(x); }"#),
         }
       ],
       r#"
@@ -2142,6 +2278,7 @@ var foo = function() {
           col: 5,
           message: variant!(PreferConstMessage, NeverReassigned, "a"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"const [a] = [1]"#),
         }
       ],
       r#"let {a} = obj"#: [
@@ -2149,6 +2286,7 @@ var foo = function() {
           col: 5,
           message: variant!(PreferConstMessage, NeverReassigned, "a"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"const {a} = obj"#),
         }
       ],
       r#"let {a = 0, b} = obj, c = a; b = a;"#: [
@@ -2170,6 +2308,7 @@ var foo = function() {
           col: 27,
           message: variant!(PreferConstMessage, NeverReassigned, "x"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"/*eslint use-x:error*/ const x = 1"#),
         }
       ],
       r#"/*eslint use-x:error*/ { let x = 1 }"#: [
@@ -2177,6 +2316,7 @@ var foo = function() {
           col: 29,
           message: variant!(PreferConstMessage, NeverReassigned, "x"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"/*eslint use-x:error*/ { const x = 1 }"#),
         }
       ],
       r#"let { foo, bar } = baz;"#: [
@@ -2184,11 +2324,13 @@ var foo = function() {
           col: 6,
           message: variant!(PreferConstMessage, NeverReassigned, "foo"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"const { foo, bar } = baz;"#),
         },
         {
           col: 11,
           message: variant!(PreferConstMessage, NeverReassigned, "bar"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"const { foo, bar } = baz;"#),
         }
       ],
       r#"const x = [1,2]; let [,y] = x;"#: [
@@ -2196,6 +2338,7 @@ var foo = function() {
           col: 23,
           message: variant!(PreferConstMessage, NeverReassigned, "y"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"const x = [1,2]; const [,y] = x;"#),
         }
       ],
       r#"const x = [1,2,3]; let [y,,z] = x;"#: [
@@ -2203,11 +2346,13 @@ var foo = function() {
           col: 24,
           message: variant!(PreferConstMessage, NeverReassigned, "y"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"const x = [1,2,3]; const [y,,z] = x;"#),
         },
         {
           col: 27,
           message: variant!(PreferConstMessage, NeverReassigned, "z"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"const x = [1,2,3]; const [y,,z] = x;"#),
         }
       ],
       r#"let predicate; [, {foo:returnType, predicate}] = foo();"#: [
@@ -2236,11 +2381,13 @@ var foo = function() {
           col: 4,
           message: variant!(PreferConstMessage, NeverReassigned, "x"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"const x = 'x', y = 'y';"#),
         },
         {
           col: 13,
           message: variant!(PreferConstMessage, NeverReassigned, "y"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"const x = 'x', y = 'y';"#),
         }
       ],
       r#"let x = 'x', y = 'y'; x = 1"#: [
@@ -2255,16 +2402,19 @@ var foo = function() {
           col: 4,
           message: variant!(PreferConstMessage, NeverReassigned, "x"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"const x = 1, y = 'y'; let z = 1;"#),
         },
         {
           col: 11,
           message: variant!(PreferConstMessage, NeverReassigned, "y"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"const x = 1, y = 'y'; let z = 1;"#),
         },
         {
           col: 24,
           message: variant!(PreferConstMessage, NeverReassigned, "z"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"let x = 1, y = 'y'; const z = 1;"#),
         }
       ],
       r#"let { a, b, c } = obj; let { x, y, z } = anotherObj; x = 2;"#: [
@@ -2272,16 +2422,19 @@ var foo = function() {
           col: 6,
           message: variant!(PreferConstMessage, NeverReassigned, "a"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"const { a, b, c } = obj; let { x, y, z } = anotherObj; x = 2;"#),
         },
         {
           col: 9,
           message: variant!(PreferConstMessage, NeverReassigned, "b"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"const { a, b, c } = obj; let { x, y, z } = anotherObj; x = 2;"#),
         },
         {
           col: 12,
           message: variant!(PreferConstMessage, NeverReassigned, "c"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"const { a, b, c } = obj; let { x, y, z } = anotherObj; x = 2;"#),
         },
       ],
       r#"let x = 'x', y = 'y'; function someFunc() { let a = 1, b = 2; foo(a, b) }"#: [
@@ -2289,21 +2442,25 @@ var foo = function() {
           col: 4,
           message: variant!(PreferConstMessage, NeverReassigned, "x"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"const x = 'x', y = 'y'; function someFunc() { let a = 1, b = 2; foo(a, b) }"#),
         },
         {
           col: 13,
           message: variant!(PreferConstMessage, NeverReassigned, "y"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"const x = 'x', y = 'y'; function someFunc() { let a = 1, b = 2; foo(a, b) }"#),
         },
         {
           col: 48,
           message: variant!(PreferConstMessage, NeverReassigned, "a"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"let x = 'x', y = 'y'; function someFunc() { const a = 1, b = 2; foo(a, b) }"#),
         },
         {
           col: 55,
           message: variant!(PreferConstMessage, NeverReassigned, "b"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"let x = 'x', y = 'y'; function someFunc() { const a = 1, b = 2; foo(a, b) }"#),
         }
       ],
       r#"let someFunc = () => { let a = 1, b = 2; foo(a, b) }"#: [
@@ -2311,16 +2468,19 @@ var foo = function() {
           col: 4,
           message: variant!(PreferConstMessage, NeverReassigned, "someFunc"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"const someFunc = () => { let a = 1, b = 2; foo(a, b) }"#),
         },
         {
           col: 27,
           message: variant!(PreferConstMessage, NeverReassigned, "a"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"let someFunc = () => { const a = 1, b = 2; foo(a, b) }"#),
         },
         {
           col: 34,
           message: variant!(PreferConstMessage, NeverReassigned, "b"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"let someFunc = () => { const a = 1, b = 2; foo(a, b) }"#),
         }
       ],
       r#"let {a, b} = c, d;"#: [
@@ -2415,10 +2575,60 @@ e = 2;
     };
   }
 
+  #[test]
+  fn prefer_const_destructuring() {
+    let any = || PreferConst {
+      destructuring: Destructuring::Any,
+    };
+
+    assert_lint_ok! {
+      PreferConst::default(),
+      r#"let {a, b} = obj; b = 0;"#,
+      r#"let [x, y] = arr; x++;"#,
+    };
+    assert_lint_ok! {
+      any(),
+      r#"let {a, b} = obj; a = 0; b = 0;"#,
+      r#"let a, b; ({a, b} = obj); b = 0;"#,
+    };
+
+    assert_lint_err! {
+      any(),
+      r#"let {a, b} = obj; b = 0;"#: [
+        {
+          col: 5,
+          message: variant!(PreferConstMessage, NeverReassigned, "a"),
+          hint: PreferConstHint::UseConst,
+        }
+      ],
+      r#"let [x, y] = arr; x++;"#: [
+        {
+          col: 8,
+          message: variant!(PreferConstMessage, NeverReassigned, "y"),
+          hint: PreferConstHint::UseConst,
+        }
+      ],
+      r#"let {a, b} = obj;"#: [
+        {
+          col: 5,
+          message: variant!(PreferConstMessage, NeverReassigned, "a"),
+          hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"const {a, b} = obj;"#),
+        },
+        {
+          col: 8,
+          message: variant!(PreferConstMessage, NeverReassigned, "b"),
+          hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, r#"const {a, b} = obj;"#),
+        }
+      ],
+    };
+  }
+
   #[test]
   fn issue1145_panic_while_scope_analysis() {
     test_util::assert_lint_not_panic(
-      Box::new(PreferConst),
+      Box::new(PreferConst::default()),
       r#"
 for await (let [[...x] = function() { initCount += 1; }()] of [[values]]) {
   assert(Array.isArray(x));
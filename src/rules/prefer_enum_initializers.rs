@@ -0,0 +1,313 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::{Handler, Traverse};
+use crate::tags::Tags;
+use crate::Program;
+use deno_ast::swc::ast::{Expr, Lit, TsEnumMember, UnaryOp};
+use deno_ast::{
+  view as ast_view, SourceRange, SourceRanged, SourceRangedForSpanned,
+};
+use derive_more::Display;
+use serde::Deserialize;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct PreferEnumInitializers {
+  /// The kind of value to initialize members with.
+  pub style: InitializerStyle,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum InitializerStyle {
+  /// Initialize members with the number they implicitly have, which keeps
+  /// the values of the enum unchanged.
+  #[default]
+  Number,
+  /// Initialize members with their name.
+  String,
+}
+
+const CODE: &str = "prefer-enum-initializers";
+const HINT: &str = "Initialize the member with an explicit value";
+
+#[derive(Display)]
+enum PreferEnumInitializersMessage {
+  #[display(
+    fmt = "The value of the member `{}` should be explicitly defined",
+    _0
+  )]
+  Uninitialized(String),
+}
+
+#[derive(Display)]
+enum PreferEnumInitializersFixDesc {
+  #[display(fmt = "Initialize the member with `{}`", _0)]
+  Initialize(String),
+}
+
+impl LintRule for PreferEnumInitializers {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Requires every enum member to be explicitly initialized. \
+        Members without an initializer get the value of the previous member \
+        plus one, so adding or reordering members silently changes the \
+        values of the members that follow, which breaks values that were \
+        stored or sent elsewhere. With the default `number` style, members \
+        are fixed by initializing them with the value they implicitly have. \
+        With the `string` style, initializing members with their name is \
+        offered as a suggestion instead, since it changes their values.",
+      invalid_examples: &[
+        "enum Status {\n  Open,\n  Close,\n}",
+        "enum Direction {\n  Up = 1,\n  Down,\n}",
+      ],
+      valid_examples: &[
+        "enum Status {\n  Open = 1,\n  Close = 2,\n}",
+        "enum Color {\n  Red = \"Red\",\n  Green = \"Green\",\n}",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "style": {
+          "description": "The kind of value to initialize members with: `number` keeps the implicit value of the member, `string` uses the name of the member",
+          "type": "string",
+          "enum": ["number", "string"],
+          "default": "number"
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<PreferEnumInitializers>(
+      options,
+    )?))
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    PreferEnumInitializersHandler { style: self.style }
+      .traverse(program, context);
+  }
+}
+
+struct PreferEnumInitializersHandler {
+  style: InitializerStyle,
+}
+
+impl Handler for PreferEnumInitializersHandler {
+  fn ts_enum_decl(
+    &mut self,
+    enum_decl: &ast_view::TsEnumDecl,
+    ctx: &mut Context,
+  ) {
+    // The value of the previous member, if it is a known number.
+    let mut previous = Some(-1.0);
+    for member in &enum_decl.inner.members {
+      let Some(init) = &member.init else {
+        let value = previous.map(|value| value + 1.0);
+        self.report(ctx, member, value);
+        previous = value;
+        continue;
+      };
+      previous = number_value(init);
+    }
+  }
+}
+
+impl PreferEnumInitializersHandler {
+  fn report(
+    &self,
+    ctx: &mut Context,
+    member: &TsEnumMember,
+    implicit_value: Option<f64>,
+  ) {
+    let range = member.range();
+    let name = member.id.range().text_fast(ctx.text_info()).to_string();
+    let message =
+      PreferEnumInitializersMessage::Uninitialized(name.clone()).to_string();
+    let hint = Some(HINT.to_string());
+    match self.style {
+      InitializerStyle::Number => {
+        let fixes = implicit_value
+          .and_then(format_number)
+          .map(|value| vec![initialize(range, value)])
+          .unwrap_or_default();
+        ctx.add_diagnostic_with_fixes(range, CODE, message, hint, fixes);
+      }
+      InitializerStyle::String => {
+        // The name of a member is either an identifier or a string literal,
+        // so quoting an identifier gives a valid string literal.
+        let value = if name.starts_with(['"', '\'']) {
+          name
+        } else {
+          format!("\"{}\"", name)
+        };
+        ctx.add_diagnostic_with_suggestions(
+          range,
+          CODE,
+          message,
+          hint,
+          vec![initialize(range, value)],
+        );
+      }
+    }
+  }
+}
+
+fn initialize(range: SourceRange, value: String) -> LintFix {
+  LintFix {
+    description: PreferEnumInitializersFixDesc::Initialize(value.clone())
+      .to_string()
+      .into(),
+    changes: vec![LintFixChange {
+      new_text: format!(" = {}", value).into(),
+      range: SourceRange::new(range.end(), range.end()),
+    }],
+  }
+}
+
+/// Returns the value of an initializer that is a number literal, optionally
+/// negated.
+fn number_value(expr: &Expr) -> Option<f64> {
+  match expr {
+    Expr::Lit(Lit::Num(num)) => Some(num.value),
+    Expr::Unary(unary) if unary.op == UnaryOp::Minus => {
+      number_value(&unary.arg).map(|value| -value)
+    }
+    Expr::Unary(unary) if unary.op == UnaryOp::Plus => number_value(&unary.arg),
+    Expr::Paren(paren) => number_value(&paren.expr),
+    _ => None,
+  }
+}
+
+/// Formats integers that can be represented exactly, which are the only
+/// values that are safe to write back into the source.
+fn format_number(value: f64) -> Option<String> {
+  if value.fract() == 0.0 && value.abs() <= 9007199254740991.0 {
+    Some(format!("{}", value as i64))
+  } else {
+    None
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn message(name: &str) -> String {
+    PreferEnumInitializersMessage::Uninitialized(name.to_string()).to_string()
+  }
+
+  #[test]
+  fn prefer_enum_initializers_valid() {
+    assert_lint_ok! {
+      PreferEnumInitializers::default(),
+      "enum E {}",
+      "enum E { A = 0, B = 1 }",
+      "enum E { A = 'A', B = 'B' }",
+      "const enum E { A = 1 << 0, B = 1 << 1 }",
+      "declare enum E { A = 1 }",
+    };
+  }
+
+  #[test]
+  fn prefer_enum_initializers_invalid() {
+    assert_lint_err! {
+      PreferEnumInitializers::default(),
+      "enum E { A }": [
+        {
+          col: 9,
+          hint: HINT,
+          message: message("A"),
+          fix: ("Initialize the member with `0`", "enum E { A = 0 }"),
+        }
+      ],
+      "enum E { A = 1, B }": [
+        {
+          col: 16,
+          hint: HINT,
+          message: message("B"),
+          fix: ("Initialize the member with `2`", "enum E { A = 1, B = 2 }"),
+        }
+      ],
+      "enum E { A = -2, B, 'c' }": [
+        {
+          col: 17,
+          hint: HINT,
+          message: message("B"),
+          fix: ("Initialize the member with `-1`", "enum E { A = -2, B = -1, 'c' }"),
+        },
+        {
+          col: 20,
+          hint: HINT,
+          message: message("'c'"),
+          fix: ("Initialize the member with `0`", "enum E { A = -2, B, 'c' = 0 }"),
+        }
+      ],
+      // The implicit value of `B` isn't known.
+      "enum E { A = f(), B }": [
+        {
+          col: 18,
+          hint: HINT,
+          message: message("B"),
+        }
+      ],
+      "enum E { A = 1.5, B }": [
+        {
+          col: 18,
+          hint: HINT,
+          message: message("B"),
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn prefer_enum_initializers_string_style() {
+    assert_lint_err! {
+      PreferEnumInitializers { style: InitializerStyle::String },
+      "enum E { A, B = 'B' }": [
+        {
+          col: 9,
+          hint: HINT,
+          message: message("A"),
+          suggestion: ("Initialize the member with `\"A\"`", "enum E { A = \"A\", B = 'B' }"),
+        }
+      ],
+      "enum E { 'a-b' }": [
+        {
+          col: 9,
+          hint: HINT,
+          message: message("'a-b'"),
+          suggestion: ("Initialize the member with `'a-b'`", "enum E { 'a-b' = 'a-b' }"),
+        }
+      ],
+    };
+  }
+}
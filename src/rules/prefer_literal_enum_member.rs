@@ -0,0 +1,231 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::handler::{Handler, Traverse};
+use crate::tags::Tags;
+use crate::Program;
+use deno_ast::swc::ast::{
+  BinaryOp, Expr, Lit, MemberProp, TsEnumMemberId, UnaryOp,
+};
+use deno_ast::{view as ast_view, SourceRangedForSpanned};
+use serde::Deserialize;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct PreferLiteralEnumMember {
+  /// Allows bitwise expressions of literals and other members of the same
+  /// enum, e.g. `Write = 1 << 1` or `ReadWrite = Read | Write`.
+  pub allow_bitwise_expressions: bool,
+}
+
+const CODE: &str = "prefer-literal-enum-member";
+const MESSAGE: &str =
+  "Explicit enum value must only be a literal value (string or number)";
+const HINT: &str =
+  "Replace the initializer with a string literal or a number literal";
+
+impl LintRule for PreferLiteralEnumMember {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Requires the initializers of enum members to be literal \
+        values. Members initialized with computed values, like function \
+        calls or references to variables, have values that aren't known \
+        when reading the enum, and they can't be used as types. String \
+        literals, number literals, negative numbers and template literals \
+        without expressions are allowed. With the `allowBitwiseExpressions` \
+        option, bitwise expressions of literals and other members of the \
+        same enum are allowed as well, which is common for flags.",
+      invalid_examples: &[
+        "const str = \"Test\";\nenum Invalid {\n  A = str,\n  B = {},\n  C = `A template literal string with ${str}`,\n  D = new Set(1),\n}",
+      ],
+      valid_examples: &[
+        "enum Valid {\n  A,\n  B = \"TestStr\",\n  C = 4,\n  D = -1,\n  E = `A template literal string`,\n}",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "allowBitwiseExpressions": {
+          "description": "Allows bitwise expressions of literals and other members of the same enum, e.g. `ReadWrite = Read | Write`",
+          "type": "boolean",
+          "default": false
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<PreferLiteralEnumMember>(
+      options,
+    )?))
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program<'_>,
+  ) {
+    PreferLiteralEnumMemberHandler { rule: self }.traverse(program, context);
+  }
+}
+
+struct PreferLiteralEnumMemberHandler<'a> {
+  rule: &'a PreferLiteralEnumMember,
+}
+
+impl Handler for PreferLiteralEnumMemberHandler<'_> {
+  fn ts_enum_decl(
+    &mut self,
+    enum_decl: &ast_view::TsEnumDecl,
+    ctx: &mut Context,
+  ) {
+    let enum_name: &str = enum_decl.id.sym();
+    let member_names = enum_decl
+      .inner
+      .members
+      .iter()
+      .filter_map(|member| match &member.id {
+        TsEnumMemberId::Ident(ident) => Some(ident.sym.as_ref()),
+        TsEnumMemberId::Str(_) => None,
+      })
+      .collect::<Vec<_>>();
+
+    for member in &enum_decl.inner.members {
+      let Some(init) = &member.init else {
+        continue;
+      };
+      let is_allowed = is_literal(init)
+        || (self.rule.allow_bitwise_expressions
+          && is_bitwise_expression(init, enum_name, &member_names));
+      if !is_allowed {
+        ctx.add_diagnostic_with_hint(init.range(), CODE, MESSAGE, HINT);
+      }
+    }
+  }
+}
+
+fn is_literal(expr: &Expr) -> bool {
+  match expr {
+    Expr::Lit(Lit::Str(_) | Lit::Num(_)) => true,
+    Expr::Tpl(tpl) => tpl.exprs.is_empty(),
+    Expr::Unary(unary) => {
+      matches!(unary.op, UnaryOp::Minus | UnaryOp::Plus)
+        && matches!(&*unary.arg, Expr::Lit(Lit::Num(_)))
+    }
+    Expr::Paren(paren) => is_literal(&paren.expr),
+    _ => false,
+  }
+}
+
+/// Checks if the expression only consists of bitwise operators applied to
+/// number literals and references to members of the same enum.
+fn is_bitwise_expression(
+  expr: &Expr,
+  enum_name: &str,
+  member_names: &[&str],
+) -> bool {
+  match expr {
+    Expr::Lit(Lit::Num(_)) => true,
+    Expr::Ident(ident) => member_names.contains(&ident.sym.as_ref()),
+    Expr::Member(member) => {
+      matches!(&*member.obj, Expr::Ident(obj) if &*obj.sym == enum_name)
+        && matches!(
+          &member.prop,
+          MemberProp::Ident(prop) if member_names.contains(&prop.sym.as_ref())
+        )
+    }
+    Expr::Unary(unary) => {
+      unary.op == UnaryOp::Tilde
+        && is_bitwise_expression(&unary.arg, enum_name, member_names)
+    }
+    Expr::Bin(bin) => {
+      matches!(
+        bin.op,
+        BinaryOp::BitOr
+          | BinaryOp::BitAnd
+          | BinaryOp::BitXor
+          | BinaryOp::LShift
+          | BinaryOp::RShift
+          | BinaryOp::ZeroFillRShift
+      ) && is_bitwise_expression(&bin.left, enum_name, member_names)
+        && is_bitwise_expression(&bin.right, enum_name, member_names)
+    }
+    Expr::Paren(paren) => {
+      is_bitwise_expression(&paren.expr, enum_name, member_names)
+    }
+    _ => false,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_literal_enum_member_valid() {
+    assert_lint_ok! {
+      PreferLiteralEnumMember::default(),
+      "enum E { A }",
+      "enum E { A = 'a', B = \"b\" }",
+      "enum E { A = 1, B = -1, C = +2, D = (3) }",
+      "enum E { A = `a` }",
+      "enum E { 'a-b' = 'a-b' }",
+      "const enum E { A = 0x10 }",
+    };
+  }
+
+  #[test]
+  fn prefer_literal_enum_member_invalid() {
+    assert_lint_err! {
+      PreferLiteralEnumMember::default(),
+      MESSAGE,
+      HINT,
+      "const str = 'a'; enum E { A = str }": [{ col: 30 }],
+      "enum E { A = {} }": [{ col: 13 }],
+      "enum E { A = `a${b}` }": [{ col: 13 }],
+      "enum E { A = new Set(1) }": [{ col: 13 }],
+      "enum E { A = 'a'.length, B = -'a' }": [{ col: 13 }, { col: 29 }],
+      "enum E { A = 1, B = A }": [{ col: 20 }],
+      "enum Flags { A = 1 << 0, B = 1 << 1, C = A | B }": [
+        { col: 17 },
+        { col: 29 },
+        { col: 41 },
+      ],
+    };
+  }
+
+  #[test]
+  fn prefer_literal_enum_member_allow_bitwise_expressions() {
+    let rule = || PreferLiteralEnumMember {
+      allow_bitwise_expressions: true,
+    };
+    assert_lint_ok! {
+      rule(),
+      "enum Flags { A = 1 << 0, B = 1 << 1, C = A | B, D = Flags.A & ~Flags.B }",
+      "enum Flags { A = 1, B = 2, C = (A | B) ^ 4, D = 8 >>> 1 }",
+    };
+    assert_lint_err! {
+      rule(),
+      MESSAGE,
+      HINT,
+      "const x = 1; enum Flags { A = x | 1 }": [{ col: 30 }],
+      "enum Flags { A = 1, B = Other.A | A }": [{ col: 24 }],
+      "enum Flags { A = 1, B = A + 1 }": [{ col: 24 }],
+    };
+  }
+}
@@ -0,0 +1,457 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::{Handler, Traverse};
+use crate::linter::EcmaVersion;
+use crate::swc_util::{
+  assign_target_expr, binary_op_precedence, expr_eq, expr_precedence,
+  is_simple_reference,
+};
+use crate::tags::Tags;
+use crate::Program;
+use deno_ast::swc::ast::{AssignOp, BinaryOp, Expr, Lit, UnaryOp};
+use deno_ast::view::{self as ast_view, Node};
+use deno_ast::{SourceRange, SourceRanged, SourceRangedForSpanned};
+
+#[derive(Debug)]
+pub struct PreferNullishCoalescing;
+
+const CODE: &str = "prefer-nullish-coalescing";
+const FIX_DESC: &str = "Use `??`";
+const ASSIGN_FIX_DESC: &str = "Use `??=`";
+
+impl LintRule for PreferNullishCoalescing {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Suggests the nullish coalescing operator (`??`) for \
+        ternaries that fall back to a default value, like `x !== null && x \
+        !== undefined ? x : y` or `x ? x : y`, and the nullish coalescing \
+        assignment (`??=`) where the result is assigned back to `x`. Unlike \
+        a truthiness check, `??` keeps falsy values like `0` or `\"\"`, which \
+        is usually what's intended. Explicit checks for `null` and \
+        `undefined` are fixed automatically when `x` has no side effects, \
+        while the conversion of truthiness checks changes behavior for falsy \
+        values and is only offered as a suggestion. Nothing is reported when \
+        targeting versions before ES2020, and `??=` is only suggested from \
+        ES2021 on.",
+      invalid_examples: &[
+        "const port = options.port !== null && options.port !== undefined ? options.port : 8080;",
+        "const name = input != null ? input : \"anonymous\";",
+        "const timeout = value ? value : 1000;",
+        "cache = cache == null ? new Map() : cache;",
+      ],
+      valid_examples: &[
+        "const port = options.port ?? 8080;",
+        "cache ??= new Map();",
+        "const name = input !== null ? input : \"anonymous\";",
+        "const label = count ? `${count} items` : \"empty\";",
+      ],
+    }
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    PreferNullishCoalescingHandler.traverse(program, context);
+  }
+}
+
+struct PreferNullishCoalescingHandler;
+
+impl Handler for PreferNullishCoalescingHandler {
+  fn cond_expr(&mut self, cond_expr: &ast_view::CondExpr, ctx: &mut Context) {
+    if ctx.ecma_version() < EcmaVersion::Es2020 {
+      return;
+    }
+    let cond = cond_expr.inner;
+    let test = cond.test.unwrap_parens();
+    let (checked, value, fallback, is_nullish_check) = match nullish_check(test)
+    {
+      Some((checked, true)) => (checked, &*cond.cons, &*cond.alt, true),
+      Some((checked, false)) => (checked, &*cond.alt, &*cond.cons, true),
+      None => match test {
+        Expr::Unary(unary) if unary.op == UnaryOp::Bang => {
+          (unary.arg.unwrap_parens(), &*cond.alt, &*cond.cons, false)
+        }
+        _ => (test, &*cond.cons, &*cond.alt, false),
+      },
+    };
+    if !expr_eq(checked, value) {
+      return;
+    }
+
+    let text_info = ctx.text_info();
+    let assign = match cond_expr.parent() {
+      Node::AssignExpr(assign)
+        if ctx.ecma_version() >= EcmaVersion::Es2021
+          && assign.inner.op == AssignOp::Assign
+          && assign_target_expr(&assign.inner.left)
+            .map_or(false, |target| expr_eq(&target, checked)) =>
+      {
+        Some(assign)
+      }
+      _ => None,
+    };
+    let (range, op, new_text) = match assign {
+      Some(assign) => (
+        assign.range(),
+        "??=",
+        format!(
+          "{} ??= {}",
+          assign.inner.left.range().text_fast(text_info),
+          fallback.range().text_fast(text_info)
+        ),
+      ),
+      None => (
+        cond.range(),
+        "??",
+        format!(
+          "{} ?? {}",
+          operand_text(checked, ctx),
+          operand_text(fallback, ctx)
+        ),
+      ),
+    };
+
+    let message = if is_nullish_check {
+      format!(
+        "Checks for `null` and `undefined` can be replaced with `{}`",
+        op
+      )
+    } else {
+      format!("Truthiness check can be replaced with `{}`", op)
+    };
+    let hint = if is_nullish_check {
+      format!("Use `{}` instead", op)
+    } else {
+      format!(
+        "Use `{}` instead, unless falsy values like `0` or `\"\"` should be replaced as well",
+        op
+      )
+    };
+
+    let has_comments = ctx.has_comments_in_range(range);
+    let fix = (!has_comments).then(|| LintFix {
+      description: if assign.is_some() {
+        ASSIGN_FIX_DESC
+      } else {
+        FIX_DESC
+      }
+      .into(),
+      changes: vec![LintFixChange {
+        new_text: new_text.into(),
+        range,
+      }],
+    });
+    report(
+      range,
+      message,
+      hint,
+      fix,
+      is_nullish_check && is_simple_reference(checked),
+      ctx,
+    );
+  }
+}
+
+/// Reports the diagnostic, with the fix applied automatically only if it
+/// doesn't change behavior. Otherwise it's offered as a suggestion.
+fn report(
+  range: SourceRange,
+  message: String,
+  hint: String,
+  fix: Option<LintFix>,
+  is_safe: bool,
+  ctx: &mut Context,
+) {
+  let fixes = fix.into_iter().collect();
+  if is_safe {
+    ctx.add_diagnostic_with_fixes(range, CODE, message, Some(hint), fixes);
+  } else {
+    ctx.add_diagnostic_with_suggestions(
+      range,
+      CODE,
+      message,
+      Some(hint),
+      fixes,
+    );
+  }
+}
+
+/// Returns the text of an operand of `??`, in parentheses if needed. `??`
+/// can't be mixed with `||` and `&&` without parentheses.
+fn operand_text(expr: &Expr, ctx: &Context) -> String {
+  let text = expr.range().text_fast(ctx.text_info());
+  let needs_parens = match expr {
+    Expr::Bin(bin) => {
+      matches!(bin.op, BinaryOp::LogicalOr | BinaryOp::LogicalAnd)
+    }
+    _ => {
+      expr_precedence(expr) < binary_op_precedence(BinaryOp::NullishCoalescing)
+    }
+  };
+  if needs_parens {
+    format!("({})", text)
+  } else {
+    text.to_string()
+  }
+}
+
+/// Checks if the expression tests for both `null` and `undefined`, like
+/// `x != null` or `x === null || x === undefined`. Returns the checked
+/// expression, and whether the test passes if it's neither of them.
+fn nullish_check(test: &Expr) -> Option<(&Expr, bool)> {
+  for is_negated in [true, false] {
+    if let Some((checked, Nullish::Both)) = comparison(test, is_negated) {
+      return Some((checked, is_negated));
+    }
+    let Expr::Bin(bin) = test else {
+      continue;
+    };
+    let op = if is_negated {
+      BinaryOp::LogicalAnd
+    } else {
+      BinaryOp::LogicalOr
+    };
+    if bin.op != op {
+      continue;
+    }
+    let (Some((left, left_nullish)), Some((right, right_nullish))) = (
+      comparison(bin.left.unwrap_parens(), is_negated),
+      comparison(bin.right.unwrap_parens(), is_negated),
+    ) else {
+      continue;
+    };
+    if left_nullish != right_nullish && expr_eq(left, right) {
+      return Some((left, is_negated));
+    }
+  }
+  None
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Nullish {
+  Null,
+  Undefined,
+  /// Loose equality, which matches both `null` and `undefined`.
+  Both,
+}
+
+/// Checks if the expression compares a value with `null` or `undefined`,
+/// using `!=` or `!==` if `is_negated` is set, and `==` or `===` otherwise.
+fn comparison(expr: &Expr, is_negated: bool) -> Option<(&Expr, Nullish)> {
+  let Expr::Bin(bin) = expr else {
+    return None;
+  };
+  let is_strict = match (bin.op, is_negated) {
+    (BinaryOp::EqEqEq, false) | (BinaryOp::NotEqEq, true) => true,
+    (BinaryOp::EqEq, false) | (BinaryOp::NotEq, true) => false,
+    _ => return None,
+  };
+  let (checked, nullish) =
+    match (nullish_literal(&bin.left), nullish_literal(&bin.right)) {
+      (None, Some(nullish)) => (&*bin.left, nullish),
+      (Some(nullish), None) => (&*bin.right, nullish),
+      _ => return None,
+    };
+  let nullish = if is_strict { nullish } else { Nullish::Both };
+  Some((checked.unwrap_parens(), nullish))
+}
+
+fn nullish_literal(expr: &Expr) -> Option<Nullish> {
+  match expr.unwrap_parens() {
+    Expr::Lit(Lit::Null(_)) => Some(Nullish::Null),
+    Expr::Ident(ident) if ident.sym == *"undefined" => Some(Nullish::Undefined),
+    Expr::Unary(unary)
+      if unary.op == UnaryOp::Void
+        && matches!(&*unary.arg, Expr::Lit(Lit::Num(_))) =>
+    {
+      Some(Nullish::Undefined)
+    }
+    _ => None,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_nullish_coalescing_valid() {
+    assert_lint_ok! {
+      PreferNullishCoalescing,
+      "x ?? y;",
+      "x ??= y;",
+      "x !== null ? x : y;",
+      "x !== undefined ? x : y;",
+      "x === null ? y : x;",
+      "x !== null && x !== undefined ? y : x;",
+      "x !== null && y !== undefined ? x : y;",
+      "x !== null || x !== undefined ? x : y;",
+      "x != null ? y : z;",
+      "x ? y : x;",
+      "x ? y : z;",
+      "!x ? x : y;",
+      "x = y != null ? x : y;",
+    };
+  }
+
+  #[test]
+  fn prefer_nullish_coalescing_invalid() {
+    assert_lint_err! {
+      PreferNullishCoalescing,
+      "x !== null && x !== undefined ? x : y;": [
+        {
+          col: 0,
+          message: "Checks for `null` and `undefined` can be replaced with `??`",
+          hint: "Use `??` instead",
+          fix: (FIX_DESC, "x ?? y;"),
+        }
+      ],
+      "undefined !== x.y && null !== x.y ? x.y : 1;": [
+        {
+          col: 0,
+          message: "Checks for `null` and `undefined` can be replaced with `??`",
+          hint: "Use `??` instead",
+          fix: (FIX_DESC, "x.y ?? 1;"),
+        }
+      ],
+      "x === null || x === void 0 ? y : x;": [
+        {
+          col: 0,
+          message: "Checks for `null` and `undefined` can be replaced with `??`",
+          hint: "Use `??` instead",
+          fix: (FIX_DESC, "x ?? y;"),
+        }
+      ],
+      "(x != null) ? (x) : a || b;": [
+        {
+          col: 0,
+          message: "Checks for `null` and `undefined` can be replaced with `??`",
+          hint: "Use `??` instead",
+          fix: (FIX_DESC, "x ?? (a || b);"),
+        }
+      ],
+      "x == undefined ? y ? 1 : 2 : x;": [
+        {
+          col: 0,
+          message: "Checks for `null` and `undefined` can be replaced with `??`",
+          hint: "Use `??` instead",
+          fix: (FIX_DESC, "x ?? (y ? 1 : 2);"),
+        }
+      ],
+      "this.x = this.x != null ? this.x : {};": [
+        {
+          col: 0,
+          message: "Checks for `null` and `undefined` can be replaced with `??=`",
+          hint: "Use `??=` instead",
+          fix: (ASSIGN_FIX_DESC, "this.x ??= {};"),
+        }
+      ],
+      "foo() != null ? foo() : y;": [
+        {
+          col: 0,
+          message: "Checks for `null` and `undefined` can be replaced with `??`",
+          hint: "Use `??` instead",
+          suggestion: (FIX_DESC, "foo() ?? y;"),
+        }
+      ],
+      "x != null ? x : /* default */ y;": [
+        {
+          col: 0,
+          message: "Checks for `null` and `undefined` can be replaced with `??`",
+          hint: "Use `??` instead",
+        }
+      ],
+      "x ? x : y;": [
+        {
+          col: 0,
+          message: "Truthiness check can be replaced with `??`",
+          hint: "Use `??` instead, unless falsy values like `0` or `\"\"` should be replaced as well",
+          suggestion: (FIX_DESC, "x ?? y;"),
+        }
+      ],
+      "!x ? y : x;": [
+        {
+          col: 0,
+          message: "Truthiness check can be replaced with `??`",
+          hint: "Use `??` instead, unless falsy values like `0` or `\"\"` should be replaced as well",
+          suggestion: (FIX_DESC, "x ?? y;"),
+        }
+      ],
+      "a || b ? a || b : c;": [
+        {
+          col: 0,
+          message: "Truthiness check can be replaced with `??`",
+          hint: "Use `??` instead, unless falsy values like `0` or `\"\"` should be replaced as well",
+          suggestion: (FIX_DESC, "(a || b) ?? c;"),
+        }
+      ],
+      "x = x ? x : y;": [
+        {
+          col: 0,
+          message: "Truthiness check can be replaced with `??=`",
+          hint: "Use `??=` instead, unless falsy values like `0` or `\"\"` should be replaced as well",
+          suggestion: (ASSIGN_FIX_DESC, "x ??= y;"),
+        }
+      ],
+      "const a = b ? b : c ? c : d;": [
+        {
+          col: 10,
+          message: "Truthiness check can be replaced with `??`",
+          hint: "Use `??` instead, unless falsy values like `0` or `\"\"` should be replaced as well",
+          suggestion: (FIX_DESC, "const a = b ?? (c ? c : d);"),
+        },
+        {
+          col: 18,
+          message: "Truthiness check can be replaced with `??`",
+          hint: "Use `??` instead, unless falsy values like `0` or `\"\"` should be replaced as well",
+          suggestion: (FIX_DESC, "const a = b ? b : c ?? d;"),
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn prefer_nullish_coalescing_ecma_version() {
+    let lint = |source: &str, ecma_version: EcmaVersion| {
+      let (_, diagnostics) = crate::testing::lint_with_ecma_version(
+        Box::new(PreferNullishCoalescing),
+        source,
+        "file:///prefer_nullish_coalescing_test.ts",
+        ecma_version,
+      );
+      diagnostics
+    };
+    assert!(lint("x != null ? x : y;", EcmaVersion::Es2019).is_empty());
+    assert_eq!(lint("x != null ? x : y;", EcmaVersion::Es2020).len(), 1);
+
+    // `??=` is only available from ES2021 on.
+    let diagnostics = lint("x = x ? x : y;", EcmaVersion::Es2020);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+      diagnostics[0].details.message,
+      "Truthiness check can be replaced with `??`"
+    );
+    let diagnostics = lint("x = x ? x : y;", EcmaVersion::Es2021);
+    assert_eq!(
+      diagnostics[0].details.message,
+      "Truthiness check can be replaced with `??=`"
+    );
+  }
+}
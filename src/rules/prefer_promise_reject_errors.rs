@@ -0,0 +1,250 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{program_ref, Context, LintRule, RuleDocs};
+use crate::error_value::{LocalClasses, NonErrorValue};
+use crate::handler::{Handler, Traverse};
+use crate::tags::Tags;
+use crate::Program;
+use deno_ast::swc::ast::{Callee, Expr, ExprOrSpread, Id, MemberProp, Pat};
+use deno_ast::view as ast_view;
+use deno_ast::SourceRangedForSpanned;
+use serde::Deserialize;
+use std::collections::HashSet;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct PreferPromiseRejectErrors {
+  /// Allows rejecting without a reason, e.g. `Promise.reject()`.
+  pub allow_empty_reject: bool,
+}
+
+const CODE: &str = "prefer-promise-reject-errors";
+const MESSAGE: &str = "Expected the promise to be rejected with an `Error`";
+const HINT: &str = "Reject with an `Error` object, e.g. `new Error(\"...\")`, so that the rejection has a stack trace";
+
+impl LintRule for PreferPromiseRejectErrors {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Requires promises to be rejected with `Error` objects, \
+        like `no-throw-literal` does for `throw`. Calls of `Promise.reject()` \
+        and of the `reject` function of a `new Promise()` executor are \
+        checked, and reported if the reason is known not to be an error, \
+        e.g. a literal, a template or an object literal, or an instance of a \
+        class declared in the same file that doesn't extend `Error`. \
+        Rejecting without a reason is reported too, unless the \
+        `allowEmptyReject` option is set.",
+      invalid_examples: &[
+        "Promise.reject(\"failed\");",
+        "new Promise((resolve, reject) => {\n  reject(404);\n});",
+      ],
+      valid_examples: &[
+        "Promise.reject(new Error(\"failed\"));",
+        "new Promise((resolve, reject) => {\n  reject(new Error(\"Not found\"));\n});",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "allowEmptyReject": {
+          "description": "Allows rejecting without a reason, e.g. `Promise.reject()`",
+          "type": "boolean"
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(
+      serde_json::from_value::<PreferPromiseRejectErrors>(options)?,
+    ))
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    PreferPromiseRejectErrorsHandler {
+      allow_empty_reject: self.allow_empty_reject,
+      classes: LocalClasses::collect(program_ref(program)),
+      reject_fns: HashSet::new(),
+    }
+    .traverse(program, context);
+  }
+}
+
+struct PreferPromiseRejectErrorsHandler {
+  allow_empty_reject: bool,
+  classes: LocalClasses,
+  /// The `reject` parameters of promise executors.
+  reject_fns: HashSet<Id>,
+}
+
+impl PreferPromiseRejectErrorsHandler {
+  fn is_rejection(&self, callee: &Expr, ctx: &Context) -> bool {
+    match callee.unwrap_parens() {
+      Expr::Ident(ident) => self.reject_fns.contains(&ident.to_id()),
+      Expr::Member(member) => {
+        matches!(
+          (&*member.obj, &member.prop),
+          (Expr::Ident(obj), MemberProp::Ident(prop))
+            if obj.sym == *"Promise"
+              && prop.sym == *"reject"
+              && ctx.scope().is_global(&obj.to_id())
+        )
+      }
+      _ => false,
+    }
+  }
+
+  fn check_reason(&self, reason: Option<&ExprOrSpread>) -> bool {
+    match reason {
+      None => !self.allow_empty_reject,
+      Some(ExprOrSpread {
+        spread: Some(_), ..
+      }) => false,
+      Some(ExprOrSpread { expr, .. }) => {
+        match self.classes.non_error_value(expr) {
+          Some(NonErrorValue::Undefined) => !self.allow_empty_reject,
+          Some(NonErrorValue::Literal | NonErrorValue::Other) => true,
+          None => false,
+        }
+      }
+    }
+  }
+}
+
+impl Handler for PreferPromiseRejectErrorsHandler {
+  fn new_expr(&mut self, new_expr: &ast_view::NewExpr, ctx: &mut Context) {
+    let new_expr = new_expr.inner;
+    let Expr::Ident(callee) = &*new_expr.callee else {
+      return;
+    };
+    if callee.sym != *"Promise" || !ctx.scope().is_global(&callee.to_id()) {
+      return;
+    }
+    let Some(executor) = new_expr.args.as_ref().and_then(|args| args.first())
+    else {
+      return;
+    };
+    let reject_param = match executor.expr.unwrap_parens() {
+      Expr::Arrow(arrow) => arrow.params.get(1),
+      Expr::Fn(fn_expr) => {
+        fn_expr.function.params.get(1).map(|param| &param.pat)
+      }
+      _ => None,
+    };
+    if let Some(Pat::Ident(reject)) = reject_param {
+      self.reject_fns.insert(reject.id.to_id());
+    }
+  }
+
+  fn call_expr(&mut self, call_expr: &ast_view::CallExpr, ctx: &mut Context) {
+    let call_expr = call_expr.inner;
+    let Callee::Expr(callee) = &call_expr.callee else {
+      return;
+    };
+    if self.is_rejection(callee, ctx)
+      && self.check_reason(call_expr.args.first())
+    {
+      ctx.add_diagnostic_with_hint(call_expr.range(), CODE, MESSAGE, HINT);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v9.0.0/tests/lib/rules/prefer-promise-reject-errors.js
+  // MIT Licensed.
+
+  #[test]
+  fn prefer_promise_reject_errors_valid() {
+    assert_lint_ok! {
+      PreferPromiseRejectErrors::default(),
+      "Promise.resolve(5)",
+      "Foo.reject(5)",
+      "Promise.reject(foo)",
+      "Promise.reject(foo.bar)",
+      "Promise.reject(foo.bar())",
+      "Promise.reject(new Error())",
+      "Promise.reject(new TypeError())",
+      "Promise.reject(new Error('foo'))",
+      "Promise.reject(foo || 5)",
+      "Promise.reject(5 && foo)",
+      "Promise.reject(...args)",
+      "new Foo((resolve, reject) => reject(5))",
+      "new Promise(function(resolve, reject) { return function(reject) { reject(5) } })",
+      "new Promise(function(resolve, reject) { if (foo) { const reject = somethingElse; reject(5) } })",
+      "new Promise(function(resolve, {apply}) { apply(5) })",
+      "new Promise((resolve, reject) => resolve(5))",
+      "new Promise((resolve, reject) => reject(new Error()))",
+      "class MyError extends Error {}\nPromise.reject(new MyError());",
+      "class MyError extends Unknown {}\nPromise.reject(new MyError());",
+      "const Promise = { reject() {} };\nPromise.reject(5);",
+      "function f(Promise) { Promise.reject(5); }",
+    };
+
+    assert_lint_ok! {
+      PreferPromiseRejectErrors {
+        allow_empty_reject: true,
+      },
+      "Promise.reject()",
+      "Promise.reject(undefined)",
+      "new Promise((resolve, reject) => reject())",
+    };
+  }
+
+  #[test]
+  fn prefer_promise_reject_errors_invalid() {
+    assert_lint_err! {
+      PreferPromiseRejectErrors::default(),
+      MESSAGE,
+      HINT,
+      "Promise.reject(5)": [{ col: 0 }],
+      "Promise.reject('foo')": [{ col: 0 }],
+      "Promise.reject(`foo`)": [{ col: 0 }],
+      "Promise.reject(!foo)": [{ col: 0 }],
+      "Promise.reject()": [{ col: 0 }],
+      "Promise.reject(undefined)": [{ col: 0 }],
+      "Promise.reject({ foo: 1 })": [{ col: 0 }],
+      "Promise.reject([1, 2, 3])": [{ col: 0 }],
+      "Promise.reject(foo = 5)": [{ col: 0 }],
+      "Promise.reject(foo ? 'a' : 'b')": [{ col: 0 }],
+      "(Promise.reject)(5)": [{ col: 0 }],
+      "new Promise(function(resolve, reject) { reject(5) })": [{ col: 40 }],
+      "new Promise((resolve, reject) => { reject(5) })": [{ col: 35 }],
+      "new Promise((resolve, reject) => reject(5))": [{ col: 33 }],
+      "new Promise((resolve, reject) => reject())": [{ col: 33 }],
+      "new Promise((yes, no) => { setTimeout(() => no('timeout'), 100); })": [{ col: 44 }],
+      "class Failure {}\nPromise.reject(new Failure());": [{ line: 2, col: 0 }],
+      "class Base {}\nclass Failure extends Base {}\nPromise.reject(new Failure());": [{ line: 3, col: 0 }],
+    };
+
+    assert_lint_err! {
+      PreferPromiseRejectErrors {
+        allow_empty_reject: true,
+      },
+      MESSAGE,
+      HINT,
+      "Promise.reject(5)": [{ col: 0 }],
+    };
+  }
+}
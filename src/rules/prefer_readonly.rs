@@ -0,0 +1,404 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::{Handler, Traverse};
+use crate::tags::Tags;
+use crate::Program;
+use deno_ast::swc::ast::{
+  Accessibility, ArrowExpr, AssignExpr, AssignTarget, Class, ClassMember, Expr,
+  Function, GetterProp, Lit, MemberExpr, MemberProp, ParamOrTsParamProp, Pat,
+  PropName, SetterProp, SimpleAssignTarget, TsParamPropParam, UpdateExpr,
+};
+use deno_ast::swc::atoms::JsWord;
+use deno_ast::swc::visit::{noop_visit_type, Visit, VisitWith};
+use deno_ast::view::{self as ast_view, Node};
+use deno_ast::{MediaType, SourceRange, SourceRangedForSpanned};
+use derive_more::Display;
+use std::collections::HashSet;
+
+#[derive(Debug)]
+pub struct PreferReadonly;
+
+const CODE: &str = "prefer-readonly";
+const HINT: &str = "Mark it as `readonly`";
+const FIX_DESC: &str = "Add `readonly`";
+
+#[derive(Display)]
+enum PreferReadonlyMessage {
+  #[display(
+    fmt = "Private member `{}` is never reassigned outside the constructor",
+    _0
+  )]
+  NeverReassigned(String),
+}
+
+impl LintRule for PreferReadonly {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Requires private class members that are only assigned \
+        in their declaration or in the constructor to be marked as \
+        `readonly`. This applies to `private` properties, `#private` fields \
+        and `private` parameter properties. The check is syntactic: any \
+        assignment to a property of the same name outside the constructor, \
+        on `this` or on another object, counts as a reassignment. Only \
+        TypeScript files are checked.",
+      invalid_examples: &[
+        "class Counter {\n  private step = 1;\n  #count = 0;\n\n  increment() {\n    this.#count += this.step;\n  }\n}",
+      ],
+      valid_examples: &[
+        "class Counter {\n  private readonly step = 1;\n  #count = 0;\n\n  increment() {\n    this.#count += this.step;\n  }\n}",
+      ],
+    }
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    if matches!(
+      context.media_type(),
+      MediaType::JavaScript
+        | MediaType::Jsx
+        | MediaType::Mjs
+        | MediaType::Cjs
+        | MediaType::Dts
+        | MediaType::Dmts
+        | MediaType::Dcts
+    ) {
+      return;
+    }
+    PreferReadonlyHandler.traverse(program, context);
+  }
+}
+
+/// The name of a class member, as it is accessed through a member
+/// expression.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum MemberName {
+  Private(JsWord),
+  Public(JsWord),
+}
+
+impl MemberName {
+  fn of(member: &MemberExpr) -> Option<Self> {
+    match &member.prop {
+      MemberProp::Ident(ident) => Some(MemberName::Public(ident.sym.clone())),
+      MemberProp::PrivateName(name) => {
+        Some(MemberName::Private(name.name.clone()))
+      }
+      MemberProp::Computed(computed) => match &*computed.expr {
+        Expr::Lit(Lit::Str(s)) => Some(MemberName::Public(s.value.clone())),
+        _ => None,
+      },
+    }
+  }
+
+  fn display(&self) -> String {
+    match self {
+      MemberName::Private(name) => format!("#{}", name),
+      MemberName::Public(name) => name.to_string(),
+    }
+  }
+}
+
+/// A private member that may be marked as `readonly`.
+struct Candidate {
+  name: MemberName,
+  range: SourceRange,
+}
+
+struct PreferReadonlyHandler;
+
+impl Handler for PreferReadonlyHandler {
+  fn class(&mut self, class: &ast_view::Class, ctx: &mut Context) {
+    if matches!(class.parent(), Node::ClassDecl(decl) if decl.inner.declare) {
+      return;
+    }
+    let candidates = collect_candidates(class.inner);
+    if candidates.is_empty() {
+      return;
+    }
+
+    let mut collector = ModificationCollector {
+      in_constructor: false,
+      modified: HashSet::new(),
+    };
+    if let Some(super_class) = &class.inner.super_class {
+      super_class.visit_with(&mut collector);
+    }
+    for member in &class.inner.body {
+      match member {
+        ClassMember::Constructor(constructor) => {
+          constructor.params.visit_with(&mut collector);
+          if let Some(body) = &constructor.body {
+            collector.in_constructor = true;
+            body.visit_with(&mut collector);
+            collector.in_constructor = false;
+          }
+        }
+        _ => member.visit_with(&mut collector),
+      }
+    }
+
+    for candidate in candidates {
+      if collector.modified.contains(&candidate.name) {
+        continue;
+      }
+      ctx.add_diagnostic_with_suggestions(
+        candidate.range,
+        CODE,
+        PreferReadonlyMessage::NeverReassigned(candidate.name.display()),
+        Some(HINT.to_string()),
+        vec![LintFix {
+          description: FIX_DESC.into(),
+          changes: vec![LintFixChange {
+            new_text: "readonly ".into(),
+            range: SourceRange::new(
+              candidate.range.start,
+              candidate.range.start,
+            ),
+          }],
+        }],
+      );
+    }
+  }
+}
+
+/// Collects the instance members of a class that are private and not yet
+/// `readonly`.
+fn collect_candidates(class: &Class) -> Vec<Candidate> {
+  let mut candidates = Vec::new();
+  for member in &class.body {
+    match member {
+      ClassMember::ClassProp(prop)
+        if prop.accessibility == Some(Accessibility::Private)
+          && !prop.is_static
+          && !prop.readonly
+          && !prop.declare =>
+      {
+        let name = match &prop.key {
+          PropName::Ident(ident) => ident.sym.clone(),
+          PropName::Str(s) => s.value.clone(),
+          _ => continue,
+        };
+        candidates.push(Candidate {
+          name: MemberName::Public(name),
+          range: prop.key.range(),
+        });
+      }
+      ClassMember::PrivateProp(prop) if !prop.is_static && !prop.readonly => {
+        candidates.push(Candidate {
+          name: MemberName::Private(prop.key.name.clone()),
+          range: prop.key.range(),
+        });
+      }
+      ClassMember::Constructor(constructor) => {
+        for param in &constructor.params {
+          let ParamOrTsParamProp::TsParamProp(prop) = param else {
+            continue;
+          };
+          if prop.accessibility != Some(Accessibility::Private) || prop.readonly
+          {
+            continue;
+          }
+          let ident = match &prop.param {
+            TsParamPropParam::Ident(ident) => ident,
+            TsParamPropParam::Assign(assign) => match &*assign.left {
+              Pat::Ident(ident) => ident,
+              _ => continue,
+            },
+          };
+          candidates.push(Candidate {
+            name: MemberName::Public(ident.id.sym.clone()),
+            range: prop.param.range(),
+          });
+        }
+      }
+      _ => {}
+    }
+  }
+  candidates
+}
+
+/// Collects the names of the members that are assigned in a class body.
+/// Assignments to properties of `this` directly in the constructor body are
+/// not counted, since `readonly` members may be initialized there.
+struct ModificationCollector {
+  in_constructor: bool,
+  modified: HashSet<MemberName>,
+}
+
+impl ModificationCollector {
+  fn add(&mut self, member: &MemberExpr) {
+    if self.in_constructor && matches!(&*member.obj, Expr::This(_)) {
+      return;
+    }
+    if let Some(name) = MemberName::of(member) {
+      self.modified.insert(name);
+    }
+  }
+
+  fn visit_nested<T: VisitWith<Self>>(&mut self, node: &T) {
+    let in_constructor = self.in_constructor;
+    self.in_constructor = false;
+    node.visit_children_with(self);
+    self.in_constructor = in_constructor;
+  }
+}
+
+impl Visit for ModificationCollector {
+  noop_visit_type!();
+
+  fn visit_assign_expr(&mut self, assign_expr: &AssignExpr) {
+    if let AssignTarget::Simple(SimpleAssignTarget::Member(member)) =
+      &assign_expr.left
+    {
+      self.add(member);
+    }
+    assign_expr.visit_children_with(self);
+  }
+
+  fn visit_update_expr(&mut self, update_expr: &UpdateExpr) {
+    if let Expr::Member(member) = &*update_expr.arg {
+      self.add(member);
+    }
+    update_expr.visit_children_with(self);
+  }
+
+  // Member expressions in destructuring assignments and `for`-`in`/`of`
+  // heads.
+  fn visit_pat(&mut self, pat: &Pat) {
+    if let Pat::Expr(expr) = pat {
+      if let Expr::Member(member) = &**expr {
+        self.add(member);
+      }
+    }
+    pat.visit_children_with(self);
+  }
+
+  fn visit_function(&mut self, function: &Function) {
+    self.visit_nested(function);
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr) {
+    self.visit_nested(arrow_expr);
+  }
+
+  fn visit_getter_prop(&mut self, getter_prop: &GetterProp) {
+    self.visit_nested(getter_prop);
+  }
+
+  fn visit_setter_prop(&mut self, setter_prop: &SetterProp) {
+    self.visit_nested(setter_prop);
+  }
+
+  fn visit_class(&mut self, class: &Class) {
+    self.visit_nested(class);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_readonly_valid() {
+    assert_lint_ok! {
+      PreferReadonly,
+      "class A { private readonly a = 1; }",
+      "class A { readonly #a = 1; }",
+      "class A { a = 1; }",
+      "class A { public a = 1; }",
+      "class A { protected a = 1; }",
+      "class A { private static a = 1; }",
+      "class A { static #a = 1; }",
+      "class A { private a = 1; b() { this.a = 2; } }",
+      "class A { private a = 1; b() { this.a += 2; } }",
+      "class A { private a = 1; b() { this.a++; } }",
+      "class A { private a = 1; b() { [this.a] = [2]; } }",
+      "class A { private a = 1; b() { ({ x: this.a } = { x: 2 }); } }",
+      "class A { private a = 1; b() { for (this.a of [2]) {} } }",
+      "class A { private a = 1; b(other: A) { other.a = 2; } }",
+      "class A { private a = 1; b() { this['a'] = 2; } }",
+      "class A { #a = 1; b() { this.#a = 2; } }",
+      "class A { #a = 1; static b(other: A) { other.#a = 2; } }",
+      "class A { private a = 1; c = () => { this.a = 2; }; }",
+      "class A { private a = 1; constructor() { setTimeout(() => { this.a = 2; }); } }",
+      "class A { private a = 1; constructor() { function f() { this.a = 2; } } }",
+      "class A { private a: number; constructor(other: A) { other.a = 1; } }",
+      "class A { constructor(private readonly a: number) {} }",
+      "class A { constructor(private a: number) {} b() { this.a = 1; } }",
+      "declare class A { private a: number; }",
+    };
+    assert_lint_ok! {
+      PreferReadonly,
+      filename: "file:///foo.js",
+      "class A { #a = 1; }",
+    };
+  }
+
+  #[test]
+  fn prefer_readonly_invalid() {
+    assert_lint_err! {
+      PreferReadonly,
+      "class A { private a = 1; }": [
+        {
+          col: 18,
+          message: variant!(PreferReadonlyMessage, NeverReassigned, "a"),
+          hint: HINT,
+          suggestion: (FIX_DESC, "class A { private readonly a = 1; }"),
+        }
+      ],
+      "class A { #a = 1; b() { return this.#a; } }": [
+        {
+          col: 10,
+          message: variant!(PreferReadonlyMessage, NeverReassigned, "#a"),
+          hint: HINT,
+          suggestion: (FIX_DESC, "class A { readonly #a = 1; b() { return this.#a; } }"),
+        }
+      ],
+      "class A { private a: number; constructor() { this.a = 1; } }": [
+        {
+          col: 18,
+          message: variant!(PreferReadonlyMessage, NeverReassigned, "a"),
+          hint: HINT,
+          suggestion: (FIX_DESC, "class A { private readonly a: number; constructor() { this.a = 1; } }"),
+        }
+      ],
+      "class A { constructor(private a: number) {} }": [
+        {
+          col: 30,
+          message: variant!(PreferReadonlyMessage, NeverReassigned, "a"),
+          hint: HINT,
+          suggestion: (FIX_DESC, "class A { constructor(private readonly a: number) {} }"),
+        }
+      ],
+      "class A { private a = 1; private b = 2; c() { this.b = this.a; } }": [
+        {
+          col: 18,
+          message: variant!(PreferReadonlyMessage, NeverReassigned, "a"),
+          hint: HINT,
+          suggestion: (FIX_DESC, "class A { private readonly a = 1; private b = 2; c() { this.b = this.a; } }"),
+        }
+      ],
+      "class A { private a = 1; b() { class B { a = 1; } } }": [
+        {
+          col: 18,
+          message: variant!(PreferReadonlyMessage, NeverReassigned, "a"),
+          hint: HINT,
+          suggestion: (FIX_DESC, "class A { private readonly a = 1; b() { class B { a = 1; } } }"),
+        }
+      ],
+    };
+  }
+}
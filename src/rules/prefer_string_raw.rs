@@ -0,0 +1,277 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::{Handler, Traverse};
+use crate::linter::EcmaVersion;
+use crate::tags::Tags;
+use crate::Program;
+use deno_ast::view::{self as ast_view, Node};
+use deno_ast::SourceRanged;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct PreferStringRaw {
+  /// The number of escaped backslashes a string literal needs to contain to
+  /// be reported.
+  pub threshold: usize,
+}
+
+impl Default for PreferStringRaw {
+  fn default() -> Self {
+    Self { threshold: 2 }
+  }
+}
+
+const CODE: &str = "prefer-string-raw";
+const MESSAGE: &str = "String literal with escaped backslashes";
+const HINT: &str =
+  "Use `String.raw` to write the backslashes without escaping them";
+const FIX_DESC: &str = "Convert to `String.raw`";
+
+impl LintRule for PreferStringRaw {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Suggests `String.raw` for string literals with many \
+        escaped backslashes, such as Windows paths or the sources of regular \
+        expressions, which are easier to read without the escapes. Only \
+        strings whose escapes are all `\\\\` are reported, since other escape \
+        sequences have no equivalent in `String.raw`. The conversion is \
+        offered as a suggestion in the editor and isn't applied by \
+        `deno lint --fix`.",
+      invalid_examples: &[
+        "const path = \"C:\\\\Users\\\\deno\\\\.config\";",
+        "const re = new RegExp(\"\\\\d+\\\\.\\\\d+\");",
+      ],
+      valid_examples: &[
+        "const path = String.raw`C:\\Users\\deno\\.config`;",
+        "const path = \"C:\\\\Users\";",
+        "const text = \"a\\\\b\\\\c\\n\";",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "threshold": {
+          "description": "The minimum number of escaped backslashes to report a string. Defaults to 2",
+          "type": "integer",
+          "minimum": 1
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<PreferStringRaw>(
+      options,
+    )?))
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    PreferStringRawHandler { rule: self }.traverse(program, context);
+  }
+}
+
+struct PreferStringRawHandler<'a> {
+  rule: &'a PreferStringRaw,
+}
+
+impl<'a> Handler for PreferStringRawHandler<'a> {
+  fn str(&mut self, str: &ast_view::Str, ctx: &mut Context) {
+    // `String.raw` is only available from ES2015 on.
+    if ctx.ecma_version() < EcmaVersion::Es2015 || !is_expression(str) {
+      return;
+    }
+    let raw = str.range().text_fast(ctx.text_info());
+    let Some(content) =
+      raw_content(&raw[1..raw.len() - 1], self.rule.threshold)
+    else {
+      return;
+    };
+    ctx.add_diagnostic_with_suggestions(
+      str.range(),
+      CODE,
+      MESSAGE,
+      Some(HINT.to_string()),
+      vec![LintFix {
+        description: FIX_DESC.into(),
+        changes: vec![LintFixChange {
+          new_text: format!("String.raw`{}`", content).into(),
+          range: str.range(),
+        }],
+      }],
+    );
+  }
+}
+
+/// Checks if the string literal is used as an expression, as opposed to
+/// places that only allow string literals like module specifiers, property
+/// keys, directives and literal types.
+fn is_expression(str: &ast_view::Str) -> bool {
+  match str.parent() {
+    Node::ImportDecl(_)
+    | Node::ExportAll(_)
+    | Node::NamedExport(_)
+    | Node::ImportNamedSpecifier(_)
+    | Node::ExportNamedSpecifier(_)
+    | Node::TsImportType(_)
+    | Node::TsExternalModuleRef(_)
+    | Node::TsModuleDecl(_)
+    | Node::TsLitType(_)
+    | Node::TsEnumMember(_)
+    | Node::JSXAttr(_)
+    | Node::ExprStmt(_)
+    | Node::MethodProp(_)
+    | Node::GetterProp(_)
+    | Node::SetterProp(_)
+    | Node::ClassMethod(_)
+    | Node::TsPropertySignature(_)
+    | Node::TsMethodSignature(_)
+    | Node::TsGetterSignature(_)
+    | Node::TsSetterSignature(_) => false,
+    Node::KeyValueProp(prop) => prop.value.range() == str.range(),
+    Node::ClassProp(prop) => prop
+      .value
+      .map_or(false, |value| value.range() == str.range()),
+    _ => true,
+  }
+}
+
+/// Returns the content of a `String.raw` template literal with the same value
+/// as the string literal's raw content, if it has at least `threshold`
+/// escaped backslashes and no other escape sequences.
+fn raw_content(raw: &str, threshold: usize) -> Option<String> {
+  // The backtick or `${` would have to be escaped, which `String.raw` keeps,
+  // and a trailing backslash would escape the closing backtick.
+  if raw.contains('`') || raw.contains("${") || raw.ends_with('\\') {
+    return None;
+  }
+  let mut content = String::with_capacity(raw.len());
+  let mut backslashes = 0;
+  let mut chars = raw.chars();
+  while let Some(c) = chars.next() {
+    if c != '\\' {
+      content.push(c);
+      continue;
+    }
+    if chars.next() != Some('\\') {
+      return None;
+    }
+    backslashes += 1;
+    content.push('\\');
+  }
+  (backslashes >= threshold).then_some(content)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_string_raw_valid() {
+    assert_lint_ok! {
+      PreferStringRaw::default(),
+      r#"const a = "a";"#,
+      r#"const a = "a\\b";"#,
+      r#"const a = "a\\b\\c\n";"#,
+      r#"const a = "a\\b\\c\"";"#,
+      r#"const a = "a\\b`\\c";"#,
+      r#"const a = "a\\b${c}\\";"#,
+      r#"const a = "a\\b\\";"#,
+      r#"import a from "a\\b\\c";"#,
+      r#"const a = { "a\\b\\c": 1 };"#,
+      r#"type A = "a\\b\\c";"#,
+      r#"enum A { "a\\b\\c" = 1 }"#,
+      r#"class A { "a\\b\\c"() {} }"#,
+      r#""a\\b\\c";"#,
+    };
+  }
+
+  #[test]
+  fn prefer_string_raw_invalid() {
+    assert_lint_err! {
+      PreferStringRaw::default(),
+      r#"const a = "C:\\Users\\deno";"#: [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: HINT,
+          suggestion: (FIX_DESC, r"const a = String.raw`C:\Users\deno`;"),
+        }
+      ],
+      r#"const a = new RegExp('\\d+\\.\\d+');"#: [
+        {
+          col: 21,
+          message: MESSAGE,
+          hint: HINT,
+          suggestion: (FIX_DESC, r"const a = new RegExp(String.raw`\d+\.\d+`);"),
+        }
+      ],
+      r#"const a = { b: "\\\\server\\share" };"#: [
+        {
+          col: 15,
+          message: MESSAGE,
+          hint: HINT,
+          suggestion: (FIX_DESC, r"const a = { b: String.raw`\\server\share` };"),
+        }
+      ],
+      r#"class A { b = "a\\b\\c"; }"#: [
+        {
+          col: 14,
+          message: MESSAGE,
+          hint: HINT,
+          suggestion: (FIX_DESC, r"class A { b = String.raw`a\b\c`; }"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      PreferStringRaw { threshold: 1 },
+      r#"const a = "C:\\Users";"#: [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: HINT,
+          suggestion: (FIX_DESC, r"const a = String.raw`C:\Users`;"),
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn prefer_string_raw_ecma_version() {
+    let lint = |source: &str, ecma_version: EcmaVersion| {
+      let (_, diagnostics) = crate::testing::lint_with_ecma_version(
+        Box::new(PreferStringRaw::default()),
+        source,
+        "file:///prefer_string_raw_test.ts",
+        ecma_version,
+      );
+      diagnostics
+    };
+    // `String.raw` is only available from ES2015 on.
+    let source = r#"const a = "C:\\Users\\deno";"#;
+    assert!(lint(source, EcmaVersion::Es5).is_empty());
+    assert_eq!(lint(source, EcmaVersion::Es2015).len(), 1);
+  }
+}
@@ -0,0 +1,346 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::{Handler, Traverse};
+use crate::linter::EcmaVersion;
+use crate::swc_util::{push_template_text, string_to_template_text};
+use crate::tags::{self, Tags};
+use crate::Program;
+use deno_ast::swc::ast::{BinExpr, BinaryOp, Expr, Lit};
+use deno_ast::view::{self as ast_view, Node, NodeTrait};
+use deno_ast::{SourceRanged, SourceRangedForSpanned};
+
+#[derive(Debug)]
+pub struct PreferTemplate;
+
+const CODE: &str = "prefer-template";
+const MESSAGE: &str = "Unexpected string concatenation";
+const HINT: &str = "Use a template literal instead";
+const FIX_DESC: &str = "Convert to a template literal";
+
+impl LintRule for PreferTemplate {
+  fn tags(&self) -> Tags {
+    &[tags::STYLISTIC]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Requires template literals instead of string \
+        concatenation with `+` where strings are combined with other \
+        expressions. Concatenations of string literals only, like `\"a\" + \
+        \"b\"`, are not reported. Operands that are added up before the first \
+        string, like `a + b` in `a + b + \"px\"`, are kept together in a \
+        single interpolation by the fix.",
+      invalid_examples: &[
+        "const greeting = \"Hello, \" + name + \"!\";",
+        "const width = (size * 2) + \"px\";",
+      ],
+      valid_examples: &[
+        "const greeting = `Hello, ${name}!`;",
+        "const width = `${size * 2}px`;",
+        "const message = \"Hello, \" + \"world!\";",
+        "const total = a + b;",
+      ],
+    }
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    PreferTemplateHandler.traverse(program, context);
+  }
+}
+
+struct PreferTemplateHandler;
+
+impl Handler for PreferTemplateHandler {
+  fn bin_expr(&mut self, bin_expr: &ast_view::BinExpr, ctx: &mut Context) {
+    // Template literals are only available from ES2015 on.
+    if bin_expr.op() != BinaryOp::Add
+      || ctx.ecma_version() < EcmaVersion::Es2015
+    {
+      return;
+    }
+    // Only the outermost concatenation is reported.
+    let mut parent = bin_expr.as_node().parent();
+    while let Some(Node::ParenExpr(paren)) = parent {
+      parent = paren.as_node().parent();
+    }
+    if let Some(Node::BinExpr(parent)) = parent {
+      if parent.op() == BinaryOp::Add {
+        return;
+      }
+    }
+
+    let bin = bin_expr.inner;
+    if !(has_string_literal(&bin.left) || has_string_literal(&bin.right))
+      || !(has_non_string_literal(&bin.left)
+        || has_non_string_literal(&bin.right))
+    {
+      return;
+    }
+
+    let range = bin_expr.range();
+    let fixes = if ctx.has_comments_in_range(range) {
+      vec![]
+    } else {
+      let mut text = String::from("`");
+      push_template_parts(&bin.left, ctx, &mut text);
+      push_template_parts(&bin.right, ctx, &mut text);
+      text.push('`');
+      vec![LintFix {
+        description: FIX_DESC.into(),
+        changes: vec![LintFixChange {
+          new_text: text.into(),
+          range,
+        }],
+      }]
+    };
+    ctx.add_diagnostic_with_fixes(
+      range,
+      CODE,
+      MESSAGE,
+      Some(HINT.to_string()),
+      fixes,
+    );
+  }
+}
+
+fn as_concatenation(expr: &Expr) -> Option<&BinExpr> {
+  match expr.unwrap_parens() {
+    Expr::Bin(bin) if bin.op == BinaryOp::Add => Some(bin),
+    _ => None,
+  }
+}
+
+fn is_string_literal(expr: &Expr) -> bool {
+  matches!(expr.unwrap_parens(), Expr::Lit(Lit::Str(_)) | Expr::Tpl(_))
+}
+
+fn has_string_literal(expr: &Expr) -> bool {
+  match as_concatenation(expr) {
+    Some(bin) => {
+      has_string_literal(&bin.left) || has_string_literal(&bin.right)
+    }
+    None => is_string_literal(expr),
+  }
+}
+
+fn has_non_string_literal(expr: &Expr) -> bool {
+  match as_concatenation(expr) {
+    Some(bin) => {
+      has_non_string_literal(&bin.left) || has_non_string_literal(&bin.right)
+    }
+    None => !is_string_literal(expr),
+  }
+}
+
+/// Appends the content of a template literal equivalent to the expression.
+/// Additions without strings, like `a + b` in `a + b + "px"`, become a
+/// single interpolation, since they are evaluated as numbers.
+fn push_template_parts(expr: &Expr, ctx: &Context, text: &mut String) {
+  let expr = expr.unwrap_parens();
+  let raw = expr.range().text_fast(ctx.text_info());
+  match expr {
+    Expr::Lit(Lit::Str(_)) => {
+      push_template_text(
+        text,
+        &string_to_template_text(&raw[1..raw.len() - 1]),
+      );
+    }
+    Expr::Tpl(_) => push_template_text(text, &raw[1..raw.len() - 1]),
+    Expr::Bin(bin) if bin.op == BinaryOp::Add && has_string_literal(expr) => {
+      push_template_parts(&bin.left, ctx, text);
+      push_template_parts(&bin.right, ctx, text);
+    }
+    _ => {
+      text.push_str("${");
+      text.push_str(raw);
+      text.push('}');
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Some tests are derived from
+  // https://github.com/eslint/eslint/blob/v9.9.0/tests/lib/rules/prefer-template.js
+  // MIT Licensed.
+
+  #[test]
+  fn prefer_template_valid() {
+    assert_lint_ok! {
+      PreferTemplate,
+      "var foo = 'bar';",
+      "var foo = 'bar' + 'baz';",
+      "var foo = foo + +'100';",
+      "var foo = `bar`;",
+      "var foo = `hello, ${name}!`;",
+      "var foo = `foo` + `bar` + \"hoge\";",
+      "var foo = `foo` +\n    `bar` +\n    \"hoge\";",
+      "var foo = a + b;",
+      "foo += 'bar';",
+    };
+  }
+
+  #[test]
+  fn prefer_template_invalid() {
+    assert_lint_err! {
+      PreferTemplate,
+      "var foo = 'hello, ' + name + '!';": [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "var foo = `hello, ${name}!`;"),
+        }
+      ],
+      "var foo = bar + 'baz';": [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "var foo = `${bar}baz`;"),
+        }
+      ],
+      "var foo = bar + `baz`;": [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "var foo = `${bar}baz`;"),
+        }
+      ],
+      "var foo = +100 + 'yen';": [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "var foo = `${+100}yen`;"),
+        }
+      ],
+      "var foo = 'bar' + baz + 'qux';": [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "var foo = `bar${baz}qux`;"),
+        }
+      ],
+      "var string = (number + 1) + 'px';": [
+        {
+          col: 13,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "var string = `${number + 1}px`;"),
+        }
+      ],
+      "var foo = 1 + 2 + 'px';": [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "var foo = `${1 + 2}px`;"),
+        }
+      ],
+      "var foo = 'a' + (b + 'c');": [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "var foo = `a${b}c`;"),
+        }
+      ],
+      "var foo = 'aaa' + aaa; var bar = 'bbb' + bbb;": [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "var foo = `aaa${aaa}`; var bar = 'bbb' + bbb;"),
+        },
+        {
+          col: 33,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "var foo = 'aaa' + aaa; var bar = `bbb${bbb}`;"),
+        }
+      ],
+      "var foo = 'a`b' + c + '${d}';": [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "var foo = `a\\`b${c}\\${d}`;"),
+        }
+      ],
+      "var foo = '$' + '{' + a;": [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "var foo = `\\${${a}`;"),
+        }
+      ],
+      "var foo = `a$` + '{b}' + c;": [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "var foo = `a\\${b}${c}`;"),
+        }
+      ],
+      "var foo = \"it's \\\"\" + a;": [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "var foo = `it's \\\"${a}`;"),
+        }
+      ],
+      "var foo = `a${b}` + c;": [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "var foo = `a${b}${c}`;"),
+        }
+      ],
+      "var foo = 'a' + /* b */ b;": [
+        {
+          col: 10,
+          message: MESSAGE,
+          hint: HINT,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn prefer_template_ecma_version() {
+    let lint = |source: &str, ecma_version: EcmaVersion| {
+      let (_, diagnostics) = crate::testing::lint_with_ecma_version(
+        Box::new(PreferTemplate),
+        source,
+        "file:///prefer_template_test.ts",
+        ecma_version,
+      );
+      diagnostics
+    };
+    // Template literals are only available from ES2015 on.
+    assert!(lint("const a = \"a\" + b;", EcmaVersion::Es5).is_empty());
+    assert_eq!(lint("const a = \"a\" + b;", EcmaVersion::Es2015).len(), 1);
+  }
+}
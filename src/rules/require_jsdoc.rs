@@ -0,0 +1,481 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::tags::{self, Tags};
+use crate::Program;
+use deno_ast::swc::ast::{
+  Decl, Decorator, DefaultDecl, ExportSpecifier, Expr, ModuleDecl,
+  ModuleExportName, ModuleItem, Pat, Stmt, TsModuleName,
+};
+use deno_ast::{SourcePos, SourceRange, SourceRangedForSpanned};
+use serde::Deserialize;
+use std::collections::HashSet;
+
+#[derive(Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct RequireJsdoc {
+  /// The kinds of exported declarations that require a JSDoc comment.
+  pub kinds: Vec<DeclKind>,
+}
+
+impl Default for RequireJsdoc {
+  fn default() -> Self {
+    Self {
+      kinds: vec![DeclKind::Function, DeclKind::Class, DeclKind::Interface],
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DeclKind {
+  /// Functions, including variables initialized with a function or an arrow
+  /// function.
+  Function,
+  Class,
+  Interface,
+  TypeAlias,
+  Enum,
+  /// Variables that aren't initialized with a function.
+  Variable,
+  Namespace,
+}
+
+impl DeclKind {
+  fn as_str(&self) -> &'static str {
+    match self {
+      DeclKind::Function => "function",
+      DeclKind::Class => "class",
+      DeclKind::Interface => "interface",
+      DeclKind::TypeAlias => "type alias",
+      DeclKind::Enum => "enum",
+      DeclKind::Variable => "variable",
+      DeclKind::Namespace => "namespace",
+    }
+  }
+}
+
+const CODE: &str = "require-jsdoc";
+const HINT: &str =
+  "Add a JSDoc comment (`/** ... */`) right before the declaration to document it";
+
+impl LintRule for RequireJsdoc {
+  fn tags(&self) -> Tags {
+    &[tags::JSDOC]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Requires JSDoc comments on the exported symbols of a \
+        module, which make up its public API. Documented symbols improve \
+        the generated documentation and the documentation score of packages \
+        published to JSR. By default functions, classes and interfaces are \
+        checked, which can be changed with the `kinds` option. Declarations \
+        exported with `export { name }` are checked as well, and only the \
+        first declaration of overloaded functions and merged declarations \
+        needs a comment.",
+      invalid_examples: &[
+        "export function add(a: number, b: number): number {\n  return a + b;\n}",
+        "// Not a JSDoc comment.\nexport class Point {}",
+        "interface Options {}\nexport { Options };",
+      ],
+      valid_examples: &[
+        "/** Adds two numbers. */\nexport function add(a: number, b: number): number {\n  return a + b;\n}",
+        "/**\n * A point in a two-dimensional space.\n */\nexport class Point {}",
+        "function internal() {}",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "kinds": {
+          "description": "The kinds of exported declarations that require a JSDoc comment. Defaults to functions, classes and interfaces",
+          "type": "array",
+          "items": {
+            "type": "string",
+            "enum": [
+              "function",
+              "class",
+              "interface",
+              "typeAlias",
+              "enum",
+              "variable",
+              "namespace"
+            ]
+          },
+          "uniqueItems": true
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<RequireJsdoc>(options)?))
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    let Program::Module(module) = program else {
+      return;
+    };
+    let body = &module.inner.body;
+
+    // Local declarations can be exported separately with `export { name }`
+    // and `export default name`.
+    let mut exported_names = HashSet::new();
+    for item in body {
+      match item {
+        ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(named_export))
+          if named_export.src.is_none() =>
+        {
+          for specifier in &named_export.specifiers {
+            if let ExportSpecifier::Named(specifier) = specifier {
+              if let ModuleExportName::Ident(ident) = &specifier.orig {
+                exported_names.insert(&*ident.sym);
+              }
+            }
+          }
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export)) => {
+          if let Expr::Ident(ident) = &*export.expr {
+            exported_names.insert(&*ident.sym);
+          }
+        }
+        _ => {}
+      }
+    }
+
+    let mut checker = Checker {
+      rule: self,
+      context,
+      checked: HashSet::new(),
+    };
+    for item in body {
+      match item {
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => {
+          let comment_pos = match &export.decl {
+            Decl::Class(class_decl) => {
+              comment_pos(export.start(), &class_decl.class.decorators)
+            }
+            _ => export.start(),
+          };
+          checker.check_decl(&export.decl, comment_pos, None);
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(export)) => {
+          let mut comment_start = export.start();
+          let (kind, ident) = match &export.decl {
+            DefaultDecl::Class(class) => {
+              comment_start =
+                comment_pos(comment_start, &class.class.decorators);
+              (DeclKind::Class, class.ident.as_ref())
+            }
+            DefaultDecl::Fn(function) => {
+              (DeclKind::Function, function.ident.as_ref())
+            }
+            DefaultDecl::TsInterfaceDecl(interface) => {
+              (DeclKind::Interface, Some(&interface.id))
+            }
+          };
+          let (name, range) = match ident {
+            Some(ident) => (&*ident.sym, ident.range()),
+            None => ("default", export.range()),
+          };
+          checker.check(kind, name, range, comment_start);
+        }
+        ModuleItem::Stmt(Stmt::Decl(decl)) => {
+          let comment_pos = match decl {
+            Decl::Class(class_decl) => {
+              comment_pos(decl.start(), &class_decl.class.decorators)
+            }
+            _ => decl.start(),
+          };
+          checker.check_decl(decl, comment_pos, Some(&exported_names));
+        }
+        _ => {}
+      }
+    }
+  }
+}
+
+/// Returns where the leading JSDoc comment of a declaration starting at
+/// `start` ends up, which is before its decorators when they come first, as
+/// in `@dec export class A {}`.
+fn comment_pos(start: SourcePos, decorators: &[Decorator]) -> SourcePos {
+  decorators
+    .first()
+    .map_or(start, |decorator| decorator.start().min(start))
+}
+
+struct Checker<'a, 'b, 'view> {
+  rule: &'a RequireJsdoc,
+  context: &'a mut Context<'view>,
+  /// Names whose first declaration was already checked, so that overloads
+  /// and merged declarations don't need their own comments.
+  checked: HashSet<&'b str>,
+}
+
+impl<'a, 'b, 'view> Checker<'a, 'b, 'view> {
+  /// Checks the names declared by `decl`, or only the ones in
+  /// `exported_names` if it isn't exported itself.
+  fn check_decl(
+    &mut self,
+    decl: &'b Decl,
+    comment_pos: SourcePos,
+    exported_names: Option<&HashSet<&str>>,
+  ) {
+    let is_exported =
+      |name: &str| exported_names.map_or(true, |names| names.contains(name));
+    let (kind, ident) = match decl {
+      Decl::Fn(fn_decl) => (DeclKind::Function, &fn_decl.ident),
+      Decl::Class(class_decl) => (DeclKind::Class, &class_decl.ident),
+      Decl::TsInterface(interface) => (DeclKind::Interface, &interface.id),
+      Decl::TsTypeAlias(type_alias) => (DeclKind::TypeAlias, &type_alias.id),
+      Decl::TsEnum(ts_enum) => (DeclKind::Enum, &ts_enum.id),
+      Decl::TsModule(module) => match &module.id {
+        TsModuleName::Ident(ident) if !module.global => {
+          (DeclKind::Namespace, ident)
+        }
+        _ => return,
+      },
+      Decl::Var(var_decl) => {
+        for declarator in &var_decl.decls {
+          let Pat::Ident(ident) = &declarator.name else {
+            continue;
+          };
+          if !is_exported(&ident.id.sym) {
+            continue;
+          }
+          let kind = match declarator.init.as_deref().map(Expr::unwrap_parens) {
+            Some(Expr::Fn(_) | Expr::Arrow(_)) => DeclKind::Function,
+            _ => DeclKind::Variable,
+          };
+          self.check(kind, &ident.id.sym, ident.id.range(), comment_pos);
+        }
+        return;
+      }
+      Decl::Using(_) => return,
+    };
+    if is_exported(&ident.sym) {
+      self.check(kind, &ident.sym, ident.range(), comment_pos);
+    }
+  }
+
+  fn check(
+    &mut self,
+    kind: DeclKind,
+    name: &'b str,
+    range: SourceRange,
+    comment_pos: SourcePos,
+  ) {
+    if !self.checked.insert(name) || !self.rule.kinds.contains(&kind) {
+      return;
+    }
+    let has_jsdoc = self
+      .context
+      .leading_jsdoc_at(comment_pos)
+      .map_or(false, |jsdoc| {
+        !jsdoc.description.is_empty() || !jsdoc.tags.is_empty()
+      });
+    if !has_jsdoc {
+      self.context.add_diagnostic_with_hint(
+        range,
+        CODE,
+        format!("Exported {} `{}` has no JSDoc comment", kind.as_str(), name),
+        HINT,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn require_jsdoc_valid() {
+    assert_lint_ok! {
+      RequireJsdoc::default(),
+      "/** Foo. */\nexport function foo() {}",
+      "/** Foo. */ export async function* foo() {}",
+      "/** A. */\nexport class A {}",
+      "/** A. */\nexport interface A {}",
+      "/** Foo. */\nexport default function foo() {}",
+      "/** Foo. */\nexport default function () {}",
+      "/** Foo. */\nexport const foo = () => {};",
+      "/**\n * Foo.\n */\n// deno-lint-ignore no-explicit-any\nexport function foo(a: any) {}",
+      "/** Foo. */\nfunction foo() {}\nexport { foo };",
+      "/** Foo. */\nfunction foo() {}\nexport default foo;",
+      "/** Foo. */\nexport function foo(a: string): void;\nexport function foo(a: number): void;\nexport function foo(a) {}",
+      "function foo() {}",
+      "export const a = 1;",
+      "export type A = string;",
+      "export enum A {}",
+      "export namespace A {}",
+      "export { foo } from \"./foo.ts\";",
+      "export * from \"./foo.ts\";",
+      "declare global { interface A {} }",
+      "/** @deprecated */\n@dec\nexport class D {}",
+      "/** D. */\n@dec\nexport default class D {}",
+      "/** D. */\nexport @dec class D {}",
+      "/** D. */\n@dec\nclass D {}\nexport { D };",
+    };
+
+    assert_lint_ok! {
+      RequireJsdoc { kinds: vec![DeclKind::Variable] },
+      "export function foo() {}",
+      "/** A. */\nexport const a = 1, b = 2;",
+    };
+  }
+
+  #[test]
+  fn require_jsdoc_invalid() {
+    assert_lint_err! {
+      RequireJsdoc::default(),
+      "export function foo() {}": [
+        {
+          col: 16,
+          message: "Exported function `foo` has no JSDoc comment",
+          hint: HINT,
+        }
+      ],
+      "/* Foo. */\nexport function foo() {}": [
+        {
+          line: 2,
+          col: 16,
+          message: "Exported function `foo` has no JSDoc comment",
+          hint: HINT,
+        }
+      ],
+      "/** Foo. */\n\nfunction bar() {}\nexport function foo() {}": [
+        {
+          line: 4,
+          col: 16,
+          message: "Exported function `foo` has no JSDoc comment",
+          hint: HINT,
+        }
+      ],
+      "/***/\nexport class A {}": [
+        {
+          line: 2,
+          col: 13,
+          message: "Exported class `A` has no JSDoc comment",
+          hint: HINT,
+        }
+      ],
+      "/** */\nexport class A {}": [
+        {
+          line: 2,
+          col: 13,
+          message: "Exported class `A` has no JSDoc comment",
+          hint: HINT,
+        }
+      ],
+      "export interface A {}": [
+        {
+          col: 17,
+          message: "Exported interface `A` has no JSDoc comment",
+          hint: HINT,
+        }
+      ],
+      "export const foo = function () {};": [
+        {
+          col: 13,
+          message: "Exported function `foo` has no JSDoc comment",
+          hint: HINT,
+        }
+      ],
+      "export default class {}": [
+        {
+          col: 0,
+          message: "Exported class `default` has no JSDoc comment",
+          hint: HINT,
+        }
+      ],
+      "class A {}\nfunction foo() {}\nexport { A, foo as bar };": [
+        {
+          col: 6,
+          message: "Exported class `A` has no JSDoc comment",
+          hint: HINT,
+        },
+        {
+          line: 2,
+          col: 9,
+          message: "Exported function `foo` has no JSDoc comment",
+          hint: HINT,
+        }
+      ],
+      "function foo() {}\nexport default foo;": [
+        {
+          col: 9,
+          message: "Exported function `foo` has no JSDoc comment",
+          hint: HINT,
+        }
+      ],
+      "export function foo(a: string): void;\n/** Foo. */\nexport function foo(a) {}": [
+        {
+          col: 16,
+          message: "Exported function `foo` has no JSDoc comment",
+          hint: HINT,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      RequireJsdoc {
+        kinds: vec![
+          DeclKind::TypeAlias,
+          DeclKind::Enum,
+          DeclKind::Variable,
+          DeclKind::Namespace,
+        ],
+      },
+      "export type A = string;": [
+        {
+          col: 12,
+          message: "Exported type alias `A` has no JSDoc comment",
+          hint: HINT,
+        }
+      ],
+      "export enum A {}": [
+        {
+          col: 12,
+          message: "Exported enum `A` has no JSDoc comment",
+          hint: HINT,
+        }
+      ],
+      "export let a = 1, b;": [
+        {
+          col: 11,
+          message: "Exported variable `a` has no JSDoc comment",
+          hint: HINT,
+        },
+        {
+          col: 18,
+          message: "Exported variable `b` has no JSDoc comment",
+          hint: HINT,
+        }
+      ],
+      "export namespace A {}": [
+        {
+          col: 17,
+          message: "Exported namespace `A` has no JSDoc comment",
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
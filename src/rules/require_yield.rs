@@ -11,10 +11,12 @@ use deno_ast::swc::ast::FnExpr;
 use deno_ast::swc::ast::Function;
 use deno_ast::swc::ast::MethodProp;
 use deno_ast::swc::ast::PrivateMethod;
+use deno_ast::swc::ast::Stmt;
 use deno_ast::swc::ast::YieldExpr;
 use deno_ast::swc::visit::Visit;
 use deno_ast::swc::visit::{noop_visit_type, VisitWith};
 use deno_ast::SourceRangedForSpanned;
+use std::mem;
 
 #[derive(Debug)]
 pub struct RequireYield;
@@ -48,6 +50,9 @@ impl LintRule for RequireYield {
 struct RequireYieldVisitor<'c, 'view> {
   context: &'c mut Context<'view>,
   yield_stack: Vec<u32>,
+  /// Whether the visited code can't be reached, so that a `yield` in it
+  /// doesn't count.
+  in_unreachable: bool,
 }
 
 impl<'c, 'view> RequireYieldVisitor<'c, 'view> {
@@ -55,16 +60,23 @@ impl<'c, 'view> RequireYieldVisitor<'c, 'view> {
     Self {
       context,
       yield_stack: vec![],
+      in_unreachable: false,
     }
   }
 
-  fn enter_function(&mut self, function: &Function) {
+  /// Returns whether the code around the function is unreachable, which is
+  /// restored by `exit_function`.
+  fn enter_function(&mut self, function: &Function) -> bool {
     if function.is_generator {
       self.yield_stack.push(0);
     }
+    // The reachability of a function's body doesn't depend on where the
+    // function is declared.
+    mem::replace(&mut self.in_unreachable, false)
   }
 
-  fn exit_function(&mut self, function: &Function) {
+  fn exit_function(&mut self, function: &Function, in_unreachable: bool) {
+    self.in_unreachable = in_unreachable;
     if function.is_generator {
       let yield_count = self.yield_stack.pop().unwrap();
 
@@ -83,39 +95,51 @@ impl<'c, 'view> Visit for RequireYieldVisitor<'c, 'view> {
   noop_visit_type!();
 
   fn visit_yield_expr(&mut self, _yield_expr: &YieldExpr) {
+    if self.in_unreachable {
+      return;
+    }
     if let Some(last) = self.yield_stack.last_mut() {
       *last += 1;
     }
   }
 
+  fn visit_stmt(&mut self, stmt: &Stmt) {
+    let in_unreachable = self.in_unreachable;
+    if let Some(meta) = self.context.control_flow().meta(stmt.start()) {
+      self.in_unreachable |= meta.unreachable;
+    }
+    stmt.visit_children_with(self);
+    self.in_unreachable = in_unreachable;
+  }
+
   fn visit_fn_decl(&mut self, fn_decl: &FnDecl) {
-    self.enter_function(&fn_decl.function);
+    let in_unreachable = self.enter_function(&fn_decl.function);
     fn_decl.visit_children_with(self);
-    self.exit_function(&fn_decl.function);
+    self.exit_function(&fn_decl.function, in_unreachable);
   }
 
   fn visit_fn_expr(&mut self, fn_expr: &FnExpr) {
-    self.enter_function(&fn_expr.function);
+    let in_unreachable = self.enter_function(&fn_expr.function);
     fn_expr.visit_children_with(self);
-    self.exit_function(&fn_expr.function);
+    self.exit_function(&fn_expr.function, in_unreachable);
   }
 
   fn visit_class_method(&mut self, class_method: &ClassMethod) {
-    self.enter_function(&class_method.function);
+    let in_unreachable = self.enter_function(&class_method.function);
     class_method.visit_children_with(self);
-    self.exit_function(&class_method.function);
+    self.exit_function(&class_method.function, in_unreachable);
   }
 
   fn visit_private_method(&mut self, private_method: &PrivateMethod) {
-    self.enter_function(&private_method.function);
+    let in_unreachable = self.enter_function(&private_method.function);
     private_method.visit_children_with(self);
-    self.exit_function(&private_method.function);
+    self.exit_function(&private_method.function, in_unreachable);
   }
 
   fn visit_method_prop(&mut self, method_prop: &MethodProp) {
-    self.enter_function(&method_prop.function);
+    let in_unreachable = self.enter_function(&method_prop.function);
     method_prop.visit_children_with(self);
-    self.exit_function(&method_prop.function);
+    self.exit_function(&method_prop.function, in_unreachable);
   }
 }
 
@@ -150,6 +174,10 @@ const obj = {
   }
 };
       "#,
+      r#"async function* foo() { yield await bar(); }"#,
+      r#"async function* foo() { for await (const x of xs) { yield x; } }"#,
+      r#"function* foo() { if (a) { return; } yield 1; }"#,
+      r#"async function* foo() {}"#,
     };
   }
 
@@ -172,6 +200,12 @@ class Fizz {
   }
 }
     "#: [{ line: 3, col: 2, message: MESSAGE }, { line: 7, col: 2, message: MESSAGE }],
+      r#"async function* foo() { await bar(); }"#: [{ col: 0, message: MESSAGE }],
+      r#"class A { async *foo() { return 1; } }"#: [{ col: 10, message: MESSAGE }],
+      r#"function* foo() { return; yield 1; }"#: [{ col: 0, message: MESSAGE }],
+      r#"async function* foo() { throw new Error(); yield* bar(); }"#: [{ col: 0, message: MESSAGE }],
+      r#"function* foo() { while (true) { break; yield 1; } }"#: [{ col: 0, message: MESSAGE }],
+      r#"function* foo() { return; function* bar() { yield 1; } }"#: [{ col: 0, message: MESSAGE }],
     }
   }
 }
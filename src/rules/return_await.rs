@@ -0,0 +1,431 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::{Handler, Traverse};
+use crate::tags::Tags;
+use crate::Program;
+use deno_ast::swc::ast::{BlockStmtOrExpr, Callee, Expr, MemberProp};
+use deno_ast::view::{self as ast_view, Node, NodeTrait};
+use deno_ast::{SourceRange, SourceRanged, SourceRangedForSpanned};
+use serde::Deserialize;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct ReturnAwait {
+  /// When returned promises have to be awaited.
+  pub mode: Mode,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Mode {
+  /// Returned promises have to be awaited where errors are handled by an
+  /// enclosing `try` statement, and must not be awaited elsewhere.
+  #[default]
+  InTryCatch,
+  Always,
+  Never,
+}
+
+const CODE: &str = "return-await";
+const REQUIRED_MESSAGE: &str =
+  "Returning an awaited promise is required in this context";
+const DISALLOWED_MESSAGE: &str =
+  "Returning an awaited promise is not allowed in this context";
+const REQUIRED_HINT: &str = "Add `await` before the returned promise";
+const DISALLOWED_HINT: &str =
+  "Remove `await`, the returned promise is awaited by the caller";
+const ERROR_HANDLING_HINT: &str = "Add `await` before the returned promise, otherwise its rejection isn't handled by the enclosing `try` statement";
+const ADD_FIX_DESC: &str = "Add `await`";
+const REMOVE_FIX_DESC: &str = "Remove `await`";
+
+impl LintRule for ReturnAwait {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Enforces a consistent use of `await` on values returned \
+        from async functions. In the default `in-try-catch` mode, a returned \
+        promise has to be awaited inside a `try` block, or inside a `catch` \
+        block followed by a `finally` block, since the `try` statement only \
+        handles the rejection of an awaited promise. Elsewhere, `return \
+        await` is redundant and not allowed. The `always` and `never` modes \
+        require or disallow `await` on every returned promise. Without type \
+        information, only `new Promise()` and calls of `Promise` methods, \
+        `.then()`, `.catch()` and `.finally()` are known to be promises.",
+      invalid_examples: &[
+        "async function load() {\n  try {\n    return fetch(url).then((res) => res.json());\n  } catch {\n    return null;\n  }\n}",
+        "async function load() {\n  return await fetch(url);\n}",
+      ],
+      valid_examples: &[
+        "async function load() {\n  try {\n    return await fetch(url).then((res) => res.json());\n  } catch {\n    return null;\n  }\n}",
+        "async function load() {\n  return fetch(url);\n}",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "mode": {
+          "description": "When returned promises have to be awaited. Defaults to `in-try-catch`",
+          "enum": ["in-try-catch", "always", "never"]
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<ReturnAwait>(options)?))
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    ReturnAwaitHandler { mode: self.mode }.traverse(program, context);
+  }
+}
+
+struct ReturnAwaitHandler {
+  mode: Mode,
+}
+
+impl ReturnAwaitHandler {
+  /// Checks a value returned from an async function. `handles_errors` is
+  /// whether an enclosing `try` statement handles the rejection of the value
+  /// if it is awaited.
+  fn check(&self, arg: &Expr, handles_errors: bool, ctx: &mut Context) {
+    let must_await = match self.mode {
+      Mode::InTryCatch => handles_errors,
+      Mode::Always => true,
+      Mode::Never => false,
+    };
+
+    if let Expr::Await(await_expr) = arg.unwrap_parens() {
+      if must_await {
+        return;
+      }
+      let range = await_expr.range();
+      let fixes = if ctx.has_comments_in_range(range) {
+        vec![]
+      } else {
+        vec![LintFix {
+          description: REMOVE_FIX_DESC.into(),
+          changes: vec![LintFixChange {
+            new_text: await_expr
+              .arg
+              .range()
+              .text_fast(ctx.text_info())
+              .to_string()
+              .into(),
+            range,
+          }],
+        }]
+      };
+      // Removing `await` changes which errors the `try` statement handles,
+      // so it is only suggested.
+      if handles_errors {
+        ctx.add_diagnostic_with_suggestions(
+          range,
+          CODE,
+          DISALLOWED_MESSAGE,
+          Some(DISALLOWED_HINT.to_string()),
+          fixes,
+        );
+      } else {
+        ctx.add_diagnostic_with_fixes(
+          range,
+          CODE,
+          DISALLOWED_MESSAGE,
+          Some(DISALLOWED_HINT.to_string()),
+          fixes,
+        );
+      }
+    } else if must_await && is_promise(arg) {
+      let range = arg.range();
+      let hint = if handles_errors {
+        ERROR_HANDLING_HINT
+      } else {
+        REQUIRED_HINT
+      };
+      ctx.add_diagnostic_with_fixes(
+        range,
+        CODE,
+        REQUIRED_MESSAGE,
+        Some(hint.to_string()),
+        vec![LintFix {
+          description: ADD_FIX_DESC.into(),
+          changes: vec![LintFixChange {
+            new_text: "await ".into(),
+            range: SourceRange::new(range.start, range.start),
+          }],
+        }],
+      );
+    }
+  }
+}
+
+impl Handler for ReturnAwaitHandler {
+  fn return_stmt(
+    &mut self,
+    return_stmt: &ast_view::ReturnStmt,
+    ctx: &mut Context,
+  ) {
+    let Some(arg) = &return_stmt.inner.arg else {
+      return;
+    };
+    let mut handles_errors = false;
+    let mut child = return_stmt.as_node();
+    while let Some(parent) = child.parent() {
+      match parent {
+        Node::TryStmt(try_stmt) => {
+          if try_stmt.block.range() == child.range()
+            || (try_stmt.finalizer.is_some()
+              && matches!(child, Node::CatchClause(_)))
+          {
+            handles_errors = true;
+          }
+        }
+        Node::Function(function) => {
+          if function.inner.is_async && !function.inner.is_generator {
+            self.check(arg, handles_errors, ctx);
+          }
+          return;
+        }
+        Node::ArrowExpr(arrow) => {
+          if arrow.inner.is_async {
+            self.check(arg, handles_errors, ctx);
+          }
+          return;
+        }
+        Node::Constructor(_)
+        | Node::GetterProp(_)
+        | Node::SetterProp(_)
+        | Node::StaticBlock(_) => return,
+        _ => {}
+      }
+      child = parent;
+    }
+  }
+
+  fn arrow_expr(&mut self, arrow: &ast_view::ArrowExpr, ctx: &mut Context) {
+    if !arrow.inner.is_async {
+      return;
+    }
+    if let BlockStmtOrExpr::Expr(expr) = &*arrow.inner.body {
+      self.check(expr, false, ctx);
+    }
+  }
+}
+
+/// Checks if an expression evaluates to a promise, as far as can be told
+/// without type information.
+fn is_promise(expr: &Expr) -> bool {
+  match expr.unwrap_parens() {
+    Expr::New(new_expr) => {
+      matches!(&*new_expr.callee, Expr::Ident(ident) if ident.sym == *"Promise")
+    }
+    Expr::Call(call_expr) => {
+      let Callee::Expr(callee) = &call_expr.callee else {
+        return false;
+      };
+      let Expr::Member(member) = &**callee else {
+        return false;
+      };
+      let MemberProp::Ident(prop) = &member.prop else {
+        return false;
+      };
+      match &*member.obj {
+        Expr::Ident(obj) if obj.sym == *"Promise" => matches!(
+          &*prop.sym,
+          "all" | "allSettled" | "any" | "race" | "reject" | "resolve"
+        ),
+        _ => matches!(&*prop.sym, "then" | "catch" | "finally"),
+      }
+    }
+    _ => false,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn return_await_valid() {
+    assert_lint_ok! {
+      ReturnAwait::default(),
+      "async function f() { return p; }",
+      "async function f() { return fetch(url); }",
+      "async function f() { return Promise.resolve(1); }",
+      "async function f() { try { return await p; } catch {} }",
+      "async function f() { try { return await p; } finally {} }",
+      "async function f() { try { return fetch(url); } catch {} }",
+      "async function f() { try {} catch { return p; } }",
+      "async function f() { try {} catch { return await p; } finally {} }",
+      "async function f() { try {} finally { return p; } }",
+      "async function f() { try { if (a) { return await p; } } catch {} }",
+      "async function f() { try {} catch { try { return await p; } catch {} } }",
+      "async function f() { try { const g = async () => { return p; }; } catch {} }",
+      "async function f() { try { function g() { return Promise.resolve(); } } catch {} }",
+      "function f() { return await p; }",
+      "async function* f() { return await p; }",
+      "const f = async () => p;",
+      "const f = async () => { return p; };",
+      "class A { async f() { try { return await p; } catch {} } }",
+    };
+  }
+
+  #[test]
+  fn return_await_invalid() {
+    assert_lint_err! {
+      ReturnAwait::default(),
+      "async function f() { return await p; }": [
+        {
+          col: 28,
+          message: DISALLOWED_MESSAGE,
+          hint: DISALLOWED_HINT,
+          fix: (REMOVE_FIX_DESC, "async function f() { return p; }"),
+        }
+      ],
+      "async function f() { return (await p); }": [
+        {
+          col: 29,
+          message: DISALLOWED_MESSAGE,
+          hint: DISALLOWED_HINT,
+          fix: (REMOVE_FIX_DESC, "async function f() { return (p); }"),
+        }
+      ],
+      "const f = async () => await p;": [
+        {
+          col: 22,
+          message: DISALLOWED_MESSAGE,
+          hint: DISALLOWED_HINT,
+          fix: (REMOVE_FIX_DESC, "const f = async () => p;"),
+        }
+      ],
+      "async function f() { try {} catch { return await p; } }": [
+        {
+          col: 43,
+          message: DISALLOWED_MESSAGE,
+          hint: DISALLOWED_HINT,
+          fix: (REMOVE_FIX_DESC, "async function f() { try {} catch { return p; } }"),
+        }
+      ],
+      "async function f() { try {} finally { return await p; } }": [
+        {
+          col: 45,
+          message: DISALLOWED_MESSAGE,
+          hint: DISALLOWED_HINT,
+          fix: (REMOVE_FIX_DESC, "async function f() { try {} finally { return p; } }"),
+        }
+      ],
+      "async function f() { try { return Promise.resolve(1); } catch {} }": [
+        {
+          col: 34,
+          message: REQUIRED_MESSAGE,
+          hint: ERROR_HANDLING_HINT,
+          fix: (ADD_FIX_DESC, "async function f() { try { return await Promise.resolve(1); } catch {} }"),
+        }
+      ],
+      "async function f() { try {} catch { return p.then(g); } finally {} }": [
+        {
+          col: 43,
+          message: REQUIRED_MESSAGE,
+          hint: ERROR_HANDLING_HINT,
+          fix: (ADD_FIX_DESC, "async function f() { try {} catch { return await p.then(g); } finally {} }"),
+        }
+      ],
+      "async function f() { try { try {} finally { return new Promise(r); } } catch {} }": [
+        {
+          col: 51,
+          message: REQUIRED_MESSAGE,
+          hint: ERROR_HANDLING_HINT,
+          fix: (ADD_FIX_DESC, "async function f() { try { try {} finally { return await new Promise(r); } } catch {} }"),
+        }
+      ],
+      "async function f() { return await /* wait */ p; }": [
+        {
+          col: 28,
+          message: DISALLOWED_MESSAGE,
+          hint: DISALLOWED_HINT,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn return_await_always() {
+    assert_lint_ok! {
+      ReturnAwait { mode: Mode::Always },
+      "async function f() { return await p; }",
+      "async function f() { return p; }",
+      "const f = async () => await p;",
+    };
+    assert_lint_err! {
+      ReturnAwait { mode: Mode::Always },
+      "async function f() { return Promise.all(ps); }": [
+        {
+          col: 28,
+          message: REQUIRED_MESSAGE,
+          hint: REQUIRED_HINT,
+          fix: (ADD_FIX_DESC, "async function f() { return await Promise.all(ps); }"),
+        }
+      ],
+      "const f = async () => p.catch(g);": [
+        {
+          col: 22,
+          message: REQUIRED_MESSAGE,
+          hint: REQUIRED_HINT,
+          fix: (ADD_FIX_DESC, "const f = async () => await p.catch(g);"),
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn return_await_never() {
+    assert_lint_ok! {
+      ReturnAwait { mode: Mode::Never },
+      "async function f() { return p; }",
+      "async function f() { try { return Promise.resolve(1); } catch {} }",
+    };
+    assert_lint_err! {
+      ReturnAwait { mode: Mode::Never },
+      "async function f() { return await p; }": [
+        {
+          col: 28,
+          message: DISALLOWED_MESSAGE,
+          hint: DISALLOWED_HINT,
+          fix: (REMOVE_FIX_DESC, "async function f() { return p; }"),
+        }
+      ],
+      "async function f() { try { return await p; } catch {} }": [
+        {
+          col: 34,
+          message: DISALLOWED_MESSAGE,
+          hint: DISALLOWED_HINT,
+          suggestion: (REMOVE_FIX_DESC, "async function f() { try { return p; } catch {} }"),
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,376 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{program_ref, Context, LintRule, RuleDocs};
+use crate::diagnostic::LintFix;
+use crate::reorder::{reorder_change, sorted_order};
+use crate::tags::Tags;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{ImportDecl, ModuleDecl, ModuleItem};
+use deno_ast::SourceRangedForSpanned;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct SortImports {
+  /// The order of the import groups. Groups that are left out come after
+  /// all of the listed groups.
+  pub groups: Vec<ImportGroup>,
+  /// Prefixes of specifiers that import modules of the project itself, e.g.
+  /// `@/` for an import map entry.
+  pub internal_prefixes: Vec<String>,
+  /// Sorts the imports of a group by their specifier.
+  pub alphabetize: bool,
+  /// Ignores the case of the specifiers when sorting them.
+  pub ignore_case: bool,
+}
+
+impl Default for SortImports {
+  fn default() -> Self {
+    Self {
+      groups: vec![
+        ImportGroup::Std,
+        ImportGroup::External,
+        ImportGroup::Internal,
+        ImportGroup::Relative,
+      ],
+      internal_prefixes: Vec::new(),
+      alphabetize: true,
+      ignore_case: false,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ImportGroup {
+  /// The standard library and the Node.js built-in modules, e.g. `jsr:@std/`
+  /// or `node:fs`.
+  Std,
+  /// Other packages and remote modules, e.g. `npm:`, `jsr:` or `https:`.
+  External,
+  /// Specifiers starting with one of the `internalPrefixes`.
+  Internal,
+  /// Relative and absolute paths, e.g. `./mod.ts`.
+  Relative,
+}
+
+const CODE: &str = "sort-imports";
+const HINT: &str = "Reorder the imports by group and specifier";
+const FIX_DESC: &str = "Sort the imports";
+
+impl LintRule for SortImports {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Enforces a consistent order of import statements. Imports \
+        are put into groups, by default the standard library (`jsr:@std/`, \
+        `https://deno.land/std` and `node:` modules), then external \
+        modules, then internal modules matching the `internalPrefixes` \
+        option, then relative paths. Within a group, imports are sorted by \
+        their specifier unless `alphabetize` is turned off. Side effect \
+        imports like `import \"./setup.ts\";` are never moved, and split the \
+        imports around them into separately sorted blocks. The fix keeps \
+        comments on the lines above an import and at the end of its line \
+        with the import, except for the comments at the start of the file, \
+        which stay in place.",
+      invalid_examples: &[
+        "import { parse } from \"./parser.ts\";\nimport { assert } from \"jsr:@std/assert\";",
+        "import { b } from \"./b.ts\";\nimport { a } from \"./a.ts\";",
+      ],
+      valid_examples: &[
+        "import { assert } from \"jsr:@std/assert\";\nimport chalk from \"npm:chalk\";\nimport { a } from \"./a.ts\";\nimport { b } from \"./b.ts\";",
+      ],
+    }
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "groups": {
+          "description": "The order of the import groups, groups that are left out come last",
+          "type": "array",
+          "items": {
+            "enum": ["std", "external", "internal", "relative"]
+          },
+          "uniqueItems": true
+        },
+        "internalPrefixes": {
+          "description": "Prefixes of specifiers that import modules of the project itself, e.g. `@/`",
+          "type": "array",
+          "items": { "type": "string" }
+        },
+        "alphabetize": {
+          "description": "Sorts the imports of a group by their specifier",
+          "type": "boolean"
+        },
+        "ignoreCase": {
+          "description": "Ignores the case of the specifiers when sorting them",
+          "type": "boolean"
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<SortImports>(options)?))
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    let ProgramRef::Module(module) = program_ref(program) else {
+      return;
+    };
+    let mut block = Vec::new();
+    for item in &module.body {
+      match item {
+        ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl))
+          if !import_decl.specifiers.is_empty() =>
+        {
+          block.push(import_decl);
+        }
+        _ => {
+          self.check_block(&block, context);
+          block.clear();
+        }
+      }
+    }
+    self.check_block(&block, context);
+  }
+}
+
+impl SortImports {
+  fn group(&self, specifier: &str) -> ImportGroup {
+    if self
+      .internal_prefixes
+      .iter()
+      .any(|prefix| specifier.starts_with(prefix.as_str()))
+    {
+      ImportGroup::Internal
+    } else if specifier.starts_with("./")
+      || specifier.starts_with("../")
+      || specifier.starts_with('/')
+      || specifier.starts_with("file:")
+    {
+      ImportGroup::Relative
+    } else if specifier.starts_with("jsr:@std/")
+      || specifier.starts_with("jsr:/@std/")
+      || specifier.starts_with("https://deno.land/std@")
+      || specifier.starts_with("https://deno.land/std/")
+      || specifier.starts_with("node:")
+    {
+      ImportGroup::Std
+    } else {
+      ImportGroup::External
+    }
+  }
+
+  fn sort_key(&self, import_decl: &ImportDecl) -> (usize, String) {
+    let specifier = import_decl.src.value.as_str();
+    let group = self.group(specifier);
+    let rank = self
+      .groups
+      .iter()
+      .position(|g| *g == group)
+      .unwrap_or(self.groups.len());
+    let name = if !self.alphabetize {
+      String::new()
+    } else if self.ignore_case {
+      specifier.to_lowercase()
+    } else {
+      specifier.to_string()
+    };
+    (rank, name)
+  }
+
+  /// Checks a block of consecutive imports, and reports the imports that come
+  /// after an import they should be placed before.
+  fn check_block(&self, block: &[&ImportDecl], ctx: &mut Context) {
+    let keys = block
+      .iter()
+      .map(|import_decl| self.sort_key(import_decl))
+      .collect::<Vec<_>>();
+    let unsorted = (1..block.len())
+      .filter(|&i| keys[i] < keys[i - 1])
+      .collect::<Vec<_>>();
+    if unsorted.is_empty() {
+      return;
+    }
+
+    let order = sorted_order(&keys, |a, b| a.cmp(b));
+    let ranges = block
+      .iter()
+      .map(|import_decl| import_decl.range())
+      .collect::<Vec<_>>();
+    let fix = LintFix {
+      description: FIX_DESC.into(),
+      changes: vec![reorder_change(ctx, &ranges, &order)],
+    };
+    for i in unsorted {
+      ctx.add_diagnostic_with_fixes(
+        block[i].range(),
+        CODE,
+        format!(
+          "`{}` should be imported before `{}`",
+          block[i].src.value,
+          block[i - 1].src.value
+        ),
+        Some(HINT.to_string()),
+        vec![fix.clone()],
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn sort_imports_valid() {
+    assert_lint_ok! {
+      SortImports::default(),
+      "import { assert } from 'jsr:@std/assert';\nimport { join } from 'node:path';\nimport chalk from 'npm:chalk';\nimport { a } from './a.ts';",
+      "import { B } from './B.ts';\nimport { a } from './a.ts';",
+      "import { b } from './b.ts';\nimport './setup.ts';\nimport { a } from './a.ts';",
+      "import { b } from './b.ts';\nconst x = 1;\nimport { a } from './a.ts';",
+      "import { serve } from 'https://deno.land/std@0.200.0/http/server.ts';\nimport React from 'https://esm.sh/react';",
+      "import { a } from '../a.ts';\nimport { b } from './b.ts';",
+    };
+
+    assert_lint_ok! {
+      SortImports {
+        groups: vec![ImportGroup::Relative, ImportGroup::Internal],
+        internal_prefixes: vec!["@/".to_string()],
+        alphabetize: false,
+        ignore_case: false,
+      },
+      "import { b } from './b.ts';\nimport { a } from './a.ts';\nimport { c } from '@/c.ts';\nimport chalk from 'npm:chalk';\nimport { assert } from 'jsr:@std/assert';",
+    };
+
+    assert_lint_ok! {
+      SortImports {
+        ignore_case: true,
+        ..Default::default()
+      },
+      "import { a } from './a.ts';\nimport { B } from './B.ts';\nimport { c } from './c.ts';",
+    };
+  }
+
+  #[test]
+  fn sort_imports_invalid() {
+    assert_lint_err! {
+      SortImports::default(),
+      "import { a } from './a.ts';\nimport { assert } from 'jsr:@std/assert';": [
+        {
+          line: 2,
+          col: 0,
+          message: "`jsr:@std/assert` should be imported before `./a.ts`",
+          hint: HINT,
+          fix: (FIX_DESC, "import { assert } from 'jsr:@std/assert';\nimport { a } from './a.ts';"),
+        }
+      ],
+      "import { c } from './c.ts';\nimport { b } from './b.ts';\nimport { a } from './a.ts';": [
+        {
+          line: 2,
+          col: 0,
+          message: "`./b.ts` should be imported before `./c.ts`",
+          hint: HINT,
+          fix: (FIX_DESC, "import { a } from './a.ts';\nimport { b } from './b.ts';\nimport { c } from './c.ts';"),
+        },
+        {
+          line: 3,
+          col: 0,
+          message: "`./a.ts` should be imported before `./b.ts`",
+          hint: HINT,
+          fix: (FIX_DESC, "import { a } from './a.ts';\nimport { b } from './b.ts';\nimport { c } from './c.ts';"),
+        }
+      ],
+      "// Copyright header\nimport { b } from './b.ts'; // b\n\n// a is needed for c\nimport { a } from './a.ts';\n\nrun();": [
+        {
+          line: 5,
+          col: 0,
+          message: "`./a.ts` should be imported before `./b.ts`",
+          hint: HINT,
+          fix: (FIX_DESC, "// Copyright header\n// a is needed for c\nimport { a } from './a.ts';\n\nimport { b } from './b.ts'; // b\n\nrun();"),
+        }
+      ],
+      "import { z } from './z.ts';\nimport { y } from './y.ts';\nimport './setup.ts';\nimport { b } from './b.ts';\nimport { a } from './a.ts';": [
+        {
+          line: 2,
+          col: 0,
+          message: "`./y.ts` should be imported before `./z.ts`",
+          hint: HINT,
+          fix: (FIX_DESC, "import { y } from './y.ts';\nimport { z } from './z.ts';\nimport './setup.ts';\nimport { b } from './b.ts';\nimport { a } from './a.ts';"),
+        },
+        {
+          line: 5,
+          col: 0,
+          message: "`./a.ts` should be imported before `./b.ts`",
+          hint: HINT,
+          fix: (FIX_DESC, "import { z } from './z.ts';\nimport { y } from './y.ts';\nimport './setup.ts';\nimport { a } from './a.ts';\nimport { b } from './b.ts';"),
+        }
+      ],
+      "import { B } from './B.ts';\nimport { a } from './a.ts';\nimport { A } from './A.ts';": [
+        {
+          line: 3,
+          col: 0,
+          message: "`./A.ts` should be imported before `./a.ts`",
+          hint: HINT,
+          fix: (FIX_DESC, "import { A } from './A.ts';\nimport { B } from './B.ts';\nimport { a } from './a.ts';"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      SortImports {
+        internal_prefixes: vec!["@/".to_string()],
+        ..Default::default()
+      },
+      "import { a } from './a.ts';\nimport { c } from '@/c.ts';": [
+        {
+          line: 2,
+          col: 0,
+          message: "`@/c.ts` should be imported before `./a.ts`",
+          hint: HINT,
+          fix: (FIX_DESC, "import { c } from '@/c.ts';\nimport { a } from './a.ts';"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      SortImports {
+        groups: vec![ImportGroup::Relative],
+        alphabetize: false,
+        ..Default::default()
+      },
+      "import chalk from 'npm:chalk';\nimport { a } from './a.ts';": [
+        {
+          line: 2,
+          col: 0,
+          message: "`./a.ts` should be imported before `npm:chalk`",
+          hint: HINT,
+          fix: (FIX_DESC, "import { a } from './a.ts';\nimport chalk from 'npm:chalk';"),
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,305 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::diagnostic::LintFix;
+use crate::handler::{Handler, Traverse};
+use crate::reorder::{reorder_change, sorted_order};
+use crate::swc_util::StringRepr;
+use crate::tags::{self, Tags};
+use crate::Program;
+use deno_ast::swc::ast::{Expr, Prop, PropName, PropOrSpread, UnaryOp};
+use deno_ast::view as ast_view;
+use deno_ast::{SourceRange, SourceRangedForSpanned};
+use std::collections::HashSet;
+
+#[derive(Debug)]
+pub struct SortKeys;
+
+const CODE: &str = "sort-keys";
+const HINT: &str = "Sort the keys of the object literal in ascending order";
+const FIX_DESC: &str = "Sort object keys";
+
+impl LintRule for SortKeys {
+  fn tags(&self) -> Tags {
+    &[tags::STYLISTIC]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Requires the keys of object literals to be sorted in \
+        ascending order. Spread elements and computed keys that aren't \
+        known statically split the object into groups that are sorted \
+        independently, since moving properties across them can change the \
+        result.",
+      invalid_examples: &["const point = { y: 2, x: 1 };"],
+      valid_examples: &[
+        "const point = { x: 1, y: 2 };",
+        "const merged = { b: 1, ...defaults, a: 2 };",
+      ],
+    }
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    SortKeysHandler.traverse(program, context);
+  }
+}
+
+struct SortKeysHandler;
+
+impl Handler for SortKeysHandler {
+  fn object_lit(
+    &mut self,
+    object_lit: &ast_view::ObjectLit,
+    ctx: &mut Context,
+  ) {
+    let mut group = Vec::new();
+    for prop in &object_lit.inner.props {
+      match key_of(prop) {
+        Some(key) => group.push(key),
+        None => {
+          check_group(&group, ctx);
+          group.clear();
+        }
+      }
+    }
+    check_group(&group, ctx);
+  }
+}
+
+struct Key<'a> {
+  name: String,
+  key_range: SourceRange,
+  prop_range: SourceRange,
+  prop: &'a Prop,
+}
+
+/// Returns the static key of the property, or `None` for spread elements and
+/// computed keys whose value isn't known statically.
+fn key_of(prop_or_spread: &PropOrSpread) -> Option<Key> {
+  let PropOrSpread::Prop(prop) = prop_or_spread else {
+    return None;
+  };
+  let (name, key_range) = match &**prop {
+    Prop::Shorthand(ident) => (ident.sym.to_string(), ident.range()),
+    Prop::KeyValue(kv) => (kv.key.string_repr()?, kv.key.range()),
+    Prop::Getter(getter) => (getter.key.string_repr()?, getter.key.range()),
+    Prop::Setter(setter) => (setter.key.string_repr()?, setter.key.range()),
+    Prop::Method(method) => (method.key.string_repr()?, method.key.range()),
+    Prop::Assign(_) => return None,
+  };
+  Some(Key {
+    name,
+    key_range,
+    prop_range: prop.range(),
+    prop,
+  })
+}
+
+fn check_group(group: &[Key], ctx: &mut Context) {
+  let Some(index) = group
+    .windows(2)
+    .position(|pair| pair[0].name > pair[1].name)
+  else {
+    return;
+  };
+  let (prev, unsorted) = (&group[index], &group[index + 1]);
+  let message = format!(
+    "Expected object keys to be in ascending order. `{}` should be before `{}`",
+    unsorted.name, prev.name
+  );
+
+  let fixes = if is_safe_to_reorder(group) {
+    let order = sorted_order(group, |a, b| a.name.cmp(&b.name));
+    let ranges = group.iter().map(|key| key.prop_range).collect::<Vec<_>>();
+    vec![LintFix {
+      description: FIX_DESC.into(),
+      changes: vec![reorder_change(ctx, &ranges, &order)],
+    }]
+  } else {
+    vec![]
+  };
+
+  ctx.add_diagnostic_with_fixes(
+    unsorted.key_range,
+    CODE,
+    message,
+    Some(HINT.to_string()),
+    fixes,
+  );
+}
+
+/// Reordering properties changes the order in which their values are
+/// evaluated and which of the duplicate keys wins, so it's only done when
+/// neither of them is observable.
+fn is_safe_to_reorder(group: &[Key]) -> bool {
+  let mut seen = HashSet::new();
+  group.iter().all(|key| {
+    let is_pure = match key.prop {
+      Prop::KeyValue(kv) => {
+        !matches!(kv.key, PropName::Computed(_)) && is_pure(&kv.value)
+      }
+      Prop::Shorthand(_)
+      | Prop::Getter(_)
+      | Prop::Setter(_)
+      | Prop::Method(_) => true,
+      Prop::Assign(_) => false,
+    };
+    // Getters and setters of the same property may share a key.
+    let is_accessor = matches!(key.prop, Prop::Getter(_) | Prop::Setter(_));
+    is_pure && (is_accessor || seen.insert(key.name.as_str()))
+  })
+}
+
+fn is_pure(expr: &Expr) -> bool {
+  match expr {
+    Expr::Lit(_)
+    | Expr::Ident(_)
+    | Expr::Arrow(_)
+    | Expr::Fn(_)
+    | Expr::This(_) => true,
+    Expr::Paren(paren) => is_pure(&paren.expr),
+    Expr::Tpl(tpl) => tpl.exprs.iter().all(|expr| is_pure(expr)),
+    Expr::Unary(unary) => unary.op != UnaryOp::Delete && is_pure(&unary.arg),
+    Expr::Array(array) => array
+      .elems
+      .iter()
+      .flatten()
+      .all(|elem| elem.spread.is_none() && is_pure(&elem.expr)),
+    Expr::Object(object) => object.props.iter().all(|prop| match prop {
+      PropOrSpread::Prop(prop) => match &**prop {
+        Prop::KeyValue(kv) => {
+          !matches!(kv.key, PropName::Computed(_)) && is_pure(&kv.value)
+        }
+        Prop::Assign(_) => false,
+        _ => true,
+      },
+      PropOrSpread::Spread(_) => false,
+    }),
+    _ => false,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn sort_keys_valid() {
+    assert_lint_ok! {
+      SortKeys,
+      "const a = {};",
+      "const a = { a: 1 };",
+      "const a = { a: 1, b: 2, c: 3 };",
+      "const a = { A: 1, a: 2 };",
+      "const a = { '1': 1, a: 2 };",
+      "const a = { a, b, c };",
+      "const a = { b: 1, ...c, a: 2 };",
+      "const a = { b: 1, [c]: 2, a: 3 };",
+      "const a = { a: 1, ['b']: 2, c: 3 };",
+      "const a = { get a() { return 1; }, set a(v) {}, b() {} };",
+    };
+  }
+
+  #[test]
+  fn sort_keys_invalid() {
+    assert_lint_err! {
+      SortKeys,
+      "const a = { b: 1, a: 2 };": [
+        {
+          col: 18,
+          message: "Expected object keys to be in ascending order. `a` should be before `b`",
+          hint: HINT,
+          fix: (FIX_DESC, "const a = { a: 2, b: 1 };"),
+        }
+      ],
+      "const a = { c, a, b };": [
+        {
+          col: 15,
+          message: "Expected object keys to be in ascending order. `a` should be before `c`",
+          hint: HINT,
+          fix: (FIX_DESC, "const a = { a, b, c };"),
+        }
+      ],
+      "const a = { a: 1, ...x, c: 2, b: 3 };": [
+        {
+          col: 30,
+          message: "Expected object keys to be in ascending order. `b` should be before `c`",
+          hint: HINT,
+          fix: (FIX_DESC, "const a = { a: 1, ...x, b: 3, c: 2 };"),
+        }
+      ],
+      r#"const a = {
+  // The second letter.
+  b: 1,
+  /* The first letter. */ a: 2,
+};"#: [
+        {
+          line: 4,
+          col: 26,
+          message: "Expected object keys to be in ascending order. `a` should be before `b`",
+          hint: HINT,
+          fix: (
+            FIX_DESC,
+            r#"const a = {
+  /* The first letter. */ a: 2,
+  // The second letter.
+  b: 1,
+};"#
+          ),
+        }
+      ],
+      r#"const a = {
+  c: 1, // The third letter.
+  b: 2,
+  a: 3 // The first letter.
+};"#: [
+        {
+          line: 3,
+          col: 2,
+          message: "Expected object keys to be in ascending order. `b` should be before `c`",
+          hint: HINT,
+          fix: (
+            FIX_DESC,
+            r#"const a = {
+  a: 3, // The first letter.
+  b: 2,
+  c: 1 // The third letter.
+};"#
+          ),
+        }
+      ],
+      "const a = { b() {}, a: () => {} };": [
+        {
+          col: 20,
+          message: "Expected object keys to be in ascending order. `a` should be before `b`",
+          hint: HINT,
+          fix: (FIX_DESC, "const a = { a: () => {}, b() {} };"),
+        }
+      ],
+    };
+
+    // Not fixable because the values have side effects.
+    assert_lint_err! {
+      SortKeys,
+      "const a = { b: f(), a: g() };": [
+        {
+          col: 20,
+          message: "Expected object keys to be in ascending order. `a` should be before `b`",
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
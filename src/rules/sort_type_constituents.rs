@@ -0,0 +1,299 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::diagnostic::LintFix;
+use crate::handler::{Handler, Traverse};
+use crate::reorder::{reorder_change, sorted_order};
+use crate::tags::{self, Tags};
+use crate::Program;
+use deno_ast::swc::ast::{
+  TsKeywordTypeKind, TsType, TsUnionOrIntersectionType,
+};
+use deno_ast::view as ast_view;
+use deno_ast::{SourceRange, SourceRanged, SourceRangedForSpanned};
+
+#[derive(Debug)]
+pub struct SortTypeConstituents;
+
+const CODE: &str = "sort-type-constituents";
+
+impl LintRule for SortTypeConstituents {
+  fn tags(&self) -> Tags {
+    &[tags::STYLISTIC]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Requires the members of union and intersection types to \
+        be sorted. Members are grouped by kind (named types, keywords, \
+        operators, literals, functions, imports, conditional types, object \
+        types, tuples, intersections, unions and finally `null`, `undefined` \
+        and `void`) and sorted alphabetically within each group.",
+      invalid_examples: &[
+        "type Status = \"pending\" | \"done\" | \"failed\";",
+        "type Value = undefined | string | Date;",
+      ],
+      valid_examples: &[
+        "type Status = \"done\" | \"failed\" | \"pending\";",
+        "type Value = Date | string | undefined;",
+      ],
+    }
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    SortTypeConstituentsHandler.traverse(program, context);
+  }
+}
+
+#[derive(Clone, Copy)]
+enum Kind {
+  Union,
+  Intersection,
+}
+
+impl Kind {
+  fn message(&self) -> &'static str {
+    match *self {
+      Kind::Union => "Union type constituents must be sorted",
+      Kind::Intersection => "Intersection type constituents must be sorted",
+    }
+  }
+
+  fn hint(&self) -> &'static str {
+    match *self {
+      Kind::Union => "Sort the members of the union type",
+      Kind::Intersection => "Sort the members of the intersection type",
+    }
+  }
+
+  fn fix_description(&self) -> &'static str {
+    match *self {
+      Kind::Union => "Sort union type constituents",
+      Kind::Intersection => "Sort intersection type constituents",
+    }
+  }
+}
+
+struct SortTypeConstituentsHandler;
+
+impl Handler for SortTypeConstituentsHandler {
+  fn ts_union_type(
+    &mut self,
+    union_type: &ast_view::TsUnionType,
+    ctx: &mut Context,
+  ) {
+    check(
+      &union_type.inner.types,
+      union_type.range(),
+      Kind::Union,
+      ctx,
+    );
+  }
+
+  fn ts_intersection_type(
+    &mut self,
+    intersection_type: &ast_view::TsIntersectionType,
+    ctx: &mut Context,
+  ) {
+    check(
+      &intersection_type.inner.types,
+      intersection_type.range(),
+      Kind::Intersection,
+      ctx,
+    );
+  }
+}
+
+/// Groups of types in the order in which they are sorted.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Group {
+  Named,
+  Keyword,
+  Operator,
+  Literal,
+  Function,
+  Import,
+  Conditional,
+  Object,
+  Tuple,
+  Intersection,
+  Union,
+  Nullish,
+}
+
+impl Group {
+  fn of(ty: &TsType) -> Self {
+    match ty {
+      TsType::TsParenthesizedType(paren) => Group::of(&paren.type_ann),
+      TsType::TsTypeRef(_)
+      | TsType::TsArrayType(_)
+      | TsType::TsIndexedAccessType(_)
+      | TsType::TsInferType(_)
+      | TsType::TsTypePredicate(_)
+      | TsType::TsOptionalType(_)
+      | TsType::TsRestType(_) => Group::Named,
+      TsType::TsKeywordType(keyword) => match keyword.kind {
+        TsKeywordTypeKind::TsNullKeyword
+        | TsKeywordTypeKind::TsUndefinedKeyword
+        | TsKeywordTypeKind::TsVoidKeyword => Group::Nullish,
+        _ => Group::Keyword,
+      },
+      TsType::TsThisType(_) => Group::Keyword,
+      TsType::TsTypeOperator(_) | TsType::TsTypeQuery(_) => Group::Operator,
+      TsType::TsLitType(_) => Group::Literal,
+      TsType::TsFnOrConstructorType(_) => Group::Function,
+      TsType::TsImportType(_) => Group::Import,
+      TsType::TsConditionalType(_) => Group::Conditional,
+      TsType::TsTypeLit(_) | TsType::TsMappedType(_) => Group::Object,
+      TsType::TsTupleType(_) => Group::Tuple,
+      TsType::TsUnionOrIntersectionType(
+        TsUnionOrIntersectionType::TsIntersectionType(_),
+      ) => Group::Intersection,
+      TsType::TsUnionOrIntersectionType(
+        TsUnionOrIntersectionType::TsUnionType(_),
+      ) => Group::Union,
+    }
+  }
+}
+
+fn check(
+  types: &[Box<TsType>],
+  range: SourceRange,
+  kind: Kind,
+  ctx: &mut Context,
+) {
+  let text_info = ctx.text_info();
+  let keys = types
+    .iter()
+    .map(|ty| (Group::of(ty), ty.range().text_fast(text_info)))
+    .collect::<Vec<_>>();
+  let order = sorted_order(&keys, |a, b| a.cmp(b));
+  if order.iter().enumerate().all(|(i, index)| i == *index) {
+    return;
+  }
+
+  let ranges = types.iter().map(|ty| ty.range()).collect::<Vec<_>>();
+  let change = reorder_change(ctx, &ranges, &order);
+  ctx.add_diagnostic_with_fixes(
+    range,
+    CODE,
+    kind.message(),
+    Some(kind.hint().to_string()),
+    vec![LintFix {
+      description: kind.fix_description().into(),
+      changes: vec![change],
+    }],
+  );
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn sort_type_constituents_valid() {
+    assert_lint_ok! {
+      SortTypeConstituents,
+      "type A = B | C;",
+      "type A = B & C;",
+      "type A = 'a' | 'b' | 'c';",
+      "type A = 1 | 2 | 3;",
+      "type A = Foo | string | keyof Bar | 'a' | (() => void) | { a: 1 } | [B] | null;",
+      "type A = Date | number | string | undefined;",
+      "type A = B[] | C;",
+      "function foo(a: B | C, b: D & E) {}",
+    };
+  }
+
+  #[test]
+  fn sort_type_constituents_invalid() {
+    let (union_message, union_hint) =
+      (Kind::Union.message(), Kind::Union.hint());
+    let (intersection_message, intersection_hint) =
+      (Kind::Intersection.message(), Kind::Intersection.hint());
+
+    assert_lint_err! {
+      SortTypeConstituents,
+      "type A = C | B;": [
+        {
+          col: 9,
+          message: union_message,
+          hint: union_hint,
+          fix: ("Sort union type constituents", "type A = B | C;"),
+        }
+      ],
+      "type A = C & B;": [
+        {
+          col: 9,
+          message: intersection_message,
+          hint: intersection_hint,
+          fix: ("Sort intersection type constituents", "type A = B & C;"),
+        }
+      ],
+      "type A = null | string | Foo;": [
+        {
+          col: 9,
+          message: union_message,
+          hint: union_hint,
+          fix: ("Sort union type constituents", "type A = Foo | string | null;"),
+        }
+      ],
+      "type A = (() => void) | 'a';": [
+        {
+          col: 9,
+          message: union_message,
+          hint: union_hint,
+          fix: ("Sort union type constituents", "type A = 'a' | (() => void);"),
+        }
+      ],
+      r#"type A =
+  | "c" // The last one.
+  | "a"
+  | "b";"#: [
+        {
+          line: 2,
+          col: 2,
+          message: union_message,
+          hint: union_hint,
+          fix: (
+            "Sort union type constituents",
+            r#"type A =
+  | "a"
+  | "b"
+  | "c"; // The last one."#
+          ),
+        }
+      ],
+      r#"type A =
+  "c" |
+  /* The first one. */ "a" |
+  "b";"#: [
+        {
+          line: 2,
+          col: 2,
+          message: union_message,
+          hint: union_hint,
+          fix: (
+            "Sort union type constituents",
+            r#"type A =
+  /* The first one. */ "a" |
+  "b" |
+  "c";"#
+          ),
+        }
+      ],
+    };
+  }
+}
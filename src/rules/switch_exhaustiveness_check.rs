@@ -0,0 +1,427 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{program_ref, Context, LintRule, RuleDocs};
+use crate::handler::{Handler, Traverse};
+use crate::tags::Tags;
+use crate::{Program, ProgramRef};
+use deno_ast::swc::ast::{
+  BindingIdent, Expr, Id, Lit, TsEntityName, TsKeywordTypeKind, TsLit, TsType,
+  TsTypeAliasDecl, TsUnionOrIntersectionType, UnaryOp,
+};
+use deno_ast::swc::atoms::Atom;
+use deno_ast::swc::visit::{noop_visit_type, Visit, VisitWith};
+use deno_ast::view as ast_view;
+use deno_ast::SourceRangedForSpanned;
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Debug)]
+pub struct SwitchExhaustivenessCheck;
+
+const CODE: &str = "switch-exhaustiveness-check";
+const HINT: &str = "Add cases for the missing values, or a `default` case";
+
+/// How deep type aliases referring to other type aliases are followed.
+const MAX_ALIAS_DEPTH: usize = 8;
+
+impl LintRule for SwitchExhaustivenessCheck {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Requires `switch` statements over a union of literal \
+        types to handle every member of the union, or to have a `default` \
+        case. Without type information, only variables and parameters \
+        annotated with a union of string, number, boolean, `null` or \
+        `undefined` literals are checked, either inline or through a type \
+        alias declared in the same file.",
+      invalid_examples: &[
+        "type Direction = \"up\" | \"down\";\n\nfunction move(direction: Direction) {\n  switch (direction) {\n    case \"up\":\n      return 1;\n  }\n}",
+      ],
+      valid_examples: &[
+        "type Direction = \"up\" | \"down\";\n\nfunction move(direction: Direction) {\n  switch (direction) {\n    case \"up\":\n      return 1;\n    case \"down\":\n      return -1;\n  }\n}",
+        "function move(direction: \"up\" | \"down\") {\n  switch (direction) {\n    case \"up\":\n      return 1;\n    default:\n      return -1;\n  }\n}",
+      ],
+    }
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    SwitchExhaustivenessCheckHandler {
+      unions: LiteralUnions::collect(program_ref(program)),
+    }
+    .traverse(program, context);
+  }
+}
+
+struct SwitchExhaustivenessCheckHandler {
+  unions: LiteralUnions,
+}
+
+impl Handler for SwitchExhaustivenessCheckHandler {
+  fn switch_stmt(
+    &mut self,
+    switch_stmt: &ast_view::SwitchStmt,
+    ctx: &mut Context,
+  ) {
+    let switch_stmt = switch_stmt.inner;
+    let Expr::Ident(ident) = switch_stmt.discriminant.unwrap_parens() else {
+      return;
+    };
+    let Some(values) = self.unions.bindings.get(&ident.to_id()) else {
+      return;
+    };
+    let mut covered = Vec::with_capacity(switch_stmt.cases.len());
+    for case in &switch_stmt.cases {
+      // A `default` case or a case we can't evaluate may cover anything.
+      let Some(value) = case.test.as_deref().and_then(literal_value) else {
+        return;
+      };
+      covered.push(value);
+    }
+    let missing = values
+      .iter()
+      .filter(|value| !covered.contains(value))
+      .map(|value| value.to_string())
+      .collect::<Vec<_>>();
+    if missing.is_empty() {
+      return;
+    }
+    ctx.add_diagnostic_with_hint(
+      switch_stmt.discriminant.range(),
+      CODE,
+      format!(
+        "Switch is not exhaustive, cases not matched: {}",
+        missing.join(" | ")
+      ),
+      HINT,
+    );
+  }
+}
+
+/// A value of a literal type, which a `case` can match.
+#[derive(Clone, PartialEq)]
+enum LiteralValue {
+  Str(Atom),
+  Num(f64),
+  Bool(bool),
+  Null,
+  Undefined,
+}
+
+impl fmt::Display for LiteralValue {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      LiteralValue::Str(value) => {
+        write!(f, "{}", serde_json::Value::from(value.as_str()))
+      }
+      LiteralValue::Num(value) => write!(f, "{}", value),
+      LiteralValue::Bool(value) => write!(f, "{}", value),
+      LiteralValue::Null => write!(f, "null"),
+      LiteralValue::Undefined => write!(f, "undefined"),
+    }
+  }
+}
+
+/// Evaluates the test of a `case` to a literal value.
+fn literal_value(expr: &Expr) -> Option<LiteralValue> {
+  match expr.unwrap_parens() {
+    Expr::Lit(Lit::Str(str)) => Some(LiteralValue::Str(str.value.clone())),
+    Expr::Lit(Lit::Num(num)) => Some(LiteralValue::Num(num.value)),
+    Expr::Lit(Lit::Bool(bool)) => Some(LiteralValue::Bool(bool.value)),
+    Expr::Lit(Lit::Null(_)) => Some(LiteralValue::Null),
+    Expr::Ident(ident) if ident.sym == *"undefined" => {
+      Some(LiteralValue::Undefined)
+    }
+    Expr::Unary(unary) if unary.op == UnaryOp::Minus => match &*unary.arg {
+      Expr::Lit(Lit::Num(num)) => Some(LiteralValue::Num(-num.value)),
+      _ => None,
+    },
+    _ => None,
+  }
+}
+
+/// Bindings declared with a union of literal types, along with the members of
+/// the union.
+struct LiteralUnions {
+  bindings: HashMap<Id, Vec<LiteralValue>>,
+}
+
+impl LiteralUnions {
+  fn collect(program: ProgramRef) -> Self {
+    let mut aliases = AliasCollector::default();
+    match program {
+      ProgramRef::Module(m) => m.visit_with(&mut aliases),
+      ProgramRef::Script(s) => s.visit_with(&mut aliases),
+    }
+    let mut bindings = BindingCollector {
+      aliases: aliases.aliases,
+      bindings: HashMap::new(),
+    };
+    match program {
+      ProgramRef::Module(m) => m.visit_with(&mut bindings),
+      ProgramRef::Script(s) => s.visit_with(&mut bindings),
+    }
+    Self {
+      bindings: bindings.bindings,
+    }
+  }
+}
+
+/// Collects the type aliases of the file.
+#[derive(Default)]
+struct AliasCollector {
+  aliases: HashMap<Atom, TsType>,
+}
+
+impl Visit for AliasCollector {
+  fn visit_ts_type_alias_decl(&mut self, decl: &TsTypeAliasDecl) {
+    if decl.type_params.is_none() {
+      self
+        .aliases
+        .insert(decl.id.sym.clone(), (*decl.type_ann).clone());
+    }
+  }
+}
+
+struct BindingCollector {
+  aliases: HashMap<Atom, TsType>,
+  bindings: HashMap<Id, Vec<LiteralValue>>,
+}
+
+impl BindingCollector {
+  /// Returns the members of the type if it is a union of literal types, or
+  /// `None` if it may contain any other values.
+  fn literal_union(
+    &self,
+    ty: &TsType,
+    depth: usize,
+  ) -> Option<Vec<LiteralValue>> {
+    match ty {
+      TsType::TsLitType(lit) => match &lit.lit {
+        TsLit::Str(str) => Some(vec![LiteralValue::Str(str.value.clone())]),
+        TsLit::Number(num) => Some(vec![LiteralValue::Num(num.value)]),
+        TsLit::Bool(bool) => Some(vec![LiteralValue::Bool(bool.value)]),
+        TsLit::BigInt(_) | TsLit::Tpl(_) => None,
+      },
+      TsType::TsKeywordType(keyword) => match keyword.kind {
+        TsKeywordTypeKind::TsBooleanKeyword => {
+          Some(vec![LiteralValue::Bool(true), LiteralValue::Bool(false)])
+        }
+        TsKeywordTypeKind::TsNullKeyword => Some(vec![LiteralValue::Null]),
+        TsKeywordTypeKind::TsUndefinedKeyword => {
+          Some(vec![LiteralValue::Undefined])
+        }
+        _ => None,
+      },
+      TsType::TsUnionOrIntersectionType(
+        TsUnionOrIntersectionType::TsUnionType(union),
+      ) => {
+        let mut values = Vec::new();
+        for ty in &union.types {
+          for value in self.literal_union(ty, depth)? {
+            if !values.contains(&value) {
+              values.push(value);
+            }
+          }
+        }
+        Some(values)
+      }
+      TsType::TsParenthesizedType(paren) => {
+        self.literal_union(&paren.type_ann, depth)
+      }
+      TsType::TsTypeRef(type_ref) if type_ref.type_params.is_none() => {
+        let TsEntityName::Ident(ident) = &type_ref.type_name else {
+          return None;
+        };
+        if depth >= MAX_ALIAS_DEPTH {
+          return None;
+        }
+        let alias = self.aliases.get(&ident.sym)?;
+        self.literal_union(alias, depth + 1)
+      }
+      _ => None,
+    }
+  }
+}
+
+impl Visit for BindingCollector {
+  noop_visit_type!();
+
+  fn visit_binding_ident(&mut self, binding: &BindingIdent) {
+    let Some(type_ann) = &binding.type_ann else {
+      return;
+    };
+    if let Some(values) = self.literal_union(&type_ann.type_ann, 0) {
+      self.bindings.insert(binding.id.to_id(), values);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn switch_exhaustiveness_check_valid() {
+    assert_lint_ok! {
+      SwitchExhaustivenessCheck,
+      r#"
+type Kind = "a" | "b";
+function f(kind: Kind) {
+  switch (kind) {
+    case "a":
+    case "b":
+      break;
+  }
+}
+"#,
+      r#"
+function f(kind: "a" | "b") {
+  switch (kind) {
+    case "a":
+      break;
+    default:
+      break;
+  }
+}
+"#,
+      r#"
+function f(kind: string) {
+  switch (kind) {
+    case "a":
+      break;
+  }
+}
+"#,
+      r#"
+function f(kind: "a" | "b" | string) {
+  switch (kind) {
+    case "a":
+      break;
+  }
+}
+"#,
+      r#"
+const B = "b";
+function f(kind: "a" | "b") {
+  switch (kind) {
+    case "a":
+      break;
+    case B:
+      break;
+  }
+}
+"#,
+      r#"
+function f(kind: Generic<"a">) {
+  switch (kind) {
+    case "a":
+      break;
+  }
+}
+"#,
+      r#"
+function f(kind: "a" | "b", other: string) {
+  switch (other) {
+    case "a":
+      break;
+  }
+}
+"#,
+      r#"
+function f(n: 1 | -1 | null | undefined) {
+  switch (n) {
+    case 1:
+    case -1:
+    case null:
+    case undefined:
+      break;
+  }
+}
+"#,
+      r#"
+type A = B;
+type B = A;
+function f(kind: A) {
+  switch (kind) {
+    case "a":
+      break;
+  }
+}
+"#,
+    };
+  }
+
+  #[test]
+  fn switch_exhaustiveness_check_invalid() {
+    assert_lint_err! {
+      SwitchExhaustivenessCheck,
+      r#"
+type Kind = "a" | "b" | "c";
+function f(kind: Kind) {
+  switch (kind) {
+    case "a":
+      break;
+  }
+}
+"#: [
+        {
+          line: 4,
+          col: 10,
+          message: r#"Switch is not exhaustive, cases not matched: "b" | "c""#,
+          hint: HINT,
+        }
+      ],
+      r#"
+let kind: "a" | ("b" | Other);
+type Other = "c";
+switch ((kind)) {
+  case "c":
+  case "a":
+}
+"#: [
+        {
+          line: 4,
+          col: 8,
+          message: r#"Switch is not exhaustive, cases not matched: "b""#,
+          hint: HINT,
+        }
+      ],
+      r#"
+function f(flag: boolean, n: 1 | 2 | null) {
+  switch (flag) {
+    case true:
+      break;
+  }
+  switch (n) {
+    case 2:
+      break;
+  }
+}
+"#: [
+        {
+          line: 3,
+          col: 10,
+          message: "Switch is not exhaustive, cases not matched: false",
+          hint: HINT,
+        },
+        {
+          line: 7,
+          col: 10,
+          message: "Switch is not exhaustive, cases not matched: 1 | null",
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,421 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule, RuleDocs};
+use crate::tags::Tags;
+use crate::Program;
+use deno_ast::swc::common::comments::Comment;
+use deno_ast::SourceRangedForSpanned;
+use derive_more::Display;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Deserializer};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct TodoFormat {
+  /// Words that start the comments checked by this rule, matched
+  /// case-insensitively.
+  pub keywords: Vec<String>,
+  /// A pattern that the comment has to match, e.g. an issue URL or a ticket
+  /// ID. Without it, the keyword has to be tagged like `TODO(@username)` or
+  /// `TODO(#issue)`.
+  #[serde(deserialize_with = "deserialize_pattern")]
+  pub reference_pattern: Option<Regex>,
+  /// The number of days after which a comment containing a `YYYY-MM-DD` date
+  /// is reported.
+  pub max_age_days: Option<u64>,
+}
+
+impl Default for TodoFormat {
+  fn default() -> Self {
+    Self {
+      keywords: vec!["TODO".to_string(), "FIXME".to_string()],
+      reference_pattern: None,
+      max_age_days: None,
+    }
+  }
+}
+
+fn deserialize_pattern<'de, D>(
+  deserializer: D,
+) -> Result<Option<Regex>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  Option::<String>::deserialize(deserializer)?
+    .map(|pattern| Regex::new(&pattern).map_err(serde::de::Error::custom))
+    .transpose()
+}
+
+const CODE: &str = "todo-format";
+
+#[derive(Display)]
+enum TodoFormatMessage {
+  #[display(fmt = "{} should be tagged with (@username) or (#issue)", _0)]
+  Untagged(String),
+  #[display(fmt = "{} should contain a reference matching `{}`", _0, _1)]
+  MissingReference(String, String),
+  #[display(fmt = "{} from {} is older than {} days", _0, _1, _2)]
+  Expired(String, String, u64),
+}
+
+#[derive(Display)]
+enum TodoFormatHint {
+  #[display(
+    fmt = "Add a user tag or issue reference to the comment, e.g. {0}(@djones), {0}(djones), {0}(#123)",
+    _0
+  )]
+  Untagged(String),
+  #[display(fmt = "Add a link to the issue or its ID to the comment")]
+  MissingReference,
+  #[display(
+    fmt = "Resolve the comment, or update its date if it still applies"
+  )]
+  Expired,
+}
+
+impl LintRule for TodoFormat {
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn supersedes(&self) -> &'static [&'static str] {
+    &["ban-untagged-todo"]
+  }
+
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Enforces a format for TODO and FIXME comments. By \
+        default, the keyword has to be tagged with the person or issue the \
+        comment belongs to, like `TODO(@username)` or `TODO(#123)`, as \
+        `ban-untagged-todo` requires, but block comments are checked as well. \
+        With the `referencePattern` option, comments have to match the given \
+        regular expression instead, e.g. an issue URL or a ticket ID. With \
+        the `maxAgeDays` option, comments that contain a `YYYY-MM-DD` date \
+        older than the given number of days are reported, so that dated \
+        workarounds don't outlive their purpose. The checked keywords can be \
+        changed with the `keywords` option.",
+      invalid_examples: &[
+        "// TODO: remove this workaround\nconst a = 1;",
+        "/**\n * FIXME handle errors\n */\nfunction f() {}",
+      ],
+      valid_examples: &[
+        "// TODO(@someusername): remove this workaround\nconst a = 1;",
+        "/**\n * FIXME(#123) handle errors\n */\nfunction f() {}",
+      ],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "keywords": {
+          "description": "Words that start the comments checked by this rule, matched case-insensitively",
+          "type": "array",
+          "items": { "type": "string" },
+          "default": ["TODO", "FIXME"]
+        },
+        "referencePattern": {
+          "description": "A regular expression the comment has to match, e.g. an issue URL or a ticket ID. Without it, the keyword has to be tagged like `TODO(@username)` or `TODO(#issue)`",
+          "type": "string"
+        },
+        "maxAgeDays": {
+          "description": "The number of days after which a comment containing a `YYYY-MM-DD` date is reported",
+          "type": "integer",
+          "minimum": 0
+        }
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<TodoFormat>(options)?))
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    _program: Program,
+  ) {
+    let today = today();
+    let violations = context
+      .all_comments()
+      .flat_map(|comment| {
+        self
+          .check_comment(comment, today)
+          .into_iter()
+          .map(|(message, hint)| (comment.range(), message, hint))
+      })
+      .collect::<Vec<_>>();
+
+    for (range, message, hint) in violations {
+      context.add_diagnostic_with_hint(range, CODE, message, hint);
+    }
+  }
+}
+
+impl TodoFormat {
+  /// Returns the messages and hints of the problems with the comment, where
+  /// `today` is the number of days since the Unix epoch.
+  fn check_comment(
+    &self,
+    comment: &Comment,
+    today: i64,
+  ) -> Vec<(String, String)> {
+    let Some((keyword, rest)) = comment
+      .text
+      .lines()
+      .find_map(|line| self.keyword_line(line))
+    else {
+      return vec![];
+    };
+
+    let mut problems = Vec::new();
+    match &self.reference_pattern {
+      Some(pattern) => {
+        if !pattern.is_match(&comment.text) {
+          problems.push((
+            TodoFormatMessage::MissingReference(
+              keyword.to_string(),
+              pattern.to_string(),
+            )
+            .to_string(),
+            TodoFormatHint::MissingReference.to_string(),
+          ));
+        }
+      }
+      None => {
+        static TAG_RE: Lazy<Regex> =
+          Lazy::new(|| Regex::new(r"^\((#|@)?\S+\)").unwrap());
+        if !TAG_RE.is_match(rest) {
+          problems.push((
+            TodoFormatMessage::Untagged(keyword.to_string()).to_string(),
+            TodoFormatHint::Untagged(keyword.to_string()).to_string(),
+          ));
+        }
+      }
+    }
+
+    if let Some(max_age_days) = self.max_age_days {
+      if let Some((date, days)) = find_date(&comment.text) {
+        if today - days > max_age_days as i64 {
+          problems.push((
+            TodoFormatMessage::Expired(
+              keyword.to_string(),
+              date.to_string(),
+              max_age_days,
+            )
+            .to_string(),
+            TodoFormatHint::Expired.to_string(),
+          ));
+        }
+      }
+    }
+
+    problems
+  }
+
+  /// If the line of a comment starts with one of the keywords, returns the
+  /// keyword and the rest of the line. Leading `*`s of block comment lines
+  /// are ignored.
+  fn keyword_line<'a>(&'a self, line: &'a str) -> Option<(&'a str, &'a str)> {
+    let line = line.trim_start().trim_start_matches('*').trim_start();
+    self.keywords.iter().find_map(|keyword| {
+      let prefix = line.get(..keyword.len())?;
+      let rest = &line[keyword.len()..];
+      let is_word_end = !rest
+        .starts_with(|c: char| c.is_alphanumeric() || c == '_' || c == '$');
+      (prefix.eq_ignore_ascii_case(keyword) && is_word_end)
+        .then_some((keyword.as_str(), rest))
+    })
+  }
+}
+
+/// Returns the first valid `YYYY-MM-DD` date in the text along with the
+/// number of days since the Unix epoch it represents.
+fn find_date(text: &str) -> Option<(&str, i64)> {
+  static DATE_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b(\d{4})-(\d{2})-(\d{2})\b").unwrap());
+  DATE_RE.captures_iter(text).find_map(|captures| {
+    let year = captures[1].parse().ok()?;
+    let month = captures[2].parse().ok()?;
+    let day = captures[3].parse().ok()?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+      return None;
+    }
+    Some((captures.get(0)?.as_str(), days_from_civil(year, month, day)))
+  })
+}
+
+/// Returns the number of days since the Unix epoch of a date in the
+/// proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+  let year = if month <= 2 { year - 1 } else { year };
+  let era = year.div_euclid(400);
+  let year_of_era = year - era * 400;
+  let month_from_march = (month + 9) % 12;
+  let day_of_year = (153 * month_from_march + 2) / 5 + day - 1;
+  let day_of_era =
+    year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+  era * 146097 + day_of_era - 719468
+}
+
+fn today() -> i64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|duration| (duration.as_secs() / 86400) as i64)
+    .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn untagged(keyword: &str) -> (String, String) {
+    (
+      TodoFormatMessage::Untagged(keyword.to_string()).to_string(),
+      TodoFormatHint::Untagged(keyword.to_string()).to_string(),
+    )
+  }
+
+  #[test]
+  fn todo_format_valid() {
+    assert_lint_ok! {
+      TodoFormat::default(),
+      "// TODO(@someusername) this should be fixed in next release",
+      "// TODO(someusername): this should be fixed",
+      "// FIXME(#123) this should be fixed",
+      "/* todo(#123) */",
+      "/**\n * TODO(@someusername)\n */",
+      "// Todos are tracked elsewhere",
+      "// This is not a TODO",
+    };
+  }
+
+  #[test]
+  fn todo_format_invalid() {
+    let (todo_message, todo_hint) = untagged("TODO");
+    let (fixme_message, fixme_hint) = untagged("FIXME");
+    assert_lint_err! {
+      TodoFormat::default(),
+      "// TODO this should be fixed": [
+        { col: 0, message: &todo_message, hint: &todo_hint }
+      ],
+      "// todo: (@someusername) this should be fixed": [
+        { col: 0, message: &todo_message, hint: &todo_hint }
+      ],
+      "// FIXME handle errors": [
+        { col: 0, message: &fixme_message, hint: &fixme_hint }
+      ],
+      "const a = 1; /* TODO */": [
+        { col: 13, message: &todo_message, hint: &todo_hint }
+      ],
+      "/**\n * Description.\n * TODO remove\n */": [
+        { line: 1, col: 0, message: &todo_message, hint: &todo_hint }
+      ],
+    };
+  }
+
+  #[test]
+  fn todo_format_keywords() {
+    let rule = || TodoFormat {
+      keywords: vec!["HACK".to_string()],
+      ..Default::default()
+    };
+    assert_lint_ok! {
+      rule(),
+      "// TODO this is not checked",
+      "// HACK(#12) this is checked",
+    };
+    let (message, hint) = untagged("HACK");
+    assert_lint_err! {
+      rule(),
+      "// hack around a bug": [{ col: 0, message: &message, hint: &hint }],
+    };
+  }
+
+  #[test]
+  fn todo_format_reference_pattern() {
+    let rule = || TodoFormat {
+      reference_pattern: Some(
+        Regex::new(r"https://github\.com/\S+/issues/\d+|\bPROJ-\d+\b").unwrap(),
+      ),
+      ..Default::default()
+    };
+    assert_lint_ok! {
+      rule(),
+      "// TODO: https://github.com/denoland/deno_lint/issues/123",
+      "// TODO PROJ-42 remove this",
+      "/*\n * FIXME handle errors\n * See https://github.com/denoland/deno/issues/1\n */",
+    };
+    let message = TodoFormatMessage::MissingReference(
+      "TODO".to_string(),
+      r"https://github\.com/\S+/issues/\d+|\bPROJ-\d+\b".to_string(),
+    );
+    let hint = TodoFormatHint::MissingReference;
+    assert_lint_err! {
+      rule(),
+      "// TODO(@someusername) remove this": [
+        { col: 0, message: &message, hint: &hint }
+      ],
+      "// TODO PROJ- remove this": [{ col: 0, message: &message, hint: &hint }],
+    };
+  }
+
+  #[test]
+  fn todo_format_max_age_days() {
+    let rule = || TodoFormat {
+      max_age_days: Some(30),
+      ..Default::default()
+    };
+    assert_lint_ok! {
+      rule(),
+      "// TODO(#123) remove after 2999-01-01",
+      "// TODO(#123) remove this",
+      "// TODO(#123) not a date: 2020-13-01",
+      "// Not a todo from 2000-01-01",
+    };
+    let message = TodoFormatMessage::Expired(
+      "TODO".to_string(),
+      "2000-01-01".to_string(),
+      30,
+    );
+    let hint = TodoFormatHint::Expired;
+    let (untagged_message, untagged_hint) = untagged("TODO");
+    assert_lint_err! {
+      rule(),
+      "// TODO(#123) remove after 2000-01-01": [
+        { col: 0, message: &message, hint: &hint }
+      ],
+      "// TODO 2000-01-01: remove this": [
+        { col: 0, message: &untagged_message, hint: &untagged_hint },
+        { col: 0, message: &message, hint: &hint }
+      ],
+    };
+  }
+
+  #[test]
+  fn days_from_civil_test() {
+    assert_eq!(days_from_civil(1970, 1, 1), 0);
+    assert_eq!(days_from_civil(2000, 3, 1), 11017);
+    assert_eq!(days_from_civil(2024, 2, 29), 19782);
+    assert_eq!(days_from_civil(1969, 12, 31), -1);
+  }
+
+  #[test]
+  fn reference_pattern_must_be_a_regex() {
+    let rule = TodoFormat::default()
+      .with_options(serde_json::json!({ "referencePattern": "(" }));
+    assert!(rule.is_err());
+  }
+}
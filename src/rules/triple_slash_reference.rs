@@ -1,7 +1,8 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
-use super::{Context, LintRule};
-
+use super::{program_ref, Context, LintRule, RuleDocs};
+use crate::ProgramRef;
+use deno_ast::swc::ast::{ModuleDecl, ModuleItem, TsModuleRef};
 use deno_ast::swc::common::comments::Comment;
 use deno_ast::swc::common::comments::CommentKind;
 use deno_ast::SourceRange;
@@ -9,9 +10,40 @@ use deno_ast::SourceRangedForSpanned;
 use derive_more::Display;
 use once_cell::sync::Lazy;
 use regex::Regex;
+use serde::Deserialize;
+use std::collections::HashSet;
+
+#[derive(Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct TripleSlashReference {
+  /// Policy for `/// <reference types="..." />`.
+  pub types: ReferenceOption,
+  /// Policy for `/// <reference path="..." />`.
+  pub path: ReferenceOption,
+  /// Policy for `/// <reference lib="..." />`.
+  pub lib: ReferenceOption,
+}
+
+impl Default for TripleSlashReference {
+  fn default() -> Self {
+    Self {
+      types: ReferenceOption::Never,
+      path: ReferenceOption::Never,
+      lib: ReferenceOption::Never,
+    }
+  }
+}
 
-#[derive(Debug)]
-pub struct TripleSlashReference;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ReferenceOption {
+  /// The reference is allowed.
+  Always,
+  /// The reference is reported.
+  Never,
+  /// The reference is reported if the file also imports the same module.
+  PreferImport,
+}
 
 const CODE: &str = "triple-slash-reference";
 
@@ -19,15 +51,29 @@ const CODE: &str = "triple-slash-reference";
 enum TripleSlashReferenceMessage {
   #[display(fmt = "`triple slash reference` is not allowed")]
   Unexpected,
+  #[display(
+    fmt = "Do not use a triple slash reference for `{}`, use `import` style instead",
+    _0
+  )]
+  PreferImport(String),
 }
 
 impl TripleSlashReference {
-  fn report(&self, context: &mut Context, range: SourceRange) {
-    context.add_diagnostic(
-      range,
-      CODE,
-      TripleSlashReferenceMessage::Unexpected,
-    );
+  fn option(&self, kind: &str) -> ReferenceOption {
+    match kind {
+      "types" => self.types,
+      "path" => self.path,
+      _ => self.lib,
+    }
+  }
+
+  fn report(
+    &self,
+    context: &mut Context,
+    range: SourceRange,
+    message: TripleSlashReferenceMessage,
+  ) {
+    context.add_diagnostic(range, CODE, message);
   }
 }
 
@@ -36,31 +82,84 @@ impl LintRule for TripleSlashReference {
     CODE
   }
 
+  fn docs(&self) -> RuleDocs {
+    RuleDocs {
+      description: "Disallows triple slash references, like \
+        `/// <reference types=\"foo\" />`, in favor of `import`. The \
+        `types`, `path` and `lib` options set the policy for each kind of \
+        reference: `\"always\"` allows it, `\"never\"` reports it, and \
+        `\"prefer-import\"` reports it only if the file also imports the \
+        same module. All kinds are reported by default.",
+      invalid_examples: &["/// <reference types=\"node\" />"],
+      valid_examples: &["import type { Buffer } from \"node:buffer\";"],
+    }
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    let option = serde_json::json!({
+      "enum": ["always", "never", "prefer-import"]
+    });
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "types": option,
+        "path": option,
+        "lib": option
+      },
+      "additionalProperties": false
+    }))
+  }
+
+  fn with_options(
+    &self,
+    options: serde_json::Value,
+  ) -> Result<Box<dyn LintRule>, serde_json::Error> {
+    Ok(Box::new(serde_json::from_value::<TripleSlashReference>(
+      options,
+    )?))
+  }
+
   fn lint_program_with_ast_view<'view>(
     &self,
     context: &mut Context<'view>,
-    _program: deno_ast::view::Program<'view>,
+    program: deno_ast::view::Program<'view>,
   ) {
     let mut violated_comment_ranges = Vec::new();
 
     violated_comment_ranges.extend(context.all_comments().filter_map(|c| {
-      if check_comment(c) {
-        Some(c.range())
-      } else {
-        None
-      }
+      let (kind, module) = parse_reference(c)?;
+      Some((c.range(), self.option(kind), module.to_string()))
     }));
+    if violated_comment_ranges.is_empty() {
+      return;
+    }
 
-    for range in violated_comment_ranges {
-      self.report(context, range);
+    let imports = imported_modules(program_ref(program));
+    for (range, option, module) in violated_comment_ranges {
+      match option {
+        ReferenceOption::Always => {}
+        ReferenceOption::Never => {
+          self.report(context, range, TripleSlashReferenceMessage::Unexpected);
+        }
+        ReferenceOption::PreferImport => {
+          if imports.contains(module.as_str()) {
+            self.report(
+              context,
+              range,
+              TripleSlashReferenceMessage::PreferImport(module),
+            );
+          }
+        }
+      }
     }
   }
 }
 
-/// Returns `true` if the comment should be reported.
-fn check_comment(comment: &Comment) -> bool {
+/// Returns the kind of the reference, e.g. `types`, and the referenced
+/// module, or `None` if the comment is not a triple slash reference.
+fn parse_reference(comment: &Comment) -> Option<(&str, &str)> {
   if comment.kind != CommentKind::Line {
-    return false;
+    return None;
   }
 
   static TSR_REGEX: Lazy<Regex> = Lazy::new(|| {
@@ -68,7 +167,37 @@ fn check_comment(comment: &Comment) -> bool {
       .unwrap()
   });
 
-  TSR_REGEX.is_match(&comment.text)
+  let captures = TSR_REGEX.captures(&comment.text)?;
+  Some((
+    captures.get(1).unwrap().as_str(),
+    captures.get(2).unwrap().as_str(),
+  ))
+}
+
+/// Collects the specifiers of the modules imported with `import` and
+/// `import foo = require()`.
+fn imported_modules(program: ProgramRef) -> HashSet<&str> {
+  let ProgramRef::Module(module) = program else {
+    return HashSet::new();
+  };
+  module
+    .body
+    .iter()
+    .filter_map(|item| match item {
+      ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
+        Some(import.src.value.as_str())
+      }
+      ModuleItem::ModuleDecl(ModuleDecl::TsImportEquals(import_equals)) => {
+        match &import_equals.module_ref {
+          TsModuleRef::TsExternalModuleRef(module_ref) => {
+            Some(module_ref.expr.value.as_str())
+          }
+          TsModuleRef::TsEntityName(_) => None,
+        }
+      }
+      _ => None,
+    })
+    .collect()
 }
 
 #[cfg(test)]
@@ -78,7 +207,7 @@ mod tests {
   #[test]
   fn triple_slash_reference_valid() {
     assert_lint_ok! {
-      TripleSlashReference,
+      TripleSlashReference::default(),
       r#"
       // <reference path="foo" />
       // <reference types="bar" />
@@ -105,7 +234,7 @@ mod tests {
   #[test]
   fn triple_slash_reference_invalid() {
     assert_lint_err! {
-      TripleSlashReference,
+      TripleSlashReference::default(),
       r#"
 /// <reference types="foo" />
 import * as foo from 'foo';"#:[
@@ -140,4 +269,46 @@ import foo = require('foo');
       }],
     }
   }
+
+  #[test]
+  fn triple_slash_reference_with_options() {
+    let rule = || TripleSlashReference {
+      types: ReferenceOption::PreferImport,
+      path: ReferenceOption::Always,
+      lib: ReferenceOption::Never,
+    };
+
+    assert_lint_ok! {
+      rule(),
+      r#"/// <reference path="foo" />"#,
+      r#"
+/// <reference types="foo" />
+import * as bar from 'bar';"#,
+    };
+
+    assert_lint_err! {
+      rule(),
+      r#"
+/// <reference types="foo" />
+import * as foo from 'foo';"#: [
+      {
+        line: 2,
+        col: 0,
+        message: TripleSlashReferenceMessage::PreferImport("foo".to_string()),
+      }],
+      r#"
+/// <reference types="foo" />
+import foo = require('foo');"#: [
+      {
+        line: 2,
+        col: 0,
+        message: TripleSlashReferenceMessage::PreferImport("foo".to_string()),
+      }],
+      r#"/// <reference lib="foo" />"#: [
+      {
+        col: 0,
+        message: TripleSlashReferenceMessage::Unexpected,
+      }],
+    };
+  }
 }
@@ -221,6 +221,10 @@ impl LintRule for VerbatimModuleSyntax {
     CODE
   }
 
+  fn fixable(&self) -> bool {
+    true
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
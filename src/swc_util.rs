@@ -1,16 +1,17 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use deno_ast::swc::ast::{
-  ArrowExpr, BigInt, BindingIdent, BlockStmt, Bool, CallExpr, Class,
-  ComputedPropName, Constructor, Expr, Function, Ident, IdentName, JSXText,
-  Lit, MemberExpr, MemberProp, Null, Number, PrivateName, Prop, PropName,
-  PropOrSpread, Regex, Str, Tpl,
+  ArrowExpr, AssignTarget, BigInt, BinaryOp, BindingIdent, BlockStmt, Bool,
+  CallExpr, Class, ComputedPropName, Constructor, Expr, Function, Ident,
+  IdentName, JSXText, Lit, MemberExpr, MemberProp, Null, Number, PrivateName,
+  Prop, PropName, PropOrSpread, Regex, SimpleAssignTarget, Str, Tpl, TsType,
 };
 use deno_ast::swc::common::DUMMY_SP;
 use deno_ast::swc::utils::{find_pat_ids, ident::IdentLike};
 use deno_ast::swc::visit::{VisitMut, VisitMutWith};
 use deno_ast::view::{self as ast_view};
 use deno_ast::Scope;
+use deno_ast::{SourceRange, SourceRanged, SourceTextInfo};
 
 /// Extracts regex string from an expression, using ScopeManager.
 /// If the passed expression is not regular expression, this will return `None`.
@@ -41,6 +42,28 @@ pub(crate) fn extract_regex(
   }
 }
 
+/// Extracts the flags of a regex created with `RegExp`, whose arguments are
+/// passed. Returns `None` if the flags aren't known statically.
+pub(crate) fn extract_regex_flags(
+  expr_args: &[&ast_view::ExprOrSpread],
+) -> Option<String> {
+  match (expr_args.first(), expr_args.get(1)) {
+    (_, Some(second_arg)) => match second_arg.expr {
+      ast_view::Expr::Lit(ast_view::Lit::Str(literal)) => {
+        Some(literal.inner.value.to_string())
+      }
+      _ => None,
+    },
+    (Some(first_arg), None) => match first_arg.expr {
+      ast_view::Expr::Lit(ast_view::Lit::Regex(regex)) => {
+        Some(regex.inner.flags.to_string())
+      }
+      _ => Some(String::new()),
+    },
+    (None, None) => Some(String::new()),
+  }
+}
+
 pub(crate) trait StringRepr {
   fn string_repr(&self) -> Option<String>;
 }
@@ -298,6 +321,79 @@ impl<'view> StringRepr for ast_view::PropName<'view> {
   }
 }
 
+/// Converts the raw text of a template literal without interpolations into a
+/// string literal, using a quote character that doesn't occur in the text.
+/// Returns `None` if the text contains both kinds of quotes.
+pub(crate) fn template_to_string_literal(raw: &str) -> Option<String> {
+  let quote = if !raw.contains('"') {
+    '"'
+  } else if !raw.contains('\'') {
+    '\''
+  } else {
+    return None;
+  };
+  let mut text = String::with_capacity(raw.len() + 2);
+  text.push(quote);
+  let mut chars = raw.chars();
+  while let Some(c) = chars.next() {
+    if c != '\\' {
+      text.push(c);
+      continue;
+    }
+    match chars.next() {
+      // Escapes that are only needed in template literals.
+      Some(next @ ('`' | '$')) => text.push(next),
+      Some(next) => {
+        text.push(c);
+        text.push(next);
+      }
+      None => text.push(c),
+    }
+  }
+  text.push(quote);
+  Some(text)
+}
+
+/// Converts the raw text of a string literal, without its quotes, into text
+/// that has the same value inside of a template literal.
+pub(crate) fn string_to_template_text(raw: &str) -> String {
+  let mut text = String::with_capacity(raw.len());
+  let mut chars = raw.chars().peekable();
+  while let Some(c) = chars.next() {
+    match c {
+      '\\' => {
+        text.push(c);
+        if let Some(next) = chars.next() {
+          text.push(next);
+        }
+      }
+      '`' => text.push_str("\\`"),
+      '$' if chars.peek() == Some(&'{') => text.push_str("\\$"),
+      _ => text.push(c),
+    }
+  }
+  text
+}
+
+/// Checks if the text of a template literal ends with a `$` that isn't
+/// escaped, which starts an interpolation when followed by `{`.
+pub(crate) fn ends_with_unescaped_dollar(text: &str) -> bool {
+  let Some(rest) = text.strip_suffix('$') else {
+    return false;
+  };
+  let backslashes = rest.len() - rest.trim_end_matches('\\').len();
+  backslashes % 2 == 0
+}
+
+/// Appends `text` to the text of a template literal. A `$` at the end of the
+/// template is escaped if `text` starts with `{`, so they don't form `${`.
+pub(crate) fn push_template_text(template: &mut String, text: &str) {
+  if text.starts_with('{') && ends_with_unescaped_dollar(template) {
+    template.insert(template.len() - 1, '\\');
+  }
+  template.push_str(text);
+}
+
 /// Find `Id`s in the lhs of an assigmnet expression.
 pub(crate) fn find_lhs_ids<I>(n: &ast_view::AssignTarget) -> Vec<I>
 where
@@ -316,6 +412,189 @@ where
   }
 }
 
+/// Returns the assignment target as an expression, so it can be compared
+/// with the operands of binary expressions.
+pub(crate) fn assign_target_expr(target: &AssignTarget) -> Option<Expr> {
+  match target {
+    AssignTarget::Simple(SimpleAssignTarget::Ident(ident)) => {
+      Some(Expr::Ident(ident.id.clone()))
+    }
+    AssignTarget::Simple(SimpleAssignTarget::Member(member)) => {
+      Some(Expr::Member(member.clone()))
+    }
+    _ => None,
+  }
+}
+
+/// Checks if the expression is a reference without side effects, like `x`,
+/// `this.x` or `x[0]`, so fixes may change how often it's evaluated.
+pub(crate) fn is_simple_reference(expr: &Expr) -> bool {
+  match expr {
+    Expr::Ident(_) => true,
+    Expr::Member(member) => {
+      matches!(&*member.obj, Expr::Ident(_) | Expr::This(_))
+        && match &member.prop {
+          MemberProp::Ident(_) | MemberProp::PrivateName(_) => true,
+          MemberProp::Computed(computed) => {
+            matches!(
+              &*computed.expr,
+              Expr::Ident(_) | Expr::Lit(Lit::Str(_) | Lit::Num(_))
+            )
+          }
+        }
+    }
+    _ => false,
+  }
+}
+
+/// Returns `true` if both types are structurally equal, i.e. they only differ
+/// in spans, syntax contexts or redundant parentheses. For example, `A | B`
+/// and `(A) | (B)` are considered equal, but `A | B` and `B | A` are not.
+pub(crate) fn ts_type_eq(a: &TsType, b: &TsType) -> bool {
+  normalize_ts_type(a) == normalize_ts_type(b)
+}
+
+/// Returns a copy of the type without spans, syntax contexts and redundant
+/// parentheses, suitable for structural comparison.
+pub(crate) fn normalize_ts_type(ty: &TsType) -> TsType {
+  let mut ty = span_and_ctx_drop(ty.clone());
+  ty.visit_mut_with(&mut StripTsParens);
+  ty
+}
+
+struct StripTsParens;
+impl VisitMut for StripTsParens {
+  fn visit_mut_ts_type(&mut self, ty: &mut TsType) {
+    while let TsType::TsParenthesizedType(paren) = ty {
+      let inner = (*paren.type_ann).clone();
+      *ty = inner;
+    }
+    ty.visit_mut_children_with(self);
+  }
+}
+
+/// Returns `true` if both expressions are structurally equal, i.e. they only
+/// differ in spans, syntax contexts or redundant parentheses. For example,
+/// `a.b[c]` and `(a.b)[c]` are considered equal.
+pub(crate) fn expr_eq(a: &Expr, b: &Expr) -> bool {
+  normalize_expr(a) == normalize_expr(b)
+}
+
+fn normalize_expr(expr: &Expr) -> Expr {
+  let mut expr = span_and_ctx_drop(expr.clone());
+  expr.visit_mut_with(&mut StripParens);
+  expr
+}
+
+struct StripParens;
+impl VisitMut for StripParens {
+  fn visit_mut_expr(&mut self, expr: &mut Expr) {
+    while let Expr::Paren(paren) = expr {
+      let inner = (*paren.expr).clone();
+      *expr = inner;
+    }
+    expr.visit_mut_children_with(self);
+  }
+}
+
+/// Checks if both expressions refer to the same variable or property, like
+/// ESLint's `isSameReference`. Only references that can be evaluated again
+/// without side effects are considered, i.e. identifiers, `this` and property
+/// accesses on them with static, literal or identifier keys. For example,
+/// `a[f()]` is never the same reference as `a[f()]`.
+pub(crate) fn is_same_reference(a: &Expr, b: &Expr) -> bool {
+  is_side_effect_free_reference(a) && expr_eq(a, b)
+}
+
+fn is_side_effect_free_reference(expr: &Expr) -> bool {
+  match expr.unwrap_parens() {
+    Expr::Ident(_) | Expr::This(_) => true,
+    Expr::Member(member) => {
+      is_side_effect_free_reference(&member.obj)
+        && match &member.prop {
+          MemberProp::Ident(_) | MemberProp::PrivateName(_) => true,
+          MemberProp::Computed(computed) => matches!(
+            computed.expr.unwrap_parens(),
+            Expr::Ident(_)
+              | Expr::Lit(Lit::Str(_) | Lit::Num(_) | Lit::BigInt(_))
+          ),
+        }
+    }
+    _ => false,
+  }
+}
+
+/// Returns the precedence of the expression, where a higher value binds more
+/// tightly. An operand whose precedence is lower than the one of the
+/// operator it's used with needs to be wrapped in parentheses.
+pub(crate) fn expr_precedence(expr: &Expr) -> u8 {
+  match expr {
+    Expr::Seq(_) => 0,
+    Expr::Assign(_) | Expr::Arrow(_) | Expr::Yield(_) => 1,
+    Expr::Cond(_) => 3,
+    Expr::Bin(bin) => binary_op_precedence(bin.op),
+    Expr::TsAs(_) | Expr::TsSatisfies(_) => binary_op_precedence(BinaryOp::Lt),
+    Expr::Unary(_) | Expr::Await(_) | Expr::TsTypeAssertion(_) => 16,
+    Expr::Update(_) => 17,
+    Expr::New(_) => 19,
+    _ => 20,
+  }
+}
+
+/// Returns the precedence of a binary operator, comparable with the values
+/// of [`expr_precedence`].
+pub(crate) fn binary_op_precedence(op: BinaryOp) -> u8 {
+  match op {
+    BinaryOp::LogicalOr | BinaryOp::NullishCoalescing => 4,
+    BinaryOp::LogicalAnd => 5,
+    BinaryOp::BitOr => 6,
+    BinaryOp::BitXor => 7,
+    BinaryOp::BitAnd => 8,
+    BinaryOp::EqEq | BinaryOp::NotEq | BinaryOp::EqEqEq | BinaryOp::NotEqEq => {
+      9
+    }
+    BinaryOp::Lt
+    | BinaryOp::LtEq
+    | BinaryOp::Gt
+    | BinaryOp::GtEq
+    | BinaryOp::In
+    | BinaryOp::InstanceOf => 10,
+    BinaryOp::LShift | BinaryOp::RShift | BinaryOp::ZeroFillRShift => 11,
+    BinaryOp::Add | BinaryOp::Sub => 12,
+    BinaryOp::Mul | BinaryOp::Div | BinaryOp::Mod => 13,
+    BinaryOp::Exp => 15,
+  }
+}
+
+/// Returns the range to remove for a statement or class member. If it is on
+/// a line of its own, the whole line is removed, otherwise the whitespace
+/// before it.
+pub(crate) fn removal_range(
+  range: SourceRange,
+  text_info: &SourceTextInfo,
+) -> SourceRange {
+  let line = text_info.line_index(range.start);
+  let line_start = text_info.line_start(line);
+  let line_end = text_info.line_end(line);
+  let before = SourceRange::new(line_start, range.start).text_fast(text_info);
+  let is_own_line = line == text_info.line_index(range.end)
+    && before.trim().is_empty()
+    && SourceRange::new(range.end, line_end)
+      .text_fast(text_info)
+      .trim()
+      .is_empty();
+  if !is_own_line {
+    return SourceRange::new(line_start + before.trim_end().len(), range.end);
+  }
+  if line + 1 < text_info.lines_count() {
+    SourceRange::new(line_start, text_info.line_start(line + 1))
+  } else if line > 0 {
+    SourceRange::new(text_info.line_end(line - 1), line_end)
+  } else {
+    range
+  }
+}
+
 pub fn span_and_ctx_drop<T>(mut t: T) -> T
 where
   T: VisitMutWith<DropSpanAndCtx>,
@@ -24,5 +24,19 @@ pub const FRESH: Tag = Tag("fresh");
 pub const JSR: Tag = Tag("jsr");
 pub const REACT: Tag = Tag("react");
 pub const JSX: Tag = Tag("jsx");
+pub const A11Y: Tag = Tag("a11y");
+pub const DENO: Tag = Tag("deno");
+pub const STYLISTIC: Tag = Tag("stylistic");
+pub const JSDOC: Tag = Tag("jsdoc");
 
-pub const ALL_TAGS: Tags = &[RECOMMENDED, FRESH, JSR, REACT, JSX];
+pub const ALL_TAGS: Tags = &[
+  RECOMMENDED,
+  FRESH,
+  JSR,
+  REACT,
+  JSX,
+  A11Y,
+  DENO,
+  STYLISTIC,
+  JSDOC,
+];
@@ -0,0 +1,548 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Helpers to test lint rules, for the rules of this crate as well as for
+//! custom rules of embedders.
+//!
+//! ```ignore
+//! use deno_lint::{assert_lint_err, assert_lint_ok};
+//!
+//! #[test]
+//! fn my_rule() {
+//!   assert_lint_ok! {
+//!     MyRule,
+//!     "let a = 1;",
+//!   };
+//!   assert_lint_err! {
+//!     MyRule,
+//!     "var a = 1;": [
+//!       {
+//!         col: 0,
+//!         message: "Use `let` instead",
+//!       }
+//!     ],
+//!   };
+//! }
+//! ```
+
+use std::borrow::Cow;
+
+use crate::diagnostic::LintDiagnostic;
+use crate::diagnostic::LintFix;
+use crate::linter::EcmaVersion;
+use crate::linter::LintConfig;
+use crate::linter::LintFileOptions;
+use crate::linter::Linter;
+use crate::linter::LinterOptions;
+use crate::rules::get_all_rules;
+use crate::rules::LintRule;
+use deno_ast::diagnostics::Diagnostic;
+use deno_ast::MediaType;
+use deno_ast::ModuleSpecifier;
+use deno_ast::ParsedSource;
+use deno_ast::SourceTextInfo;
+use deno_ast::TextChange;
+
+/// Asserts that the rule reports nothing for each of the sources.
+#[macro_export]
+macro_rules! assert_lint_ok {
+  (
+    $rule:expr,
+    filename: $filename:expr,
+    $($src:literal),+
+    $(,)?
+  ) => {
+    $(
+      $crate::testing::assert_lint_ok(Box::new($rule), $src, $filename);
+    )*
+  };
+  ($rule:expr, $($src:literal),+ $(,)?) => {
+    $crate::assert_lint_ok! {
+      $rule,
+      filename: "file:///deno_lint_ok_test.ts",
+      $($src,)*
+    };
+  };
+}
+
+/// Asserts that the rule reports the given diagnostics for each of the
+/// sources, including their hints, fixes and suggestions.
+#[macro_export]
+macro_rules! assert_lint_err {
+  (
+    $rule:expr,
+    filename: $filename:expr,
+    $($src:literal : $test:tt),+
+    $(,)?
+  ) => {
+    $(
+      let errors = $crate::parse_err_test!($test);
+      let tester = $crate::testing::LintErrTester::new(
+        Box::new($rule),
+        $src,
+        errors,
+        $filename,
+      );
+      tester.run();
+    )*
+  };
+  (
+    $rule:expr,
+    $($src:literal : $test:tt),+
+    $(,)?
+  ) => {
+    $crate::assert_lint_err! {
+      $rule,
+      filename: "file:///deno_lint_err_test.ts",
+      $($src: $test,)*
+    }
+  };
+
+  (
+    $rule: expr,
+    $message: expr,
+    $hint: expr,
+    filename: $filename:expr,
+    $($src:literal : $test:tt),+
+    $(,)?
+  ) => {
+    $(
+      let errors = $crate::parse_err_test!($message, $hint, $test);
+      let tester = $crate::testing::LintErrTester::new(
+        Box::new($rule),
+        $src,
+        errors,
+        $filename,
+      );
+      tester.run();
+    )*
+  };
+  (
+    $rule: expr,
+    $message: expr,
+    $hint: expr,
+    $($src:literal : $test:tt),+
+    $(,)?
+  ) => {
+    $crate::assert_lint_err! {
+      $rule,
+      $message,
+      $hint,
+      filename: "file:///deno_lint_err_test.ts",
+      $($src: $test,)*
+    }
+  };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! parse_err_test {
+  (
+    [
+      $(
+        {
+          $($field:ident : $value:expr),* $(,)?
+        }
+      ),* $(,)?
+    ]
+  ) => {{
+    let mut errors = Vec::new();
+    $(
+      let mut builder = $crate::testing::LintErrBuilder::new();
+      $(
+        builder.$field($value);
+      )*
+      let e = builder.build();
+      errors.push(e);
+    )*
+    errors
+  }};
+
+  (
+    {
+      filename : $filename:expr,
+      errors : $errors:tt $(,)?
+    }
+  ) => {{
+    let (errors, _) = $crate::parse_err_test!($errors);
+    (errors, $filename)
+  }};
+
+  (
+    $message: expr,
+    $hint: expr,
+    [
+      $(
+        {
+          $($field:ident : $value:expr),* $(,)?
+        }
+      ),* $(,)?
+    ]
+  ) => {{
+    let errors = $crate::parse_err_test!(
+      [
+        $(
+          {
+            message: $message,
+            hint: $hint,
+            $(
+              $field: $value,
+            )*
+          },
+        )*
+      ]
+    );
+    errors
+  }};
+}
+
+pub struct LintErrTester {
+  src: &'static str,
+  errors: Vec<LintErr>,
+  filename: &'static str,
+  rule: Box<dyn LintRule>,
+}
+
+impl LintErrTester {
+  pub fn new(
+    rule: Box<dyn LintRule>,
+    src: &'static str,
+    errors: Vec<LintErr>,
+    filename: &'static str,
+  ) -> Self {
+    Self {
+      src,
+      errors,
+      filename,
+      rule,
+    }
+  }
+
+  #[track_caller]
+  pub fn run(self) {
+    let rule_code = self.rule.code();
+    let (parsed_source, diagnostics) = lint(self.rule, self.src, self.filename);
+    if self.errors.len() != diagnostics.len() {
+      eprintln!(
+        "Actual diagnostics:\n{:#?}",
+        diagnostics
+          .iter()
+          .map(|d| d.details.message.to_string())
+          .collect::<Vec<_>>()
+      );
+      assert_eq!(
+        self.errors.len(),
+        diagnostics.len(),
+        "{} diagnostics expected, but got {}.\n\nsource:\n{}\n",
+        self.errors.len(),
+        diagnostics.len(),
+        self.src,
+      );
+    }
+
+    for (error, diagnostic) in self.errors.iter().zip(&diagnostics) {
+      let LintErr {
+        line,
+        col,
+        message,
+        hint,
+        fixes,
+        suggestions,
+      } = error;
+      assert_diagnostic_2(
+        diagnostic,
+        rule_code,
+        *line,
+        *col,
+        self.src,
+        message,
+        hint.as_deref(),
+        fixes,
+        suggestions,
+        parsed_source.text_info_lazy(),
+      );
+    }
+  }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct LintErrFix {
+  pub description: String,
+  pub fixed_code: String,
+}
+
+#[derive(Default)]
+pub struct LintErr {
+  pub line: usize,
+  pub col: usize,
+  pub message: String,
+  pub hint: Option<String>,
+  pub fixes: Vec<LintErrFix>,
+  pub suggestions: Vec<LintErrFix>,
+}
+
+#[derive(Default)]
+pub struct LintErrBuilder {
+  line: Option<usize>,
+  col: Option<usize>,
+  message: Option<String>,
+  hint: Option<String>,
+  fixes: Vec<LintErrFix>,
+  suggestions: Vec<LintErrFix>,
+}
+
+impl LintErrBuilder {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn line(&mut self, line: usize) -> &mut Self {
+    // Line is 1-based in deno_lint
+    assert!(line >= 1);
+    self.line = Some(line);
+    self
+  }
+
+  pub fn col(&mut self, col: usize) -> &mut Self {
+    self.col = Some(col);
+    self
+  }
+
+  pub fn message(&mut self, message: impl ToString) -> &mut Self {
+    self.message = Some(message.to_string());
+    self
+  }
+
+  pub fn hint(&mut self, hint: impl ToString) -> &mut Self {
+    self.hint = Some(hint.to_string());
+    self
+  }
+
+  pub fn fix(&mut self, value: (&'static str, &'static str)) -> &mut Self {
+    self.fixes.push(LintErrFix {
+      description: value.0.to_string(),
+      fixed_code: value.1.to_string(),
+    });
+    self
+  }
+
+  pub fn suggestion(
+    &mut self,
+    value: (&'static str, &'static str),
+  ) -> &mut Self {
+    self.suggestions.push(LintErrFix {
+      description: value.0.to_string(),
+      fixed_code: value.1.to_string(),
+    });
+    self
+  }
+
+  pub fn build(self) -> LintErr {
+    LintErr {
+      line: self.line.unwrap_or(1),
+      col: self.col.unwrap_or(0),
+      message: self.message.unwrap_or_default(),
+      hint: self.hint,
+      fixes: self.fixes,
+      suggestions: self.suggestions,
+    }
+  }
+}
+
+/// Lints the source with the rule alone, panicking if it fails to parse.
+#[track_caller]
+pub fn lint(
+  rule: Box<dyn LintRule>,
+  source: &str,
+  specifier: &str,
+) -> (ParsedSource, Vec<LintDiagnostic>) {
+  lint_with_ecma_version(rule, source, specifier, EcmaVersion::default())
+}
+
+/// Lints the source like `lint`, for code that has to run on the given
+/// ECMAScript version.
+#[track_caller]
+pub fn lint_with_ecma_version(
+  rule: Box<dyn LintRule>,
+  source: &str,
+  specifier: &str,
+  ecma_version: EcmaVersion,
+) -> (ParsedSource, Vec<LintDiagnostic>) {
+  let linter = Linter::new(LinterOptions {
+    rules: vec![rule],
+    all_rule_codes: get_all_rules()
+      .into_iter()
+      .map(|rule| rule.code())
+      .map(Cow::from)
+      .collect(),
+    custom_ignore_diagnostic_directive: None,
+    custom_ignore_file_directive: None,
+    extractors: vec![],
+    rule_time_budget: None,
+  });
+
+  let specifier = ModuleSpecifier::parse(specifier).unwrap();
+  let media_type = MediaType::from_specifier(&specifier);
+  let lint_result = linter.lint_file(LintFileOptions {
+    specifier,
+    source_code: source.to_string(),
+    media_type,
+    config: LintConfig {
+      default_jsx_factory: Some("React.createElement".to_owned()),
+      default_jsx_fragment_factory: Some("React.Fragment".to_owned()),
+      ecma_version,
+    },
+    external_linter: None,
+  });
+  match lint_result {
+    Ok((source, diagnostics)) => (source, diagnostics),
+    Err(e) => panic!(
+      "Failed to lint.\n[cause]\n{}\n\n[source code]\n{}",
+      e, source
+    ),
+  }
+}
+
+/// Lints the sources together as a project with the rule alone, see
+/// `Linter::lint_project`, panicking if one of them fails to parse. `files`
+/// are pairs of specifiers and sources, and `other_files` are the specifiers
+/// of files that aren't linted, like JSON modules. Returns the diagnostics of
+/// each file.
+#[track_caller]
+pub fn lint_project(
+  rule: Box<dyn LintRule>,
+  files: &[(&str, &str)],
+  other_files: &[&str],
+) -> Vec<Vec<LintDiagnostic>> {
+  let linter = Linter::new(LinterOptions {
+    rules: vec![rule],
+    ..Default::default()
+  });
+
+  let files = files
+    .iter()
+    .map(|(specifier, source)| {
+      let specifier = ModuleSpecifier::parse(specifier).unwrap();
+      LintFileOptions {
+        media_type: MediaType::from_specifier(&specifier),
+        specifier,
+        source_code: source.to_string(),
+        config: LintConfig::default(),
+        external_linter: None,
+      }
+    })
+    .collect();
+  let other_files = other_files
+    .iter()
+    .map(|specifier| ModuleSpecifier::parse(specifier).unwrap())
+    .collect();
+  linter
+    .lint_project(files, other_files)
+    .into_iter()
+    .map(|result| match result {
+      Ok((_, diagnostics)) => diagnostics,
+      Err(e) => panic!("Failed to lint.\n[cause]\n{}", e),
+    })
+    .collect()
+}
+
+#[allow(clippy::too_many_arguments)]
+#[track_caller]
+fn assert_diagnostic_2(
+  diagnostic: &LintDiagnostic,
+  code: &str,
+  line: usize,
+  col: usize,
+  source: &str,
+  message: &str,
+  hint: Option<&str>,
+  fixes: &[LintErrFix],
+  suggestions: &[LintErrFix],
+  text_info: &SourceTextInfo,
+) {
+  let diagnostic_range = diagnostic.range.as_ref().unwrap();
+  let line_and_column = diagnostic_range
+    .text_info
+    .line_and_column_index(diagnostic_range.range.start);
+  assert_eq!(
+    code, diagnostic.details.code,
+    "Rule code is expected to be \"{}\", but got \"{}\"\n\nsource:\n{}\n",
+    code, diagnostic.details.code, source
+  );
+  assert_eq!(
+    line,
+    line_and_column.line_index + 1,
+    "Line is expected to be \"{}\", but got \"{}\"\n\nsource:\n{}\n",
+    line,
+    line_and_column.line_index + 1,
+    source
+  );
+  assert_eq!(
+    col, line_and_column.column_index,
+    "Column is expected to be \"{}\", but got \"{}\"\n\nsource:\n{}\n",
+    col, line_and_column.column_index, source
+  );
+  assert_eq!(
+    message, &diagnostic.details.message,
+    "Diagnostic message is expected to be \"{}\", but got \"{}\"\n\nsource:\n{}\n",
+    message, &diagnostic.details.message, source
+  );
+  assert_eq!(
+    hint,
+    diagnostic.details.hint.as_deref(),
+    "Diagnostic hint is expected to be \"{:?}\", but got \"{:?}\"\n\nsource:\n{}\n",
+    hint,
+    diagnostic.details.hint.as_deref(),
+    source
+  );
+  assert_eq!(
+    apply_fixes(&diagnostic.details.fixes, text_info),
+    fixes,
+    "Quick fixes did not match."
+  );
+  assert_eq!(
+    apply_fixes(&diagnostic.details.suggestions, text_info),
+    suggestions,
+    "Suggestions did not match."
+  );
+}
+
+fn apply_fixes(
+  fixes: &[LintFix],
+  text_info: &SourceTextInfo,
+) -> Vec<LintErrFix> {
+  fixes
+    .iter()
+    .map(|fix| LintErrFix {
+      description: fix.description.to_string(),
+      fixed_code: deno_ast::apply_text_changes(
+        text_info.text_str(),
+        fix
+          .changes
+          .iter()
+          .map(|change| TextChange {
+            range: change.range.as_byte_range(text_info.range().start),
+            new_text: change.new_text.to_string(),
+          })
+          .collect(),
+      ),
+    })
+    .collect()
+}
+
+/// Asserts that the rule reports nothing for the source.
+#[track_caller]
+pub fn assert_lint_ok(
+  rule: Box<dyn LintRule>,
+  source: &str,
+  specifier: &'static str,
+) {
+  let (_parsed_source, diagnostics) = lint(rule, source, specifier);
+  if !diagnostics.is_empty() {
+    eprintln!("filename {:?}", specifier);
+    panic!(
+      "Unexpected diagnostics found:\n{:#?}\n\nsource:\n{}\n",
+      diagnostics.iter().map(|d| d.message()).collect::<Vec<_>>(),
+      source
+    );
+  }
+}
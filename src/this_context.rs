@@ -0,0 +1,148 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Analysis of how a function depends on the context it's called with, i.e.
+//! whether it refers to `this`, `arguments`, `super` or `new.target`. Arrow
+//! functions don't have their own bindings for these and inherit them from
+//! the enclosing function instead, so this decides whether a function can be
+//! turned into an arrow function without changing its behavior.
+
+use deno_ast::swc::ast::{
+  ArrowExpr, ClassProp, Constructor, Function, GetterProp, Ident, MetaPropExpr,
+  MetaPropKind, PrivateProp, SetterProp, StaticBlock, Super, ThisExpr,
+};
+use deno_ast::swc::visit::{noop_visit_type, Visit, VisitWith};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ThisContext {
+  pub uses_this: bool,
+  pub uses_arguments: bool,
+  pub uses_super: bool,
+  pub uses_new_target: bool,
+}
+
+impl ThisContext {
+  /// Collects the usages in the parameters and the body of `function`.
+  /// Nested functions, methods and class members have their own context, so
+  /// only the arrow functions inside of it are taken into account.
+  pub(crate) fn of_function(function: &Function) -> Self {
+    let mut this_context = Self::default();
+    function.params.visit_with(&mut this_context);
+    function.body.visit_with(&mut this_context);
+    this_context
+  }
+
+  /// Collects the usages in the parameters and the body of `arrow`, which
+  /// refer to the context of the enclosing function.
+  pub(crate) fn of_arrow(arrow: &ArrowExpr) -> Self {
+    let mut this_context = Self::default();
+    arrow.params.visit_with(&mut this_context);
+    arrow.body.visit_with(&mut this_context);
+    this_context
+  }
+
+  /// Checks if anything other than `this` depends on the context. Unlike
+  /// `this`, these can't be preserved by binding the function.
+  pub(crate) fn uses_non_this_bindings(&self) -> bool {
+    self.uses_arguments || self.uses_super || self.uses_new_target
+  }
+}
+
+impl Visit for ThisContext {
+  noop_visit_type!();
+
+  fn visit_this_expr(&mut self, _: &ThisExpr) {
+    self.uses_this = true;
+  }
+
+  fn visit_super(&mut self, _: &Super) {
+    self.uses_super = true;
+  }
+
+  fn visit_ident(&mut self, ident: &Ident) {
+    if ident.sym == *"arguments" {
+      self.uses_arguments = true;
+    }
+  }
+
+  fn visit_meta_prop_expr(&mut self, meta_prop: &MetaPropExpr) {
+    if meta_prop.kind == MetaPropKind::NewTarget {
+      self.uses_new_target = true;
+    }
+  }
+
+  fn visit_function(&mut self, function: &Function) {
+    // Decorators are evaluated in the enclosing context.
+    function.decorators.visit_with(self);
+  }
+
+  fn visit_constructor(&mut self, constructor: &Constructor) {
+    constructor.key.visit_with(self);
+  }
+
+  fn visit_getter_prop(&mut self, getter: &GetterProp) {
+    getter.key.visit_with(self);
+  }
+
+  fn visit_setter_prop(&mut self, setter: &SetterProp) {
+    setter.key.visit_with(self);
+  }
+
+  fn visit_class_prop(&mut self, class_prop: &ClassProp) {
+    class_prop.key.visit_with(self);
+    class_prop.decorators.visit_with(self);
+  }
+
+  fn visit_private_prop(&mut self, private_prop: &PrivateProp) {
+    private_prop.decorators.visit_with(self);
+  }
+
+  fn visit_static_block(&mut self, _: &StaticBlock) {}
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util;
+  use deno_ast::swc::ast::{Expr, Stmt};
+  use deno_ast::ProgramRef;
+
+  fn this_context_of(src: &str) -> ThisContext {
+    let parsed_source = test_util::parse(src);
+    let stmt = match parsed_source.program_ref() {
+      ProgramRef::Module(module) => module.body[0].as_stmt(),
+      ProgramRef::Script(script) => script.body.first(),
+    };
+    let Some(Stmt::Expr(expr_stmt)) = stmt else {
+      panic!("expected an expression statement");
+    };
+    let Expr::Paren(paren) = &*expr_stmt.expr else {
+      panic!("expected a parenthesized expression");
+    };
+    let Expr::Fn(fn_expr) = &*paren.expr else {
+      panic!("expected a function expression");
+    };
+    ThisContext::of_function(&fn_expr.function)
+  }
+
+  #[test]
+  fn this_context() {
+    assert_eq!(this_context_of("(function () {})"), ThisContext::default());
+    assert!(this_context_of("(function () { this.a; })").uses_this);
+    assert!(this_context_of("(function () { () => this; })").uses_this);
+    assert!(this_context_of("(function (a = this) {})").uses_this);
+    assert!(this_context_of("(function () { ({ [this.a]: 1 }); })").uses_this);
+    assert!(this_context_of("(function () { arguments[0]; })").uses_arguments);
+    assert!(this_context_of("(function () { new.target; })").uses_new_target);
+
+    assert_eq!(
+      this_context_of(
+        "(function () {
+          (function () { this; arguments; });
+          ({ get a() { return this; }, b() { return this; } });
+          class A { a = this; constructor() { this; } static { this; } }
+        })"
+      ),
+      ThisContext::default(),
+    );
+  }
+}